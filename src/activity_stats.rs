@@ -0,0 +1,101 @@
+//! Distance and vert split by activity type, so running miles can be told
+//! apart from hiking miles.
+
+use chrono::Datelike;
+
+use crate::models::DailyLog;
+use crate::models::activity::ActivityType;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ActivityTotals {
+    /// None for days that were never tagged.
+    pub activity: Option<ActivityType>,
+    pub days: usize,
+    pub miles: f32,
+    pub elevation: i32,
+}
+
+/// Totals for each activity type logged in `year`, in `ActivityType::ALL`
+/// order with untagged days last. Empty unless at least one day that year
+/// is tagged, since an "Untagged" line on its own says nothing.
+pub fn totals_by_activity(logs: &[DailyLog], year: i32) -> Vec<ActivityTotals> {
+    let in_year: Vec<&DailyLog> = logs.iter().filter(|log| log.date.year() == year).collect();
+    if in_year.iter().all(|log| log.activity.is_none()) {
+        return Vec::new();
+    }
+    ActivityType::ALL
+        .into_iter()
+        .map(Some)
+        .chain([None])
+        .filter_map(|activity| {
+            let days: Vec<&&DailyLog> = in_year
+                .iter()
+                .filter(|log| log.activity == activity)
+                // Untagged rest days aren't worth counting
+                .filter(|log| {
+                    activity.is_some()
+                        || log.miles_covered.is_some()
+                        || log.elevation_gain.is_some()
+                })
+                .collect();
+            (!days.is_empty()).then(|| ActivityTotals {
+                activity,
+                days: days.len(),
+                miles: days.iter().filter_map(|log| log.miles_covered).sum(),
+                elevation: days.iter().filter_map(|log| log.elevation_gain).sum(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn day(d: u32, activity: Option<ActivityType>, miles: Option<f32>) -> DailyLog {
+        DailyLog {
+            activity,
+            miles_covered: miles,
+            elevation_gain: miles.map(|m| (m * 100.0) as i32),
+            ..DailyLog::new(NaiveDate::from_ymd_opt(2026, 7, d).unwrap())
+        }
+    }
+
+    #[test]
+    fn splits_the_year_by_activity_with_untagged_days_last() {
+        let logs = vec![
+            day(1, Some(ActivityType::Hike), Some(8.0)),
+            day(2, Some(ActivityType::Run), Some(5.0)),
+            day(3, None, Some(3.0)),
+            day(4, Some(ActivityType::Run), Some(6.0)),
+            day(5, None, None),
+        ];
+        let totals = totals_by_activity(&logs, 2026);
+        assert_eq!(
+            totals,
+            vec![
+                ActivityTotals {
+                    activity: Some(ActivityType::Run),
+                    days: 2,
+                    miles: 11.0,
+                    elevation: 1100,
+                },
+                ActivityTotals {
+                    activity: Some(ActivityType::Hike),
+                    days: 1,
+                    miles: 8.0,
+                    elevation: 800,
+                },
+                ActivityTotals {
+                    activity: None,
+                    days: 1,
+                    miles: 3.0,
+                    elevation: 300,
+                },
+            ]
+        );
+        assert!(totals_by_activity(&logs[2..3], 2026).is_empty());
+        assert!(totals_by_activity(&logs, 2025).is_empty());
+    }
+}
@@ -1,21 +1,119 @@
 use anyhow::{Context, Result};
 use crossterm::event::{Event, KeyCode, MouseEvent};
 use ratatui::{Frame, Terminal, backend::CrosstermBackend, widgets::ListState};
+use std::collections::HashSet;
 use std::io;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
 
-use crate::config::AppConfig;
+use crate::barcode;
+use crate::best_efforts::{self, BEST_EFFORTS, BestEffort};
+use crate::computed::Formulas;
+use crate::config::{AppConfig, IdleAction};
+use crate::date_parse::parse_fuzzy_date;
 use crate::db_manager::{ConnectionState, DbManager};
 use crate::events::handlers::{ActionHandler, InputHandler, NavigationHandler, SectionNavigator};
+use crate::events::keys::{Keymap, normalize_key};
 use crate::file_manager::FileManager;
+use crate::format::Units;
+use crate::health;
+use crate::lock::AppLock;
+use crate::manual;
+use crate::ocr;
+use crate::hooks::{self, Hook};
+use crate::platform::{Clipboard, SystemClipboard};
+use crate::profiling::{self, Profiler};
+use crate::gear::Gear;
+use crate::goals::{GoalDraft, GoalKind};
+use crate::injuries::{self, Injury, InjuryDraft, Severity};
+use crate::phases::{self, Phase, PhaseDraft, PhaseKind};
+use crate::weather::{self, Weather};
+use crate::save_errors::{self, FailedSave, FailureKind, RETRY_DELAYS, SaveQueue};
+use crate::share_card;
+use crate::models::jump_list::{JumpList, JumpLocation};
+use crate::models::backfill::{Backfill, BackfillField};
+use crate::models::food_form::{FoodField, FoodForm};
+use crate::models::home_filter::{HomeRow, save_view};
 use crate::models::{
-    AppScreen, AppState, ConfigSyncField, FocusedSection, MeasurementField, RunningField,
+    AppScreen, AppState, ConfigSyncField, DailyLog, FocusedSection, FoodEntry,
+    MeasurementField, Reflection, RunningField,
 };
 use crate::ui::screens;
-use crate::ui::{ClickAction, ClickTarget, hit_test, left_click_position};
+use crate::ui::theme::{Theme, ThemeName};
+use crate::ui::screens::charts::{ChartMetric, ChartRange};
+use crate::month_stats::{month_start, shift_month};
+use crate::week_stats::week_start;
+use crate::ui::{ClickAction, ClickTarget, hit_test, hover_position, left_click_position};
+use crate::units::Quantity;
+
+/// How many frequent foods the Add Food dropdown offers.
+const FOOD_SUGGESTIONS: usize = 6;
+
+const SYNC_STARTING: &str = "Getting ready to sync...";
+const SYNC_FINISHING_SAVES: &str = "Finishing saves...";
+const SYNC_OFFLINE: &str = "Offline - changes will sync when network is available";
+
+/// How long the shutdown sync may run before it's given up on.
+const SHUTDOWN_SYNC_TIMEOUT: Duration = Duration::from_secs(20);
+
+/// How long the shutdown sync's result stays up before the app exits.
+const SHUTDOWN_RESULT_SHOWN: Duration = Duration::from_secs(1);
+
+/// How the sync on the way out went.
+enum ShutdownOutcome {
+    /// Synced, moving this many pages of the database.
+    Synced(usize),
+    /// Not connected, or the sync failed; the changes go up next time.
+    Offline,
+}
+
+/// The sync on the way out. It runs on its own task so the syncing screen
+/// keeps animating, and stays responsive, while it waits on the network.
+struct ShutdownSync {
+    started: Instant,
+    /// What the task is doing, as it reports it.
+    stage: tokio::sync::watch::Receiver<&'static str>,
+    /// Saves the task is waiting on before it syncs.
+    pending_saves: tokio::sync::watch::Receiver<usize>,
+    task: tokio::task::JoinHandle<ShutdownOutcome>,
+    /// Once the task is done: the result to show, and when to quit.
+    finished: Option<(String, Instant)>,
+    /// The post-sync hook after a successful sync, waited on before exiting
+    /// so it isn't cut off with the process.
+    post_sync: Option<std::thread::JoinHandle<()>>,
+}
+
+/// "1 write pending", "3 writes pending".
+fn writes_pending(count: usize) -> String {
+    format!("{} {} pending", count, if count == 1 { "write" } else { "writes" })
+}
+
+impl ShutdownSync {
+    fn status(&self) -> String {
+        match &self.finished {
+            Some((status, _)) => status.clone(),
+            None if *self.stage.borrow() == SYNC_FINISHING_SAVES => {
+                format!("Finishing saves... {}", writes_pending(*self.pending_saves.borrow()))
+            }
+            None => self.stage.borrow().to_string(),
+        }
+    }
+
+    /// The spinner frame and seconds elapsed, while it's still running.
+    fn spinner(&self, ascii: bool) -> Option<String> {
+        if self.finished.is_some() {
+            return None;
+        }
+        let elapsed = self.started.elapsed();
+        Some(format!(
+            "{} {}s   Esc: skip",
+            screens::spinner_frame(elapsed, ascii),
+            elapsed.as_secs()
+        ))
+    }
+}
 
 pub struct App {
     state: AppState,
@@ -35,23 +133,201 @@ pub struct App {
     /// Set by the background cloud-sync task after it pulls from the primary,
     /// signalling the event loop to reload the in-memory daily_logs cache.
     needs_reload: Arc<AtomicBool>,
+    /// New day waiting on a `sync_on_new_day` sync before it's edited.
+    pending_day_sync: Option<chrono::NaiveDate>,
+    /// That sync, running on its own task, and the day it's for.
+    day_sync: Option<(chrono::NaiveDate, tokio::task::JoinHandle<Result<usize>>)>,
+    /// Field whose sanity warning was already shown; a second Enter saves it.
+    sanity_override: Option<crate::models::field_accessor::FieldType>,
+    /// Entry picked from the numeric field's recent-values dropdown.
+    history_index: Option<usize>,
+    jump_list: JumpList,
+    heatmap_cursor: chrono::NaiveDate,
+    /// Screen the heatmap was opened from, restored on Esc.
+    heatmap_return: AppScreen,
+    year_cursor: chrono::NaiveDate,
+    /// Row of `state.food_history` picked on the food lookup.
+    food_history_selected: usize,
+    /// Add / Edit Food fields other than the one being typed in.
+    food_form: FoodForm,
+    /// Pointer position and the day under it, while hovering a logged day
+    /// on the logged days list or calendar.
+    tooltip: Option<((u16, u16), chrono::NaiveDate)>,
+    /// Highlighted row of the Views menu.
+    views_selected: usize,
+    /// The Views menu is taking a name to save the current filter under.
+    naming_view: bool,
+    /// Screen the year view was opened from, restored on Esc.
+    year_return: AppScreen,
+    chart_metric: ChartMetric,
+    chart_range: ChartRange,
+    /// Screen the charts were opened from, restored on Esc.
+    charts_return: AppScreen,
+    /// Highlighted day in the week view; the week shown is the one containing it.
+    week_cursor: chrono::NaiveDate,
+    /// Screen the week view was opened from, restored on Esc.
+    week_return: AppScreen,
+    /// First day of the month shown in the monthly summary.
+    month_cursor: chrono::NaiveDate,
+    /// Screen the monthly summary was opened from, restored on Esc.
+    month_return: AppScreen,
+    /// First manual line shown on the F1 screen.
+    manual_scroll: u16,
+    /// Screen the manual was opened from, restored on Esc or F1.
+    manual_return: AppScreen,
+    /// Highlighted day on the calendar picker.
+    calendar_cursor: chrono::NaiveDate,
+    /// Theme highlighted on the settings screen, previewed until saved.
+    settings_theme: ThemeName,
+    /// F9 privacy screen: the real screen is hidden until F9 again.
+    private: bool,
+    /// F10 render profiling overlay.
+    profiler: Profiler,
+    /// Passphrase lock; while locked only the lock screen is shown.
+    lock: AppLock,
+    /// Last key or mouse event, for the idle lock and `[session]` action.
+    last_input: Instant,
+    /// The `[session]` idle action already ran since the last input.
+    idle_handled: bool,
+    /// Draw decorative glyphs as ASCII, resolved once from config and locale.
+    ascii_glyphs: bool,
+    /// Letter swaps for the configured keyboard layout.
+    keymap: Keymap,
+    clipboard: Box<dyn Clipboard>,
+    /// The sync run on the syncing screen before quitting, once started.
+    shutdown_sync: Option<ShutdownSync>,
+    /// Saves still being written in the background. Quitting asks first
+    /// while any are, and the shutdown sync waits for them.
+    pending_saves: tokio::sync::watch::Sender<usize>,
+    /// Keeps each day's saves in order, so a retry can't land over a newer
+    /// save of the same day.
+    save_queue: SaveQueue,
+    /// Why quitting needs a second thought, while the y/n prompt is up.
+    quit_prompt: Option<Vec<String>>,
+    /// Esc was pressed on an edit that changed the saved value; y throws
+    /// the change away, n goes back to typing.
+    discard_prompt: bool,
+    /// Background saves report here once their retries run out; the event
+    /// loop moves them into `state.failed_saves`.
+    failed_saves_tx: tokio::sync::mpsc::UnboundedSender<FailedSave>,
+    failed_saves_rx: tokio::sync::mpsc::UnboundedReceiver<FailedSave>,
+    /// Days saved whose markdown backup couldn't be written, with why.
+    backup_errors_tx: tokio::sync::mpsc::UnboundedSender<(chrono::NaiveDate, String)>,
+    backup_errors_rx: tokio::sync::mpsc::UnboundedReceiver<(chrono::NaiveDate, String)>,
+    /// Fetched weather comes back here for the event loop to save with its
+    /// day.
+    weather_tx: tokio::sync::mpsc::UnboundedSender<(chrono::NaiveDate, Weather)>,
+    weather_rx: tokio::sync::mpsc::UnboundedReceiver<(chrono::NaiveDate, Weather)>,
+    /// Days whose weather has been asked for this session, so a day that
+    /// couldn't get it (offline) isn't asked again on every save.
+    weather_requested: std::sync::Mutex<HashSet<chrono::NaiveDate>>,
+    /// Barcodes looked up in the background come back here to fill the
+    /// Add Food form.
+    barcode_tx: tokio::sync::mpsc::UnboundedSender<(String, Result<barcode::Product>)>,
+    barcode_rx: tokio::sync::mpsc::UnboundedReceiver<(String, Result<barcode::Product>)>,
+    /// The barcode being looked up, while it is.
+    barcode_lookup: Option<String>,
+    /// Highlighted row of the failed saves screen.
+    save_errors_selected: usize,
+    /// Screen the failed saves were opened from, restored on Esc.
+    save_errors_return: AppScreen,
+    /// Highlighted row of the gear screen.
+    gear_selected: usize,
+    /// The gear screen is taking the name of new gear.
+    naming_gear: bool,
+    /// Screen the gear screen was opened from, restored on Esc.
+    gear_return: AppScreen,
+    /// Highlighted choice in the gear picker; past the active gear is "None".
+    gear_picker_selected: usize,
+    /// Highlighted row of the routes screen.
+    routes_selected: usize,
+    /// Screen the routes screen was opened from, restored on Esc.
+    routes_return: AppScreen,
+    /// Highlighted exercise on the strength screen.
+    strength_selected: usize,
+    /// Screen the strength screen was opened from, restored on Esc.
+    strength_return: AppScreen,
+    /// Focused list and row of the best efforts screen.
+    best_efforts_focus: BestEffort,
+    best_efforts_selected: usize,
+    /// Screen the best efforts screen was opened from, restored on Esc.
+    best_efforts_return: AppScreen,
+    /// Highlighted goal on the goals screen.
+    goals_selected: usize,
+    /// The goal being added or edited, its target in the input buffer.
+    goal_draft: Option<GoalDraft>,
+    /// Screen the goals screen was opened from, restored on Esc.
+    goals_return: AppScreen,
+    /// Highlighted phase on the phases screen.
+    phases_selected: usize,
+    /// The phase being marked or changed, its range in the input buffer.
+    phase_draft: Option<PhaseDraft>,
+    /// Screen the phases screen was opened from, restored on Esc.
+    phases_return: AppScreen,
+    /// Highlighted injury on the injuries screen.
+    injuries_selected: usize,
+    /// The injury being logged or changed, what hurts and when in the input
+    /// buffer.
+    injury_draft: Option<InjuryDraft>,
+    /// Screen the injuries screen was opened from, restored on Esc.
+    injuries_return: AppScreen,
+    /// The backfill wizard's range and the day being filled in.
+    backfill: Backfill,
+    backfill_return: AppScreen,
+    /// Lines read off a photo, each with whether it's picked to log.
+    ocr_candidates: Vec<(String, bool)>,
+    /// Highlighted line of the photo review.
+    ocr_selected: usize,
+    /// The OCR command reading a photo on its own thread: set the flag to
+    /// stop it, and its text comes back on the channel.
+    ocr_reading: Option<(Arc<AtomicBool>, tokio::sync::oneshot::Receiver<Result<String>>)>,
 }
 
 impl App {
     /// Creates app with instant startup, spawns background cloud sync if configured
     pub async fn new(config: AppConfig) -> Result<Self> {
-        let mountains_dir = crate::config::data_dir()?;
+        let mountains_dir = config.storage_dir()?;
 
         if !mountains_dir.exists() {
             std::fs::create_dir_all(&mountains_dir)
                 .context("Failed to create .mountains directory")?;
         }
 
+        let file_manager = FileManager::in_dir(mountains_dir.clone())?;
+        Self::with_storage(config, mountains_dir, file_manager).await
+    }
+
+    async fn with_storage(
+        config: AppConfig,
+        mountains_dir: std::path::PathBuf,
+        file_manager: FileManager,
+    ) -> Result<Self> {
         let db_manager = DbManager::new_local_first(&mountains_dir).await?;
-        let file_manager = FileManager::new()?;
 
         let mut state = AppState::new();
-        state.daily_logs = db_manager.load_all_daily_logs().await?;
+        state.set_daily_logs(db_manager.load_all_daily_logs().await?);
+        state.health_banner = health::banner(&file_manager.health_path(), &config);
+        if let Some(notice) = health::take_skipped_sync(&file_manager.health_path()) {
+            state.show_toast(notice);
+        }
+        state.units = Units::from_config(&config.display);
+        state.theme = config.colors.apply(Theme::new(config.display.theme));
+        state.screen_reader = config.display.screen_reader;
+        state.stats.configure(config.stats);
+        state.stats.set_streak_rules(config.streak_rules());
+        state.computed = Formulas::compile(&config.computed.fields);
+        state.calorie_target = config.food.calorie_target;
+        state.goals = config.goals;
+        state.notes_word_goal = config.notes.word_goal;
+        state.hr_zones = config.heart_rate.zone_starts();
+        state.sync_configured = config.sync.is_configured();
+        state.gear = db_manager.load_gear().await?;
+        state.gear_retire_after = config.gear.retire_after_miles(config.display.units);
+        state.goal_list = db_manager.load_goals().await?;
+        state.phases = db_manager.load_phases().await?;
+        state.injuries = db_manager.load_injuries().await?;
+        state.plan = db_manager.load_planned_workouts().await?;
+        let file_manager = file_manager.with_units(Units::from_config(&config.display));
 
         let db_manager = Arc::new(RwLock::new(db_manager));
         let needs_reload = Arc::new(AtomicBool::new(false));
@@ -79,7 +355,14 @@ impl App {
             });
         }
 
-        Ok(Self {
+        let ascii_glyphs = config.display.screen_reader || config.display.glyphs.use_ascii();
+        let keymap = Keymap::new(config.keys.preset);
+        let lock = AppLock::new(&config.lock);
+        let (failed_saves_tx, failed_saves_rx) = tokio::sync::mpsc::unbounded_channel();
+        let (backup_errors_tx, backup_errors_rx) = tokio::sync::mpsc::unbounded_channel();
+        let (weather_tx, weather_rx) = tokio::sync::mpsc::unbounded_channel();
+        let (barcode_tx, barcode_rx) = tokio::sync::mpsc::unbounded_channel();
+        let mut app = Self {
             state,
             config,
             db_manager,
@@ -95,7 +378,85 @@ impl App {
             config_sync_enabled: false,
             click_targets: Vec::new(),
             needs_reload,
-        })
+            pending_day_sync: None,
+            day_sync: None,
+            sanity_override: None,
+            history_index: None,
+            jump_list: JumpList::default(),
+            heatmap_cursor: chrono::Local::now().date_naive(),
+            heatmap_return: AppScreen::Startup,
+            year_cursor: chrono::Local::now().date_naive(),
+            food_history_selected: 0,
+            tooltip: None,
+            views_selected: 0,
+            naming_view: false,
+            food_form: FoodForm::default(),
+            year_return: AppScreen::Startup,
+            chart_metric: ChartMetric::Weight,
+            chart_range: ChartRange::Days30,
+            charts_return: AppScreen::Startup,
+            week_cursor: chrono::Local::now().date_naive(),
+            week_return: AppScreen::Startup,
+            month_cursor: month_start(chrono::Local::now().date_naive()),
+            month_return: AppScreen::Startup,
+            manual_scroll: 0,
+            manual_return: AppScreen::Startup,
+            calendar_cursor: chrono::Local::now().date_naive(),
+            settings_theme: ThemeName::default(),
+            private: false,
+            profiler: Profiler::default(),
+            lock,
+            last_input: Instant::now(),
+            idle_handled: false,
+            ascii_glyphs,
+            keymap,
+            clipboard: Box::new(SystemClipboard::default()),
+            shutdown_sync: None,
+            pending_saves: tokio::sync::watch::Sender::new(0),
+            save_queue: SaveQueue::default(),
+            quit_prompt: None,
+            discard_prompt: false,
+            failed_saves_tx,
+            failed_saves_rx,
+            backup_errors_tx,
+            backup_errors_rx,
+            weather_tx,
+            weather_rx,
+            barcode_tx,
+            barcode_rx,
+            barcode_lookup: None,
+            weather_requested: std::sync::Mutex::new(HashSet::new()),
+            save_errors_selected: 0,
+            save_errors_return: AppScreen::Startup,
+            gear_selected: 0,
+            naming_gear: false,
+            gear_return: AppScreen::Startup,
+            gear_picker_selected: 0,
+            routes_selected: 0,
+            routes_return: AppScreen::Startup,
+            strength_selected: 0,
+            strength_return: AppScreen::Startup,
+            best_efforts_focus: BestEffort::Miles,
+            best_efforts_selected: 0,
+            best_efforts_return: AppScreen::Startup,
+            goals_selected: 0,
+            goal_draft: None,
+            goals_return: AppScreen::Startup,
+            phases_selected: 0,
+            phase_draft: None,
+            phases_return: AppScreen::Startup,
+            injuries_selected: 0,
+            injury_draft: None,
+            injuries_return: AppScreen::Startup,
+            backfill: Backfill::default(),
+            backfill_return: AppScreen::Startup,
+            ocr_candidates: Vec::new(),
+            ocr_selected: 0,
+            ocr_reading: None,
+        };
+        // The launch screen is the first place Ctrl+O can return to
+        app.record_jump();
+        Ok(app)
     }
 
     /// Main event loop
@@ -106,32 +467,97 @@ impl App {
         loop {
             self.update_sync_status().await;
             self.reload_logs_if_needed().await?;
+            self.collect_failed_saves();
+            self.collect_weather();
+            self.collect_ocr();
+            self.collect_barcode();
+            self.state.clear_expired_toast();
+            let idle_for = self.last_input.elapsed();
+            self.lock.check_idle(idle_for);
+            self.handle_idle(idle_for);
 
-            // Handle syncing screen
             if matches!(self.state.current_screen, AppScreen::Syncing) {
-                terminal.draw(|f| self.ui(f))?;
-                self.perform_shutdown_sync().await;
-                terminal.draw(|f| self.ui(f))?;
-                std::thread::sleep(Duration::from_millis(1000));
+                self.tick_shutdown_sync().await;
             }
 
+            let (started, allocations) = (Instant::now(), profiling::allocations());
             terminal.draw(|f| self.ui(f))?;
+            self.profiler
+                .record_frame(started.elapsed(), profiling::allocations() - allocations);
+            // After the draw, so the "Syncing" toast is up while it runs
+            self.sync_new_day_if_needed().await;
 
             if crossterm::event::poll(Duration::from_millis(100))? {
-                match crossterm::event::read()? {
-                    Event::Key(key) => {
-                        self.handle_key_event_with_modifiers(key.code, key.modifiers)
-                            .await?;
-                    }
-                    Event::Mouse(mouse) => self.handle_mouse_event(mouse),
-                    _ => {}
-                }
+                self.handle_event(crossterm::event::read()?).await?;
             }
 
             if self.should_quit {
                 break;
             }
         }
+        self.finish_post_sync_hook().await;
+        Ok(())
+    }
+
+    /// Waits for the post-sync hook started after the shutdown sync, which
+    /// would otherwise die with the process. It stops itself at the hook
+    /// timeout, so this can't hang.
+    async fn finish_post_sync_hook(&mut self) {
+        if let Some(post_sync) = self.shutdown_sync.as_mut().and_then(|s| s.post_sync.take()) {
+            let _ = tokio::task::spawn_blocking(move || post_sync.join()).await;
+        }
+    }
+
+    async fn handle_event(&mut self, event: Event) -> Result<()> {
+        if matches!(event, Event::Key(_) | Event::Mouse(_)) {
+            self.last_input = Instant::now();
+            self.idle_handled = false;
+        }
+        match event {
+            Event::Key(key) => {
+                self.tooltip = None;
+                let Some((mut code, modifiers)) = normalize_key(key) else {
+                    return Ok(());
+                };
+                if self.lock.locked {
+                    self.handle_lock_input(code);
+                    return Ok(());
+                }
+                // F9 hides the screen from anywhere, even mid-edit, and while
+                // hidden nothing else gets through
+                if code == KeyCode::F(9) {
+                    self.private = !self.private;
+                    return Ok(());
+                }
+                if self.private {
+                    return Ok(());
+                }
+                // F10 shows how long frames and screens take to draw, from
+                // anywhere, so slow screens can be caught mid-edit too
+                if code == KeyCode::F(10) {
+                    self.profiler.toggle();
+                    return Ok(());
+                }
+                // The quit and discard prompts are answered with y/n as
+                // typed, whatever screen they're open over
+                let prompting = self.quit_prompt.is_some() || self.discard_prompt;
+                if self.takes_commands() && !prompting {
+                    code = self.keymap.translate(code, modifiers);
+                }
+                self.handle_key_event_with_modifiers(code, modifiers)
+                    .await?;
+            }
+            Event::Mouse(mouse)
+                if !self.private
+                    && !self.lock.locked
+                    && self.quit_prompt.is_none()
+                    && !self.discard_prompt =>
+            {
+                self.handle_mouse_event(mouse)
+            }
+            _ => {}
+        }
+        self.record_jump();
         Ok(())
     }
 
@@ -140,6 +566,33 @@ impl App {
         key: KeyCode,
         modifiers: crossterm::event::KeyModifiers,
     ) -> Result<()> {
+        if self.quit_prompt.is_some() {
+            self.handle_quit_prompt_input(key);
+            return Ok(());
+        }
+        let key = if self.discard_prompt {
+            match key {
+                // Carries on as the Esc that opened the prompt
+                KeyCode::Char('y') | KeyCode::Enter => {
+                    self.discard_prompt = false;
+                    KeyCode::Esc
+                }
+                KeyCode::Char('n') | KeyCode::Esc => {
+                    self.discard_prompt = false;
+                    return Ok(());
+                }
+                _ => return Ok(()),
+            }
+        } else if key == KeyCode::Esc && self.guards_edit() && self.has_unsaved_edit() {
+            self.discard_prompt = true;
+            return Ok(());
+        } else {
+            key
+        };
+        if key == KeyCode::F(1) && self.takes_commands() {
+            self.toggle_manual();
+            return Ok(());
+        }
         match self.state.current_screen {
             AppScreen::AddFood => self.handle_add_food_input(key).await?,
             AppScreen::EditFood(food_index) => self.handle_edit_food_input(key, food_index).await?,
@@ -153,14 +606,54 @@ impl App {
             AppScreen::ConfirmDelete(target) => {
                 self.handle_delete_confirmation_input(key, target).await?;
             }
+            AppScreen::ConfirmValue(field_type) => {
+                self.handle_value_confirmation_input(key, field_type);
+            }
             AppScreen::DateInput => self.handle_date_input(key).await?,
+            AppScreen::JumpToDate => self.handle_jump_to_date_input(key),
+            AppScreen::Calendar => self.handle_calendar_input(key),
+            AppScreen::HomeFilter => self.handle_home_filter_input(key),
+            AppScreen::HomeViews => self.handle_home_views_input(key),
+            AppScreen::FoodLookup => self.handle_food_lookup_input(key).await?,
             AppScreen::ConfigSync => self.handle_config_sync_input(key).await?,
+            AppScreen::Heatmap => self.handle_heatmap_input(key),
+            AppScreen::Year => self.handle_year_input(key),
+            AppScreen::Charts => self.handle_charts_input(key),
+            AppScreen::Manual => self.handle_manual_input(key),
+            AppScreen::Settings => self.handle_settings_input(key),
+            AppScreen::SaveErrors => self.handle_save_errors_input(key),
+            AppScreen::Gear => self.handle_gear_input(key).await,
+            AppScreen::GearPicker => self.handle_gear_picker_input(key),
+            AppScreen::EditRoute => self.handle_edit_route_input(key),
+            AppScreen::Routes => self.handle_routes_input(key),
+            AppScreen::Strength => self.handle_strength_input(key),
+            AppScreen::BestEfforts => self.handle_best_efforts_input(key),
+            AppScreen::Goals => self.handle_goals_input(key).await,
+            AppScreen::Phases => self.handle_phases_input(key).await,
+            AppScreen::Injuries => self.handle_injuries_input(key).await,
+            AppScreen::Backfill => self.handle_backfill_input(key),
+            AppScreen::EditReflection(reflection) => {
+                self.handle_edit_reflection_input(key, reflection)
+            }
+            AppScreen::OcrImage => self.handle_ocr_image_input(key).await,
+            AppScreen::OcrReview => self.handle_ocr_review_input(key),
+            AppScreen::Syncing => {
+                // Esc gives up on a sync that's taking too long
+                if key == KeyCode::Esc {
+                    self.skip_shutdown_sync(None).await;
+                }
+            }
             _ => self.handle_navigation_input(key, modifiers).await?,
         }
         Ok(())
     }
 
     fn handle_mouse_event(&mut self, mouse: MouseEvent) {
+        if let Some(position) = hover_position(mouse) {
+            self.tooltip = self.hover_tooltip(position);
+            return;
+        }
+        self.tooltip = None;
         let Some((column, row)) = left_click_position(mouse) else {
             return;
         };
@@ -171,6 +664,7 @@ impl App {
                 | AppScreen::Home
                 | AppScreen::DailyView
                 | AppScreen::ConfigSync
+                | AppScreen::Calendar
         ) {
             return;
         }
@@ -180,14 +674,30 @@ impl App {
         }
     }
 
+    /// The day under the pointer on the logged days list or the calendar, if
+    /// it has a log to summarize.
+    fn hover_tooltip(&self, position: (u16, u16)) -> Option<((u16, u16), chrono::NaiveDate)> {
+        let date = match hit_test(&self.click_targets, position.0, position.1)? {
+            ClickAction::OpenLog(index) if self.state.current_screen == AppScreen::Home => {
+                self.state.home_log(index)?.date
+            }
+            ClickAction::CalendarDay(date)
+                if self.state.current_screen == AppScreen::Calendar
+                    && self.state.get_daily_log(date).is_some() =>
+            {
+                date
+            }
+            _ => return None,
+        };
+        Some((position, date))
+    }
+
     fn handle_click_action(&mut self, action: ClickAction) {
         match action {
             ClickAction::StartupToday
                 if matches!(self.state.current_screen, AppScreen::Startup) =>
             {
-                self.state.selected_date = chrono::Local::now().date_naive();
-                self.state.get_or_create_daily_log(self.state.selected_date);
-                self.state.current_screen = AppScreen::DailyView;
+                self.open_day(chrono::Local::now().date_naive());
             }
             ClickAction::StartupLogs if matches!(self.state.current_screen, AppScreen::Startup) => {
                 self.state.current_screen = AppScreen::Home;
@@ -204,6 +714,12 @@ impl App {
             {
                 self.state.current_screen = AppScreen::Statistics;
             }
+            ClickAction::OpenHeatmap if matches!(self.state.current_screen, AppScreen::Startup) => {
+                self.open_heatmap();
+            }
+            ClickAction::OpenCharts if matches!(self.state.current_screen, AppScreen::Startup) => {
+                self.open_charts();
+            }
             ClickAction::OpenCloudSync
                 if matches!(self.state.current_screen, AppScreen::Startup) =>
             {
@@ -215,13 +731,19 @@ impl App {
                     AppScreen::Startup | AppScreen::Statistics
                 ) =>
             {
-                self.state.current_screen = AppScreen::Syncing;
+                self.request_quit();
             }
             ClickAction::BackToStartup
                 if matches!(self.state.current_screen, AppScreen::Statistics) =>
             {
                 self.state.current_screen = AppScreen::Startup;
             }
+            ClickAction::CalendarDay(date)
+                if matches!(self.state.current_screen, AppScreen::Calendar)
+                    && date <= chrono::Local::now().date_naive() =>
+            {
+                self.open_day(date);
+            }
             ClickAction::OpenLog(index) if matches!(self.state.current_screen, AppScreen::Home) => {
                 self.list_state.select(Some(index));
                 ActionHandler::handle_home_enter(&mut self.state, Some(index));
@@ -232,6 +754,14 @@ impl App {
                         field,
                         crate::models::field_accessor::FieldType::Weight
                             | crate::models::field_accessor::FieldType::Waist
+                            | crate::models::field_accessor::FieldType::Sleep
+                            | crate::models::field_accessor::FieldType::RestingHr
+                            | crate::models::field_accessor::FieldType::Hrv
+                            | crate::models::field_accessor::FieldType::Duration
+                            | crate::models::field_accessor::FieldType::StartTime
+                            | crate::models::field_accessor::FieldType::Rpe
+                            | crate::models::field_accessor::FieldType::AvgHr
+                            | crate::models::field_accessor::FieldType::MaxHr
                             | crate::models::field_accessor::FieldType::Miles
                             | crate::models::field_accessor::FieldType::Elevation
                     ) =>
@@ -308,158 +838,327 @@ impl App {
         }
     }
 
-    async fn handle_add_food_input(&mut self, key: KeyCode) -> Result<()> {
-        match key {
-            KeyCode::Enter => {
-                if let Some(log) = ActionHandler::save_food_entry(
-                    &mut self.state,
-                    self.input_handler.input_buffer.clone(),
-                ) {
-                    self.input_handler.clear();
-                    self.state.current_screen = AppScreen::DailyView;
-
-                    // Persist in background for instant UI feedback
-                    let db_manager = Arc::clone(&self.db_manager);
-                    let file_manager = self.file_manager.clone();
-                    tokio::spawn(async move {
-                        ActionHandler::persist_daily_log(db_manager, &file_manager, log).await;
+    /// Saves `log` in the background for instant UI feedback, then starts
+    /// the post-save hook. Saves of a day go one at a time, and one that a
+    /// newer save of the day overtook is dropped. A locked database is
+    /// retried with backoff; a save that still fails is sent back for the
+    /// failed saves screen.
+    fn persist(&self, log: DailyLog) {
+        self.request_weather(&log);
+        let db_manager = Arc::clone(&self.db_manager);
+        let file_manager = self.file_manager.clone();
+        let hook = self.config.hooks.command(Hook::PostSave).map(|command| {
+            let payload = hooks::payload(Hook::PostSave, log.date, Some(&log));
+            (command.to_string(), payload)
+        });
+        let pending_saves = self.pending_saves.clone();
+        let failed_saves = self.failed_saves_tx.clone();
+        let backup_errors = self.backup_errors_tx.clone();
+        let save_queue = self.save_queue.clone();
+        let (save, day_lock) = save_queue.enqueue(log.date);
+        pending_saves.send_modify(|pending| *pending += 1);
+        tokio::spawn(async move {
+            let _writing = day_lock.lock().await;
+            let mut attempts = 0;
+            let failure = loop {
+                if save_queue.superseded(log.date, save) {
+                    pending_saves.send_modify(|pending| *pending -= 1);
+                    return;
+                }
+                attempts += 1;
+                let persisted =
+                    ActionHandler::persist_daily_log(Arc::clone(&db_manager), &file_manager, &log)
+                        .await;
+                let error = match persisted {
+                    Ok(backup_error) => {
+                        if let Some(error) = backup_error {
+                            let _ = backup_errors.send((log.date, format!("{:#}", error)));
+                        }
+                        break None;
+                    }
+                    Err(error) => error,
+                };
+                let kind = save_errors::classify(&error);
+                match RETRY_DELAYS.get(attempts - 1) {
+                    Some(&delay) if kind == FailureKind::Transient => tokio::time::sleep(delay).await,
+                    _ => break Some((error, kind)),
+                }
+            };
+            pending_saves.send_modify(|pending| *pending -= 1);
+            match failure {
+                Some((error, kind)) => {
+                    let _ = failed_saves.send(FailedSave {
+                        log,
+                        error: format!("{:#}", error),
+                        kind,
+                        attempts,
                     });
-                } else {
-                    self.input_handler.clear();
-                    self.state.current_screen = AppScreen::DailyView;
+                }
+                None => {
+                    if let Some((command, payload)) = hook {
+                        hooks::spawn(command, Hook::PostSave, payload);
+                    }
                 }
             }
-            KeyCode::Esc => {
-                self.input_handler.clear();
-                self.state.current_screen = AppScreen::DailyView;
+        });
+    }
+
+    /// Looks up the weather for a day with a run and none yet, once a
+    /// session, if `[weather]` has a location. A failed lookup is dropped.
+    fn request_weather(&self, log: &DailyLog) {
+        let today = chrono::Local::now().date_naive();
+        let has_run = log.miles_covered.is_some() || log.duration_secs.is_some();
+        if self.config.weather.location().is_none()
+            || log.weather.is_some()
+            || !has_run
+            || log.date > today
+        {
+            return;
+        }
+        let mut requested = self
+            .weather_requested
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if !requested.insert(log.date) {
+            return;
+        }
+        let config = self.config.weather.clone();
+        let date = log.date;
+        let weather_tx = self.weather_tx.clone();
+        tokio::spawn(async move {
+            let fetched =
+                tokio::task::spawn_blocking(move || weather::fetch(&config, date, today)).await;
+            if let Ok(Ok(found)) = fetched {
+                let _ = weather_tx.send((date, found));
             }
-            _ => {
-                self.input_handler.handle_text_input(key);
+        });
+    }
+
+    /// Saves weather that's come back onto its day, unless the day has
+    /// gone or got weather some other way meanwhile.
+    fn collect_weather(&mut self) {
+        while let Ok((date, found)) = self.weather_rx.try_recv() {
+            if let Some(log) = self.state.daily_log_mut(date)
+                && log.weather.is_none()
+            {
+                log.weather = Some(found);
+                let log = log.clone();
+                self.persist(log);
             }
         }
-        Ok(())
     }
 
-    async fn handle_edit_food_input(&mut self, key: KeyCode, food_index: usize) -> Result<()> {
-        match key {
-            KeyCode::Enter => {
-                if let Some(log) = ActionHandler::update_food_entry(
-                    &mut self.state,
-                    food_index,
-                    self.input_handler.input_buffer.clone(),
-                ) {
-                    self.input_handler.clear();
-                    self.state.current_screen = AppScreen::DailyView;
+    /// Moves saves that failed in the background onto the failed saves list,
+    /// keeping only the latest failure for each day. A backup that couldn't
+    /// be written is only mentioned, since the day was saved all the same.
+    fn collect_failed_saves(&mut self) {
+        let mut backup_error = None;
+        while let Ok(error) = self.backup_errors_rx.try_recv() {
+            backup_error = Some(error);
+        }
+        // Shown first, so a failed save's toast replaces it
+        if let Some((date, error)) = backup_error {
+            self.state.show_toast(format!(
+                "Saved {}, but its markdown backup wasn't written: {}",
+                date.format("%b %d"),
+                error
+            ));
+        }
+        let mut newest = None;
+        while let Ok(failed) = self.failed_saves_rx.try_recv() {
+            let date = failed.log.date;
+            self.state.failed_saves.retain(|f| f.log.date != date);
+            self.state.failed_saves.push(failed);
+            newest = Some(date);
+        }
+        if let Some(date) = newest {
+            self.state.show_toast(format!(
+                "Couldn't save {} - press ! to retry",
+                date.format("%b %d")
+            ));
+        }
+    }
 
-                    let db_manager = Arc::clone(&self.db_manager);
-                    let file_manager = self.file_manager.clone();
-                    tokio::spawn(async move {
-                        ActionHandler::persist_daily_log(db_manager, &file_manager, log).await;
-                    });
-                } else {
+    /// Whether keys are commands rather than text: the screen takes them,
+    /// and no name or draft is being typed on it.
+    fn takes_commands(&self) -> bool {
+        self.state.current_screen.takes_commands()
+            && !self.naming_gear
+            && self.goal_draft.is_none()
+            && self.phase_draft.is_none()
+            && self.injury_draft.is_none()
+    }
+
+    fn open_gear(&mut self) {
+        self.gear_selected = 0;
+        self.naming_gear = false;
+        self.gear_return = self.state.current_screen.clone();
+        self.state.current_screen = AppScreen::Gear;
+    }
+
+    /// Gear in the order the gear screen lists it: active first.
+    fn gear_rows(&self) -> Vec<crate::gear::GearMileage> {
+        crate::gear::mileage(&self.state.gear, self.state.daily_logs())
+    }
+
+    /// `a` names new gear, `x` retires the highlighted gear or brings it
+    /// back. Changes are written straight to the `gear` table.
+    async fn handle_gear_input(&mut self, key: KeyCode) {
+        if self.naming_gear {
+            match key {
+                KeyCode::Enter => {
+                    let name = self.input_handler.input_buffer.trim().to_string();
+                    if name.is_empty() {
+                        return;
+                    }
+                    if self.state.gear.iter().any(|g| g.name.eq_ignore_ascii_case(&name)) {
+                        self.state.show_toast(format!("\"{}\" is already in your gear", name));
+                        return;
+                    }
+                    let gear = Gear {
+                        name: name.clone(),
+                        retired: false,
+                    };
+                    self.write_gear(gear, format!("Added \"{}\"", name)).await;
+                    self.naming_gear = false;
                     self.input_handler.clear();
-                    self.state.current_screen = AppScreen::DailyView;
+                }
+                KeyCode::Esc => {
+                    self.naming_gear = false;
+                    self.input_handler.clear();
+                }
+                _ => {
+                    self.input_handler.handle_text_input(key);
                 }
             }
-            KeyCode::Esc => {
+            return;
+        }
+
+        let rows = self.gear_rows();
+        match key {
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.gear_selected = (self.gear_selected + 1).min(rows.len().saturating_sub(1));
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.gear_selected = self.gear_selected.saturating_sub(1);
+            }
+            KeyCode::Char('a') => {
+                self.naming_gear = true;
                 self.input_handler.clear();
-                self.state.current_screen = AppScreen::DailyView;
             }
-            _ => {
-                self.input_handler.handle_text_input(key);
+            KeyCode::Char('x') => {
+                if let Some(row) = rows.get(self.gear_selected) {
+                    let gear = Gear {
+                        name: row.name.clone(),
+                        retired: !row.retired,
+                    };
+                    let message = if gear.retired {
+                        format!("Retired \"{}\"", gear.name)
+                    } else {
+                        format!("\"{}\" is back in use", gear.name)
+                    };
+                    self.write_gear(gear, message).await;
+                }
             }
+            KeyCode::Esc => self.state.current_screen = self.gear_return.clone(),
+            _ => {}
         }
-        Ok(())
     }
 
-    /// Generic handler for all field inputs - consolidates 6 separate handlers
-    async fn handle_field_input(
-        &mut self,
-        key: KeyCode,
-        modifiers: crossterm::event::KeyModifiers,
-        field_type: crate::models::field_accessor::FieldType,
-    ) -> Result<()> {
-        use crate::models::field_accessor::FieldType;
-
-        match key {
-            KeyCode::Enter => {
-                let is_multiline =
-                    matches!(field_type, FieldType::StrengthMobility | FieldType::Notes);
-                // Use Alt modifier for newline insertion (most reliable across terminals)
-                let has_alt = modifiers.contains(crossterm::event::KeyModifiers::ALT);
+    /// Saves `gear` and mirrors it in `state.gear`, toasting `message` on
+    /// success.
+    async fn write_gear(&mut self, gear: Gear, message: String) {
+        let saved = self.db_manager.read().await.save_gear(&gear).await;
+        match saved {
+            Ok(()) => {
+                match self.state.gear.iter_mut().find(|g| g.name == gear.name) {
+                    Some(existing) => *existing = gear,
+                    None => self.state.gear.push(gear),
+                }
+                self.state.show_toast(message);
+            }
+            Err(e) => self.state.show_toast(format!("Couldn't save gear: {:#}", e)),
+        }
+    }
 
-                // Alt+Enter in multiline inputs inserts newline, regular Enter saves
-                if is_multiline && has_alt {
-                    // Insert newline and stay in edit mode
-                    self.input_handler.insert_newline();
-                } else {
-                    // Save and exit
-                    let entered = !self.input_handler.input_buffer.trim().is_empty();
-                    let log = ActionHandler::update_field(
-                        &mut self.state,
-                        field_type,
-                        self.input_handler.input_buffer.clone(),
-                    );
-                    self.input_handler.clear();
+    /// Gear that can be picked for a run: everything not retired.
+    fn active_gear(&self) -> Vec<String> {
+        self.state
+            .gear
+            .iter()
+            .filter(|g| !g.retired)
+            .map(|g| g.name.clone())
+            .collect()
+    }
 
-                    // After entering data, move focus to the next field so entry
-                    // flows top-to-bottom without manual Shift+J. An empty save
-                    // stays put. Focus-only — the next field isn't auto-opened.
-                    self.state.focused_section = if entered {
-                        SectionNavigator::advance_field(field_type)
-                    } else {
-                        SectionNavigator::field_section(field_type)
-                    };
-                    self.state.strength_mobility_scroll = 0;
-                    self.state.notes_scroll = 0;
-                    self.state.current_screen = AppScreen::DailyView;
+    fn open_gear_picker(&mut self) {
+        let active = self.active_gear();
+        if active.is_empty() {
+            self.state
+                .show_toast("No gear yet - add some with G on the startup screen".to_string());
+            return;
+        }
+        let current = self
+            .state
+            .get_daily_log(self.state.selected_date)
+            .and_then(|log| log.gear.clone());
+        self.gear_picker_selected = current
+            .and_then(|name| active.iter().position(|g| *g == name))
+            .unwrap_or(0);
+        self.state.current_screen = AppScreen::GearPicker;
+    }
 
-                    let db_manager = Arc::clone(&self.db_manager);
-                    let file_manager = self.file_manager.clone();
-                    tokio::spawn(async move {
-                        ActionHandler::persist_daily_log(db_manager, &file_manager, log).await;
-                    });
-                }
+    /// Enter puts the day's run on the highlighted gear, or on none.
+    fn handle_gear_picker_input(&mut self, key: KeyCode) {
+        let active = self.active_gear();
+        match key {
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.gear_picker_selected = (self.gear_picker_selected + 1).min(active.len());
             }
-            KeyCode::Esc => {
-                self.input_handler.clear();
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.gear_picker_selected = self.gear_picker_selected.saturating_sub(1);
+            }
+            KeyCode::Enter => {
+                let gear = active.get(self.gear_picker_selected).cloned();
+                let log = ActionHandler::set_gear(&mut self.state, gear);
+                self.persist(log);
                 self.state.current_screen = AppScreen::DailyView;
             }
-            _ => match field_type {
-                FieldType::Weight | FieldType::Waist | FieldType::Miles => {
-                    self.input_handler.handle_numeric_input(key);
-                }
-                FieldType::Elevation => {
-                    self.input_handler.handle_integer_input(key);
-                }
-                FieldType::StrengthMobility | FieldType::Notes => {
-                    self.input_handler
-                        .handle_multiline_text_input(key, modifiers);
-                }
-            },
+            KeyCode::Esc => self.state.current_screen = AppScreen::DailyView,
+            _ => {}
         }
-        Ok(())
     }
 
-    async fn handle_add_sokay_input(&mut self, key: KeyCode) -> Result<()> {
+    fn open_route_edit(&mut self) {
+        let route = self
+            .state
+            .get_daily_log(self.state.selected_date)
+            .and_then(|log| log.route.clone())
+            .unwrap_or_default();
+        self.input_handler.set_input(route);
+        self.state.current_screen = AppScreen::EditRoute;
+    }
+
+    /// Free text, with routes run before that start with what's typed
+    /// offered underneath like frequent foods.
+    fn handle_edit_route_input(&mut self, key: KeyCode) {
         match key {
+            KeyCode::Down => self.input_handler.select_next_suggestion(),
+            KeyCode::Up => self.input_handler.select_previous_suggestion(),
+            KeyCode::Tab => {
+                self.input_handler.accept_suggestion();
+            }
             KeyCode::Enter => {
-                if let Some(log) = ActionHandler::save_sokay_entry(
+                if self.input_handler.suggestion_selected.is_some() {
+                    self.input_handler.accept_suggestion();
+                }
+                let log = ActionHandler::set_route(
                     &mut self.state,
                     self.input_handler.input_buffer.clone(),
-                ) {
-                    self.input_handler.clear();
-                    self.state.current_screen = AppScreen::DailyView;
-
-                    let db_manager = Arc::clone(&self.db_manager);
-                    let file_manager = self.file_manager.clone();
-                    tokio::spawn(async move {
-                        ActionHandler::persist_daily_log(db_manager, &file_manager, log).await;
-                    });
-                } else {
-                    self.input_handler.clear();
-                    self.state.current_screen = AppScreen::DailyView;
-                }
+                );
+                self.input_handler.clear();
+                self.state.current_screen = AppScreen::DailyView;
+                self.persist(log);
             }
             KeyCode::Esc => {
                 self.input_handler.clear();
@@ -467,31 +1166,38 @@ impl App {
             }
             _ => {
                 self.input_handler.handle_text_input(key);
+                let suggestions = crate::routes::route_suggestions(
+                    self.state.daily_logs(),
+                    &self.input_handler.input_buffer,
+                    FOOD_SUGGESTIONS,
+                );
+                self.input_handler.set_suggestions(suggestions);
             }
         }
-        Ok(())
     }
 
-    async fn handle_edit_sokay_input(&mut self, key: KeyCode, sokay_index: usize) -> Result<()> {
+    fn open_reflection_edit(&mut self, reflection: Reflection) {
+        let text = self
+            .state
+            .get_daily_log(self.state.selected_date)
+            .and_then(|log| reflection.get(log))
+            .unwrap_or_default()
+            .to_string();
+        self.input_handler.set_input(text);
+        self.state.current_screen = AppScreen::EditReflection(reflection);
+    }
+
+    fn handle_edit_reflection_input(&mut self, key: KeyCode, reflection: Reflection) {
         match key {
             KeyCode::Enter => {
-                if let Some(log) = ActionHandler::update_sokay_entry(
+                let log = ActionHandler::set_reflection(
                     &mut self.state,
-                    sokay_index,
-                    self.input_handler.input_buffer.clone(),
-                ) {
-                    self.input_handler.clear();
-                    self.state.current_screen = AppScreen::DailyView;
-
-                    let db_manager = Arc::clone(&self.db_manager);
-                    let file_manager = self.file_manager.clone();
-                    tokio::spawn(async move {
-                        ActionHandler::persist_daily_log(db_manager, &file_manager, log).await;
-                    });
-                } else {
-                    self.input_handler.clear();
-                    self.state.current_screen = AppScreen::DailyView;
-                }
+                    reflection,
+                    &self.input_handler.input_buffer,
+                );
+                self.input_handler.clear();
+                self.state.current_screen = AppScreen::DailyView;
+                self.persist(log);
             }
             KeyCode::Esc => {
                 self.input_handler.clear();
@@ -501,191 +1207,1788 @@ impl App {
                 self.input_handler.handle_text_input(key);
             }
         }
-        Ok(())
     }
 
-    async fn handle_date_input(&mut self, key: KeyCode) -> Result<()> {
+    fn open_ocr_image(&mut self) {
+        if self.ocr_command().is_none() {
+            self.state
+                .show_toast("Set [food] ocr_command to read food off photos".to_string());
+            return;
+        }
+        self.input_handler.clear();
+        self.state.current_screen = AppScreen::OcrImage;
+    }
+
+    fn ocr_command(&self) -> Option<String> {
+        self.config
+            .food
+            .ocr_command
+            .as_deref()
+            .map(str::trim)
+            .filter(|c| !c.is_empty())
+            .map(str::to_string)
+    }
+
+    /// Enter starts the OCR command on the typed path; the review of what
+    /// it found opens when it's done. Esc stops a read in progress.
+    async fn handle_ocr_image_input(&mut self, key: KeyCode) {
+        if let Some((cancel, _)) = &self.ocr_reading {
+            if key == KeyCode::Esc {
+                cancel.store(true, Ordering::Relaxed);
+                self.ocr_reading = None;
+            }
+            return;
+        }
         match key {
             KeyCode::Enter => {
-                let input = self.input_handler.input_buffer.clone();
-                match chrono::NaiveDate::parse_from_str(&input, "%m.%d.%Y") {
-                    Ok(date) => {
-                        let today = chrono::Local::now().date_naive();
-                        if date > today {
-                            self.state.date_input_error =
-                                Some("Future dates not allowed".to_string());
-                        } else {
-                            self.input_handler.clear();
-                            self.state.date_input_error = None;
-                            self.state.selected_date = date;
-                            self.state.get_or_create_daily_log(date);
-                            self.state.current_screen = AppScreen::DailyView;
+                let Some(command) = self.ocr_command() else {
+                    return;
+                };
+                let typed = &self.input_handler.input_buffer;
+                let image =
+                    match crate::platform::expand_user_path(typed, dirs::home_dir().as_deref()) {
+                        Ok(image) => image,
+                        Err(e) => {
+                            self.state.show_toast(format!("{:#}", e));
+                            return;
                         }
-                    }
-                    Err(_) => {
-                        self.state.date_input_error = Some("Invalid date format".to_string());
-                    }
-                }
+                    };
+                let cancel = Arc::new(AtomicBool::new(false));
+                let (read_tx, read_rx) = tokio::sync::oneshot::channel();
+                let stop = Arc::clone(&cancel);
+                tokio::task::spawn_blocking(move || {
+                    let read = ocr::extract_text(&command, &image, ocr::OCR_TIMEOUT, &stop);
+                    let _ = read_tx.send(read);
+                });
+                self.ocr_reading = Some((cancel, read_rx));
             }
             KeyCode::Esc => {
                 self.input_handler.clear();
-                self.state.date_input_error = None;
-                self.state.current_screen = AppScreen::Home;
-            }
-            KeyCode::Char(c) => {
-                if c.is_ascii_digit() || c == '.' {
-                    self.state.date_input_error = None;
-                    self.input_handler.handle_text_input(key);
-                }
+                self.state.current_screen = AppScreen::DailyView;
             }
             _ => {
-                self.state.date_input_error = None;
                 self.input_handler.handle_text_input(key);
             }
         }
-        Ok(())
     }
 
-    async fn handle_navigation_input(
-        &mut self,
-        key: KeyCode,
-        modifiers: crossterm::event::KeyModifiers,
-    ) -> Result<()> {
-        // Shift+J/K switches section focus in DailyView
-        if modifiers.contains(crossterm::event::KeyModifiers::SHIFT) {
-            match key {
-                KeyCode::Char('J') => {
-                    if matches!(self.state.current_screen, AppScreen::DailyView) {
-                        // Reset scroll when leaving expanded sections
-                        self.state.strength_mobility_scroll = 0;
-                        self.state.notes_scroll = 0;
-                        self.state.focused_section =
-                            SectionNavigator::move_focus_down(&self.state.focused_section);
-                    }
-                    return Ok(());
+    /// Opens the review of what the OCR command read, once it's done.
+    fn collect_ocr(&mut self) {
+        let Some((_, read_rx)) = &mut self.ocr_reading else {
+            return;
+        };
+        let read = match read_rx.try_recv() {
+            Ok(read) => read,
+            Err(tokio::sync::oneshot::error::TryRecvError::Empty) => return,
+            Err(tokio::sync::oneshot::error::TryRecvError::Closed) => {
+                Err(anyhow::anyhow!("The OCR command stopped unexpectedly"))
+            }
+        };
+        self.ocr_reading = None;
+        if self.state.current_screen != AppScreen::OcrImage {
+            return;
+        }
+        match read {
+            Ok(text) => {
+                let foods = ocr::candidate_foods(&text);
+                if foods.is_empty() {
+                    self.state
+                        .show_toast("No food found in that photo".to_string());
+                    return;
                 }
-                KeyCode::Char('K') => {
-                    if matches!(self.state.current_screen, AppScreen::DailyView) {
-                        // Reset scroll when leaving expanded sections
-                        self.state.strength_mobility_scroll = 0;
-                        self.state.notes_scroll = 0;
-                        self.state.focused_section =
-                            SectionNavigator::move_focus_up(&self.state.focused_section);
-                    }
-                    return Ok(());
+                self.input_handler.clear();
+                self.ocr_candidates = foods.into_iter().map(|food| (food, false)).collect();
+                self.ocr_selected = 0;
+                self.state.current_screen = AppScreen::OcrReview;
+            }
+            Err(e) => self.state.show_toast(format!("{:#}", e)),
+        }
+    }
+
+    /// Space picks lines, `a` picks them all, and Enter logs the picked
+    /// ones as food.
+    fn handle_ocr_review_input(&mut self, key: KeyCode) {
+        let last = self.ocr_candidates.len().saturating_sub(1);
+        match key {
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.ocr_selected = (self.ocr_selected + 1).min(last);
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.ocr_selected = self.ocr_selected.saturating_sub(1);
+            }
+            KeyCode::Char(' ') => {
+                if let Some((_, picked)) = self.ocr_candidates.get_mut(self.ocr_selected) {
+                    *picked = !*picked;
                 }
-                _ => {}
             }
+            KeyCode::Char('a') => {
+                let all = self.ocr_candidates.iter().all(|(_, picked)| *picked);
+                for (_, picked) in &mut self.ocr_candidates {
+                    *picked = !all;
+                }
+            }
+            KeyCode::Enter => {
+                let mut saved = None;
+                let mut count = 0;
+                for (food, _) in self.ocr_candidates.iter().filter(|(_, picked)| *picked) {
+                    saved = ActionHandler::save_food_entry(
+                        &mut self.state,
+                        FoodEntry::new(food.clone()),
+                    )
+                    .or(saved);
+                    count += 1;
+                }
+                if let Some(log) = saved {
+                    self.persist(log);
+                    self.state.show_toast(format!(
+                        "Logged {} {} from the photo",
+                        count,
+                        if count == 1 { "food" } else { "foods" }
+                    ));
+                }
+                self.ocr_candidates.clear();
+                self.state.current_screen = AppScreen::DailyView;
+            }
+            KeyCode::Esc => {
+                self.ocr_candidates.clear();
+                self.state.current_screen = AppScreen::DailyView;
+            }
+            _ => {}
         }
+    }
+
+    fn open_routes(&mut self) {
+        self.routes_selected = 0;
+        self.routes_return = self.state.current_screen.clone();
+        self.state.current_screen = AppScreen::Routes;
+    }
 
+    /// Enter opens the highlighted route's latest run.
+    fn handle_routes_input(&mut self, key: KeyCode) {
+        let routes = crate::routes::route_stats(self.state.daily_logs());
+        let selected = self.routes_selected.min(routes.len().saturating_sub(1));
         match key {
-            KeyCode::Char('q') => {
-                self.state.current_screen = AppScreen::Syncing;
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.routes_selected = (selected + 1).min(routes.len().saturating_sub(1));
             }
-            KeyCode::Tab => {
-                if matches!(self.state.current_screen, AppScreen::DailyView) {
-                    self.state.focused_section =
-                        SectionNavigator::toggle_internal_focus(&self.state.focused_section);
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.routes_selected = selected.saturating_sub(1);
+            }
+            KeyCode::Enter => {
+                if let Some(route) = routes.get(selected) {
+                    self.open_day(route.last_run);
                 }
             }
+            KeyCode::Esc => self.state.current_screen = self.routes_return.clone(),
+            _ => {}
+        }
+    }
+
+    fn open_strength(&mut self) {
+        self.strength_selected = 0;
+        self.strength_return = self.state.current_screen.clone();
+        self.state.current_screen = AppScreen::Strength;
+    }
+
+    /// Enter opens the highlighted exercise's latest session.
+    fn handle_strength_input(&mut self, key: KeyCode) {
+        let exercises = crate::strength::exercise_progress(self.state.daily_logs());
+        let selected = self.strength_selected.min(exercises.len().saturating_sub(1));
+        match key {
             KeyCode::Char('j') | KeyCode::Down => {
-                if matches!(self.state.current_screen, AppScreen::DailyView) {
-                    match self.state.focused_section {
-                        FocusedSection::FoodItems => self.move_food_selection_down(),
-                        FocusedSection::Sokay => self.move_sokay_selection_down(),
-                        FocusedSection::StrengthMobility => {
-                            let max = self.strength_mobility_max_scroll();
-                            self.state.strength_mobility_scroll = self
-                                .state
-                                .strength_mobility_scroll
-                                .saturating_add(1)
-                                .min(max);
-                        }
-                        FocusedSection::Notes => {
-                            let max = self.notes_max_scroll();
-                            self.state.notes_scroll =
-                                self.state.notes_scroll.saturating_add(1).min(max);
-                        }
-                        _ => {}
-                    }
-                } else if matches!(self.state.current_screen, AppScreen::Home) {
-                    self.move_selection_down();
+                self.strength_selected = (selected + 1).min(exercises.len().saturating_sub(1));
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.strength_selected = selected.saturating_sub(1);
+            }
+            KeyCode::Enter => {
+                if let Some(session) = exercises.get(selected).and_then(|e| e.sessions.last()) {
+                    self.open_day(session.date);
                 }
             }
+            KeyCode::Esc => self.state.current_screen = self.strength_return.clone(),
+            _ => {}
+        }
+    }
+
+    fn open_best_efforts(&mut self) {
+        self.best_efforts_focus = BestEffort::Miles;
+        self.best_efforts_selected = 0;
+        self.best_efforts_return = self.state.current_screen.clone();
+        self.state.current_screen = AppScreen::BestEfforts;
+    }
+
+    /// Tab switches between the distance and vert lists; Enter opens the
+    /// highlighted day.
+    fn handle_best_efforts_input(&mut self, key: KeyCode) {
+        let days =
+            best_efforts::top_days(self.state.daily_logs(), self.best_efforts_focus, BEST_EFFORTS);
+        let last = days.len().saturating_sub(1);
+        let selected = self.best_efforts_selected.min(last);
+        match key {
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.best_efforts_selected = (selected + 1).min(last);
+            }
             KeyCode::Char('k') | KeyCode::Up => {
-                if matches!(self.state.current_screen, AppScreen::DailyView) {
-                    match self.state.focused_section {
-                        FocusedSection::FoodItems => self.move_food_selection_up(),
-                        FocusedSection::Sokay => self.move_sokay_selection_up(),
-                        FocusedSection::StrengthMobility => {
-                            self.state.strength_mobility_scroll =
-                                self.state.strength_mobility_scroll.saturating_sub(1);
-                        }
-                        FocusedSection::Notes => {
-                            self.state.notes_scroll = self.state.notes_scroll.saturating_sub(1);
-                        }
-                        _ => {}
+                self.best_efforts_selected = selected.saturating_sub(1);
+            }
+            KeyCode::Tab
+            | KeyCode::Char('h')
+            | KeyCode::Char('l')
+            | KeyCode::Left
+            | KeyCode::Right => {
+                self.best_efforts_focus = self.best_efforts_focus.toggle();
+            }
+            KeyCode::Enter => {
+                if let Some(&(date, _)) = days.get(selected) {
+                    self.open_day(date);
+                }
+            }
+            KeyCode::Esc => self.state.current_screen = self.best_efforts_return.clone(),
+            _ => {}
+        }
+    }
+
+    fn open_goals(&mut self) {
+        self.goals_selected = 0;
+        self.goal_draft = None;
+        self.goals_return = self.state.current_screen.clone();
+        self.state.current_screen = AppScreen::Goals;
+    }
+
+    /// `a` adds a goal, Tab picking its kind; `e` or Enter changes the
+    /// highlighted goal's target, `x` pauses or resumes it and `d` deletes
+    /// it. Changes are written straight to the `goals` table.
+    async fn handle_goals_input(&mut self, key: KeyCode) {
+        if let Some(draft) = self.goal_draft {
+            match key {
+                KeyCode::Enter => {
+                    if self.save_goal_draft(draft).await {
+                        self.goal_draft = None;
+                        self.input_handler.clear();
                     }
-                } else if matches!(self.state.current_screen, AppScreen::Home) {
-                    self.move_selection_up();
+                }
+                KeyCode::Tab if draft.editing.is_none() => {
+                    self.goal_draft = Some(GoalDraft {
+                        kind: draft.kind.next(),
+                        ..draft
+                    });
+                }
+                KeyCode::Esc => {
+                    self.goal_draft = None;
+                    self.input_handler.clear();
+                }
+                _ => {
+                    self.input_handler.handle_text_input(key);
                 }
             }
-            KeyCode::Enter => {
-                if matches!(self.state.current_screen, AppScreen::DailyView) {
-                    self.handle_section_enter().await?;
-                } else {
-                    self.handle_enter();
+            return;
+        }
+
+        let last = self.state.goal_list.len().saturating_sub(1);
+        let selected = self.goals_selected.min(last);
+        let goal = self.state.goal_list.get(selected).cloned();
+        match key {
+            KeyCode::Char('j') | KeyCode::Down => self.goals_selected = (selected + 1).min(last),
+            KeyCode::Char('k') | KeyCode::Up => self.goals_selected = selected.saturating_sub(1),
+            KeyCode::Char('a') => {
+                self.goal_draft = Some(GoalDraft {
+                    editing: None,
+                    kind: GoalKind::WeeklyMiles,
+                });
+                self.input_handler.clear();
+            }
+            KeyCode::Char('e') | KeyCode::Enter => {
+                if let Some(goal) = goal {
+                    let target = match goal.kind.quantity() {
+                        Some(quantity) => self.state.units.amount(quantity, goal.target as f32),
+                        None => (goal.target as u32).to_string(),
+                    };
+                    self.goal_draft = Some(GoalDraft {
+                        editing: Some(goal.id),
+                        kind: goal.kind,
+                    });
+                    self.input_handler.set_input(target);
                 }
             }
-            KeyCode::Esc => {
-                self.handle_escape();
+            KeyCode::Char('x') => {
+                if let Some(goal) = goal {
+                    let goal = crate::goals::Goal {
+                        active: !goal.active,
+                        ..goal
+                    };
+                    let message = if goal.active {
+                        format!("Tracking \"{}\" again", goal.label(self.state.units))
+                    } else {
+                        format!("Paused \"{}\"", goal.label(self.state.units))
+                    };
+                    self.write_goal(goal, message).await;
+                }
             }
             KeyCode::Char('d') => {
-                if matches!(self.state.current_screen, AppScreen::Home) {
-                    self.handle_delete_day_confirmation();
-                } else if matches!(self.state.current_screen, AppScreen::DailyView) {
-                    use crate::models::DeleteTarget;
-                    match self.state.focused_section {
-                        FocusedSection::FoodItems => {
-                            if self.state.food_list_focused
-                                && let Some(selected_index) = self.food_list_state.selected()
-                            {
-                                self.state.current_screen =
-                                    AppScreen::ConfirmDelete(DeleteTarget::Food(selected_index));
-                            }
-                        }
-                        FocusedSection::Sokay => {
-                            if self.state.sokay_list_focused
-                                && let Some(selected_index) = self.sokay_list_state.selected()
-                            {
-                                self.state.current_screen =
-                                    AppScreen::ConfirmDelete(DeleteTarget::Sokay(selected_index));
-                            }
+                if let Some(goal) = goal {
+                    let deleted = self.db_manager.read().await.delete_goal(goal.id).await;
+                    match deleted {
+                        Ok(()) => {
+                            self.state.goal_list.retain(|g| g.id != goal.id);
+                            self.state
+                                .show_toast(format!("Deleted \"{}\"", goal.label(self.state.units)));
                         }
-                        _ => {}
+                        Err(e) => self.state.show_toast(format!("Couldn't delete goal: {:#}", e)),
                     }
                 }
             }
-            KeyCode::Char('f') => {
-                if matches!(self.state.current_screen, AppScreen::DailyView) {
-                    self.state.current_screen = AppScreen::AddFood;
-                }
+            KeyCode::Esc => self.state.current_screen = self.goals_return.clone(),
+            _ => {}
+        }
+    }
+
+    /// Adds or updates the goal in `draft` with the typed target, in display
+    /// units. False, with a toast, if the target doesn't read as one.
+    async fn save_goal_draft(&mut self, draft: GoalDraft) -> bool {
+        let typed = self.input_handler.input_buffer.trim();
+        let target = match draft.kind.quantity() {
+            Some(quantity) => self.state.units.parse(quantity, typed).filter(|&t| t > 0.0),
+            None => typed.parse::<u32>().ok().map(f64::from),
+        };
+        let Some(target) = target else {
+            self.state.show_toast(format!("\"{}\" isn't a target for {}", typed, draft.kind.title()));
+            return false;
+        };
+        match draft.editing {
+            Some(id) => {
+                let Some(goal) = self.state.goal_list.iter().find(|g| g.id == id).cloned() else {
+                    return true;
+                };
+                let goal = crate::goals::Goal { target, ..goal };
+                let message = format!("Updated to \"{}\"", goal.label(self.state.units));
+                self.write_goal(goal, message).await;
             }
-            KeyCode::Char('e') => {
-                if matches!(self.state.current_screen, AppScreen::DailyView) {
-                    match self.state.focused_section {
-                        FocusedSection::FoodItems => self.handle_edit_food(),
-                        FocusedSection::Sokay => self.handle_edit_sokay(),
-                        _ => {}
+            None => {
+                let since = chrono::Local::now().date_naive();
+                let added = self.db_manager.read().await.add_goal(draft.kind, target, since).await;
+                match added {
+                    Ok(id) => {
+                        let goal = crate::goals::Goal {
+                            id,
+                            kind: draft.kind,
+                            target,
+                            since,
+                            active: true,
+                        };
+                        self.state
+                            .show_toast(format!("Added \"{}\"", goal.label(self.state.units)));
+                        self.state.goal_list.push(goal);
+                        self.goals_selected = self.state.goal_list.len() - 1;
                     }
+                    Err(e) => self.state.show_toast(format!("Couldn't save goal: {:#}", e)),
                 }
             }
-            KeyCode::Char('w') => {
-                if matches!(self.state.current_screen, AppScreen::DailyView) {
-                    self.handle_edit_weight();
+        }
+        true
+    }
+
+    /// Saves a changed goal and mirrors it in `state.goal_list`, toasting
+    /// `message` on success.
+    async fn write_goal(&mut self, goal: crate::goals::Goal, message: String) {
+        let saved = self.db_manager.read().await.update_goal(&goal).await;
+        match saved {
+            Ok(()) => {
+                if let Some(existing) = self.state.goal_list.iter_mut().find(|g| g.id == goal.id) {
+                    *existing = goal;
                 }
+                self.state.show_toast(message);
             }
-            KeyCode::Char('s') => {
+            Err(e) => self.state.show_toast(format!("Couldn't save goal: {:#}", e)),
+        }
+    }
+
+    fn open_phases(&mut self) {
+        // Start on the phase today falls in, if any
+        let today = chrono::Local::now().date_naive();
+        self.phases_selected = self
+            .state
+            .phases
+            .iter()
+            .position(|phase| phase.contains(today))
+            .unwrap_or(0);
+        self.phase_draft = None;
+        self.phases_return = self.state.current_screen.clone();
+        self.state.current_screen = AppScreen::Phases;
+    }
+
+    /// `a` marks a phase, Tab picking its kind before Enter saves the typed
+    /// range; `e` or Enter changes the highlighted one and `d` deletes it.
+    /// Changes are written straight to the `phases` table.
+    async fn handle_phases_input(&mut self, key: KeyCode) {
+        if let Some(draft) = self.phase_draft {
+            match key {
+                KeyCode::Enter => {
+                    if self.save_phase_draft(draft).await {
+                        self.phase_draft = None;
+                        self.input_handler.clear();
+                    }
+                }
+                KeyCode::Tab => {
+                    self.phase_draft = Some(PhaseDraft {
+                        kind: draft.kind.next(),
+                        ..draft
+                    });
+                }
+                KeyCode::Esc => {
+                    self.phase_draft = None;
+                    self.input_handler.clear();
+                }
+                _ => {
+                    self.input_handler.handle_text_input(key);
+                }
+            }
+            return;
+        }
+
+        let last = self.state.phases.len().saturating_sub(1);
+        let selected = self.phases_selected.min(last);
+        let phase = self.state.phases.get(selected).cloned();
+        match key {
+            KeyCode::Char('j') | KeyCode::Down => self.phases_selected = (selected + 1).min(last),
+            KeyCode::Char('k') | KeyCode::Up => self.phases_selected = selected.saturating_sub(1),
+            KeyCode::Char('a') => {
+                self.phase_draft = Some(PhaseDraft {
+                    editing: None,
+                    kind: PhaseKind::Base,
+                });
+                self.input_handler.clear();
+            }
+            KeyCode::Char('e') | KeyCode::Enter => {
+                if let Some(phase) = phase {
+                    self.phase_draft = Some(PhaseDraft {
+                        editing: Some(phase.id),
+                        kind: phase.kind,
+                    });
+                    self.input_handler.set_input(phase.range_text());
+                }
+            }
+            KeyCode::Char('d') => {
+                if let Some(phase) = phase {
+                    let deleted = self.db_manager.read().await.delete_phase(phase.id).await;
+                    match deleted {
+                        Ok(()) => {
+                            self.state.phases.retain(|p| p.id != phase.id);
+                            self.state
+                                .show_toast(format!("Deleted {}", phase.label(self.state.units)));
+                        }
+                        Err(e) => self.state.show_toast(format!("Couldn't delete phase: {:#}", e)),
+                    }
+                }
+            }
+            KeyCode::Esc => self.state.current_screen = self.phases_return.clone(),
+            _ => {}
+        }
+    }
+
+    /// Adds or updates the phase in `draft` over the typed range. False,
+    /// with a toast, if the range doesn't read or runs into another phase.
+    async fn save_phase_draft(&mut self, draft: PhaseDraft) -> bool {
+        let today = chrono::Local::now().date_naive();
+        let (start, end) = match phases::parse_range(&self.input_handler.input_buffer, today) {
+            Ok(range) => range,
+            Err(message) => {
+                self.state.show_toast(message);
+                return false;
+            }
+        };
+        if let Some(other) = phases::overlapping(&self.state.phases, start, end, draft.editing) {
+            let message = format!("Overlaps {}", other.label(self.state.units));
+            self.state.show_toast(message);
+            return false;
+        }
+        let db = self.db_manager.read().await;
+        let saved = match draft.editing {
+            Some(id) => db
+                .update_phase(&Phase {
+                    id,
+                    kind: draft.kind,
+                    start,
+                    end,
+                })
+                .await
+                .map(|()| id),
+            None => db.add_phase(draft.kind, start, end).await,
+        };
+        drop(db);
+        match saved {
+            Ok(id) => {
+                let phase = Phase {
+                    id,
+                    kind: draft.kind,
+                    start,
+                    end,
+                };
+                let message = format!("Saved {}", phase.label(self.state.units));
+                self.state.phases.retain(|p| p.id != id);
+                self.state.phases.push(phase);
+                self.state.phases.sort_by_key(|p| p.start);
+                self.phases_selected = self.state.phases.iter().position(|p| p.id == id).unwrap_or(0);
+                self.state.show_toast(message);
+            }
+            Err(e) => self.state.show_toast(format!("Couldn't save phase: {:#}", e)),
+        }
+        true
+    }
+
+    fn open_injuries(&mut self) {
+        self.injuries_selected = 0;
+        self.injury_draft = None;
+        self.injuries_return = self.state.current_screen.clone();
+        self.state.current_screen = AppScreen::Injuries;
+    }
+
+    /// `a` logs an injury, Tab picking how bad it is before Enter saves what
+    /// was typed; `e` or Enter changes the highlighted one, `r` marks it
+    /// cleared up today (or hurting again) and `d` deletes it. Changes are
+    /// written straight to the `injuries` table.
+    async fn handle_injuries_input(&mut self, key: KeyCode) {
+        if let Some(draft) = self.injury_draft {
+            match key {
+                KeyCode::Enter => {
+                    if self.save_injury_draft(draft).await {
+                        self.injury_draft = None;
+                        self.input_handler.clear();
+                    }
+                }
+                KeyCode::Tab => {
+                    self.injury_draft = Some(InjuryDraft {
+                        severity: draft.severity.next(),
+                        ..draft
+                    });
+                }
+                KeyCode::Esc => {
+                    self.injury_draft = None;
+                    self.input_handler.clear();
+                }
+                _ => {
+                    self.input_handler.handle_text_input(key);
+                }
+            }
+            return;
+        }
+
+        let last = self.state.injuries.len().saturating_sub(1);
+        let selected = self.injuries_selected.min(last);
+        let injury = self.state.injuries.get(selected).cloned();
+        match key {
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.injuries_selected = (selected + 1).min(last)
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.injuries_selected = selected.saturating_sub(1)
+            }
+            KeyCode::Char('a') => {
+                self.injury_draft = Some(InjuryDraft {
+                    editing: None,
+                    severity: Severity::Mild,
+                });
+                self.input_handler.clear();
+            }
+            KeyCode::Char('e') | KeyCode::Enter => {
+                if let Some(injury) = injury {
+                    self.injury_draft = Some(InjuryDraft {
+                        editing: Some(injury.id),
+                        severity: injury.severity,
+                    });
+                    self.input_handler.set_input(injury.entry_text());
+                }
+            }
+            KeyCode::Char('r') => {
+                if let Some(mut injury) = injury {
+                    let today = chrono::Local::now().date_naive();
+                    injury.resolved = match injury.resolved {
+                        Some(_) => None,
+                        None => Some(today.max(injury.start)),
+                    };
+                    let updated = self.db_manager.read().await.update_injury(&injury).await;
+                    match updated {
+                        Ok(()) => {
+                            let message = match injury.resolved {
+                                Some(_) => format!("{} cleared up", injury.body_part),
+                                None => format!("{} hurting again", injury.body_part),
+                            };
+                            if let Some(stored) =
+                                self.state.injuries.iter_mut().find(|i| i.id == injury.id)
+                            {
+                                *stored = injury;
+                            }
+                            self.state.show_toast(message);
+                        }
+                        Err(e) => {
+                            self.state.show_toast(format!("Couldn't update injury: {:#}", e))
+                        }
+                    }
+                }
+            }
+            KeyCode::Char('d') => {
+                if let Some(injury) = injury {
+                    let deleted = self.db_manager.read().await.delete_injury(injury.id).await;
+                    match deleted {
+                        Ok(()) => {
+                            self.state.injuries.retain(|i| i.id != injury.id);
+                            self.state
+                                .show_toast(format!("Deleted {}", injury.label(self.state.units)));
+                        }
+                        Err(e) => {
+                            self.state.show_toast(format!("Couldn't delete injury: {:#}", e))
+                        }
+                    }
+                }
+            }
+            KeyCode::Esc => self.state.current_screen = self.injuries_return.clone(),
+            _ => {}
+        }
+    }
+
+    /// Adds or updates the injury in `draft` from what was typed. False,
+    /// with a toast, if it doesn't read.
+    async fn save_injury_draft(&mut self, draft: InjuryDraft) -> bool {
+        let today = chrono::Local::now().date_naive();
+        let (body_part, start, resolved) =
+            match injuries::parse_entry(&self.input_handler.input_buffer, today) {
+                Ok(entry) => entry,
+                Err(message) => {
+                    self.state.show_toast(message);
+                    return false;
+                }
+            };
+        let db = self.db_manager.read().await;
+        let saved = match draft.editing {
+            Some(id) => db
+                .update_injury(&Injury {
+                    id,
+                    body_part: body_part.clone(),
+                    severity: draft.severity,
+                    start,
+                    resolved,
+                })
+                .await
+                .map(|()| id),
+            None => db.add_injury(&body_part, draft.severity, start, resolved).await,
+        };
+        drop(db);
+        match saved {
+            Ok(id) => {
+                let injury = Injury {
+                    id,
+                    body_part,
+                    severity: draft.severity,
+                    start,
+                    resolved,
+                };
+                let message = format!("Saved {}", injury.label(self.state.units));
+                let injuries = &mut self.state.injuries;
+                injuries.retain(|i| i.id != id);
+                injuries.push(injury);
+                injuries.sort_by(|a, b| b.start.cmp(&a.start).then(b.id.cmp(&a.id)));
+                self.injuries_selected = injuries.iter().position(|i| i.id == id).unwrap_or(0);
+                self.state.show_toast(message);
+            }
+            Err(e) => self.state.show_toast(format!("Couldn't save injury: {:#}", e)),
+        }
+        true
+    }
+
+    /// Shows values in both unit systems, or back to the configured one,
+    /// for this session; `[display] dual_units` sets where it starts.
+    fn toggle_dual_units(&mut self) {
+        let units = &mut self.state.units;
+        units.dual = !units.dual;
+        let message = if units.dual {
+            format!(
+                "Showing {} and {}",
+                units.name(Quantity::Distance),
+                units.system.other().name(Quantity::Distance)
+            )
+        } else {
+            format!("Showing {} only", units.name(Quantity::Distance))
+        };
+        self.state.show_toast(message);
+    }
+
+    fn open_backfill(&mut self) {
+        self.backfill = Backfill::default();
+        self.backfill_return = self.state.current_screen.clone();
+        self.input_handler.clear();
+        self.state.current_screen = AppScreen::Backfill;
+    }
+
+    /// The backfill wizard: Enter on the range starts on its first day, and
+    /// Enter on a day saves it and moves to the next. A day left blank that
+    /// wasn't logged is skipped rather than saved empty.
+    fn handle_backfill_input(&mut self, key: KeyCode) {
+        match key {
+            KeyCode::Tab => self.focus_backfill_field(self.backfill.field.next()),
+            KeyCode::BackTab => self.focus_backfill_field(self.backfill.field.previous()),
+            KeyCode::Enter => {
+                self.focus_backfill_field(self.backfill.field);
+                match self.backfill.day {
+                    None => {
+                        let today = chrono::Local::now().date_naive();
+                        match self.backfill.choose_range(today) {
+                            Ok(day) => self.start_backfill_day(day),
+                            Err(error) => self.backfill.error = Some(error),
+                        }
+                    }
+                    Some(day) => {
+                        if let Err(error) = self.save_backfill_day(day) {
+                            self.backfill.error = Some(error);
+                            return;
+                        }
+                        match self.backfill.advance() {
+                            Some(next) => self.start_backfill_day(next),
+                            None => self.close_backfill(),
+                        }
+                    }
+                }
+            }
+            KeyCode::Esc => self.close_backfill(),
+            _ => {
+                self.backfill.error = None;
+                self.input_handler.handle_text_input(key);
+            }
+        }
+    }
+
+    fn focus_backfill_field(&mut self, field: BackfillField) {
+        let current = self.backfill.field;
+        self.backfill
+            .set_value(current, self.input_handler.input_buffer.clone());
+        self.backfill.field = field;
+        self.input_handler
+            .set_input(self.backfill.value(field).to_string());
+    }
+
+    fn start_backfill_day(&mut self, day: chrono::NaiveDate) {
+        self.backfill.error = None;
+        self.backfill.load(self.state.get_daily_log(day), self.state.units);
+        self.backfill.field = BackfillField::Miles;
+        self.input_handler.set_input(self.backfill.miles.clone());
+    }
+
+    /// Saves the typed values on `day` if they change anything.
+    fn save_backfill_day(&mut self, day: chrono::NaiveDate) -> Result<(), String> {
+        let existing = self.state.get_daily_log(day);
+        if let Some(log) = self.backfill.changes(day, existing, self.state.units)? {
+            self.state.insert_daily_log(log.clone());
+            self.persist(log);
+            self.backfill.saved += 1;
+        }
+        Ok(())
+    }
+
+    fn close_backfill(&mut self) {
+        if self.backfill.saved > 0 {
+            let saved = self.backfill.saved;
+            self.state.show_toast(format!(
+                "Backfilled {} {}",
+                saved,
+                if saved == 1 { "day" } else { "days" }
+            ));
+        }
+        self.backfill = Backfill::default();
+        self.input_handler.clear();
+        self.state.current_screen = self.backfill_return.clone();
+    }
+
+    fn open_save_errors(&mut self) {
+        self.save_errors_selected = 0;
+        self.save_errors_return = self.state.current_screen.clone();
+        self.state.current_screen = AppScreen::SaveErrors;
+    }
+
+    /// The day to retry or export for a failed save: what's in memory now,
+    /// which may have been edited since, or the copy that failed.
+    fn failed_save_log(&self, index: usize) -> Option<DailyLog> {
+        let failed = self.state.failed_saves.get(index)?;
+        Some(
+            self.state
+                .get_daily_log(failed.log.date)
+                .unwrap_or(&failed.log)
+                .clone(),
+        )
+    }
+
+    fn handle_save_errors_input(&mut self, key: KeyCode) {
+        let count = self.state.failed_saves.len();
+        let selected = self.save_errors_selected.min(count.saturating_sub(1));
+        match key {
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.save_errors_selected = (selected + 1).min(count.saturating_sub(1));
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.save_errors_selected = selected.saturating_sub(1);
+            }
+            KeyCode::Char('r') => {
+                if let Some(log) = self.failed_save_log(selected) {
+                    self.state.failed_saves.remove(selected);
+                    self.state
+                        .show_toast(format!("Retrying {}", log.date.format("%b %d")));
+                    self.persist(log);
+                }
+            }
+            KeyCode::Char('e') => {
+                if let Some(log) = self.failed_save_log(selected) {
+                    let message =
+                        match save_errors::export_json(&self.file_manager.exports_dir(), &log) {
+                            Ok(path) => format!("Exported to {}", path.display()),
+                            Err(e) => format!("Couldn't export: {:#}", e),
+                        };
+                    self.state.show_toast(message);
+                }
+            }
+            KeyCode::Esc => {
+                self.state.current_screen = self.save_errors_return.clone();
+            }
+            _ => {}
+        }
+    }
+
+    /// Heads for the syncing screen, or asks first when quitting now would
+    /// throw away a half-typed edit or cut a save short.
+    fn request_quit(&mut self) {
+        let mut concerns = Vec::new();
+        if self.has_unsaved_edit() {
+            concerns.push("What you've typed hasn't been saved yet.".to_string());
+        }
+        let failed = self.state.failed_saves.len();
+        if failed > 0 {
+            concerns.push(format!(
+                "{} couldn't be saved and will be lost (n, then ! to retry or export).",
+                if failed == 1 {
+                    "1 day".to_string()
+                } else {
+                    format!("{} days", failed)
+                }
+            ));
+        }
+        let saving = *self.pending_saves.borrow();
+        if saving > 0 {
+            concerns.push(format!(
+                "{} still being saved.",
+                if saving == 1 {
+                    "1 change is".to_string()
+                } else {
+                    format!("{} changes are", saving)
+                }
+            ));
+        }
+        if concerns.is_empty() {
+            self.state.current_screen = AppScreen::Syncing;
+        } else {
+            self.quit_prompt = Some(concerns);
+        }
+    }
+
+    /// y quits, dropping any unsaved edit; the shutdown sync still waits for
+    /// saves in flight. n goes back to where 'q' was pressed.
+    fn handle_quit_prompt_input(&mut self, key: KeyCode) {
+        match key {
+            KeyCode::Char('y') | KeyCode::Enter => self.quit_anyway(),
+            KeyCode::Char('n') | KeyCode::Esc => self.quit_prompt = None,
+            _ => {}
+        }
+    }
+
+    /// Drops any unsaved edit and heads for the syncing screen, whose sync
+    /// waits for saves in flight first.
+    fn quit_anyway(&mut self) {
+        self.quit_prompt = None;
+        self.input_handler.clear();
+        self.food_form = FoodForm::default();
+        self.state.current_screen = AppScreen::Syncing;
+    }
+
+    /// Screens editing something already saved, where Esc asks before
+    /// throwing a change away.
+    fn guards_edit(&self) -> bool {
+        use crate::models::field_accessor::FieldType;
+        matches!(
+            self.state.current_screen,
+            AppScreen::EditFood(_)
+                | AppScreen::EditSokay(_)
+                | AppScreen::InputField(FieldType::Notes | FieldType::StrengthMobility)
+        )
+    }
+
+    /// Whether the open edit modal holds something typed that differs from
+    /// what's saved.
+    fn has_unsaved_edit(&self) -> bool {
+        let typed = self.input_handler.input_buffer.trim();
+        let food_form = || {
+            let mut form = self.food_form.clone();
+            form.set_value(form.field, self.input_handler.input_buffer.clone());
+            form
+        };
+        match self.state.current_screen {
+            AppScreen::InputField(field) | AppScreen::ConfirmValue(field) => {
+                typed != field.get_value(&self.state).trim()
+            }
+            AppScreen::AddSokay => !typed.is_empty(),
+            AppScreen::EditRoute => {
+                let saved = self
+                    .state
+                    .get_daily_log(self.state.selected_date)
+                    .and_then(|log| log.route.clone())
+                    .unwrap_or_default();
+                typed != saved.trim()
+            }
+            AppScreen::EditReflection(reflection) => {
+                let saved = self
+                    .state
+                    .get_daily_log(self.state.selected_date)
+                    .and_then(|log| reflection.get(log))
+                    .unwrap_or_default();
+                typed != saved.trim()
+            }
+            AppScreen::OcrImage => !typed.is_empty() && self.ocr_reading.is_none(),
+            AppScreen::Goals => self.goal_draft.is_some() && !typed.is_empty(),
+            AppScreen::Phases => match self.phase_draft {
+                Some(PhaseDraft { editing: Some(id), .. }) => self
+                    .state
+                    .phases
+                    .iter()
+                    .find(|phase| phase.id == id)
+                    .is_some_and(|phase| phase.range_text() != typed),
+                Some(_) => !typed.is_empty(),
+                None => false,
+            },
+            AppScreen::Backfill => self.backfill.day.is_some_and(|day| {
+                let mut backfill = self.backfill.clone();
+                backfill.set_value(backfill.field, self.input_handler.input_buffer.clone());
+                let existing = self.state.get_daily_log(day);
+                !matches!(backfill.changes(day, existing, self.state.units), Ok(None))
+            }),
+            AppScreen::OcrReview => self.ocr_candidates.iter().any(|(_, picked)| *picked),
+            AppScreen::EditSokay(index) => ActionHandler::start_edit_sokay(&self.state, index)
+                .is_some_and(|saved| saved.trim() != typed),
+            AppScreen::AddFood => {
+                let form = food_form();
+                [FoodField::Name, FoodField::Quantity, FoodField::Calories]
+                    .into_iter()
+                    .any(|field| !form.value(field).trim().is_empty())
+            }
+            AppScreen::EditFood(index) => {
+                food_form().entry() != ActionHandler::start_edit_food(&self.state, index)
+            }
+            _ => false,
+        }
+    }
+
+    /// Starts the script configured for `hook`, if any, with the selected
+    /// day, returning its thread.
+    fn run_hook(&self, hook: Hook) -> Option<std::thread::JoinHandle<()>> {
+        let command = self.config.hooks.command(hook)?;
+        let date = self.state.selected_date;
+        let payload = hooks::payload(hook, date, self.state.get_daily_log(date));
+        Some(hooks::spawn(command.to_string(), hook, payload))
+    }
+
+    async fn handle_add_food_input(&mut self, key: KeyCode) -> Result<()> {
+        if key == KeyCode::Enter
+            && self.food_form.field == FoodField::Name
+            && self.input_handler.suggestion_selected.is_none()
+            && barcode::is_barcode(&self.input_handler.input_buffer)
+        {
+            self.look_up_barcode();
+            return Ok(());
+        }
+        if let Some(entry) = self.handle_food_form_key(key)
+            && let Some(log) = ActionHandler::save_food_entry(&mut self.state, entry)
+        {
+            self.persist(log);
+        }
+        Ok(())
+    }
+
+    /// Looks up the barcode typed as the name on its own task; the form
+    /// says so until `collect_barcode` fills it in.
+    fn look_up_barcode(&mut self) {
+        let code = self.input_handler.input_buffer.trim().to_string();
+        let cache = self.file_manager.barcode_cache_path();
+        let barcode_tx = self.barcode_tx.clone();
+        self.barcode_lookup = Some(code.clone());
+        tokio::spawn(async move {
+            let lookup = code.clone();
+            let found = tokio::task::spawn_blocking(move || barcode::lookup(&cache, &lookup))
+                .await
+                .context("The barcode lookup stopped unexpectedly")
+                .and_then(|found| found);
+            let _ = barcode_tx.send((code, found));
+        });
+    }
+
+    /// Fills the Add Food form from a barcode that's come back, leaving it
+    /// open to check before saving. A lookup the form has moved on from,
+    /// closed or with another name typed, is dropped.
+    fn collect_barcode(&mut self) {
+        while let Ok((code, found)) = self.barcode_rx.try_recv() {
+            if self.barcode_lookup.as_ref() != Some(&code) {
+                continue;
+            }
+            self.barcode_lookup = None;
+            let still_wanted = self.state.current_screen == AppScreen::AddFood
+                && self.food_form.field == FoodField::Name
+                && self.input_handler.input_buffer.trim() == code;
+            if !still_wanted {
+                continue;
+            }
+            match found {
+                Ok(product) => {
+                    self.food_form = FoodForm::from_entry(&product.into_entry());
+                    self.input_handler.set_input(self.food_form.name.clone());
+                    self.input_handler.set_suggestions(Vec::new());
+                }
+                Err(e) => self.state.show_toast(format!("{:#}", e)),
+            }
+        }
+    }
+
+    async fn handle_edit_food_input(&mut self, key: KeyCode, food_index: usize) -> Result<()> {
+        if let Some(entry) = self.handle_food_form_key(key)
+            && let Some(log) = ActionHandler::update_food_entry(&mut self.state, food_index, entry)
+        {
+            self.persist(log);
+        }
+        Ok(())
+    }
+
+    /// Keys shared by the Add and Edit Food forms. Enter and Esc close the
+    /// form; Enter also hands back the entry to save, if it has a name.
+    fn handle_food_form_key(&mut self, key: KeyCode) -> Option<FoodEntry> {
+        let on_name = self.food_form.field == FoodField::Name;
+        match key {
+            KeyCode::Down if on_name => self.input_handler.select_next_suggestion(),
+            KeyCode::Up if on_name => self.input_handler.select_previous_suggestion(),
+            KeyCode::Tab => {
+                if !(on_name && self.input_handler.accept_suggestion()) {
+                    self.focus_food_field(self.food_form.field.next());
+                }
+            }
+            KeyCode::BackTab => self.focus_food_field(self.food_form.field.previous()),
+            KeyCode::Enter => {
+                if on_name && self.input_handler.suggestion_selected.is_some() {
+                    self.input_handler.accept_suggestion();
+                }
+                self.focus_food_field(self.food_form.field);
+                let entry = self.food_form.entry();
+                self.close_food_form();
+                return entry;
+            }
+            KeyCode::Esc => self.close_food_form(),
+            KeyCode::Char(c) if self.food_form.field == FoodField::Calories && !c.is_ascii_digit() => {}
+            _ => {
+                self.input_handler.handle_text_input(key);
+                if on_name {
+                    let suggestions = self.state.food_index.suggestions(
+                        self.state.daily_logs(),
+                        &self.input_handler.input_buffer,
+                        FOOD_SUGGESTIONS,
+                    );
+                    self.input_handler.set_suggestions(suggestions);
+                }
+            }
+        }
+        None
+    }
+
+    /// Stores the focused food field's text and moves the input buffer to
+    /// `field`.
+    fn focus_food_field(&mut self, field: FoodField) {
+        let current = self.food_form.field;
+        self.food_form
+            .set_value(current, self.input_handler.input_buffer.clone());
+        self.food_form.field = field;
+        self.input_handler
+            .set_input(self.food_form.value(field).to_string());
+        self.input_handler.set_suggestions(Vec::new());
+    }
+
+    fn close_food_form(&mut self) {
+        self.input_handler.clear();
+        self.food_form = FoodForm::default();
+        self.state.current_screen = AppScreen::DailyView;
+    }
+
+    /// Generic handler for all field inputs - consolidates 6 separate handlers
+    async fn handle_field_input(
+        &mut self,
+        key: KeyCode,
+        modifiers: crossterm::event::KeyModifiers,
+        field_type: crate::models::field_accessor::FieldType,
+    ) -> Result<()> {
+        use crate::models::field_accessor::FieldType;
+
+        let completing = field_type == FieldType::StrengthMobility;
+        match key {
+            // Exercise names complete from history while they're typed
+            KeyCode::Tab if completing => {
+                self.input_handler.complete_line();
+            }
+            KeyCode::Up | KeyCode::Down
+                if completing && !self.input_handler.suggestions.is_empty() =>
+            {
+                if key == KeyCode::Down {
+                    self.input_handler.select_next_suggestion();
+                } else {
+                    self.input_handler.select_previous_suggestion();
+                }
+            }
+            KeyCode::Enter if completing && self.input_handler.suggestion_selected.is_some() => {
+                self.input_handler.complete_line();
+            }
+            KeyCode::Enter => {
+                let is_multiline =
+                    matches!(field_type, FieldType::StrengthMobility | FieldType::Notes);
+                // Use Alt modifier for newline insertion (most reliable across terminals)
+                let has_alt = modifiers.contains(crossterm::event::KeyModifiers::ALT);
+
+                // Alt+Enter in multiline inputs inserts newline, regular Enter saves
+                if is_multiline && has_alt {
+                    // Insert newline and stay in edit mode
+                    self.input_handler.insert_newline();
+                    self.input_handler.set_suggestions(Vec::new());
+                } else if let Some(warning) = self.implausible_value_warning(field_type) {
+                    // Keep the buffer so 'n' drops back into the edit untouched
+                    self.state.value_warning = Some(warning);
+                    self.state.current_screen = AppScreen::ConfirmValue(field_type);
+                } else if self.sanity_override != Some(field_type)
+                    && let Some(warning) = self.running_sanity_warning(field_type)
+                {
+                    self.state
+                        .show_toast(format!("{} Press Enter again to save anyway.", warning));
+                    self.sanity_override = Some(field_type);
+                } else {
+                    self.save_field_input(field_type);
+                }
+            }
+            KeyCode::Esc => {
+                self.sanity_override = None;
+                self.history_index = None;
+                self.input_handler.clear();
+                self.state.current_screen = AppScreen::DailyView;
+            }
+            // Numbers don't take letters, so 'q' can quit from them as it
+            // does everywhere else
+            KeyCode::Char('q')
+                if !matches!(field_type, FieldType::StrengthMobility | FieldType::Notes) =>
+            {
+                self.request_quit();
+            }
+            KeyCode::Up | KeyCode::Down
+                if modifiers.contains(crossterm::event::KeyModifiers::ALT)
+                    && field_type.shows_history() =>
+            {
+                let history =
+                    field_type.history(self.state.daily_logs(), self.state.selected_date);
+                self.history_index = if key == KeyCode::Down {
+                    NavigationHandler::move_selection_down(self.history_index, history.len())
+                } else {
+                    NavigationHandler::move_selection_up(self.history_index, history.len())
+                };
+                if let Some((_, value)) = self.history_index.and_then(|i| history.get(i)) {
+                    self.sanity_override = None;
+                    let quantity = field_type.quantity().unwrap_or(Quantity::Weight);
+                    self.input_handler
+                        .set_input(self.state.units.amount(quantity, *value));
+                }
+            }
+            KeyCode::Up | KeyCode::Down if field_type.scrub_step(false).is_some() => {
+                let large = modifiers.contains(crossterm::event::KeyModifiers::SHIFT);
+                if let Some((step, decimals)) = field_type.scrub_step(large) {
+                    let step = if key == KeyCode::Up { step } else { -step };
+                    self.sanity_override = None;
+                    self.history_index = None;
+                    self.input_handler.scrub_number(step, decimals);
+                }
+            }
+            _ => {
+                // Any edit re-arms the sanity check for the new value
+                self.sanity_override = None;
+                self.history_index = None;
+                match field_type {
+                    FieldType::Weight | FieldType::Waist | FieldType::Miles | FieldType::Sleep => {
+                        self.input_handler.handle_numeric_input(key);
+                    }
+                    FieldType::Elevation
+                    | FieldType::RestingHr
+                    | FieldType::Hrv
+                    | FieldType::Rpe
+                    | FieldType::AvgHr
+                    | FieldType::MaxHr => {
+                        self.input_handler.handle_integer_input(key);
+                    }
+                    FieldType::Duration | FieldType::StartTime => {
+                        self.input_handler.handle_duration_input(key);
+                    }
+                    FieldType::StrengthMobility | FieldType::Notes => {
+                        self.input_handler
+                            .handle_multiline_text_input(key, modifiers);
+                        if completing {
+                            self.suggest_exercises();
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Offers exercise names done before for the strength line being typed,
+    /// until its sets and reps start.
+    fn suggest_exercises(&mut self) {
+        let typed = self.input_handler.line_before_cursor();
+        let suggestions = if typed.contains(|c: char| c.is_ascii_digit()) {
+            Vec::new()
+        } else {
+            crate::strength::exercise_suggestions(self.state.daily_logs(), typed, FOOD_SUGGESTIONS)
+        };
+        self.input_handler.set_suggestions(suggestions);
+    }
+
+    /// Saves the input buffer into `field_type` and returns to the daily view.
+    fn save_field_input(&mut self, field_type: crate::models::field_accessor::FieldType) {
+        self.sanity_override = None;
+        self.history_index = None;
+        let entered = !self.input_handler.input_buffer.trim().is_empty();
+        let log = ActionHandler::update_field(
+            &mut self.state,
+            field_type,
+            self.input_handler.input_buffer.clone(),
+        );
+        self.input_handler.clear();
+
+        // After entering data, move focus to the next field so entry
+        // flows top-to-bottom without manual Shift+J. An empty save
+        // stays put. Focus-only — the next field isn't auto-opened.
+        self.state.focused_section = if entered {
+            SectionNavigator::advance_field(field_type)
+        } else {
+            SectionNavigator::field_section(field_type)
+        };
+        self.state.strength_mobility_scroll = 0;
+        self.state.notes_scroll = 0;
+        self.state.current_screen = AppScreen::DailyView;
+
+        self.persist(log);
+    }
+
+    /// Warning for an entered value that parses but is probably a typo.
+    fn implausible_value_warning(
+        &self,
+        field_type: crate::models::field_accessor::FieldType,
+    ) -> Option<String> {
+        use crate::models::field_accessor::FieldType;
+
+        match field_type {
+            FieldType::Weight => {
+                let weight = self
+                    .state
+                    .units
+                    .parse(Quantity::Weight, self.input_handler.input_buffer.trim())?;
+                crate::weight_stats::weight_typo_warning(
+                    self.state.daily_logs(),
+                    self.state.selected_date,
+                    weight as f32,
+                    self.config.validation.weight_threshold,
+                    self.state.units,
+                )
+            }
+            _ => None,
+        }
+    }
+
+    /// Cross-checks the entered miles or elevation against the other half of
+    /// the pair already logged for the day.
+    fn running_sanity_warning(
+        &self,
+        field_type: crate::models::field_accessor::FieldType,
+    ) -> Option<String> {
+        use crate::models::field_accessor::FieldType;
+
+        let log = self.state.get_daily_log(self.state.selected_date);
+        let input = self.input_handler.input_buffer.trim();
+        let units = self.state.units;
+        let (miles, elevation) = match field_type {
+            FieldType::Miles => (
+                units
+                    .parse(Quantity::Distance, input)
+                    .map(|miles| miles as f32),
+                log.and_then(|log| log.elevation_gain),
+            ),
+            FieldType::Elevation => (
+                log.and_then(|log| log.miles_covered),
+                Some(units.parse(Quantity::Elevation, input)?.round() as i32),
+            ),
+            _ => return None,
+        };
+        crate::elevation_stats::elevation_sanity_warning(
+            miles,
+            elevation,
+            self.config.validation.max_feet_per_mile,
+            units,
+        )
+    }
+
+    fn handle_value_confirmation_input(
+        &mut self,
+        key: KeyCode,
+        field_type: crate::models::field_accessor::FieldType,
+    ) {
+        match key {
+            KeyCode::Char('y') | KeyCode::Enter => {
+                self.state.value_warning = None;
+                self.save_field_input(field_type);
+            }
+            KeyCode::Char('n') | KeyCode::Esc => {
+                self.state.value_warning = None;
+                self.state.current_screen = AppScreen::InputField(field_type);
+            }
+            _ => {}
+        }
+    }
+
+    async fn handle_add_sokay_input(&mut self, key: KeyCode) -> Result<()> {
+        match key {
+            KeyCode::Enter => {
+                if let Some(log) = ActionHandler::save_sokay_entry(
+                    &mut self.state,
+                    self.input_handler.input_buffer.clone(),
+                ) {
+                    self.input_handler.clear();
+                    self.state.current_screen = AppScreen::DailyView;
+
+                    self.persist(log);
+                } else {
+                    self.input_handler.clear();
+                    self.state.current_screen = AppScreen::DailyView;
+                }
+            }
+            KeyCode::Esc => {
+                self.input_handler.clear();
+                self.state.current_screen = AppScreen::DailyView;
+            }
+            _ => {
+                self.input_handler.handle_text_input(key);
+            }
+        }
+        Ok(())
+    }
+
+    async fn handle_edit_sokay_input(&mut self, key: KeyCode, sokay_index: usize) -> Result<()> {
+        match key {
+            KeyCode::Enter => {
+                if let Some(log) = ActionHandler::update_sokay_entry(
+                    &mut self.state,
+                    sokay_index,
+                    self.input_handler.input_buffer.clone(),
+                ) {
+                    self.input_handler.clear();
+                    self.state.current_screen = AppScreen::DailyView;
+
+                    self.persist(log);
+                } else {
+                    self.input_handler.clear();
+                    self.state.current_screen = AppScreen::DailyView;
+                }
+            }
+            KeyCode::Esc => {
+                self.input_handler.clear();
+                self.state.current_screen = AppScreen::DailyView;
+            }
+            _ => {
+                self.input_handler.handle_text_input(key);
+            }
+        }
+        Ok(())
+    }
+
+    async fn handle_date_input(&mut self, key: KeyCode) -> Result<()> {
+        match key {
+            KeyCode::Enter => {
+                let input = self.input_handler.input_buffer.clone();
+                match chrono::NaiveDate::parse_from_str(&input, "%m.%d.%Y") {
+                    Ok(date) => {
+                        let today = chrono::Local::now().date_naive();
+                        if date > today {
+                            self.state.date_input_error =
+                                Some("Future dates not allowed".to_string());
+                        } else {
+                            self.input_handler.clear();
+                            self.state.date_input_error = None;
+                            self.open_day(date);
+                        }
+                    }
+                    Err(_) => {
+                        self.state.date_input_error = Some("Invalid date format".to_string());
+                    }
+                }
+            }
+            KeyCode::Esc => {
+                self.input_handler.clear();
+                self.state.date_input_error = None;
+                self.state.current_screen = AppScreen::Home;
+            }
+            KeyCode::Char(c) => {
+                if c.is_ascii_digit() || c == '.' {
+                    self.state.date_input_error = None;
+                    self.input_handler.handle_text_input(key);
+                }
+            }
+            _ => {
+                self.state.date_input_error = None;
+                self.input_handler.handle_text_input(key);
+            }
+        }
+        Ok(())
+    }
+
+    /// The `g` prompt: anything `parse_fuzzy_date` understands, up to today.
+    fn handle_jump_to_date_input(&mut self, key: KeyCode) {
+        match key {
+            KeyCode::Enter => {
+                let today = chrono::Local::now().date_naive();
+                match parse_fuzzy_date(&self.input_handler.input_buffer, today) {
+                    Some(date) if date > today => {
+                        self.state.date_input_error = Some("Future dates not allowed".to_string());
+                    }
+                    Some(date) => {
+                        self.input_handler.clear();
+                        self.state.date_input_error = None;
+                        self.open_day(date);
+                    }
+                    None => {
+                        self.state.date_input_error = Some("Unrecognized date".to_string());
+                    }
+                }
+            }
+            KeyCode::Esc => {
+                self.input_handler.clear();
+                self.state.date_input_error = None;
+                self.state.current_screen = AppScreen::DailyView;
+            }
+            _ => {
+                self.state.date_input_error = None;
+                self.input_handler.handle_text_input(key);
+            }
+        }
+    }
+
+    /// Opens the food lookup, searching for the selected food if there is one.
+    async fn open_food_lookup(&mut self) -> Result<()> {
+        let selected = self
+            .food_list_state
+            .selected()
+            .filter(|_| self.state.food_list_focused)
+            .and_then(|index| {
+                let log = self.state.get_daily_log(self.state.selected_date)?;
+                log.food_entries.get(index).map(|food| food.name.clone())
+            });
+        self.input_handler.set_input(selected.unwrap_or_default());
+        self.state.current_screen = AppScreen::FoodLookup;
+        self.run_food_lookup().await
+    }
+
+    async fn handle_food_lookup_input(&mut self, key: KeyCode) -> Result<()> {
+        match key {
+            KeyCode::Up => {
+                self.food_history_selected = self.food_history_selected.saturating_sub(1);
+            }
+            KeyCode::Down => {
+                let last = self.state.food_history.len().saturating_sub(1);
+                self.food_history_selected = (self.food_history_selected + 1).min(last);
+            }
+            KeyCode::Enter => {
+                if let Some(&date) = self
+                    .state
+                    .food_history
+                    .get(self.food_history_selected)
+                    .and_then(|food| food.dates.first())
+                {
+                    self.close_food_lookup();
+                    self.open_day(date);
+                }
+            }
+            KeyCode::Esc => {
+                self.close_food_lookup();
+                self.state.current_screen = AppScreen::DailyView;
+            }
+            _ => {
+                self.input_handler.handle_text_input(key);
+                self.run_food_lookup().await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Searches past food entries for the typed query.
+    async fn run_food_lookup(&mut self) -> Result<()> {
+        let query = self.input_handler.input_buffer.trim().to_string();
+        self.state.food_history = if query.is_empty() {
+            Vec::new()
+        } else {
+            self.db_manager.read().await.food_history(&query).await?
+        };
+        self.food_history_selected = 0;
+        Ok(())
+    }
+
+    fn close_food_lookup(&mut self) {
+        self.input_handler.clear();
+        self.state.food_history.clear();
+        self.food_history_selected = 0;
+    }
+
+    async fn handle_navigation_input(
+        &mut self,
+        key: KeyCode,
+        modifiers: crossterm::event::KeyModifiers,
+    ) -> Result<()> {
+        if modifiers.contains(crossterm::event::KeyModifiers::CONTROL) {
+            let target = match key {
+                KeyCode::Char('o') => self.jump_list.back(),
+                KeyCode::Char('i') => self.jump_list.forward(),
+                _ => None,
+            };
+            if let Some(location) = target {
+                self.jump_to(location);
+            }
+            if matches!(key, KeyCode::Char('o' | 'i')) {
+                return Ok(());
+            }
+        }
+
+        // Shift+J/K switches section focus in DailyView
+        if modifiers.contains(crossterm::event::KeyModifiers::SHIFT) {
+            match key {
+                KeyCode::Char('J') => {
+                    if matches!(self.state.current_screen, AppScreen::DailyView) {
+                        // Reset scroll when leaving expanded sections
+                        self.state.strength_mobility_scroll = 0;
+                        self.state.notes_scroll = 0;
+                        self.state.focused_section =
+                            SectionNavigator::move_focus_down(&self.state.focused_section);
+                    }
+                    return Ok(());
+                }
+                KeyCode::Char('K') => {
+                    if matches!(self.state.current_screen, AppScreen::DailyView) {
+                        // Reset scroll when leaving expanded sections
+                        self.state.strength_mobility_scroll = 0;
+                        self.state.notes_scroll = 0;
+                        self.state.focused_section =
+                            SectionNavigator::move_focus_up(&self.state.focused_section);
+                    }
+                    return Ok(());
+                }
+                _ => {}
+            }
+        }
+
+        match key {
+            KeyCode::Char('q') => self.request_quit(),
+            KeyCode::Tab => {
+                if matches!(self.state.current_screen, AppScreen::DailyView) {
+                    self.state.focused_section =
+                        SectionNavigator::toggle_internal_focus(&self.state.focused_section);
+                }
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                if matches!(self.state.current_screen, AppScreen::DailyView) {
+                    match self.state.focused_section {
+                        FocusedSection::FoodItems => self.move_food_selection_down(),
+                        FocusedSection::Sokay => self.move_sokay_selection_down(),
+                        FocusedSection::StrengthMobility => {
+                            let max = self.strength_mobility_max_scroll();
+                            self.state.strength_mobility_scroll = self
+                                .state
+                                .strength_mobility_scroll
+                                .saturating_add(1)
+                                .min(max);
+                        }
+                        FocusedSection::Notes => {
+                            let max = self.notes_max_scroll();
+                            self.state.notes_scroll =
+                                self.state.notes_scroll.saturating_add(1).min(max);
+                        }
+                        _ => {}
+                    }
+                } else if matches!(self.state.current_screen, AppScreen::Home) {
+                    self.move_selection_down();
+                } else if matches!(self.state.current_screen, AppScreen::Week) {
+                    self.move_week_cursor(1);
+                }
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                if matches!(self.state.current_screen, AppScreen::DailyView) {
+                    match self.state.focused_section {
+                        FocusedSection::FoodItems => self.move_food_selection_up(),
+                        FocusedSection::Sokay => self.move_sokay_selection_up(),
+                        FocusedSection::StrengthMobility => {
+                            self.state.strength_mobility_scroll =
+                                self.state.strength_mobility_scroll.saturating_sub(1);
+                        }
+                        FocusedSection::Notes => {
+                            self.state.notes_scroll = self.state.notes_scroll.saturating_sub(1);
+                        }
+                        _ => {}
+                    }
+                } else if matches!(self.state.current_screen, AppScreen::Home) {
+                    self.move_selection_up();
+                } else if matches!(self.state.current_screen, AppScreen::Week) {
+                    self.move_week_cursor(-1);
+                }
+            }
+            KeyCode::Enter => {
+                if matches!(self.state.current_screen, AppScreen::DailyView) {
+                    self.handle_section_enter().await?;
+                } else {
+                    self.handle_enter();
+                }
+            }
+            KeyCode::Esc => {
+                self.handle_escape();
+            }
+            KeyCode::Char('d') => {
+                if matches!(self.state.current_screen, AppScreen::Home) {
+                    self.handle_delete_day_confirmation();
+                } else if matches!(self.state.current_screen, AppScreen::DailyView) {
+                    use crate::models::DeleteTarget;
+                    match self.state.focused_section {
+                        FocusedSection::FoodItems => {
+                            if self.state.food_list_focused
+                                && let Some(selected_index) = self.food_list_state.selected()
+                            {
+                                self.state.current_screen =
+                                    AppScreen::ConfirmDelete(DeleteTarget::Food(selected_index));
+                            }
+                        }
+                        FocusedSection::Sokay => {
+                            if self.state.sokay_list_focused
+                                && let Some(selected_index) = self.sokay_list_state.selected()
+                            {
+                                self.state.current_screen =
+                                    AppScreen::ConfirmDelete(DeleteTarget::Sokay(selected_index));
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            KeyCode::Char('f') => {
+                if matches!(self.state.current_screen, AppScreen::DailyView) {
+                    self.state.current_screen = AppScreen::AddFood;
+                }
+            }
+            KeyCode::Char('e') => {
+                if matches!(self.state.current_screen, AppScreen::DailyView) {
+                    match self.state.focused_section {
+                        FocusedSection::FoodItems => self.handle_edit_food(),
+                        FocusedSection::Sokay => self.handle_edit_sokay(),
+                        _ => {}
+                    }
+                }
+            }
+            KeyCode::Char('w') => {
+                if matches!(self.state.current_screen, AppScreen::DailyView) {
+                    self.handle_edit_weight();
+                }
+            }
+            KeyCode::Char('r') => {
+                if matches!(self.state.current_screen, AppScreen::DailyView) {
+                    self.handle_edit_resting_hr();
+                }
+            }
+            KeyCode::Char('T') => {
+                if matches!(self.state.current_screen, AppScreen::DailyView) {
+                    self.handle_edit_duration();
+                }
+            }
+            KeyCode::Char('E') => {
+                if matches!(self.state.current_screen, AppScreen::DailyView) {
+                    self.handle_edit_rpe();
+                }
+            }
+            KeyCode::Char('P') => match self.state.current_screen {
+                AppScreen::Startup | AppScreen::Home => self.open_phases(),
+                AppScreen::DailyView => self.open_ocr_image(),
+                _ => {}
+            },
+            KeyCode::Char('U') => self.toggle_dual_units(),
+            KeyCode::Char('N') => {
+                if matches!(
+                    self.state.current_screen,
+                    AppScreen::Startup | AppScreen::Home | AppScreen::DailyView
+                ) {
+                    self.open_injuries();
+                }
+            }
+            KeyCode::Char('L') => {
+                if matches!(self.state.current_screen, AppScreen::Startup | AppScreen::Home) {
+                    self.open_strength();
+                }
+            }
+            KeyCode::Char('F') => {
+                if matches!(self.state.current_screen, AppScreen::Startup | AppScreen::Home) {
+                    self.open_backfill();
+                }
+            }
+            KeyCode::Char('O') => {
+                if matches!(self.state.current_screen, AppScreen::Startup | AppScreen::Home) {
+                    self.open_goals();
+                }
+            }
+            KeyCode::Char('B') => match self.state.current_screen {
+                AppScreen::Startup | AppScreen::Home => self.open_best_efforts(),
+                AppScreen::DailyView => self.handle_edit_start_time(),
+                _ => {}
+            },
+            KeyCode::Char('A') => match self.state.current_screen {
+                AppScreen::DailyView => {
+                    let log = ActionHandler::cycle_activity(&mut self.state);
+                    self.persist(log);
+                }
+                AppScreen::Home => {
+                    let filter = &mut self.state.home_filter;
+                    filter.show_archived = !filter.show_archived;
+                    self.clamp_home_selection();
+                }
+                _ => {}
+            },
+            KeyCode::Char('I') => {
+                if matches!(self.state.current_screen, AppScreen::DailyView) {
+                    let log = ActionHandler::toggle_indoor(&mut self.state);
+                    self.persist(log);
+                }
+            }
+            KeyCode::Char('D') => {
+                if matches!(self.state.current_screen, AppScreen::DailyView) {
+                    let log = ActionHandler::toggle_rest_day(&mut self.state);
+                    self.persist(log);
+                }
+            }
+            KeyCode::Char(c @ '1'..='5') => {
+                if matches!(self.state.current_screen, AppScreen::DailyView) {
+                    let log = ActionHandler::rate_energy(&mut self.state, c as u8 - b'0');
+                    self.persist(log);
+                }
+            }
+            KeyCode::Char(c @ ('+' | '-')) => {
+                if matches!(self.state.current_screen, AppScreen::DailyView) {
+                    let glass = self.config.hydration.glass_ounces(self.state.units.system);
+                    let ounces = if c == '+' { glass } else { -glass };
+                    if let Some(log) = ActionHandler::add_water(&mut self.state, ounces) {
+                        self.persist(log);
+                    }
+                }
+            }
+            KeyCode::Char('s') => {
                 if matches!(self.state.current_screen, AppScreen::Startup) {
                     self.state.current_screen = AppScreen::Statistics;
                 } else if matches!(self.state.current_screen, AppScreen::DailyView) {
@@ -699,9 +3002,7 @@ impl App {
             }
             KeyCode::Char('n') => {
                 if matches!(self.state.current_screen, AppScreen::Startup) {
-                    self.state.selected_date = chrono::Local::now().date_naive();
-                    self.state.get_or_create_daily_log(self.state.selected_date);
-                    self.state.current_screen = AppScreen::DailyView;
+                    self.open_day(chrono::Local::now().date_naive());
                 } else if matches!(self.state.current_screen, AppScreen::DailyView) {
                     self.handle_edit_notes();
                 }
@@ -711,6 +3012,101 @@ impl App {
                     self.handle_edit_miles();
                 }
             }
+            KeyCode::Char('y') => {
+                if matches!(self.state.current_screen, AppScreen::DailyView) {
+                    self.copy_day_to_clipboard();
+                }
+            }
+            KeyCode::Char('p') => {
+                if matches!(self.state.current_screen, AppScreen::DailyView) {
+                    self.save_share_card();
+                }
+            }
+            KeyCode::Char('/') => {
+                if matches!(self.state.current_screen, AppScreen::Home) {
+                    self.input_handler
+                        .set_input(self.state.home_filter.query.clone());
+                    self.state.current_screen = AppScreen::HomeFilter;
+                } else if matches!(self.state.current_screen, AppScreen::DailyView)
+                    && self.state.focused_section == FocusedSection::FoodItems
+                {
+                    self.open_food_lookup().await?;
+                }
+            }
+            KeyCode::Char('V') => {
+                if matches!(self.state.current_screen, AppScreen::Home) {
+                    self.views_selected = 0;
+                    self.state.current_screen = AppScreen::HomeViews;
+                }
+            }
+            KeyCode::Char('o') => match self.state.current_screen {
+                AppScreen::Home => {
+                    self.state.home_filter.sort = self.state.home_filter.sort.next();
+                    self.list_state.select(None);
+                }
+                AppScreen::DailyView => self.open_reflection_edit(Reflection::Gratitude),
+                _ => {}
+            },
+            KeyCode::Char('i') => {
+                if matches!(self.state.current_screen, AppScreen::DailyView) {
+                    self.open_reflection_edit(Reflection::Intention);
+                }
+            }
+            KeyCode::Char('z') => {
+                if matches!(self.state.current_screen, AppScreen::DailyView) {
+                    self.handle_edit_sleep();
+                } else if matches!(self.state.current_screen, AppScreen::Home)
+                    && let Some(month) = self.selected_month()
+                {
+                    self.state.toggle_month(month);
+                    self.select_month_header(month);
+                }
+            }
+            KeyCode::Char('Z') => {
+                if matches!(self.state.current_screen, AppScreen::Home) {
+                    let selected = self.selected_month();
+                    let months: HashSet<chrono::NaiveDate> = self
+                        .state
+                        .daily_logs()
+                        .iter()
+                        .map(|log| month_start(log.date))
+                        .collect();
+                    // Fold everything, or unfold everything once it's all folded
+                    if months.is_subset(&self.state.collapsed_months) {
+                        self.state.collapsed_months.clear();
+                    } else {
+                        self.state.collapsed_months = months;
+                    }
+                    match selected {
+                        Some(month) => self.select_month_header(month),
+                        None => self.list_state.select(None),
+                    }
+                }
+            }
+            KeyCode::Char('x') => match self.state.current_screen {
+                AppScreen::Startup => self.state.health_banner = None,
+                AppScreen::Home => self.toggle_archived_day(),
+                _ => {}
+            },
+            KeyCode::Char('v') => {
+                if matches!(self.state.current_screen, AppScreen::DailyView) {
+                    self.handle_edit_hrv();
+                } else if matches!(self.state.current_screen, AppScreen::Home) {
+                    self.calendar_cursor = self
+                        .list_state
+                        .selected()
+                        .and_then(|index| self.state.home_log(index))
+                        .map_or_else(|| chrono::Local::now().date_naive(), |log| log.date);
+                    self.state.current_screen = AppScreen::Calendar;
+                }
+            }
+            KeyCode::Char('g') => {
+                if matches!(self.state.current_screen, AppScreen::DailyView) {
+                    self.input_handler.clear();
+                    self.state.date_input_error = None;
+                    self.state.current_screen = AppScreen::JumpToDate;
+                }
+            }
             KeyCode::Char('l') => {
                 if matches!(self.state.current_screen, AppScreen::Startup) {
                     self.state.current_screen = AppScreen::Home;
@@ -718,41 +3114,496 @@ impl App {
                     self.handle_edit_elevation();
                 }
             }
-            KeyCode::Char('c') => {
-                if matches!(self.state.current_screen, AppScreen::DailyView) {
-                    self.state.current_screen = AppScreen::AddSokay;
-                } else if matches!(self.state.current_screen, AppScreen::Startup) {
-                    self.open_config_sync();
-                }
+            KeyCode::Char('c') => {
+                if matches!(self.state.current_screen, AppScreen::DailyView) {
+                    self.state.current_screen = AppScreen::AddSokay;
+                } else if matches!(self.state.current_screen, AppScreen::Startup) {
+                    self.open_config_sync();
+                }
+            }
+            KeyCode::Char('R') => match self.state.current_screen {
+                AppScreen::Startup | AppScreen::Home => self.open_routes(),
+                AppScreen::DailyView => self.open_route_edit(),
+                _ => {}
+            },
+            KeyCode::Char('G') => match self.state.current_screen {
+                AppScreen::Startup | AppScreen::Home => self.open_gear(),
+                AppScreen::DailyView => self.open_gear_picker(),
+                _ => {}
+            },
+            KeyCode::Char(',') => {
+                if matches!(self.state.current_screen, AppScreen::Startup) {
+                    self.settings_theme = self.config.display.theme;
+                    self.state.current_screen = AppScreen::Settings;
+                }
+            }
+            KeyCode::Char('H') => match self.state.current_screen {
+                AppScreen::Startup | AppScreen::Home => self.open_heatmap(),
+                AppScreen::DailyView => self.handle_edit_avg_hr(),
+                _ => {}
+            },
+            KeyCode::Char('X') => {
+                if matches!(self.state.current_screen, AppScreen::DailyView) {
+                    self.handle_edit_max_hr();
+                }
+            }
+            KeyCode::Char('Y') => {
+                if matches!(
+                    self.state.current_screen,
+                    AppScreen::Startup | AppScreen::Home
+                ) {
+                    self.open_year();
+                }
+            }
+            KeyCode::Char('C') => match self.state.current_screen {
+                AppScreen::Startup | AppScreen::Home => self.open_charts(),
+                AppScreen::DailyView => {
+                    let now = chrono::Local::now().time();
+                    let log = ActionHandler::quick_sokay_entry(&mut self.state, now);
+                    let count = log.sokay_entries.len();
+                    self.persist(log);
+                    self.state.show_toast(format!(
+                        "Sokay #{} for the day - e on it to add words",
+                        count
+                    ));
+                }
+                _ => {}
+            },
+            KeyCode::Char('!') => {
+                if matches!(
+                    self.state.current_screen,
+                    AppScreen::Startup | AppScreen::Home | AppScreen::DailyView
+                ) {
+                    self.open_save_errors();
+                }
+            }
+            KeyCode::Char('W') => {
+                if matches!(
+                    self.state.current_screen,
+                    AppScreen::Startup | AppScreen::Home | AppScreen::DailyView
+                ) {
+                    self.open_week();
+                }
+            }
+            KeyCode::Char('M') => {
+                if matches!(
+                    self.state.current_screen,
+                    AppScreen::Startup | AppScreen::Home | AppScreen::DailyView
+                ) {
+                    self.open_month();
+                }
+            }
+            KeyCode::Char('[') => {
+                if matches!(self.state.current_screen, AppScreen::Week) {
+                    self.move_week_cursor(-7);
+                } else if matches!(self.state.current_screen, AppScreen::Month) {
+                    self.move_month_cursor(-1);
+                }
+            }
+            KeyCode::Char(']') => {
+                if matches!(self.state.current_screen, AppScreen::Week) {
+                    self.move_week_cursor(7);
+                } else if matches!(self.state.current_screen, AppScreen::Month) {
+                    self.move_month_cursor(1);
+                }
+            }
+            KeyCode::Char('S') => {
+                if matches!(
+                    self.state.current_screen,
+                    AppScreen::Home | AppScreen::DailyView
+                ) {
+                    self.state.current_screen = AppScreen::Startup;
+                }
+            }
+            KeyCode::Char('a') => {
+                if matches!(
+                    self.state.current_screen,
+                    AppScreen::Home | AppScreen::Startup
+                ) {
+                    self.input_handler.clear();
+                    self.state.date_input_error = None;
+                    self.state.current_screen = AppScreen::DateInput;
+                }
+            }
+            KeyCode::Char(' ') => {
+                if matches!(self.state.current_screen, AppScreen::DailyView) {
+                    self.state.current_screen = AppScreen::ShortcutsHelp;
+                } else if matches!(self.state.current_screen, AppScreen::ShortcutsHelp) {
+                    self.state.current_screen = AppScreen::DailyView;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn open_heatmap(&mut self) {
+        self.heatmap_cursor = chrono::Local::now().date_naive();
+        self.heatmap_return = self.state.current_screen.clone();
+        self.state.current_screen = AppScreen::Heatmap;
+    }
+
+    fn handle_heatmap_input(&mut self, key: KeyCode) {
+        let today = chrono::Local::now().date_naive();
+        let cursor = self.heatmap_cursor;
+        let moved = match key {
+            KeyCode::Char('h') | KeyCode::Left => cursor.checked_sub_days(chrono::Days::new(7)),
+            KeyCode::Char('l') | KeyCode::Right => cursor.checked_add_days(chrono::Days::new(7)),
+            KeyCode::Char('k') | KeyCode::Up => cursor.pred_opt(),
+            KeyCode::Char('j') | KeyCode::Down => cursor.succ_opt(),
+            KeyCode::Enter => {
+                self.open_day(cursor);
+                None
+            }
+            KeyCode::Esc => {
+                self.state.current_screen = self.heatmap_return.clone();
+                None
+            }
+            _ => None,
+        };
+        // No future days: there's nothing to log there yet
+        if let Some(date) = moved {
+            self.heatmap_cursor = date.min(today);
+        }
+    }
+
+    fn open_year(&mut self) {
+        self.year_cursor = chrono::Local::now().date_naive();
+        self.year_return = self.state.current_screen.clone();
+        self.state.current_screen = AppScreen::Year;
+    }
+
+    fn handle_year_input(&mut self, key: KeyCode) {
+        let today = chrono::Local::now().date_naive();
+        let cursor = self.year_cursor;
+        let moved = match key {
+            KeyCode::Char('h') | KeyCode::Left => cursor.pred_opt(),
+            KeyCode::Char('l') | KeyCode::Right => cursor.succ_opt(),
+            KeyCode::Char('k') | KeyCode::Up => cursor.checked_sub_days(chrono::Days::new(7)),
+            KeyCode::Char('j') | KeyCode::Down => cursor.checked_add_days(chrono::Days::new(7)),
+            KeyCode::Char('[') => cursor.checked_sub_months(chrono::Months::new(1)),
+            KeyCode::Char(']') => cursor.checked_add_months(chrono::Months::new(1)),
+            KeyCode::Char('{') => cursor.checked_sub_months(chrono::Months::new(12)),
+            KeyCode::Char('}') => cursor.checked_add_months(chrono::Months::new(12)),
+            KeyCode::Enter => {
+                self.open_day(cursor);
+                None
+            }
+            KeyCode::Char('m') => {
+                self.month_cursor = month_start(cursor);
+                self.month_return = AppScreen::Year;
+                self.state.current_screen = AppScreen::Month;
+                None
+            }
+            KeyCode::Esc => {
+                self.state.current_screen = self.year_return.clone();
+                None
+            }
+            _ => None,
+        };
+        if let Some(date) = moved {
+            self.year_cursor = date.min(today);
+        }
+    }
+
+    /// Opens the week view on the day being viewed, or today from the lists.
+    fn open_week(&mut self) {
+        self.week_cursor = if matches!(self.state.current_screen, AppScreen::DailyView) {
+            self.state.selected_date
+        } else {
+            chrono::Local::now().date_naive()
+        };
+        self.week_return = self.state.current_screen.clone();
+        self.state.current_screen = AppScreen::Week;
+    }
+
+    /// Moves the week view's cursor by `days`, never past today. Single-day
+    /// moves stay inside the week; `[`/`]` pass ±7 to change weeks.
+    fn move_week_cursor(&mut self, days: i64) {
+        let today = chrono::Local::now().date_naive();
+        let Some(date) = self
+            .week_cursor
+            .checked_add_signed(chrono::Duration::days(days))
+        else {
+            return;
+        };
+        let first = self.state.stats.config().week_start;
+        let same_week = week_start(date, first) == week_start(self.week_cursor, first);
+        if (days.abs() >= 7 || same_week) && date <= today {
+            self.week_cursor = date;
+        } else if days >= 7 && week_start(date, first) <= today {
+            // Next week exists but hasn't reached this weekday yet
+            self.week_cursor = today;
+        }
+    }
+
+    /// Opens the monthly summary on the day being viewed, or this month from the lists.
+    fn open_month(&mut self) {
+        let date = if matches!(self.state.current_screen, AppScreen::DailyView) {
+            self.state.selected_date
+        } else {
+            chrono::Local::now().date_naive()
+        };
+        self.month_cursor = month_start(date);
+        self.month_return = self.state.current_screen.clone();
+        self.state.current_screen = AppScreen::Month;
+    }
+
+    /// Steps the monthly summary by `months`, stopping at the current month.
+    fn move_month_cursor(&mut self, months: i32) {
+        let this_month = month_start(chrono::Local::now().date_naive());
+        if let Some(month) = shift_month(self.month_cursor, months)
+            && month <= this_month
+        {
+            self.month_cursor = month;
+        }
+    }
+
+    fn open_charts(&mut self) {
+        self.charts_return = self.state.current_screen.clone();
+        self.state.current_screen = AppScreen::Charts;
+    }
+
+    fn handle_charts_input(&mut self, key: KeyCode) {
+        match key {
+            KeyCode::Char('l') | KeyCode::Right | KeyCode::Tab => {
+                self.chart_metric = self.chart_metric.next();
+            }
+            KeyCode::Char('h') | KeyCode::Left | KeyCode::BackTab => {
+                self.chart_metric = self.chart_metric.prev();
+            }
+            KeyCode::Char('r') => self.chart_range = self.chart_range.next(),
+            KeyCode::Esc => self.state.current_screen = self.charts_return.clone(),
+            _ => {}
+        }
+    }
+
+    /// Copies the day being viewed to the clipboard as markdown.
+    fn copy_day_to_clipboard(&mut self) {
+        let date = self.state.selected_date;
+        let Some(log) = self.state.get_daily_log(date) else {
+            self.state
+                .show_toast(format!("Nothing logged for {}", date.format("%B %d")));
+            return;
+        };
+        let markdown = self.file_manager.daily_log_to_markdown(log);
+        let message = match self.clipboard.copy(&markdown) {
+            Ok(()) => format!("Copied {} as markdown", date.format("%B %d")),
+            Err(err) => format!("Couldn't copy: {:#}", err),
+        };
+        self.state.show_toast(message);
+    }
+
+    fn save_share_card(&mut self) {
+        let Some(log) = self.state.get_daily_log(self.state.selected_date) else {
+            return;
+        };
+        let dir = self.file_manager.exports_dir();
+        let message = match share_card::save(log, self.state.units, &dir) {
+            Ok(path) => format!("Saved share card to {}", path.display()),
+            Err(err) => format!("Couldn't save share card: {:#}", err),
+        };
+        self.state.show_toast(message);
+    }
+
+    /// Typing a `/` query filters the logged days list as it changes; Enter
+    /// keeps the filter, Esc clears it.
+    fn handle_home_filter_input(&mut self, key: KeyCode) {
+        match key {
+            KeyCode::Enter => {
+                self.input_handler.clear();
+                self.state.current_screen = AppScreen::Home;
+                return;
+            }
+            KeyCode::Esc => {
+                self.input_handler.clear();
+                self.state.home_filter.query.clear();
+                self.state.current_screen = AppScreen::Home;
+            }
+            _ => {
+                self.input_handler.handle_text_input(key);
+                self.state.home_filter.query = self.input_handler.input_buffer.clone();
+            }
+        }
+        self.list_state.select(None);
+    }
+
+    /// Enter applies the highlighted view, `s` names the current filter to
+    /// save it and `d` deletes the highlighted view; both write config.toml.
+    fn handle_home_views_input(&mut self, key: KeyCode) {
+        if self.naming_view {
+            match key {
+                KeyCode::Enter => {
+                    let name = self.input_handler.input_buffer.trim().to_string();
+                    if name.is_empty() {
+                        return;
+                    }
+                    self.views_selected = save_view(
+                        &mut self.config.home.views,
+                        &name,
+                        &self.state.home_filter.query,
+                    );
+                    self.save_views(format!("Saved view \"{}\"", name));
+                    self.naming_view = false;
+                    self.input_handler.clear();
+                }
+                KeyCode::Esc => {
+                    self.naming_view = false;
+                    self.input_handler.clear();
+                }
+                _ => {
+                    self.input_handler.handle_text_input(key);
+                }
+            }
+            return;
+        }
+
+        let count = self.config.home.views.len();
+        match key {
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.views_selected = (self.views_selected + 1).min(count.saturating_sub(1));
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.views_selected = self.views_selected.saturating_sub(1);
+            }
+            KeyCode::Enter => {
+                if let Some(view) = self.config.home.views.get(self.views_selected) {
+                    self.state.home_filter.query = view.query.clone();
+                    self.list_state.select(None);
+                    self.state.current_screen = AppScreen::Home;
+                }
+            }
+            KeyCode::Char('s') => {
+                if self.state.home_filter.query.trim().is_empty() {
+                    self.state
+                        .show_toast("Filter with / first, then save it as a view".to_string());
+                } else {
+                    self.naming_view = true;
+                    self.input_handler.clear();
+                }
+            }
+            KeyCode::Char('d') if self.views_selected < count => {
+                let view = self.config.home.views.remove(self.views_selected);
+                self.views_selected = self.views_selected.min(count.saturating_sub(2));
+                self.save_views(format!("Deleted view \"{}\"", view.name));
+            }
+            KeyCode::Esc => self.state.current_screen = AppScreen::Home,
+            _ => {}
+        }
+    }
+
+    fn save_views(&mut self, done: String) {
+        match self.config.save() {
+            Ok(()) => self.state.show_toast(done),
+            Err(e) => self.state.show_toast(format!("Couldn't save views: {}", e)),
+        }
+    }
+
+    /// hjkl move by day and week, `[`/`]` by month; the cursor never goes
+    /// past today. Enter opens the day.
+    fn handle_calendar_input(&mut self, key: KeyCode) {
+        let cursor = self.calendar_cursor;
+        let moved = match key {
+            KeyCode::Char('h') | KeyCode::Left => cursor.pred_opt(),
+            KeyCode::Char('l') | KeyCode::Right => cursor.succ_opt(),
+            KeyCode::Char('k') | KeyCode::Up => cursor.checked_sub_days(chrono::Days::new(7)),
+            KeyCode::Char('j') | KeyCode::Down => cursor.checked_add_days(chrono::Days::new(7)),
+            KeyCode::Char('[') => cursor.checked_sub_months(chrono::Months::new(1)),
+            KeyCode::Char(']') => cursor.checked_add_months(chrono::Months::new(1)),
+            KeyCode::Enter => {
+                self.open_day(cursor);
+                return;
+            }
+            KeyCode::Esc => {
+                self.state.current_screen = AppScreen::Home;
+                return;
+            }
+            _ => return,
+        };
+        let today = chrono::Local::now().date_naive();
+        if let Some(date) = moved {
+            self.calendar_cursor = date.min(today);
+        }
+    }
+
+    fn toggle_manual(&mut self) {
+        if matches!(self.state.current_screen, AppScreen::Manual) {
+            self.state.current_screen = self.manual_return.clone();
+        } else {
+            self.manual_return = self.state.current_screen.clone();
+            self.manual_scroll = 0;
+            self.state.current_screen = AppScreen::Manual;
+        }
+    }
+
+    fn handle_manual_input(&mut self, key: KeyCode) {
+        let page = screens::manual_page_height(self.state.frame_height);
+        let max = (manual::lines(&self.keymap).len() as u16).saturating_sub(page);
+        let scroll = self.manual_scroll;
+        self.manual_scroll = match key {
+            KeyCode::Char('j') | KeyCode::Down => scroll.saturating_add(1),
+            KeyCode::Char('k') | KeyCode::Up => scroll.saturating_sub(1),
+            KeyCode::PageDown => scroll.saturating_add(page),
+            KeyCode::PageUp => scroll.saturating_sub(page),
+            KeyCode::Esc => {
+                self.toggle_manual();
+                scroll
+            }
+            _ => scroll,
+        }
+        .min(max);
+    }
+
+    /// Moves through the themes, previewing each one; Enter keeps the
+    /// selection and saves it to config.toml, Esc puts the old theme back.
+    fn handle_settings_input(&mut self, key: KeyCode) {
+        match key {
+            KeyCode::Char('j') | KeyCode::Down | KeyCode::Char('l') | KeyCode::Right => {
+                self.settings_theme = self.settings_theme.cycle(1);
+            }
+            KeyCode::Char('k') | KeyCode::Up | KeyCode::Char('h') | KeyCode::Left => {
+                self.settings_theme = self.settings_theme.cycle(-1);
             }
-            KeyCode::Char('S') => {
-                if matches!(
-                    self.state.current_screen,
-                    AppScreen::Home | AppScreen::DailyView
-                ) {
-                    self.state.current_screen = AppScreen::Startup;
+            KeyCode::Enter => {
+                self.config.display.theme = self.settings_theme;
+                match self.config.save() {
+                    Ok(()) => self.state.show_toast(format!(
+                        "Theme set to {}",
+                        self.settings_theme.label()
+                    )),
+                    Err(e) => self.state.show_toast(format!("Couldn't save theme: {}", e)),
                 }
+                self.state.current_screen = AppScreen::Startup;
             }
-            KeyCode::Char('a') => {
-                if matches!(
-                    self.state.current_screen,
-                    AppScreen::Home | AppScreen::Startup
-                ) {
-                    self.input_handler.clear();
-                    self.state.date_input_error = None;
-                    self.state.current_screen = AppScreen::DateInput;
-                }
+            KeyCode::Esc => {
+                self.settings_theme = self.config.display.theme;
+                self.state.current_screen = AppScreen::Startup;
             }
-            KeyCode::Char(' ') => {
-                if matches!(self.state.current_screen, AppScreen::DailyView) {
-                    self.state.current_screen = AppScreen::ShortcutsHelp;
-                } else if matches!(self.state.current_screen, AppScreen::ShortcutsHelp) {
-                    self.state.current_screen = AppScreen::DailyView;
-                }
+            KeyCode::Char('g') => {
+                self.settings_theme = self.config.display.theme;
+                self.state.current_screen = AppScreen::Startup;
+                self.open_gear();
+            }
+            _ => return,
+        }
+        self.state.theme = self
+            .config
+            .colors
+            .apply(Theme::new(self.settings_theme));
+    }
+
+    /// Typing on the lock screen: Enter checks the passphrase, Esc quits
+    /// without unlocking.
+    fn handle_lock_input(&mut self, key: KeyCode) {
+        match key {
+            KeyCode::Enter => self.lock.submit(),
+            KeyCode::Esc => self.state.current_screen = AppScreen::Syncing,
+            KeyCode::Backspace => {
+                self.lock.input.pop();
             }
+            KeyCode::Char(c) => self.lock.input.push(c),
             _ => {}
         }
-        Ok(())
     }
 
     fn open_config_sync(&mut self) {
@@ -826,6 +3677,7 @@ impl App {
                 match self.config.save() {
                     Ok(()) => {
                         self.state.config_sync_status = Some("Saved!".to_string());
+                        self.state.sync_configured = self.config.sync.is_configured();
                     }
                     Err(e) => {
                         self.state.config_sync_status = Some(format!("Error: {}", e));
@@ -876,10 +3728,18 @@ impl App {
             FocusedSection::Measurements { focused_field } => match focused_field {
                 MeasurementField::Weight => self.handle_edit_weight(),
                 MeasurementField::Waist => self.handle_edit_waist(),
+                MeasurementField::Sleep => self.handle_edit_sleep(),
+                MeasurementField::RestingHr => self.handle_edit_resting_hr(),
+                MeasurementField::Hrv => self.handle_edit_hrv(),
             },
             FocusedSection::Running { focused_field } => match focused_field {
                 RunningField::Miles => self.handle_edit_miles(),
                 RunningField::Elevation => self.handle_edit_elevation(),
+                RunningField::Duration => self.handle_edit_duration(),
+                RunningField::StartTime => self.handle_edit_start_time(),
+                RunningField::Rpe => self.handle_edit_rpe(),
+                RunningField::AvgHr => self.handle_edit_avg_hr(),
+                RunningField::MaxHr => self.handle_edit_max_hr(),
             },
             FocusedSection::FoodItems => {
                 self.state.current_screen = AppScreen::AddFood;
@@ -901,6 +3761,16 @@ impl App {
         self.state.frame_width = f.area().width;
         self.state.frame_height = f.area().height;
         self.click_targets.clear();
+        if self.lock.locked && self.state.current_screen != AppScreen::Syncing {
+            screens::render_lock_screen(f, &self.lock, &self.state.theme);
+            return;
+        }
+        if self.private {
+            screens::render_privacy_screen(f, &self.state.theme);
+            return;
+        }
+        let (screen_started, screen_allocations) = (Instant::now(), profiling::allocations());
+        let changed = self.guards_edit() && self.has_unsaved_edit();
         match self.state.current_screen {
             AppScreen::Startup => {
                 screens::render_startup_screen(f, &self.state, Some(&mut self.click_targets));
@@ -933,8 +3803,312 @@ impl App {
                     Some(&mut self.click_targets),
                 );
             }
-            AppScreen::AddFood => {
-                screens::render_add_food_screen(
+            AppScreen::AddFood => {
+                screens::render_add_food_screen(
+                    f,
+                    &self.state,
+                    &mut self.food_list_state,
+                    &mut self.sokay_list_state,
+                    &self.sync_status,
+                    screens::FoodFormView {
+                        form: &self.food_form,
+                        input_buffer: &self.input_handler.input_buffer,
+                        cursor_position: self.input_handler.cursor_position,
+                        suggestions: &self.input_handler.suggestions,
+                        suggestion_selected: self.input_handler.suggestion_selected,
+                        changed: false,
+                        looking_up: self.barcode_lookup.is_some(),
+                    },
+                );
+            }
+            AppScreen::EditFood(_) => {
+                screens::render_edit_food_screen(
+                    f,
+                    &self.state,
+                    &mut self.food_list_state,
+                    &mut self.sokay_list_state,
+                    &self.sync_status,
+                    screens::FoodFormView {
+                        form: &self.food_form,
+                        input_buffer: &self.input_handler.input_buffer,
+                        cursor_position: self.input_handler.cursor_position,
+                        suggestions: &self.input_handler.suggestions,
+                        suggestion_selected: self.input_handler.suggestion_selected,
+                        changed,
+                        looking_up: false,
+                    },
+                );
+            }
+            AppScreen::AddSokay => {
+                screens::render_add_sokay_screen(
+                    f,
+                    &self.state,
+                    &mut self.food_list_state,
+                    &mut self.sokay_list_state,
+                    &self.sync_status,
+                    &self.input_handler.input_buffer,
+                    self.input_handler.cursor_position,
+                );
+            }
+            AppScreen::EditSokay(_) => {
+                screens::render_edit_sokay_screen(
+                    f,
+                    &self.state,
+                    &mut self.food_list_state,
+                    &mut self.sokay_list_state,
+                    &self.sync_status,
+                    &self.input_handler.input_buffer,
+                    self.input_handler.cursor_position,
+                    changed,
+                );
+            }
+            AppScreen::InputField(field_type) => {
+                use crate::models::field_accessor::FieldType;
+                match field_type {
+                    // Numeric fields edit in place inside their daily-view row.
+                    FieldType::Weight
+                    | FieldType::Waist
+                    | FieldType::Miles
+                    | FieldType::Elevation => {
+                        let edit = screens::InPlaceEdit {
+                            field: field_type,
+                            buffer: &self.input_handler.input_buffer,
+                            cursor: self.input_handler.cursor_position,
+                            history_selected: self.history_index,
+                        };
+                        screens::render_daily_view_screen(
+                            f,
+                            &self.state,
+                            &mut self.food_list_state,
+                            &mut self.sokay_list_state,
+                            &self.sync_status,
+                            Some(edit),
+                            None,
+                        );
+                    }
+                    FieldType::Sleep
+                    | FieldType::RestingHr
+                    | FieldType::Hrv
+                    | FieldType::Duration
+                    | FieldType::StartTime
+                    | FieldType::Rpe
+                    | FieldType::AvgHr
+                    | FieldType::MaxHr => {
+                        screens::render_edit_measurement_screen(
+                            f,
+                            &self.state,
+                            &mut self.food_list_state,
+                            &mut self.sokay_list_state,
+                            &self.sync_status,
+                            field_type,
+                            &self.input_handler.input_buffer,
+                            self.input_handler.cursor_position,
+                        )
+                    }
+                    FieldType::StrengthMobility => screens::render_edit_strength_mobility_screen(
+                        f,
+                        &self.state,
+                        &mut self.food_list_state,
+                        &mut self.sokay_list_state,
+                        &self.sync_status,
+                        &self.input_handler.input_buffer,
+                        self.input_handler.cursor_position,
+                        changed,
+                        &self.input_handler.suggestions,
+                        self.input_handler.suggestion_selected,
+                    ),
+                    FieldType::Notes => screens::render_edit_notes_screen(
+                        f,
+                        &self.state,
+                        &mut self.food_list_state,
+                        &mut self.sokay_list_state,
+                        &self.sync_status,
+                        &self.input_handler.input_buffer,
+                        self.input_handler.cursor_position,
+                        changed,
+                    ),
+                }
+            }
+            AppScreen::ConfirmDelete(target) => {
+                use crate::models::DeleteTarget;
+                match target {
+                    DeleteTarget::Day => {
+                        screens::render_confirm_delete_day_screen(
+                            f,
+                            self.state.selected_date,
+                            self.state.units,
+                            &self.state.theme,
+                        );
+                    }
+                    DeleteTarget::Food(food_index) => {
+                        screens::render_confirm_delete_food_screen(
+                            f,
+                            &self.state,
+                            &mut self.food_list_state,
+                            &mut self.sokay_list_state,
+                            &self.sync_status,
+                            food_index,
+                        );
+                    }
+                    DeleteTarget::Sokay(sokay_index) => {
+                        screens::render_confirm_delete_sokay_screen(
+                            f,
+                            &self.state,
+                            &mut self.food_list_state,
+                            &mut self.sokay_list_state,
+                            &self.sync_status,
+                            sokay_index,
+                        );
+                    }
+                }
+            }
+            AppScreen::ConfirmValue(field_type) => {
+                let edit = screens::InPlaceEdit {
+                    field: field_type,
+                    buffer: &self.input_handler.input_buffer,
+                    cursor: self.input_handler.cursor_position,
+                    history_selected: self.history_index,
+                };
+                screens::render_confirm_value_screen(
+                    f,
+                    &self.state,
+                    &mut self.food_list_state,
+                    &mut self.sokay_list_state,
+                    &self.sync_status,
+                    edit,
+                );
+            }
+            AppScreen::HomeFilter => {
+                screens::render_home_screen(
+                    f,
+                    &self.state,
+                    &mut self.list_state,
+                    &self.sync_status,
+                    None,
+                );
+            }
+            AppScreen::HomeViews => {
+                screens::render_home_views_screen(
+                    f,
+                    &self.state,
+                    &mut self.list_state,
+                    &self.sync_status,
+                    screens::ViewsMenu {
+                        views: &self.config.home.views,
+                        selected: self.views_selected,
+                        naming: self
+                            .naming_view
+                            .then_some(self.input_handler.input_buffer.as_str()),
+                    },
+                );
+            }
+            AppScreen::Calendar => {
+                screens::render_calendar_screen(
+                    f,
+                    &self.state,
+                    &mut self.list_state,
+                    &self.sync_status,
+                    self.calendar_cursor,
+                    Some(&mut self.click_targets),
+                );
+            }
+            AppScreen::FoodLookup => {
+                screens::render_food_lookup_screen(
+                    f,
+                    &self.state,
+                    &mut self.food_list_state,
+                    &mut self.sokay_list_state,
+                    &self.sync_status,
+                    &self.input_handler.input_buffer,
+                    self.food_history_selected,
+                );
+            }
+            AppScreen::JumpToDate => {
+                screens::render_jump_to_date_screen(
+                    f,
+                    &self.state,
+                    &mut self.food_list_state,
+                    &mut self.sokay_list_state,
+                    &self.sync_status,
+                    &self.input_handler.input_buffer,
+                    self.input_handler.cursor_position,
+                );
+            }
+            AppScreen::DateInput => {
+                screens::render_date_input_screen(
+                    f,
+                    &self.state,
+                    &mut self.list_state,
+                    &self.sync_status,
+                    &self.input_handler.input_buffer,
+                    self.input_handler.cursor_position,
+                );
+            }
+            AppScreen::ShortcutsHelp => {
+                screens::render_shortcuts_help_screen(
+                    f,
+                    &self.state,
+                    &mut self.food_list_state,
+                    &mut self.sokay_list_state,
+                    &self.sync_status,
+                    &self.keymap,
+                );
+            }
+            AppScreen::ConfigSync => {
+                screens::render_config_sync_screen(
+                    f,
+                    &self.state,
+                    &self.config_url_buffer,
+                    &self.config_token_buffer,
+                    self.config_sync_enabled,
+                    !self.config.sync.auth_token.is_empty(),
+                    Some(&mut self.click_targets),
+                );
+            }
+            AppScreen::Syncing => {
+                let (status, spinner) = match &self.shutdown_sync {
+                    Some(sync) => (sync.status(), sync.spinner(self.ascii_glyphs)),
+                    None => (SYNC_STARTING.to_string(), None),
+                };
+                screens::render_syncing_screen(f, &status, spinner.as_deref(), &self.state.theme);
+            }
+            AppScreen::Heatmap => {
+                screens::render_heatmap_screen(f, &self.state, self.heatmap_cursor);
+            }
+            AppScreen::Year => {
+                screens::render_year_screen(f, &self.state, self.year_cursor);
+            }
+            AppScreen::Week => {
+                screens::render_week_screen(f, &self.state, self.week_cursor);
+            }
+            AppScreen::Month => {
+                screens::render_month_screen(f, &self.state, self.month_cursor);
+            }
+            AppScreen::Manual => {
+                screens::render_manual_screen(
+                    f,
+                    &manual::lines(&self.keymap),
+                    self.manual_scroll,
+                    &self.state.theme,
+                );
+            }
+            AppScreen::Settings => {
+                screens::render_settings_screen(f, &self.state, self.settings_theme);
+            }
+            AppScreen::SaveErrors => {
+                screens::render_save_errors_screen(f, &self.state, self.save_errors_selected);
+            }
+            AppScreen::Gear => {
+                screens::render_gear_screen(
+                    f,
+                    &self.state,
+                    self.gear_selected,
+                    self.naming_gear
+                        .then_some(self.input_handler.input_buffer.as_str()),
+                );
+            }
+            AppScreen::EditRoute => {
+                screens::render_edit_route_screen(
                     f,
                     &self.state,
                     &mut self.food_list_state,
@@ -942,169 +4116,198 @@ impl App {
                     &self.sync_status,
                     &self.input_handler.input_buffer,
                     self.input_handler.cursor_position,
+                    &self.input_handler.suggestions,
+                    self.input_handler.suggestion_selected,
                 );
             }
-            AppScreen::EditFood(_) => {
-                screens::render_edit_food_screen(
+            AppScreen::EditReflection(reflection) => {
+                screens::render_edit_reflection_screen(
                     f,
                     &self.state,
                     &mut self.food_list_state,
                     &mut self.sokay_list_state,
                     &self.sync_status,
+                    reflection,
                     &self.input_handler.input_buffer,
                     self.input_handler.cursor_position,
                 );
             }
-            AppScreen::AddSokay => {
-                screens::render_add_sokay_screen(
+            AppScreen::Routes => {
+                screens::render_routes_screen(f, &self.state, self.routes_selected);
+            }
+            AppScreen::Strength => {
+                screens::render_strength_screen(f, &self.state, self.strength_selected);
+            }
+            AppScreen::BestEfforts => {
+                screens::render_best_efforts_screen(
                     f,
                     &self.state,
-                    &mut self.food_list_state,
-                    &mut self.sokay_list_state,
-                    &self.sync_status,
-                    &self.input_handler.input_buffer,
-                    self.input_handler.cursor_position,
+                    self.best_efforts_focus,
+                    self.best_efforts_selected,
                 );
             }
-            AppScreen::EditSokay(_) => {
-                screens::render_edit_sokay_screen(
+            AppScreen::Backfill => {
+                screens::render_backfill_screen(
                     f,
                     &self.state,
-                    &mut self.food_list_state,
-                    &mut self.sokay_list_state,
+                    &mut self.list_state,
                     &self.sync_status,
+                    &self.backfill,
                     &self.input_handler.input_buffer,
                     self.input_handler.cursor_position,
                 );
             }
-            AppScreen::InputField(field_type) => {
-                use crate::models::field_accessor::FieldType;
-                match field_type {
-                    // Numeric fields edit in place inside their daily-view row.
-                    FieldType::Weight
-                    | FieldType::Waist
-                    | FieldType::Miles
-                    | FieldType::Elevation => {
-                        let edit = screens::InPlaceEdit {
-                            field: field_type,
-                            buffer: &self.input_handler.input_buffer,
-                            cursor: self.input_handler.cursor_position,
-                        };
-                        screens::render_daily_view_screen(
-                            f,
-                            &self.state,
-                            &mut self.food_list_state,
-                            &mut self.sokay_list_state,
-                            &self.sync_status,
-                            Some(edit),
-                            None,
-                        );
-                    }
-                    FieldType::StrengthMobility => screens::render_edit_strength_mobility_screen(
-                        f,
-                        &self.state,
-                        &mut self.food_list_state,
-                        &mut self.sokay_list_state,
-                        &self.sync_status,
-                        &self.input_handler.input_buffer,
-                        self.input_handler.cursor_position,
-                    ),
-                    FieldType::Notes => screens::render_edit_notes_screen(
-                        f,
-                        &self.state,
-                        &mut self.food_list_state,
-                        &mut self.sokay_list_state,
-                        &self.sync_status,
-                        &self.input_handler.input_buffer,
-                        self.input_handler.cursor_position,
-                    ),
-                }
+            AppScreen::Injuries => {
+                screens::render_injuries_screen(
+                    f,
+                    &self.state,
+                    self.injuries_selected,
+                    self.injury_draft
+                        .map(|draft| (draft, self.input_handler.input_buffer.as_str())),
+                );
             }
-            AppScreen::ConfirmDelete(target) => {
-                use crate::models::DeleteTarget;
-                match target {
-                    DeleteTarget::Day => {
-                        screens::render_confirm_delete_day_screen(f, self.state.selected_date);
-                    }
-                    DeleteTarget::Food(food_index) => {
-                        screens::render_confirm_delete_food_screen(
-                            f,
-                            &self.state,
-                            &mut self.food_list_state,
-                            &mut self.sokay_list_state,
-                            &self.sync_status,
-                            food_index,
-                        );
-                    }
-                    DeleteTarget::Sokay(sokay_index) => {
-                        screens::render_confirm_delete_sokay_screen(
-                            f,
-                            &self.state,
-                            &mut self.food_list_state,
-                            &mut self.sokay_list_state,
-                            &self.sync_status,
-                            sokay_index,
-                        );
-                    }
-                }
+            AppScreen::Phases => {
+                screens::render_phases_screen(
+                    f,
+                    &self.state,
+                    self.phases_selected,
+                    self.phase_draft
+                        .map(|draft| (draft, self.input_handler.input_buffer.as_str())),
+                );
             }
-            AppScreen::DateInput => {
-                screens::render_date_input_screen(
+            AppScreen::Goals => {
+                screens::render_goals_screen(
                     f,
                     &self.state,
-                    &mut self.list_state,
+                    self.goals_selected,
+                    self.goal_draft
+                        .map(|draft| (draft, self.input_handler.input_buffer.as_str())),
+                );
+            }
+            AppScreen::OcrImage => {
+                screens::render_ocr_image_screen(
+                    f,
+                    &self.state,
+                    &mut self.food_list_state,
+                    &mut self.sokay_list_state,
                     &self.sync_status,
                     &self.input_handler.input_buffer,
                     self.input_handler.cursor_position,
+                    self.ocr_reading.is_some(),
                 );
             }
-            AppScreen::ShortcutsHelp => {
-                screens::render_shortcuts_help_screen(
+            AppScreen::OcrReview => {
+                screens::render_ocr_review_screen(
                     f,
                     &self.state,
                     &mut self.food_list_state,
                     &mut self.sokay_list_state,
                     &self.sync_status,
+                    &self.ocr_candidates,
+                    self.ocr_selected,
                 );
             }
-            AppScreen::ConfigSync => {
-                screens::render_config_sync_screen(
+            AppScreen::GearPicker => {
+                screens::render_gear_picker_screen(
                     f,
                     &self.state,
-                    &self.config_url_buffer,
-                    &self.config_token_buffer,
-                    self.config_sync_enabled,
-                    !self.config.sync.auth_token.is_empty(),
-                    Some(&mut self.click_targets),
+                    &mut self.food_list_state,
+                    &mut self.sokay_list_state,
+                    &self.sync_status,
+                    self.gear_picker_selected,
                 );
             }
-            AppScreen::Syncing => {
-                screens::render_syncing_screen(f, &self.sync_status);
+            AppScreen::Charts => {
+                screens::render_charts_screen(
+                    f,
+                    &self.state,
+                    self.chart_metric,
+                    self.chart_range,
+                    chrono::Local::now().date_naive(),
+                );
             }
         }
+
+        self.profiler.record_screen(
+            &self.state.current_screen,
+            screen_started.elapsed(),
+            profiling::allocations() - screen_allocations,
+        );
+
+        if let Some((position, date)) = self.tooltip
+            && matches!(
+                self.state.current_screen,
+                AppScreen::Home | AppScreen::Calendar
+            )
+            && let Some(log) = self.state.get_daily_log(date)
+        {
+            crate::ui::components::render_tooltip(
+                f,
+                position,
+                &date.format(" %a %b %-d, %Y ").to_string(),
+                &crate::ui::components::day_summary_lines(log, self.state.units),
+                &self.state.theme,
+            );
+        }
+
+        if let Some(concerns) = &self.quit_prompt {
+            screens::render_quit_confirmation(f, concerns, &self.state.theme);
+        }
+        if self.discard_prompt {
+            screens::render_discard_confirmation(f, &self.state.theme);
+        }
+
+        if let Some(toast) = &self.state.toast {
+            crate::ui::components::render_toast(f, &toast.message, &self.state.theme);
+        }
+
+        if self.profiler.enabled {
+            crate::ui::components::render_profiler(f, &self.profiler, &self.state.theme);
+        }
+
+        if self.ascii_glyphs {
+            crate::ui::glyphs::asciify(f.buffer_mut());
+        }
+    }
+
+    /// Month of the selected row on the logged days list, header or day.
+    fn selected_month(&self) -> Option<chrono::NaiveDate> {
+        match self.state.home_list().get(self.list_state.selected()?)? {
+            HomeRow::Month { month, .. } => Some(*month),
+            HomeRow::Day(pos) => Some(month_start(self.state.all_daily_logs()[*pos].date)),
+        }
+    }
+
+    /// Moves the selection to `month`'s header, e.g. after folding it away
+    /// from under the selection.
+    fn select_month_header(&mut self, month: chrono::NaiveDate) {
+        let row = self.state.home_list().iter().position(
+            |row| matches!(row, HomeRow::Month { month: header, .. } if *header == month),
+        );
+        self.list_state.select(row);
     }
 
     fn move_selection_down(&mut self) {
-        if self.list_state.selected().is_none() && !self.state.daily_logs.is_empty() {
-            self.list_state.select(Some(0));
+        let list = self.state.home_list();
+        let rows = list.len();
+        if self.list_state.selected().is_none() && rows > 0 {
+            // Start on the newest day rather than its month's header
+            let first_day = list.iter().position(|row| matches!(row, HomeRow::Day(_)));
+            self.list_state.select(Some(first_day.unwrap_or(0)));
         } else {
-            let new_selection = NavigationHandler::move_selection_down(
-                self.list_state.selected(),
-                self.state.daily_logs.len(),
-            );
+            let new_selection =
+                NavigationHandler::move_selection_down(self.list_state.selected(), rows);
             self.list_state.select(new_selection);
         }
     }
 
     fn move_selection_up(&mut self) {
-        if self.list_state.selected().is_none() && !self.state.daily_logs.is_empty() {
-            self.list_state
-                .select(Some(self.state.daily_logs.len() - 1));
+        let rows = self.state.home_list().len();
+        if self.list_state.selected().is_none() && rows > 0 {
+            self.list_state.select(Some(rows - 1));
         } else {
-            let new_selection = NavigationHandler::move_selection_up(
-                self.list_state.selected(),
-                self.state.daily_logs.len(),
-            );
+            let new_selection =
+                NavigationHandler::move_selection_up(self.list_state.selected(), rows);
             self.list_state.select(new_selection);
         }
     }
@@ -1174,16 +4377,76 @@ impl App {
     }
 
     fn handle_enter(&mut self) {
-        if let AppScreen::Home = self.state.current_screen {
-            ActionHandler::handle_home_enter(&mut self.state, self.list_state.selected());
+        if let AppScreen::Week = self.state.current_screen {
+            self.open_day(self.week_cursor);
+        } else if let AppScreen::Home = self.state.current_screen {
+            if self.list_state.selected().is_none() {
+                self.open_day(chrono::Local::now().date_naive());
+            } else {
+                ActionHandler::handle_home_enter(&mut self.state, self.list_state.selected());
+            }
+        }
+    }
+
+    /// Switches the daily view to `date`, creating the day from the configured
+    /// template when it has no log yet. The new day is kept in memory only and
+    /// persisted with the first edit, same as a blank day.
+    /// Starts on `date`'s daily view instead of the startup screen, for
+    /// `--date`. The startup screen stays a Ctrl+O away.
+    pub fn open_on(&mut self, date: chrono::NaiveDate) {
+        self.open_day(date);
+        self.record_jump();
+    }
+
+    fn open_day(&mut self, date: chrono::NaiveDate) {
+        if self.state.get_daily_log(date).is_none() {
+            self.state
+                .insert_daily_log(self.config.template.new_day(date));
+            if self.config.sync.sync_on_new_day
+                && self.config.sync.is_configured()
+                && date == chrono::Local::now().date_naive()
+            {
+                self.pending_day_sync = Some(date);
+                self.state.show_toast("Syncing before the new day...".to_string());
+            }
         }
+        self.state.selected_date = date;
+        self.state.current_screen = AppScreen::DailyView;
+    }
+
+    /// Adds the current screen to the jump list if it's a place worth returning to.
+    fn record_jump(&mut self) {
+        let location = match self.state.current_screen {
+            AppScreen::Startup => JumpLocation::Startup,
+            AppScreen::Home => JumpLocation::Home,
+            AppScreen::Statistics => JumpLocation::Statistics,
+            AppScreen::DailyView => JumpLocation::Day(self.state.selected_date),
+            _ => return,
+        };
+        self.jump_list.record(location);
+    }
+
+    fn jump_to(&mut self, location: JumpLocation) {
+        self.state.current_screen = match location {
+            JumpLocation::Startup => AppScreen::Startup,
+            JumpLocation::Home => AppScreen::Home,
+            JumpLocation::Statistics => AppScreen::Statistics,
+            JumpLocation::Day(date) => {
+                self.state.selected_date = date;
+                self.food_list_state.select(None);
+                self.sokay_list_state.select(None);
+                self.state.food_list_focused = false;
+                self.state.sokay_list_focused = false;
+                AppScreen::DailyView
+            }
+        };
     }
 
     fn strength_mobility_max_scroll(&self) -> u16 {
         let text = self
             .state
             .get_daily_log(self.state.selected_date)
-            .and_then(|l| l.strength_mobility.clone())
+            .and_then(|l| l.strength_text(self.state.units))
             .unwrap_or_default();
         screens::max_scroll_offset(&text, self.state.frame_width, self.state.frame_height)
     }
@@ -1208,22 +4471,20 @@ impl App {
             AppScreen::ShortcutsHelp => {
                 self.state.current_screen = AppScreen::DailyView;
             }
+            AppScreen::Week => {
+                self.state.current_screen = self.week_return.clone();
+            }
+            AppScreen::Month => {
+                self.state.current_screen = self.month_return.clone();
+            }
             AppScreen::DailyView => match self.state.focused_section {
-                FocusedSection::FoodItems => {
-                    if self.state.food_list_focused {
-                        self.state.food_list_focused = false;
-                        self.food_list_state.select(None);
-                    } else {
-                        self.state.current_screen = AppScreen::Home;
-                    }
+                FocusedSection::FoodItems if self.state.food_list_focused => {
+                    self.state.food_list_focused = false;
+                    self.food_list_state.select(None);
                 }
-                FocusedSection::Sokay => {
-                    if self.state.sokay_list_focused {
-                        self.state.sokay_list_focused = false;
-                        self.sokay_list_state.select(None);
-                    } else {
-                        self.state.current_screen = AppScreen::Home;
-                    }
+                FocusedSection::Sokay if self.state.sokay_list_focused => {
+                    self.state.sokay_list_focused = false;
+                    self.sokay_list_state.select(None);
                 }
                 _ => {
                     self.state.current_screen = AppScreen::Home;
@@ -1239,9 +4500,10 @@ impl App {
         }
 
         if let Some(selected_index) = self.food_list_state.selected()
-            && let Some(current_name) = ActionHandler::start_edit_food(&self.state, selected_index)
+            && let Some(entry) = ActionHandler::start_edit_food(&self.state, selected_index)
         {
-            self.input_handler.set_input(current_name);
+            self.food_form = FoodForm::from_entry(&entry);
+            self.input_handler.set_input(entry.name);
             self.state.current_screen = AppScreen::EditFood(selected_index);
         }
     }
@@ -1262,6 +4524,46 @@ impl App {
         self.handle_edit_field(FieldType::Waist);
     }
 
+    fn handle_edit_sleep(&mut self) {
+        use crate::models::field_accessor::FieldType;
+        self.handle_edit_field(FieldType::Sleep);
+    }
+
+    fn handle_edit_resting_hr(&mut self) {
+        use crate::models::field_accessor::FieldType;
+        self.handle_edit_field(FieldType::RestingHr);
+    }
+
+    fn handle_edit_hrv(&mut self) {
+        use crate::models::field_accessor::FieldType;
+        self.handle_edit_field(FieldType::Hrv);
+    }
+
+    fn handle_edit_duration(&mut self) {
+        use crate::models::field_accessor::FieldType;
+        self.handle_edit_field(FieldType::Duration);
+    }
+
+    fn handle_edit_start_time(&mut self) {
+        use crate::models::field_accessor::FieldType;
+        self.handle_edit_field(FieldType::StartTime);
+    }
+
+    fn handle_edit_rpe(&mut self) {
+        use crate::models::field_accessor::FieldType;
+        self.handle_edit_field(FieldType::Rpe);
+    }
+
+    fn handle_edit_avg_hr(&mut self) {
+        use crate::models::field_accessor::FieldType;
+        self.handle_edit_field(FieldType::AvgHr);
+    }
+
+    fn handle_edit_max_hr(&mut self) {
+        use crate::models::field_accessor::FieldType;
+        self.handle_edit_field(FieldType::MaxHr);
+    }
+
     fn handle_edit_strength_mobility(&mut self) {
         use crate::models::field_accessor::FieldType;
         self.handle_edit_field(FieldType::StrengthMobility);
@@ -1295,12 +4597,47 @@ impl App {
         }
     }
 
+    /// Archives the selected day on the logged days list, or brings an
+    /// archived one back.
+    fn toggle_archived_day(&mut self) {
+        let Some(log) = self
+            .list_state
+            .selected()
+            .and_then(|index| self.state.home_log(index))
+        else {
+            return;
+        };
+        let (date, archived) = (log.date, !log.archived);
+        if let Some(log) = self.state.set_archived(date, archived) {
+            self.persist(log);
+            self.state.show_toast(if archived {
+                format!(
+                    "Archived {} - A shows archived days, x brings it back",
+                    date.format("%b %d, %Y")
+                )
+            } else {
+                format!("{} is back in the lists and stats", date.format("%b %d, %Y"))
+            });
+            self.clamp_home_selection();
+        }
+    }
+
+    /// Keeps the logged days selection on the list after rows drop out.
+    fn clamp_home_selection(&mut self) {
+        let rows = self.state.home_list().len();
+        if let Some(selected) = self.list_state.selected()
+            && selected >= rows
+        {
+            self.list_state.select(rows.checked_sub(1));
+        }
+    }
+
     fn handle_delete_day_confirmation(&mut self) {
         use crate::models::DeleteTarget;
         if let Some(selected_index) = self.list_state.selected()
-            && selected_index < self.state.daily_logs.len()
+            && let Some(log) = self.state.home_log(selected_index)
         {
-            self.state.selected_date = self.state.daily_logs[selected_index].date;
+            self.state.selected_date = log.date;
             self.state.current_screen = AppScreen::ConfirmDelete(DeleteTarget::Day);
         }
     }
@@ -1345,11 +4682,7 @@ impl App {
                         }
                         self.state.current_screen = AppScreen::DailyView;
 
-                        let db_manager = Arc::clone(&self.db_manager);
-                        let file_manager = self.file_manager.clone();
-                        tokio::spawn(async move {
-                            ActionHandler::persist_daily_log(db_manager, &file_manager, log).await;
-                        });
+                        self.persist(log);
                     } else {
                         self.state.current_screen = AppScreen::DailyView;
                     }
@@ -1370,11 +4703,7 @@ impl App {
                         }
                         self.state.current_screen = AppScreen::DailyView;
 
-                        let db_manager = Arc::clone(&self.db_manager);
-                        let file_manager = self.file_manager.clone();
-                        tokio::spawn(async move {
-                            ActionHandler::persist_daily_log(db_manager, &file_manager, log).await;
-                        });
+                        self.persist(log);
                     } else {
                         self.state.current_screen = AppScreen::DailyView;
                     }
@@ -1399,49 +4728,250 @@ impl App {
     async fn reload_logs_if_needed(&mut self) -> Result<()> {
         if self.needs_reload.swap(false, Ordering::AcqRel) {
             let db = self.db_manager.read().await;
-            self.state.daily_logs = db.load_all_daily_logs().await?;
+            let logs = db.load_all_daily_logs().await?;
+            self.state.set_daily_logs(logs);
         }
         Ok(())
     }
 
+    /// Runs the `sync_on_new_day` sync: pulls from the cloud on its own
+    /// task, then reloads, so a log for the day made on another device
+    /// replaces the blank one. A failure is toasted and the logs in memory
+    /// are kept.
+    async fn sync_new_day_if_needed(&mut self) {
+        if let Some(date) = self.pending_day_sync.take() {
+            let db_manager = Arc::clone(&self.db_manager);
+            let task = tokio::spawn(async move { db_manager.read().await.sync_now().await });
+            self.day_sync = Some((date, task));
+        }
+        // Anything saved meanwhile lands before the reload reads it back
+        let Some((_, task)) = &self.day_sync else {
+            return;
+        };
+        if !task.is_finished() || *self.pending_saves.borrow() > 0 {
+            return;
+        }
+        let Some((date, task)) = self.day_sync.take() else {
+            return;
+        };
+        let synced = match task.await {
+            Ok(synced) => synced,
+            Err(err) => Err(err.into()),
+        };
+        if let Err(err) = synced {
+            self.state.show_toast(format!("Couldn't sync: {:#}", err));
+            return;
+        }
+        let logs = match self.db_manager.read().await.load_all_daily_logs().await {
+            Ok(logs) => logs,
+            Err(err) => {
+                self.state
+                    .show_toast(format!("Couldn't reload after syncing: {:#}", err));
+                return;
+            }
+        };
+        self.run_hook(Hook::PostSync);
+
+        self.state.set_daily_logs(logs);
+        if self.state.get_daily_log(date).is_some() {
+            self.state
+                .show_toast(format!("Pulled {} from another device", date.format("%B %d")));
+        } else {
+            // Still nothing saved anywhere; put the blank day back
+            self.state
+                .insert_daily_log(self.config.template.new_day(date));
+            self.state.toast = None;
+        }
+    }
+
     async fn update_sync_status(&mut self) {
         let db = self.db_manager.read().await;
         let state = db.get_connection_state().await;
 
-        self.sync_status = match state {
-            ConnectionState::Disconnected => "⚪ Offline".to_string(),
-            ConnectionState::Connected => "✓ Synced".to_string(),
-            ConnectionState::Error(_) => "⚠️ Sync Error".to_string(),
+        let (glyph, status) = match state {
+            ConnectionState::Disconnected => ("⚪", "Offline"),
+            ConnectionState::Connected => ("✓", "Synced"),
+            ConnectionState::Error(_) => ("⚠️", "Sync Error"),
+        };
+        // A screen reader would read the glyph's name before the word
+        self.sync_status = if self.state.screen_reader {
+            format!("({})", status)
+        } else {
+            format!("{} {}", glyph, status)
         };
+        // Saves are written in the background; say so until they land
+        let pending = *self.pending_saves.borrow();
+        if pending > 0 {
+            self.sync_status
+                .push_str(&format!(" | {}", writes_pending(pending)));
+        }
+        let failed = self.state.failed_saves.len();
+        if failed > 0 {
+            self.sync_status.push_str(&format!(
+                " | {} failed (!)",
+                if failed == 1 {
+                    "1 save".to_string()
+                } else {
+                    format!("{} saves", failed)
+                }
+            ));
+        }
     }
 
-    /// Performs shutdown sync and updates sync_status with result
-    pub async fn perform_shutdown_sync(&mut self) {
-        let db = self.db_manager.read().await;
-        let connection_state = db.get_connection_state().await;
+    /// Runs the `[session]` idle action once per idle stretch: always a
+    /// sync, then exiting, or locking (the privacy screen when there's no
+    /// passphrase to unlock with). The sync runs on its own task.
+    fn handle_idle(&mut self, idle_for: Duration) {
+        let session = &self.config.session;
+        if session.idle_minutes == 0
+            || self.idle_handled
+            || idle_for < Duration::from_secs(u64::from(session.idle_minutes) * 60)
+        {
+            return;
+        }
+        self.idle_handled = true;
+        match session.idle_action {
+            IdleAction::Exit => {
+                // There's no one to answer the quit prompt, so an edit left
+                // open is dropped as y would, rather than leaving the day
+                // unsynced overnight. The syncing screen waits for saves
+                // still in flight, syncs and then quits.
+                self.quit_anyway();
+                return;
+            }
+            IdleAction::Lock if self.lock.has_passphrase() => self.lock.locked = true,
+            IdleAction::Lock => self.private = true,
+            IdleAction::Sync => {}
+        }
+        let post_sync = self.config.hooks.command(Hook::PostSync).map(|command| {
+            let date = self.state.selected_date;
+            let payload = hooks::payload(Hook::PostSync, date, self.state.get_daily_log(date));
+            (command.to_string(), payload)
+        });
+        let db_manager = Arc::clone(&self.db_manager);
+        tokio::spawn(async move {
+            let db = db_manager.read().await;
+            if matches!(db.get_connection_state().await, ConnectionState::Connected) {
+                // A failure shows up in the sync indicator; there's no one
+                // here to tell otherwise
+                if db.sync_now().await.is_ok()
+                    && let Some((command, payload)) = post_sync
+                {
+                    hooks::spawn(command, Hook::PostSync, payload);
+                }
+            }
+        });
+    }
 
-        match connection_state {
-            ConnectionState::Connected => {
-                self.sync_status = "Syncing with Turso Cloud...".to_string();
-                drop(db);
+    /// Moves the shutdown sync along on each pass of the event loop: starts
+    /// it, picks up its result when the task finishes, and quits once the
+    /// result has been on screen for `SHUTDOWN_RESULT_SHOWN`.
+    async fn tick_shutdown_sync(&mut self) {
+        let Some(sync) = &mut self.shutdown_sync else {
+            self.shutdown_sync = Some(self.start_shutdown_sync());
+            return;
+        };
+        if let Some((_, quit_at)) = &sync.finished {
+            if Instant::now() >= *quit_at {
+                self.should_quit = true;
+            }
+            return;
+        }
+        if !sync.task.is_finished() {
+            if sync.started.elapsed() >= SHUTDOWN_SYNC_TIMEOUT {
+                let status = format!(
+                    "Sync timed out after {}s - the network may be down",
+                    SHUTDOWN_SYNC_TIMEOUT.as_secs()
+                );
+                self.skip_shutdown_sync(Some(status)).await;
+            }
+            return;
+        }
+        let status = match (&mut sync.task).await {
+            Ok(ShutdownOutcome::Synced(0)) => "Sync complete! Already up to date".to_string(),
+            Ok(ShutdownOutcome::Synced(pages)) => format!(
+                "Sync complete! {} {} synced",
+                pages,
+                if pages == 1 { "page" } else { "pages" }
+            ),
+            Ok(ShutdownOutcome::Offline) | Err(_) => SYNC_OFFLINE.to_string(),
+        };
+        let synced = status.starts_with("Sync complete");
+        sync.finished = Some((status, Instant::now() + SHUTDOWN_RESULT_SHOWN));
+        if synced {
+            let post_sync = self.run_hook(Hook::PostSync);
+            if let Some(sync) = &mut self.shutdown_sync {
+                sync.post_sync = post_sync;
+            }
+        }
+    }
 
-                let db = self.db_manager.read().await;
-                match db.sync_now().await {
-                    Ok(_) => {
-                        self.sync_status = "Sync complete!".to_string();
-                    }
-                    Err(_) => {
-                        self.sync_status =
-                            "Offline - changes will sync when network is available".to_string();
-                    }
-                }
+    /// Abandons the shutdown sync, noting it for the next startup. With a
+    /// `status` it's shown before quitting, as when the sync times out;
+    /// without one (Esc) the app quits straight away.
+    async fn skip_shutdown_sync(&mut self, status: Option<String>) {
+        if let Some(sync) = &self.shutdown_sync {
+            if sync.finished.is_some() {
+                // Nothing left to skip; Esc just stops waiting
+                self.should_quit = true;
+                return;
             }
-            _ => {
-                self.sync_status =
-                    "Offline - changes will sync when network is available".to_string();
+            sync.task.abort();
+        }
+        let _ = health::record_skipped_sync(&self.file_manager.health_path());
+        match (status, &mut self.shutdown_sync) {
+            (Some(status), Some(sync)) => {
+                sync.finished = Some((status, Instant::now() + SHUTDOWN_RESULT_SHOWN));
             }
+            _ => self.should_quit = true,
         }
+    }
 
-        self.should_quit = true;
+    /// Spawns the pre-quit hook and the sync after it.
+    fn start_shutdown_sync(&self) -> ShutdownSync {
+        let pre_quit = self.config.hooks.command(Hook::PreQuit).map(|command| {
+            let date = self.state.selected_date;
+            let payload = hooks::payload(Hook::PreQuit, date, self.state.get_daily_log(date));
+            (command.to_string(), payload)
+        });
+        let (stage, receiver) = tokio::sync::watch::channel(SYNC_STARTING);
+        let db_manager = Arc::clone(&self.db_manager);
+        let mut pending_saves = self.pending_saves.subscribe();
+        let shown_saves = pending_saves.clone();
+        let task = tokio::spawn(async move {
+            // Whatever was saved last should go up with this sync
+            if *pending_saves.borrow() > 0 {
+                let _ = stage.send(SYNC_FINISHING_SAVES);
+                let _ = pending_saves.wait_for(|&pending| pending == 0).await;
+            }
+            if let Some((command, payload)) = pre_quit {
+                let _ = stage.send("Running the pre-quit hook...");
+                // Quitting waits for this one, up to the hook timeout
+                let _ = tokio::task::spawn_blocking(move || {
+                    hooks::run(&command, Hook::PreQuit, &payload, hooks::HOOK_TIMEOUT)
+                })
+                .await;
+            }
+            let db = db_manager.read().await;
+            if !matches!(db.get_connection_state().await, ConnectionState::Connected) {
+                return ShutdownOutcome::Offline;
+            }
+            let _ = stage.send("Syncing with Turso Cloud...");
+            match db.sync_now().await {
+                Ok(pages) => ShutdownOutcome::Synced(pages),
+                Err(_) => ShutdownOutcome::Offline,
+            }
+        });
+        ShutdownSync {
+            started: Instant::now(),
+            stage: receiver,
+            pending_saves: shown_saves,
+            task,
+            finished: None,
+            post_sync: None,
+        }
     }
 }
+
+#[cfg(test)]
+mod tests;
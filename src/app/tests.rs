@@ -0,0 +1,1956 @@
+//! End-to-end tests that drive `App` with synthetic key events against a
+//! scratch data directory, the same way the event loop does.
+
+use super::*;
+use crate::models::field_accessor::FieldType;
+use crate::models::{DailyLog, DeleteTarget};
+use crossterm::event::{KeyEvent, KeyModifiers};
+use tempfile::TempDir;
+
+/// An `App` backed by a throwaway database and markdown directory.
+struct Harness {
+    app: App,
+    dir: TempDir,
+}
+
+impl Harness {
+    async fn new() -> Self {
+        Self::with_config(AppConfig::default()).await
+    }
+
+    async fn with_config(config: AppConfig) -> Self {
+        let dir = TempDir::new().unwrap();
+        let file_manager = FileManager::in_dir(dir.path().to_path_buf()).unwrap();
+        let app = App::with_storage(config, dir.path().to_path_buf(), file_manager)
+            .await
+            .unwrap();
+        Self { app, dir }
+    }
+
+    async fn press(&mut self, code: KeyCode) {
+        self.press_with(code, KeyModifiers::NONE).await;
+    }
+
+    async fn press_with(&mut self, code: KeyCode, modifiers: KeyModifiers) {
+        self.app
+            .handle_event(Event::Key(KeyEvent::new(code, modifiers)))
+            .await
+            .unwrap();
+    }
+
+    async fn type_text(&mut self, text: &str) {
+        for c in text.chars() {
+            self.press(KeyCode::Char(c)).await;
+        }
+    }
+
+    fn state(&self) -> &AppState {
+        &self.app.state
+    }
+
+    fn screen(&self) -> AppScreen {
+        self.app.state.current_screen.clone()
+    }
+
+    fn today(&self) -> Option<&DailyLog> {
+        self.state()
+            .get_daily_log(chrono::Local::now().date_naive())
+    }
+
+    /// Waits until no background save is pending, however many were
+    /// queued, rather than hoping one yield was enough.
+    async fn saves_finished(&self) {
+        let mut pending = self.app.pending_saves.subscribe();
+        pending.wait_for(|&saving| saving == 0).await.unwrap();
+    }
+
+    /// Waits for the background saves to finish, then reads the database
+    /// back.
+    async fn saved_logs(&self) -> Vec<DailyLog> {
+        self.saves_finished().await;
+        let db = self.app.db_manager.read().await;
+        db.load_all_daily_logs().await.unwrap()
+    }
+
+    /// Shift+J through the daily view until `section` has focus.
+    async fn focus(&mut self, section: FocusedSection) {
+        for _ in 0..6 {
+            if self.state().focused_section == section {
+                return;
+            }
+            self.press_with(KeyCode::Char('J'), KeyModifiers::SHIFT)
+                .await;
+        }
+        panic!("never reached {:?}", section);
+    }
+}
+
+#[tokio::test]
+async fn food_is_added_persisted_and_deleted_after_confirmation() {
+    let mut h = Harness::new().await;
+    h.press(KeyCode::Char('n')).await;
+    assert_eq!(h.screen(), AppScreen::DailyView);
+
+    h.press(KeyCode::Char('f')).await;
+    assert_eq!(h.screen(), AppScreen::AddFood);
+    h.type_text("Oatmeal").await;
+    h.press(KeyCode::Enter).await;
+
+    assert_eq!(h.screen(), AppScreen::DailyView);
+    assert_eq!(h.today().unwrap().food_entries[0].name, "Oatmeal");
+    let saved = h.saved_logs().await;
+    assert_eq!(saved.len(), 1);
+    assert_eq!(saved[0].food_entries[0].name, "Oatmeal");
+
+    h.focus(FocusedSection::FoodItems).await;
+    h.press(KeyCode::Char('j')).await;
+    h.press(KeyCode::Char('d')).await;
+    assert_eq!(h.screen(), AppScreen::ConfirmDelete(DeleteTarget::Food(0)));
+
+    // Declining keeps the entry
+    h.press(KeyCode::Char('n')).await;
+    assert_eq!(h.screen(), AppScreen::DailyView);
+    assert_eq!(h.today().unwrap().food_entries.len(), 1);
+
+    h.press(KeyCode::Char('d')).await;
+    h.press(KeyCode::Char('y')).await;
+    assert_eq!(h.screen(), AppScreen::DailyView);
+    assert!(h.today().unwrap().food_entries.is_empty());
+    assert!(h.saved_logs().await[0].food_entries.is_empty());
+}
+
+#[tokio::test]
+async fn add_food_suggests_frequent_foods_as_you_type() {
+    let mut h = Harness::new().await;
+    let earlier = chrono::Local::now().date_naive() - chrono::Days::new(3);
+    h.app.state.set_daily_logs(vec![DailyLog {
+        food_entries: ["Oatmeal", "Oat milk", "Oatmeal"]
+            .into_iter()
+            .map(|name| crate::models::FoodEntry::new(name.to_string()))
+            .collect(),
+        ..DailyLog::new(earlier)
+    }]);
+    h.press(KeyCode::Char('n')).await;
+    h.press(KeyCode::Char('f')).await;
+    h.type_text("oa").await;
+    assert_eq!(h.app.input_handler.suggestions, vec!["Oatmeal", "Oat milk"]);
+
+    h.press(KeyCode::Down).await;
+    h.press(KeyCode::Down).await;
+    h.press(KeyCode::Enter).await;
+    assert_eq!(h.screen(), AppScreen::DailyView);
+    assert_eq!(h.today().unwrap().food_entries[0].name, "Oat milk");
+
+    // Tab fills in the only match to keep editing
+    h.press(KeyCode::Char('f')).await;
+    h.type_text("oat m").await;
+    h.press(KeyCode::Tab).await;
+    assert_eq!(h.app.input_handler.input_buffer, "Oat milk");
+    h.type_text(" latte").await;
+    h.press(KeyCode::Enter).await;
+    assert_eq!(h.today().unwrap().food_entries[1].name, "Oat milk latte");
+}
+
+#[tokio::test]
+async fn food_form_takes_a_quantity_and_calories() {
+    let mut h = Harness::new().await;
+    h.press(KeyCode::Char('n')).await;
+    h.press(KeyCode::Char('f')).await;
+    h.type_text("Oatmeal").await;
+    h.press(KeyCode::Tab).await;
+    h.type_text("1 cup").await;
+    h.press(KeyCode::Tab).await;
+    // Calories only take digits
+    h.type_text("35o0").await;
+    h.press(KeyCode::Enter).await;
+
+    let entry = &h.today().unwrap().food_entries[0];
+    assert_eq!(entry.label(), "Oatmeal (1 cup, 350 cal)");
+    assert_eq!(h.saved_logs().await[0].calories(), Some(350));
+
+    // Editing starts from the saved fields and keeps the ones left alone
+    h.focus(FocusedSection::FoodItems).await;
+    h.press(KeyCode::Char('j')).await;
+    h.press(KeyCode::Char('e')).await;
+    assert_eq!(h.screen(), AppScreen::EditFood(0));
+    h.press(KeyCode::BackTab).await;
+    assert_eq!(h.app.input_handler.input_buffer, "350");
+    h.press(KeyCode::Backspace).await;
+    h.press(KeyCode::Backspace).await;
+    h.press(KeyCode::Backspace).await;
+    h.type_text("400").await;
+    h.press(KeyCode::Enter).await;
+    assert_eq!(
+        h.today().unwrap().food_entries[0].label(),
+        "Oatmeal (1 cup, 400 cal)"
+    );
+}
+
+#[tokio::test]
+async fn a_barcode_fills_the_food_form_from_the_cache() {
+    let mut h = Harness::new().await;
+    std::fs::write(
+        h.dir.path().join("barcodes.json"),
+        r#"{"0722252100900": {"name": "Energy Bar", "quantity": "1 bar", "calories": 250,
+            "macros": {"protein": 9.0, "carbs": 45.0, "fat": 5.0}}}"#,
+    )
+    .unwrap();
+    h.press(KeyCode::Char('n')).await;
+    h.press(KeyCode::Char('f')).await;
+    h.type_text("0722252100900").await;
+    h.press(KeyCode::Enter).await;
+    assert_eq!(h.app.barcode_lookup.as_deref(), Some("0722252100900"));
+    while h.app.barcode_lookup.is_some() {
+        tokio::task::yield_now().await;
+        h.app.collect_barcode();
+    }
+    // The form stays open with the product filled in, to check first
+    assert_eq!(h.screen(), AppScreen::AddFood);
+    assert_eq!(h.app.input_handler.input_buffer, "Energy Bar");
+    h.press(KeyCode::Enter).await;
+    assert_eq!(
+        h.today().unwrap().food_entries[0].label(),
+        "Energy Bar (1 bar, 250 cal, 9g protein, 45g carbs, 5g fat)"
+    );
+    assert_eq!(h.saved_logs().await[0].food_entries, h.today().unwrap().food_entries);
+}
+
+#[tokio::test]
+async fn plus_and_minus_add_and_take_back_glasses_of_water() {
+    let mut h = Harness::new().await;
+    h.press(KeyCode::Char('n')).await;
+    // Nothing to take back yet
+    h.press(KeyCode::Char('-')).await;
+    assert_eq!(h.today().and_then(|log| log.water), None);
+
+    h.press(KeyCode::Char('+')).await;
+    h.press(KeyCode::Char('+')).await;
+    h.press(KeyCode::Char('-')).await;
+    assert_eq!(h.today().unwrap().water, Some(8.0));
+    h.press(KeyCode::Char('-')).await;
+    assert_eq!(h.today().unwrap().water, None);
+
+    h.press(KeyCode::Char('+')).await;
+    assert_eq!(h.saved_logs().await[0].water, Some(8.0));
+}
+
+#[tokio::test]
+async fn number_keys_rate_the_days_energy_and_repeat_to_clear() {
+    let mut h = Harness::new().await;
+    h.press(KeyCode::Char('n')).await;
+    h.press(KeyCode::Char('4')).await;
+    assert_eq!(h.today().unwrap().energy, Some(4));
+    h.press(KeyCode::Char('2')).await;
+    assert_eq!(h.saved_logs().await[0].energy, Some(2));
+
+    h.press(KeyCode::Char('2')).await;
+    assert_eq!(h.today().unwrap().energy, None);
+    // Out of range keys do nothing
+    h.press(KeyCode::Char('7')).await;
+    assert_eq!(h.today().unwrap().energy, None);
+}
+
+#[tokio::test]
+async fn shift_a_cycles_the_days_activity_type() {
+    use crate::models::activity::ActivityType;
+    let mut h = Harness::new().await;
+    h.press(KeyCode::Char('n')).await;
+    h.press_with(KeyCode::Char('A'), KeyModifiers::SHIFT).await;
+    h.press_with(KeyCode::Char('A'), KeyModifiers::SHIFT).await;
+    assert_eq!(h.today().unwrap().activity, Some(ActivityType::Hike));
+    assert_eq!(h.saved_logs().await[0].activity, Some(ActivityType::Hike));
+
+    for _ in 0..4 {
+        h.press_with(KeyCode::Char('A'), KeyModifiers::SHIFT).await;
+    }
+    assert_eq!(h.today().unwrap().activity, None);
+}
+
+#[tokio::test]
+async fn shift_e_rates_the_runs_effort_in_its_own_modal() {
+    let mut h = Harness::new().await;
+    h.press(KeyCode::Char('n')).await;
+    h.press_with(KeyCode::Char('E'), KeyModifiers::SHIFT).await;
+    assert_eq!(h.screen(), AppScreen::InputField(FieldType::Rpe));
+    h.type_text("8").await;
+    h.press(KeyCode::Enter).await;
+
+    assert_eq!(h.screen(), AppScreen::DailyView);
+    assert_eq!(
+        h.state().focused_section,
+        FocusedSection::Running {
+            focused_field: RunningField::AvgHr
+        }
+    );
+    assert_eq!(h.saved_logs().await[0].rpe, Some(8));
+}
+
+#[tokio::test]
+async fn shift_h_and_shift_x_take_the_runs_heart_rate() {
+    let mut h = Harness::new().await;
+    h.press(KeyCode::Char('n')).await;
+    h.press_with(KeyCode::Char('H'), KeyModifiers::SHIFT).await;
+    assert_eq!(h.screen(), AppScreen::InputField(FieldType::AvgHr));
+    h.type_text("146").await;
+    h.press(KeyCode::Enter).await;
+    h.press_with(KeyCode::Char('X'), KeyModifiers::SHIFT).await;
+    h.type_text("171").await;
+    h.press(KeyCode::Enter).await;
+
+    assert_eq!(h.state().focused_section, FocusedSection::FoodItems);
+    let logs = h.saved_logs().await;
+    assert_eq!((logs[0].avg_hr, logs[0].max_hr), (Some(146), Some(171)));
+}
+
+#[tokio::test]
+async fn escape_discards_a_food_entry_in_progress() {
+    let mut h = Harness::new().await;
+    h.press(KeyCode::Char('n')).await;
+    h.press(KeyCode::Char('f')).await;
+    h.type_text("Pizza").await;
+    h.press(KeyCode::Esc).await;
+
+    assert_eq!(h.screen(), AppScreen::DailyView);
+    assert!(h.today().unwrap().food_entries.is_empty());
+    assert!(h.app.input_handler.input_buffer.is_empty());
+}
+
+#[tokio::test]
+async fn weight_entry_saves_and_far_off_values_ask_first() {
+    let mut h = Harness::new().await;
+    h.press(KeyCode::Char('n')).await;
+
+    h.press(KeyCode::Char('w')).await;
+    assert_eq!(h.screen(), AppScreen::InputField(FieldType::Weight));
+    h.type_text("172.5").await;
+    h.press(KeyCode::Enter).await;
+    assert_eq!(h.today().unwrap().weight, Some(172.5));
+    assert_eq!(h.saved_logs().await[0].weight, Some(172.5));
+
+    // Backdate the first weigh-in so today is checked against it
+    let today = chrono::Local::now().date_naive();
+    let mut yesterday = h.today().unwrap().clone();
+    yesterday.date = today.pred_opt().unwrap();
+    h.app.state.remove_daily_log(today);
+    h.app.state.insert_daily_log(yesterday);
+    h.app.open_day(today);
+
+    h.press(KeyCode::Char('w')).await;
+    h.type_text("127.5").await;
+    h.press(KeyCode::Enter).await;
+    assert_eq!(h.screen(), AppScreen::ConfirmValue(FieldType::Weight));
+
+    h.press(KeyCode::Char('n')).await;
+    assert_eq!(h.screen(), AppScreen::InputField(FieldType::Weight));
+    h.press(KeyCode::Enter).await;
+    h.press(KeyCode::Char('y')).await;
+    assert_eq!(h.screen(), AppScreen::DailyView);
+    assert_eq!(h.today().unwrap().weight, Some(127.5));
+}
+
+#[tokio::test]
+async fn day_is_deleted_from_the_log_list_after_confirmation() {
+    let mut h = Harness::new().await;
+    h.press(KeyCode::Char('n')).await;
+    h.press(KeyCode::Char('m')).await;
+    h.type_text("5").await;
+    h.press(KeyCode::Enter).await;
+    assert_eq!(h.saved_logs().await.len(), 1);
+
+    h.press_with(KeyCode::Char('S'), KeyModifiers::SHIFT).await;
+    h.press(KeyCode::Char('l')).await;
+    assert_eq!(h.screen(), AppScreen::Home);
+    h.press(KeyCode::Char('j')).await;
+    h.press(KeyCode::Char('d')).await;
+    assert_eq!(h.screen(), AppScreen::ConfirmDelete(DeleteTarget::Day));
+    h.press(KeyCode::Char('y')).await;
+
+    assert_eq!(h.screen(), AppScreen::Home);
+    assert!(h.state().daily_logs().is_empty());
+    assert!(h.saved_logs().await.is_empty());
+}
+
+#[tokio::test]
+async fn jump_list_walks_back_through_visited_screens() {
+    let mut h = Harness::new().await;
+    h.press(KeyCode::Char('n')).await;
+    h.press(KeyCode::Esc).await;
+    assert_eq!(h.screen(), AppScreen::Home);
+
+    h.press_with(KeyCode::Char('o'), KeyModifiers::CONTROL)
+        .await;
+    assert_eq!(h.screen(), AppScreen::DailyView);
+    h.press_with(KeyCode::Char('o'), KeyModifiers::CONTROL)
+        .await;
+    assert_eq!(h.screen(), AppScreen::Startup);
+    h.press_with(KeyCode::Char('i'), KeyModifiers::CONTROL)
+        .await;
+    assert_eq!(h.screen(), AppScreen::DailyView);
+}
+
+#[tokio::test]
+async fn week_view_steps_between_weeks_and_opens_days() {
+    let mut h = Harness::new().await;
+    let today = chrono::Local::now().date_naive();
+    h.press(KeyCode::Char('n')).await;
+    h.press_with(KeyCode::Char('W'), KeyModifiers::SHIFT).await;
+    assert_eq!(h.screen(), AppScreen::Week);
+    assert_eq!(h.app.week_cursor, today);
+
+    // Nothing after today to step into
+    h.press(KeyCode::Char(']')).await;
+    assert_eq!(h.app.week_cursor, today);
+
+    h.press(KeyCode::Char('[')).await;
+    let last_week = today - chrono::Duration::days(7);
+    assert_eq!(h.app.week_cursor, last_week);
+    h.press(KeyCode::Enter).await;
+    assert_eq!(h.screen(), AppScreen::DailyView);
+    assert_eq!(h.state().selected_date, last_week);
+
+    h.press_with(KeyCode::Char('W'), KeyModifiers::SHIFT).await;
+    h.press(KeyCode::Esc).await;
+    assert_eq!(h.screen(), AppScreen::DailyView);
+}
+
+#[tokio::test]
+async fn month_summary_steps_back_but_not_past_this_month() {
+    let mut h = Harness::new().await;
+    let this_month = crate::month_stats::month_start(chrono::Local::now().date_naive());
+    h.press_with(KeyCode::Char('M'), KeyModifiers::SHIFT).await;
+    assert_eq!(h.screen(), AppScreen::Month);
+
+    h.press(KeyCode::Char(']')).await;
+    assert_eq!(h.app.month_cursor, this_month);
+    h.press(KeyCode::Char('[')).await;
+    assert_eq!(
+        Some(h.app.month_cursor),
+        crate::month_stats::shift_month(this_month, -1)
+    );
+
+    h.press(KeyCode::Esc).await;
+    assert_eq!(h.screen(), AppScreen::Startup);
+}
+
+#[tokio::test]
+async fn key_releases_are_ignored_and_shift_letters_normalized() {
+    let mut h = Harness::new().await;
+    h.press(KeyCode::Char('n')).await;
+    assert_eq!(h.screen(), AppScreen::DailyView);
+
+    // Windows reports a release after every press; it must not act twice
+    let mut release = KeyEvent::new(KeyCode::Char('J'), KeyModifiers::SHIFT);
+    release.kind = crossterm::event::KeyEventKind::Release;
+    h.app.handle_event(Event::Key(release)).await.unwrap();
+    assert_eq!(
+        h.state().focused_section,
+        FocusedSection::Measurements {
+            focused_field: crate::models::MeasurementField::Weight
+        }
+    );
+
+    // Shift+j as reported with keyboard enhancement moves focus like Shift+J
+    h.press_with(KeyCode::Char('j'), KeyModifiers::SHIFT).await;
+    assert_eq!(
+        h.state().focused_section,
+        FocusedSection::Running {
+            focused_field: crate::models::RunningField::Miles
+        }
+    );
+}
+
+#[tokio::test]
+async fn dvorak_preset_swaps_command_keys_but_not_typed_text() {
+    let mut config = AppConfig::default();
+    config.keys.preset = crate::events::keys::KeymapPreset::Dvorak;
+    let mut h = Harness::with_config(config).await;
+    // n moved to l, since n now moves right
+    h.press(KeyCode::Char('l')).await;
+    assert_eq!(h.screen(), AppScreen::DailyView);
+
+    // Shift+H is Shift+J on Dvorak, then n acts as l (edit elevation)
+    h.press_with(KeyCode::Char('H'), KeyModifiers::SHIFT).await;
+    h.press(KeyCode::Char('n')).await;
+    assert_eq!(h.screen(), AppScreen::InputField(FieldType::Elevation));
+    h.press(KeyCode::Esc).await;
+
+    // Inside a text field letters are never swapped
+    h.press(KeyCode::Char('f')).await;
+    h.type_text("hot tea").await;
+    h.press(KeyCode::Enter).await;
+    assert_eq!(h.today().unwrap().food_entries[0].name, "hot tea");
+
+    // The lists edited in place swap too, except while a name is typed
+    for screen in [
+        AppScreen::Gear,
+        AppScreen::GearPicker,
+        AppScreen::Goals,
+        AppScreen::Phases,
+        AppScreen::Injuries,
+    ] {
+        assert!(screen.takes_commands(), "{screen:?}");
+    }
+    h.press(KeyCode::Esc).await;
+    h.press(KeyCode::Char('G')).await;
+    assert_eq!(h.screen(), AppScreen::Gear);
+    for name in ["Speedgoat", "Hoka Mafate"] {
+        h.press(KeyCode::Char('a')).await;
+        h.type_text(name).await;
+        h.press(KeyCode::Enter).await;
+    }
+    let mut names: Vec<_> = h.state().gear.iter().map(|g| g.name.clone()).collect();
+    names.sort();
+    assert_eq!(names, ["Hoka Mafate", "Speedgoat"]);
+    h.app.gear_selected = 0;
+    h.press(KeyCode::Char('h')).await;
+    assert_eq!(h.app.gear_selected, 1);
+}
+
+#[tokio::test]
+async fn dvorak_answers_the_quit_prompt_with_n_as_typed() {
+    let mut config = AppConfig::default();
+    config.keys.preset = crate::events::keys::KeymapPreset::Dvorak;
+    let mut h = Harness::with_config(config).await;
+    h.press(KeyCode::Char('l')).await;
+    h.press(KeyCode::Char('+')).await;
+    h.press(KeyCode::Char('q')).await;
+    assert!(h.app.quit_prompt.is_some());
+    // n would be l on the daily view, but the prompt takes it as no
+    h.press(KeyCode::Char('n')).await;
+    assert_eq!(h.app.quit_prompt, None);
+    assert_eq!(h.screen(), AppScreen::DailyView);
+}
+
+#[tokio::test]
+async fn f1_opens_the_manual_and_returns_where_it_was() {
+    let mut h = Harness::new().await;
+    h.app.state.frame_height = 30;
+    h.press(KeyCode::Char('n')).await;
+    h.press(KeyCode::F(1)).await;
+    assert_eq!(h.screen(), AppScreen::Manual);
+
+    h.press(KeyCode::Char('j')).await;
+    h.press(KeyCode::PageDown).await;
+    assert_eq!(h.app.manual_scroll, 1 + screens::manual_page_height(30));
+    h.press(KeyCode::Char('k')).await;
+    assert_eq!(h.app.manual_scroll, screens::manual_page_height(30));
+
+    h.press(KeyCode::Esc).await;
+    assert_eq!(h.screen(), AppScreen::DailyView);
+
+    // Text fields keep F1 to themselves
+    h.press(KeyCode::Char('f')).await;
+    h.press(KeyCode::F(1)).await;
+    assert_eq!(h.screen(), AppScreen::AddFood);
+}
+
+/// Keeps what's copied for the test to read, in place of the system
+/// clipboard.
+#[derive(Clone, Default)]
+struct FakeClipboard(Arc<std::sync::Mutex<Vec<String>>>);
+
+impl crate::platform::Clipboard for FakeClipboard {
+    fn copy(&mut self, text: &str) -> Result<()> {
+        self.0.lock().unwrap().push(text.to_string());
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn y_copies_the_day_as_markdown_or_says_there_is_nothing() {
+    let mut h = Harness::new().await;
+    let clipboard = FakeClipboard::default();
+    h.app.clipboard = Box::new(clipboard.clone());
+    let today = chrono::Local::now().date_naive();
+
+    // The blank new day isn't anything to copy yet
+    h.press(KeyCode::Char('n')).await;
+    h.app.state.remove_daily_log(today);
+    h.press(KeyCode::Char('y')).await;
+    let toast = &h.state().toast.as_ref().unwrap().message;
+    assert_eq!(*toast, format!("Nothing logged for {}", today.format("%B %d")));
+    assert!(clipboard.0.lock().unwrap().is_empty());
+
+    let mut log = DailyLog::new(today);
+    log.notes = Some("Ridge loop in the fog".to_string());
+    h.app.state.insert_daily_log(log.clone());
+    h.press(KeyCode::Char('y')).await;
+    let copied = clipboard.0.lock().unwrap().clone();
+    assert_eq!(copied, vec![h.app.file_manager.daily_log_to_markdown(&log)]);
+    assert!(copied[0].contains("Ridge loop in the fog"));
+    let toast = &h.state().toast.as_ref().unwrap().message;
+    assert_eq!(*toast, format!("Copied {} as markdown", today.format("%B %d")));
+    assert_eq!(h.screen(), AppScreen::DailyView);
+}
+
+#[tokio::test]
+async fn settings_preview_themes_and_esc_restores_the_saved_one() {
+    let mut h = Harness::new().await;
+    h.press(KeyCode::Char(',')).await;
+    assert_eq!(h.screen(), AppScreen::Settings);
+
+    h.press(KeyCode::Char('k')).await;
+    assert_eq!(h.app.settings_theme, ThemeName::HighContrast);
+    assert_eq!(h.state().theme, Theme::new(ThemeName::HighContrast));
+
+    h.press(KeyCode::Esc).await;
+    assert_eq!(h.screen(), AppScreen::Startup);
+    assert_eq!(h.state().theme, Theme::default());
+}
+
+#[tokio::test]
+async fn p_saves_a_share_card_into_exports() {
+    let mut h = Harness::new().await;
+    h.press(KeyCode::Char('n')).await;
+    h.press(KeyCode::Char('p')).await;
+
+    let date = chrono::Local::now().date_naive();
+    let path = h
+        .dir
+        .path()
+        .join("exports")
+        .join(format!("share-{}.png", date.format("%Y-%m-%d")));
+    assert!(path.exists());
+    assert!(h.state().toast.as_ref().unwrap().message.starts_with("Saved share card"));
+}
+
+#[tokio::test]
+async fn f9_hides_the_screen_even_while_typing() {
+    let mut h = Harness::new().await;
+    h.press(KeyCode::Char('n')).await;
+    h.press(KeyCode::Char('w')).await;
+    h.type_text("180").await;
+    h.press(KeyCode::F(9)).await;
+    assert!(h.app.private);
+
+    let mut terminal = ratatui::Terminal::new(ratatui::backend::TestBackend::new(80, 24)).unwrap();
+    terminal.draw(|f| h.app.ui(f)).unwrap();
+    let screen = format!("{:?}", terminal.backend().buffer());
+    assert!(!screen.contains("Weight") && !screen.contains("180"));
+
+    // Keys typed while hidden go nowhere
+    h.type_text("5").await;
+    h.press(KeyCode::Enter).await;
+    h.press(KeyCode::F(9)).await;
+    assert!(!h.app.private);
+    assert_eq!(h.screen(), AppScreen::InputField(FieldType::Weight));
+    assert_eq!(h.app.input_handler.input_buffer, "180");
+}
+
+#[tokio::test]
+async fn f10_profiles_each_screen_drawn_while_it_is_on() {
+    let mut h = Harness::new().await;
+    let mut terminal = ratatui::Terminal::new(ratatui::backend::TestBackend::new(120, 30)).unwrap();
+    terminal.draw(|f| h.app.ui(f)).unwrap();
+    assert!(h.app.profiler.screens().is_empty());
+
+    h.press(KeyCode::F(10)).await;
+    terminal.draw(|f| h.app.ui(f)).unwrap();
+    h.press(KeyCode::Char('n')).await;
+    terminal.draw(|f| h.app.ui(f)).unwrap();
+    let names: Vec<&str> = h.app.profiler.screens().iter().map(|(name, _)| *name).collect();
+    assert_eq!(names.len(), 2);
+    assert!(names.contains(&"Startup") && names.contains(&"DailyView"));
+    let screen = format!("{:?}", terminal.backend().buffer());
+    assert!(screen.contains("Render Profile (F10)"));
+
+    h.press(KeyCode::F(10)).await;
+    terminal.draw(|f| h.app.ui(f)).unwrap();
+    assert!(!format!("{:?}", terminal.backend().buffer()).contains("Render Profile"));
+}
+
+#[tokio::test]
+async fn hovering_a_logged_day_shows_its_numbers() {
+    let mut h = Harness::new().await;
+    let today = chrono::Local::now().date_naive();
+    h.app.state.set_daily_logs(vec![DailyLog {
+        miles_covered: Some(9.0),
+        elevation_gain: Some(2400),
+        ..DailyLog::new(today)
+    }]);
+    let mut terminal = ratatui::Terminal::new(ratatui::backend::TestBackend::new(100, 30)).unwrap();
+    let hover = |target: &ClickTarget| {
+        Event::Mouse(MouseEvent {
+            kind: crossterm::event::MouseEventKind::Moved,
+            column: target.area.x,
+            row: target.area.y,
+            modifiers: KeyModifiers::NONE,
+        })
+    };
+
+    h.press(KeyCode::Char('l')).await;
+    terminal.draw(|f| h.app.ui(f)).unwrap();
+    let row = h
+        .app
+        .click_targets
+        .iter()
+        .find(|target| match target.action {
+            ClickAction::OpenLog(index) => h.app.state.home_log(index).is_some(),
+            _ => false,
+        })
+        .cloned()
+        .unwrap();
+    h.app.handle_event(hover(&row)).await.unwrap();
+    terminal.draw(|f| h.app.ui(f)).unwrap();
+    let screen = format!("{:?}", terminal.backend().buffer());
+    assert!(screen.contains("9 mi, 2,400 ft vert"), "{screen}");
+
+    // Any key puts the tooltip away
+    h.press(KeyCode::Char('v')).await;
+    assert!(h.app.tooltip.is_none());
+    terminal.draw(|f| h.app.ui(f)).unwrap();
+    let day = h
+        .app
+        .click_targets
+        .iter()
+        .find(|target| target.action == ClickAction::CalendarDay(today))
+        .cloned()
+        .unwrap();
+    h.app.handle_event(hover(&day)).await.unwrap();
+    assert_eq!(h.app.tooltip.map(|(_, date)| date), Some(today));
+}
+
+#[tokio::test]
+async fn lock_screen_takes_every_key_until_the_passphrase_matches() {
+    let mut config = AppConfig::default();
+    config.lock.passphrase_hash = Some(crate::lock::hash_passphrase("summit").unwrap());
+    let mut h = Harness::with_config(config).await;
+    assert!(h.app.lock.locked);
+
+    h.press(KeyCode::Char('n')).await;
+    h.press(KeyCode::Enter).await;
+    assert!(h.app.lock.locked && h.app.lock.failed);
+    assert_eq!(h.screen(), AppScreen::Startup);
+
+    h.type_text("summit").await;
+    h.press(KeyCode::Enter).await;
+    assert!(!h.app.lock.locked);
+    h.press(KeyCode::Char('n')).await;
+    assert_eq!(h.screen(), AppScreen::DailyView);
+}
+
+#[tokio::test]
+async fn g_jumps_to_a_fuzzy_date_from_the_daily_view() {
+    let mut h = Harness::new().await;
+    h.press(KeyCode::Char('n')).await;
+    h.press(KeyCode::Char('g')).await;
+    assert_eq!(h.screen(), AppScreen::JumpToDate);
+
+    h.type_text("someday").await;
+    h.press(KeyCode::Enter).await;
+    assert_eq!(h.screen(), AppScreen::JumpToDate);
+    assert!(h.state().date_input_error.is_some());
+
+    for _ in "someday".chars() {
+        h.press(KeyCode::Backspace).await;
+    }
+    h.type_text("-3").await;
+    h.press(KeyCode::Enter).await;
+    assert_eq!(h.screen(), AppScreen::DailyView);
+    let today = chrono::Local::now().date_naive();
+    assert_eq!(h.state().selected_date, today - chrono::Duration::days(3));
+}
+
+#[tokio::test]
+async fn date_flag_opens_on_that_day_with_startup_one_jump_back() {
+    let mut h = Harness::new().await;
+    let day = chrono::NaiveDate::from_ymd_opt(2024, 3, 9).unwrap();
+    h.app.open_on(day);
+    assert_eq!(h.screen(), AppScreen::DailyView);
+    assert_eq!(h.state().selected_date, day);
+
+    h.press_with(KeyCode::Char('o'), KeyModifiers::CONTROL).await;
+    assert_eq!(h.screen(), AppScreen::Startup);
+}
+
+#[tokio::test]
+async fn quitting_spins_while_syncing_then_shows_the_result() {
+    let mut h = Harness::new().await;
+    h.press(KeyCode::Char('q')).await;
+    assert_eq!(h.screen(), AppScreen::Syncing);
+
+    h.app.tick_shutdown_sync().await;
+    let mut terminal = ratatui::Terminal::new(ratatui::backend::TestBackend::new(80, 24)).unwrap();
+    terminal.draw(|f| h.app.ui(f)).unwrap();
+    assert!(format!("{:?}", terminal.backend().buffer()).contains("0s"));
+
+    // The loop keeps ticking while the sync runs on its own task
+    let started = Instant::now();
+    while !h.app.should_quit {
+        assert!(started.elapsed() < Duration::from_secs(10));
+        h.app.tick_shutdown_sync().await;
+        tokio::task::yield_now().await;
+        std::thread::sleep(Duration::from_millis(20));
+    }
+    // No cloud database here, so it ends offline
+    assert_eq!(h.app.shutdown_sync.as_ref().unwrap().status(), SYNC_OFFLINE);
+    assert!(started.elapsed() >= SHUTDOWN_RESULT_SHOWN);
+}
+
+#[tokio::test]
+async fn quitting_mid_save_asks_first() {
+    let mut h = Harness::new().await;
+    h.press(KeyCode::Char('n')).await;
+    h.press(KeyCode::Char('+')).await;
+    // The save hasn't had a chance to run yet
+    h.press(KeyCode::Char('q')).await;
+    assert_eq!(h.screen(), AppScreen::DailyView);
+    assert_eq!(
+        h.app.quit_prompt,
+        Some(vec!["1 change is still being saved.".to_string()])
+    );
+    // Other keys wait on the answer
+    h.press(KeyCode::Char('+')).await;
+    assert_eq!(h.today().unwrap().water, Some(8.0));
+    h.press(KeyCode::Char('n')).await;
+    assert_eq!(h.app.quit_prompt, None);
+
+    h.saves_finished().await;
+    h.press(KeyCode::Char('q')).await;
+    assert_eq!(h.screen(), AppScreen::Syncing);
+}
+
+#[tokio::test]
+async fn the_status_bar_counts_writes_until_they_land() {
+    let mut h = Harness::new().await;
+    h.press(KeyCode::Char('n')).await;
+    h.press(KeyCode::Char('+')).await;
+    h.press(KeyCode::Char('+')).await;
+    h.app.update_sync_status().await;
+    assert!(h.app.sync_status.ends_with(" | 2 writes pending"));
+
+    h.saves_finished().await;
+    h.app.update_sync_status().await;
+    assert!(!h.app.sync_status.contains("pending"));
+
+    // The shutdown sync counts them down while it waits
+    let (_stage, stage) = tokio::sync::watch::channel(SYNC_FINISHING_SAVES);
+    let (_saves, pending_saves) = tokio::sync::watch::channel(1);
+    let sync = ShutdownSync {
+        started: Instant::now(),
+        stage,
+        pending_saves,
+        task: tokio::spawn(async { ShutdownOutcome::Offline }),
+        finished: None,
+        post_sync: None,
+    };
+    assert_eq!(sync.status(), "Finishing saves... 1 write pending");
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn quitting_waits_for_the_post_sync_hook_after_the_shutdown_sync() {
+    let out = TempDir::new().unwrap();
+    let ran = out.path().join("ran");
+    let mut config = AppConfig::default();
+    config.hooks.post_sync = Some(format!("sleep 0.3; touch '{}'", ran.display()));
+    let mut h = Harness::with_config(config).await;
+
+    let (_stage, stage) = tokio::sync::watch::channel(SYNC_STARTING);
+    let (_saves, pending_saves) = tokio::sync::watch::channel(0);
+    let task = tokio::spawn(async { ShutdownOutcome::Synced(1) });
+    while !task.is_finished() {
+        tokio::task::yield_now().await;
+    }
+    h.app.shutdown_sync = Some(ShutdownSync {
+        started: Instant::now(),
+        stage,
+        pending_saves,
+        task,
+        finished: None,
+        post_sync: None,
+    });
+    h.app.tick_shutdown_sync().await;
+    assert!(h.app.shutdown_sync.as_ref().unwrap().post_sync.is_some());
+    assert!(!ran.exists());
+
+    h.app.finish_post_sync_hook().await;
+    assert!(ran.exists());
+}
+
+#[tokio::test]
+async fn a_failed_save_can_be_exported_and_retried() {
+    let mut h = Harness::new().await;
+    let today = chrono::Local::now().date_naive();
+    refuse_saves(&h, true).await;
+
+    h.press(KeyCode::Char('n')).await;
+    h.press(KeyCode::Char('+')).await;
+    h.saves_finished().await;
+    h.app.collect_failed_saves();
+    assert_eq!(h.state().failed_saves.len(), 1);
+    let failed = &h.state().failed_saves[0];
+    assert_eq!(failed.kind, FailureKind::Permanent);
+    assert_eq!(failed.attempts, 1);
+    assert!(failed.error.contains("Saves are refused"));
+    h.app.update_sync_status().await;
+    assert!(h.app.sync_status.ends_with(" | 1 save failed (!)"));
+
+    h.press(KeyCode::Char('!')).await;
+    assert_eq!(h.screen(), AppScreen::SaveErrors);
+    h.press(KeyCode::Char('e')).await;
+    let exported = h
+        .dir
+        .path()
+        .join("exports")
+        .join(format!("unsaved-{}.json", today.format("%Y-%m-%d")));
+    assert!(exported.exists());
+
+    refuse_saves(&h, false).await;
+    h.press(KeyCode::Char('r')).await;
+    h.saves_finished().await;
+    h.app.collect_failed_saves();
+    assert!(h.state().failed_saves.is_empty());
+    assert_eq!(h.saved_logs().await.len(), 1);
+    h.press(KeyCode::Esc).await;
+    assert_eq!(h.screen(), AppScreen::DailyView);
+}
+
+/// Makes the database itself turn down every save of a day, or stop.
+async fn refuse_saves(h: &Harness, refused: bool) {
+    let path = h.dir.path().join("mountains.db");
+    let db = libsql::Builder::new_local(path.to_str().unwrap()).build().await.unwrap();
+    let sql = if refused {
+        "CREATE TRIGGER refuse_saves BEFORE INSERT ON daily_logs
+         BEGIN SELECT RAISE(ABORT, 'Saves are refused'); END"
+    } else {
+        "DROP TRIGGER refuse_saves"
+    };
+    db.connect().unwrap().execute(sql, ()).await.unwrap();
+}
+
+#[tokio::test]
+async fn a_backup_that_fails_is_mentioned_but_the_save_stands() {
+    let mut h = Harness::new().await;
+    // A directory where the markdown backup goes makes its write fail
+    let today = chrono::Local::now().date_naive();
+    let backup = h
+        .dir
+        .path()
+        .join(format!("mtslog-{}.md", today.format("%m.%d.%Y")));
+    std::fs::create_dir(&backup).unwrap();
+
+    h.press(KeyCode::Char('n')).await;
+    h.press(KeyCode::Char('+')).await;
+    h.saves_finished().await;
+    h.app.collect_failed_saves();
+    assert!(h.state().failed_saves.is_empty());
+    let toast = &h.state().toast.as_ref().unwrap().message;
+    assert!(toast.contains("markdown backup wasn't written"), "{toast}");
+    assert_eq!(h.saved_logs().await.len(), 1);
+    // The startup check sees backups falling behind
+    let times = health::Timestamps::load(&health::path(h.dir.path()));
+    assert!(times.last_save.is_some() && times.last_backup.is_none());
+}
+
+#[tokio::test]
+async fn gear_is_added_picked_for_a_run_and_retired() {
+    let mut h = Harness::new().await;
+    h.press(KeyCode::Char('n')).await;
+    h.press(KeyCode::Char('G')).await;
+    // Nothing to pick from yet
+    assert_eq!(h.screen(), AppScreen::DailyView);
+
+    h.press(KeyCode::Char('S')).await;
+    h.press(KeyCode::Char('G')).await;
+    assert_eq!(h.screen(), AppScreen::Gear);
+    for name in ["Speedgoat 6", "Mafate"] {
+        h.press(KeyCode::Char('a')).await;
+        h.type_text(name).await;
+        h.press(KeyCode::Enter).await;
+    }
+    assert_eq!(h.state().gear.len(), 2);
+    h.press(KeyCode::Esc).await;
+
+    h.press(KeyCode::Char('n')).await;
+    h.press(KeyCode::Char('m')).await;
+    h.type_text("5").await;
+    h.press(KeyCode::Enter).await;
+    h.press(KeyCode::Char('G')).await;
+    assert_eq!(h.screen(), AppScreen::GearPicker);
+    h.press(KeyCode::Char('j')).await;
+    h.press(KeyCode::Enter).await;
+    assert_eq!(h.screen(), AppScreen::DailyView);
+    let logs = h.saved_logs().await;
+    assert_eq!(logs[0].gear.as_deref(), Some("Mafate"));
+
+    // Retiring keeps the history but takes it out of the picker
+    h.press(KeyCode::Char('S')).await;
+    h.press(KeyCode::Char('G')).await;
+    h.press(KeyCode::Char('j')).await;
+    h.press(KeyCode::Char('x')).await;
+    let rows = h.app.gear_rows();
+    assert_eq!((rows[1].name.as_str(), rows[1].retired), ("Mafate", true));
+    assert_eq!(rows[1].miles, 5.0);
+    assert_eq!(h.app.active_gear(), vec!["Speedgoat 6".to_string()]);
+    let stored = h.app.db_manager.read().await.load_gear().await.unwrap();
+    assert_eq!(stored, h.state().gear);
+}
+
+#[tokio::test]
+async fn routes_autocomplete_from_earlier_runs_and_add_up() {
+    let mut h = Harness::new().await;
+    let today = chrono::Local::now().date_naive();
+    for (days_ago, miles, route) in [(2, "7", "Bear Peak loop"), (0, "8", "be")] {
+        h.app.open_day(today - chrono::Duration::days(days_ago));
+        h.press(KeyCode::Char('m')).await;
+        h.type_text(miles).await;
+        h.press(KeyCode::Enter).await;
+        h.press(KeyCode::Char('R')).await;
+        assert_eq!(h.screen(), AppScreen::EditRoute);
+        h.type_text(route).await;
+        if days_ago == 0 {
+            assert_eq!(h.app.input_handler.suggestions, vec!["Bear Peak loop".to_string()]);
+            h.press(KeyCode::Tab).await;
+        }
+        h.press(KeyCode::Enter).await;
+    }
+    let logs = h.saved_logs().await;
+    assert!(logs.iter().all(|log| log.route.as_deref() == Some("Bear Peak loop")));
+
+    h.press(KeyCode::Char('S')).await;
+    h.press(KeyCode::Char('R')).await;
+    assert_eq!(h.screen(), AppScreen::Routes);
+    let routes = crate::routes::route_stats(h.state().daily_logs());
+    assert_eq!((routes[0].runs, routes[0].miles), (2, 15.0));
+    h.press(KeyCode::Enter).await;
+    assert_eq!(h.screen(), AppScreen::DailyView);
+    assert_eq!(h.state().selected_date, today);
+}
+
+#[tokio::test]
+async fn strength_sets_complete_from_history_and_chart_by_exercise() {
+    let mut h = Harness::new().await;
+    let today = chrono::Local::now().date_naive();
+    h.app.open_day(today - chrono::Duration::days(3));
+    h.press(KeyCode::Char('t')).await;
+    h.type_text("Back squat 3x8 @ 135").await;
+    h.press_with(KeyCode::Enter, KeyModifiers::ALT).await;
+    h.type_text("Hip mobility flow").await;
+    h.press(KeyCode::Enter).await;
+
+    h.app.open_day(today);
+    h.press(KeyCode::Char('t')).await;
+    h.type_text("- ba").await;
+    assert_eq!(h.app.input_handler.suggestions, vec!["Back squat".to_string()]);
+    h.press(KeyCode::Tab).await;
+    h.type_text("4x6 @ 145").await;
+    assert!(h.app.input_handler.suggestions.is_empty());
+    h.press(KeyCode::Enter).await;
+
+    let logs = h.saved_logs().await;
+    let first = logs.iter().find(|log| log.date < today).unwrap();
+    assert_eq!(first.strength_mobility.as_deref(), Some("Hip mobility flow"));
+    let latest = logs.iter().find(|log| log.date == today).unwrap();
+    assert_eq!(latest.strength_mobility, None);
+    assert_eq!(
+        latest.strength_sets,
+        vec![crate::strength::StrengthSet {
+            exercise: "Back squat".to_string(),
+            sets: 4,
+            reps: 6,
+            weight: Some(145.0),
+        }]
+    );
+    // Edited again, the sets come first and read as they were typed
+    h.press(KeyCode::Char('t')).await;
+    assert_eq!(h.app.input_handler.input_buffer, "Back squat 4x6 @ 145 lbs");
+    h.press(KeyCode::Esc).await;
+
+    h.press(KeyCode::Char('S')).await;
+    h.press(KeyCode::Char('L')).await;
+    assert_eq!(h.screen(), AppScreen::Strength);
+    let exercises = crate::strength::exercise_progress(h.state().daily_logs());
+    assert_eq!(exercises[0].sessions.len(), 2);
+    assert_eq!(exercises[0].best_weight(), Some(145.0));
+    h.press(KeyCode::Enter).await;
+    assert_eq!(h.state().selected_date, today);
+}
+
+#[tokio::test]
+async fn archived_days_leave_the_list_and_stats_until_restored() {
+    let mut h = Harness::new().await;
+    h.press(KeyCode::Char('n')).await;
+    h.press(KeyCode::Char('m')).await;
+    h.type_text("5").await;
+    h.press(KeyCode::Enter).await;
+    h.saves_finished().await;
+
+    h.press(KeyCode::Char('S')).await;
+    h.press(KeyCode::Char('l')).await;
+    h.press(KeyCode::Char('j')).await;
+    h.press(KeyCode::Char('x')).await;
+    assert!(h.state().daily_logs().is_empty());
+    assert!(h.state().home_list().is_empty());
+    let logs = h.saved_logs().await;
+    assert!(logs[0].archived);
+    assert_eq!(logs[0].miles_covered, Some(5.0));
+
+    h.press(KeyCode::Char('A')).await;
+    assert_eq!(h.state().home_list().len(), 2);
+    h.press(KeyCode::Char('j')).await;
+    let selected = h.app.list_state.selected().unwrap();
+    assert!(h.state().home_log(selected).unwrap().archived);
+    h.press(KeyCode::Char('x')).await;
+    assert_eq!(h.state().daily_logs().len(), 1);
+    assert!(!h.saved_logs().await[0].archived);
+}
+
+#[tokio::test]
+async fn fetched_weather_is_saved_with_its_day_once() {
+    let mut h = Harness::new().await;
+    h.press(KeyCode::Char('n')).await;
+    h.press(KeyCode::Char('m')).await;
+    h.type_text("5").await;
+    h.press(KeyCode::Enter).await;
+    h.saves_finished().await;
+
+    let today = chrono::Local::now().date_naive();
+    let fetched = |high| crate::weather::Weather {
+        high,
+        low: 45.0,
+        code: 0,
+    };
+    h.app.weather_tx.send((today, fetched(61.0))).unwrap();
+    // A second answer for the same day doesn't replace the first
+    h.app.weather_tx.send((today, fetched(99.0))).unwrap();
+    h.app.collect_weather();
+    let logs = h.saved_logs().await;
+    assert_eq!(logs[0].weather, Some(fetched(61.0)));
+    assert_eq!(logs[0].miles_covered, Some(5.0));
+}
+
+#[tokio::test]
+async fn shift_c_adds_a_sokay_with_just_the_time() {
+    let mut h = Harness::new().await;
+    h.press(KeyCode::Char('n')).await;
+    h.press(KeyCode::Char('C')).await;
+    h.press(KeyCode::Char('C')).await;
+    assert_eq!(h.screen(), AppScreen::DailyView);
+    let logs = h.saved_logs().await;
+    assert_eq!(logs[0].sokay_entries.len(), 2);
+    assert!(logs[0].sokay_entries[0].starts_with("Sokay at "));
+}
+
+#[tokio::test]
+async fn shift_i_marks_the_run_indoor_and_back() {
+    let mut h = Harness::new().await;
+    h.press(KeyCode::Char('n')).await;
+    h.press(KeyCode::Char('I')).await;
+    assert!(h.saved_logs().await[0].indoor);
+    h.press(KeyCode::Char('I')).await;
+    assert!(!h.saved_logs().await[0].indoor);
+}
+
+#[tokio::test]
+async fn shift_d_marks_a_rest_day_that_keeps_the_streak() {
+    let mut h = Harness::new().await;
+    let today = chrono::Local::now().date_naive();
+    h.app.state.set_daily_logs(vec![DailyLog {
+        miles_covered: Some(5.0),
+        ..DailyLog::new(today - chrono::Duration::days(1))
+    }]);
+    h.press(KeyCode::Char('n')).await;
+    h.press(KeyCode::Char('D')).await;
+    assert!(h.saved_logs().await[0].rest_day);
+    let streak = h.state().stats.run_streak(h.state().daily_logs());
+    assert_eq!(streak.map(|s| s.days), Some(1));
+
+    h.press(KeyCode::Char('D')).await;
+    assert!(!h.today().unwrap().rest_day);
+}
+
+#[tokio::test]
+async fn intention_and_gratitude_have_their_own_boxes() {
+    let mut h = Harness::new().await;
+    h.press(KeyCode::Char('n')).await;
+    h.press(KeyCode::Char('i')).await;
+    assert_eq!(h.screen(), AppScreen::EditReflection(Reflection::Intention));
+    h.type_text("Stay patient on the climbs").await;
+    h.press(KeyCode::Enter).await;
+    h.press(KeyCode::Char('o')).await;
+    h.type_text("Cool morning").await;
+    h.press(KeyCode::Enter).await;
+    assert_eq!(h.screen(), AppScreen::DailyView);
+    let logs = h.saved_logs().await;
+    assert_eq!(logs[0].intention.as_deref(), Some("Stay patient on the climbs"));
+    assert_eq!(logs[0].gratitude.as_deref(), Some("Cool morning"));
+}
+
+#[tokio::test]
+async fn quitting_from_a_changed_field_asks_before_dropping_it() {
+    let mut h = Harness::new().await;
+    h.press(KeyCode::Char('n')).await;
+    h.press(KeyCode::Char('w')).await;
+    // Nothing typed yet, so there's nothing to lose
+    h.press(KeyCode::Char('q')).await;
+    assert_eq!(h.screen(), AppScreen::Syncing);
+
+    let mut h = Harness::new().await;
+    h.press(KeyCode::Char('n')).await;
+    h.press(KeyCode::Char('w')).await;
+    h.type_text("180").await;
+    h.press(KeyCode::Char('q')).await;
+    assert_eq!(h.screen(), AppScreen::InputField(FieldType::Weight));
+    assert_eq!(
+        h.app.quit_prompt,
+        Some(vec!["What you've typed hasn't been saved yet.".to_string()])
+    );
+    let mut terminal = ratatui::Terminal::new(ratatui::backend::TestBackend::new(80, 24)).unwrap();
+    terminal.draw(|f| h.app.ui(f)).unwrap();
+    assert!(format!("{:?}", terminal.backend().buffer()).contains("Press 'y' to quit anyway"));
+
+    h.press(KeyCode::Char('y')).await;
+    assert_eq!(h.screen(), AppScreen::Syncing);
+    assert_eq!(h.today().and_then(|log| log.weight), None);
+}
+
+#[tokio::test]
+async fn esc_skips_the_shutdown_sync_and_the_next_start_says_so() {
+    let mut h = Harness::new().await;
+    h.press(KeyCode::Char('q')).await;
+    h.app.tick_shutdown_sync().await;
+    h.press(KeyCode::Esc).await;
+    assert!(h.app.should_quit);
+
+    let Harness { app, dir } = h;
+    drop(app);
+    let reopen = || async {
+        let file_manager = FileManager::in_dir(dir.path().to_path_buf()).unwrap();
+        App::with_storage(AppConfig::default(), dir.path().to_path_buf(), file_manager)
+            .await
+            .unwrap()
+    };
+    let app = reopen().await;
+    let notice = app.state.toast.as_ref().map(|toast| toast.message.clone());
+    assert!(notice.unwrap().starts_with("The sync was skipped when you quit on"));
+    drop(app);
+    // It's only said once
+    assert!(reopen().await.state.toast.is_none());
+}
+
+#[tokio::test]
+async fn idle_action_runs_once_per_idle_stretch() {
+    let mut config = AppConfig::default();
+    config.session.idle_minutes = 30;
+    config.session.idle_action = IdleAction::Lock;
+    let mut h = Harness::with_config(config).await;
+
+    h.app.handle_idle(Duration::from_secs(29 * 60));
+    assert!(!h.app.private);
+    // No passphrase is set, so locking falls back to the privacy screen
+    h.app.handle_idle(Duration::from_secs(30 * 60));
+    assert!(h.app.private);
+
+    h.press(KeyCode::F(9)).await;
+    h.app.config.session.idle_action = IdleAction::Exit;
+    h.app.handle_idle(Duration::from_secs(30 * 60));
+    assert_eq!(h.screen(), AppScreen::Syncing);
+}
+
+#[tokio::test]
+async fn idle_exit_syncs_even_with_an_edit_left_open() {
+    let mut config = AppConfig::default();
+    config.session.idle_minutes = 30;
+    config.session.idle_action = IdleAction::Exit;
+    let mut h = Harness::with_config(config).await;
+    h.press(KeyCode::Char('n')).await;
+    h.press(KeyCode::Char('l')).await;
+    assert_eq!(h.screen(), AppScreen::InputField(FieldType::Elevation));
+    h.type_text("1200").await;
+
+    // Nobody is there to answer the quit prompt, so there isn't one
+    h.app.handle_idle(Duration::from_secs(30 * 60));
+    assert_eq!(h.app.quit_prompt, None);
+    assert_eq!(h.screen(), AppScreen::Syncing);
+    assert!(h.app.input_handler.input_buffer.is_empty());
+    h.app.tick_shutdown_sync().await;
+    assert!(h.app.shutdown_sync.is_some());
+}
+
+#[tokio::test]
+async fn calendar_picks_a_day_with_hjkl_and_never_passes_today() {
+    let mut h = Harness::new().await;
+    h.press(KeyCode::Char('l')).await;
+    assert_eq!(h.screen(), AppScreen::Home);
+    h.press(KeyCode::Char('v')).await;
+    assert_eq!(h.screen(), AppScreen::Calendar);
+
+    let today = chrono::Local::now().date_naive();
+    h.press(KeyCode::Char('l')).await;
+    h.press(KeyCode::Char('j')).await;
+    assert_eq!(h.app.calendar_cursor, today);
+
+    h.press(KeyCode::Char('k')).await;
+    h.press(KeyCode::Char('h')).await;
+    h.press(KeyCode::Char('[')).await;
+    let expected = (today - chrono::Duration::days(8))
+        .checked_sub_months(chrono::Months::new(1))
+        .unwrap();
+    assert_eq!(h.app.calendar_cursor, expected);
+
+    h.press(KeyCode::Enter).await;
+    assert_eq!(h.screen(), AppScreen::DailyView);
+    assert_eq!(h.state().selected_date, expected);
+}
+
+#[tokio::test]
+async fn new_day_sync_swaps_in_a_log_pulled_from_another_device() {
+    let mut h = Harness::new().await;
+    h.press(KeyCode::Char('n')).await;
+    let today = chrono::Local::now().date_naive();
+    assert!(h.today().unwrap().notes.is_none());
+
+    // Stands in for the row the cloud sync pulls down
+    let mut pulled = DailyLog::new(today);
+    pulled.notes = Some("Logged on my phone".to_string());
+    h.app.db_manager.write().await.save_daily_log(&pulled).await.unwrap();
+
+    h.app.pending_day_sync = Some(today);
+    h.app.sync_new_day_if_needed().await;
+    // The sync runs on its own task; the event loop picks it up when done
+    while h.app.day_sync.is_some() {
+        tokio::task::yield_now().await;
+        h.app.sync_new_day_if_needed().await;
+    }
+    assert_eq!(h.today().unwrap().notes.as_deref(), Some("Logged on my phone"));
+    assert!(h.state().toast.as_ref().unwrap().message.starts_with("Pulled"));
+}
+
+#[tokio::test]
+async fn saves_are_stamped_for_the_health_banner_and_x_dismisses_it() {
+    let mut h = Harness::new().await;
+    assert!(h.state().health_banner.is_none());
+    h.press(KeyCode::Char('n')).await;
+    h.press(KeyCode::Char('f')).await;
+    h.type_text("Oatmeal").await;
+    h.press(KeyCode::Enter).await;
+    h.saves_finished().await;
+    // Stamped beside the database, not in it, since it's this machine's
+    let times = health::Timestamps::load(&health::path(h.dir.path()));
+    assert!(times.last_save.is_some());
+    assert_eq!(times.last_save, times.last_backup);
+
+    h.press(KeyCode::Char('S')).await;
+    h.app.state.health_banner = Some("Last cloud sync was 9 days ago".to_string());
+    h.press(KeyCode::Char('x')).await;
+    assert!(h.state().health_banner.is_none());
+}
+
+#[tokio::test]
+async fn slash_filters_the_logged_days_and_enter_opens_the_filtered_row() {
+    let mut h = Harness::new().await;
+    let mut logs = Vec::new();
+    for (day, notes) in [(1, "Ridge loop"), (2, "Easy flats"), (3, "Ridge again")] {
+        let mut log = DailyLog::new(chrono::NaiveDate::from_ymd_opt(2024, 11, day).unwrap());
+        log.notes = Some(notes.to_string());
+        logs.push(log);
+    }
+    h.app.state.set_daily_logs(logs);
+
+    h.press(KeyCode::Char('l')).await;
+    h.press(KeyCode::Char('/')).await;
+    assert_eq!(h.screen(), AppScreen::HomeFilter);
+    h.type_text("flats").await;
+    h.press(KeyCode::Enter).await;
+    assert_eq!(h.screen(), AppScreen::Home);
+    assert_eq!(h.state().home_rows(), vec![1]);
+
+    h.press(KeyCode::Char('j')).await;
+    h.press(KeyCode::Enter).await;
+    assert_eq!(
+        h.state().selected_date,
+        chrono::NaiveDate::from_ymd_opt(2024, 11, 2).unwrap()
+    );
+
+    h.press(KeyCode::Esc).await;
+    h.press(KeyCode::Char('/')).await;
+    h.press(KeyCode::Esc).await;
+    h.press(KeyCode::Char('o')).await;
+    assert_eq!(h.state().home_rows(), vec![2, 1, 0]);
+}
+
+#[tokio::test]
+async fn views_menu_applies_a_saved_filter() {
+    let mut config = AppConfig::default();
+    config.home.views = ["ridge", "flats"]
+        .into_iter()
+        .map(|query| crate::models::home_filter::SavedView {
+            name: format!("{} days", query),
+            query: query.to_string(),
+        })
+        .collect();
+    let mut h = Harness::with_config(config).await;
+    let mut logs = Vec::new();
+    for (day, notes) in [(1, "Ridge loop"), (2, "Easy flats"), (3, "Ridge again")] {
+        let mut log = DailyLog::new(chrono::NaiveDate::from_ymd_opt(2024, 11, day).unwrap());
+        log.notes = Some(notes.to_string());
+        logs.push(log);
+    }
+    h.app.state.set_daily_logs(logs);
+
+    h.press(KeyCode::Char('l')).await;
+    h.press(KeyCode::Char('V')).await;
+    assert_eq!(h.screen(), AppScreen::HomeViews);
+    h.press(KeyCode::Down).await;
+    h.press(KeyCode::Enter).await;
+    assert_eq!(h.screen(), AppScreen::Home);
+    assert_eq!(h.state().home_filter.query, "flats");
+    assert_eq!(h.state().home_rows(), vec![1]);
+
+    // Saving needs a filter to save; without one it's a toast, not a prompt
+    h.press(KeyCode::Char('/')).await;
+    h.press(KeyCode::Esc).await;
+    h.press(KeyCode::Char('V')).await;
+    h.press(KeyCode::Char('s')).await;
+    assert!(!h.app.naming_view);
+    h.press(KeyCode::Esc).await;
+    assert_eq!(h.screen(), AppScreen::Home);
+}
+
+#[tokio::test]
+async fn year_view_moves_by_day_week_and_month_and_drills_into_a_month() {
+    let mut h = Harness::new().await;
+    let today = chrono::Local::now().date_naive();
+
+    h.press(KeyCode::Char('Y')).await;
+    assert_eq!(h.screen(), AppScreen::Year);
+    h.press(KeyCode::Char('l')).await;
+    assert_eq!(h.app.year_cursor, today, "no moving into the future");
+
+    h.press(KeyCode::Char('k')).await;
+    h.press(KeyCode::Char('[')).await;
+    let cursor = (today - chrono::Days::new(7))
+        .checked_sub_months(chrono::Months::new(1))
+        .unwrap();
+    assert_eq!(h.app.year_cursor, cursor);
+
+    h.press(KeyCode::Char('m')).await;
+    assert_eq!(h.screen(), AppScreen::Month);
+    assert_eq!(h.app.month_cursor, month_start(cursor));
+    h.press(KeyCode::Esc).await;
+    assert_eq!(h.screen(), AppScreen::Year);
+
+    h.press(KeyCode::Enter).await;
+    assert_eq!(h.screen(), AppScreen::DailyView);
+    assert_eq!(h.state().selected_date, cursor);
+}
+
+#[tokio::test]
+async fn folded_months_are_skipped_when_moving_through_the_list() {
+    let mut h = Harness::new().await;
+    let day = |m, d| chrono::NaiveDate::from_ymd_opt(2024, m, d).unwrap();
+    h.app.state.set_daily_logs(vec![
+        DailyLog::new(day(11, 2)),
+        DailyLog::new(day(11, 1)),
+        DailyLog::new(day(10, 30)),
+        DailyLog::new(day(9, 4)),
+    ]);
+
+    h.press(KeyCode::Char('l')).await;
+    h.press(KeyCode::Char('j')).await;
+    assert_eq!(h.app.list_state.selected(), Some(1), "starts on the first day");
+    h.press(KeyCode::Char('z')).await;
+    assert_eq!(h.app.list_state.selected(), Some(0), "moves up to the header");
+    assert_eq!(h.state().home_list().len(), 5);
+
+    // November header, October header, Oct 30
+    h.press(KeyCode::Char('j')).await;
+    h.press(KeyCode::Char('j')).await;
+    h.press(KeyCode::Enter).await;
+    assert_eq!(h.screen(), AppScreen::DailyView);
+    assert_eq!(h.state().selected_date, day(10, 30));
+
+    h.press(KeyCode::Esc).await;
+    h.press(KeyCode::Char('Z')).await;
+    assert_eq!(h.state().home_list().len(), 3);
+    h.press(KeyCode::Char('Z')).await;
+    assert_eq!(h.state().home_list().len(), 7);
+
+    h.app.list_state.select(Some(0));
+    h.press(KeyCode::Enter).await;
+    assert_eq!(h.screen(), AppScreen::Home, "Enter on a header folds it");
+    assert_eq!(h.state().home_list().len(), 5);
+}
+
+#[tokio::test]
+async fn food_lookup_finds_past_entries_and_opens_the_last_day() {
+    let mut h = Harness::new().await;
+    let day = |d| chrono::NaiveDate::from_ymd_opt(2024, 11, d).unwrap();
+    for (date, food) in [(day(1), "Burrito"), (day(4), "Bean burrito"), (day(6), "Oatmeal")] {
+        let mut log = DailyLog::new(date);
+        log.add_food_entry(crate::models::FoodEntry::new(food.to_string()));
+        h.app.db_manager.write().await.save_daily_log(&log).await.unwrap();
+    }
+
+    h.press(KeyCode::Char('n')).await;
+    h.press_with(KeyCode::Char('J'), KeyModifiers::SHIFT).await;
+    h.press_with(KeyCode::Char('J'), KeyModifiers::SHIFT).await;
+    assert_eq!(h.state().focused_section, FocusedSection::FoodItems);
+    h.press(KeyCode::Char('/')).await;
+    assert_eq!(h.screen(), AppScreen::FoodLookup);
+    h.type_text("burrito").await;
+    let names: Vec<&str> = h
+        .state()
+        .food_history
+        .iter()
+        .map(|food| food.name.as_str())
+        .collect();
+    assert_eq!(names, ["Bean burrito", "Burrito"]);
+
+    h.press(KeyCode::Down).await;
+    h.press(KeyCode::Enter).await;
+    assert_eq!(h.screen(), AppScreen::DailyView);
+    assert_eq!(h.state().selected_date, day(1));
+    assert!(h.state().food_history.is_empty());
+}
+
+#[tokio::test]
+async fn backfill_steps_through_the_range_saving_each_day() {
+    let mut h = Harness::new().await;
+    let today = chrono::Local::now().date_naive();
+    h.app.state.set_daily_logs(vec![DailyLog {
+        weight: Some(171.0),
+        ..DailyLog::new(today - chrono::Days::new(1))
+    }]);
+
+    h.press_with(KeyCode::Char('F'), KeyModifiers::SHIFT).await;
+    assert_eq!(h.screen(), AppScreen::Backfill);
+    h.type_text("-2").await;
+    h.press(KeyCode::Enter).await;
+    assert_eq!(h.app.backfill.day, Some(today - chrono::Days::new(2)));
+
+    // Miles, Tab to vert, then a note; Enter saves and moves on
+    h.type_text("6.5").await;
+    h.press(KeyCode::Tab).await;
+    h.type_text("1800").await;
+    h.press(KeyCode::BackTab).await;
+    h.press(KeyCode::BackTab).await;
+    h.type_text("Rainy").await;
+    h.press(KeyCode::Enter).await;
+    assert_eq!(h.app.backfill.day, Some(today - chrono::Days::new(1)));
+    assert_eq!(h.app.backfill.weight, "171");
+
+    // Left as logged, then today left blank: neither is saved
+    h.press(KeyCode::Enter).await;
+    h.press(KeyCode::Enter).await;
+    assert_eq!(h.screen(), AppScreen::Startup);
+    assert!(h.today().is_none());
+
+    let saved = h.saved_logs().await;
+    assert_eq!(saved.len(), 1);
+    assert_eq!(saved[0].date, today - chrono::Days::new(2));
+    assert_eq!((saved[0].miles_covered, saved[0].elevation_gain), (Some(6.5), Some(1800)));
+    assert_eq!(saved[0].notes.as_deref(), Some("Rainy"));
+}
+
+#[tokio::test]
+async fn esc_on_a_changed_edit_asks_before_discarding_it() {
+    let mut h = Harness::new().await;
+    h.app.state.set_daily_logs(vec![DailyLog {
+        notes: Some("Easy day".to_string()),
+        ..DailyLog::new(chrono::Local::now().date_naive())
+    }]);
+    h.press(KeyCode::Char('n')).await;
+    h.press(KeyCode::Char('n')).await;
+    assert_eq!(h.screen(), AppScreen::InputField(FieldType::Notes));
+
+    // Unchanged, Esc just closes
+    h.press(KeyCode::Esc).await;
+    assert_eq!(h.screen(), AppScreen::DailyView);
+
+    h.press(KeyCode::Char('n')).await;
+    h.type_text(", legs tired").await;
+    h.press(KeyCode::Esc).await;
+    assert!(h.app.discard_prompt);
+    let mut terminal = ratatui::Terminal::new(ratatui::backend::TestBackend::new(100, 30)).unwrap();
+    terminal.draw(|f| h.app.ui(f)).unwrap();
+    let screen = format!("{:?}", terminal.backend().buffer());
+    assert!(screen.contains("(changed)") && screen.contains("Discard changes?"));
+
+    // n keeps typing, y throws the change away
+    h.press(KeyCode::Char('n')).await;
+    assert!(!h.app.discard_prompt);
+    assert_eq!(h.app.input_handler.input_buffer, "Easy day, legs tired");
+    h.press(KeyCode::Esc).await;
+    h.press(KeyCode::Char('y')).await;
+    assert_eq!(h.screen(), AppScreen::DailyView);
+    assert_eq!(h.today().unwrap().notes.as_deref(), Some("Easy day"));
+}
+
+#[tokio::test]
+async fn phases_are_marked_shown_on_the_day_and_kept_apart() {
+    let mut h = Harness::new().await;
+    let today = chrono::Local::now().date_naive();
+    let range = |from: i64, to: i64| {
+        let day = |offset| (today + chrono::Duration::days(offset)).format("%Y-%m-%d").to_string();
+        format!("{} to {}", day(from), day(to))
+    };
+    h.press(KeyCode::Char('P')).await;
+    assert_eq!(h.screen(), AppScreen::Phases);
+    h.press(KeyCode::Char('a')).await;
+    h.press(KeyCode::Tab).await;
+    h.type_text(&range(-10, 20)).await;
+    h.press(KeyCode::Enter).await;
+    assert_eq!(h.state().phases.len(), 1);
+    assert_eq!(h.state().phases[0].kind, PhaseKind::Build);
+
+    // A phase can't share days with another
+    h.press(KeyCode::Char('a')).await;
+    h.type_text(&range(15, 30)).await;
+    h.press(KeyCode::Enter).await;
+    assert_eq!(h.state().phases.len(), 1);
+    assert!(h.app.phase_draft.is_some());
+    h.press(KeyCode::Esc).await;
+
+    h.press(KeyCode::Esc).await;
+    h.press(KeyCode::Char('n')).await;
+    let mut terminal = ratatui::Terminal::new(ratatui::backend::TestBackend::new(140, 40)).unwrap();
+    terminal.draw(|f| h.app.ui(f)).unwrap();
+    assert!(format!("{:?}", terminal.backend().buffer()).contains("- Build Phase"));
+
+    // Changing it keeps its id; the table matches what's shown
+    h.press(KeyCode::Esc).await;
+    h.press(KeyCode::Char('P')).await;
+    h.press(KeyCode::Char('e')).await;
+    h.press(KeyCode::Tab).await;
+    h.press(KeyCode::Enter).await;
+    assert_eq!(h.state().phases[0].kind, PhaseKind::Peak);
+    let stored = h.app.db_manager.read().await.load_phases().await.unwrap();
+    assert_eq!(stored, h.state().phases);
+    h.press(KeyCode::Char('d')).await;
+    assert!(h.state().phases.is_empty());
+}
+
+#[tokio::test]
+async fn injuries_are_logged_shown_on_the_day_and_resolved() {
+    let mut h = Harness::new().await;
+    let today = chrono::Local::now().date_naive();
+    h.press(KeyCode::Char('N')).await;
+    assert_eq!(h.screen(), AppScreen::Injuries);
+    h.press(KeyCode::Char('a')).await;
+    h.press(KeyCode::Tab).await;
+    h.type_text("Left knee, -3").await;
+    h.press(KeyCode::Enter).await;
+    assert_eq!(h.state().injuries.len(), 1);
+    let knee = h.state().injuries[0].clone();
+    assert_eq!(knee.severity, crate::injuries::Severity::Moderate);
+    assert_eq!((knee.start, knee.resolved), (today - chrono::Duration::days(3), None));
+
+    // A start still to come is refused and the draft kept
+    h.press(KeyCode::Char('a')).await;
+    h.type_text("Hip, 2099-01-01").await;
+    h.press(KeyCode::Enter).await;
+    assert_eq!(h.state().injuries.len(), 1);
+    assert!(h.app.injury_draft.is_some());
+    h.press(KeyCode::Esc).await;
+
+    h.press(KeyCode::Esc).await;
+    h.press(KeyCode::Char('n')).await;
+    let mut terminal = ratatui::Terminal::new(ratatui::backend::TestBackend::new(140, 40)).unwrap();
+    terminal.draw(|f| h.app.ui(f)).unwrap();
+    let text = format!("{:?}", terminal.backend().buffer());
+    assert!(text.contains("Niggles: Left knee (moderate, day 4)"));
+
+    // Resolving it is written straight to the table
+    h.press(KeyCode::Char('N')).await;
+    assert_eq!(h.screen(), AppScreen::Injuries);
+    h.press(KeyCode::Char('r')).await;
+    assert_eq!(h.state().injuries[0].resolved, Some(today));
+    let stored = h.app.db_manager.read().await.load_injuries().await.unwrap();
+    assert_eq!(stored, h.state().injuries);
+    h.press(KeyCode::Char('d')).await;
+    assert!(h.state().injuries.is_empty());
+    h.press(KeyCode::Esc).await;
+    assert_eq!(h.screen(), AppScreen::DailyView);
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn photos_are_read_in_the_background_and_esc_stops_a_read() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let scripts = TempDir::new().unwrap();
+    let image = scripts.path().join("receipt.png");
+    std::fs::write(&image, b"").unwrap();
+    let script = |name: &str, body: &str| {
+        let path = scripts.path().join(name);
+        std::fs::write(&path, format!("#!/bin/sh\n{}\n", body)).unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+        path.display().to_string()
+    };
+    let mut config = AppConfig::default();
+    config.food.ocr_command = Some(script("slow-ocr", "sleep 5"));
+    let mut h = Harness::with_config(config).await;
+    h.press(KeyCode::Char('n')).await;
+
+    // A slow read leaves the screen responsive, and Esc gives up on it
+    h.press(KeyCode::Char('P')).await;
+    h.type_text(&image.display().to_string()).await;
+    h.press(KeyCode::Enter).await;
+    assert!(h.app.ocr_reading.is_some());
+    h.press(KeyCode::Esc).await;
+    assert!(h.app.ocr_reading.is_none());
+    assert_eq!(h.screen(), AppScreen::OcrImage);
+
+    h.app.config.food.ocr_command = Some(script("ocr", "echo 'Fish tacos 12.00'"));
+    h.press(KeyCode::Enter).await;
+    while h.app.ocr_reading.is_some() {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        h.app.collect_ocr();
+    }
+    assert_eq!(h.screen(), AppScreen::OcrReview);
+    assert_eq!(h.app.ocr_candidates, vec![("Fish tacos".to_string(), false)]);
+}
+
+/// The key a manual binding names, as the event loop gets it.
+fn manual_key(key: &str) -> (KeyCode, KeyModifiers) {
+    let (modifiers, name) = if let Some(name) = key.strip_prefix("Ctrl+") {
+        (KeyModifiers::CONTROL, name)
+    } else if let Some(name) = key.strip_prefix("Alt+") {
+        (KeyModifiers::ALT, name)
+    } else if let Some(name) = key.strip_prefix("Shift+") {
+        (KeyModifiers::SHIFT, name)
+    } else {
+        (KeyModifiers::NONE, key)
+    };
+    let code = match name {
+        "Enter" => KeyCode::Enter,
+        "Esc" => KeyCode::Esc,
+        "Tab" if modifiers == KeyModifiers::SHIFT => KeyCode::BackTab,
+        "Tab" => KeyCode::Tab,
+        "Space" => KeyCode::Char(' '),
+        "Up" => KeyCode::Up,
+        "Down" => KeyCode::Down,
+        "PageUp" => KeyCode::PageUp,
+        "PageDown" => KeyCode::PageDown,
+        // A range of digits, like the energy rating's 1-5
+        "1-5" => KeyCode::Char('1'),
+        _ if name.starts_with('F') && name.len() > 1 => KeyCode::F(name[1..].parse().unwrap()),
+        _ if name.chars().count() == 1 => {
+            let c = name.chars().next().unwrap();
+            match modifiers {
+                KeyModifiers::CONTROL => KeyCode::Char(c.to_ascii_lowercase()),
+                _ => KeyCode::Char(c),
+            }
+        }
+        _ => panic!("the manual names a key the test can't press: {key}"),
+    };
+    (code, modifiers)
+}
+
+/// Keys that move back and forth. Either of a pair may be up against the
+/// end it moves towards, so the other is pressed first.
+const MOVES: &[&str] = &[
+    "j", "k", "h", "l", "[", "]", "{", "}", "Up", "Down", "PageUp", "PageDown", "Shift+J",
+    "Shift+K", "Tab", "Shift+Tab", "Ctrl+O", "Ctrl+I",
+];
+
+/// A harness with a bit of everything logged over the last ten days, so
+/// every list has rows to move through, left on the startup screen.
+async fn seeded(config: AppConfig) -> Harness {
+    let mut h = Harness::with_config(config).await;
+    if h.app.lock.locked {
+        h.type_text("summit").await;
+        h.press(KeyCode::Enter).await;
+    }
+    h.app.clipboard = Box::new(FakeClipboard::default());
+    h.app.state.frame_height = 30;
+    let today = chrono::Local::now().date_naive();
+    for days_ago in 0..10 {
+        let mut log = DailyLog::new(today - chrono::Duration::days(days_ago));
+        log.miles_covered = Some(4.0 + days_ago as f32);
+        log.elevation_gain = Some(800 + 100 * days_ago as i32);
+        log.weight = Some(150.0 + days_ago as f32 / 2.0);
+        log.waist = Some(32.0);
+        log.water = Some(16.0);
+        log.food_entries = ["Oatmeal", "Oat milk", "Rice"]
+            .into_iter()
+            .map(|name| FoodEntry::new(name.to_string()))
+            .collect();
+        log.sokay_entries = vec!["Chips".to_string(), "Cookie".to_string()];
+        let route = if days_ago % 2 == 0 { "Bear Peak loop" } else { "Mesa trail" };
+        log.route = Some(route.to_string());
+        log.strength_sets = vec![crate::strength::StrengthSet {
+            exercise: if days_ago % 2 == 0 { "Back squat" } else { "Deadlift" }.to_string(),
+            sets: 3,
+            reps: 8,
+            weight: Some(135.0 + days_ago as f32),
+        }];
+        log.notes = Some("Legs heavy\nbut the ridge was worth it".to_string());
+        h.app.db_manager.write().await.save_daily_log(&log).await.unwrap();
+    }
+    let logs = h.app.db_manager.read().await.load_all_daily_logs().await.unwrap();
+    h.app.state.set_daily_logs(logs);
+
+    let past = |days_ago: i64| (today - chrono::Duration::days(days_ago)).format("%Y-%m-%d");
+    let lists: [(char, &[&str]); 4] = [
+        ('G', &["Speedgoat 6", "Mafate"]),
+        ('O', &["40", "\t5000"]),
+        (
+            'P',
+            &[&format!("\t{} to {}", past(20), past(12)), &format!("{} to {}", past(5), past(0))],
+        ),
+        ('N', &["Left knee, -3", "Right calf, -9 to -6"]),
+    ];
+    for (open, entries) in lists {
+        h.press(KeyCode::Char(open)).await;
+        for entry in entries {
+            h.press(KeyCode::Char('a')).await;
+            for c in entry.chars() {
+                h.press(if c == '\t' { KeyCode::Tab } else { KeyCode::Char(c) }).await;
+            }
+            h.press(KeyCode::Enter).await;
+        }
+        h.press(KeyCode::Esc).await;
+    }
+    assert_eq!(h.screen(), AppScreen::Startup);
+    h.app.state.failed_saves = (1..3)
+        .map(|days_ago| FailedSave {
+            log: DailyLog::new(today - chrono::Duration::days(days_ago)),
+            error: "disk full".to_string(),
+            kind: FailureKind::Permanent,
+            attempts: 1,
+        })
+        .collect();
+    h.app.state.health_banner = Some("No backup in 9 days".to_string());
+    h
+}
+
+/// Opens the screen `section` of the manual is about, ready for `key` to
+/// do what `action` says.
+async fn open_section(h: &mut Harness, section: &str, action: &str, key: &str) {
+    let today = chrono::Local::now().date_naive();
+    let opens: &[KeyCode] = match section {
+        "Anywhere" | crate::manual::DAILY_VIEW => &[KeyCode::Char('n')],
+        "Startup" | "Lock Screen" => &[],
+        "Settings" => &[KeyCode::Char(',')],
+        "Calendar" => &[KeyCode::Char('l'), KeyCode::Char('v')],
+        "Logged Days" => &[KeyCode::Char('l')],
+        "Statistics" => &[KeyCode::Char('s')],
+        "Heatmap" => &[KeyCode::Char('H')],
+        "Year" => &[KeyCode::Char('Y')],
+        "Charts" => &[KeyCode::Char('C')],
+        "Week" => &[KeyCode::Char('W')],
+        "Month" => &[KeyCode::Char('M')],
+        "Manual" => &[KeyCode::Char('n'), KeyCode::F(1)],
+        "Failed Saves" => &[KeyCode::Char('!')],
+        "Routes" => &[KeyCode::Char('R')],
+        "Strength" => &[KeyCode::Char('L')],
+        "Best Efforts" => &[KeyCode::Char('B')],
+        "Backfill" => &[KeyCode::Char('F')],
+        "Goals" => &[KeyCode::Char('O')],
+        "Phases" => &[KeyCode::Char('P')],
+        "Injuries" => &[KeyCode::Char('N')],
+        "Gear" => &[KeyCode::Char('G')],
+        "Syncing" => &[KeyCode::Char('q')],
+        crate::manual::TEXT_FIELDS => &[],
+        _ => panic!("no way to open the manual's \"{section}\" section"),
+    };
+    for &code in opens {
+        h.press(code).await;
+    }
+
+    match (section, action) {
+        (crate::manual::DAILY_VIEW, action)
+            if action.starts_with("Select a list item")
+                || action.starts_with("Edit / delete the selected") =>
+        {
+            h.focus(FocusedSection::FoodItems).await;
+            if !MOVES.contains(&key) {
+                h.press(KeyCode::Char('j')).await;
+            }
+        }
+        (crate::manual::DAILY_VIEW, action) if action.starts_with("On Food Items") => {
+            h.focus(FocusedSection::FoodItems).await;
+        }
+        ("Logged Days", action) if action.contains("selected") || action.contains("selection") => {
+            h.press(KeyCode::Char('j')).await;
+        }
+        (crate::manual::TEXT_FIELDS, action) => {
+            let (open, typed) = match action {
+                "Save" | "Nudge a number (Shift for x10)" => ('w', "151"),
+                "Pick a recent weight or waist" => ('w', ""),
+                "New line (strength & mobility, notes)" => ('n', ""),
+                "Food name: pick a frequent food as you type" => ('f', "Oat"),
+                "Add Food with a barcode as the name: look it up on OpenFoodFacts" => {
+                    std::fs::write(
+                        h.dir.path().join("barcodes.json"),
+                        r#"{"0722252100900": {"name": "Energy Bar", "quantity": null,
+                            "calories": 250, "macros": null}}"#,
+                    )
+                    .unwrap();
+                    ('f', "0722252100900")
+                }
+                action if action.starts_with("Strength & mobility") => {
+                    h.app.open_day(today - chrono::Duration::days(20));
+                    ('t', "Back")
+                }
+                action if action.starts_with("Food form") => ('f', ""),
+                _ => ('w', "151"),
+            };
+            if h.screen() == AppScreen::Startup {
+                h.press(KeyCode::Char('n')).await;
+            }
+            h.press(KeyCode::Char(open)).await;
+            h.type_text(typed).await;
+        }
+        ("Lock Screen", _) => h.app.lock.locked = true,
+        ("Syncing", _) => {
+            // Held on the syncing screen as the sync runs
+            h.app.tick_shutdown_sync().await;
+        }
+        _ => {}
+    }
+}
+
+/// Everything a key could change that's worth noticing: the screen as
+/// drawn and the state behind prompts that don't draw.
+fn fingerprint(h: &mut Harness) -> String {
+    let mut terminal = ratatui::Terminal::new(ratatui::backend::TestBackend::new(160, 48)).unwrap();
+    terminal.draw(|f| h.app.ui(f)).unwrap();
+    // Styles too: a moved highlight may be the only thing that changes
+    let screen = format!("{:?}", terminal.backend().buffer());
+    format!(
+        "{:?} {:?} {:?} {} {} {} {} {:?} {}",
+        h.app.state.current_screen,
+        h.app.state.toast.as_ref().map(|toast| &toast.message),
+        h.app.quit_prompt,
+        h.app.should_quit,
+        h.app.private,
+        h.app.lock.failed,
+        h.app.input_handler.input_buffer,
+        h.app.input_handler.suggestion_selected,
+        screen
+    )
+}
+
+#[tokio::test]
+async fn every_key_in_the_manual_does_something_on_its_screen() {
+    let mut idle = Vec::new();
+    for section in crate::manual::SECTIONS {
+        for binding in section.bindings {
+            for (index, key) in binding.keys.iter().enumerate() {
+                let mut config = AppConfig::default();
+                if section.title == "Lock Screen" {
+                    config.lock.passphrase_hash =
+                        Some(crate::lock::hash_passphrase("summit").unwrap());
+                }
+                let mut h = seeded(config).await;
+                open_section(&mut h, section.title, binding.action, key).await;
+                let other = if index == 0 { binding.keys.get(1) } else { binding.keys.first() };
+                if let Some(&other) = other && MOVES.contains(key) && MOVES.contains(&other) {
+                    let (code, modifiers) = manual_key(other);
+                    h.press_with(code, modifiers).await;
+                }
+                let before = fingerprint(&mut h);
+                let (code, modifiers) = manual_key(key);
+                h.press_with(code, modifiers).await;
+                if fingerprint(&mut h) == before {
+                    idle.push(format!("{}: {} ({})", section.title, key, binding.action));
+                }
+            }
+        }
+    }
+    assert!(idle.is_empty(), "keys in the manual that did nothing:\n{}", idle.join("\n"));
+}
@@ -0,0 +1,223 @@
+//! Packaged foods by barcode from OpenFoodFacts. A code typed or pasted into
+//! the food form is looked up once, then kept in `barcodes.json` in the data
+//! directory so the same product fills in offline from then on.
+
+use anyhow::{Context, Result, bail};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::models::{FoodEntry, Macros};
+
+/// How long to wait for OpenFoodFacts before giving up on a product.
+const FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// OpenFoodFacts asks apps to name themselves in the user agent.
+const USER_AGENT: &str = concat!(
+    "mountains-tui/",
+    env!("CARGO_PKG_VERSION"),
+    " (https://github.com/papadavis47/mountains-tui)"
+);
+
+/// What a barcode fills into the food form, per serving when the label
+/// gives one and per 100 g otherwise.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Product {
+    pub name: String,
+    pub quantity: Option<String>,
+    pub calories: Option<u32>,
+    pub macros: Option<Macros>,
+}
+
+impl Product {
+    pub fn into_entry(self) -> FoodEntry {
+        FoodEntry {
+            name: self.name,
+            quantity: self.quantity,
+            calories: self.calories,
+            macros: self.macros,
+        }
+    }
+}
+
+/// Whether `text` reads as a barcode: 8 to 14 digits, the lengths of
+/// EAN-8, UPC-A, EAN-13 and GTIN-14.
+pub fn is_barcode(text: &str) -> bool {
+    let code = text.trim();
+    (8..=14).contains(&code.len()) && code.chars().all(|c| c.is_ascii_digit())
+}
+
+/// Reads a product out of OpenFoodFacts' response. The serving's numbers
+/// are used only if the label has its calories; otherwise every number is
+/// per 100 g, so they never mix.
+fn parse_product(json: &serde_json::Value) -> Result<Product> {
+    if json.get("status").and_then(|s| s.as_i64()) != Some(1) {
+        bail!("OpenFoodFacts doesn't know that barcode");
+    }
+    let product = json.get("product").context("No product in the response")?;
+    let text = |key: &str| {
+        product
+            .get(key)
+            .and_then(|v| v.as_str())
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+    };
+    let name = text("product_name")
+        .or_else(|| text("brands"))
+        .context("That product has no name on OpenFoodFacts")?
+        .to_string();
+
+    let nutriments = product.get("nutriments");
+    let nutriment = |key: &str, per: &str| {
+        nutriments
+            .and_then(|n| n.get(format!("{}_{}", key, per)))
+            .and_then(|v| v.as_f64())
+    };
+    let serving = text("serving_size").filter(|_| nutriment("energy-kcal", "serving").is_some());
+    let (per, quantity) = match serving {
+        Some(size) => ("serving", Some(size.to_string())),
+        None => ("100g", Some("100 g".to_string())),
+    };
+    let calories = nutriment("energy-kcal", per).map(|kcal| kcal.round() as u32);
+    let macros = match (
+        nutriment("proteins", per),
+        nutriment("carbohydrates", per),
+        nutriment("fat", per),
+    ) {
+        (Some(protein), Some(carbs), Some(fat)) => Some(Macros {
+            protein: protein as f32,
+            carbs: carbs as f32,
+            fat: fat as f32,
+        }),
+        _ => None,
+    };
+    let quantity = quantity.filter(|_| calories.is_some() || macros.is_some());
+    Ok(Product {
+        name,
+        quantity,
+        calories,
+        macros,
+    })
+}
+
+fn fetch(code: &str) -> Result<Product> {
+    let url = format!(
+        "https://world.openfoodfacts.org/api/v2/product/{}.json\
+         ?fields=product_name,brands,serving_size,nutriments",
+        code
+    );
+    let json: serde_json::Value = ureq::get(&url)
+        .set("User-Agent", USER_AGENT)
+        .timeout(FETCH_TIMEOUT)
+        .call()
+        .context("Failed to reach OpenFoodFacts")?
+        .into_json()
+        .context("Failed to read OpenFoodFacts' response")?;
+    parse_product(&json)
+}
+
+/// Products looked up so far, by barcode. A missing or unreadable cache is
+/// an empty one.
+fn read_cache(path: &Path) -> HashMap<String, Product> {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+/// The product for `code`, from the cache at `cache` or else from
+/// OpenFoodFacts, caching what it finds. Blocking; run it with
+/// `spawn_blocking`.
+pub fn lookup(cache: &Path, code: &str) -> Result<Product> {
+    let code = code.trim();
+    let mut products = read_cache(cache);
+    if let Some(product) = products.get(code) {
+        return Ok(product.clone());
+    }
+    let product = fetch(code)?;
+    products.insert(code.to_string(), product.clone());
+    // A cache that can't be written only means looking it up again next time
+    if let Ok(json) = serde_json::to_string_pretty(&products) {
+        let _ = fs::write(cache, json);
+    }
+    Ok(product)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recognizes_barcodes_by_length_and_digits() {
+        assert!(is_barcode("0722252100900"));
+        assert!(is_barcode(" 96385074 "));
+        assert!(!is_barcode("1234567"));
+        assert!(!is_barcode("07222521009OO"));
+        assert!(!is_barcode("Oatmeal"));
+    }
+
+    #[test]
+    fn reads_the_serving_or_falls_back_to_100_grams() {
+        let json = serde_json::json!({
+            "status": 1,
+            "product": {
+                "product_name": "Chocolate Chip Energy Bar",
+                "serving_size": "1 bar (68 g)",
+                "nutriments": {
+                    "energy-kcal_serving": 250.4,
+                    "proteins_serving": 9.0,
+                    "carbohydrates_serving": 45.0,
+                    "fat_serving": 5.0,
+                    "energy-kcal_100g": 368.0
+                }
+            }
+        });
+        let product = parse_product(&json).unwrap();
+        assert_eq!(product.name, "Chocolate Chip Energy Bar");
+        assert_eq!(product.quantity.as_deref(), Some("1 bar (68 g)"));
+        assert_eq!(product.calories, Some(250));
+        assert_eq!(
+            product.macros,
+            Some(Macros {
+                protein: 9.0,
+                carbs: 45.0,
+                fat: 5.0
+            })
+        );
+
+        let per_100g = serde_json::json!({
+            "status": 1,
+            "product": {
+                "product_name": "",
+                "brands": "Trail Mix Co",
+                "serving_size": "1/4 cup",
+                "nutriments": { "energy-kcal_100g": 480, "proteins_100g": 14 }
+            }
+        });
+        let product = parse_product(&per_100g).unwrap();
+        assert_eq!(product.name, "Trail Mix Co");
+        assert_eq!(product.quantity.as_deref(), Some("100 g"));
+        assert_eq!((product.calories, product.macros), (Some(480), None));
+
+        let unknown = serde_json::json!({ "status": 0, "status_verbose": "product not found" });
+        assert!(parse_product(&unknown).is_err());
+    }
+
+    #[test]
+    fn cached_products_are_found_without_a_lookup() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = dir.path().join("barcodes.json");
+        let product = Product {
+            name: "Oat Milk".to_string(),
+            quantity: Some("1 cup".to_string()),
+            calories: Some(120),
+            macros: None,
+        };
+        let products = HashMap::from([("0012345678905".to_string(), product.clone())]);
+        fs::write(&cache, serde_json::to_string(&products).unwrap()).unwrap();
+        assert_eq!(lookup(&cache, " 0012345678905").unwrap(), product);
+        assert!(read_cache(&dir.path().join("missing.json")).is_empty());
+    }
+}
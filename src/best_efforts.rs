@@ -0,0 +1,88 @@
+//! The biggest days on record: the longest runs and the most vert, for the
+//! best efforts screen.
+
+use chrono::NaiveDate;
+
+use crate::models::DailyLog;
+
+/// How many days each best efforts list keeps.
+pub const BEST_EFFORTS: usize = 10;
+
+/// Which list of the best efforts screen is focused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BestEffort {
+    Miles,
+    Elevation,
+}
+
+impl BestEffort {
+    pub fn title(self) -> &'static str {
+        match self {
+            BestEffort::Miles => "Distance",
+            BestEffort::Elevation => "Vert",
+        }
+    }
+
+    /// The other list.
+    pub fn toggle(self) -> Self {
+        match self {
+            BestEffort::Miles => BestEffort::Elevation,
+            BestEffort::Elevation => BestEffort::Miles,
+        }
+    }
+
+    /// The day's value for this list, in stored units.
+    fn value(self, log: &DailyLog) -> Option<f32> {
+        match self {
+            BestEffort::Miles => log.miles_covered,
+            BestEffort::Elevation => log.elevation_gain.map(|feet| feet as f32),
+        }
+    }
+}
+
+/// Up to `limit` days with the most of `effort`, biggest first. Days with
+/// none logged are left out, and on a tie the earlier day ranks higher.
+pub fn top_days(logs: &[DailyLog], effort: BestEffort, limit: usize) -> Vec<(NaiveDate, f32)> {
+    let mut days: Vec<(NaiveDate, f32)> = logs
+        .iter()
+        .filter_map(|log| Some((log.date, effort.value(log)?)))
+        .filter(|&(_, value)| value > 0.0)
+        .collect();
+    days.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    days.truncate(limit);
+    days
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(d: u32, miles: Option<f32>, elevation: Option<i32>) -> DailyLog {
+        DailyLog {
+            miles_covered: miles,
+            elevation_gain: elevation,
+            ..DailyLog::new(NaiveDate::from_ymd_opt(2026, 5, d).unwrap())
+        }
+    }
+
+    #[test]
+    fn ranks_the_biggest_days_and_skips_empty_ones() {
+        let date = |d| NaiveDate::from_ymd_opt(2026, 5, d).unwrap();
+        let logs = vec![
+            day(1, Some(8.0), Some(1200)),
+            day(2, Some(20.5), None),
+            day(3, None, Some(4100)),
+            day(4, Some(8.0), Some(0)),
+            day(5, Some(3.0), Some(900)),
+        ];
+        assert_eq!(
+            top_days(&logs, BestEffort::Miles, 3),
+            vec![(date(2), 20.5), (date(1), 8.0), (date(4), 8.0)]
+        );
+        assert_eq!(
+            top_days(&logs, BestEffort::Elevation, BEST_EFFORTS),
+            vec![(date(3), 4100.0), (date(1), 1200.0), (date(5), 900.0)]
+        );
+        assert!(top_days(&[], BestEffort::Miles, BEST_EFFORTS).is_empty());
+    }
+}
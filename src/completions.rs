@@ -0,0 +1,298 @@
+//! `mountains completions <shell>`: prints a completion script for bash, zsh
+//! or fish. Every script, and the man page's synopsis, is built from
+//! `COMMANDS`, so a new subcommand only needs an entry here to show up in all
+//! of them.
+
+/// A shell we can generate completions for.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Shell {
+    Bash,
+    Zsh,
+    Fish,
+}
+
+impl Shell {
+    pub const NAMES: [&'static str; 3] = ["bash", "zsh", "fish"];
+
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "bash" => Some(Shell::Bash),
+            "zsh" => Some(Shell::Zsh),
+            "fish" => Some(Shell::Fish),
+            _ => None,
+        }
+    }
+}
+
+/// What a positional argument completes to.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Arg {
+    Directory,
+    /// A file with this extension.
+    File(&'static str),
+    Shell,
+}
+
+pub struct Command {
+    pub name: &'static str,
+    pub about: &'static str,
+    /// Arguments as shown in usage lines.
+    pub usage: &'static str,
+    args: &'static [Arg],
+}
+
+pub const COMMANDS: &[Command] = &[
+    Command {
+        name: "import",
+        about: "Import markdown files from another journal app",
+        usage: "<DIR> <MAPPING.toml>",
+        args: &[Arg::Directory, Arg::File("toml")],
+    },
+    Command {
+        name: "plan",
+        about: "Import a training plan",
+        usage: "<PLAN.csv>",
+        args: &[Arg::File("csv")],
+    },
+    Command {
+        name: "completions",
+        about: "Print a shell completion script",
+        usage: "<bash|zsh|fish>",
+        args: &[Arg::Shell],
+    },
+    Command {
+        name: "man",
+        about: "Print the man page",
+        usage: "",
+        args: &[],
+    },
+    Command {
+        name: "passphrase",
+        about: "Set or remove the startup passphrase",
+        usage: "",
+        args: &[],
+    },
+];
+
+/// Flags accepted before any subcommand: (short, long, the value it
+/// takes if any, description).
+pub const FLAGS: &[(char, &str, Option<&str>, &str)] = &[
+    ('d', "date", Some("DATE"), "Open straight on that day"),
+    ('h', "help", None, "Print help"),
+    ('V', "version", None, "Print version information"),
+];
+
+/// What `--date` completes to besides the last week's ISO dates, which the
+/// scripts work out when they run rather than when they're generated.
+const DATE_WORDS: &str = "today yesterday";
+
+/// Prints the last week's dates, newest first. GNU date takes `-d`, the BSD
+/// one on macOS `-v`.
+const RECENT_DATES: &str =
+    r#"for i in 0 1 2 3 4 5 6; do date -d "-$i days" +%F 2>/dev/null || date -v-${i}d +%F; done"#;
+
+pub fn script(shell: Shell) -> String {
+    match shell {
+        Shell::Bash => bash(),
+        Shell::Zsh => zsh(),
+        Shell::Fish => fish(),
+    }
+}
+
+fn bash() -> String {
+    let mut words: Vec<String> = COMMANDS.iter().map(|c| c.name.to_string()).collect();
+    for (short, long, _, _) in FLAGS {
+        words.push(format!("-{short}"));
+        words.push(format!("--{long}"));
+    }
+
+    let mut cases = String::new();
+    for command in COMMANDS.iter().filter(|c| !c.args.is_empty()) {
+        cases.push_str(&format!("        {})\n", command.name));
+        cases.push_str("            case $COMP_CWORD in\n");
+        for (position, arg) in command.args.iter().enumerate() {
+            let reply = match arg {
+                Arg::Directory => "compgen -d -- \"$cur\"".to_string(),
+                Arg::File(_) => "compgen -f -- \"$cur\"".to_string(),
+                Arg::Shell => format!("compgen -W \"{}\" -- \"$cur\"", Shell::NAMES.join(" ")),
+            };
+            cases.push_str(&format!(
+                "                {}) COMPREPLY=($({reply})) ;;\n",
+                position + 2
+            ));
+        }
+        cases.push_str("            esac\n            ;;\n");
+    }
+
+    format!(
+        r#"# bash completion for mountains
+_mountains_dates() {{
+    {RECENT_DATES}
+}}
+
+_mountains() {{
+    local cur=${{COMP_WORDS[COMP_CWORD]}}
+    case "${{COMP_WORDS[COMP_CWORD-1]}}" in
+        -d|--date)
+            COMPREPLY=($(compgen -W "{DATE_WORDS} $(_mountains_dates)" -- "$cur"))
+            return
+            ;;
+    esac
+    if [[ $COMP_CWORD -eq 1 ]]; then
+        COMPREPLY=($(compgen -W "{words}" -- "$cur"))
+        return
+    fi
+    case "${{COMP_WORDS[1]}}" in
+{cases}    esac
+}}
+complete -o filenames -F _mountains mountains
+"#,
+        words = words.join(" ")
+    )
+}
+
+fn zsh() -> String {
+    let commands: String = COMMANDS
+        .iter()
+        .map(|c| format!("        '{}:{}'\n", c.name, c.about))
+        .collect();
+    let flags: String = FLAGS
+        .iter()
+        .map(|(short, long, value, about)| match value {
+            Some(value) => format!(
+                "        '(-{short} --{long})'{{-{short},--{long}}}'[{about}]:{}:{}' \\\n",
+                value.to_lowercase(),
+                if *long == "date" { "_mountains_dates" } else { "" }
+            ),
+            None => format!("        '(- *)'{{-{short},--{long}}}'[{about}]' \\\n"),
+        })
+        .collect();
+
+    let mut cases = String::new();
+    for command in COMMANDS.iter().filter(|c| !c.args.is_empty()) {
+        let specs: Vec<String> = command
+            .args
+            .iter()
+            .enumerate()
+            .map(|(position, arg)| {
+                let action = match arg {
+                    Arg::Directory => "directory:_files -/".to_string(),
+                    Arg::File(ext) => format!("{ext} file:_files -g \"*.{ext}\""),
+                    Arg::Shell => format!("shell:({})", Shell::NAMES.join(" ")),
+                };
+                format!("'{}:{action}'", position + 1)
+            })
+            .collect();
+        cases.push_str(&format!(
+            "                {}) _arguments {} ;;\n",
+            command.name,
+            specs.join(" ")
+        ));
+    }
+
+    format!(
+        r#"#compdef mountains
+
+_mountains_dates() {{
+    local -a dates
+    dates=({DATE_WORDS} $({RECENT_DATES}))
+    _describe 'date' dates
+}}
+
+_mountains() {{
+    local -a commands
+    commands=(
+{commands}    )
+    _arguments -C \
+{flags}        '1: :->command' \
+        '*:: :->args'
+    case $state in
+        command) _describe 'command' commands ;;
+        args)
+            case $words[1] in
+{cases}            esac
+            ;;
+    esac
+}}
+
+if [ "$funcstack[1]" = "_mountains" ]; then
+    _mountains "$@"
+else
+    compdef _mountains mountains
+fi
+"#
+    )
+}
+
+fn fish() -> String {
+    let mut lines = vec![
+        "function __mountains_dates".to_string(),
+        "    for i in (seq 0 6)".to_string(),
+        "        date -d \"-$i days\" +%F 2>/dev/null; or date -v-\"$i\"d +%F".to_string(),
+        "    end".to_string(),
+        "end".to_string(),
+        "complete -c mountains -f".to_string(),
+    ];
+    for (short, long, value, about) in FLAGS {
+        // -r: the flag takes a value, which isn't a file here
+        let takes_value = match value {
+            Some(_) if *long == "date" => format!(" -r -a '{DATE_WORDS} (__mountains_dates)'"),
+            Some(_) => " -r".to_string(),
+            None => String::new(),
+        };
+        lines.push(format!(
+            "complete -c mountains -n __fish_use_subcommand -s {short} -l {long}{takes_value} -d '{about}'"
+        ));
+    }
+    for command in COMMANDS {
+        lines.push(format!(
+            "complete -c mountains -n __fish_use_subcommand -a {} -d '{}'",
+            command.name, command.about
+        ));
+        let condition = format!("'__fish_seen_subcommand_from {}'", command.name);
+        for arg in command.args {
+            let action = match arg {
+                Arg::Directory => "-a '(__fish_complete_directories)'".to_string(),
+                Arg::File(_) => "-F".to_string(),
+                Arg::Shell => format!("-a '{}'", Shell::NAMES.join(" ")),
+            };
+            let line = format!("complete -c mountains -n {condition} {action}");
+            if !lines.contains(&line) {
+                lines.push(line);
+            }
+        }
+    }
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_shell_offers_every_command() {
+        for name in Shell::NAMES {
+            let script = script(Shell::parse(name).unwrap());
+            for command in COMMANDS {
+                assert!(script.contains(command.name), "{name}: {}", command.name);
+            }
+            assert!(script.contains("version"), "{name}");
+            assert!(script.contains("date"), "{name}");
+            // --date offers words and the last week's days, not files
+            assert!(script.contains("today yesterday"), "{name}");
+            assert!(script.contains("date -d \"-$i days\" +%F"), "{name}");
+        }
+        assert_eq!(Shell::parse("powershell"), None);
+
+        let bash = script(Shell::Bash);
+        assert!(bash.contains("2) COMPREPLY=($(compgen -d -- \"$cur\")) ;;"));
+        assert!(bash.contains("complete -o filenames -F _mountains mountains"));
+        assert!(bash.contains("-d|--date)"));
+        let zsh = script(Shell::Zsh);
+        assert!(zsh.starts_with("#compdef mountains\n"));
+        assert!(zsh.contains("[Open straight on that day]:date:_mountains_dates'"));
+        let fish = script(Shell::Fish);
+        assert!(fish.contains("-n '__fish_seen_subcommand_from completions' -a 'bash zsh fish'"));
+        assert!(fish.contains("-l date -r -a 'today yesterday (__mountains_dates)'"));
+    }
+}
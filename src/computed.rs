@@ -0,0 +1,407 @@
+//! Computed fields: user formulas from `[[computed.fields]]`, worked out
+//! from the day's log and the days before it and shown in the daily view's
+//! Computed section. Formulas are Rhai expressions over the day's numbers
+//! plus `sum`, `avg` and `max` over recent days, e.g.
+//! `sum("vert", 7) / 1000 + avg("miles", 7)`.
+//!
+//! Each formula is compiled once, when the config loads. The engine can only
+//! read numbers handed to it: no modules, no printing, and limits on nesting
+//! and on the work a formula may do, so a bad one shows an error instead of
+//! hanging the daily view.
+
+use chrono::{Days, NaiveDate};
+use rhai::{AST, Dynamic, Engine, EvalAltResult, Scope};
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, RwLock};
+
+use crate::models::DailyLog;
+
+/// Longest window a function may look back over.
+const MAX_WINDOW_DAYS: i64 = 3650;
+/// How deeply expressions, and the bodies of functions a formula defines,
+/// may nest.
+const MAX_EXPR_DEPTH: usize = 32;
+const MAX_FUNCTION_EXPR_DEPTH: usize = 16;
+/// Work a formula may do before it's stopped, which also ends loops.
+const MAX_OPERATIONS: u64 = 100_000;
+
+/// One formula to show.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ComputedField {
+    pub name: String,
+    pub formula: String,
+    pub decimals: usize,
+}
+
+impl Default for ComputedField {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            formula: String::new(),
+            decimals: 1,
+        }
+    }
+}
+
+/// A number a formula can name, read from one day's log. Missing values
+/// read as 0, and days without them are left out of averages.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Variable {
+    Miles,
+    Vert,
+    Weight,
+    Waist,
+    Sleep,
+    RestingHr,
+    Hrv,
+    Energy,
+    Rpe,
+    Duration,
+    AvgHr,
+    MaxHr,
+    Calories,
+    Foods,
+    Sokay,
+}
+
+/// Every variable, by the name formulas use for it.
+const VARIABLES: [(&str, Variable); 15] = [
+    ("miles", Variable::Miles),
+    ("vert", Variable::Vert),
+    ("weight", Variable::Weight),
+    ("waist", Variable::Waist),
+    ("sleep", Variable::Sleep),
+    ("resting_hr", Variable::RestingHr),
+    ("hrv", Variable::Hrv),
+    ("energy", Variable::Energy),
+    ("rpe", Variable::Rpe),
+    ("duration", Variable::Duration),
+    ("avg_hr", Variable::AvgHr),
+    ("max_hr", Variable::MaxHr),
+    ("calories", Variable::Calories),
+    ("foods", Variable::Foods),
+    ("sokay", Variable::Sokay),
+];
+
+impl Variable {
+    fn read(self, log: &DailyLog) -> Option<f64> {
+        match self {
+            Variable::Miles => log.miles_covered.map(f64::from),
+            Variable::Vert => log.elevation_gain.map(f64::from),
+            Variable::Weight => log.weight.map(f64::from),
+            Variable::Waist => log.waist.map(f64::from),
+            Variable::Sleep => log.sleep_hours.map(f64::from),
+            Variable::RestingHr => log.resting_hr.map(f64::from),
+            Variable::Hrv => log.hrv.map(f64::from),
+            Variable::Energy => log.energy.map(f64::from),
+            Variable::Rpe => log.rpe.map(f64::from),
+            Variable::Duration => log.duration_secs.map(|seconds| seconds as f64 / 60.0),
+            Variable::AvgHr => log.avg_hr.map(f64::from),
+            Variable::MaxHr => log.max_hr.map(f64::from),
+            Variable::Calories => log.calories().map(f64::from),
+            Variable::Foods => Some(log.food_entries.len() as f64),
+            Variable::Sokay => Some(log.sokay_entries.len() as f64),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Window {
+    Sum,
+    Avg,
+    Max,
+}
+
+impl Window {
+    fn name(self) -> &'static str {
+        match self {
+            Window::Sum => "sum",
+            Window::Avg => "avg",
+            Window::Max => "max",
+        }
+    }
+}
+
+/// The days a formula's windows can reach, newest first: each day's date
+/// and its variables in `VARIABLES` order.
+type History = Vec<(NaiveDate, [Option<f64>; VARIABLES.len()])>;
+
+/// `sum("vert", 7)` and the like: the variable over the last `days` days,
+/// the latest day in `history` included.
+fn window(
+    history: &RwLock<History>,
+    window: Window,
+    name: &str,
+    days: i64,
+) -> Result<f64, Box<EvalAltResult>> {
+    let index = VARIABLES
+        .iter()
+        .position(|(variable, _)| *variable == name)
+        .ok_or_else(|| format!("unknown name '{}'", name))?;
+    if !(1..=MAX_WINDOW_DAYS).contains(&days) {
+        return Err(format!("{}() needs 1 to {} days", window.name(), MAX_WINDOW_DAYS).into());
+    }
+    let history = history.read().map_err(|_| "history unavailable".to_string())?;
+    let Some((date, _)) = history.first() else {
+        return Ok(0.0);
+    };
+    let first = date
+        .checked_sub_days(Days::new(days as u64 - 1))
+        .unwrap_or(NaiveDate::MIN);
+    let values: Vec<f64> = history
+        .iter()
+        .take_while(|(day, _)| *day >= first)
+        .filter_map(|(_, values)| values[index])
+        .collect();
+    Ok(match window {
+        Window::Sum => values.iter().fold(0.0, |total, value| total + value),
+        Window::Avg if values.is_empty() => 0.0,
+        Window::Avg => values.iter().sum::<f64>() / values.len() as f64,
+        Window::Max => values.into_iter().fold(0.0, f64::max),
+    })
+}
+
+/// A configured field with its formula compiled, or why it didn't compile.
+#[derive(Debug)]
+struct Compiled {
+    name: String,
+    decimals: usize,
+    ast: Result<AST, String>,
+}
+
+/// The `[[computed.fields]]` formulas, compiled once when the config loads
+/// and worked out for whichever day the daily view shows.
+#[derive(Debug)]
+pub struct Formulas {
+    engine: Engine,
+    fields: Vec<Compiled>,
+    /// What the window functions read, filled in before each evaluation.
+    history: Arc<RwLock<History>>,
+}
+
+impl Default for Formulas {
+    fn default() -> Self {
+        Self::compile(&[])
+    }
+}
+
+impl Formulas {
+    pub fn compile(fields: &[ComputedField]) -> Self {
+        let history = Arc::new(RwLock::new(History::new()));
+        let mut engine = Engine::new();
+        engine
+            .set_max_expr_depths(MAX_EXPR_DEPTH, MAX_FUNCTION_EXPR_DEPTH)
+            .set_max_operations(MAX_OPERATIONS)
+            .set_max_call_levels(8)
+            .set_max_string_size(1000)
+            .set_max_array_size(1000)
+            .set_max_map_size(1000)
+            .set_strict_variables(true)
+            .set_module_resolver(rhai::module_resolvers::DummyModuleResolver::new())
+            .on_print(|_| {})
+            .on_debug(|_, _, _| {});
+        engine.disable_symbol("eval");
+        for kind in [Window::Sum, Window::Avg, Window::Max] {
+            let history = Arc::clone(&history);
+            engine.register_fn(kind.name(), move |name: &str, days: i64| {
+                window(&history, kind, name, days)
+            });
+        }
+
+        // Compiling against the variables catches misspelled names up front.
+        // They're plain variables here, as constants would be folded into
+        // the compiled formula with their placeholder zeros.
+        let mut scope = Scope::new();
+        for (name, _) in VARIABLES {
+            scope.push(name, 0.0);
+        }
+        let fields = fields
+            .iter()
+            .map(|field| Compiled {
+                name: field.name.clone(),
+                decimals: field.decimals,
+                ast: engine
+                    .compile_with_scope(&scope, &field.formula)
+                    .map_err(|err| err.to_string()),
+            })
+            .collect();
+        Self {
+            engine,
+            fields,
+            history,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Each field's name and its value for `date`, formatted, or what's
+    /// wrong with the formula.
+    pub fn values(&self, logs: &[DailyLog], date: NaiveDate) -> Vec<(&str, String)> {
+        if self.fields.is_empty() {
+            return Vec::new();
+        }
+        let first = date
+            .checked_sub_days(Days::new(MAX_WINDOW_DAYS as u64 - 1))
+            .unwrap_or(NaiveDate::MIN);
+        let mut days: History = logs
+            .iter()
+            .filter(|log| log.date >= first && log.date <= date)
+            .map(|log| (log.date, VARIABLES.map(|(_, variable)| variable.read(log))))
+            .collect();
+        days.sort_by_key(|(day, _)| std::cmp::Reverse(*day));
+        // Windows count back from `date` even when it has no log
+        if days.first().is_none_or(|(day, _)| *day != date) {
+            days.insert(0, (date, [None; VARIABLES.len()]));
+        }
+        if let Ok(mut history) = self.history.write() {
+            *history = days;
+        }
+
+        let mut scope = day_scope(logs.iter().find(|log| log.date == date));
+        let values = self
+            .fields
+            .iter()
+            .map(|field| {
+                let value = match &field.ast {
+                    Ok(ast) => self.evaluate(&mut scope, ast),
+                    Err(err) => Err(err.clone()),
+                };
+                let shown = match value {
+                    // Adding 0.0 turns a -0.0 into a plain 0
+                    Ok(value) if value.is_finite() => {
+                        format!("{:.*}", field.decimals, value + 0.0)
+                    }
+                    Ok(_) => "-".to_string(),
+                    Err(err) => format!("error: {}", err),
+                };
+                (field.name.as_str(), shown)
+            })
+            .collect();
+        if let Ok(mut history) = self.history.write() {
+            history.clear();
+        }
+        values
+    }
+
+    fn evaluate(&self, scope: &mut Scope, ast: &AST) -> Result<f64, String> {
+        let size = scope.len();
+        let result = self.engine.eval_ast_with_scope::<Dynamic>(scope, ast);
+        // Drop anything the formula declared before the next one runs
+        scope.rewind(size);
+        let value = result.map_err(|err| match *err {
+            EvalAltResult::ErrorRuntime(message, _) => message.to_string(),
+            err => err.to_string(),
+        })?;
+        value
+            .as_float()
+            .ok()
+            .or_else(|| value.as_int().ok().map(|value| value as f64))
+            .ok_or_else(|| format!("gives {}, not a number", value.type_name()))
+    }
+}
+
+/// The day's variables as constants, 0 where nothing's logged.
+fn day_scope(log: Option<&DailyLog>) -> Scope<'static> {
+    let mut scope = Scope::new();
+    for (name, variable) in VARIABLES {
+        let value = log.and_then(|log| variable.read(log)).unwrap_or(0.0);
+        scope.push_constant(name, value);
+    }
+    scope
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 7, d).unwrap()
+    }
+
+    fn logs() -> Vec<DailyLog> {
+        [(1, 4.0, 800), (5, 6.0, 1200), (6, 10.0, 3000), (7, 8.0, 2000)]
+            .into_iter()
+            .map(|(d, miles, vert)| DailyLog {
+                miles_covered: Some(miles),
+                elevation_gain: Some(vert),
+                ..DailyLog::new(day(d))
+            })
+            .collect()
+    }
+
+    /// Each formula's shown value for `date`, two decimals.
+    fn values(formulas: &[&str], date: NaiveDate) -> Vec<String> {
+        let fields: Vec<ComputedField> = formulas
+            .iter()
+            .map(|formula| ComputedField {
+                name: formula.to_string(),
+                formula: formula.to_string(),
+                decimals: 2,
+            })
+            .collect();
+        Formulas::compile(&fields)
+            .values(&logs(), date)
+            .into_iter()
+            .map(|(_, value)| value)
+            .collect()
+    }
+
+    #[test]
+    fn formulas_follow_precedence_and_read_the_day() {
+        assert_eq!(
+            values(&["1 + 2 * 3", "(1 + 2) * -3", "vert / miles"], day(7)),
+            ["7.00", "-9.00", "250.00"]
+        );
+        // Nothing logged reads as zero
+        assert_eq!(values(&["miles + weight"], day(3)), ["0.00"]);
+    }
+
+    #[test]
+    fn windows_cover_the_day_and_the_days_before() {
+        assert_eq!(
+            values(
+                &[
+                    r#"sum("vert", 3)"#,
+                    r#"sum("vert", 7)"#,
+                    // Averages skip days with nothing logged
+                    r#"avg("miles", 7)"#,
+                    r#"avg("weight", 7)"#,
+                ],
+                day(7)
+            ),
+            ["6200.00", "7000.00", "7.00", "0.00"]
+        );
+        assert_eq!(values(&[r#"max("miles", 2 + 1)"#], day(6)), ["10.00"]);
+        // A day with no log still counts back from itself
+        assert_eq!(values(&[r#"sum("miles", 2)"#], day(8)), ["8.00"]);
+    }
+
+    #[test]
+    fn bad_formulas_say_what_is_wrong_without_hanging() {
+        let shown = values(
+            &[
+                "vertt * 2",
+                r#"median("vert", 7)"#,
+                r#"sum("vertt", 7)"#,
+                r#"sum("vert", 0)"#,
+                "1 +",
+                "loop {}",
+                &format!("{}1{}", "(".repeat(100), ")".repeat(100)),
+                r#""miles""#,
+            ],
+            day(7),
+        );
+        assert!(shown[0].starts_with("error: ") && shown[0].contains("vertt"));
+        assert!(shown[1].contains("median"));
+        assert_eq!(shown[2], "error: unknown name 'vertt'");
+        assert_eq!(shown[3], "error: sum() needs 1 to 3650 days");
+        assert!(shown[4].starts_with("error: "));
+        assert!(shown[5].starts_with("error: ") && shown[5].contains("operations"));
+        assert!(shown[6].starts_with("error: "));
+        assert_eq!(shown[7], "error: gives string, not a number");
+        assert_eq!(values(&["vert / miles"], day(3)), ["-"]);
+    }
+}
@@ -1,49 +1,393 @@
 use anyhow::{Context, Result};
+use ratatui::style::Color;
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+use crate::models::DailyLog;
+use crate::models::home_filter::SavedView;
+use crate::computed::ComputedField;
+use crate::streaks::StreakRule;
+use crate::ui::theme::{Theme, ThemeName};
+use crate::units::{Quantity, UnitSystem};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct AppConfig {
     pub sync: SyncConfig,
+    pub template: DayTemplate,
+    pub validation: ValidationConfig,
+    pub display: DisplayConfig,
+    pub keys: KeysConfig,
+    pub stats: StatsConfig,
+    pub storage: StorageConfig,
+    pub lock: LockConfig,
+    pub session: SessionConfig,
+    pub health: HealthConfig,
+    pub streaks: StreaksConfig,
+    pub hooks: HooksConfig,
+    pub computed: ComputedConfig,
+    pub food: FoodConfig,
+    pub hydration: HydrationConfig,
+    pub notes: NotesConfig,
+    pub heart_rate: HeartRateConfig,
+    pub gear: GearConfig,
+    pub weather: WeatherConfig,
+    pub home: HomeConfig,
+    pub goals: GoalsConfig,
+    pub colors: ColorConfig,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SyncConfig {
     pub enabled: bool,
     pub db_url: String,
     pub auth_token: String,
+    /// Sync before starting today's log, so anything logged on another
+    /// device today shows up before editing begins.
+    #[serde(default)]
+    pub sync_on_new_day: bool,
+}
+
+impl SyncConfig {
+    pub fn is_configured(&self) -> bool {
+        self.enabled && !self.db_url.is_empty() && !self.auth_token.is_empty()
+    }
+}
+
+/// Sections pre-filled into a newly created day when `enabled`, e.g. a standard
+/// supplements checklist or a weigh-in reminder in the notes.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DayTemplate {
+    pub enabled: bool,
+    pub food_entries: Vec<String>,
+    pub sokay_entries: Vec<String>,
+    pub strength_mobility: Option<String>,
+    pub notes: Option<String>,
+}
+
+impl DayTemplate {
+    /// Builds the log for a day that doesn't exist yet: blank when the template
+    /// is disabled, otherwise pre-filled with the configured sections.
+    pub fn new_day(&self, date: chrono::NaiveDate) -> DailyLog {
+        let mut log = DailyLog::new(date);
+        if !self.enabled {
+            return log;
+        }
+
+        for name in self.food_entries.iter().filter(|n| !n.trim().is_empty()) {
+            log.add_food_entry(crate::models::FoodEntry::new(name.clone()));
+        }
+        for entry in self.sokay_entries.iter().filter(|e| !e.trim().is_empty()) {
+            log.add_sokay_entry(entry.clone());
+        }
+        log.strength_mobility = self
+            .strength_mobility
+            .clone()
+            .filter(|text| !text.trim().is_empty());
+        log.notes = self.notes.clone().filter(|text| !text.trim().is_empty());
+        log
+    }
 }
 
-impl Default for AppConfig {
+/// Sanity checks applied before a value is saved.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ValidationConfig {
+    /// Ask for confirmation when a weight is further than this many lbs from
+    /// the recent average. 0 disables the check.
+    pub weight_threshold: f32,
+    /// Warn when elevation gain per mile exceeds this, or elevation is
+    /// entered without miles. 0 disables the check.
+    pub max_feet_per_mile: i32,
+}
+
+impl Default for ValidationConfig {
     fn default() -> Self {
         Self {
-            sync: SyncConfig::default(),
+            weight_threshold: 8.0,
+            max_feet_per_mile: 1500,
         }
     }
 }
 
-impl Default for SyncConfig {
+/// How training totals and streaks are counted.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StatsConfig {
+    /// Daily elevation gain in feet that makes a vert day and extends a streak.
+    pub vert_threshold: i32,
+    /// First day of the week for weekly totals, charts and the week view.
+    pub week_start: chrono::Weekday,
+    /// Count indoor days (treadmill miles) in vert per mile and the distance
+    /// and vert streaks. Off, they're left out, holding a streak without
+    /// adding to it.
+    pub count_indoor: bool,
+    /// Miles a run needs to be a long run, for the week view's streak of
+    /// weeks with one.
+    pub long_run_miles: f32,
+    /// Last 7 days' distance over the 28-day weekly average that warns
+    /// on the daily view; 0 turns the warning off.
+    pub ramp_warning: f32,
+}
+
+impl Default for StatsConfig {
     fn default() -> Self {
         Self {
-            enabled: false,
-            db_url: String::new(),
-            auth_token: String::new(),
+            vert_threshold: 1000,
+            week_start: chrono::Weekday::Mon,
+            count_indoor: true,
+            long_run_miles: 10.0,
+            ramp_warning: 1.5,
         }
     }
 }
 
-impl SyncConfig {
-    pub fn is_configured(&self) -> bool {
-        self.enabled && !self.db_url.is_empty() && !self.auth_token.is_empty()
+/// Where the database and markdown logs live. config.toml itself always stays
+/// in the default data directory so it can be found at startup.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StorageConfig {
+    pub data_dir: Option<String>,
+}
+
+/// The passphrase lock. Set the passphrase with `mountains passphrase`
+/// rather than by hand; only its Argon2 hash is stored.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LockConfig {
+    pub passphrase_hash: Option<String>,
+    /// Lock again after this many minutes without input. 0 locks only at
+    /// startup.
+    pub idle_minutes: u32,
+}
+
+/// The startup data health banner.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HealthConfig {
+    /// Warn when the last sync, or the last markdown backup behind the
+    /// latest save, is this many days old. 0 turns the banner off.
+    pub stale_after_days: u32,
+}
+
+impl Default for HealthConfig {
+    fn default() -> Self {
+        Self {
+            stale_after_days: 7,
+        }
     }
 }
 
-pub fn data_dir() -> Result<PathBuf> {
-    if let Ok(dir) = std::env::var("MOUNTAINS_DATA_DIR") {
-        return Ok(PathBuf::from(dir));
+/// Streaks tracked on the statistics screen, as `[[streaks.rules]]`
+/// entries. With none, the vert streak from `[stats] vert_threshold` is
+/// shown.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StreaksConfig {
+    pub rules: Vec<StreakRule>,
+}
+
+/// Formulas shown in the daily view's Computed section, as
+/// `[[computed.fields]]` entries.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ComputedConfig {
+    pub fields: Vec<ComputedField>,
+}
+
+/// The logged days list.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HomeConfig {
+    /// Named filters for the Views menu, as `[[home.views]]` entries. Saving
+    /// one from the menu writes it here.
+    pub views: Vec<SavedView>,
+}
+
+/// Food logging.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FoodConfig {
+    /// Calories to aim for each day, shown as a gauge over the food list
+    /// once a day's food has calories. Unset hides the gauge.
+    pub calorie_target: Option<u32>,
+    /// OCR program for reading food off a meal or receipt photo, called as
+    /// `<command> <image> stdout` like tesseract. Unset turns it off.
+    pub ocr_command: Option<String>,
+}
+
+/// Targets to train toward, each shown with its progress and where the
+/// current pace ends up.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GoalsConfig {
+    /// Miles to run each week, always in miles like `[stats]
+    /// long_run_miles`. Set, the week view shows a gauge toward it.
+    pub weekly_miles: Option<f32>,
+    /// Feet of vert to climb each year, always in feet like `[stats]
+    /// vert_threshold`. Set, the startup screen shows how far along the
+    /// year is and where its pace ends up.
+    pub yearly_vert: Option<i32>,
+}
+
+/// Notes as a journal.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NotesConfig {
+    /// Words to write each day. Set, the Notes section counts toward it and
+    /// a journaling streak joins the streaks. Unset hides both.
+    pub word_goal: Option<u32>,
+}
+
+/// Heart rate zones for the week view's time in zone.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HeartRateConfig {
+    /// Heart rates in bpm where zone 2 and each zone after it start, e.g.
+    /// `[125, 145, 160, 175]` for five zones. Empty hides time in zone.
+    pub zones: Vec<u32>,
+}
+
+impl HeartRateConfig {
+    /// The zone starts in order, without repeats or zeros.
+    pub fn zone_starts(&self) -> Vec<u32> {
+        let mut starts: Vec<u32> = self.zones.iter().copied().filter(|&bpm| bpm > 0).collect();
+        starts.sort_unstable();
+        starts.dedup();
+        starts
+    }
+}
+
+/// Water tracking on the daily view.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HydrationConfig {
+    /// How much `+` adds, in the display units (oz or L). Unset means a
+    /// glass: 8 oz, or 0.25 L in metric.
+    pub glass: Option<f32>,
+}
+
+impl HydrationConfig {
+    /// One glass in stored fluid ounces.
+    pub fn glass_ounces(&self, units: UnitSystem) -> f32 {
+        let shown = self.glass.filter(|glass| *glass > 0.0).unwrap_or(match units {
+            UnitSystem::Imperial => 8.0,
+            UnitSystem::Metric => 0.25,
+        });
+        units.store(Quantity::Water, shown as f64) as f32
+    }
+}
+
+/// Shoe and gear tracking.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GearConfig {
+    /// Distance in the display units after which gear should be retired;
+    /// it's flagged as it gets close. 0 turns the warning off.
+    pub retire_after: f32,
+}
+
+impl Default for GearConfig {
+    fn default() -> Self {
+        Self { retire_after: 400.0 }
     }
-    let home = dirs::home_dir().context("Could not find home directory")?;
-    Ok(home.join(".mountains"))
+}
+
+impl GearConfig {
+    /// `retire_after` in stored miles.
+    pub fn retire_after_miles(&self, units: UnitSystem) -> f32 {
+        units.store(Quantity::Distance, self.retire_after.max(0.0) as f64) as f32
+    }
+}
+
+/// Where to look up the weather for days with a run. Unset, nothing is
+/// fetched.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WeatherConfig {
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+}
+
+impl WeatherConfig {
+    /// Latitude and longitude, once both are set and on the globe.
+    pub fn location(&self) -> Option<(f64, f64)> {
+        let (latitude, longitude) = self.latitude.zip(self.longitude)?;
+        ((-90.0..=90.0).contains(&latitude) && (-180.0..=180.0).contains(&longitude))
+            .then_some((latitude, longitude))
+    }
+}
+
+/// Scripts to run after a save, after a sync and before quitting, each
+/// given the day as JSON on stdin. Run through `sh -c` (`cmd /C` on
+/// Windows).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HooksConfig {
+    pub post_save: Option<String>,
+    pub post_sync: Option<String>,
+    pub pre_quit: Option<String>,
+}
+
+/// What to do when the app is left open without input.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SessionConfig {
+    /// Minutes without input before `idle_action` runs. 0 disables it.
+    pub idle_minutes: u32,
+    pub idle_action: IdleAction,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IdleAction {
+    /// Push pending changes to the cloud and carry on.
+    #[default]
+    Sync,
+    /// Sync, then quit as if q had been pressed and any quit prompt
+    /// answered y: an edit left open is dropped.
+    Exit,
+    /// Sync, then lock (or hide the screen when no passphrase is set).
+    Lock,
+}
+
+/// Overrides for the theme's daily view section accents. Takes any color
+/// name ratatui knows ("lightred", "magenta") or a hex value ("#ff8800").
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ColorConfig {
+    pub measurements: Option<String>,
+    pub running: Option<String>,
+    pub food: Option<String>,
+    pub sokay: Option<String>,
+    pub strength: Option<String>,
+    pub notes: Option<String>,
+}
+
+impl ColorConfig {
+    /// The theme with these overrides applied; a name that doesn't parse
+    /// keeps the theme's color.
+    pub fn apply(&self, mut theme: Theme) -> Theme {
+        let apply = |name: &Option<String>, color: &mut Color| {
+            if let Some(parsed) = name.as_deref().and_then(|name| name.trim().parse().ok()) {
+                *color = parsed;
+            }
+        };
+        let sections = &mut theme.sections;
+        apply(&self.measurements, &mut sections.measurements);
+        apply(&self.running, &mut sections.running);
+        apply(&self.food, &mut sections.food);
+        apply(&self.sokay, &mut sections.sokay);
+        apply(&self.strength, &mut sections.strength);
+        apply(&self.notes, &mut sections.notes);
+        theme
+    }
+}
+
+pub fn data_dir() -> Result<PathBuf> {
+    let overridden = std::env::var("MOUNTAINS_DATA_DIR").ok();
+    crate::platform::resolve_data_dir(overridden.as_deref(), dirs::home_dir().as_deref())
 }
 
 impl AppConfig {
@@ -64,7 +408,8 @@ impl AppConfig {
         }
         let contents = toml::to_string_pretty(self).context("Failed to serialize config")?;
         std::fs::write(path, contents).context("Failed to write config file")?;
-        Ok(())
+        // Holds the sync auth token
+        crate::platform::restrict_to_owner(path)
     }
 
     pub fn load() -> Result<Self> {
@@ -72,10 +417,101 @@ impl AppConfig {
         Self::load_from_path(&path)
     }
 
+    /// Directory for the database and markdown logs: `[storage] data_dir`
+    /// when set, otherwise the default data directory.
+    pub fn storage_dir(&self) -> Result<PathBuf> {
+        match &self.storage.data_dir {
+            Some(dir) => {
+                crate::platform::resolve_data_dir(Some(dir), dirs::home_dir().as_deref())
+            }
+            None => data_dir(),
+        }
+    }
+
     pub fn save(&self) -> Result<()> {
         let path = data_dir()?.join("config.toml");
         self.save_to_path(&path)
     }
+
+    /// The `[[streaks.rules]]`, or the vert streak when there are none, plus
+    /// a journaling streak once `[notes] word_goal` is set.
+    pub fn streak_rules(&self) -> Vec<StreakRule> {
+        let mut rules = self.streaks.rules.clone();
+        if let Some(goal) = self.notes.word_goal {
+            if rules.is_empty() {
+                rules.push(StreakRule::vert(self.stats.vert_threshold));
+            }
+            rules.push(StreakRule::journal(goal));
+        }
+        rules
+    }
+}
+
+/// Terminal rendering options.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DisplayConfig {
+    pub glyphs: GlyphMode,
+    /// Built-in palette; `[colors]` can still override its section accents.
+    pub theme: ThemeName,
+    /// Units for entering and showing values; storage is always imperial.
+    pub units: crate::units::UnitSystem,
+    /// Decimal places for distances and weights on screen and in exports.
+    /// Unset shows distances and weights as typed and totals to one place.
+    pub distance_decimals: Option<usize>,
+    pub weight_decimals: Option<usize>,
+    /// Thousands separators, decimal mark and date order.
+    pub locale: crate::format::Locale,
+    /// Show distances, vert and weights in both unit systems at once; `U`
+    /// toggles it for the session.
+    pub dual_units: bool,
+    /// ASCII glyphs plus "(focused)" and "(selected)" labels in place of
+    /// color-only cues.
+    pub screen_reader: bool,
+}
+
+/// Keyboard options.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct KeysConfig {
+    /// Layout the movement keys are arranged for.
+    pub preset: crate::events::keys::KeymapPreset,
+}
+
+/// Whether decorative glyphs (borders, arrows, check marks) are drawn as
+/// Unicode or replaced with ASCII for consoles that can't show them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GlyphMode {
+    /// ASCII when the locale isn't UTF-8.
+    #[default]
+    Auto,
+    Unicode,
+    Ascii,
+}
+
+impl GlyphMode {
+    pub fn use_ascii(&self) -> bool {
+        match self {
+            GlyphMode::Auto => !locale_is_utf8(|name| std::env::var(name).ok()),
+            GlyphMode::Unicode => false,
+            GlyphMode::Ascii => true,
+        }
+    }
+}
+
+/// Checks the locale the way libc resolves it: the first non-empty of LC_ALL,
+/// LC_CTYPE and LANG decides. With none set (typical on Windows) Unicode is
+/// assumed, since modern consoles handle it.
+fn locale_is_utf8(var: impl Fn(&str) -> Option<String>) -> bool {
+    let Some(locale) = ["LC_ALL", "LC_CTYPE", "LANG"]
+        .into_iter()
+        .find_map(|name| var(name).filter(|value| !value.is_empty()))
+    else {
+        return true;
+    };
+    let locale = locale.to_ascii_lowercase();
+    locale.contains("utf-8") || locale.contains("utf8")
 }
 
 /// One-time migration from .env to config.toml.
@@ -115,7 +551,9 @@ pub fn migrate_from_env(data_dir: &Path) -> Result<bool> {
             enabled: has_credentials,
             db_url,
             auth_token,
+            ..SyncConfig::default()
         },
+        ..AppConfig::default()
     };
 
     let config_path = data_dir.join("config.toml");
@@ -123,6 +561,7 @@ pub fn migrate_from_env(data_dir: &Path) -> Result<bool> {
 
     let bak_path = data_dir.join(".env.bak");
     std::fs::rename(&env_path, &bak_path).context("Failed to rename .env to .env.bak")?;
+    crate::platform::restrict_to_owner(&bak_path)?;
 
     Ok(true)
 }
@@ -161,6 +600,7 @@ mod tests {
             enabled: false,
             db_url: "libsql://test.turso.io".into(),
             auth_token: "token123".into(),
+            ..SyncConfig::default()
         };
         assert!(!sync.is_configured());
     }
@@ -175,7 +615,9 @@ mod tests {
                 enabled: true,
                 db_url: "libsql://mydb.turso.io".into(),
                 auth_token: "secret".into(),
+                ..SyncConfig::default()
             },
+            ..AppConfig::default()
         };
 
         config.save_to_path(&path).unwrap();
@@ -254,10 +696,118 @@ mod tests {
         assert_eq!(config.sync.auth_token, "mytoken");
     }
 
+    #[test]
+    fn config_without_template_section_disables_template() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("config.toml");
+        std::fs::write(&path, "[sync]\nenabled = false\ndb_url = \"\"\nauth_token = \"\"\n").unwrap();
+        let config = AppConfig::load_from_path(&path).unwrap();
+        assert!(!config.template.enabled);
+        assert_eq!(config.validation.weight_threshold, 8.0);
+        assert_eq!(config.validation.max_feet_per_mile, 1500);
+    }
+
+    #[test]
+    fn stats_storage_and_color_settings_load() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("config.toml");
+        std::fs::write(
+            &path,
+            "[stats]\nvert_threshold = 1500\nweek_start = \"sunday\"\n\
+             [storage]\ndata_dir = \"/srv/mountains\"\n\
+             [display]\ntheme = \"light\"\n\
+             [colors]\nsokay = \"#ff8800\"\nnotes = \"not-a-color\"\n",
+        )
+        .unwrap();
+        let config = AppConfig::load_from_path(&path).unwrap();
+        assert_eq!(config.stats.vert_threshold, 1500);
+        assert_eq!(config.stats.week_start, chrono::Weekday::Sun);
+        assert_eq!(config.storage_dir().unwrap(), PathBuf::from("/srv/mountains"));
+
+        assert_eq!(config.display.theme, ThemeName::Light);
+
+        let theme = config.colors.apply(Theme::new(config.display.theme));
+        assert_eq!(theme.sections.sokay, Color::Rgb(0xff, 0x88, 0x00));
+        assert_eq!(theme.sections.notes, Theme::new(ThemeName::Light).sections.notes);
+
+        // Defaults round-trip through a saved file
+        AppConfig::default().save_to_path(&path).unwrap();
+        let config = AppConfig::load_from_path(&path).unwrap();
+        assert_eq!(config.stats, StatsConfig::default());
+        assert!(config.storage.data_dir.is_none());
+    }
+
+    #[test]
+    fn word_goal_adds_a_journal_streak_after_the_others() {
+        let mut config = AppConfig::default();
+        assert!(config.streak_rules().is_empty());
+
+        config.notes.word_goal = Some(200);
+        let rules = config.streak_rules();
+        assert_eq!(rules.len(), 2);
+        assert_eq!(rules[0], StreakRule::vert(1000));
+        assert_eq!(rules[1].metric, crate::streaks::StreakMetric::Words);
+        assert_eq!(rules[1].threshold, 200.0);
+    }
+
+    #[test]
+    fn template_prefills_new_day_when_enabled() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("config.toml");
+        std::fs::write(
+            &path,
+            "[template]\nenabled = true\nfood_entries = [\"Fish oil\", \"\", \"Vitamin D\"]\nnotes = \"Weigh-in: \"\n",
+        )
+        .unwrap();
+        let config = AppConfig::load_from_path(&path).unwrap();
+        let date = chrono::NaiveDate::from_ymd_opt(2026, 7, 22).unwrap();
+
+        let log = config.template.new_day(date);
+        assert_eq!(log.date, date);
+        let foods: Vec<_> = log.food_entries.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(foods, ["Fish oil", "Vitamin D"]);
+        assert_eq!(log.notes.as_deref(), Some("Weigh-in: "));
+        assert!(log.strength_mobility.is_none());
+        assert!(log.sokay_entries.is_empty());
+    }
+
+    #[test]
+    fn disabled_template_creates_blank_day() {
+        let template = DayTemplate {
+            enabled: false,
+            food_entries: vec!["Fish oil".into()],
+            notes: Some("Weigh-in".into()),
+            ..DayTemplate::default()
+        };
+        let log = template.new_day(chrono::NaiveDate::from_ymd_opt(2026, 7, 22).unwrap());
+        assert!(log.food_entries.is_empty());
+        assert!(log.notes.is_none());
+    }
+
     #[test]
     fn migrate_no_env_returns_false() {
         let dir = TempDir::new().unwrap();
         let migrated = migrate_from_env(dir.path()).unwrap();
         assert!(!migrated);
     }
+
+    #[test]
+    fn locale_detection_uses_the_first_set_variable() {
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |name: &str| {
+                vars.iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| value.to_string())
+            }
+        };
+        assert!(locale_is_utf8(env(&[])));
+        assert!(locale_is_utf8(env(&[("LANG", "en_US.UTF-8")])));
+        assert!(locale_is_utf8(env(&[("LC_ALL", ""), ("LANG", "de_DE.utf8")])));
+        assert!(!locale_is_utf8(env(&[("LC_ALL", "C"), ("LANG", "en_US.UTF-8")])));
+        assert!(!locale_is_utf8(env(&[("LANG", "POSIX")])));
+        assert_eq!(
+            toml::from_str::<AppConfig>("[display]\nglyphs = \"ascii\"\n").unwrap().display.glyphs,
+            GlyphMode::Ascii
+        );
+    }
 }
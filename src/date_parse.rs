@@ -0,0 +1,107 @@
+//! Forgiving date parsing for the jump-to-date prompt: absolute dates in a
+//! few common formats, month-and-day without a year, and relative words and
+//! offsets.
+
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+
+const FORMATS: [&str; 4] = ["%Y-%m-%d", "%m.%d.%Y", "%m/%d/%Y", "%B %d %Y"];
+
+/// Parses `input` relative to `today`. Accepts:
+///
+/// - `2024-11-03`, `11.03.2024`, `11/3/2024`, `Nov 3 2024`
+/// - `Nov 3`, `3 november`: the most recent such day up to today
+/// - `today`, `yesterday`
+/// - `-3`: three days ago
+/// - `mon`, `friday`: the most recent such weekday up to today
+pub fn parse_fuzzy_date(input: &str, today: NaiveDate) -> Option<NaiveDate> {
+    let input = input.trim().to_lowercase().replace(',', " ");
+    let input = input.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    match input.as_str() {
+        "" => return None,
+        "today" | "t" => return Some(today),
+        "yesterday" | "y" => return today.pred_opt(),
+        _ => {}
+    }
+    if let Some(days) = input.strip_prefix('-') {
+        let days: i64 = days.trim().parse().ok()?;
+        return today.checked_sub_signed(Duration::try_days(days)?);
+    }
+    if let Ok(weekday) = input.parse::<Weekday>() {
+        let back =
+            (today.weekday().num_days_from_monday() + 7 - weekday.num_days_from_monday()) % 7;
+        return today.checked_sub_signed(Duration::days(i64::from(back)));
+    }
+
+    let month_first = month_first(&input);
+    if let Some(date) = FORMATS
+        .iter()
+        .find_map(|format| NaiveDate::parse_from_str(&month_first, format).ok())
+    {
+        return Some(date);
+    }
+
+    // Month and day without a year: this year's, or last year's if that
+    // hasn't happened yet
+    let date =
+        NaiveDate::parse_from_str(&format!("{} {}", month_first, today.year()), "%B %d %Y").ok()?;
+    if date > today {
+        NaiveDate::from_ymd_opt(date.year() - 1, date.month(), date.day())
+    } else {
+        Some(date)
+    }
+}
+
+/// Moves a leading day number after the month name, so "3 nov" reads like
+/// "nov 3".
+fn month_first(input: &str) -> String {
+    let words: Vec<&str> = input.split(' ').collect();
+    match words.as_slice() {
+        [day, month, rest @ ..] if day.parse::<u32>().is_ok() && month.parse::<u32>().is_err() => {
+            [*month, *day]
+                .iter()
+                .chain(rest)
+                .copied()
+                .collect::<Vec<_>>()
+                .join(" ")
+        }
+        _ => input.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn parses_absolute_relative_and_yearless_dates() {
+        // A Wednesday
+        let today = date(2024, 11, 13);
+        let cases = [
+            ("2024-11-03", date(2024, 11, 3)),
+            ("11.03.2024", date(2024, 11, 3)),
+            ("11/3/2024", date(2024, 11, 3)),
+            ("Nov 3, 2024", date(2024, 11, 3)),
+            ("Nov 3", date(2024, 11, 3)),
+            ("3 november", date(2024, 11, 3)),
+            ("Dec 25", date(2023, 12, 25)),
+            ("today", today),
+            (" Yesterday ", date(2024, 11, 12)),
+            ("-3", date(2024, 11, 10)),
+            ("wed", today),
+            ("monday", date(2024, 11, 11)),
+            ("Fri", date(2024, 11, 8)),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(parse_fuzzy_date(input, today), Some(expected), "{input}");
+        }
+
+        for input in ["", "soon", "-", "-x", "Feb 30", "13/40/2024"] {
+            assert_eq!(parse_fuzzy_date(input, today), None, "{input}");
+        }
+    }
+}
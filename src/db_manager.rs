@@ -1,11 +1,20 @@
 use anyhow::{Context, Result};
 use chrono::NaiveDate;
 use libsql::{Builder, Connection, Database};
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
-use crate::models::{DailyLog, FoodEntry};
+use crate::gear::Gear;
+use crate::goals::{Goal, GoalKind};
+use crate::injuries::{Injury, Severity};
+use crate::phases::{Phase, PhaseKind, overlapping};
+use crate::plan::PlannedWorkout;
+use crate::strength::StrengthSet;
+use crate::weather::Weather;
+use crate::models::activity::ActivityType;
+use crate::models::{DailyLog, FoodEntry, FoodHistory, Macros};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum ConnectionState {
@@ -18,6 +27,8 @@ pub struct DbManager {
     db: Database,
     conn: Connection,
     connection_state: Arc<RwLock<ConnectionState>>,
+    /// This machine's health check timestamps, kept beside the database.
+    health_path: PathBuf,
 }
 
 impl DbManager {
@@ -40,6 +51,7 @@ impl DbManager {
             db,
             conn,
             connection_state: Arc::new(RwLock::new(state)),
+            health_path: crate::health::path(data_dir),
         };
 
         // Always initialize schema (needed even for in-memory placeholder)
@@ -82,11 +94,6 @@ impl DbManager {
                         self.db = new_db;
                         self.conn = new_conn;
 
-                        // Only reinitialize schema if we replaced the database
-                        if !is_already_replica {
-                            self.init_schema().await?;
-                        }
-
                         // Pull anything written to the primary by other clients
                         // (e.g. the web app) while we were away. Stashed local
                         // data is only imported after a successful pull: the
@@ -94,7 +101,7 @@ impl DbManager {
                         // against a replica that hasn't seen the primary yet.
                         // On failure the stash stays for retry on next connect.
                         if self.db.sync().await.is_ok() {
-                            let _ = self.import_stashed_dbs(db_path_str).await;
+                            self.settle_replica(db_path_str).await?;
                         }
 
                         *self.connection_state.write().await = ConnectionState::Connected;
@@ -115,6 +122,18 @@ impl DbManager {
         }
     }
 
+    /// Follows the first pull from the primary, on every connect. The schema
+    /// is brought up to date through the replica, whose writes go to the
+    /// primary: migrating only the local file would leave the primary
+    /// rejecting saves with newer columns, and the next pull could hand the
+    /// old tables back. Then any stashed local data is imported.
+    async fn settle_replica(&mut self, db_path_str: &str) -> Result<()> {
+        self.init_schema().await?;
+        let _ = self.import_stashed_dbs(db_path_str).await;
+        self.record_sync();
+        Ok(())
+    }
+
     pub async fn get_connection_state(&self) -> ConnectionState {
         self.connection_state.read().await.clone()
     }
@@ -181,10 +200,40 @@ impl DbManager {
         stashes
     }
 
-    /// Imports daily logs from stashed pre-sync databases into the replica.
-    /// Only dates the replica doesn't already have are inserted (remote wins on
-    /// conflict), then each stash is removed. A failure leaves the remaining
-    /// stashes in place for retry on the next connect.
+    /// A stashed pre-sync database, brought up to the current schema so the
+    /// tables it predates read as empty. One without daily logs isn't ours.
+    async fn open_stash(stash_str: &str) -> Result<Self> {
+        let db = Builder::new_local(stash_str).build().await?;
+        let conn = db.connect()?;
+        let mut rows = conn
+            .query(
+                "SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'daily_logs'",
+                (),
+            )
+            .await?;
+        if rows.next().await?.is_none() {
+            anyhow::bail!("{} has no daily logs to import", stash_str);
+        }
+        drop(rows);
+        let dir = Path::new(stash_str).parent().unwrap_or(Path::new("."));
+        let mut stash = Self {
+            db,
+            conn,
+            connection_state: Arc::new(RwLock::new(ConnectionState::Disconnected)),
+            health_path: crate::health::path(dir),
+        };
+        stash.init_schema().await?;
+        Ok(stash)
+    }
+
+    /// Imports daily logs, goals, gear, phases, injuries and the training
+    /// plan from stashed pre-sync databases into the replica. Only rows the
+    /// replica doesn't already have are inserted (remote wins on conflict):
+    /// days and planned days by date, goals by kind and the day they were
+    /// set, gear by name, injuries by body part and start, and phases that
+    /// don't overlap one there, since phases never do. Then each stash is
+    /// removed. A failure leaves the
+    /// remaining stashes in place for retry on the next connect.
     async fn import_stashed_dbs(&mut self, db_path_str: &str) -> Result<()> {
         let stashes = Self::find_stashed_dbs(db_path_str);
         if stashes.is_empty() {
@@ -196,14 +245,38 @@ impl DbManager {
         while let Some(row) = rows.next().await? {
             existing_dates.insert(row.get::<String>(0)?);
         }
+        let mut existing_goals: std::collections::HashSet<_> = self
+            .load_goals()
+            .await?
+            .into_iter()
+            .map(|goal| (goal.kind, goal.since))
+            .collect();
+        let mut existing_gear: std::collections::HashSet<_> = self
+            .load_gear()
+            .await?
+            .into_iter()
+            .map(|gear| gear.name.to_lowercase())
+            .collect();
+        let mut existing_phases = self.load_phases().await?;
+        let mut existing_injuries: std::collections::HashSet<_> = self
+            .load_injuries()
+            .await?
+            .into_iter()
+            .map(|injury| (injury.body_part.to_lowercase(), injury.start))
+            .collect();
+        let mut planned_dates: std::collections::HashSet<_> =
+            self.load_planned_workouts().await?.into_keys().collect();
 
         for stash in stashes {
             let stash_str = stash.to_str().context("Invalid stash path")?;
-            let stash_db = Builder::new_local(stash_str).build().await?;
-            let stash_conn = stash_db.connect()?;
-            let logs = Self::load_daily_logs_from(&stash_conn).await?;
-            drop(stash_conn);
-            drop(stash_db);
+            let stashed = Self::open_stash(stash_str).await?;
+            let logs = stashed.load_all_daily_logs().await?;
+            let goals = stashed.load_goals().await?;
+            let gear = stashed.load_gear().await?;
+            let phases = stashed.load_phases().await?;
+            let injuries = stashed.load_injuries().await?;
+            let plan = stashed.load_planned_workouts().await?;
+            drop(stashed);
 
             for log in logs {
                 let date_str = log.date.format("%Y-%m-%d").to_string();
@@ -213,6 +286,42 @@ impl DbManager {
                 self.save_daily_log(&log).await?;
                 existing_dates.insert(date_str);
             }
+            for goal in goals {
+                if !existing_goals.insert((goal.kind, goal.since)) {
+                    continue;
+                }
+                let id = self.add_goal(goal.kind, goal.target, goal.since).await?;
+                if !goal.active {
+                    self.update_goal(&Goal { id, ..goal }).await?;
+                }
+            }
+            // Retired gear and gear no day mentions only live in this table
+            for gear in gear {
+                if existing_gear.insert(gear.name.to_lowercase()) {
+                    self.save_gear(&gear).await?;
+                }
+            }
+            for phase in phases {
+                if overlapping(&existing_phases, phase.start, phase.end, None).is_some() {
+                    continue;
+                }
+                let id = self.add_phase(phase.kind, phase.start, phase.end).await?;
+                existing_phases.push(Phase { id, ..phase });
+            }
+            for injury in injuries {
+                if existing_injuries.insert((injury.body_part.to_lowercase(), injury.start)) {
+                    let (body_part, severity) = (&injury.body_part, injury.severity);
+                    self.add_injury(body_part, severity, injury.start, injury.resolved)
+                        .await?;
+                }
+            }
+            let plan: Vec<_> = plan
+                .into_values()
+                .filter(|planned| planned_dates.insert(planned.date))
+                .collect();
+            if !plan.is_empty() {
+                self.save_planned_workouts(&plan).await?;
+            }
 
             std::fs::remove_file(&stash).ok();
             std::fs::remove_file(format!("{}-wal", stash_str)).ok();
@@ -254,6 +363,8 @@ impl DbManager {
             .await
             .context("Failed to create food_entries table")?;
 
+        Self::migrate(&self.conn).await?;
+
         // Create index on date for faster queries
         self.conn
             .execute(
@@ -286,9 +397,503 @@ impl DbManager {
             .await
             .context("Failed to create index on sokay_entries")?;
 
+        // Shoes and other gear runs are logged in, by name
+        self.conn
+            .execute(
+                "CREATE TABLE IF NOT EXISTS gear (
+                    name TEXT PRIMARY KEY,
+                    retired INTEGER NOT NULL DEFAULT 0
+                )",
+                (),
+            )
+            .await
+            .context("Failed to create gear table")?;
+
+        // Goals from the goals screen; targets in stored units
+        self.conn
+            .execute(
+                "CREATE TABLE IF NOT EXISTS goals (
+                    id INTEGER PRIMARY KEY AUTOINCREMENT,
+                    kind TEXT NOT NULL,
+                    target REAL NOT NULL,
+                    since TEXT NOT NULL,
+                    active INTEGER NOT NULL DEFAULT 1
+                )",
+                (),
+            )
+            .await
+            .context("Failed to create goals table")?;
+
+        // Training phases from the phases screen, which never overlap
+        self.conn
+            .execute(
+                "CREATE TABLE IF NOT EXISTS phases (
+                    id INTEGER PRIMARY KEY AUTOINCREMENT,
+                    kind TEXT NOT NULL,
+                    start_date TEXT NOT NULL,
+                    end_date TEXT NOT NULL
+                )",
+                (),
+            )
+            .await
+            .context("Failed to create phases table")?;
+
+        // Injuries and niggles from the injuries screen; no resolved date
+        // while they still hurt
+        self.conn
+            .execute(
+                "CREATE TABLE IF NOT EXISTS injuries (
+                    id INTEGER PRIMARY KEY AUTOINCREMENT,
+                    body_part TEXT NOT NULL,
+                    severity TEXT NOT NULL,
+                    start_date TEXT NOT NULL,
+                    resolved_date TEXT
+                )",
+                (),
+            )
+            .await
+            .context("Failed to create injuries table")?;
+
+        // Imported training plan, one row per planned day; stored units
+        self.conn
+            .execute(
+                "CREATE TABLE IF NOT EXISTS planned_workouts (
+                    date TEXT PRIMARY KEY,
+                    miles REAL,
+                    vert INTEGER,
+                    note TEXT
+                )",
+                (),
+            )
+            .await
+            .context("Failed to create planned_workouts table")?;
+
+        Ok(())
+    }
+
+    /// Adds the columns newer versions expect to tables created by older
+    /// ones. Safe to run on every open.
+    async fn migrate(conn: &Connection) -> Result<()> {
+        Self::add_column_if_missing(conn, "food_entries", "quantity", "TEXT").await?;
+        Self::add_column_if_missing(conn, "food_entries", "calories", "INTEGER").await?;
+        Self::add_column_if_missing(conn, "food_entries", "protein", "REAL").await?;
+        Self::add_column_if_missing(conn, "food_entries", "carbs", "REAL").await?;
+        Self::add_column_if_missing(conn, "food_entries", "fat", "REAL").await?;
+        Self::add_column_if_missing(conn, "daily_logs", "water", "REAL").await?;
+        Self::add_column_if_missing(conn, "daily_logs", "sleep_hours", "REAL").await?;
+        Self::add_column_if_missing(conn, "daily_logs", "resting_hr", "INTEGER").await?;
+        Self::add_column_if_missing(conn, "daily_logs", "hrv", "INTEGER").await?;
+        Self::add_column_if_missing(conn, "daily_logs", "energy", "INTEGER").await?;
+        Self::add_column_if_missing(conn, "daily_logs", "rpe", "INTEGER").await?;
+        Self::add_column_if_missing(conn, "daily_logs", "duration_secs", "INTEGER").await?;
+        Self::add_column_if_missing(conn, "daily_logs", "activity", "TEXT").await?;
+        Self::add_column_if_missing(conn, "daily_logs", "gear", "TEXT").await?;
+        Self::add_column_if_missing(conn, "daily_logs", "route", "TEXT").await?;
+        Self::add_column_if_missing(conn, "daily_logs", "weather_high", "REAL").await?;
+        Self::add_column_if_missing(conn, "daily_logs", "weather_low", "REAL").await?;
+        Self::add_column_if_missing(conn, "daily_logs", "weather_code", "INTEGER").await?;
+        Self::add_column_if_missing(
+            conn,
+            "daily_logs",
+            "archived",
+            "INTEGER NOT NULL DEFAULT 0",
+        )
+        .await?;
+        Self::add_column_if_missing(conn, "daily_logs", "indoor", "INTEGER NOT NULL DEFAULT 0")
+            .await?;
+        Self::add_column_if_missing(conn, "daily_logs", "intention", "TEXT").await?;
+        Self::add_column_if_missing(conn, "daily_logs", "gratitude", "TEXT").await?;
+        Self::add_column_if_missing(conn, "daily_logs", "avg_hr", "INTEGER").await?;
+        Self::add_column_if_missing(conn, "daily_logs", "max_hr", "INTEGER").await?;
+        Self::add_column_if_missing(conn, "daily_logs", "start_time", "TEXT").await?;
+        Self::add_column_if_missing(conn, "daily_logs", "rest_day", "INTEGER NOT NULL DEFAULT 0")
+            .await?;
+        // Sets picked out of the strength & mobility text, in the order
+        // typed. Made here so stashed databases from before them load too.
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS strength_sets (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                date TEXT NOT NULL,
+                exercise TEXT NOT NULL,
+                sets INTEGER NOT NULL,
+                reps INTEGER NOT NULL,
+                weight REAL,
+                FOREIGN KEY (date) REFERENCES daily_logs(date) ON DELETE CASCADE
+            )",
+            (),
+        )
+        .await
+        .context("Failed to create strength_sets table")?;
+        conn.execute(
+            "CREATE INDEX IF NOT EXISTS idx_strength_sets_date ON strength_sets(date)",
+            (),
+        )
+        .await
+        .context("Failed to create index on strength_sets")?;
+        Ok(())
+    }
+
+    async fn add_column_if_missing(
+        conn: &Connection,
+        table: &str,
+        column: &str,
+        declaration: &str,
+    ) -> Result<()> {
+        let mut rows = conn
+            .query(&format!("PRAGMA table_info({})", table), ())
+            .await
+            .with_context(|| format!("Failed to read the {} columns", table))?;
+        while let Some(row) = rows.next().await? {
+            if row.get::<String>(1)? == column {
+                return Ok(());
+            }
+        }
+        conn.execute(
+            &format!("ALTER TABLE {} ADD COLUMN {} {}", table, column, declaration),
+            (),
+        )
+        .await
+        .with_context(|| format!("Failed to add {}.{}", table, column))?;
+        Ok(())
+    }
+
+    /// Every piece of gear, oldest first.
+    pub async fn load_gear(&self) -> Result<Vec<Gear>> {
+        let mut rows = self
+            .conn
+            .query("SELECT name, retired FROM gear ORDER BY rowid", ())
+            .await
+            .context("Failed to query gear")?;
+        let mut gear = Vec::new();
+        while let Some(row) = rows.next().await? {
+            gear.push(Gear {
+                name: row.get(0)?,
+                retired: row.get::<i64>(1)? != 0,
+            });
+        }
+        Ok(gear)
+    }
+
+    /// Adds `gear`, or updates its retired flag if the name is taken.
+    pub async fn save_gear(&self, gear: &Gear) -> Result<()> {
+        self.conn
+            .execute(
+                "INSERT INTO gear (name, retired) VALUES (?1, ?2)
+                 ON CONFLICT(name) DO UPDATE SET retired = excluded.retired",
+                libsql::params![gear.name.as_str(), gear.retired as i64],
+            )
+            .await
+            .context("Failed to save gear")?;
+        self.sync().await;
         Ok(())
     }
 
+    /// Every goal, oldest first. Rows of a kind this version doesn't know
+    /// are skipped.
+    pub async fn load_goals(&self) -> Result<Vec<Goal>> {
+        let mut rows = self
+            .conn
+            .query("SELECT id, kind, target, since, active FROM goals ORDER BY id", ())
+            .await
+            .context("Failed to query goals")?;
+        let mut goals = Vec::new();
+        while let Some(row) = rows.next().await? {
+            let Some(kind) = GoalKind::from_key(&row.get::<String>(1)?) else {
+                continue;
+            };
+            let since: String = row.get(3)?;
+            goals.push(Goal {
+                id: row.get(0)?,
+                kind,
+                target: row.get(2)?,
+                since: NaiveDate::parse_from_str(&since, "%Y-%m-%d")
+                    .context("Invalid goal date")?,
+                active: row.get::<i64>(4)? != 0,
+            });
+        }
+        Ok(goals)
+    }
+
+    /// Adds a goal, returning its id.
+    pub async fn add_goal(&self, kind: GoalKind, target: f64, since: NaiveDate) -> Result<i64> {
+        self.conn
+            .execute(
+                "INSERT INTO goals (kind, target, since, active) VALUES (?1, ?2, ?3, 1)",
+                libsql::params![kind.key(), target, since.format("%Y-%m-%d").to_string()],
+            )
+            .await
+            .context("Failed to add goal")?;
+        let id = self.conn.last_insert_rowid();
+        self.sync().await;
+        Ok(id)
+    }
+
+    /// Updates a goal's target and whether it's active.
+    pub async fn update_goal(&self, goal: &Goal) -> Result<()> {
+        self.conn
+            .execute(
+                "UPDATE goals SET target = ?1, active = ?2 WHERE id = ?3",
+                libsql::params![goal.target, goal.active as i64, goal.id],
+            )
+            .await
+            .context("Failed to update goal")?;
+        self.sync().await;
+        Ok(())
+    }
+
+    pub async fn delete_goal(&self, id: i64) -> Result<()> {
+        self.conn
+            .execute("DELETE FROM goals WHERE id = ?1", libsql::params![id])
+            .await
+            .context("Failed to delete goal")?;
+        self.sync().await;
+        Ok(())
+    }
+
+    /// Every phase, earliest first. Rows of a kind this version doesn't
+    /// know are skipped.
+    pub async fn load_phases(&self) -> Result<Vec<Phase>> {
+        let mut rows = self
+            .conn
+            .query(
+                "SELECT id, kind, start_date, end_date FROM phases ORDER BY start_date",
+                (),
+            )
+            .await
+            .context("Failed to query phases")?;
+        let mut phases = Vec::new();
+        while let Some(row) = rows.next().await? {
+            let Some(kind) = PhaseKind::from_key(&row.get::<String>(1)?) else {
+                continue;
+            };
+            let date = |text: String| {
+                NaiveDate::parse_from_str(&text, "%Y-%m-%d").context("Invalid phase date")
+            };
+            phases.push(Phase {
+                id: row.get(0)?,
+                kind,
+                start: date(row.get(2)?)?,
+                end: date(row.get(3)?)?,
+            });
+        }
+        Ok(phases)
+    }
+
+    /// Adds a phase, returning its id.
+    pub async fn add_phase(&self, kind: PhaseKind, start: NaiveDate, end: NaiveDate) -> Result<i64> {
+        self.conn
+            .execute(
+                "INSERT INTO phases (kind, start_date, end_date) VALUES (?1, ?2, ?3)",
+                libsql::params![
+                    kind.key(),
+                    start.format("%Y-%m-%d").to_string(),
+                    end.format("%Y-%m-%d").to_string()
+                ],
+            )
+            .await
+            .context("Failed to add phase")?;
+        let id = self.conn.last_insert_rowid();
+        self.sync().await;
+        Ok(id)
+    }
+
+    /// Updates a phase's kind and range.
+    pub async fn update_phase(&self, phase: &Phase) -> Result<()> {
+        self.conn
+            .execute(
+                "UPDATE phases SET kind = ?1, start_date = ?2, end_date = ?3 WHERE id = ?4",
+                libsql::params![
+                    phase.kind.key(),
+                    phase.start.format("%Y-%m-%d").to_string(),
+                    phase.end.format("%Y-%m-%d").to_string(),
+                    phase.id
+                ],
+            )
+            .await
+            .context("Failed to update phase")?;
+        self.sync().await;
+        Ok(())
+    }
+
+    pub async fn delete_phase(&self, id: i64) -> Result<()> {
+        self.conn
+            .execute("DELETE FROM phases WHERE id = ?1", libsql::params![id])
+            .await
+            .context("Failed to delete phase")?;
+        self.sync().await;
+        Ok(())
+    }
+
+    /// Every injury, latest first. Rows of a severity this version doesn't
+    /// know are skipped.
+    pub async fn load_injuries(&self) -> Result<Vec<Injury>> {
+        let mut rows = self
+            .conn
+            .query(
+                "SELECT id, body_part, severity, start_date, resolved_date FROM injuries
+                 ORDER BY start_date DESC, id DESC",
+                (),
+            )
+            .await
+            .context("Failed to query injuries")?;
+        let mut injuries = Vec::new();
+        while let Some(row) = rows.next().await? {
+            let Some(severity) = Severity::from_key(&row.get::<String>(2)?) else {
+                continue;
+            };
+            let date = |text: String| {
+                NaiveDate::parse_from_str(&text, "%Y-%m-%d").context("Invalid injury date")
+            };
+            injuries.push(Injury {
+                id: row.get(0)?,
+                body_part: row.get(1)?,
+                severity,
+                start: date(row.get(3)?)?,
+                resolved: row.get::<Option<String>>(4)?.map(date).transpose()?,
+            });
+        }
+        Ok(injuries)
+    }
+
+    /// Adds an injury, returning its id.
+    pub async fn add_injury(
+        &self,
+        body_part: &str,
+        severity: Severity,
+        start: NaiveDate,
+        resolved: Option<NaiveDate>,
+    ) -> Result<i64> {
+        self.conn
+            .execute(
+                "INSERT INTO injuries (body_part, severity, start_date, resolved_date)
+                 VALUES (?1, ?2, ?3, ?4)",
+                libsql::params![
+                    body_part,
+                    severity.key(),
+                    start.format("%Y-%m-%d").to_string(),
+                    resolved.map(|date| date.format("%Y-%m-%d").to_string())
+                ],
+            )
+            .await
+            .context("Failed to add injury")?;
+        let id = self.conn.last_insert_rowid();
+        self.sync().await;
+        Ok(id)
+    }
+
+    /// Updates an injury's body part, severity and dates.
+    pub async fn update_injury(&self, injury: &Injury) -> Result<()> {
+        self.conn
+            .execute(
+                "UPDATE injuries SET body_part = ?1, severity = ?2, start_date = ?3,
+                 resolved_date = ?4 WHERE id = ?5",
+                libsql::params![
+                    injury.body_part.as_str(),
+                    injury.severity.key(),
+                    injury.start.format("%Y-%m-%d").to_string(),
+                    injury.resolved.map(|date| date.format("%Y-%m-%d").to_string()),
+                    injury.id
+                ],
+            )
+            .await
+            .context("Failed to update injury")?;
+        self.sync().await;
+        Ok(())
+    }
+
+    pub async fn delete_injury(&self, id: i64) -> Result<()> {
+        self.conn
+            .execute("DELETE FROM injuries WHERE id = ?1", libsql::params![id])
+            .await
+            .context("Failed to delete injury")?;
+        self.sync().await;
+        Ok(())
+    }
+
+    /// The imported training plan, by day.
+    pub async fn load_planned_workouts(&self) -> Result<BTreeMap<NaiveDate, PlannedWorkout>> {
+        let mut rows = self
+            .conn
+            .query("SELECT date, miles, vert, note FROM planned_workouts", ())
+            .await
+            .context("Failed to query planned workouts")?;
+        let mut plan = BTreeMap::new();
+        while let Some(row) = rows.next().await? {
+            let date: String = row.get(0)?;
+            let date = NaiveDate::parse_from_str(&date, "%Y-%m-%d")
+                .context("Invalid planned workout date")?;
+            plan.insert(
+                date,
+                PlannedWorkout {
+                    date,
+                    miles: row.get::<Option<f64>>(1)?.map(|miles| miles as f32),
+                    vert: row.get::<Option<i64>>(2)?.map(|feet| feet as i32),
+                    note: row.get(3)?,
+                },
+            );
+        }
+        Ok(plan)
+    }
+
+    /// Stores planned days, replacing any plan already there for them.
+    pub async fn save_planned_workouts(&mut self, plan: &[PlannedWorkout]) -> Result<()> {
+        let tx = self.conn.transaction().await?;
+        for planned in plan {
+            tx.execute(
+                "INSERT OR REPLACE INTO planned_workouts (date, miles, vert, note) VALUES (?1, ?2, ?3, ?4)",
+                libsql::params![
+                    planned.date.format("%Y-%m-%d").to_string(),
+                    planned.miles,
+                    planned.vert,
+                    planned.note.as_deref(),
+                ],
+            )
+            .await
+            .context("Failed to save planned workout")?;
+        }
+        tx.commit().await.context("Failed to commit transaction")?;
+        self.sync().await;
+        Ok(())
+    }
+
+    /// Every past food whose name contains `query`, ignoring case. Entries
+    /// differing only in case or surrounding spaces count as one food, and
+    /// the food eaten most recently comes first.
+    pub async fn food_history(&self, query: &str) -> Result<Vec<FoodHistory>> {
+        let mut rows = self
+            .conn
+            .query(
+                "SELECT name, date FROM food_entries WHERE instr(lower(name), lower(?1)) > 0 ORDER BY date DESC, id DESC",
+                [query.trim()],
+            )
+            .await
+            .context("Failed to query food history")?;
+
+        let mut history: Vec<FoodHistory> = Vec::new();
+        while let Some(row) = rows.next().await? {
+            let name: String = row.get(0)?;
+            let date_str: String = row.get(1)?;
+            let date = NaiveDate::parse_from_str(&date_str, "%Y-%m-%d")
+                .context("Failed to parse date from database")?;
+            let key = name.trim().to_lowercase();
+            match history
+                .iter_mut()
+                .find(|food| food.name.trim().to_lowercase() == key)
+            {
+                Some(food) => {
+                    if food.dates.last() != Some(&date) {
+                        food.dates.push(date);
+                    }
+                }
+                None => history.push(FoodHistory {
+                    name: name.trim().to_string(),
+                    dates: vec![date],
+                }),
+            }
+        }
+        Ok(history)
+    }
+
     pub async fn save_daily_log(&mut self, log: &DailyLog) -> Result<()> {
         let date_str = log.date.format("%Y-%m-%d").to_string();
 
@@ -297,7 +902,7 @@ impl DbManager {
 
         // Upsert daily_logs record
         tx.execute(
-            "INSERT OR REPLACE INTO daily_logs (date, weight, waist, miles_covered, elevation_gain, strength_mobility, notes) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            "INSERT OR REPLACE INTO daily_logs (date, weight, waist, miles_covered, elevation_gain, strength_mobility, notes, water, sleep_hours, resting_hr, hrv, energy, rpe, duration_secs, activity, gear, route, archived, weather_high, weather_low, weather_code, indoor, intention, gratitude, avg_hr, max_hr, start_time, rest_day) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?23, ?24, ?25, ?26, ?27, ?28)",
             libsql::params![
                 date_str.clone(),
                 log.weight,
@@ -306,6 +911,27 @@ impl DbManager {
                 log.elevation_gain,
                 log.strength_mobility.as_deref(),
                 log.notes.as_deref(),
+                log.water,
+                log.sleep_hours,
+                log.resting_hr,
+                log.hrv,
+                log.energy.map(u32::from),
+                log.rpe.map(u32::from),
+                log.duration_secs,
+                log.activity.map(ActivityType::name),
+                log.gear.as_deref(),
+                log.route.as_deref(),
+                log.archived as i64,
+                log.weather.map(|w| w.high),
+                log.weather.map(|w| w.low),
+                log.weather.map(|w| u32::from(w.code)),
+                log.indoor as i64,
+                log.intention.as_deref(),
+                log.gratitude.as_deref(),
+                log.avg_hr,
+                log.max_hr,
+                log.start_time.map(|time| time.format("%H:%M").to_string()),
+                log.rest_day as i64,
             ],
         )
         .await
@@ -322,8 +948,16 @@ impl DbManager {
         // Insert all food entries
         for entry in &log.food_entries {
             tx.execute(
-                "INSERT INTO food_entries (date, name) VALUES (?1, ?2)",
-                libsql::params![date_str.clone(), entry.name.clone(),],
+                "INSERT INTO food_entries (date, name, quantity, calories, protein, carbs, fat) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                libsql::params![
+                    date_str.clone(),
+                    entry.name.clone(),
+                    entry.quantity.as_deref(),
+                    entry.calories,
+                    entry.macros.map(|m| m.protein),
+                    entry.macros.map(|m| m.carbs),
+                    entry.macros.map(|m| m.fat),
+                ],
             )
             .await
             .context("Failed to insert food entry")?;
@@ -347,6 +981,28 @@ impl DbManager {
             .context("Failed to insert sokay entry")?;
         }
 
+        tx.execute(
+            "DELETE FROM strength_sets WHERE date = ?1",
+            [date_str.as_str()],
+        )
+        .await
+        .context("Failed to delete old strength sets")?;
+
+        for set in &log.strength_sets {
+            tx.execute(
+                "INSERT INTO strength_sets (date, exercise, sets, reps, weight) VALUES (?1, ?2, ?3, ?4, ?5)",
+                libsql::params![
+                    date_str.clone(),
+                    set.exercise.clone(),
+                    set.sets,
+                    set.reps,
+                    set.weight,
+                ],
+            )
+            .await
+            .context("Failed to insert strength set")?;
+        }
+
         // Commit the transaction
         tx.commit().await.context("Failed to commit transaction")?;
 
@@ -364,7 +1020,7 @@ impl DbManager {
         // Query all dates from daily_logs
         let mut rows = conn
             .query(
-                "SELECT date, weight, waist, miles_covered, elevation_gain, strength_mobility, notes FROM daily_logs ORDER BY date DESC",
+                "SELECT date, weight, waist, miles_covered, elevation_gain, strength_mobility, notes, water, sleep_hours, resting_hr, hrv, energy, rpe, duration_secs, activity, gear, route, archived, weather_high, weather_low, weather_code, indoor, intention, gratitude, avg_hr, max_hr, start_time, rest_day FROM daily_logs ORDER BY date DESC",
                 (),
             )
             .await
@@ -383,11 +1039,45 @@ impl DbManager {
             let elevation_gain: Option<i32> = row.get::<Option<i64>>(4)?.map(|v| v as i32);
             let strength_mobility: Option<String> = row.get(5)?;
             let notes: Option<String> = row.get(6)?;
+            let water: Option<f32> = row.get::<Option<f64>>(7)?.map(|v| v as f32);
+            let sleep_hours: Option<f32> = row.get::<Option<f64>>(8)?.map(|v| v as f32);
+            let resting_hr: Option<u32> = row.get::<Option<i64>>(9)?.map(|v| v as u32);
+            let hrv: Option<u32> = row.get::<Option<i64>>(10)?.map(|v| v as u32);
+            let energy: Option<u8> = row.get::<Option<i64>>(11)?.map(|v| v as u8);
+            let rpe: Option<u8> = row.get::<Option<i64>>(12)?.map(|v| v as u8);
+            let duration_secs: Option<u32> = row.get::<Option<i64>>(13)?.map(|v| v as u32);
+            let activity = row
+                .get::<Option<String>>(14)?
+                .and_then(|name| ActivityType::from_name(&name));
+            let gear: Option<String> = row.get(15)?;
+            let route: Option<String> = row.get(16)?;
+            let archived = row.get::<i64>(17)? != 0;
+            let weather = match (
+                row.get::<Option<f64>>(18)?,
+                row.get::<Option<f64>>(19)?,
+                row.get::<Option<i64>>(20)?,
+            ) {
+                (Some(high), Some(low), Some(code)) => Some(Weather {
+                    high: high as f32,
+                    low: low as f32,
+                    code: code as u8,
+                }),
+                _ => None,
+            };
+            let indoor = row.get::<i64>(21)? != 0;
+            let intention: Option<String> = row.get(22)?;
+            let gratitude: Option<String> = row.get(23)?;
+            let avg_hr: Option<u32> = row.get::<Option<i64>>(24)?.map(|v| v as u32);
+            let max_hr: Option<u32> = row.get::<Option<i64>>(25)?.map(|v| v as u32);
+            let start_time = row
+                .get::<Option<String>>(26)?
+                .and_then(|time| chrono::NaiveTime::parse_from_str(&time, "%H:%M").ok());
+            let rest_day = row.get::<i64>(27)? != 0;
 
             // Query food entries for this date
             let mut food_rows = conn
                 .query(
-                    "SELECT name FROM food_entries WHERE date = ?1 ORDER BY id",
+                    "SELECT name, quantity, calories, protein, carbs, fat FROM food_entries WHERE date = ?1 ORDER BY id",
                     [date_str.as_str()],
                 )
                 .await
@@ -395,8 +1085,23 @@ impl DbManager {
 
             let mut food_entries = Vec::new();
             while let Some(food_row) = food_rows.next().await? {
-                let name: String = food_row.get(0)?;
-                food_entries.push(FoodEntry::new(name));
+                food_entries.push(FoodEntry {
+                    name: food_row.get(0)?,
+                    quantity: food_row.get(1)?,
+                    calories: food_row.get::<Option<i64>>(2)?.map(|c| c as u32),
+                    macros: match (
+                        food_row.get::<Option<f64>>(3)?,
+                        food_row.get::<Option<f64>>(4)?,
+                        food_row.get::<Option<f64>>(5)?,
+                    ) {
+                        (Some(protein), Some(carbs), Some(fat)) => Some(Macros {
+                            protein: protein as f32,
+                            carbs: carbs as f32,
+                            fat: fat as f32,
+                        }),
+                        _ => None,
+                    },
+                });
             }
 
             // Query sokay entries for this date
@@ -414,6 +1119,24 @@ impl DbManager {
                 sokay_entries.push(entry_text);
             }
 
+            let mut set_rows = conn
+                .query(
+                    "SELECT exercise, sets, reps, weight FROM strength_sets WHERE date = ?1 ORDER BY id",
+                    [date_str.as_str()],
+                )
+                .await
+                .context("Failed to query strength sets")?;
+
+            let mut strength_sets = Vec::new();
+            while let Some(set_row) = set_rows.next().await? {
+                strength_sets.push(StrengthSet {
+                    exercise: set_row.get(0)?,
+                    sets: set_row.get::<i64>(1)? as u32,
+                    reps: set_row.get::<i64>(2)? as u32,
+                    weight: set_row.get::<Option<f64>>(3)?.map(|w| w as f32),
+                });
+            }
+
             daily_logs.push(DailyLog {
                 date,
                 food_entries,
@@ -423,7 +1146,27 @@ impl DbManager {
                 elevation_gain,
                 sokay_entries,
                 strength_mobility,
+                strength_sets,
                 notes,
+                water,
+                sleep_hours,
+                resting_hr,
+                hrv,
+                energy,
+                rpe,
+                duration_secs,
+                avg_hr,
+                max_hr,
+                start_time,
+                activity,
+                gear,
+                route,
+                weather,
+                indoor,
+                rest_day,
+                intention,
+                gratitude,
+                archived,
             });
         }
 
@@ -443,19 +1186,31 @@ impl DbManager {
     }
 
     /// Explicit sync with Turso Cloud (called on shutdown)
-    pub async fn sync_now(&self) -> Result<()> {
+    /// Syncs with the cloud database and returns how many pages of the
+    /// write-ahead log it moved; libsql reports nothing finer while it runs.
+    pub async fn sync_now(&self) -> Result<usize> {
         // Only sync if we're connected to Turso
         let state = self.connection_state.read().await;
         if *state != ConnectionState::Connected {
-            return Ok(()); // Skip sync if not connected, but don't error
+            return Ok(0); // Skip sync if not connected, but don't error
         }
         drop(state); // Release lock before sync
 
-        self.db
+        let replicated = self
+            .db
             .sync()
             .await
             .context("Failed to sync with Turso Cloud")?;
-        Ok(())
+        self.record_sync();
+        Ok(replicated.frames_synced())
+    }
+
+    /// Notes a successful sync for the startup health check.
+    fn record_sync(&self) {
+        let now = Some(chrono::Utc::now());
+        let _ = crate::health::Timestamps::update(&self.health_path, |times| {
+            times.last_sync = now
+        });
     }
 
     pub async fn delete_daily_log(&mut self, date: NaiveDate) -> Result<()> {
@@ -506,6 +1261,38 @@ mod tests {
         let mut db = DbManager::new_local_first(dir.path()).await.unwrap();
         db.save_daily_log(&log("2026-07-01", "local-day1")).await.unwrap();
         db.save_daily_log(&log("2026-07-02", "local-day2")).await.unwrap();
+        let day = |d| NaiveDate::from_ymd_opt(2026, 7, d).unwrap();
+        db.add_goal(GoalKind::WeeklyMiles, 40.0, day(1)).await.unwrap();
+        let paused = db.add_goal(GoalKind::MonthlyVert, 5000.0, day(2)).await.unwrap();
+        db.update_goal(&Goal {
+            id: paused,
+            kind: GoalKind::MonthlyVert,
+            target: 5000.0,
+            since: day(2),
+            active: false,
+        })
+        .await
+        .unwrap();
+        for (name, retired) in [("Speedgoat 5", true), ("Mafate 4", false)] {
+            let name = name.to_string();
+            db.save_gear(&Gear { name, retired }).await.unwrap();
+        }
+        let june = |d| NaiveDate::from_ymd_opt(2026, 6, d).unwrap();
+        db.add_phase(PhaseKind::Base, june(1), june(14)).await.unwrap();
+        db.add_phase(PhaseKind::Build, day(1), day(10)).await.unwrap();
+        db.add_injury("Left knee", Severity::Mild, june(3), Some(june(9)))
+            .await
+            .unwrap();
+        db.add_injury("Right calf", Severity::Mild, day(1), None).await.unwrap();
+        let planned = |d, miles| PlannedWorkout {
+            date: day(d),
+            miles: Some(miles),
+            vert: None,
+            note: None,
+        };
+        db.save_planned_workouts(&[planned(6, 8.0), planned(7, 5.0)])
+            .await
+            .unwrap();
         db.stash_local_db(&db_path_str).await;
         drop(db);
         assert!(!db_path.exists());
@@ -515,6 +1302,15 @@ mod tests {
         // holding 07-02 (as if another client wrote it)
         let mut db = DbManager::new_local_first(dir.path()).await.unwrap();
         db.save_daily_log(&log("2026-07-02", "remote-day2")).await.unwrap();
+        db.add_goal(GoalKind::WeeklyMiles, 50.0, day(1)).await.unwrap();
+        let mafate = Gear {
+            name: "mafate 4".to_string(),
+            retired: true,
+        };
+        db.save_gear(&mafate).await.unwrap();
+        db.add_phase(PhaseKind::Peak, day(5), day(20)).await.unwrap();
+        db.add_injury("right calf", Severity::Severe, day(1), None).await.unwrap();
+        db.save_planned_workouts(&[planned(7, 10.0)]).await.unwrap();
         db.import_stashed_dbs(&db_path_str).await.unwrap();
 
         let logs = db.load_all_daily_logs().await.unwrap();
@@ -526,10 +1322,325 @@ mod tests {
         assert!(logs.iter().any(|l| l.notes.as_deref() == Some("remote-day2")));
         assert!(!logs.iter().any(|l| l.notes.as_deref() == Some("local-day2-overwritten")));
 
+        // Goals come across paused or not, unless the replica has one of
+        // that kind set the same day
+        let goals = db.load_goals().await.unwrap();
+        let found: Vec<_> = goals.iter().map(|g| (g.kind, g.target, g.active)).collect();
+        assert_eq!(
+            found,
+            [(GoalKind::WeeklyMiles, 50.0, true), (GoalKind::MonthlyVert, 5000.0, false)]
+        );
+        // Gear comes across retired or not, by name whatever the case
+        let speedgoat = Gear {
+            name: "Speedgoat 5".to_string(),
+            retired: true,
+        };
+        assert_eq!(db.load_gear().await.unwrap(), [mafate, speedgoat]);
+        // A stashed phase overlapping one already there stays out
+        let phases: Vec<_> = db
+            .load_phases()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|p| (p.kind, p.start))
+            .collect();
+        assert_eq!(phases, [(PhaseKind::Base, june(1)), (PhaseKind::Peak, day(5))]);
+        let injuries: Vec<_> = db
+            .load_injuries()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|i| (i.body_part, i.severity, i.resolved))
+            .collect();
+        assert_eq!(
+            injuries,
+            [
+                ("right calf".to_string(), Severity::Severe, None),
+                ("Left knee".to_string(), Severity::Mild, Some(june(9))),
+            ]
+        );
+        let plan: Vec<_> = db.load_planned_workouts().await.unwrap().into_values().collect();
+        assert_eq!(plan, [planned(6, 8.0), planned(7, 10.0)]);
+
         // Stash consumed after successful import
         assert!(DbManager::find_stashed_dbs(&db_path_str).is_empty());
     }
 
+    #[tokio::test]
+    async fn food_history_groups_matches_by_name_newest_first() {
+        let dir = TempDir::new().unwrap();
+        let mut db = DbManager::new_local_first(dir.path()).await.unwrap();
+        for (date, foods) in [
+            ("2026-07-01", vec!["Oatmeal", "Burrito"]),
+            ("2026-07-03", vec!["oatmeal ", "Oat milk", "Oatmeal"]),
+            ("2026-07-05", vec!["Burrito"]),
+        ] {
+            let mut log = DailyLog::new(NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap());
+            for food in foods {
+                log.add_food_entry(FoodEntry::new(food.to_string()));
+            }
+            db.save_daily_log(&log).await.unwrap();
+        }
+
+        let day = |d| NaiveDate::from_ymd_opt(2026, 7, d).unwrap();
+        let history = db.food_history("OAT").await.unwrap();
+        assert_eq!(
+            history,
+            vec![
+                FoodHistory {
+                    name: "Oatmeal".to_string(),
+                    dates: vec![day(3), day(1)],
+                },
+                FoodHistory {
+                    name: "Oat milk".to_string(),
+                    dates: vec![day(3)],
+                },
+            ]
+        );
+        assert_eq!(db.food_history("burr").await.unwrap()[0].dates, vec![day(5), day(1)]);
+        assert!(db.food_history("kale").await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn newer_columns_survive_an_old_schema() {
+        let dir = TempDir::new().unwrap();
+        // A database from before food quantities, calories, water, sleep and heart rate
+        {
+            let old = Builder::new_local(dir.path().join("mountains.db").to_str().unwrap())
+                .build()
+                .await
+                .unwrap();
+            let conn = old.connect().unwrap();
+            conn.execute("CREATE TABLE daily_logs (date TEXT PRIMARY KEY, weight REAL, waist REAL, miles_covered REAL, elevation_gain INTEGER, strength_mobility TEXT, notes TEXT)", ())
+                .await
+                .unwrap();
+            conn.execute("CREATE TABLE food_entries (id INTEGER PRIMARY KEY AUTOINCREMENT, date TEXT NOT NULL, name TEXT NOT NULL)", ())
+                .await
+                .unwrap();
+            conn.execute("INSERT INTO daily_logs (date) VALUES ('2026-07-01')", ())
+                .await
+                .unwrap();
+            conn.execute("INSERT INTO food_entries (date, name) VALUES ('2026-07-01', 'Toast')", ())
+                .await
+                .unwrap();
+        }
+
+        let mut db = DbManager::new_local_first(dir.path()).await.unwrap();
+        let mut log = db.load_all_daily_logs().await.unwrap().remove(0);
+        assert_eq!(log.food_entries, vec![FoodEntry::new("Toast".to_string())]);
+
+        log.add_food_entry(FoodEntry {
+            name: "Oatmeal".to_string(),
+            quantity: Some("1 cup".to_string()),
+            calories: Some(350),
+            macros: Some(Macros {
+                protein: 12.5,
+                carbs: 60.0,
+                fat: 6.0,
+            }),
+        });
+        log.water = Some(24.0);
+        log.sleep_hours = Some(7.5);
+        log.resting_hr = Some(48);
+        log.hrv = Some(72);
+        log.energy = Some(4);
+        log.rpe = Some(7);
+        log.duration_secs = Some(3723);
+        log.avg_hr = Some(146);
+        log.max_hr = Some(171);
+        log.start_time = chrono::NaiveTime::from_hms_opt(6, 15, 0);
+        log.activity = Some(ActivityType::Hike);
+        log.gear = Some("Speedgoat 6".to_string());
+        log.route = Some("Bear Peak loop".to_string());
+        log.archived = true;
+        log.indoor = true;
+        log.rest_day = true;
+        log.intention = Some("Easy on the downhills".to_string());
+        log.gratitude = Some("Dry trails".to_string());
+        log.weather = Some(Weather {
+            high: 86.5,
+            low: 59.0,
+            code: 2,
+        });
+        db.save_daily_log(&log).await.unwrap();
+        let saved = db.load_all_daily_logs().await.unwrap().remove(0);
+        assert_eq!(saved.food_entries, log.food_entries);
+        assert_eq!(saved.calories(), Some(350));
+        assert_eq!(saved.water, Some(24.0));
+        assert_eq!(saved.sleep_hours, Some(7.5));
+        assert_eq!((saved.resting_hr, saved.hrv), (Some(48), Some(72)));
+        assert_eq!((saved.energy, saved.rpe), (Some(4), Some(7)));
+        assert_eq!(saved.duration_secs, Some(3723));
+        assert_eq!((saved.avg_hr, saved.max_hr), (Some(146), Some(171)));
+        assert_eq!(saved.start_time, log.start_time);
+        assert_eq!(saved.activity, Some(ActivityType::Hike));
+        assert_eq!(saved.gear.as_deref(), Some("Speedgoat 6"));
+        assert_eq!(saved.route.as_deref(), Some("Bear Peak loop"));
+        assert!(saved.archived);
+        assert!(saved.indoor && saved.rest_day);
+        assert_eq!(saved.intention, log.intention);
+        assert_eq!(saved.gratitude, log.gratitude);
+        assert_eq!(saved.weather, log.weather);
+    }
+
+    #[tokio::test]
+    async fn a_replica_pulled_with_an_old_schema_is_brought_up_to_date() {
+        let dir = TempDir::new().unwrap();
+        let mut db = DbManager::new_local_first(dir.path()).await.unwrap();
+
+        // An existing replica whose pull handed back the primary's tables
+        // from before water and food quantities; the -info file is what
+        // marks it a replica
+        let replica_dir = TempDir::new().unwrap();
+        let replica_path = replica_dir.path().join("mountains.db");
+        let replica_str = replica_path.to_str().unwrap().to_string();
+        std::fs::write(format!("{}-info", replica_str), "{}").unwrap();
+        let replica = Builder::new_local(&replica_str).build().await.unwrap();
+        let conn = replica.connect().unwrap();
+        conn.execute("CREATE TABLE daily_logs (date TEXT PRIMARY KEY, weight REAL, waist REAL, miles_covered REAL, elevation_gain INTEGER, strength_mobility TEXT, notes TEXT)", ())
+            .await
+            .unwrap();
+        conn.execute("CREATE TABLE food_entries (id INTEGER PRIMARY KEY AUTOINCREMENT, date TEXT NOT NULL, name TEXT NOT NULL)", ())
+            .await
+            .unwrap();
+        conn.execute("INSERT INTO daily_logs (date) VALUES ('2026-07-01')", ())
+            .await
+            .unwrap();
+        (db.db, db.conn) = (replica, conn);
+
+        db.settle_replica(&replica_str).await.unwrap();
+        let mut log = db.load_all_daily_logs().await.unwrap().remove(0);
+        log.water = Some(24.0);
+        log.add_food_entry(FoodEntry {
+            quantity: Some("1 cup".to_string()),
+            ..FoodEntry::new("Oatmeal".to_string())
+        });
+        db.save_daily_log(&log).await.unwrap();
+        let saved = db.load_all_daily_logs().await.unwrap().remove(0);
+        assert_eq!(saved.water, Some(24.0));
+        assert_eq!(saved.food_entries, log.food_entries);
+        assert!(db.load_goals().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn gear_is_kept_in_order_and_retired_in_place() {
+        let dir = TempDir::new().unwrap();
+        let db = DbManager::new_local_first(dir.path()).await.unwrap();
+        for name in ["Speedgoat 6", "Mafate 4"] {
+            db.save_gear(&Gear {
+                name: name.to_string(),
+                retired: false,
+            })
+            .await
+            .unwrap();
+        }
+        db.save_gear(&Gear {
+            name: "Speedgoat 6".to_string(),
+            retired: true,
+        })
+        .await
+        .unwrap();
+
+        let gear = db.load_gear().await.unwrap();
+        assert_eq!(gear.len(), 2);
+        assert_eq!((gear[0].name.as_str(), gear[0].retired), ("Speedgoat 6", true));
+        assert_eq!((gear[1].name.as_str(), gear[1].retired), ("Mafate 4", false));
+    }
+
+    #[tokio::test]
+    async fn goals_are_added_updated_and_deleted() {
+        let dir = TempDir::new().unwrap();
+        let db = DbManager::new_local_first(dir.path()).await.unwrap();
+        let since = NaiveDate::from_ymd_opt(2026, 7, 1).unwrap();
+        let weekly = db.add_goal(GoalKind::WeeklyMiles, 40.0, since).await.unwrap();
+        let sokay = db.add_goal(GoalKind::SokayLimit, 3.0, since).await.unwrap();
+        assert_ne!(weekly, sokay);
+
+        let mut goals = db.load_goals().await.unwrap();
+        assert_eq!(goals.len(), 2);
+        assert_eq!(
+            (goals[0].kind, goals[0].target, goals[0].since),
+            (GoalKind::WeeklyMiles, 40.0, since)
+        );
+        goals[0].target = 45.0;
+        goals[0].active = false;
+        db.update_goal(&goals[0]).await.unwrap();
+        db.delete_goal(sokay).await.unwrap();
+
+        let goals = db.load_goals().await.unwrap();
+        assert_eq!(goals.len(), 1);
+        assert_eq!((goals[0].id, goals[0].target, goals[0].active), (weekly, 45.0, false));
+    }
+
+    #[tokio::test]
+    async fn phases_are_added_updated_and_deleted() {
+        let dir = TempDir::new().unwrap();
+        let db = DbManager::new_local_first(dir.path()).await.unwrap();
+        let day = |month, d| NaiveDate::from_ymd_opt(2026, month, d).unwrap();
+        let taper = db.add_phase(PhaseKind::Taper, day(9, 16), day(9, 30)).await.unwrap();
+        let build = db.add_phase(PhaseKind::Build, day(8, 1), day(9, 15)).await.unwrap();
+
+        let mut phases = db.load_phases().await.unwrap();
+        assert_eq!(phases.iter().map(|p| p.id).collect::<Vec<_>>(), vec![build, taper]);
+        phases[1].kind = PhaseKind::Peak;
+        phases[1].end = day(9, 25);
+        db.update_phase(&phases[1]).await.unwrap();
+        db.delete_phase(build).await.unwrap();
+
+        let phases = db.load_phases().await.unwrap();
+        assert_eq!(phases.len(), 1);
+        assert_eq!((phases[0].kind, phases[0].end), (PhaseKind::Peak, day(9, 25)));
+    }
+
+    #[tokio::test]
+    async fn injuries_are_added_resolved_and_deleted() {
+        let dir = TempDir::new().unwrap();
+        let db = DbManager::new_local_first(dir.path()).await.unwrap();
+        let day = |month, d| NaiveDate::from_ymd_opt(2026, month, d).unwrap();
+        let knee = db.add_injury("Left knee", Severity::Mild, day(8, 1), None).await.unwrap();
+        let calf = db
+            .add_injury("Right calf", Severity::Severe, day(6, 2), Some(day(6, 20)))
+            .await
+            .unwrap();
+
+        let mut injuries = db.load_injuries().await.unwrap();
+        assert_eq!(injuries.iter().map(|i| i.id).collect::<Vec<_>>(), vec![knee, calf]);
+        assert_eq!(injuries[1].resolved, Some(day(6, 20)));
+        injuries[0].severity = Severity::Moderate;
+        injuries[0].resolved = Some(day(8, 12));
+        db.update_injury(&injuries[0]).await.unwrap();
+        db.delete_injury(calf).await.unwrap();
+
+        let injuries = db.load_injuries().await.unwrap();
+        assert_eq!(injuries.len(), 1);
+        assert_eq!(
+            (injuries[0].severity, injuries[0].resolved),
+            (Severity::Moderate, Some(day(8, 12)))
+        );
+    }
+
+    #[tokio::test]
+    async fn planned_workouts_are_replaced_by_day() {
+        let dir = TempDir::new().unwrap();
+        let mut db = DbManager::new_local_first(dir.path()).await.unwrap();
+        let day = |d| NaiveDate::from_ymd_opt(2026, 8, d).unwrap();
+        let planned = |d, miles| PlannedWorkout {
+            date: day(d),
+            miles,
+            vert: None,
+            note: Some("Easy".to_string()),
+        };
+        db.save_planned_workouts(&[planned(3, Some(6.0)), planned(4, None)])
+            .await
+            .unwrap();
+        db.save_planned_workouts(&[planned(4, Some(8.5))]).await.unwrap();
+
+        let plan = db.load_planned_workouts().await.unwrap();
+        assert_eq!(plan.len(), 2);
+        assert_eq!(plan[&day(3)], planned(3, Some(6.0)));
+        assert_eq!(plan[&day(4)], planned(4, Some(8.5)));
+    }
+
     /// Builds a db with the given logs in a scratch dir, stashes it, and moves the
     /// stash into `main_dir` under `stash_name` (bypasses the unix-seconds stash
     /// naming, which would collide for two stashes created within the same second).
@@ -1,22 +1,32 @@
 use crate::models::DailyLog;
-use chrono::{Datelike, NaiveDate};
+use crate::streaks::{StreakRule, current_streak};
+use crate::format::Units;
+use crate::units::{Quantity, UnitSystem};
+use chrono::{Datelike, NaiveDate, Weekday};
 
-const ELEVATION_THRESHOLD: i32 = 1000;
-
-pub fn count_monthly_1000_days(logs: &[DailyLog], reference_date: NaiveDate) -> usize {
+/// Days in the month of `reference_date` with at least `threshold` feet of gain.
+pub fn count_monthly_vert_days(
+    logs: &[DailyLog],
+    reference_date: NaiveDate,
+    threshold: i32,
+) -> usize {
     logs.iter()
         .filter(|log| {
             log.date.year() == reference_date.year()
                 && log.date.month() == reference_date.month()
-                && log.elevation_gain.unwrap_or(0) >= ELEVATION_THRESHOLD
+                && log.elevation_gain.unwrap_or(0) >= threshold
         })
         .count()
 }
 
-pub fn calculate_weekly_elevation(logs: &[DailyLog], reference_date: NaiveDate) -> i32 {
-    let current_week = reference_date.iso_week();
+pub fn calculate_weekly_elevation(
+    logs: &[DailyLog],
+    reference_date: NaiveDate,
+    week_start: Weekday,
+) -> i32 {
+    let current_week = reference_date.week(week_start).first_day();
     logs.iter()
-        .filter(|log| log.date.iso_week() == current_week)
+        .filter(|log| log.date.week(week_start).first_day() == current_week)
         .filter_map(|log| log.elevation_gain)
         .sum()
 }
@@ -37,54 +47,100 @@ pub fn calculate_yearly_elevation(logs: &[DailyLog], reference_date: NaiveDate)
         .sum()
 }
 
-/// Returns streak count only if active (extends to most recent logged day)
-pub fn calculate_current_streak(logs: &[DailyLog]) -> Option<usize> {
-    if logs.is_empty() {
-        return None;
+/// Totals for each week (keyed by its first day) from the week containing
+/// `from` through the week containing `to`, oldest first. Weeks without vert
+/// are 0.
+pub fn weekly_elevation_series(
+    logs: &[DailyLog],
+    from: NaiveDate,
+    to: NaiveDate,
+    week_start: Weekday,
+) -> Vec<(NaiveDate, i32)> {
+    let first = from.week(week_start).first_day();
+    let mut series: Vec<(NaiveDate, i32)> = first
+        .iter_weeks()
+        .take_while(|week| *week <= to)
+        .map(|week| (week, 0))
+        .collect();
+    for log in logs.iter().filter(|log| log.date >= first) {
+        let week = (log.date - first).num_days() as usize / 7;
+        if let (Some(feet), Some(entry)) = (log.elevation_gain, series.get_mut(week)) {
+            entry.1 += feet;
+        }
     }
+    series
+}
 
-    let mut sorted_logs = logs.to_vec();
-    sorted_logs.sort_by(|a, b| b.date.cmp(&a.date));
-
-    let most_recent_date = sorted_logs.first()?.date;
-
-    let most_recent_has_threshold =
-        sorted_logs.first()?.elevation_gain.unwrap_or(0) >= ELEVATION_THRESHOLD;
-
-    if !most_recent_has_threshold {
+/// Warning for a miles/elevation pair that is probably mistyped: vert with no
+/// distance, or steeper than `max_feet_per_mile` on average. 0 disables it.
+/// The text is written in `units`.
+pub fn elevation_sanity_warning(
+    miles: Option<f32>,
+    elevation: Option<i32>,
+    max_feet_per_mile: i32,
+    units: Units,
+) -> Option<String> {
+    if max_feet_per_mile <= 0 {
         return None;
     }
-
-    let mut streak_count = 0;
-    let mut current_date = most_recent_date;
-
-    while let Some(log) = sorted_logs.iter().find(|log| log.date == current_date) {
-        if log.elevation_gain.unwrap_or(0) >= ELEVATION_THRESHOLD {
-            streak_count += 1;
-            current_date = match current_date.pred_opt() {
-                Some(date) => date,
-                None => break,
+    let elevation = elevation.filter(|&feet| feet > 0)?;
+    match miles.filter(|&miles| miles > 0.0) {
+        None => Some(format!(
+            "{} of elevation logged without any {}.",
+            units.format(Quantity::Elevation, elevation as f32),
+            units.name(Quantity::Distance)
+        )),
+        Some(miles) => {
+            let feet_per_mile = elevation as f32 / miles;
+            let grade = |feet_per_mile: f64| {
+                units.convert(Quantity::Elevation, feet_per_mile)
+                    / units.convert(Quantity::Distance, 1.0)
             };
-        } else {
-            break;
+            let rate = format!(
+                "{}/{}",
+                units.label(Quantity::Elevation),
+                match units.system {
+                    UnitSystem::Imperial => "mile",
+                    UnitSystem::Metric => "km",
+                }
+            );
+            (feet_per_mile > max_feet_per_mile as f32).then(|| {
+                format!(
+                    "{} {} is steeper than the {} {} ceiling.",
+                    units.decimal(grade(feet_per_mile as f64), 0),
+                    rate,
+                    units.decimal(grade(max_feet_per_mile as f64), 0),
+                    rate
+                )
+            })
         }
     }
+}
 
-    if streak_count >= 2 {
-        Some(streak_count)
-    } else {
-        None
-    }
+/// Returns streak count only if active (extends to most recent logged day)
+pub fn calculate_current_streak(
+    logs: &[DailyLog],
+    threshold: i32,
+    count_indoor: bool,
+) -> Option<usize> {
+    current_streak(logs, &StreakRule::vert(threshold), count_indoor)
+        .map(|streak| streak.days)
+        .filter(|&days| days >= 2)
 }
 
-pub fn get_streak_message(logs: &[DailyLog]) -> String {
-    if let Some(streak_count) = calculate_current_streak(logs) {
+pub fn get_streak_message(streak: Option<usize>, threshold: i32, units: Units) -> String {
+    let threshold = units.vert_threshold(threshold);
+    let unit = units.name(Quantity::Elevation);
+    if let Some(streak_count) = streak {
         format!(
-            "You currently have {} consecutive days of 1000+ feet of vert!",
-            streak_count
+            "You currently have {} consecutive days of {} {} of vert!",
+            streak_count, threshold, unit
         )
     } else {
-        "Consider starting a streak - 1000+ daily feet of gain".to_string()
+        format!(
+            "Consider starting a streak - {} daily {} of gain",
+            threshold, unit
+        )
     }
 }
 
@@ -102,7 +158,7 @@ mod tests {
     }
 
     #[test]
-    fn count_monthly_1000_days_matches_month_year_and_threshold() {
+    fn count_monthly_vert_days_matches_month_year_and_threshold() {
         let reference = NaiveDate::from_ymd_opt(2026, 1, 15).unwrap();
         let logs = vec![
             log(NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(), Some(1200)),
@@ -112,7 +168,7 @@ mod tests {
             log(NaiveDate::from_ymd_opt(2025, 12, 31).unwrap(), Some(2000)),
         ];
 
-        assert_eq!(count_monthly_1000_days(&logs, reference), 2);
+        assert_eq!(count_monthly_vert_days(&logs, reference, 1000), 2);
     }
 
     #[test]
@@ -127,7 +183,10 @@ mod tests {
             log(NaiveDate::from_ymd_opt(2025, 7, 22).unwrap(), Some(5000)),
         ];
 
-        assert_eq!(calculate_weekly_elevation(&logs, reference), 2700);
+        assert_eq!(
+            calculate_weekly_elevation(&logs, reference, Weekday::Mon),
+            2700
+        );
         assert_eq!(calculate_monthly_elevation(&logs, reference), 3500);
         assert_eq!(calculate_yearly_elevation(&logs, reference), 3900);
     }
@@ -142,7 +201,10 @@ mod tests {
             log(NaiveDate::from_ymd_opt(2026, 1, 5).unwrap(), Some(6000)),
         ];
 
-        assert_eq!(calculate_weekly_elevation(&logs, reference), 2700);
+        assert_eq!(
+            calculate_weekly_elevation(&logs, reference, Weekday::Mon),
+            2700
+        );
     }
 
     #[test]
@@ -169,6 +231,38 @@ mod tests {
             },
         ];
 
-        assert_eq!(calculate_current_streak(&logs), Some(3));
+        assert_eq!(calculate_current_streak(&logs, 1000, true), Some(3));
+    }
+
+    #[test]
+    fn elevation_sanity_flags_missing_miles_and_steep_ratio() {
+        let imperial = Units::from(UnitSystem::Imperial);
+        assert!(elevation_sanity_warning(None, Some(800), 1500, imperial).is_some());
+        assert!(elevation_sanity_warning(Some(0.0), Some(800), 1500, imperial).is_some());
+        assert!(elevation_sanity_warning(Some(2.0), Some(3000), 1500, imperial).is_none());
+        assert!(elevation_sanity_warning(Some(2.0), Some(3001), 1500, imperial).is_some());
+        assert!(elevation_sanity_warning(Some(5.0), None, 1500, imperial).is_none());
+        assert!(elevation_sanity_warning(None, Some(0), 1500, imperial).is_none());
+        assert!(elevation_sanity_warning(None, Some(800), 0, imperial).is_none());
+        assert_eq!(
+            elevation_sanity_warning(Some(1.0), Some(2000), 1500, UnitSystem::Metric.into()).unwrap(),
+            "379 m/km is steeper than the 284 m/km ceiling."
+        );
+    }
+
+    #[test]
+    fn weekly_elevation_series_spans_the_range_by_week() {
+        let day = |d| NaiveDate::from_ymd_opt(2026, 7, d).unwrap();
+        let logs = vec![
+            log(day(6), Some(1200)),
+            log(day(8), Some(300)),
+            log(day(22), Some(2500)),
+            log(day(27), Some(900)),
+        ];
+
+        assert_eq!(
+            weekly_elevation_series(&logs, day(8), day(26), Weekday::Mon),
+            vec![(day(6), 1500), (day(13), 0), (day(20), 2500)]
+        );
     }
 }
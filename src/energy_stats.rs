@@ -0,0 +1,179 @@
+//! How the 1-5 energy rating lines up with mileage: the average rating on
+//! run days against rest days, and how closely it follows the miles.
+
+use chrono::{Days, NaiveDate};
+
+use crate::models::DailyLog;
+
+/// How far back the statistics screen looks when comparing energy.
+pub const ENERGY_WINDOW_DAYS: u64 = 90;
+
+/// Fewer rated days than this and a correlation says more about chance than
+/// about training.
+const MIN_DAYS_FOR_CORRELATION: usize = 5;
+
+/// The average rating over a set of days and how many there were.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EnergyAverage {
+    pub average: f32,
+    pub days: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EnergyByMileage {
+    /// Rated days with some miles logged.
+    pub run_days: Option<EnergyAverage>,
+    /// Rated days without any.
+    pub rest_days: Option<EnergyAverage>,
+    /// Pearson correlation between the rating and the day's miles, from -1
+    /// to 1. None until there are enough rated days, or when either side
+    /// never changes.
+    pub correlation: Option<f32>,
+}
+
+/// Energy against mileage over the `ENERGY_WINDOW_DAYS` ending on `today`,
+/// or None when no day in that window has a rating.
+pub fn energy_by_mileage(logs: &[DailyLog], today: NaiveDate) -> Option<EnergyByMileage> {
+    let from = today
+        .checked_sub_days(Days::new(ENERGY_WINDOW_DAYS - 1))
+        .unwrap_or(today);
+    let rated: Vec<(f32, f32)> = logs
+        .iter()
+        .filter(|log| log.date >= from && log.date <= today)
+        .filter_map(|log| {
+            let energy = log.energy?;
+            Some((energy as f32, log.miles_covered.unwrap_or(0.0)))
+        })
+        .collect();
+    if rated.is_empty() {
+        return None;
+    }
+
+    let average = |days: Vec<f32>| {
+        (!days.is_empty()).then(|| EnergyAverage {
+            average: days.iter().sum::<f32>() / days.len() as f32,
+            days: days.len(),
+        })
+    };
+    Some(EnergyByMileage {
+        run_days: average(
+            rated
+                .iter()
+                .filter(|(_, miles)| *miles > 0.0)
+                .map(|(e, _)| *e)
+                .collect(),
+        ),
+        rest_days: average(
+            rated
+                .iter()
+                .filter(|(_, miles)| *miles <= 0.0)
+                .map(|(e, _)| *e)
+                .collect(),
+        ),
+        correlation: correlation(&rated),
+    })
+}
+
+fn correlation(pairs: &[(f32, f32)]) -> Option<f32> {
+    if pairs.len() < MIN_DAYS_FOR_CORRELATION {
+        return None;
+    }
+    let n = pairs.len() as f64;
+    let mean_x = pairs.iter().map(|(x, _)| *x as f64).sum::<f64>() / n;
+    let mean_y = pairs.iter().map(|(_, y)| *y as f64).sum::<f64>() / n;
+    let (mut covariance, mut var_x, mut var_y) = (0.0, 0.0, 0.0);
+    for (x, y) in pairs {
+        let (dx, dy) = (*x as f64 - mean_x, *y as f64 - mean_y);
+        covariance += dx * dy;
+        var_x += dx * dx;
+        var_y += dy * dy;
+    }
+    if var_x == 0.0 || var_y == 0.0 {
+        return None;
+    }
+    Some((covariance / (var_x * var_y).sqrt()) as f32)
+}
+
+/// "Run days: 3.8/5 (12 days)", or "no days yet" for a side with nothing
+/// rated.
+pub fn describe_average(label: &str, average: Option<EnergyAverage>) -> String {
+    match average {
+        Some(EnergyAverage { average, days }) => format!(
+            "{label}: {average:.1}/5 ({days} {})",
+            if days == 1 { "day" } else { "days" }
+        ),
+        None => format!("{label}: no days yet"),
+    }
+}
+
+/// A plain reading of a correlation: "r = 0.42, higher on bigger days".
+pub fn describe_correlation(r: f32) -> String {
+    let reading = if r >= 0.3 {
+        "higher on bigger days"
+    } else if r <= -0.3 {
+        "lower on bigger days"
+    } else {
+        "no clear link"
+    };
+    format!("r = {r:.2}, {reading}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 7, d).unwrap()
+    }
+
+    fn rated(d: u32, energy: Option<u8>, miles: Option<f32>) -> DailyLog {
+        DailyLog {
+            energy,
+            miles_covered: miles,
+            ..DailyLog::new(day(d))
+        }
+    }
+
+    #[test]
+    fn compares_run_and_rest_days_and_follows_the_miles() {
+        let logs = vec![
+            rated(1, Some(4), Some(6.0)),
+            rated(2, Some(2), None),
+            rated(3, Some(5), Some(10.0)),
+            rated(4, None, Some(20.0)),
+            rated(5, Some(3), Some(3.0)),
+            rated(6, Some(1), Some(0.0)),
+        ];
+        let stats = energy_by_mileage(&logs, day(6)).unwrap();
+        assert_eq!(
+            stats.run_days,
+            Some(EnergyAverage {
+                average: 4.0,
+                days: 3
+            })
+        );
+        assert_eq!(
+            stats.rest_days,
+            Some(EnergyAverage {
+                average: 1.5,
+                days: 2
+            })
+        );
+        assert!(stats.correlation.unwrap() > 0.9);
+        assert_eq!(
+            describe_average("Run days", stats.run_days),
+            "Run days: 4.0/5 (3 days)"
+        );
+        assert_eq!(describe_correlation(-0.1), "r = -0.10, no clear link");
+
+        // Too few days to correlate, and nothing rated before the window
+        let stats = energy_by_mileage(&logs[..2], day(2)).unwrap();
+        assert_eq!(stats.correlation, None);
+        assert_eq!(
+            describe_average("Rest days", None),
+            "Rest days: no days yet"
+        );
+        let later = NaiveDate::from_ymd_opt(2026, 12, 1).unwrap();
+        assert_eq!(energy_by_mileage(&logs, later), None);
+    }
+}
@@ -1,8 +1,11 @@
+use anyhow::Result;
+use chrono::NaiveTime;
 use crate::db_manager::DbManager;
 use crate::file_manager::FileManager;
+use crate::health;
 use crate::models::{
-    AppScreen, AppState, DailyLog, FocusedSection, FoodEntry, MeasurementField, RunningField,
-    field_accessor::FieldType,
+    AppScreen, AppState, DailyLog, FocusedSection, FoodEntry, MeasurementField, Reflection,
+    RunningField, activity::ActivityType, field_accessor::FieldType, home_filter::HomeRow,
 };
 use crossterm::event::{KeyCode, KeyModifiers};
 use std::sync::Arc;
@@ -11,6 +14,9 @@ use tokio::sync::RwLock;
 pub struct InputHandler {
     pub input_buffer: String,
     pub cursor_position: usize,
+    /// Completions offered under the input, like frequent foods.
+    pub suggestions: Vec<String>,
+    pub suggestion_selected: Option<usize>,
 }
 
 impl InputHandler {
@@ -18,12 +24,78 @@ impl InputHandler {
         Self {
             input_buffer: String::new(),
             cursor_position: 0,
+            suggestions: Vec::new(),
+            suggestion_selected: None,
         }
     }
 
     pub fn clear(&mut self) {
         self.input_buffer.clear();
         self.cursor_position = 0;
+        self.set_suggestions(Vec::new());
+    }
+
+    /// Replaces the suggestions, dropping the selection since the list
+    /// under it changed.
+    pub fn set_suggestions(&mut self, suggestions: Vec<String>) {
+        self.suggestions = suggestions;
+        self.suggestion_selected = None;
+    }
+
+    pub fn select_next_suggestion(&mut self) {
+        self.suggestion_selected =
+            NavigationHandler::move_selection_down(self.suggestion_selected, self.suggestions.len());
+    }
+
+    pub fn select_previous_suggestion(&mut self) {
+        self.suggestion_selected =
+            NavigationHandler::move_selection_up(self.suggestion_selected, self.suggestions.len());
+    }
+
+    /// Puts the selected suggestion, or the only one, into the buffer.
+    /// Returns false when there's nothing to take.
+    pub fn accept_suggestion(&mut self) -> bool {
+        let index = match (self.suggestion_selected, self.suggestions.len()) {
+            (Some(index), _) => index,
+            (None, 1) => 0,
+            _ => return false,
+        };
+        match self.suggestions.get(index).cloned() {
+            Some(suggestion) => {
+                self.set_input(suggestion);
+                self.set_suggestions(Vec::new());
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// What's typed on the cursor's line up to the cursor, past any bullet
+    /// or indent, for completing a line at a time.
+    pub fn line_before_cursor(&self) -> &str {
+        let before = &self.input_buffer[..self.cursor_position.min(self.input_buffer.len())];
+        before
+            .rsplit('\n')
+            .next()
+            .unwrap_or_default()
+            .trim_start_matches(|c: char| !c.is_alphanumeric())
+    }
+
+    /// Swaps `line_before_cursor` for the selected suggestion, or the
+    /// first, and a space to go on typing after it. Returns false when
+    /// there's nothing to take.
+    pub fn complete_line(&mut self) -> bool {
+        let index = self.suggestion_selected.unwrap_or(0);
+        let Some(suggestion) = self.suggestions.get(index).cloned() else {
+            return false;
+        };
+        let end = self.cursor_position.min(self.input_buffer.len());
+        let start = end - self.line_before_cursor().len();
+        self.input_buffer
+            .replace_range(start..end, &format!("{} ", suggestion));
+        self.cursor_position = start + suggestion.len() + 1;
+        self.set_suggestions(Vec::new());
+        true
     }
 
     pub fn set_input(&mut self, text: String) {
@@ -75,6 +147,14 @@ impl InputHandler {
         self.cursor_position = self.input_buffer.len();
     }
 
+    /// Nudges a numeric buffer by `step` (empty counts as 0), never below
+    /// zero, leaving the cursor at the end.
+    pub fn scrub_number(&mut self, step: f64, decimals: usize) {
+        let current = self.input_buffer.trim().parse::<f64>().unwrap_or(0.0);
+        let value = (current + step).max(0.0);
+        self.set_input(format!("{:.*}", decimals, value));
+    }
+
     pub fn insert_newline(&mut self) -> bool {
         let current_line_count = self.input_buffer.chars().filter(|&c| c == '\n').count() + 1;
         if current_line_count >= 200 {
@@ -154,6 +234,53 @@ impl InputHandler {
         }
     }
 
+    /// Like `handle_numeric_input`, but for times typed as hh:mm:ss.
+    pub fn handle_duration_input(&mut self, key: KeyCode) -> bool {
+        match key {
+            KeyCode::Char(c) if c.is_ascii_digit() || c == ':' => {
+                self.insert_char(c);
+                true
+            }
+            KeyCode::Char(_) => true,
+            _ => self.handle_integer_input(key),
+        }
+    }
+
+    /// Seconds in a time typed as "h:mm:ss", "mm:ss" or whole minutes.
+    /// Anything after the first part has to be under 60, and a zero time is
+    /// no time at all.
+    pub fn parse_duration(text: &str) -> Option<u32> {
+        let parts: Vec<&str> = text.trim().split(':').collect();
+        if parts.len() > 3 {
+            return None;
+        }
+        let mut numbers = Vec::with_capacity(parts.len());
+        for part in &parts {
+            numbers.push(part.parse::<u32>().ok()?);
+        }
+        if numbers[1..].iter().any(|&n| n >= 60) {
+            return None;
+        }
+        let seconds = match numbers[..] {
+            [minutes] => minutes.checked_mul(60)?,
+            [minutes, seconds] => minutes.checked_mul(60)?.checked_add(seconds)?,
+            [hours, minutes, seconds] => hours
+                .checked_mul(3600)?
+                .checked_add(minutes * 60 + seconds)?,
+            _ => return None,
+        };
+        (seconds > 0).then_some(seconds)
+    }
+
+    /// A 24-hour time of day typed as "h:mm", like "6:05" or "18:30".
+    pub fn parse_time_of_day(text: &str) -> Option<chrono::NaiveTime> {
+        let (hours, minutes) = text.trim().split_once(':')?;
+        if minutes.len() != 2 {
+            return None;
+        }
+        chrono::NaiveTime::from_hms_opt(hours.parse().ok()?, minutes.parse().ok()?, 0)
+    }
+
     pub fn handle_integer_input(&mut self, key: KeyCode) -> bool {
         match key {
             KeyCode::Char(c) => {
@@ -346,7 +473,33 @@ impl SectionNavigator {
             FieldType::Miles => FocusedSection::Running {
                 focused_field: RunningField::Elevation,
             },
-            FieldType::Elevation => FocusedSection::FoodItems,
+            FieldType::Elevation => FocusedSection::Running {
+                focused_field: RunningField::Duration,
+            },
+            FieldType::Duration => FocusedSection::Running {
+                focused_field: RunningField::StartTime,
+            },
+            FieldType::StartTime => FocusedSection::Running {
+                focused_field: RunningField::Rpe,
+            },
+            FieldType::Sleep => FocusedSection::Measurements {
+                focused_field: MeasurementField::RestingHr,
+            },
+            FieldType::RestingHr => FocusedSection::Measurements {
+                focused_field: MeasurementField::Hrv,
+            },
+            // The morning numbers are optional, so the last carries on to
+            // the run like waist does
+            FieldType::Hrv => FocusedSection::Running {
+                focused_field: RunningField::Miles,
+            },
+            FieldType::Rpe => FocusedSection::Running {
+                focused_field: RunningField::AvgHr,
+            },
+            FieldType::AvgHr => FocusedSection::Running {
+                focused_field: RunningField::MaxHr,
+            },
+            FieldType::MaxHr => FocusedSection::FoodItems,
             FieldType::StrengthMobility => FocusedSection::Notes,
             FieldType::Notes => FocusedSection::Measurements {
                 focused_field: MeasurementField::Weight,
@@ -364,12 +517,36 @@ impl SectionNavigator {
             FieldType::Waist => FocusedSection::Measurements {
                 focused_field: MeasurementField::Waist,
             },
+            FieldType::Sleep => FocusedSection::Measurements {
+                focused_field: MeasurementField::Sleep,
+            },
+            FieldType::RestingHr => FocusedSection::Measurements {
+                focused_field: MeasurementField::RestingHr,
+            },
+            FieldType::Hrv => FocusedSection::Measurements {
+                focused_field: MeasurementField::Hrv,
+            },
             FieldType::Miles => FocusedSection::Running {
                 focused_field: RunningField::Miles,
             },
             FieldType::Elevation => FocusedSection::Running {
                 focused_field: RunningField::Elevation,
             },
+            FieldType::Duration => FocusedSection::Running {
+                focused_field: RunningField::Duration,
+            },
+            FieldType::StartTime => FocusedSection::Running {
+                focused_field: RunningField::StartTime,
+            },
+            FieldType::Rpe => FocusedSection::Running {
+                focused_field: RunningField::Rpe,
+            },
+            FieldType::AvgHr => FocusedSection::Running {
+                focused_field: RunningField::AvgHr,
+            },
+            FieldType::MaxHr => FocusedSection::Running {
+                focused_field: RunningField::MaxHr,
+            },
             FieldType::StrengthMobility => FocusedSection::StrengthMobility,
             FieldType::Notes => FocusedSection::Notes,
         }
@@ -380,7 +557,10 @@ impl SectionNavigator {
             FocusedSection::Measurements { focused_field } => {
                 let new_field = match focused_field {
                     MeasurementField::Weight => MeasurementField::Waist,
-                    MeasurementField::Waist => MeasurementField::Weight,
+                    MeasurementField::Waist => MeasurementField::Sleep,
+                    MeasurementField::Sleep => MeasurementField::RestingHr,
+                    MeasurementField::RestingHr => MeasurementField::Hrv,
+                    MeasurementField::Hrv => MeasurementField::Weight,
                 };
                 FocusedSection::Measurements {
                     focused_field: new_field,
@@ -389,7 +569,12 @@ impl SectionNavigator {
             FocusedSection::Running { focused_field } => {
                 let new_field = match focused_field {
                     RunningField::Miles => RunningField::Elevation,
-                    RunningField::Elevation => RunningField::Miles,
+                    RunningField::Elevation => RunningField::Duration,
+                    RunningField::Duration => RunningField::StartTime,
+                    RunningField::StartTime => RunningField::Rpe,
+                    RunningField::Rpe => RunningField::AvgHr,
+                    RunningField::AvgHr => RunningField::MaxHr,
+                    RunningField::MaxHr => RunningField::Miles,
                 };
                 FocusedSection::Running {
                     focused_field: new_field,
@@ -441,9 +626,8 @@ impl NavigationHandler {
 pub struct ActionHandler;
 
 impl ActionHandler {
-    pub fn save_food_entry(state: &mut AppState, food_name: String) -> Option<DailyLog> {
-        if !food_name.is_empty() {
-            let food_entry = FoodEntry::new(food_name);
+    pub fn save_food_entry(state: &mut AppState, food_entry: FoodEntry) -> Option<DailyLog> {
+        if !food_entry.name.is_empty() {
             let log = state.get_or_create_daily_log(state.selected_date);
             log.add_food_entry(food_entry);
             return Some(log.clone());
@@ -451,40 +635,51 @@ impl ActionHandler {
         None
     }
 
-    /// Background persistence to avoid blocking UI
+    /// Background persistence to avoid blocking UI. Both the database and
+    /// the markdown backup are attempted. Only the database decides whether
+    /// the day was saved; a backup that couldn't be written comes back
+    /// alongside, to mention without treating the save as failed.
     pub async fn persist_daily_log(
         db_manager: Arc<RwLock<DbManager>>,
         file_manager: &FileManager,
-        log: DailyLog,
-    ) {
+        log: &DailyLog,
+    ) -> Result<Option<anyhow::Error>> {
         let mut db = db_manager.write().await;
-        let _ = db.save_daily_log(&log).await;
-        let _ = file_manager.save_daily_log(&log);
+        let saved = db.save_daily_log(log).await;
+        let backed_up = file_manager.save_daily_log(log);
+
+        // Bookkeeping for the startup health check
+        if saved.is_ok() || backed_up.is_ok() {
+            let now = Some(chrono::Utc::now());
+            let _ = health::Timestamps::update(&file_manager.health_path(), |times| {
+                if saved.is_ok() {
+                    times.last_save = now;
+                }
+                if backed_up.is_ok() {
+                    times.last_backup = now;
+                }
+            });
+        }
+        saved.map(|()| backed_up.err())
     }
 
     pub fn update_food_entry(
         state: &mut AppState,
         food_index: usize,
-        new_name: String,
+        food_entry: FoodEntry,
     ) -> Option<DailyLog> {
-        if !new_name.is_empty()
-            && let Some(log) = state
-                .daily_logs
-                .iter_mut()
-                .find(|log| log.date == state.selected_date)
+        if !food_entry.name.is_empty()
+            && let Some(log) = state.daily_log_mut(state.selected_date)
             && food_index < log.food_entries.len()
         {
-            log.food_entries[food_index].name = new_name;
+            log.food_entries[food_index] = food_entry;
             return Some(log.clone());
         }
         None
     }
 
     pub fn delete_food_entry(state: &mut AppState, food_index: usize) -> Option<DailyLog> {
-        if let Some(log) = state
-            .daily_logs
-            .iter_mut()
-            .find(|log| log.date == state.selected_date)
+        if let Some(log) = state.daily_log_mut(state.selected_date)
             && food_index < log.food_entries.len()
         {
             log.remove_food_entry(food_index);
@@ -493,10 +688,17 @@ impl ActionHandler {
         None
     }
 
+    /// Opens the day on row `selected_index` of the (possibly filtered)
+    /// logged days list, or today when nothing is selected.
     pub fn handle_home_enter(state: &mut AppState, selected_index: Option<usize>) {
         if let Some(index) = selected_index {
-            if index < state.daily_logs.len() {
-                state.selected_date = state.daily_logs[index].date;
+            // Enter on a month header folds or unfolds it instead
+            if let Some(&HomeRow::Month { month, .. }) = state.home_list().get(index) {
+                state.toggle_month(month);
+                return;
+            }
+            if let Some(date) = state.home_log(index).map(|log| log.date) {
+                state.selected_date = date;
             }
         } else {
             state.selected_date = chrono::Local::now().date_naive();
@@ -514,13 +716,74 @@ impl ActionHandler {
         field_type.update_value(state, input)
     }
 
-    pub fn start_edit_food(state: &AppState, food_index: usize) -> Option<String> {
-        if let Some(log) = state.get_daily_log(state.selected_date)
-            && food_index < log.food_entries.len()
-        {
-            return Some(log.food_entries[food_index].name.clone());
+    pub fn start_edit_food(state: &AppState, food_index: usize) -> Option<FoodEntry> {
+        state
+            .get_daily_log(state.selected_date)?
+            .food_entries
+            .get(food_index)
+            .cloned()
+    }
+
+    /// Adds (or with a negative amount, takes back) water on the selected
+    /// day. None when there was none to take back.
+    pub fn add_water(state: &mut AppState, ounces: f32) -> Option<DailyLog> {
+        if ounces < 0.0 && state.get_daily_log(state.selected_date)?.water.is_none() {
+            return None;
         }
-        None
+        let log = state.get_or_create_daily_log(state.selected_date);
+        log.add_water(ounces).then(|| log.clone())
+    }
+
+    /// Rates the selected day's energy, or clears the rating when it's
+    /// given again.
+    pub fn rate_energy(state: &mut AppState, rating: u8) -> DailyLog {
+        let log = state.get_or_create_daily_log(state.selected_date);
+        log.rate_energy(rating);
+        log.clone()
+    }
+
+    /// Sets the selected day's route; blank clears it.
+    pub fn set_route(state: &mut AppState, route: String) -> DailyLog {
+        let log = state.get_or_create_daily_log(state.selected_date);
+        let route = route.trim();
+        log.route = (!route.is_empty()).then(|| route.to_string());
+        log.clone()
+    }
+
+    /// Sets the selected day's intention or gratitude; blank clears it.
+    pub fn set_reflection(state: &mut AppState, reflection: Reflection, text: &str) -> DailyLog {
+        let log = state.get_or_create_daily_log(state.selected_date);
+        reflection.set(log, text);
+        log.clone()
+    }
+
+    /// Puts the selected day's run on `gear`, or on nothing.
+    pub fn set_gear(state: &mut AppState, gear: Option<String>) -> DailyLog {
+        let log = state.get_or_create_daily_log(state.selected_date);
+        log.gear = gear;
+        log.clone()
+    }
+
+    /// Moves the selected day on to the next activity type, through to
+    /// untagged.
+    pub fn cycle_activity(state: &mut AppState) -> DailyLog {
+        let log = state.get_or_create_daily_log(state.selected_date);
+        log.activity = ActivityType::cycle(log.activity);
+        log.clone()
+    }
+
+    /// Marks the day's run as indoor, or back to outdoor.
+    pub fn toggle_indoor(state: &mut AppState) -> DailyLog {
+        let log = state.get_or_create_daily_log(state.selected_date);
+        log.indoor = !log.indoor;
+        log.clone()
+    }
+
+    /// Marks the day as a deliberate rest day, or takes the mark off.
+    pub fn toggle_rest_day(state: &mut AppState) -> DailyLog {
+        let log = state.get_or_create_daily_log(state.selected_date);
+        log.rest_day = !log.rest_day;
+        log.clone()
     }
 
     pub fn save_sokay_entry(state: &mut AppState, sokay_text: String) -> Option<DailyLog> {
@@ -532,16 +795,21 @@ impl ActionHandler {
         None
     }
 
+    /// Adds a sokay entry that's only the time it was logged, for when the
+    /// tap matters more than the words. It can be edited like any other.
+    pub fn quick_sokay_entry(state: &mut AppState, at: NaiveTime) -> DailyLog {
+        let log = state.get_or_create_daily_log(state.selected_date);
+        log.add_sokay_entry(format!("Sokay at {}", at.format("%H:%M")));
+        log.clone()
+    }
+
     pub fn update_sokay_entry(
         state: &mut AppState,
         sokay_index: usize,
         new_text: String,
     ) -> Option<DailyLog> {
         if !new_text.is_empty()
-            && let Some(log) = state
-                .daily_logs
-                .iter_mut()
-                .find(|log| log.date == state.selected_date)
+            && let Some(log) = state.daily_log_mut(state.selected_date)
             && sokay_index < log.sokay_entries.len()
         {
             log.sokay_entries[sokay_index] = new_text;
@@ -551,10 +819,7 @@ impl ActionHandler {
     }
 
     pub fn delete_sokay_entry(state: &mut AppState, sokay_index: usize) -> Option<DailyLog> {
-        if let Some(log) = state
-            .daily_logs
-            .iter_mut()
-            .find(|log| log.date == state.selected_date)
+        if let Some(log) = state.daily_log_mut(state.selected_date)
             && sokay_index < log.sokay_entries.len()
         {
             log.remove_sokay_entry(sokay_index);
@@ -572,9 +837,11 @@ impl ActionHandler {
         None
     }
 
-    pub fn calculate_cumulative_sokay(state: &AppState, up_to_date: chrono::NaiveDate) -> usize {
-        state
-            .daily_logs
+    pub fn calculate_cumulative_sokay(
+        daily_logs: &[DailyLog],
+        up_to_date: chrono::NaiveDate,
+    ) -> usize {
+        daily_logs
             .iter()
             .filter(|log| log.date <= up_to_date)
             .map(|log| log.sokay_entries.len())
@@ -588,7 +855,7 @@ impl ActionHandler {
         date: chrono::NaiveDate,
     ) -> anyhow::Result<()> {
         db_manager.delete_daily_log(date).await?;
-        state.daily_logs.retain(|log| log.date != date);
+        state.remove_daily_log(date);
         let _ = file_manager.delete_daily_log(date);
         Ok(())
     }
@@ -598,6 +865,80 @@ impl ActionHandler {
 mod tests {
     use super::*;
 
+    mod input_handler {
+        use super::*;
+
+        #[test]
+        fn test_parse_duration_forms() {
+            assert_eq!(InputHandler::parse_duration("1:02:03"), Some(3723));
+            assert_eq!(InputHandler::parse_duration(" 45:30 "), Some(2730));
+            assert_eq!(InputHandler::parse_duration("45"), Some(2700));
+            assert_eq!(InputHandler::parse_duration("1:75:00"), None);
+            assert_eq!(InputHandler::parse_duration("0:00"), None);
+            assert_eq!(InputHandler::parse_duration("1::00"), None);
+            assert_eq!(InputHandler::parse_duration(""), None);
+        }
+
+        #[test]
+        fn test_parse_time_of_day() {
+            let time = |h, m| chrono::NaiveTime::from_hms_opt(h, m, 0);
+            assert_eq!(InputHandler::parse_time_of_day("6:05"), time(6, 5));
+            assert_eq!(InputHandler::parse_time_of_day(" 18:30 "), time(18, 30));
+            assert_eq!(InputHandler::parse_time_of_day("0:00"), time(0, 0));
+            assert_eq!(InputHandler::parse_time_of_day("24:00"), None);
+            assert_eq!(InputHandler::parse_time_of_day("6:5"), None);
+            assert_eq!(InputHandler::parse_time_of_day("630"), None);
+        }
+
+        #[test]
+        fn test_scrub_number_steps_and_rounds() {
+            let mut input = InputHandler::new();
+            input.set_input("172.3".to_string());
+            input.scrub_number(0.1, 1);
+            assert_eq!(input.input_buffer, "172.4");
+            input.scrub_number(-1.0, 1);
+            assert_eq!(input.input_buffer, "171.4");
+            assert_eq!(input.cursor_position, input.input_buffer.len());
+
+            input.set_input("1205".to_string());
+            input.scrub_number(10.0, 0);
+            assert_eq!(input.input_buffer, "1215");
+        }
+
+        #[test]
+        fn test_suggestions_wrap_and_fill_the_buffer() {
+            let mut input = InputHandler::new();
+            input.set_input("oa".to_string());
+            assert!(!input.accept_suggestion());
+
+            input.set_suggestions(vec!["Oatmeal".to_string(), "Oat milk".to_string()]);
+            assert!(!input.accept_suggestion());
+            input.select_previous_suggestion();
+            input.select_previous_suggestion();
+            assert_eq!(input.suggestion_selected, Some(1));
+            input.select_next_suggestion();
+            assert_eq!(input.suggestion_selected, Some(0));
+            assert!(input.accept_suggestion());
+            assert_eq!(input.input_buffer, "Oatmeal");
+            assert_eq!(input.cursor_position, 7);
+            assert!(input.suggestions.is_empty());
+
+            input.set_suggestions(vec!["Oatmeal".to_string()]);
+            input.clear();
+            assert!(input.suggestions.is_empty());
+            assert_eq!(input.suggestion_selected, None);
+        }
+
+        #[test]
+        fn test_scrub_number_from_empty_and_floor_at_zero() {
+            let mut input = InputHandler::new();
+            input.scrub_number(0.1, 1);
+            assert_eq!(input.input_buffer, "0.1");
+            input.scrub_number(-1.0, 1);
+            assert_eq!(input.input_buffer, "0.0");
+        }
+    }
+
     mod navigation_handler {
         use super::*;
 
@@ -672,9 +1013,45 @@ mod tests {
                     focused_field: RunningField::Elevation
                 }
             );
-            // Elevation advances into the Food list (focus only, no dialog).
+            assert_eq!(
+                SectionNavigator::advance_field(FieldType::Sleep),
+                FocusedSection::Measurements {
+                    focused_field: MeasurementField::RestingHr
+                }
+            );
+            assert_eq!(
+                SectionNavigator::advance_field(FieldType::Hrv),
+                FocusedSection::Running {
+                    focused_field: RunningField::Miles
+                }
+            );
             assert_eq!(
                 SectionNavigator::advance_field(FieldType::Elevation),
+                FocusedSection::Running {
+                    focused_field: RunningField::Duration
+                }
+            );
+            assert_eq!(
+                SectionNavigator::advance_field(FieldType::Duration),
+                FocusedSection::Running {
+                    focused_field: RunningField::StartTime
+                }
+            );
+            assert_eq!(
+                SectionNavigator::advance_field(FieldType::StartTime),
+                FocusedSection::Running {
+                    focused_field: RunningField::Rpe
+                }
+            );
+            assert_eq!(
+                SectionNavigator::advance_field(FieldType::Rpe),
+                FocusedSection::Running {
+                    focused_field: RunningField::AvgHr
+                }
+            );
+            // Max HR advances into the Food list (focus only, no dialog).
+            assert_eq!(
+                SectionNavigator::advance_field(FieldType::MaxHr),
                 FocusedSection::FoodItems
             );
             assert_eq!(
@@ -705,6 +1082,12 @@ mod tests {
                     focused_field: MeasurementField::Waist
                 }
             );
+            assert_eq!(
+                SectionNavigator::field_section(FieldType::Sleep),
+                FocusedSection::Measurements {
+                    focused_field: MeasurementField::Sleep
+                }
+            );
             assert_eq!(
                 SectionNavigator::field_section(FieldType::Miles),
                 FocusedSection::Running {
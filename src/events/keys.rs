@@ -0,0 +1,217 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use serde::{Deserialize, Serialize};
+
+/// The vim-style movement keys every screen navigates with.
+const MOVEMENT: [char; 4] = ['h', 'j', 'k', 'l'];
+
+/// Irons out how terminals differ in reporting the same keystroke, so the
+/// handlers can match one shape:
+///
+/// - Windows consoles report a release for every press (and kitty-protocol
+///   terminals can too); only presses and repeats are kept.
+/// - A shifted letter arrives as `J`+SHIFT on most terminals, as `j`+SHIFT
+///   with keyboard enhancement, and as plain `J` when Caps Lock or some
+///   Windows setups drop the modifier. All become `J`+SHIFT.
+pub fn normalize_key(event: KeyEvent) -> Option<(KeyCode, KeyModifiers)> {
+    if event.kind == KeyEventKind::Release {
+        return None;
+    }
+    let mut modifiers = event.modifiers;
+    let code = match event.code {
+        KeyCode::Char(c) if c.is_ascii_uppercase() => {
+            modifiers.insert(KeyModifiers::SHIFT);
+            KeyCode::Char(c)
+        }
+        KeyCode::Char(c) if c.is_ascii_lowercase() && modifiers.contains(KeyModifiers::SHIFT) => {
+            KeyCode::Char(c.to_ascii_uppercase())
+        }
+        code => code,
+    };
+    Some((code, modifiers))
+}
+
+/// Keyboard layout the movement keys are placed for.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum KeymapPreset {
+    #[default]
+    Qwerty,
+    Dvorak,
+    Colemak,
+}
+
+impl KeymapPreset {
+    /// The letters this layout types from the keys QWERTY labels h, j, k, l.
+    fn home_row(self) -> [char; 4] {
+        match self {
+            KeymapPreset::Qwerty => MOVEMENT,
+            KeymapPreset::Dvorak => ['d', 'h', 't', 'n'],
+            KeymapPreset::Colemak => ['h', 'n', 'e', 'i'],
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            KeymapPreset::Qwerty => "qwerty",
+            KeymapPreset::Dvorak => "dvorak",
+            KeymapPreset::Colemak => "colemak",
+        }
+    }
+}
+
+/// Swaps letters on command screens so movement stays under the right-hand
+/// home row on the configured layout. Commands bumped off those keys move to
+/// the letters movement vacated, so nothing becomes unreachable.
+#[derive(Debug, Clone, Default)]
+pub struct Keymap {
+    preset: KeymapPreset,
+    /// Typed letter -> the command letter it acts as, lowercase.
+    swaps: Vec<(char, char)>,
+}
+
+impl Keymap {
+    pub fn new(preset: KeymapPreset) -> Self {
+        let typed = preset.home_row();
+        let mut swaps: Vec<(char, char)> = typed
+            .into_iter()
+            .zip(MOVEMENT)
+            .filter(|(from, to)| from != to)
+            .collect();
+        let displaced = typed.into_iter().filter(|c| !MOVEMENT.contains(c));
+        let vacated = MOVEMENT.into_iter().filter(|c| !typed.contains(c));
+        swaps.extend(vacated.zip(displaced));
+        Self { preset, swaps }
+    }
+
+    /// The command key for a typed key. Shift keeps working (Shift+T is
+    /// Shift+K on Dvorak); Ctrl and Alt chords are left alone.
+    pub fn translate(&self, code: KeyCode, modifiers: KeyModifiers) -> KeyCode {
+        let KeyCode::Char(c) = code else {
+            return code;
+        };
+        if !(modifiers - KeyModifiers::SHIFT).is_empty() {
+            return code;
+        }
+        let lower = c.to_ascii_lowercase();
+        match self.swaps.iter().find(|(from, _)| *from == lower) {
+            Some((_, to)) if c.is_ascii_uppercase() => KeyCode::Char(to.to_ascii_uppercase()),
+            Some((_, to)) => KeyCode::Char(*to),
+            None => code,
+        }
+    }
+
+    /// The key typed for a command key on this layout, the inverse of
+    /// `translate`.
+    pub fn key_for(&self, command: char) -> char {
+        let lower = command.to_ascii_lowercase();
+        match self.swaps.iter().find(|(_, to)| *to == lower) {
+            Some((from, _)) if command.is_ascii_uppercase() => from.to_ascii_uppercase(),
+            Some((from, _)) => *from,
+            None => command,
+        }
+    }
+
+    /// One line listing the swaps, for the shortcuts help; `None` on QWERTY.
+    pub fn describe(&self) -> Option<String> {
+        if self.swaps.is_empty() {
+            return None;
+        }
+        let swaps: Vec<String> = self
+            .swaps
+            .iter()
+            .map(|(from, to)| format!("{from}={to}"))
+            .collect();
+        Some(format!(
+            "Keymap {}: {}",
+            self.preset.name(),
+            swaps.join(" ")
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyEventState;
+
+    fn event(code: KeyCode, modifiers: KeyModifiers, kind: KeyEventKind) -> KeyEvent {
+        KeyEvent {
+            code,
+            modifiers,
+            kind,
+            state: KeyEventState::NONE,
+        }
+    }
+
+    #[test]
+    fn shifted_letters_have_one_shape() {
+        let shift_j = Some((KeyCode::Char('J'), KeyModifiers::SHIFT));
+        for (code, modifiers) in [
+            (KeyCode::Char('J'), KeyModifiers::SHIFT),
+            (KeyCode::Char('j'), KeyModifiers::SHIFT),
+            (KeyCode::Char('J'), KeyModifiers::NONE),
+        ] {
+            assert_eq!(
+                normalize_key(event(code, modifiers, KeyEventKind::Press)),
+                shift_j
+            );
+        }
+        assert_eq!(
+            normalize_key(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE)),
+            Some((KeyCode::Char('j'), KeyModifiers::NONE))
+        );
+        // Symbols typed with Shift keep their modifiers untouched
+        assert_eq!(
+            normalize_key(KeyEvent::new(KeyCode::Char('?'), KeyModifiers::SHIFT)),
+            Some((KeyCode::Char('?'), KeyModifiers::SHIFT))
+        );
+    }
+
+    #[test]
+    fn releases_are_dropped_and_repeats_kept() {
+        let down = KeyCode::Down;
+        assert_eq!(
+            normalize_key(event(down, KeyModifiers::NONE, KeyEventKind::Release)),
+            None
+        );
+        assert_eq!(
+            normalize_key(event(down, KeyModifiers::NONE, KeyEventKind::Repeat)),
+            Some((down, KeyModifiers::NONE))
+        );
+    }
+
+    #[test]
+    fn presets_move_navigation_to_the_home_row_and_keep_commands() {
+        let dvorak = Keymap::new(KeymapPreset::Dvorak);
+        let none = KeyModifiers::NONE;
+        let typed = |keymap: &Keymap, c| keymap.translate(KeyCode::Char(c), none);
+        assert_eq!(typed(&dvorak, 'd'), KeyCode::Char('h'));
+        assert_eq!(typed(&dvorak, 'h'), KeyCode::Char('j'));
+        assert_eq!(typed(&dvorak, 'j'), KeyCode::Char('d'));
+        assert_eq!(typed(&dvorak, 'w'), KeyCode::Char('w'));
+        assert_eq!(
+            dvorak.translate(KeyCode::Char('T'), KeyModifiers::SHIFT),
+            KeyCode::Char('K')
+        );
+        assert_eq!(
+            dvorak.translate(KeyCode::Char('n'), KeyModifiers::CONTROL),
+            KeyCode::Char('n')
+        );
+        assert_eq!(
+            dvorak.describe().unwrap(),
+            "Keymap dvorak: d=h h=j t=k n=l j=d k=t l=n"
+        );
+
+        // Every letter is still typed by exactly one key
+        let colemak = Keymap::new(KeymapPreset::Colemak);
+        let mut commands: Vec<KeyCode> = ('a'..='z').map(|c| typed(&colemak, c)).collect();
+        commands.sort_by_key(|code| format!("{code:?}"));
+        commands.dedup();
+        assert_eq!(commands.len(), 26);
+        assert_eq!(typed(&colemak, 'e'), KeyCode::Char('k'));
+        for c in ('a'..='z').chain('A'..='Z') {
+            assert_eq!(typed(&colemak, colemak.key_for(c)), KeyCode::Char(c));
+        }
+        assert!(Keymap::new(KeymapPreset::Qwerty).describe().is_none());
+    }
+}
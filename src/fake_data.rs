@@ -0,0 +1,214 @@
+//! Years of made-up but believable days for `mountains gen-fake`: mileage
+//! that builds through the summer and eases off in winter, a long run on
+//! Saturdays, vert that follows the snow line, and a weight that drifts
+//! with the seasons. For trying the screens against a large log and for
+//! screenshots without real numbers. Only in debug builds.
+
+use anyhow::{Context, Result, bail};
+use chrono::{Datelike, Days, NaiveDate, NaiveTime, Weekday};
+use std::f64::consts::TAU;
+
+use crate::db_manager::DbManager;
+use crate::models::activity::ActivityType;
+use crate::models::{DailyLog, FoodEntry};
+use crate::strength::StrengthSet;
+
+/// A small seeded generator (SplitMix64), so the same seed always makes the
+/// same log.
+struct Rng(u64);
+
+impl Rng {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in [0, 1).
+    fn unit(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    fn range(&mut self, low: f64, high: f64) -> f64 {
+        low + (high - low) * self.unit()
+    }
+
+    fn chance(&mut self, probability: f64) -> bool {
+        self.unit() < probability
+    }
+
+    fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.next_u64() as usize % items.len()]
+    }
+}
+
+/// Breakfasts, lunches and dinners, with their calories.
+const MEALS: [&[(&str, u32)]; 3] = [
+    &[("Oatmeal with berries", 380), ("Eggs and toast", 450), ("Bagel", 320)],
+    &[("Burrito", 720), ("Turkey sandwich", 540), ("Rice bowl", 610)],
+    &[("Pasta", 820), ("Salmon and potatoes", 690), ("Pizza", 900), ("Chili", 640)],
+];
+
+const TREATS: [&str; 4] = ["Cookie", "Ice cream", "Candy bar", "Second dessert"];
+
+const ROUTES: [&str; 5] = [
+    "Mesa Trail",
+    "Green Mountain",
+    "Bear Peak",
+    "Canyon Loop",
+    "Creek Path",
+];
+
+/// How far into the season a date is: 1 in late July, -1 in late January.
+fn season(date: NaiveDate) -> f64 {
+    ((date.ordinal() as f64 - 205.0) / 365.25 * TAU).cos()
+}
+
+/// Share of the week's miles each day gets, Monday first. Monday is a rest
+/// day and Saturday the long run.
+const WEEK_SHARES: [f64; 7] = [0.0, 0.16, 0.2, 0.15, 0.08, 0.26, 0.15];
+
+/// Every day from `years` back through `today`, made from `seed`.
+pub fn generate(years: u32, today: NaiveDate, seed: u64) -> Vec<DailyLog> {
+    let mut rng = Rng(seed);
+    let start = today - Days::new(years as u64 * 365);
+    let mut weight_drift = 0.0;
+    let mut logs = Vec::new();
+    for date in start.iter_days().take_while(|&date| date <= today) {
+        let season = season(date);
+        // A little fitter every year, and a lot busier in the summer
+        let years_in = (date - start).num_days() as f64 / 365.25;
+        let weekly_miles = 32.0 + 2.0 * years_in + 14.0 * season;
+        let share = WEEK_SHARES[date.weekday().num_days_from_monday() as usize];
+
+        let mut log = DailyLog::new(date);
+        if share > 0.0 && !rng.chance(0.08) {
+            let miles = (weekly_miles * share * rng.range(0.8, 1.2) * 10.0).round() / 10.0;
+            let vert_per_mile = (140.0 + 90.0 * season) * rng.range(0.6, 1.4);
+            let elevation = (miles * vert_per_mile / 10.0).round() as i32 * 10;
+            let minutes_per_mile = 9.0 + vert_per_mile / 100.0 + rng.range(-0.5, 0.5);
+            log.miles_covered = Some(miles as f32);
+            log.elevation_gain = Some(elevation);
+            log.duration_secs = Some((miles * minutes_per_mile * 60.0) as u32);
+            log.start_time = NaiveTime::from_hms_opt(rng.range(5.5, 8.0) as u32, 0, 0);
+            log.activity = Some(if season > 0.3 && date.weekday() == Weekday::Sun {
+                ActivityType::Hike
+            } else if season < -0.6 && rng.chance(0.15) {
+                ActivityType::Ski
+            } else {
+                ActivityType::Run
+            });
+            log.route = Some(rng.pick(&ROUTES).to_string());
+            log.rpe = Some(if share > 0.2 { 7 } else { rng.range(3.0, 6.0) as u8 });
+            log.avg_hr = Some(rng.range(135.0, 152.0) as u32);
+            log.max_hr = log.avg_hr.map(|avg| avg + rng.range(10.0, 25.0) as u32);
+        } else if rng.chance(0.4) {
+            log.activity = Some(ActivityType::Strength);
+            log.strength_sets = vec![StrengthSet {
+                exercise: "Goblet squat".to_string(),
+                sets: 3,
+                reps: 10,
+                weight: Some((35.0 + 5.0 * years_in).round() as f32),
+            }];
+            log.strength_mobility = Some("Lunges, core and 20 min of stretching".to_string());
+        }
+
+        // Heavier in the winter, wandering a little week to week
+        weight_drift = (weight_drift + rng.range(-0.3, 0.3)) * 0.98;
+        if rng.chance(0.7) {
+            let weight = 171.0 - 3.0 * season + weight_drift + rng.range(-0.8, 0.8);
+            log.weight = Some((weight * 10.0).round() as f32 / 10.0);
+        }
+        log.sleep_hours = Some((rng.range(6.0, 8.5) * 4.0).round() as f32 / 4.0);
+        log.resting_hr = Some((50.0 - 3.0 * season + rng.range(-2.0, 2.0)) as u32);
+        log.hrv = Some((62.0 + 8.0 * season + rng.range(-10.0, 10.0)) as u32);
+        log.energy = Some(rng.range(2.0, 6.0) as u8);
+        log.water = Some(rng.range(48.0, 110.0).round() as f32);
+
+        log.food_entries = MEALS
+            .iter()
+            .map(|meals| {
+                let &(name, calories) = rng.pick(meals);
+                FoodEntry {
+                    calories: Some(calories),
+                    ..FoodEntry::new(name.to_string())
+                }
+            })
+            .collect();
+        if rng.chance(0.15) {
+            log.sokay_entries.push(rng.pick(&TREATS).to_string());
+        }
+        if rng.chance(0.05) {
+            log.notes = Some("Felt strong on the climbs today.".to_string());
+        }
+        logs.push(log);
+    }
+    logs
+}
+
+/// `mountains gen-fake`: fills an empty database with `years` of generated
+/// days. Refuses a database that has days already, so real logs are never
+/// mixed with made-up ones, and never syncs.
+pub async fn run_cli(years: u32, seed: u64) -> Result<()> {
+    let config = crate::config::AppConfig::load()?;
+    let data_dir = config.storage_dir()?;
+    std::fs::create_dir_all(&data_dir).context("Failed to create .mountains directory")?;
+    let mut db = DbManager::new_local_first(&data_dir).await?;
+    let existing = db.load_all_daily_logs().await?.len();
+    if existing > 0 {
+        bail!(
+            "{} already has {} day(s); point MOUNTAINS_DATA_DIR at an empty directory",
+            data_dir.display(),
+            existing
+        );
+    }
+
+    let logs = generate(years, chrono::Local::now().date_naive(), seed);
+    for (index, log) in logs.iter().enumerate() {
+        db.save_daily_log(log).await?;
+        if (index + 1) % 365 == 0 {
+            println!("{} of {} days", index + 1, logs.len());
+        }
+    }
+    println!("Generated {} days in {}", logs.len(), data_dir.display());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn miles_in(logs: &[DailyLog], month: u32) -> f32 {
+        logs.iter()
+            .filter(|log| log.date.month() == month)
+            .filter_map(|log| log.miles_covered)
+            .sum()
+    }
+
+    #[test]
+    fn generates_every_day_with_summer_busier_than_winter() {
+        let today = NaiveDate::from_ymd_opt(2026, 10, 16).unwrap();
+        let logs = generate(2, today, 7);
+        assert_eq!(logs.len(), 731);
+        assert_eq!(logs.last().unwrap().date, today);
+        assert!(miles_in(&logs, 7) > miles_in(&logs, 1) * 1.5);
+        assert!(logs.iter().all(|log| log.date.weekday() != Weekday::Mon
+            || log.miles_covered.is_none()));
+        assert!(
+            logs.iter()
+                .filter_map(|log| log.weight)
+                .all(|weight| (160.0..185.0).contains(&weight))
+        );
+        // The same seed makes the same log
+        let numbers = |logs: Vec<DailyLog>| -> Vec<_> {
+            logs.into_iter()
+                .map(|log| (log.miles_covered, log.elevation_gain, log.weight))
+                .collect()
+        };
+        let again = numbers(generate(2, today, 7));
+        assert_eq!(again, numbers(logs));
+        assert_ne!(numbers(generate(2, today, 8)), again);
+    }
+}
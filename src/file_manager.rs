@@ -1,4 +1,7 @@
 use crate::models::DailyLog;
+use crate::models::field_accessor::{format_duration, format_hours, format_time_of_day};
+use crate::format::Units;
+use crate::units::Quantity;
 use anyhow::{Context, Result};
 use chrono::NaiveDate;
 use std::fs;
@@ -7,18 +10,46 @@ use std::path::PathBuf;
 #[derive(Clone)]
 pub struct FileManager {
     mountains_dir: PathBuf,
+    units: Units,
 }
 
 impl FileManager {
-    pub fn new() -> Result<Self> {
-        let home_dir = dirs::home_dir().context("Could not find home directory")?;
-        let mountains_dir = home_dir.join(".mountains");
-
+    /// Writes the markdown logs into `mountains_dir`, normally the data
+    /// directory alongside the database.
+    pub fn in_dir(mountains_dir: PathBuf) -> Result<Self> {
         if !mountains_dir.exists() {
             fs::create_dir_all(&mountains_dir).context("Failed to create .mountains directory")?;
         }
 
-        Ok(Self { mountains_dir })
+        Ok(Self {
+            mountains_dir,
+            units: Units::default(),
+        })
+    }
+
+    /// Writes values in `units` instead of imperial. The markdown logs keep
+    /// to one system even when the screens show both.
+    pub fn with_units(mut self, units: Units) -> Self {
+        self.units = Units {
+            dual: false,
+            ..units
+        };
+        self
+    }
+
+    /// Where products looked up by barcode are kept.
+    pub fn barcode_cache_path(&self) -> PathBuf {
+        self.mountains_dir.join("barcodes.json")
+    }
+
+    /// Where this machine's timestamps for the startup health check go.
+    pub fn health_path(&self) -> PathBuf {
+        crate::health::path(&self.mountains_dir)
+    }
+
+    /// Where share cards and other generated files go.
+    pub fn exports_dir(&self) -> PathBuf {
+        self.mountains_dir.join("exports")
     }
 
     fn get_file_path(&self, date: NaiveDate) -> PathBuf {
@@ -34,21 +65,51 @@ impl FileManager {
         Ok(())
     }
 
-    fn daily_log_to_markdown(&self, log: &DailyLog) -> String {
+    /// The day as markdown, exactly as written to its backup file.
+    pub fn daily_log_to_markdown(&self, log: &DailyLog) -> String {
         let mut content = String::new();
 
         content.push_str(&format!(
             "# Mountains Training Log - {}\n\n",
-            log.date.format("%B %d, %Y")
+            self.units.date(log.date)
         ));
 
-        if log.weight.is_some() || log.waist.is_some() {
+        if let Some(intention) = &log.intention {
+            content.push_str(&format!("## Intention\n{}\n\n", intention));
+        }
+
+        if log.weight.is_some()
+            || log.waist.is_some()
+            || log.sleep_hours.is_some()
+            || log.resting_hr.is_some()
+            || log.hrv.is_some()
+            || log.energy.is_some()
+        {
             content.push_str("## Measurements\n");
             if let Some(weight) = log.weight {
-                content.push_str(&format!("- **Weight:** {} lbs\n", weight));
+                content.push_str(&format!(
+                    "- **Weight:** {}\n",
+                    self.units.format(Quantity::Weight, weight)
+                ));
             }
             if let Some(waist) = log.waist {
-                content.push_str(&format!("- **Waist:** {} inches\n", waist));
+                content.push_str(&format!(
+                    "- **Waist:** {} {}\n",
+                    self.units.amount(Quantity::Waist, waist),
+                    self.units.name(Quantity::Waist)
+                ));
+            }
+            if let Some(hours) = log.sleep_hours {
+                content.push_str(&format!("- **Sleep:** {} hours\n", format_hours(hours)));
+            }
+            if let Some(bpm) = log.resting_hr {
+                content.push_str(&format!("- **Resting HR:** {} bpm\n", bpm));
+            }
+            if let Some(ms) = log.hrv {
+                content.push_str(&format!("- **HRV:** {} ms\n", ms));
+            }
+            if let Some(energy) = log.energy {
+                content.push_str(&format!("- **Energy:** {}/5\n", energy));
             }
             content.push('\n');
         }
@@ -56,18 +117,89 @@ impl FileManager {
         if !log.food_entries.is_empty() {
             content.push_str("## Food\n");
             for entry in &log.food_entries {
-                content.push_str(&format!("- {}\n", entry.name));
+                content.push_str(&format!("- {}\n", entry.label()));
+            }
+            if let Some(calories) = log.calories() {
+                content.push_str(&format!("- **Calories:** {}\n", calories));
             }
             content.push('\n');
         }
 
-        if log.miles_covered.is_some() || log.elevation_gain.is_some() {
+        if let Some(water) = log.water {
+            content.push_str("## Hydration\n");
+            content.push_str(&format!(
+                "- **Water:** {}\n\n",
+                self.units.format(Quantity::Water, water)
+            ));
+        }
+
+        if log.miles_covered.is_some()
+            || log.elevation_gain.is_some()
+            || log.duration_secs.is_some()
+            || log.start_time.is_some()
+            || log.rpe.is_some()
+            || log.avg_hr.is_some()
+            || log.max_hr.is_some()
+            || log.activity.is_some()
+            || log.gear.is_some()
+            || log.route.is_some()
+            || log.weather.is_some()
+            || log.indoor
+            || log.rest_day
+        {
             content.push_str("## Running\n");
+            if let Some(activity) = log.activity {
+                content.push_str(&format!("- **Activity:** {}\n", activity.label()));
+            }
+            if let Some(route) = &log.route {
+                content.push_str(&format!("- **Route:** {}\n", route));
+            }
+            if log.rest_day {
+                content.push_str("- **Rest day:** yes\n");
+            }
+            if log.indoor {
+                content.push_str("- **Indoor:** yes\n");
+            }
             if let Some(miles) = log.miles_covered {
-                content.push_str(&format!("- **Miles:** {} mi\n", miles));
+                content.push_str(&format!(
+                    "- **{}:** {}\n",
+                    self.units.distance_title(),
+                    self.units.format(Quantity::Distance, miles)
+                ));
             }
             if let Some(elevation) = log.elevation_gain {
-                content.push_str(&format!("- **Elevation:** {} ft\n", elevation));
+                content.push_str(&format!(
+                    "- **Elevation:** {}\n",
+                    self.units.format(Quantity::Elevation, elevation as f32)
+                ));
+            }
+            if let Some(seconds) = log.duration_secs {
+                let pace = log
+                    .miles_covered
+                    .and_then(|miles| self.units.pace(seconds, miles))
+                    .map(|pace| format!(" ({})", pace))
+                    .unwrap_or_default();
+                content.push_str(&format!("- **Time:** {}{}\n", format_duration(seconds), pace));
+            }
+            if let Some(time) = log.start_time {
+                content.push_str(&format!("- **Started:** {}\n", format_time_of_day(time)));
+            }
+            if let Some(rpe) = log.rpe {
+                content.push_str(&format!("- **RPE:** {}/10\n", rpe));
+            }
+            match (log.avg_hr, log.max_hr) {
+                (Some(avg), Some(max)) => {
+                    content.push_str(&format!("- **Heart Rate:** {} avg / {} max bpm\n", avg, max));
+                }
+                (Some(avg), None) => content.push_str(&format!("- **Heart Rate:** {} avg bpm\n", avg)),
+                (None, Some(max)) => content.push_str(&format!("- **Heart Rate:** {} max bpm\n", max)),
+                (None, None) => {}
+            }
+            if let Some(gear) = &log.gear {
+                content.push_str(&format!("- **Gear:** {}\n", gear));
+            }
+            if let Some(weather) = log.weather {
+                content.push_str(&format!("- **Weather:** {}\n", weather.describe(self.units)));
             }
             content.push('\n');
         }
@@ -80,9 +212,9 @@ impl FileManager {
             content.push('\n');
         }
 
-        if let Some(strength_mobility) = &log.strength_mobility {
+        if let Some(strength_mobility) = log.strength_text(self.units) {
             content.push_str("## Strength & Mobility\n");
-            content.push_str(strength_mobility);
+            content.push_str(&strength_mobility);
             content.push('\n');
         }
 
@@ -92,6 +224,10 @@ impl FileManager {
             content.push('\n');
         }
 
+        if let Some(gratitude) = &log.gratitude {
+            content.push_str(&format!("\n## Gratitude\n{}\n", gratitude));
+        }
+
         content
     }
 
@@ -106,3 +242,62 @@ impl FileManager {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::UnitSystem;
+
+    #[test]
+    fn markdown_uses_the_configured_units() {
+        let dir = tempfile::tempdir().unwrap();
+        let log = DailyLog {
+            weight: Some(176.0),
+            waist: Some(34.0),
+            miles_covered: Some(10.0),
+            elevation_gain: Some(1000),
+            sleep_hours: Some(7.5),
+            resting_hr: Some(48),
+            duration_secs: Some(6000),
+            avg_hr: Some(146),
+            activity: Some(crate::models::activity::ActivityType::Hike),
+            gear: Some("Speedgoat 6".to_string()),
+            route: Some("Bear Peak loop".to_string()),
+            indoor: true,
+            weather: Some(crate::weather::Weather {
+                high: 86.0,
+                low: 59.0,
+                code: 61,
+            }),
+            ..DailyLog::new(NaiveDate::from_ymd_opt(2026, 7, 22).unwrap())
+        };
+
+        let imperial = FileManager::in_dir(dir.path().to_path_buf()).unwrap();
+        let markdown = imperial.daily_log_to_markdown(&log);
+        assert!(markdown.contains("- **Weight:** 176 lbs\n- **Waist:** 34 inches\n- **Sleep:** 7.5 hours\n- **Resting HR:** 48 bpm\n"));
+        assert!(markdown.contains("## Running\n- **Activity:** Hike\n- **Route:** Bear Peak loop\n- **Indoor:** yes\n- **Miles:** 10 mi\n- **Elevation:** 1,000 ft\n- **Time:** 1:40:00 (10:00 /mi)\n- **Heart Rate:** 146 avg bpm\n- **Gear:** Speedgoat 6\n- **Weather:** 86°F / 59°F, Rain\n"));
+
+        let metric = imperial.with_units(UnitSystem::Metric.into());
+        let markdown = metric.daily_log_to_markdown(&log);
+        assert!(markdown.contains("- **Weight:** 79.8 kg\n- **Waist:** 86.4 centimeters\n"));
+        assert!(markdown.contains("- **Kilometers:** 16.1 km\n- **Elevation:** 305 m\n- **Time:** 1:40:00 (6:13 /km)\n"));
+    }
+
+    #[test]
+    fn markdown_opens_with_the_intention_and_ends_with_gratitude() {
+        let dir = tempfile::tempdir().unwrap();
+        let log = DailyLog {
+            intention: Some("Easy on the downhills".to_string()),
+            gratitude: Some("Dry trails".to_string()),
+            notes: Some("Knees happy".to_string()),
+            ..DailyLog::new(NaiveDate::from_ymd_opt(2026, 7, 22).unwrap())
+        };
+        let markdown = FileManager::in_dir(dir.path().to_path_buf())
+            .unwrap()
+            .daily_log_to_markdown(&log);
+        assert!(markdown.starts_with(
+            "# Mountains Training Log - July 22, 2026\n\n## Intention\nEasy on the downhills\n\n"
+        ));
+        assert!(markdown.ends_with("## Notes\nKnees happy\n\n## Gratitude\nDry trails\n"));
+    }
+}
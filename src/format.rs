@@ -0,0 +1,374 @@
+//! How numbers and dates are shown on screen and in exports: the unit
+//! system, the rounding and the locale set under `[display]`. Renderers and
+//! exporters format values through `Units` rather than picking their own
+//! precision, separators or date order.
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::config::DisplayConfig;
+use crate::units::{Quantity, UnitSystem};
+
+/// Conventions for separators and date order. Month and day names stay in
+/// English; the locale only decides how numbers are grouped and which way
+/// round a date reads.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Locale {
+    /// 12,450.5 and October 16, 2026
+    #[default]
+    #[serde(rename = "en-US")]
+    EnUs,
+    /// 12,450.5 and 16 October 2026
+    #[serde(rename = "en-GB")]
+    EnGb,
+    /// 12.450,5 and 16.10.2026
+    #[serde(rename = "de-DE")]
+    DeDe,
+    /// 12 450,5 and 16/10/2026, grouped with a non-breaking space
+    #[serde(rename = "fr-FR")]
+    FrFr,
+}
+
+impl Locale {
+    /// The thousands separator and the decimal mark.
+    fn separators(self) -> (char, char) {
+        match self {
+            Locale::EnUs | Locale::EnGb => (',', '.'),
+            Locale::DeDe => ('.', ','),
+            Locale::FrFr => ('\u{a0}', ','),
+        }
+    }
+
+    /// Date formats: full, abbreviated, with the weekday, and without the
+    /// year.
+    fn date_formats(self) -> [&'static str; 4] {
+        match self {
+            Locale::EnUs => ["%B %d, %Y", "%b %d, %Y", "%A, %B %d, %Y", "%b %d"],
+            Locale::EnGb => ["%d %B %Y", "%d %b %Y", "%A, %d %B %Y", "%d %b"],
+            Locale::DeDe => ["%d.%m.%Y", "%d.%m.%Y", "%A, %d.%m.%Y", "%d.%m."],
+            Locale::FrFr => ["%d/%m/%Y", "%d/%m/%Y", "%A %d/%m/%Y", "%d/%m"],
+        }
+    }
+}
+
+/// Decimal places set in config. Unset keeps each quantity's usual
+/// precision.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Precision {
+    pub distance: Option<usize>,
+    pub weight: Option<usize>,
+}
+
+/// The units values are shown in, how they're rounded and how they read.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Units {
+    pub system: UnitSystem,
+    pub precision: Precision,
+    pub locale: Locale,
+    /// Values with their unit follow it with the other system's:
+    /// "6.2 mi / 10.0 km".
+    pub dual: bool,
+}
+
+impl From<UnitSystem> for Units {
+    fn from(system: UnitSystem) -> Self {
+        Self {
+            system,
+            ..Self::default()
+        }
+    }
+}
+
+impl Units {
+    pub fn from_config(display: &DisplayConfig) -> Self {
+        Self {
+            system: display.units,
+            precision: Precision {
+                distance: display.distance_decimals,
+                weight: display.weight_decimals,
+            },
+            locale: display.locale,
+            dual: display.dual_units,
+        }
+    }
+
+    /// " / 10.0 km" after a value when both systems are shown, at the other
+    /// system's usual precision; empty otherwise.
+    fn also(self, quantity: Quantity, stored: f64) -> String {
+        if !self.dual {
+            return String::new();
+        }
+        let other = Units {
+            system: self.system.other(),
+            locale: self.locale,
+            ..Units::default()
+        };
+        format!(" / {}", other.total(quantity, stored))
+    }
+
+    /// A plain number as printed by `format!`, like "-12450.5", grouped in
+    /// thousands and with the locale's decimal mark.
+    pub fn localize(self, number: &str) -> String {
+        let (group, decimal) = self.locale.separators();
+        let (sign, digits) = match number.strip_prefix('-') {
+            Some(digits) => ("-", digits),
+            None => ("", number),
+        };
+        let (whole, fraction) = match digits.split_once('.') {
+            Some((whole, fraction)) => (whole, Some(fraction)),
+            None => (digits, None),
+        };
+        let mut text = sign.to_string();
+        for (index, digit) in whole.chars().enumerate() {
+            if index > 0 && (whole.len() - index) % 3 == 0 {
+                text.push(group);
+            }
+            text.push(digit);
+        }
+        if let Some(fraction) = fraction {
+            text.push(decimal);
+            text.push_str(fraction);
+        }
+        text
+    }
+
+    /// A figure with no unit, like an average RPE or a ratio, at `decimals`
+    /// places: "6.5".
+    pub fn decimal(self, value: f64, decimals: usize) -> String {
+        self.localize(&format!("{:.*}", decimals, value))
+    }
+
+    /// A whole count, like a day's calories: "2,450".
+    pub fn count(self, value: i64) -> String {
+        self.localize(&value.to_string())
+    }
+
+    /// A date in full, for titles and exports: "October 16, 2026".
+    pub fn date(self, date: NaiveDate) -> String {
+        date.format(self.locale.date_formats()[0]).to_string()
+    }
+
+    /// A date abbreviated for lists: "Oct 16, 2026".
+    pub fn short_date(self, date: NaiveDate) -> String {
+        date.format(self.locale.date_formats()[1]).to_string()
+    }
+
+    /// A date with its weekday: "Friday, October 16, 2026".
+    pub fn weekday_date(self, date: NaiveDate) -> String {
+        date.format(self.locale.date_formats()[2]).to_string()
+    }
+
+    /// A date without its year, when the year is plain from context:
+    /// "Oct 16".
+    pub fn month_day(self, date: NaiveDate) -> String {
+        date.format(self.locale.date_formats()[3]).to_string()
+    }
+
+    /// A date as `short_date` has it, after its abbreviated weekday:
+    /// "Fri Oct 16, 2026".
+    pub fn short_weekday_date(self, date: NaiveDate) -> String {
+        format!("{} {}", date.format("%a"), self.short_date(date))
+    }
+
+    /// A date as `month_day` has it, after its abbreviated weekday, for
+    /// rows of days: "Fri Oct 16".
+    pub fn weekday_month_day(self, date: NaiveDate) -> String {
+        format!("{} {}", date.format("%a"), self.month_day(date))
+    }
+
+    /// Decimal places set for `quantity`, if any.
+    fn configured(self, quantity: Quantity) -> Option<usize> {
+        match quantity {
+            Quantity::Distance => self.precision.distance,
+            Quantity::Weight => self.precision.weight,
+            _ => None,
+        }
+    }
+
+    /// Decimal places for totals and averages of `quantity`: as set, or one
+    /// for distance and body measurements and none for elevation.
+    pub fn decimals(self, quantity: Quantity) -> usize {
+        self.configured(quantity).unwrap_or(match quantity {
+            Quantity::Elevation => 0,
+            Quantity::Water => 2,
+            Quantity::Distance | Quantity::Weight | Quantity::Waist => 1,
+        })
+    }
+
+    pub fn label(self, quantity: Quantity) -> &'static str {
+        self.system.label(quantity)
+    }
+
+    pub fn name(self, quantity: Quantity) -> &'static str {
+        self.system.name(quantity)
+    }
+
+    pub fn distance_title(self) -> &'static str {
+        self.system.distance_title()
+    }
+
+    pub fn convert(self, quantity: Quantity, stored: f64) -> f64 {
+        self.system.convert(quantity, stored)
+    }
+
+    pub fn parse(self, quantity: Quantity, text: &str) -> Option<f64> {
+        self.system.parse(quantity, text)
+    }
+
+    pub fn pace(self, seconds: u32, miles: f32) -> Option<String> {
+        self.system.pace(seconds, miles)
+    }
+
+    pub fn vert_threshold(self, feet: i32) -> String {
+        format!("{}+", self.localize(&self.system.amount(Quantity::Elevation, feet as f32)))
+    }
+
+    /// The exact number for a stored value, as it's put in an input to edit.
+    /// Never rounded to the configured precision, so editing doesn't lose
+    /// digits.
+    pub fn amount(self, quantity: Quantity, stored: f32) -> String {
+        self.system.amount(quantity, stored)
+    }
+
+    /// A single logged value with its unit, e.g. "5.3 mi". With a precision
+    /// set it's rounded to it, trailing zeros dropped like a typed value.
+    /// Both systems show when `dual` is set.
+    pub fn format(self, quantity: Quantity, stored: f32) -> String {
+        let text = match self.configured(quantity) {
+            Some(decimals) => {
+                let text = format!("{:.*}", decimals, self.convert(quantity, stored as f64));
+                if text.contains('.') {
+                    text.trim_end_matches('0').trim_end_matches('.').to_string()
+                } else {
+                    text
+                }
+            }
+            None => self.system.amount(quantity, stored),
+        };
+        format!(
+            "{} {}{}",
+            self.localize(&text),
+            self.label(quantity),
+            self.also(quantity, stored as f64)
+        )
+    }
+
+    /// A total or average without its unit, at the quantity's precision:
+    /// "312.4".
+    pub fn number(self, quantity: Quantity, stored: f64) -> String {
+        self.localize(&format!(
+            "{:.*}",
+            self.decimals(quantity),
+            self.convert(quantity, stored)
+        ))
+    }
+
+    /// A total or average with its unit: "312.4 mi" or "12,450 ft", and
+    /// "312.4 mi / 502.8 km" with both systems shown.
+    pub fn total(self, quantity: Quantity, stored: f64) -> String {
+        format!(
+            "{} {}{}",
+            self.number(quantity, stored),
+            self.label(quantity),
+            self.also(quantity, stored)
+        )
+    }
+
+    /// A stored total with a fixed number of decimals, for columns too
+    /// narrow for the configured precision. One system only, even with both
+    /// shown elsewhere.
+    pub fn fixed(self, quantity: Quantity, stored: f64, decimals: usize) -> String {
+        format!(
+            "{} {}",
+            self.localize(&format!("{:.*}", decimals, self.convert(quantity, stored))),
+            self.label(quantity)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn totals_follow_the_configured_precision() {
+        let units = Units::from(UnitSystem::Imperial);
+        assert_eq!(units.total(Quantity::Distance, 312.44), "312.4 mi");
+        assert_eq!(units.total(Quantity::Elevation, 12450.0), "12,450 ft");
+        assert_eq!(units.format(Quantity::Distance, 5.25), "5.25 mi");
+
+        let rounded = Units {
+            system: UnitSystem::Metric,
+            precision: Precision {
+                distance: Some(2),
+                weight: Some(0),
+            },
+            locale: Locale::EnUs,
+            dual: false,
+        };
+        assert_eq!(rounded.total(Quantity::Distance, 10.0), "16.09 km");
+        assert_eq!(rounded.format(Quantity::Distance, 6.2137119), "10 km");
+        assert_eq!(rounded.format(Quantity::Weight, 175.5), "80 kg");
+        // Inputs keep every digit
+        assert_eq!(rounded.amount(Quantity::Weight, 175.5), "79.6");
+    }
+
+    #[test]
+    fn locales_group_numbers_and_order_dates() {
+        let us = Units::from(UnitSystem::Imperial);
+        assert_eq!(us.total(Quantity::Elevation, 1234567.0), "1,234,567 ft");
+        assert_eq!(us.format(Quantity::Elevation, 950.0), "950 ft");
+        assert_eq!(us.localize("-4100.25"), "-4,100.25");
+        assert_eq!(us.count(2450), "2,450");
+        assert_eq!(us.decimal(1234.56, 1), "1,234.6");
+        assert_eq!(us.vert_threshold(1000), "1,000+");
+
+        let date = NaiveDate::from_ymd_opt(2026, 10, 6).unwrap();
+        assert_eq!(us.date(date), "October 06, 2026");
+        assert_eq!(us.weekday_date(date), "Tuesday, October 06, 2026");
+
+        let de = Units {
+            locale: Locale::DeDe,
+            ..us
+        };
+        assert_eq!(de.total(Quantity::Distance, 1312.44), "1.312,4 mi");
+        assert_eq!(de.decimal(1.25, 1), "1,2");
+        assert_eq!(de.short_date(date), "06.10.2026");
+        assert_eq!(de.month_day(date), "06.10.");
+        assert_eq!(de.weekday_month_day(date), "Tue 06.10.");
+
+        let fr = Units {
+            locale: Locale::FrFr,
+            ..us
+        };
+        assert_eq!(fr.total(Quantity::Elevation, 12450.0), "12\u{a0}450 ft");
+        assert_eq!(fr.date(date), "06/10/2026");
+
+        let gb = Units {
+            locale: Locale::EnGb,
+            ..us
+        };
+        assert_eq!(gb.date(date), "06 October 2026");
+        assert_eq!(gb.month_day(date), "06 Oct");
+        assert_eq!(gb.short_weekday_date(date), "Tue 06 Oct 2026");
+    }
+
+    #[test]
+    fn dual_units_follow_each_value_with_the_other_system() {
+        let dual = Units {
+            dual: true,
+            ..Units::from(UnitSystem::Imperial)
+        };
+        assert_eq!(dual.format(Quantity::Distance, 6.2), "6.2 mi / 10.0 km");
+        assert_eq!(dual.total(Quantity::Elevation, 1800.0), "1,800 ft / 549 m");
+        // Inputs and bare numbers stay in one system
+        assert_eq!(dual.amount(Quantity::Distance, 6.2), "6.2");
+        assert_eq!(dual.number(Quantity::Distance, 6.2), "6.2");
+
+        let metric = Units {
+            system: UnitSystem::Metric,
+            ..dual
+        };
+        assert_eq!(metric.format(Quantity::Weight, 176.0), "79.8 kg / 176.0 lbs");
+    }
+}
@@ -0,0 +1,126 @@
+//! Shoes and other gear: each day's run can be put on one, and its distance
+//! adds up on that gear until it's time to retire it.
+
+use chrono::NaiveDate;
+
+use crate::models::DailyLog;
+
+/// Within this share of `[gear] retire_after`, gear is flagged to retire
+/// soon.
+const RETIRE_SOON_SHARE: f32 = 0.9;
+
+/// A pair of shoes (or poles, or a bike), stored in the `gear` table under
+/// its name.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gear {
+    pub name: String,
+    /// Retired gear keeps its history but isn't offered for new runs.
+    pub retired: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GearStatus {
+    Fine,
+    /// Close to the configured distance.
+    RetireSoon,
+    /// At or past it.
+    Worn,
+}
+
+/// What's been put on one piece of gear.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GearMileage {
+    pub name: String,
+    pub retired: bool,
+    pub miles: f32,
+    pub days: usize,
+    pub last_used: Option<NaiveDate>,
+}
+
+impl GearMileage {
+    /// How worn it is against `retire_after` miles; 0 never warns.
+    pub fn status(&self, retire_after: f32) -> GearStatus {
+        if retire_after <= 0.0 || self.retired {
+            GearStatus::Fine
+        } else if self.miles >= retire_after {
+            GearStatus::Worn
+        } else if self.miles >= retire_after * RETIRE_SOON_SHARE {
+            GearStatus::RetireSoon
+        } else {
+            GearStatus::Fine
+        }
+    }
+}
+
+/// Distance, days and last use for each piece of gear, active gear first
+/// and otherwise in the order given.
+pub fn mileage(gear: &[Gear], logs: &[DailyLog]) -> Vec<GearMileage> {
+    let mut totals: Vec<GearMileage> = gear
+        .iter()
+        .map(|g| {
+            let used: Vec<&DailyLog> = logs
+                .iter()
+                .filter(|log| log.gear.as_deref() == Some(g.name.as_str()))
+                .collect();
+            GearMileage {
+                name: g.name.clone(),
+                retired: g.retired,
+                miles: used.iter().filter_map(|log| log.miles_covered).sum(),
+                days: used.len(),
+                last_used: used.iter().map(|log| log.date).max(),
+            }
+        })
+        .collect();
+    totals.sort_by_key(|g| g.retired);
+    totals
+}
+
+/// Distance on the gear named `name`, for the daily view's label.
+pub fn miles_on(name: &str, logs: &[DailyLog]) -> f32 {
+    logs.iter()
+        .filter(|log| log.gear.as_deref() == Some(name))
+        .filter_map(|log| log.miles_covered)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(d: u32, gear: &str, miles: f32) -> DailyLog {
+        DailyLog {
+            gear: Some(gear.to_string()),
+            miles_covered: Some(miles),
+            ..DailyLog::new(NaiveDate::from_ymd_opt(2026, 7, d).unwrap())
+        }
+    }
+
+    #[test]
+    fn adds_up_each_pair_and_warns_near_the_limit() {
+        let gear = vec![
+            Gear {
+                name: "Old Speedgoats".to_string(),
+                retired: true,
+            },
+            Gear {
+                name: "Speedgoat 6".to_string(),
+                retired: false,
+            },
+        ];
+        let logs = vec![
+            run(3, "Speedgoat 6", 200.0),
+            run(2, "Old Speedgoats", 50.0),
+            run(1, "Speedgoat 6", 170.0),
+        ];
+        let totals = mileage(&gear, &logs);
+        assert_eq!(totals[0].name, "Speedgoat 6");
+        assert_eq!((totals[0].miles, totals[0].days), (370.0, 2));
+        assert_eq!(totals[0].last_used, NaiveDate::from_ymd_opt(2026, 7, 3));
+        assert_eq!(totals[0].status(400.0), GearStatus::RetireSoon);
+        assert_eq!(totals[0].status(350.0), GearStatus::Worn);
+        assert_eq!(totals[0].status(0.0), GearStatus::Fine);
+        // Retired gear has done its job
+        assert_eq!(totals[1].status(10.0), GearStatus::Fine);
+        assert_eq!(miles_on("Old Speedgoats", &logs), 50.0);
+    }
+}
@@ -0,0 +1,460 @@
+//! Progress toward training goals, the ones set in `[goals]` and the ones
+//! kept in the `goals` table from the goals screen, and where the current
+//! pace would finish the period.
+
+use chrono::{Datelike, Months, NaiveDate, Weekday};
+
+use crate::format::Units;
+use crate::models::DailyLog;
+use crate::units::Quantity;
+
+/// How far along a goal is in its period, in stored units.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Progress {
+    pub done: f64,
+    pub target: f64,
+    /// Days of the period through today; all of them once it's over, none
+    /// before it starts.
+    pub elapsed_days: u32,
+    pub period_days: u32,
+}
+
+impl Progress {
+    /// Progress over the period from `start` running `period_days` days, as
+    /// of `today`.
+    pub fn new(
+        done: f64,
+        target: f64,
+        start: NaiveDate,
+        period_days: u32,
+        today: NaiveDate,
+    ) -> Self {
+        let elapsed = (today - start).num_days() + 1;
+        Self {
+            done,
+            target,
+            elapsed_days: elapsed.clamp(0, period_days as i64) as u32,
+            period_days,
+        }
+    }
+
+    /// Share of the target done, capped at 1.
+    pub fn ratio(&self) -> f64 {
+        if self.target <= 0.0 {
+            return 1.0;
+        }
+        (self.done / self.target).clamp(0.0, 1.0)
+    }
+
+    pub fn remaining_days(&self) -> u32 {
+        self.period_days - self.elapsed_days
+    }
+
+    /// Where the period ends up at the average so far.
+    pub fn projected(&self) -> f64 {
+        if self.elapsed_days == 0 {
+            return 0.0;
+        }
+        self.done / self.elapsed_days as f64 * self.period_days as f64
+    }
+
+    pub fn met(&self) -> bool {
+        self.done >= self.target
+    }
+
+    /// Met, or projected to be.
+    pub fn on_pace(&self) -> bool {
+        self.met() || self.projected() >= self.target
+    }
+
+    /// One line on how the goal stands: met, missed, on pace, or what each
+    /// remaining day needs to catch up.
+    pub fn projection(&self, units: Units, quantity: Quantity) -> String {
+        if self.met() {
+            return match self.remaining_days() {
+                0 => format!("Goal met: {}", units.total(quantity, self.done)),
+                _ => format!("Goal met, on pace for {}", units.total(quantity, self.projected())),
+            };
+        }
+        if self.elapsed_days == 0 {
+            return "Not started yet".to_string();
+        }
+        let short = units.total(quantity, self.target - self.done);
+        match self.remaining_days() {
+            0 => format!("Missed by {}", short),
+            _ if self.on_pace() => format!("On pace for {}", units.total(quantity, self.projected())),
+            remaining => format!(
+                "Behind pace: {} a day over the last {} {} makes it",
+                units.total(quantity, (self.target - self.done) / remaining as f64),
+                remaining,
+                if remaining == 1 { "day" } else { "days" }
+            ),
+        }
+    }
+}
+
+/// What a goal on the goals screen tracks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GoalKind {
+    /// Miles each week, at least.
+    WeeklyMiles,
+    /// Feet of vert each month, at least.
+    MonthlyVert,
+    /// A body weight to get to, up or down.
+    Weight,
+    /// Sokay entries each week, at most.
+    SokayLimit,
+}
+
+impl GoalKind {
+    pub const ALL: [GoalKind; 4] = [
+        GoalKind::WeeklyMiles,
+        GoalKind::MonthlyVert,
+        GoalKind::Weight,
+        GoalKind::SokayLimit,
+    ];
+
+    /// How the kind is stored in the `goals` table.
+    pub fn key(self) -> &'static str {
+        match self {
+            GoalKind::WeeklyMiles => "weekly_miles",
+            GoalKind::MonthlyVert => "monthly_vert",
+            GoalKind::Weight => "weight",
+            GoalKind::SokayLimit => "sokay_limit",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.key() == key)
+    }
+
+    pub fn title(self) -> &'static str {
+        match self {
+            GoalKind::WeeklyMiles => "Weekly distance",
+            GoalKind::MonthlyVert => "Monthly vert",
+            GoalKind::Weight => "Target weight",
+            GoalKind::SokayLimit => "Sokay limit",
+        }
+    }
+
+    /// What the target measures; none for a plain count.
+    pub fn quantity(self) -> Option<Quantity> {
+        match self {
+            GoalKind::WeeklyMiles => Some(Quantity::Distance),
+            GoalKind::MonthlyVert => Some(Quantity::Elevation),
+            GoalKind::Weight => Some(Quantity::Weight),
+            GoalKind::SokayLimit => None,
+        }
+    }
+
+    /// The next kind, for cycling through them while adding a goal.
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|&kind| kind == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+/// The goal being added or edited on the goals screen; its target is typed
+/// into the input buffer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GoalDraft {
+    /// The goal being changed, or none for a new one.
+    pub editing: Option<i64>,
+    pub kind: GoalKind,
+}
+
+/// A goal from the goals screen, stored in the `goals` table. The target is
+/// in stored units: miles, feet, lbs or a count.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Goal {
+    pub id: i64,
+    pub kind: GoalKind,
+    pub target: f64,
+    /// When it was set, so a weight goal measures from the weight then.
+    pub since: NaiveDate,
+    /// Paused goals keep their target but aren't tracked.
+    pub active: bool,
+}
+
+/// Where a goal stands today.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Standing {
+    /// How full its progress bar is.
+    pub ratio: f64,
+    /// On pace, moving the right way, or under the limit.
+    pub on_track: bool,
+    /// "23.0 / 40.0 mi - On pace for 42.0 mi"
+    pub detail: String,
+}
+
+impl Goal {
+    /// The target as it reads in lists: "40.0 mi a week", "170 lbs".
+    pub fn label(&self, units: Units) -> String {
+        match self.kind {
+            GoalKind::WeeklyMiles => {
+                format!("{} a week", units.total(Quantity::Distance, self.target))
+            }
+            GoalKind::MonthlyVert => {
+                format!("{} a month", units.total(Quantity::Elevation, self.target))
+            }
+            GoalKind::Weight => units.format(Quantity::Weight, self.target as f32),
+            GoalKind::SokayLimit => format!("At most {} sokay a week", self.target as u32),
+        }
+    }
+
+    pub fn standing(
+        &self,
+        logs: &[DailyLog],
+        today: NaiveDate,
+        week_start: Weekday,
+        units: Units,
+    ) -> Standing {
+        let week = today.week(week_start).first_day();
+        let this_week = |log: &&DailyLog| log.date >= week && log.date <= today;
+        match self.kind {
+            GoalKind::WeeklyMiles => {
+                let miles = logs
+                    .iter()
+                    .filter(this_week)
+                    .filter_map(|log| log.miles_covered)
+                    .fold(0.0, |total, miles| total + miles as f64);
+                let progress = Progress::new(miles, self.target, week, 7, today);
+                paced(&progress, units, Quantity::Distance)
+            }
+            GoalKind::MonthlyVert => {
+                let month = today.with_day(1).unwrap_or(today);
+                let days = month
+                    .checked_add_months(Months::new(1))
+                    .map_or(30, |next| (next - month).num_days() as u32);
+                let feet = logs
+                    .iter()
+                    .filter(|log| log.date >= month && log.date <= today)
+                    .filter_map(|log| log.elevation_gain)
+                    .fold(0.0, |total, feet| total + feet as f64);
+                let progress = Progress::new(feet, self.target, month, days, today);
+                paced(&progress, units, Quantity::Elevation)
+            }
+            GoalKind::Weight => self.weight_standing(logs, today, units),
+            GoalKind::SokayLimit => {
+                let count: usize = logs
+                    .iter()
+                    .filter(this_week)
+                    .map(|log| log.sokay_entries.len())
+                    .sum();
+                let limit = self.target as usize;
+                Standing {
+                    ratio: if limit == 0 {
+                        if count == 0 { 0.0 } else { 1.0 }
+                    } else {
+                        (count as f64 / limit as f64).min(1.0)
+                    },
+                    on_track: count <= limit,
+                    detail: if count > limit {
+                        format!("{} of {} this week - over the limit", count, limit)
+                    } else {
+                        format!("{} of {} this week", count, limit)
+                    },
+                }
+            }
+        }
+    }
+
+    /// Weight measured from where it was when the goal was set (or the
+    /// first weigh-in after) to the latest weigh-in.
+    fn weight_standing(&self, logs: &[DailyLog], today: NaiveDate, units: Units) -> Standing {
+        let mut weighed: Vec<(NaiveDate, f64)> = logs
+            .iter()
+            .filter(|log| log.date <= today)
+            .filter_map(|log| Some((log.date, log.weight? as f64)))
+            .collect();
+        weighed.sort_by_key(|&(date, _)| date);
+        let start = weighed
+            .iter()
+            .rev()
+            .find(|&&(date, _)| date <= self.since)
+            .or_else(|| weighed.first());
+        let (Some(&(_, start)), Some(&(_, current))) = (start, weighed.last()) else {
+            return Standing {
+                ratio: 0.0,
+                on_track: false,
+                detail: "No weight logged yet".to_string(),
+            };
+        };
+        let to_go = (current - self.target).abs();
+        let reached = (start >= self.target && current <= self.target)
+            || (start <= self.target && current >= self.target);
+        let ratio = if reached {
+            1.0
+        } else {
+            ((start - current) / (start - self.target)).clamp(0.0, 1.0)
+        };
+        Standing {
+            ratio,
+            on_track: reached || ratio > 0.0,
+            detail: if reached {
+                format!("Reached at {}", units.format(Quantity::Weight, current as f32))
+            } else {
+                format!(
+                    "{}, {} to go",
+                    units.format(Quantity::Weight, current as f32),
+                    units.total(Quantity::Weight, to_go)
+                )
+            },
+        }
+    }
+}
+
+fn paced(progress: &Progress, units: Units, quantity: Quantity) -> Standing {
+    Standing {
+        ratio: progress.ratio(),
+        on_track: progress.on_pace(),
+        detail: format!(
+            "{} / {} - {}",
+            units.number(quantity, progress.done),
+            units.total(quantity, progress.target),
+            progress.projection(units, quantity)
+        ),
+    }
+}
+
+/// The startup screen's line on the active goals, or none without any:
+/// "Goals: 2 of 3 on track, behind on 30,000 ft a month". True with it when
+/// every one is on track.
+pub fn summary(
+    goals: &[Goal],
+    logs: &[DailyLog],
+    today: NaiveDate,
+    week_start: Weekday,
+    units: Units,
+) -> Option<(String, bool)> {
+    let active: Vec<&Goal> = goals.iter().filter(|goal| goal.active).collect();
+    if active.is_empty() {
+        return None;
+    }
+    let behind: Vec<String> = active
+        .iter()
+        .filter(|goal| !goal.standing(logs, today, week_start, units).on_track)
+        .map(|goal| goal.label(units))
+        .collect();
+    let on_track = active.len() - behind.len();
+    if behind.is_empty() {
+        return Some((format!("Goals: all {} on track", active.len()), true));
+    }
+    Some((
+        format!(
+            "Goals: {} of {} on track, behind on {}",
+            on_track,
+            active.len(),
+            behind.join(", ")
+        ),
+        false,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::UnitSystem;
+
+    #[test]
+    fn projects_the_week_from_the_pace_so_far() {
+        let start = NaiveDate::from_ymd_opt(2026, 7, 20).unwrap();
+        let day = |d| NaiveDate::from_ymd_opt(2026, 7, d).unwrap();
+        let units = Units::from(UnitSystem::Imperial);
+
+        // 18 miles through Wednesday paces 42 for the week
+        let progress = Progress::new(18.0, 40.0, start, 7, day(22));
+        assert_eq!((progress.elapsed_days, progress.remaining_days()), (3, 4));
+        assert!((progress.ratio() - 0.45).abs() < 1e-9);
+        assert_eq!(progress.projection(units, Quantity::Distance), "On pace for 42.0 mi");
+
+        let behind = Progress::new(12.0, 40.0, start, 7, day(22));
+        assert_eq!(
+            behind.projection(units, Quantity::Distance),
+            "Behind pace: 7.0 mi a day over the last 4 days makes it"
+        );
+
+        let over = Progress::new(36.5, 40.0, start, 7, day(30));
+        assert_eq!(over.projection(units, Quantity::Distance), "Missed by 3.5 mi");
+        let met = Progress::new(41.0, 40.0, start, 7, day(24));
+        assert_eq!(met.ratio(), 1.0);
+        assert_eq!(
+            met.projection(units, Quantity::Distance),
+            "Goal met, on pace for 57.4 mi"
+        );
+        let done = Progress::new(41.0, 40.0, start, 7, day(26));
+        assert_eq!(done.projection(units, Quantity::Distance), "Goal met: 41.0 mi");
+        let ahead = Progress::new(0.0, 40.0, start, 7, day(19));
+        assert_eq!(ahead.projection(units, Quantity::Distance), "Not started yet");
+    }
+
+    fn log(d: u32) -> DailyLog {
+        DailyLog::new(NaiveDate::from_ymd_opt(2026, 7, d).unwrap())
+    }
+
+    fn goal(kind: GoalKind, target: f64) -> Goal {
+        Goal {
+            id: 1,
+            kind,
+            target,
+            since: NaiveDate::from_ymd_opt(2026, 7, 1).unwrap(),
+            active: true,
+        }
+    }
+
+    #[test]
+    fn goals_stand_by_pace_direction_or_limit() {
+        let today = NaiveDate::from_ymd_opt(2026, 7, 22).unwrap();
+        let units = Units::from(UnitSystem::Imperial);
+        let logs = vec![
+            DailyLog {
+                weight: Some(180.0),
+                miles_covered: Some(30.0),
+                ..log(1)
+            },
+            DailyLog {
+                miles_covered: Some(18.0),
+                elevation_gain: Some(6000),
+                sokay_entries: vec![Default::default(); 2],
+                ..log(21)
+            },
+            DailyLog {
+                weight: Some(176.0),
+                sokay_entries: vec![Default::default(); 2],
+                ..log(22)
+            },
+        ];
+        let stand = |goal: &Goal| goal.standing(&logs, today, Weekday::Mon, units);
+
+        let weekly = stand(&goal(GoalKind::WeeklyMiles, 40.0));
+        assert_eq!(weekly.detail, "18.0 / 40.0 mi - On pace for 42.0 mi");
+        assert!(weekly.on_track);
+
+        let vert = stand(&goal(GoalKind::MonthlyVert, 20_000.0));
+        assert!(!vert.on_track && (vert.ratio - 0.3).abs() < 1e-9);
+
+        let weight = stand(&goal(GoalKind::Weight, 170.0));
+        assert_eq!(weight.detail, "176 lbs, 6.0 lbs to go");
+        assert!(weight.on_track && (weight.ratio - 0.4).abs() < 1e-9);
+
+        let sokay = stand(&goal(GoalKind::SokayLimit, 3.0));
+        assert_eq!(sokay.detail, "4 of 3 this week - over the limit");
+        assert!(!sokay.on_track);
+
+        let goals = vec![
+            goal(GoalKind::WeeklyMiles, 40.0),
+            goal(GoalKind::MonthlyVert, 20_000.0),
+            Goal {
+                active: false,
+                ..goal(GoalKind::SokayLimit, 3.0)
+            },
+        ];
+        assert_eq!(
+            summary(&goals, &logs, today, Weekday::Mon, units),
+            Some(("Goals: 1 of 2 on track, behind on 20,000 ft a month".to_string(), false))
+        );
+        assert_eq!(summary(&goals[2..], &logs, today, Weekday::Mon, units), None);
+        assert_eq!(GoalKind::from_key("sokay_limit"), Some(GoalKind::SokayLimit));
+        assert_eq!(GoalKind::SokayLimit.next(), GoalKind::WeeklyMiles);
+    }
+}
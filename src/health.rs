@@ -0,0 +1,229 @@
+//! The startup data health check: warns when the cloud copy or the markdown
+//! backups have fallen behind, going by timestamps kept in `health.json` in
+//! the data directory. They describe this machine, so they stay out of the
+//! database, which syncs.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::config::AppConfig;
+
+/// What the check knows about the data on this machine.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Timestamps {
+    pub last_sync: Option<DateTime<Utc>>,
+    pub last_save: Option<DateTime<Utc>>,
+    pub last_backup: Option<DateTime<Utc>>,
+    /// When a shutdown sync was last skipped or timed out; cleared once the
+    /// next startup has said so.
+    pub skipped_sync: Option<DateTime<Utc>>,
+    /// When this machine was first seen with sync set up, so a sync that
+    /// hasn't had its chance yet isn't taken for one that keeps failing.
+    pub sync_set_up: Option<DateTime<Utc>>,
+}
+
+/// Saves and syncs finish on their own tasks; one update at a time keeps
+/// them from writing over each other's timestamps.
+static UPDATING: Mutex<()> = Mutex::new(());
+
+/// Where the timestamps are kept for the data directory `dir`.
+pub fn path(dir: &Path) -> PathBuf {
+    dir.join("health.json")
+}
+
+impl Timestamps {
+    /// A missing or unreadable file knows nothing yet.
+    pub fn load(path: &Path) -> Self {
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default()
+    }
+
+    /// Changes the timestamps in the file at `path`.
+    pub fn update(path: &Path, change: impl FnOnce(&mut Timestamps)) -> Result<()> {
+        let _updating = UPDATING.lock().unwrap_or_else(|e| e.into_inner());
+        let mut times = Self::load(path);
+        change(&mut times);
+        let json = serde_json::to_string_pretty(&times)?;
+        fs::write(path, json).context("Failed to write health.json")
+    }
+}
+
+/// Problems worth a banner: no sync for `stale_after_days` when sync is set
+/// up, or that many days of saves without a markdown backup. A sync that
+/// never finished counts from when sync was set up here, or as stale if that
+/// isn't known; otherwise missing timestamps only count against backups, so a
+/// fresh install without sync starts quiet.
+pub fn warnings(
+    times: &Timestamps,
+    sync_configured: bool,
+    stale_after_days: u32,
+    now: DateTime<Utc>,
+) -> Vec<String> {
+    if stale_after_days == 0 {
+        return Vec::new();
+    }
+    let stale = chrono::Duration::days(i64::from(stale_after_days));
+    let mut warnings = Vec::new();
+
+    if sync_configured {
+        match times.last_sync {
+            Some(last_sync) if now - last_sync < stale => {}
+            Some(last_sync) => warnings.push(format!(
+                "Last cloud sync was {} days ago; check your connection and [sync] settings",
+                (now - last_sync).num_days()
+            )),
+            None => match times.sync_set_up {
+                Some(set_up) if now - set_up < stale => {}
+                Some(set_up) => warnings.push(format!(
+                    "Cloud sync hasn't finished on this machine in {} days; check your \
+                     connection and [sync] settings",
+                    (now - set_up).num_days()
+                )),
+                None => warnings.push(
+                    "Cloud sync hasn't finished on this machine; check your connection and \
+                     [sync] settings"
+                        .to_string(),
+                ),
+            },
+        }
+    }
+
+    if let Some(last_save) = times.last_save {
+        match times.last_backup {
+            Some(last_backup) if last_save - last_backup < stale => {}
+            Some(last_backup) => warnings.push(format!(
+                "Markdown backups haven't been written since {}; check the data directory",
+                last_backup.with_timezone(&chrono::Local).format("%B %-d")
+            )),
+            None if now - last_save >= stale => {
+                warnings.push("Markdown backups have never been written".to_string())
+            }
+            None => {}
+        }
+    }
+    warnings
+}
+
+/// Records that the sync on the way out didn't finish.
+pub fn record_skipped_sync(path: &Path) -> Result<()> {
+    Timestamps::update(path, |times| times.skipped_sync = Some(Utc::now()))
+}
+
+/// A notice for a shutdown sync that was skipped last time, cleared as it's
+/// read so it only shows once.
+pub fn take_skipped_sync(path: &Path) -> Option<String> {
+    let skipped = Timestamps::load(path).skipped_sync?;
+    let _ = Timestamps::update(path, |times| times.skipped_sync = None);
+    Some(format!(
+        "The sync was skipped when you quit on {}; your changes go up with the next sync",
+        skipped.with_timezone(&chrono::Local).format("%B %-d at %-I:%M %p")
+    ))
+}
+
+/// The banner text for the startup screen, if anything needs attention.
+/// It's worked out before the startup sync runs, so the first launch with
+/// sync set up notes when that was rather than warning about a sync that
+/// hasn't been tried yet.
+pub fn banner(path: &Path, config: &AppConfig) -> Option<String> {
+    let mut times = Timestamps::load(path);
+    let now = Utc::now();
+    let sync_configured = config.sync.is_configured();
+    if sync_configured && times.last_sync.is_none() && times.sync_set_up.is_none() {
+        times.sync_set_up = Some(now);
+        let _ = Timestamps::update(path, |times| times.sync_set_up = Some(now));
+    }
+    let warnings = warnings(&times, sync_configured, config.health.stale_after_days, now);
+    (!warnings.is_empty()).then(|| warnings.join(" | "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn warns_about_stale_sync_and_backups_only() {
+        let now = Utc::now();
+        let days_ago = |days| Some(now - chrono::Duration::days(days));
+
+        let fresh = Timestamps {
+            last_sync: days_ago(1),
+            last_save: days_ago(0),
+            last_backup: days_ago(0),
+            ..Timestamps::default()
+        };
+        assert!(warnings(&fresh, true, 7, now).is_empty());
+        assert!(warnings(&Timestamps::default(), false, 7, now).is_empty());
+        // Sync set up but never finished here is as stale as it gets
+        let never = warnings(&Timestamps::default(), true, 7, now);
+        assert_eq!(never.len(), 1);
+        assert!(never[0].starts_with("Cloud sync hasn't finished on this machine"));
+        // Unless it was only set up lately and hasn't had its chance
+        let set_up = |days| Timestamps { sync_set_up: days_ago(days), ..Timestamps::default() };
+        assert!(warnings(&set_up(2), true, 7, now).is_empty());
+        let waiting = warnings(&set_up(9), true, 7, now);
+        assert!(waiting[0].starts_with("Cloud sync hasn't finished on this machine in 9 days"));
+
+        let stale = Timestamps {
+            last_sync: days_ago(12),
+            last_save: days_ago(0),
+            last_backup: days_ago(9),
+            ..Timestamps::default()
+        };
+        let found = warnings(&stale, true, 7, now);
+        assert_eq!(found.len(), 2);
+        assert!(found[0].starts_with("Last cloud sync was 12 days ago"));
+        assert!(found[1].starts_with("Markdown backups haven't been written"));
+
+        // Sync age only matters when sync is set up, and 0 turns it all off
+        assert_eq!(warnings(&stale, false, 7, now).len(), 1);
+        assert!(warnings(&stale, true, 0, now).is_empty());
+    }
+
+    #[test]
+    fn timestamps_live_in_a_file_and_a_skipped_sync_is_told_once() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = path(dir.path());
+        assert!(Timestamps::load(&path).last_save.is_none());
+
+        let now = Utc::now();
+        Timestamps::update(&path, |times| times.last_save = Some(now)).unwrap();
+        Timestamps::update(&path, |times| times.last_backup = Some(now)).unwrap();
+        let times = Timestamps::load(&path);
+        assert_eq!((times.last_save, times.last_backup), (Some(now), Some(now)));
+
+        record_skipped_sync(&path).unwrap();
+        assert!(take_skipped_sync(&path).unwrap().starts_with("The sync was skipped"));
+        assert_eq!(take_skipped_sync(&path), None);
+        assert_eq!(Timestamps::load(&path).last_save, Some(now));
+    }
+
+    #[test]
+    fn first_launch_with_sync_set_up_starts_quiet() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = path(dir.path());
+        let mut config = AppConfig::default();
+        config.sync.enabled = true;
+        config.sync.db_url = "libsql://test.turso.io".into();
+        config.sync.auth_token = "token123".into();
+
+        // No health.json yet, as on the first launch after an upgrade
+        assert_eq!(banner(&path, &config), None);
+        let set_up = Timestamps::load(&path).sync_set_up.unwrap();
+        // Later launches keep the first time rather than pushing it back
+        assert_eq!(banner(&path, &config), None);
+        assert_eq!(Timestamps::load(&path).sync_set_up, Some(set_up));
+
+        let mut times = Timestamps::load(&path);
+        times.sync_set_up = Some(set_up - chrono::Duration::days(30));
+        fs::write(&path, serde_json::to_string(&times).unwrap()).unwrap();
+        assert!(banner(&path, &config).unwrap().contains("hasn't finished on this machine in 30"));
+    }
+}
@@ -0,0 +1,60 @@
+//! Heart rate zones from `[heart_rate] zones`, and the time spent in each.
+//! Without a second-by-second trace, a run's whole time counts in the zone
+//! of its average heart rate.
+
+use crate::models::DailyLog;
+
+/// The zone `bpm` falls in, counting from 1: one past the number of zone
+/// starts at or below it.
+pub fn zone(bpm: u32, zone_starts: &[u32]) -> usize {
+    1 + zone_starts.iter().filter(|&&start| bpm >= start).count()
+}
+
+/// Seconds spent in each zone across `logs`, zone 1 first. Days need both a
+/// run time and an average heart rate to count. Empty without zones.
+pub fn time_in_zones<'a>(
+    logs: impl IntoIterator<Item = &'a DailyLog>,
+    zone_starts: &[u32],
+) -> Vec<u32> {
+    if zone_starts.is_empty() {
+        return Vec::new();
+    }
+    let mut seconds = vec![0; zone_starts.len() + 1];
+    for log in logs {
+        if let (Some(bpm), Some(duration)) = (log.avg_hr, log.duration_secs) {
+            seconds[zone(bpm, zone_starts) - 1] += duration;
+        }
+    }
+    seconds
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn run(d: u32, avg_hr: Option<u32>, minutes: u32) -> DailyLog {
+        DailyLog {
+            avg_hr,
+            duration_secs: Some(minutes * 60),
+            ..DailyLog::new(NaiveDate::from_ymd_opt(2026, 7, d).unwrap())
+        }
+    }
+
+    #[test]
+    fn each_run_counts_in_the_zone_of_its_average() {
+        let zones = [125, 145, 160, 175];
+        assert_eq!(zone(110, &zones), 1);
+        assert_eq!(zone(145, &zones), 3);
+        assert_eq!(zone(190, &zones), 5);
+
+        let logs = vec![
+            run(20, Some(138), 60),
+            run(21, Some(141), 45),
+            run(22, Some(166), 30),
+            run(23, None, 90),
+        ];
+        assert_eq!(time_in_zones(&logs, &zones), vec![0, 6300, 0, 1800, 0]);
+        assert!(time_in_zones(&logs, &[]).is_empty());
+    }
+}
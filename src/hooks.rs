@@ -0,0 +1,183 @@
+//! User scripts run at points in the app's life, configured under
+//! `[hooks]`. Each gets the day it concerns as JSON on stdin, so it can feed
+//! a dashboard or flip a light without the app knowing about either.
+
+use anyhow::{Context, Result};
+use chrono::NaiveDate;
+use serde::Serialize;
+use std::io::Write;
+use std::process::{Command, ExitStatus, Stdio};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use crate::config::HooksConfig;
+use crate::models::DailyLog;
+
+/// How long a hook may run before it's killed.
+pub const HOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Hook {
+    /// After a day is saved to the database and its markdown backup.
+    PostSave,
+    /// After a successful sync with the cloud database.
+    PostSync,
+    /// Just before quitting, ahead of the shutdown sync.
+    PreQuit,
+}
+
+impl Hook {
+    pub fn name(self) -> &'static str {
+        match self {
+            Hook::PostSave => "post-save",
+            Hook::PostSync => "post-sync",
+            Hook::PreQuit => "pre-quit",
+        }
+    }
+}
+
+impl HooksConfig {
+    /// The command configured for `hook`, if any.
+    pub fn command(&self, hook: Hook) -> Option<&str> {
+        let command = match hook {
+            Hook::PostSave => &self.post_save,
+            Hook::PostSync => &self.post_sync,
+            Hook::PreQuit => &self.pre_quit,
+        };
+        command.as_deref().map(str::trim).filter(|c| !c.is_empty())
+    }
+}
+
+/// What a hook reads on stdin.
+#[derive(Serialize)]
+struct Payload<'a> {
+    hook: &'static str,
+    date: NaiveDate,
+    /// Null when the day has no log.
+    log: Option<&'a DailyLog>,
+}
+
+pub fn payload(hook: Hook, date: NaiveDate, log: Option<&DailyLog>) -> String {
+    serde_json::to_string(&Payload {
+        hook: hook.name(),
+        date,
+        log,
+    })
+    .unwrap_or_default()
+}
+
+/// Runs `command` through the shell with `payload` on stdin and
+/// `MOUNTAINS_HOOK` set to the hook's name, waiting up to `timeout`. Its
+/// output is discarded so it can't draw over the screen.
+pub fn run(command: &str, hook: Hook, payload: &str, timeout: Duration) -> Result<ExitStatus> {
+    let mut shell = if cfg!(windows) {
+        let mut shell = Command::new("cmd");
+        shell.arg("/C");
+        shell
+    } else {
+        let mut shell = Command::new("sh");
+        shell.arg("-c");
+        shell
+    };
+    let mut child = shell
+        .arg(command)
+        .env("MOUNTAINS_HOOK", hook.name())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .with_context(|| format!("Failed to start the {} hook", hook.name()))?;
+
+    // Written on its own thread, since a script that never reads would block
+    // the write once the pipe fills, before the timeout could start. A
+    // script that doesn't read its input closes the pipe early, and a killed
+    // one ends the write.
+    if let Some(mut stdin) = child.stdin.take() {
+        let payload = payload.to_string();
+        std::thread::spawn(move || {
+            let _ = stdin.write_all(payload.as_bytes());
+        });
+    }
+
+    let started = Instant::now();
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(status);
+        }
+        if started.elapsed() >= timeout {
+            let _ = child.kill();
+            let _ = child.wait();
+            anyhow::bail!(
+                "The {} hook was stopped after {}s",
+                hook.name(),
+                timeout.as_secs()
+            );
+        }
+        std::thread::sleep(Duration::from_millis(20));
+    }
+}
+
+/// Runs a hook on its own thread, so a slow script never holds up the UI.
+/// Failures are dropped: there's nowhere to show them by the time it ends.
+/// The thread is returned for hooks that have to finish before the app
+/// exits; it ends within `HOOK_TIMEOUT`.
+pub fn spawn(command: String, hook: Hook, payload: String) -> JoinHandle<()> {
+    std::thread::spawn(move || {
+        let _ = run(&command, hook, &payload, HOOK_TIMEOUT);
+    })
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn hooks_get_the_day_on_stdin_and_their_name_in_the_environment() {
+        let dir = TempDir::new().unwrap();
+        let out = dir.path().join("out.json");
+        let date = NaiveDate::from_ymd_opt(2026, 7, 1).unwrap();
+        let log = DailyLog {
+            miles_covered: Some(6.5),
+            ..DailyLog::new(date)
+        };
+
+        let command = format!("echo $MOUNTAINS_HOOK > '{0}'; cat >> '{0}'", out.display());
+        let status = run(
+            &command,
+            Hook::PostSave,
+            &payload(Hook::PostSave, date, Some(&log)),
+            HOOK_TIMEOUT,
+        )
+        .unwrap();
+        assert!(status.success());
+
+        let written = std::fs::read_to_string(&out).unwrap();
+        let (name, json) = written.split_once('\n').unwrap();
+        assert_eq!(name, "post-save");
+        let json: serde_json::Value = serde_json::from_str(json).unwrap();
+        assert_eq!(json["hook"], "post-save");
+        assert_eq!(json["date"], "2026-07-01");
+        assert_eq!(json["log"]["miles_covered"], 6.5);
+        assert!(payload(Hook::PreQuit, date, None).ends_with("\"log\":null}"));
+    }
+
+    #[test]
+    fn slow_hooks_are_stopped() {
+        let error = run("sleep 5", Hook::PreQuit, "", Duration::from_millis(100)).unwrap_err();
+        assert_eq!(error.to_string(), "The pre-quit hook was stopped after 0s");
+
+        // Even when it never reads a payload bigger than the pipe holds
+        let started = Instant::now();
+        let big = "x".repeat(1 << 20);
+        assert!(run("sleep 5", Hook::PreQuit, &big, Duration::from_millis(100)).is_err());
+        assert!(started.elapsed() < Duration::from_secs(2));
+
+        let blank = HooksConfig {
+            post_save: Some("  ".to_string()),
+            ..HooksConfig::default()
+        };
+        assert_eq!(blank.command(Hook::PostSave), None);
+        assert_eq!(blank.command(Hook::PostSync), None);
+    }
+}
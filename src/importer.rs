@@ -0,0 +1,672 @@
+use anyhow::{Context, Result, bail};
+use chrono::NaiveDate;
+use regex::{Regex, RegexBuilder};
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::db_manager::DbManager;
+use crate::file_manager::FileManager;
+use crate::format::Units;
+use crate::models::{DailyLog, FoodEntry};
+
+/// User-provided mapping from another app's markdown journal to DailyLog fields.
+///
+/// ```toml
+/// date_key = "date"                     # front-matter key holding the date
+/// date_regex = '(\d{4}-\d{2}-\d{2})'    # fallback, matched against the file name
+/// date_format = "%Y-%m-%d"
+///
+/// [fields]
+/// weight = { key = "weight" }
+/// miles_covered = { regex = 'Ran ([\d.]+) mi' }
+/// food_entries = { regex = '^- ate: (.+)$' }
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ImportMapping {
+    pub date_key: Option<String>,
+    pub date_regex: Option<String>,
+    pub date_format: String,
+    pub fields: FieldRules,
+}
+
+impl Default for ImportMapping {
+    fn default() -> Self {
+        Self {
+            date_key: Some("date".to_string()),
+            date_regex: None,
+            date_format: "%Y-%m-%d".to_string(),
+            fields: FieldRules::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct FieldRules {
+    pub weight: Option<FieldRule>,
+    pub waist: Option<FieldRule>,
+    pub miles_covered: Option<FieldRule>,
+    pub elevation_gain: Option<FieldRule>,
+    pub food_entries: Option<FieldRule>,
+    pub sokay_entries: Option<FieldRule>,
+    pub strength_mobility: Option<FieldRule>,
+    pub notes: Option<FieldRule>,
+}
+
+/// Where a field's value comes from: a front-matter key or a regex over the
+/// body (first capture group, or the whole match when the regex has none).
+/// Regexes run in multi-line mode so `^`/`$` anchor to lines.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct FieldRule {
+    pub key: Option<String>,
+    pub regex: Option<String>,
+}
+
+enum Source {
+    Key(String),
+    Pattern(Regex),
+}
+
+impl FieldRule {
+    fn compile(&self, field: &str) -> Result<Source> {
+        match (&self.key, &self.regex) {
+            (Some(key), None) => Ok(Source::Key(key.clone())),
+            (None, Some(pattern)) => RegexBuilder::new(pattern)
+                .multi_line(true)
+                .build()
+                .map(Source::Pattern)
+                .with_context(|| format!("Invalid regex for field '{}'", field)),
+            _ => bail!("Field '{}' needs exactly one of `key` or `regex`", field),
+        }
+    }
+}
+
+struct CompiledMapping {
+    date_key: Option<String>,
+    date_regex: Option<Regex>,
+    date_format: String,
+    fields: Vec<(&'static str, Source)>,
+}
+
+impl ImportMapping {
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents = std::fs::read_to_string(path).context("Failed to read mapping file")?;
+        toml::from_str(&contents).context("Failed to parse mapping TOML")
+    }
+
+    fn compile(&self) -> Result<CompiledMapping> {
+        let date_regex = self
+            .date_regex
+            .as_deref()
+            .map(Regex::new)
+            .transpose()
+            .context("Invalid date_regex")?;
+        if self.date_key.is_none() && date_regex.is_none() {
+            bail!("Mapping needs a `date_key` or `date_regex` to find each entry's date");
+        }
+
+        let rules = &self.fields;
+        let mut fields = Vec::new();
+        for (name, rule) in [
+            ("weight", &rules.weight),
+            ("waist", &rules.waist),
+            ("miles_covered", &rules.miles_covered),
+            ("elevation_gain", &rules.elevation_gain),
+            ("food_entries", &rules.food_entries),
+            ("sokay_entries", &rules.sokay_entries),
+            ("strength_mobility", &rules.strength_mobility),
+            ("notes", &rules.notes),
+        ] {
+            if let Some(rule) = rule {
+                fields.push((name, rule.compile(name)?));
+            }
+        }
+
+        Ok(CompiledMapping {
+            date_key: self.date_key.clone(),
+            date_regex,
+            date_format: self.date_format.clone(),
+            fields,
+        })
+    }
+}
+
+/// A parsed file ready to import, with any values that couldn't be read.
+#[derive(Debug)]
+pub struct ImportCandidate {
+    pub path: PathBuf,
+    pub log: DailyLog,
+    pub warnings: Vec<String>,
+}
+
+/// Why a file was left out of an import.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SkipReason {
+    Unreadable(String),
+    NoDate,
+    BadDate(String),
+    DuplicateDate(NaiveDate),
+}
+
+impl std::fmt::Display for SkipReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SkipReason::Unreadable(e) => write!(f, "unreadable: {}", e),
+            SkipReason::NoDate => write!(f, "no date found"),
+            SkipReason::BadDate(text) => write!(f, "unparseable date '{}'", text),
+            SkipReason::DuplicateDate(date) => write!(f, "duplicate date {}", date),
+        }
+    }
+}
+
+/// Result of scanning a directory, shown to the user before anything is written.
+#[derive(Debug, Default)]
+pub struct ImportPreview {
+    pub candidates: Vec<ImportCandidate>,
+    pub skipped: Vec<(PathBuf, SkipReason)>,
+}
+
+/// Parses every `.md` file in `dir` (non-recursive, sorted by name). Files
+/// without a recognizable date, or repeating a date already seen, are skipped
+/// with a reason rather than failing the whole import.
+pub fn scan_directory(dir: &Path, mapping: &ImportMapping) -> Result<ImportPreview> {
+    let compiled = mapping.compile()?;
+
+    let mut paths: Vec<PathBuf> = std::fs::read_dir(dir)
+        .with_context(|| format!("Failed to read directory: {:?}", dir))?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.is_file()
+                && path
+                    .extension()
+                    .is_some_and(|ext| ext.eq_ignore_ascii_case("md"))
+        })
+        .collect();
+    paths.sort();
+
+    let mut preview = ImportPreview::default();
+    let mut seen_dates = HashSet::new();
+    for path in paths {
+        let text = match std::fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) => {
+                preview
+                    .skipped
+                    .push((path, SkipReason::Unreadable(e.to_string())));
+                continue;
+            }
+        };
+        let file_name = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or_default()
+            .to_string();
+
+        match parse_entry(&compiled, &file_name, &text) {
+            Ok((log, _)) if !seen_dates.insert(log.date) => {
+                preview
+                    .skipped
+                    .push((path, SkipReason::DuplicateDate(log.date)));
+            }
+            Ok((log, warnings)) => preview.candidates.push(ImportCandidate {
+                path,
+                log,
+                warnings,
+            }),
+            Err(reason) => preview.skipped.push((path, reason)),
+        }
+    }
+    Ok(preview)
+}
+
+/// Splits a leading `---` front-matter block of flat `key: value` lines from the body.
+fn split_front_matter(text: &str) -> (HashMap<String, String>, &str) {
+    let mut front_matter = HashMap::new();
+    let Some(rest) = text
+        .strip_prefix("---\n")
+        .or_else(|| text.strip_prefix("---\r\n"))
+    else {
+        return (front_matter, text);
+    };
+
+    let mut offset = 0;
+    for line in rest.split_inclusive('\n') {
+        offset += line.len();
+        let line = line.trim_end();
+        if line == "---" {
+            return (front_matter, &rest[offset..]);
+        }
+        if let Some((key, value)) = line.split_once(':') {
+            let value = value.trim().trim_matches('"').trim_matches('\'');
+            front_matter.insert(key.trim().to_string(), value.to_string());
+        }
+    }
+    // Unterminated block: treat the whole file as body
+    (HashMap::new(), text)
+}
+
+fn parse_entry(
+    mapping: &CompiledMapping,
+    file_name: &str,
+    text: &str,
+) -> std::result::Result<(DailyLog, Vec<String>), SkipReason> {
+    let (front_matter, body) = split_front_matter(text);
+
+    let date_text = mapping
+        .date_key
+        .as_ref()
+        .and_then(|key| front_matter.get(key).cloned())
+        .or_else(|| {
+            mapping
+                .date_regex
+                .as_ref()
+                .and_then(|re| first_capture(re, file_name))
+        })
+        .ok_or(SkipReason::NoDate)?;
+    let date = NaiveDate::parse_from_str(date_text.trim(), &mapping.date_format)
+        .map_err(|_| SkipReason::BadDate(date_text))?;
+
+    let mut log = DailyLog::new(date);
+    let mut warnings = Vec::new();
+
+    for (name, source) in &mapping.fields {
+        let values: Vec<String> = match source {
+            Source::Key(key) => front_matter
+                .get(key)
+                .map(|v| match *name {
+                    "food_entries" | "sokay_entries" => {
+                        v.split(',').map(|s| s.trim().to_string()).collect()
+                    }
+                    _ => vec![v.clone()],
+                })
+                .unwrap_or_default(),
+            Source::Pattern(re) => re
+                .captures_iter(body)
+                .filter_map(|caps| caps.get(1).or_else(|| caps.get(0)))
+                .map(|m| m.as_str().trim().to_string())
+                .collect(),
+        };
+        let values: Vec<String> = values.into_iter().filter(|v| !v.is_empty()).collect();
+        let Some(first) = values.first() else {
+            continue;
+        };
+
+        let number = || first.replace(',', "");
+        match *name {
+            "weight" => log.weight = parse_number(&number(), name, &mut warnings),
+            "waist" => log.waist = parse_number(&number(), name, &mut warnings),
+            "miles_covered" => log.miles_covered = parse_number(&number(), name, &mut warnings),
+            "elevation_gain" => {
+                log.elevation_gain = parse_number::<f64>(&number(), name, &mut warnings)
+                    .map(|ft| ft.round() as i32)
+            }
+            "food_entries" => {
+                for value in values {
+                    log.add_food_entry(FoodEntry::new(value));
+                }
+            }
+            "sokay_entries" => {
+                for value in values {
+                    log.add_sokay_entry(value);
+                }
+            }
+            "strength_mobility" => log.strength_mobility = Some(values.join("\n")),
+            "notes" => log.notes = Some(values.join("\n")),
+            _ => {}
+        }
+    }
+
+    Ok((log, warnings))
+}
+
+/// Parses a measurement, rejecting NaN, infinities and negatives so a
+/// malformed file can't put values in the database that nothing can display.
+fn parse_number<T>(text: &str, field: &str, warnings: &mut Vec<String>) -> Option<T>
+where
+    T: std::str::FromStr + Copy + Into<f64>,
+{
+    let Ok(parsed) = text.parse::<T>() else {
+        warnings.push(format!("{}: '{}' is not a number", field, text));
+        return None;
+    };
+    let value: f64 = parsed.into();
+    if !value.is_finite() || value < 0.0 || value > i32::MAX as f64 {
+        warnings.push(format!("{}: '{}' is out of range", field, text));
+        return None;
+    }
+    Some(parsed)
+}
+
+fn first_capture(re: &Regex, text: &str) -> Option<String> {
+    let caps = re.captures(text)?;
+    caps.get(1)
+        .or_else(|| caps.get(0))
+        .map(|m| m.as_str().to_string())
+}
+
+/// One-line summary of the fields an imported log would set.
+pub fn summarize(log: &DailyLog) -> String {
+    let mut parts = Vec::new();
+    if let Some(weight) = log.weight {
+        parts.push(format!("{} lbs", weight));
+    }
+    if let Some(waist) = log.waist {
+        parts.push(format!("{} in", waist));
+    }
+    if let Some(miles) = log.miles_covered {
+        parts.push(format!("{} mi", miles));
+    }
+    if let Some(elevation) = log.elevation_gain {
+        parts.push(format!("{} ft", elevation));
+    }
+    if !log.food_entries.is_empty() {
+        parts.push(format!("{} foods", log.food_entries.len()));
+    }
+    if !log.sokay_entries.is_empty() {
+        parts.push(format!("{} sokay", log.sokay_entries.len()));
+    }
+    if log.strength_mobility.is_some() {
+        parts.push("strength".to_string());
+    }
+    if log.notes.is_some() {
+        parts.push("notes".to_string());
+    }
+    if parts.is_empty() {
+        "(no fields matched)".to_string()
+    } else {
+        parts.join(" | ")
+    }
+}
+
+/// `mountains import <dir> <mapping.toml>`: prints a preview, asks for
+/// confirmation, then saves days not already in the database (existing days
+/// are never overwritten).
+pub async fn run_cli(dir: &Path, mapping_path: &Path) -> Result<()> {
+    let mapping = ImportMapping::load(mapping_path)?;
+    let preview = scan_directory(dir, &mapping)?;
+
+    let config = crate::config::AppConfig::load()?;
+    let data_dir = config.storage_dir()?;
+    std::fs::create_dir_all(&data_dir).context("Failed to create .mountains directory")?;
+    let mut db = DbManager::new_local_first(&data_dir).await?;
+    if config.sync.is_configured()
+        && let Some(db_path_str) = data_dir.join("mountains.db").to_str()
+    {
+        // Offline is fine: rows land locally and sync on the next connect
+        let _ = db
+            .upgrade_to_remote_replica(
+                db_path_str,
+                config.sync.db_url.clone(),
+                config.sync.auth_token.clone(),
+            )
+            .await;
+    }
+
+    let existing: HashSet<NaiveDate> = db
+        .load_all_daily_logs()
+        .await?
+        .into_iter()
+        .map(|log| log.date)
+        .collect();
+
+    let mut to_import = Vec::new();
+    for candidate in preview.candidates {
+        let exists = existing.contains(&candidate.log.date);
+        println!(
+            "{}  {:<8} {}  ({})",
+            candidate.log.date.format("%Y-%m-%d"),
+            if exists { "exists" } else { "new" },
+            summarize(&candidate.log),
+            candidate
+                .path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .unwrap_or_default()
+        );
+        for warning in &candidate.warnings {
+            println!("            warning: {}", warning);
+        }
+        if !exists {
+            to_import.push(candidate.log);
+        }
+    }
+    for (path, reason) in &preview.skipped {
+        println!("skipped {:?}: {}", path, reason);
+    }
+
+    if to_import.is_empty() {
+        println!("\nNothing new to import.");
+        return Ok(());
+    }
+
+    print!(
+        "\nImport {} new day(s)? Existing days are left untouched. [y/N] ",
+        to_import.len()
+    );
+    std::io::stdout().flush().ok();
+    let mut answer = String::new();
+    std::io::stdin().read_line(&mut answer)?;
+    if !matches!(answer.trim(), "y" | "Y" | "yes") {
+        println!("Import cancelled.");
+        return Ok(());
+    }
+
+    let file_manager =
+        FileManager::in_dir(data_dir)?.with_units(Units::from_config(&config.display));
+    for log in &to_import {
+        db.save_daily_log(log).await?;
+        let _ = file_manager.save_daily_log(log);
+    }
+    db.sync_now().await.ok();
+    println!("Imported {} day(s).", to_import.len());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn mapping(toml_text: &str) -> ImportMapping {
+        toml::from_str(toml_text).unwrap()
+    }
+
+    #[test]
+    fn front_matter_and_regex_rules_fill_fields() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(
+            dir.path().join("a.md"),
+            "---\ndate: 2024-03-09\nweight: 172.4\n---\nRan 6.2 mi with 1,450 ft up.\n- ate: oatmeal\n- ate: banana\n",
+        )
+        .unwrap();
+        let mapping = mapping(
+            r#"
+            [fields]
+            weight = { key = "weight" }
+            miles_covered = { regex = 'Ran ([\d.]+) mi' }
+            elevation_gain = { regex = '([\d,]+) ft' }
+            food_entries = { regex = '^- ate: (.+)$' }
+            "#,
+        );
+
+        let preview = scan_directory(dir.path(), &mapping).unwrap();
+        assert!(preview.skipped.is_empty());
+        let log = &preview.candidates[0].log;
+        assert_eq!(log.date, NaiveDate::from_ymd_opt(2024, 3, 9).unwrap());
+        assert_eq!(log.weight, Some(172.4));
+        assert_eq!(log.miles_covered, Some(6.2));
+        assert_eq!(log.elevation_gain, Some(1450));
+        let foods: Vec<_> = log.food_entries.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(foods, ["oatmeal", "banana"]);
+    }
+
+    #[test]
+    fn date_falls_back_to_file_name_and_undated_files_are_skipped() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join("journal 03-09-2024.md"), "Slept well").unwrap();
+        std::fs::write(dir.path().join("ideas.md"), "no date here").unwrap();
+        std::fs::write(dir.path().join("ignored.txt"), "2024-03-10").unwrap();
+        let mapping = mapping(
+            r#"
+            date_regex = '(\d{2}-\d{2}-\d{4})'
+            date_format = "%m-%d-%Y"
+            [fields]
+            notes = { regex = '(?s)\A(.+)\z' }
+            "#,
+        );
+
+        let preview = scan_directory(dir.path(), &mapping).unwrap();
+        assert_eq!(preview.candidates.len(), 1);
+        assert_eq!(preview.candidates[0].log.notes.as_deref(), Some("Slept well"));
+        assert_eq!(preview.skipped.len(), 1);
+        assert!(preview.skipped[0].0.ends_with("ideas.md"));
+    }
+
+    #[test]
+    fn duplicate_dates_and_bad_numbers_are_reported() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join("1.md"), "---\ndate: 2024-03-09\nweight: heavy\n---\n").unwrap();
+        std::fs::write(dir.path().join("2.md"), "---\ndate: 2024-03-09\n---\n").unwrap();
+        let mapping = mapping("[fields]\nweight = { key = \"weight\" }\n");
+
+        let preview = scan_directory(dir.path(), &mapping).unwrap();
+        assert_eq!(preview.candidates.len(), 1);
+        assert_eq!(preview.candidates[0].log.weight, None);
+        assert_eq!(preview.candidates[0].warnings.len(), 1);
+        assert_eq!(
+            preview.skipped[0].1,
+            SkipReason::DuplicateDate(NaiveDate::from_ymd_opt(2024, 3, 9).unwrap())
+        );
+    }
+
+    #[test]
+    fn invalid_mappings_are_rejected() {
+        let dir = TempDir::new().unwrap();
+        let both = mapping("[fields]\nnotes = { key = \"n\", regex = \"x\" }\n");
+        assert!(scan_directory(dir.path(), &both).is_err());
+
+        let no_date = mapping("date_key = \"\"\n");
+        let no_date = ImportMapping {
+            date_key: None,
+            ..no_date
+        };
+        assert!(scan_directory(dir.path(), &no_date).is_err());
+
+        assert!(toml::from_str::<ImportMapping>("[fields]\ncalories = { key = \"c\" }\n").is_err());
+    }
+
+    #[test]
+    fn non_finite_and_negative_numbers_are_rejected() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(
+            dir.path().join("1.md"),
+            "---\ndate: 2024-03-09\nweight: NaN\nwaist: -3\nmiles: inf\nvert: 1e40\n---\n",
+        )
+        .unwrap();
+        let mapping = mapping(
+            r#"
+            [fields]
+            weight = { key = "weight" }
+            waist = { key = "waist" }
+            miles_covered = { key = "miles" }
+            elevation_gain = { key = "vert" }
+            "#,
+        );
+
+        let preview = scan_directory(dir.path(), &mapping).unwrap();
+        let candidate = &preview.candidates[0];
+        assert_eq!(candidate.log.weight, None);
+        assert_eq!(candidate.log.waist, None);
+        assert_eq!(candidate.log.miles_covered, None);
+        assert_eq!(candidate.log.elevation_gain, None);
+        assert_eq!(candidate.warnings.len(), 4);
+    }
+
+    /// Arbitrary file names and contents run through a mapping that uses every
+    /// rule kind. Nothing may panic, and anything that parses must hold values
+    /// the app can store and display.
+    mod fuzz {
+        use super::*;
+        use proptest::prelude::*;
+
+        fn compiled() -> CompiledMapping {
+            mapping(
+                r#"
+                date_regex = '(\d{4}-\d{2}-\d{2})'
+                [fields]
+                weight = { key = "weight" }
+                waist = { regex = 'waist ([^\n]*)' }
+                miles_covered = { key = "miles" }
+                elevation_gain = { regex = '([\d,.e+-]+) ft' }
+                food_entries = { key = "food" }
+                sokay_entries = { regex = '^- (.*)$' }
+                strength_mobility = { key = "strength" }
+                notes = { regex = '(?s)\A(.+)\z' }
+                "#,
+            )
+            .compile()
+            .unwrap()
+        }
+
+        /// Front matter built from the mapped keys with junk values, so the
+        /// parser gets past the date and exercises the field rules.
+        fn document() -> impl Strategy<Value = String> {
+            (
+                prop::collection::vec(
+                    (
+                        prop::sample::select(vec![
+                            "date", "weight", "miles", "food", "strength", "junk",
+                        ]),
+                        ".{0,24}",
+                    ),
+                    0..8,
+                ),
+                prop::bool::ANY,
+                "(?s).{0,200}",
+            )
+                .prop_map(|(pairs, terminated, body)| {
+                    let mut text = String::from("---\n");
+                    for (key, value) in pairs {
+                        text.push_str(&format!("{}: {}\n", key, value));
+                    }
+                    if terminated {
+                        text.push_str("---\n");
+                    }
+                    text.push_str(&body);
+                    text
+                })
+        }
+
+        fn check(mapping: &CompiledMapping, file_name: &str, text: &str) -> Result<(), TestCaseError> {
+            if let Ok((log, _)) = parse_entry(mapping, file_name, text) {
+                for value in [log.weight, log.waist, log.miles_covered].into_iter().flatten() {
+                    prop_assert!(value.is_finite() && value >= 0.0, "stored {}", value);
+                }
+                prop_assert!(log.elevation_gain.is_none_or(|feet| feet >= 0));
+                prop_assert!(log.food_entries.iter().all(|food| !food.name.is_empty()));
+            }
+            Ok(())
+        }
+
+        proptest! {
+            #[test]
+            fn arbitrary_text_never_panics(file_name in ".{0,40}", text in "(?s).{0,300}") {
+                check(&compiled(), &file_name, &text)?;
+                split_front_matter(&text);
+            }
+
+            #[test]
+            fn structured_documents_parse_to_storable_logs(
+                date in "20[0-9]{2}-[01][0-9]-[0-3][0-9]",
+                text in document(),
+            ) {
+                check(&compiled(), &format!("journal {}.md", date), &text)?;
+            }
+        }
+    }
+}
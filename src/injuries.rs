@@ -0,0 +1,275 @@
+//! Injuries and niggles: a body part, how bad it is, the day it started and
+//! the day it cleared up, kept in the `injuries` table and logged on the
+//! injuries screen. The daily view names the ones hurting on the day, the
+//! weekly distance chart marks the weeks they cover, and the statistics
+//! screen counts the year's.
+
+use chrono::NaiveDate;
+
+use crate::date_parse::parse_fuzzy_date;
+use crate::format::Units;
+use crate::models::DailyLog;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Mild,
+    Moderate,
+    Severe,
+}
+
+impl Severity {
+    pub const ALL: [Severity; 3] = [Severity::Mild, Severity::Moderate, Severity::Severe];
+
+    /// How the severity is stored in the `injuries` table.
+    pub fn key(self) -> &'static str {
+        match self {
+            Severity::Mild => "mild",
+            Severity::Moderate => "moderate",
+            Severity::Severe => "severe",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|severity| severity.key() == key)
+    }
+
+    pub fn title(self) -> &'static str {
+        match self {
+            Severity::Mild => "Mild",
+            Severity::Moderate => "Moderate",
+            Severity::Severe => "Severe",
+        }
+    }
+
+    /// The next severity, for cycling through them while logging one.
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|&severity| severity == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+/// The injury being logged or changed on the injuries screen; the body part
+/// and dates are typed into the input buffer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InjuryDraft {
+    /// The injury being changed, or none for a new one.
+    pub editing: Option<i64>,
+    pub severity: Severity,
+}
+
+/// An injury from `start` through `resolved`, both days included, or still
+/// hurting when there's no `resolved`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Injury {
+    pub id: i64,
+    pub body_part: String,
+    pub severity: Severity,
+    pub start: NaiveDate,
+    pub resolved: Option<NaiveDate>,
+}
+
+impl Injury {
+    pub fn active_on(&self, date: NaiveDate) -> bool {
+        self.start <= date && self.resolved.is_none_or(|resolved| date <= resolved)
+    }
+
+    /// Days it's lasted: through the day it cleared up, or through `today`
+    /// while it hasn't.
+    pub fn days(&self, today: NaiveDate) -> i64 {
+        ((self.resolved.unwrap_or(today) - self.start).num_days() + 1).max(0)
+    }
+
+    /// "Left knee (moderate), Aug 1, 2026 to Aug 20, 2026", or "since" the
+    /// start while it hasn't cleared up.
+    pub fn label(&self, units: Units) -> String {
+        let dates = match self.resolved {
+            Some(resolved) => {
+                format!("{} to {}", units.short_date(self.start), units.short_date(resolved))
+            }
+            None => format!("since {}", units.short_date(self.start)),
+        };
+        format!("{} ({}), {}", self.body_part, self.severity.key(), dates)
+    }
+
+    /// As it's typed to change it: "Left knee, 2026-08-01 to 2026-08-20".
+    pub fn entry_text(&self) -> String {
+        let mut text = format!("{}, {}", self.body_part, self.start.format("%Y-%m-%d"));
+        if let Some(resolved) = self.resolved {
+            text.push_str(&format!(" to {}", resolved.format("%Y-%m-%d")));
+        }
+        text
+    }
+
+    /// Distance run while it lasted, through `today` at the latest.
+    pub fn miles_during<'a>(
+        &self,
+        today: NaiveDate,
+        actual: impl Fn(NaiveDate) -> Option<&'a DailyLog>,
+    ) -> f64 {
+        let last = self.resolved.unwrap_or(today).min(today);
+        self.start
+            .iter_days()
+            .take_while(|&date| date <= last)
+            .filter_map(|date| actual(date).and_then(|log| log.miles_covered))
+            .fold(0.0, |total, miles| total + miles as f64)
+    }
+}
+
+/// The injuries hurting on `date`, as they're listed: latest first.
+pub fn active_on(injuries: &[Injury], date: NaiveDate) -> Vec<&Injury> {
+    injuries.iter().filter(|injury| injury.active_on(date)).collect()
+}
+
+/// For each week starting on one of `weeks`, whether an injury covers any
+/// of its days.
+pub fn weeks_hurt(injuries: &[Injury], weeks: &[NaiveDate]) -> Vec<bool> {
+    weeks
+        .iter()
+        .map(|&week| {
+            week.iter_days()
+                .take(7)
+                .any(|date| injuries.iter().any(|injury| injury.active_on(date)))
+        })
+        .collect()
+}
+
+/// Reads "BODY PART", "BODY PART, FROM" or "BODY PART, FROM to TO", the
+/// dates anything `parse_fuzzy_date` takes. Without a start it started
+/// today; without an end it hasn't cleared up.
+pub fn parse_entry(
+    text: &str,
+    today: NaiveDate,
+) -> Result<(String, NaiveDate, Option<NaiveDate>), String> {
+    let (body_part, dates) = text.split_once(',').unwrap_or((text, ""));
+    let body_part = body_part.trim();
+    if body_part.is_empty() {
+        return Err("Type what hurts, like: Left knee, aug 3".to_string());
+    }
+    let read = |text: &str| {
+        parse_fuzzy_date(text, today).ok_or_else(|| format!("Unrecognized date '{}'", text.trim()))
+    };
+    let (start, resolved) = match dates.split_once(" to ") {
+        _ if dates.trim().is_empty() => (today, None),
+        Some((from, to)) => (read(from)?, Some(read(to)?)),
+        None => (read(dates)?, None),
+    };
+    if start > today {
+        return Err("Future dates not allowed".to_string());
+    }
+    if resolved.is_some_and(|resolved| resolved < start) {
+        return Err("It clears up before it starts".to_string());
+    }
+    Ok((body_part.to_string(), start, resolved))
+}
+
+/// A year's injuries, as far as it's gone.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct YearInjuries {
+    pub count: usize,
+    /// Days with anything hurting, each counted once however many did.
+    pub days: u32,
+    pub miles: f64,
+}
+
+/// Injuries that hurt during `year` through `today`, or None when none
+/// did.
+pub fn year_injuries<'a>(
+    injuries: &[Injury],
+    year: i32,
+    today: NaiveDate,
+    actual: impl Fn(NaiveDate) -> Option<&'a DailyLog>,
+) -> Option<YearInjuries> {
+    let first = NaiveDate::from_ymd_opt(year, 1, 1)?;
+    let last = NaiveDate::from_ymd_opt(year, 12, 31)?.min(today);
+    let in_year: Vec<&Injury> = injuries
+        .iter()
+        .filter(|injury| injury.start <= last && injury.resolved.is_none_or(|end| end >= first))
+        .collect();
+    if in_year.is_empty() {
+        return None;
+    }
+    let mut summary = YearInjuries {
+        count: in_year.len(),
+        days: 0,
+        miles: 0.0,
+    };
+    for date in first.iter_days().take_while(|&date| date <= last) {
+        if in_year.iter().any(|injury| injury.active_on(date)) {
+            summary.days += 1;
+            summary.miles += actual(date).and_then(|log| log.miles_covered).unwrap_or(0.0) as f64;
+        }
+    }
+    Some(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(month: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, month, d).unwrap()
+    }
+
+    fn injury(id: i64, start: NaiveDate, resolved: Option<NaiveDate>) -> Injury {
+        Injury {
+            id,
+            body_part: "Left knee".to_string(),
+            severity: Severity::Moderate,
+            start,
+            resolved,
+        }
+    }
+
+    #[test]
+    fn reads_an_entry_and_knows_when_it_hurt() {
+        let today = day(8, 10);
+        assert_eq!(parse_entry("Left knee", today), Ok(("Left knee".to_string(), today, None)));
+        assert_eq!(
+            parse_entry(" Right calf , aug 1 to -2", today),
+            Ok(("Right calf".to_string(), day(8, 1), Some(day(8, 8))))
+        );
+        assert!(parse_entry(", aug 1", today).is_err());
+        assert!(parse_entry("Hip, aug 5 to aug 1", today).is_err());
+        assert!(parse_entry("Hip, someday", today).is_err());
+
+        let knee = injury(1, day(8, 1), Some(day(8, 8)));
+        assert!(knee.active_on(day(8, 8)));
+        assert!(!knee.active_on(day(8, 9)));
+        assert_eq!(knee.days(today), 8);
+        assert_eq!(knee.entry_text(), "Left knee, 2026-08-01 to 2026-08-08");
+        let calf = injury(2, day(8, 7), None);
+        assert_eq!(calf.days(today), 4);
+        assert_eq!(active_on(&[knee.clone(), calf.clone()], day(8, 9)), vec![&calf]);
+
+        // Of the weeks starting Jul 20, Jul 27 and Aug 10, the knee only hurt in the second
+        let weeks = [day(7, 20), day(7, 27), day(8, 10)];
+        assert_eq!(weeks_hurt(&[knee], &weeks), vec![false, true, false]);
+        assert_eq!(Severity::Severe.next(), Severity::Mild);
+        assert_eq!(Severity::from_key("mild"), Some(Severity::Mild));
+    }
+
+    #[test]
+    fn totals_the_year_counting_overlapping_days_once() {
+        let logs = [
+            DailyLog {
+                miles_covered: Some(4.0),
+                ..DailyLog::new(day(8, 3))
+            },
+            DailyLog {
+                miles_covered: Some(9.0),
+                ..DailyLog::new(day(8, 20))
+            },
+        ];
+        let actual = |date| logs.iter().find(|log| log.date == date);
+        let injuries = [
+            injury(1, day(8, 1), Some(day(8, 5))),
+            injury(2, day(8, 4), None),
+            injury(3, NaiveDate::from_ymd_opt(2025, 3, 1).unwrap(), Some(day(1, 2))),
+        ];
+        let today = day(8, 10);
+        let year = year_injuries(&injuries, 2026, today, actual).unwrap();
+        assert_eq!((year.count, year.days, year.miles), (3, 12, 4.0));
+        assert_eq!(injuries[0].miles_during(today, actual), 4.0);
+        assert_eq!(year_injuries(&injuries, 2024, today, actual), None);
+    }
+}
@@ -0,0 +1,141 @@
+//! The optional passphrase lock. Only an Argon2 hash of the passphrase is
+//! kept, in `[lock] passphrase_hash`; it is set from the command line with
+//! `mountains passphrase` so the passphrase never passes through the TUI's
+//! config screens.
+
+use anyhow::{Context, Result, bail};
+use argon2::{
+    Argon2, PasswordHash, PasswordHasher, PasswordVerifier,
+    password_hash::{SaltString, rand_core::OsRng},
+};
+use std::time::Duration;
+
+use crate::config::{AppConfig, LockConfig};
+
+/// Hashes `passphrase` with a fresh random salt, as a PHC string.
+pub fn hash_passphrase(passphrase: &str) -> Result<String> {
+    let salt = SaltString::generate(&mut OsRng);
+    let hash = Argon2::default()
+        .hash_password(passphrase.as_bytes(), &salt)
+        .map_err(|e| anyhow::anyhow!("Failed to hash passphrase: {}", e))?;
+    Ok(hash.to_string())
+}
+
+/// Whether `passphrase` matches `hash`. A hash that doesn't parse matches
+/// nothing, so a mangled config keeps the app locked rather than open.
+pub fn verify_passphrase(hash: &str, passphrase: &str) -> bool {
+    PasswordHash::new(hash).is_ok_and(|hash| {
+        Argon2::default()
+            .verify_password(passphrase.as_bytes(), &hash)
+            .is_ok()
+    })
+}
+
+/// Lock state for a running app: locked at startup when a passphrase is set,
+/// and again after `idle_minutes` without input.
+pub struct AppLock {
+    hash: Option<String>,
+    idle: Option<Duration>,
+    pub locked: bool,
+    /// The passphrase typed so far on the lock screen.
+    pub input: String,
+    /// The last attempt was wrong.
+    pub failed: bool,
+}
+
+impl AppLock {
+    pub fn new(config: &LockConfig) -> Self {
+        let hash = config
+            .passphrase_hash
+            .clone()
+            .filter(|hash| !hash.trim().is_empty());
+        Self {
+            locked: hash.is_some(),
+            idle: (config.idle_minutes > 0)
+                .then(|| Duration::from_secs(u64::from(config.idle_minutes) * 60)),
+            hash,
+            input: String::new(),
+            failed: false,
+        }
+    }
+
+    pub fn has_passphrase(&self) -> bool {
+        self.hash.is_some()
+    }
+
+    /// Locks the app if it has been idle for too long.
+    pub fn check_idle(&mut self, idle_for: Duration) {
+        if let (Some(_), Some(idle)) = (&self.hash, self.idle)
+            && idle_for >= idle
+        {
+            self.locked = true;
+        }
+    }
+
+    /// Checks the typed passphrase, unlocking on a match.
+    pub fn submit(&mut self) {
+        let matches = self
+            .hash
+            .as_deref()
+            .is_some_and(|hash| verify_passphrase(hash, &self.input));
+        self.input.clear();
+        self.failed = !matches;
+        if matches {
+            self.locked = false;
+        }
+    }
+}
+
+/// `mountains passphrase`: prompts for a new passphrase twice and stores its
+/// hash in config.toml. An empty passphrase removes the lock.
+pub fn run_cli() -> Result<()> {
+    let mut config = AppConfig::load()?;
+    let passphrase = rpassword::prompt_password("New passphrase (empty to remove the lock): ")
+        .context("Failed to read passphrase")?;
+    if passphrase.is_empty() {
+        config.lock.passphrase_hash = None;
+        config.save()?;
+        println!("Passphrase removed; mountains will no longer lock.");
+        return Ok(());
+    }
+    let again =
+        rpassword::prompt_password("Repeat passphrase: ").context("Failed to read passphrase")?;
+    if again != passphrase {
+        bail!("Passphrases didn't match; nothing was changed");
+    }
+    config.lock.passphrase_hash = Some(hash_passphrase(&passphrase)?);
+    config.save()?;
+    println!("Passphrase set; mountains will ask for it at startup.");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locks_at_startup_and_after_idle_until_the_passphrase_is_entered() {
+        let config = LockConfig {
+            passphrase_hash: Some(hash_passphrase("correct horse").unwrap()),
+            idle_minutes: 5,
+        };
+        let mut lock = AppLock::new(&config);
+        assert!(lock.locked);
+
+        lock.input = "wrong".to_string();
+        lock.submit();
+        assert!(lock.locked && lock.failed && lock.input.is_empty());
+
+        lock.input = "correct horse".to_string();
+        lock.submit();
+        assert!(!lock.locked && !lock.failed);
+
+        lock.check_idle(Duration::from_secs(4 * 60));
+        assert!(!lock.locked);
+        lock.check_idle(Duration::from_secs(5 * 60));
+        assert!(lock.locked);
+
+        assert!(!AppLock::new(&LockConfig::default()).locked);
+        assert!(!verify_passphrase("not a hash", ""));
+    }
+}
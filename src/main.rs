@@ -1,28 +1,90 @@
+mod activity_stats;
 mod app;
 mod assets;
+mod barcode;
+mod best_efforts;
+mod completions;
+mod computed;
 mod config;
+mod date_parse;
 mod db_manager;
 mod elevation_stats;
+mod energy_stats;
 mod events;
+#[cfg(debug_assertions)]
+mod fake_data;
 mod file_manager;
+mod format;
+mod gear;
+mod goals;
+mod health;
+mod heart_rate;
+mod hooks;
+mod importer;
+mod injuries;
+mod lock;
+mod manual;
+mod milestones;
 mod miles_stats;
 mod models;
+mod month_stats;
+mod ocr;
+mod on_this_day;
+mod phases;
+mod plan;
+mod platform;
+mod profiling;
+mod recovery_stats;
+mod routes;
+mod save_errors;
+mod share_card;
+mod sleep_stats;
+mod stats_cache;
+mod streaks;
+mod strength;
+mod time_of_day;
+mod training_load;
 mod ui;
+mod units;
+mod weather;
+mod week_stats;
+mod weight_stats;
 
 use anyhow::Result;
+use chrono::NaiveDate;
 use crossterm::{
-    event::{DisableMouseCapture, EnableMouseCapture},
+    event::{
+        DisableMouseCapture, EnableMouseCapture, KeyboardEnhancementFlags,
+        PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags,
+    },
     execute,
-    terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
+    terminal::{
+        EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode,
+        supports_keyboard_enhancement,
+    },
 };
 use ratatui::{Terminal, backend::CrosstermBackend};
 use std::io;
+use std::path::PathBuf;
 
 use crate::app::App;
 
+// Counting every allocation costs a little on each one, so only debug
+// builds, where the F10 overlay is used to check redraws, pay for it
+#[cfg(debug_assertions)]
+#[global_allocator]
+static ALLOCATOR: profiling::CountingAllocator = profiling::CountingAllocator;
+
 #[tokio::main]
 async fn main() -> Result<()> {
-    handle_cli_args();
+    let open_on = match handle_cli_args() {
+        CliCommand::Run { date } => date,
+        CliCommand::Import { dir, mapping } => return importer::run_cli(&dir, &mapping).await,
+        CliCommand::Plan { csv } => return plan::run_cli(&csv).await,
+        CliCommand::Passphrase => return lock::run_cli(),
+        #[cfg(debug_assertions)]
+        CliCommand::GenFake { years, seed } => return fake_data::run_cli(years, seed).await,
+    };
 
     let data_dir = config::data_dir()?;
 
@@ -39,6 +101,9 @@ async fn main() -> Result<()> {
     // Separate scope ensures app is dropped before terminal cleanup
     let result = {
         let mut app = App::new(app_config).await?;
+        if let Some(date) = open_on {
+            app.open_on(date);
+        }
         app.run(&mut terminal).await
     };
 
@@ -52,38 +117,133 @@ const HELP_TEXT: &str = concat!(
     "\n",
     "USAGE:\n",
     "    ", env!("CARGO_PKG_NAME"), " [OPTIONS]\n",
+    "    ", env!("CARGO_PKG_NAME"), " import <DIR> <MAPPING.toml>\n",
+    "    ", env!("CARGO_PKG_NAME"), " plan <PLAN.csv>\n",
+    "    ", env!("CARGO_PKG_NAME"), " completions <bash|zsh|fish>\n",
+    "    ", env!("CARGO_PKG_NAME"), " man\n",
+    "    ", env!("CARGO_PKG_NAME"), " passphrase\n",
     "\n",
     "OPTIONS:\n",
-    "    -h, --help       Print this help message\n",
-    "    -V, --version    Print version information\n",
+    "    -d, --date <DATE>  Open straight on that day, e.g. 2024-03-09,\n",
+    "                       \"Nov 3\", yesterday or -3\n",
+    "    -h, --help         Print this help message\n",
+    "    -V, --version      Print version information\n",
     "\n",
-    "Run with no arguments to launch the interactive TUI.\n",
-    "Data is stored in ~/.mountains/ (database, config, markdown backups).\n",
+    "COMMANDS:\n",
+    "    import           Import markdown files from another journal app, mapping\n",
+    "                     front-matter keys/regexes to fields; previews first\n",
+    "    plan             Import a training plan, a CSV of date,miles,vert[,note]\n",
+    "                     in display units, to show against what was run\n",
+    "    completions      Print a shell completion script, e.g.\n",
+    "                     mountains completions bash > ~/.local/share/bash-completion/completions/mountains\n",
+    "    man              Print the man page, e.g. mountains man | man -l -\n",
+    "    passphrase       Set or remove the passphrase asked for at startup\n",
+    "\n",
+    "Run with no arguments to launch the interactive TUI; press F1 there for\n",
+    "the manual of every screen, shortcut and config option.\n",
+    "Data is stored in ~/.mountains/ (database, config, markdown backups);\n",
+    "set MOUNTAINS_DATA_DIR to use another directory.\n",
     "\n",
     "Repository: https://github.com/papadavis47/mountains",
 );
 
+/// What to do after argument parsing: launch the TUI or run a one-shot command.
+enum CliCommand {
+    /// The TUI, opened on `date`'s daily view when given.
+    Run { date: Option<NaiveDate> },
+    Import { dir: PathBuf, mapping: PathBuf },
+    Plan { csv: PathBuf },
+    Passphrase,
+    /// Fill an empty database with generated days; debug builds only.
+    #[cfg(debug_assertions)]
+    GenFake { years: u32, seed: u64 },
+}
+
 /// Handles `--version`/`--help` flags before the TUI starts. Exits the process
-/// after printing; returns only when no recognized flag is present so the app
-/// can launch normally.
-fn handle_cli_args() {
-    for arg in std::env::args().skip(1) {
-        match arg.as_str() {
-            "-V" | "--version" => {
-                println!("{} {}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
-                std::process::exit(0);
-            }
-            "-h" | "--help" => {
-                println!("{}", HELP_TEXT);
-                std::process::exit(0);
-            }
-            other => {
-                eprintln!("error: unrecognized argument '{}'\n", other);
-                eprintln!("{}", HELP_TEXT);
-                std::process::exit(2);
-            }
+/// after printing; returns the command to run otherwise.
+fn handle_cli_args() -> CliCommand {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        None => CliCommand::Run { date: None },
+        Some("-V" | "--version") => {
+            println!("{} {}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
+            std::process::exit(0);
+        }
+        Some("-h" | "--help") => {
+            println!("{}", HELP_TEXT);
+            std::process::exit(0);
+        }
+        Some("import") if args.len() == 3 => CliCommand::Import {
+            dir: PathBuf::from(&args[1]),
+            mapping: PathBuf::from(&args[2]),
+        },
+        Some("import") => usage_error("import expects <DIR> <MAPPING.toml>"),
+        Some("plan") if args.len() == 2 => CliCommand::Plan {
+            csv: PathBuf::from(&args[1]),
+        },
+        Some("plan") => usage_error("plan expects <PLAN.csv>"),
+        Some("completions") => {
+            let Some(shell) = args.get(1).and_then(|name| completions::Shell::parse(name)) else {
+                usage_error("completions expects one of: bash, zsh, fish");
+            };
+            print!("{}", completions::script(shell));
+            std::process::exit(0);
+        }
+        Some("-d" | "--date") if args.len() == 2 => CliCommand::Run {
+            date: Some(parse_date_arg(&args[1])),
+        },
+        Some("-d" | "--date") => usage_error("--date expects one <DATE>"),
+        Some(flag) if flag.starts_with("--date=") && args.len() == 1 => CliCommand::Run {
+            date: Some(parse_date_arg(&flag["--date=".len()..])),
+        },
+        Some("passphrase") => CliCommand::Passphrase,
+        #[cfg(debug_assertions)]
+        Some("gen-fake") => parse_gen_fake(&args[1..]),
+        Some("man") => {
+            print!("{}", manual::man_page());
+            std::process::exit(0);
+        }
+        Some(other) => usage_error(&format!("unrecognized argument '{}'", other)),
+    }
+}
+
+/// Reads `--date` the way the `g` prompt does, refusing days that haven't
+/// happened yet.
+fn parse_date_arg(value: &str) -> NaiveDate {
+    let today = chrono::Local::now().date_naive();
+    match date_parse::parse_fuzzy_date(value, today) {
+        Some(date) if date > today => usage_error(&format!("--date {} is in the future", value)),
+        Some(date) => date,
+        None => usage_error(&format!("unrecognized date '{}'", value)),
+    }
+}
+
+/// `gen-fake [--years N] [--seed N]`, five years and seed 1 by default.
+#[cfg(debug_assertions)]
+fn parse_gen_fake(args: &[String]) -> CliCommand {
+    let (mut years, mut seed) = (5, 1);
+    let mut args = args.iter();
+    while let Some(flag) = args.next() {
+        let value = args.next().map(String::as_str).unwrap_or_default();
+        match flag.as_str() {
+            "--years" => match value.parse() {
+                Ok(n) if n > 0 => years = n,
+                _ => usage_error("--years expects a number of years"),
+            },
+            "--seed" => match value.parse() {
+                Ok(n) => seed = n,
+                Err(_) => usage_error("--seed expects a number"),
+            },
+            other => usage_error(&format!("unrecognized gen-fake argument '{}'", other)),
         }
     }
+    CliCommand::GenFake { years, seed }
+}
+
+fn usage_error(message: &str) -> ! {
+    eprintln!("error: {}\n", message);
+    eprintln!("{}", HELP_TEXT);
+    std::process::exit(2);
 }
 
 /// Enables raw mode and alternate screen for TUI
@@ -91,11 +251,21 @@ fn setup_terminal() -> Result<()> {
     enable_raw_mode()?;
     let mut stdout = io::stdout();
     execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    // Lets terminals that support it report Ctrl+I distinctly from Tab
+    if supports_keyboard_enhancement().unwrap_or(false) {
+        execute!(
+            stdout,
+            PushKeyboardEnhancementFlags(KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES)
+        )?;
+    }
     Ok(())
 }
 
 /// Restores terminal to normal mode and ensures cursor is visible
 fn cleanup_terminal(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Result<()> {
+    if supports_keyboard_enhancement().unwrap_or(false) {
+        execute!(terminal.backend_mut(), PopKeyboardEnhancementFlags)?;
+    }
     disable_raw_mode()?;
     execute!(
         terminal.backend_mut(),
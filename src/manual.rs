@@ -0,0 +1,931 @@
+//! The user manual: every screen's keys and every config option, kept in one
+//! table. The F1 manual screen, the Space shortcuts popup and `mountains man`
+//! all render from it, and keys are shown as typed on the configured keymap.
+
+use crate::completions::{COMMANDS, FLAGS};
+use crate::events::keys::Keymap;
+
+/// Keys are written as command keys (QWERTY). Single letters, with or
+/// without `Shift+`, are shown through the keymap; everything else as is.
+pub struct Binding {
+    pub keys: &'static [&'static str],
+    pub action: &'static str,
+}
+
+pub struct Section {
+    pub title: &'static str,
+    pub about: &'static str,
+    pub bindings: &'static [Binding],
+}
+
+pub struct ConfigOption {
+    pub table: &'static str,
+    pub key: &'static str,
+    pub default: &'static str,
+    pub about: &'static str,
+}
+
+const fn bind(keys: &'static [&'static str], action: &'static str) -> Binding {
+    Binding { keys, action }
+}
+
+pub const DAILY_VIEW: &str = "Daily View";
+pub const TEXT_FIELDS: &str = "Editing Fields";
+
+pub const SECTIONS: &[Section] = &[
+    Section {
+        title: "Anywhere",
+        about: "Work on every screen except text fields and prompts.",
+        bindings: &[
+            bind(&["F1"], "Open this manual"),
+            bind(
+                &["Ctrl+O", "Ctrl+I"],
+                "Jump back / forward between visited screens",
+            ),
+            bind(
+                &["q"],
+                "Sync and quit, asking first if anything is unsaved (not on the heatmap or charts)",
+            ),
+            bind(
+                &["F9"],
+                "Hide everything behind a blank prompt until F9 again (also while typing)",
+            ),
+            bind(
+                &["F10"],
+                "Show or hide frame and per-screen render times (and allocations in debug builds)",
+            ),
+        ],
+    },
+    Section {
+        title: "Startup",
+        about: "The launch screen, with this month's vert days, the current streak and the next lifetime milestones.",
+        bindings: &[
+            bind(&["n"], "Open today"),
+            bind(&["l"], "Browse logged days"),
+            bind(&["a"], "Open a day by date"),
+            bind(&["s"], "Statistics"),
+            bind(&["H"], "Year heatmap"),
+            bind(&["Y"], "Year at a glance, a calendar per month"),
+            bind(&["C"], "Charts"),
+            bind(&["W"], "This week's summary"),
+            bind(&["M"], "This month's summary"),
+            bind(&["!"], "Days that couldn't be saved"),
+            bind(&["G"], "Gear and its mileage"),
+            bind(&["R"], "Routes, with runs and vert on each"),
+            bind(&["L"], "Strength exercises, with a chart of each"),
+            bind(&["B"], "Best efforts: the longest days and the most vert"),
+            bind(&["O"], "Goals, with a bar for each"),
+            bind(&["P"], "Training phases and their totals"),
+            bind(&["N"], "Injuries and niggles, logged and resolved"),
+            bind(&["F"], "Backfill a range of days, one short form per day"),
+            bind(&["U"], "Show both unit systems, or just the one set"),
+            bind(&["c"], "Cloud sync settings"),
+            bind(&[","], "Settings"),
+            bind(&["x"], "Dismiss the data health banner"),
+        ],
+    },
+    Section {
+        title: "Settings",
+        about: "Display settings. The theme previews as you move through the list.",
+        bindings: &[
+            bind(&["j", "k"], "Next / previous theme"),
+            bind(&["Enter"], "Keep the theme and save it to config.toml"),
+            bind(&["g"], "Gear"),
+            bind(&["Esc"], "Go back to the saved theme"),
+        ],
+    },
+    Section {
+        title: "Lock Screen",
+        about: "Shown at startup and after [lock] idle_minutes once a passphrase is set with mountains passphrase.",
+        bindings: &[
+            bind(&["Enter"], "Unlock with the typed passphrase"),
+            bind(&["Esc"], "Quit without unlocking"),
+        ],
+    },
+    Section {
+        title: "Calendar",
+        about: "A month at a time; days with a log are bold, today is underlined.",
+        bindings: &[
+            bind(&["h", "l"], "Previous / next day"),
+            bind(&["k", "j"], "Previous / next week"),
+            bind(&["[", "]"], "Previous / next month"),
+            bind(&["Enter"], "Open the day"),
+            bind(&["Esc"], "Back to logged days"),
+        ],
+    },
+    Section {
+        title: "Logged Days",
+        about: "Every day with a log, newest first, under month totals.",
+        bindings: &[
+            bind(&["j", "k"], "Move the selection"),
+            bind(
+                &["Enter"],
+                "Open the selected day (today when none is selected)",
+            ),
+            bind(&["a"], "Open a day by date"),
+            bind(&["v"], "Pick a day from a calendar"),
+            bind(
+                &["/"],
+                "Filter: vert, vert>=1500, miles<10, type:hike, sokay, notes, food, or any word",
+            ),
+            bind(&["V"], "Views: saved filters; s saves the current one, d deletes"),
+            bind(&["o"], "Sort newest first, oldest first or by distance"),
+            bind(&["z"], "Fold or unfold the selected month (also Enter on its header)"),
+            bind(&["Z"], "Fold every month, or unfold them all"),
+            bind(&["d"], "Delete the selected day"),
+            bind(&["x"], "Archive the selected day, hiding it from lists and stats, or bring it back"),
+            bind(&["A"], "Show or hide archived days"),
+            bind(&["H", "Y", "C", "W", "M"], "Heatmap, year, charts, week, month"),
+            bind(&["!"], "Days that couldn't be saved"),
+            bind(&["G"], "Gear and its mileage"),
+            bind(&["R"], "Routes, with runs and vert on each"),
+            bind(&["L"], "Strength exercises, with a chart of each"),
+            bind(&["B"], "Best efforts: the longest days and the most vert"),
+            bind(&["O"], "Goals, with a bar for each"),
+            bind(&["P"], "Training phases and their totals"),
+            bind(&["N"], "Injuries and niggles, logged and resolved"),
+            bind(&["F"], "Backfill a range of days, one short form per day"),
+            bind(&["U"], "Show both unit systems, or just the one set"),
+            bind(&["S"], "Back to startup"),
+            bind(&["Esc"], "Clear the selection"),
+        ],
+    },
+    Section {
+        title: DAILY_VIEW,
+        about: "One day's log, split into sections.",
+        bindings: &[
+            bind(&["Shift+J", "Shift+K"], "Focus the next / previous section"),
+            bind(&["Tab"], "Switch between a section's number fields"),
+            bind(&["Enter"], "Edit or add to the focused section"),
+            bind(&["w", "s"], "Edit weight / waist"),
+            bind(&["z"], "Edit hours slept"),
+            bind(&["r", "v"], "Edit resting heart rate / HRV"),
+            bind(&["1-5"], "Rate the day's energy (again to clear)"),
+            bind(&["m", "l"], "Edit miles / elevation gain"),
+            bind(&["T"], "Edit the run time (h:mm:ss), which shows your pace"),
+            bind(&["B"], "Edit the run's start time (24-hour h:mm)"),
+            bind(&["E"], "Edit the run's perceived effort (RPE 1-10)"),
+            bind(&["H", "X"], "Edit the run's average / max heart rate"),
+            bind(&["A"], "Tag the day run, hike, bike, ski or strength (repeat to cycle)"),
+            bind(&["I"], "Mark the day's run indoor, like the treadmill (again to undo)"),
+            bind(&["D"], "Mark the day a deliberate rest day, which holds streaks (again to undo)"),
+            bind(&["G"], "Pick the shoes (or other gear) the day's run was in"),
+            bind(&["R"], "Name the route, with routes run before offered (Tab)"),
+            bind(&["f", "c"], "Add a food / sokay entry"),
+            bind(&["C"], "Add a sokay entry with just the time, to fill in later"),
+            bind(&["P"], "Read food off a meal or receipt photo (needs [food] ocr_command)"),
+            bind(&["N"], "Injuries and niggles; the day's active ones show under the title"),
+            bind(&["t", "n"], "Edit strength & mobility (lines like Squat 3x8 @ 135 are sets) / notes"),
+            bind(&["i", "o"], "Set the morning intention / evening gratitude"),
+            bind(&["j", "k"], "Select a list item, or scroll a long section"),
+            bind(&["e", "d"], "Edit / delete the selected item"),
+            bind(&["+", "-"], "Add / take back a glass of water"),
+            bind(
+                &["/"],
+                "On Food Items: when you last ate a food, and how often",
+            ),
+            bind(&["W", "M"], "Week / month summary for this day"),
+            bind(&["U"], "Show both unit systems, or just the one set"),
+            bind(&["!"], "Days that couldn't be saved"),
+            bind(&["g"], "Go to a date: 2024-11-03, Nov 3, yesterday, -3, fri"),
+            bind(&["y"], "Copy the day as markdown to the clipboard"),
+            bind(&["p"], "Save a PNG share card of the day to exports/"),
+            bind(&["Space"], "Shortcuts popup"),
+            bind(&["S"], "Back to startup"),
+            bind(&["Esc"], "Leave the list, or go back to logged days"),
+        ],
+    },
+    Section {
+        title: TEXT_FIELDS,
+        about: "While typing a value or entry.",
+        bindings: &[
+            bind(&["Enter"], "Save"),
+            bind(&["Esc"], "Cancel; a changed food, sokay entry or notes asks first (y / n)"),
+            bind(&["Alt+Enter"], "New line (strength & mobility, notes)"),
+            bind(&["Up", "Down"], "Nudge a number (Shift for x10)"),
+            bind(&["Alt+Up", "Alt+Down"], "Pick a recent weight or waist"),
+            bind(&["Up", "Down"], "Food name: pick a frequent food as you type"),
+            bind(&["Tab"], "Strength & mobility: complete the exercise from ones logged before"),
+            bind(&["Enter"], "Add Food with a barcode as the name: look it up on OpenFoodFacts"),
+            bind(
+                &["Tab", "Shift+Tab"],
+                "Food form: take the picked (or only) frequent food, or move between name, quantity and calories",
+            ),
+        ],
+    },
+    Section {
+        title: "Statistics",
+        about: "Week, month and year totals, and lifetime totals since the first log.",
+        bindings: &[bind(&["Esc"], "Back to startup")],
+    },
+    Section {
+        title: "Heatmap",
+        about: "A year of daily vert, one column per week.",
+        bindings: &[
+            bind(&["h", "l"], "Previous / next week"),
+            bind(&["k", "j"], "Previous / next day"),
+            bind(&["Enter"], "Open the day"),
+            bind(&["Esc"], "Back"),
+        ],
+    },
+    Section {
+        title: "Year",
+        about: "Twelve small month calendars, each day colored by its vert.",
+        bindings: &[
+            bind(&["h", "l"], "Previous / next day"),
+            bind(&["k", "j"], "Previous / next week"),
+            bind(&["[", "]"], "Previous / next month"),
+            bind(&["{", "}"], "Previous / next year"),
+            bind(&["Enter"], "Open the day"),
+            bind(&["m"], "The cursor month's summary"),
+            bind(&["Esc"], "Back"),
+        ],
+    },
+    Section {
+        title: "Charts",
+        about: "Weight, waist, weekly miles, weekly vert and weekly sleep over time.",
+        bindings: &[
+            bind(&["h", "l"], "Previous / next chart (also Shift+Tab / Tab)"),
+            bind(&["r"], "Change the time range"),
+            bind(&["Esc"], "Back"),
+        ],
+    },
+    Section {
+        title: "Week",
+        about: "One week's totals, days and the last 12 weeks of vert, with the imported plan and how closely it was followed.",
+        bindings: &[
+            bind(&["k", "j"], "Previous / next day"),
+            bind(&["[", "]"], "Previous / next week"),
+            bind(&["Enter"], "Open the day"),
+            bind(&["Esc"], "Back"),
+        ],
+    },
+    Section {
+        title: "Month",
+        about: "One month's totals.",
+        bindings: &[
+            bind(&["[", "]"], "Previous / next month"),
+            bind(&["Esc"], "Back"),
+        ],
+    },
+    Section {
+        title: "Manual",
+        about: "This screen.",
+        bindings: &[
+            bind(&["j", "k"], "Scroll"),
+            bind(&["PageDown", "PageUp"], "Scroll a page"),
+            bind(&["Esc"], "Close (also F1)"),
+        ],
+    },
+    Section {
+        title: "Failed Saves",
+        about: "Days whose save failed even after retrying a locked database.",
+        bindings: &[
+            bind(&["j", "k"], "Select a day"),
+            bind(&["r"], "Save it again"),
+            bind(&["e"], "Export it as JSON to exports/"),
+            bind(&["Esc"], "Back"),
+        ],
+    },
+    Section {
+        title: "Routes",
+        about: "Every route you've named, most run first, with its distance and vert.",
+        bindings: &[
+            bind(&["j", "k"], "Select a route"),
+            bind(&["Enter"], "Open its latest run"),
+            bind(&["Esc"], "Back"),
+        ],
+    },
+    Section {
+        title: "Strength",
+        about: "Every exercise logged as sets, most done first, with a chart of its top weight each session (its reps when done without weight).",
+        bindings: &[
+            bind(&["j", "k"], "Select an exercise"),
+            bind(&["Enter"], "Open its latest session"),
+            bind(&["Esc"], "Back"),
+        ],
+    },
+    Section {
+        title: "Best Efforts",
+        about: "The ten longest days and the ten with the most vert, across everything logged.",
+        bindings: &[
+            bind(&["j", "k"], "Select a day"),
+            bind(&["Tab", "h", "l"], "Switch between distance and vert"),
+            bind(&["Enter"], "Open the day"),
+            bind(&["Esc"], "Back"),
+        ],
+    },
+    Section {
+        title: "Backfill",
+        about: "Catching up on a range of days: distance, vert, weight and a one-line note for each, already filled in with anything logged.",
+        bindings: &[
+            bind(&["Tab", "Shift+Tab"], "Next or previous field"),
+            bind(&["Enter"], "Start on the range, or save the day and go to the next"),
+            bind(&["Esc"], "Stop; days already saved stay saved"),
+        ],
+    },
+    Section {
+        title: "Goals",
+        about: "Weekly distance, monthly vert, target weight and sokay limits, each with a bar toward it. Paused goals are kept but left off the startup summary.",
+        bindings: &[
+            bind(&["j", "k"], "Select a goal"),
+            bind(&["a"], "Add a goal; Tab picks the kind before Enter saves the target"),
+            bind(&["e", "Enter"], "Change the target"),
+            bind(&["x"], "Pause it, or track it again"),
+            bind(&["d"], "Delete it"),
+            bind(&["Esc"], "Back"),
+        ],
+    },
+    Section {
+        title: "Phases",
+        about: "Base, build, peak, taper and recovery phases over ranges of days, with the running done in each. The daily and week views name the phase they fall in.",
+        bindings: &[
+            bind(&["j", "k"], "Select a phase"),
+            bind(&["a"], "Mark a phase; Tab picks the kind, then type FROM to TO and Enter"),
+            bind(&["e", "Enter"], "Change its kind or range"),
+            bind(&["d"], "Delete it"),
+            bind(&["Esc"], "Back"),
+        ],
+    },
+    Section {
+        title: "Injuries",
+        about: "Injuries and niggles by body part and severity, with how long each lasted and the distance run through it. The daily view names the ones hurting that day, the weekly distance chart marks their weeks and the statistics screen counts the year's.",
+        bindings: &[
+            bind(&["j", "k"], "Select an injury"),
+            bind(&["a"], "Log one; Tab picks the severity, then type BODY PART, FROM to TO and Enter"),
+            bind(&["e", "Enter"], "Change it"),
+            bind(&["r"], "Mark it cleared up today, or hurting again"),
+            bind(&["d"], "Delete it"),
+            bind(&["Esc"], "Back"),
+        ],
+    },
+    Section {
+        title: "Gear",
+        about: "Shoes and other gear, with the distance run in each. Active gear comes first.",
+        bindings: &[
+            bind(&["j", "k"], "Select gear"),
+            bind(&["a"], "Add gear by name"),
+            bind(&["x"], "Retire it, or bring it back"),
+            bind(&["Esc"], "Back"),
+        ],
+    },
+    Section {
+        title: "Syncing",
+        about: "The sync on the way out; it gives up after 20 seconds.",
+        bindings: &[bind(&["Esc"], "Skip it and quit now; the next start reminds you")],
+    },
+];
+
+pub const CONFIG: &[ConfigOption] = &[
+    ConfigOption {
+        table: "sync",
+        key: "enabled",
+        default: "false",
+        about: "Sync with a Turso database (set up from Startup with c)",
+    },
+    ConfigOption {
+        table: "sync",
+        key: "db_url",
+        default: "\"\"",
+        about: "Turso database URL",
+    },
+    ConfigOption {
+        table: "sync",
+        key: "auth_token",
+        default: "\"\"",
+        about: "Turso auth token",
+    },
+    ConfigOption {
+        table: "sync",
+        key: "sync_on_new_day",
+        default: "false",
+        about: "Pull today's log from other devices before starting a new one",
+    },
+    ConfigOption {
+        table: "template",
+        key: "enabled",
+        default: "false",
+        about: "Pre-fill new days from this template",
+    },
+    ConfigOption {
+        table: "template",
+        key: "food_entries",
+        default: "[]",
+        about: "Food entries for a new day",
+    },
+    ConfigOption {
+        table: "template",
+        key: "sokay_entries",
+        default: "[]",
+        about: "Sokay entries for a new day",
+    },
+    ConfigOption {
+        table: "template",
+        key: "strength_mobility",
+        default: "unset",
+        about: "Strength & mobility text for a new day",
+    },
+    ConfigOption {
+        table: "template",
+        key: "notes",
+        default: "unset",
+        about: "Notes for a new day",
+    },
+    ConfigOption {
+        table: "validation",
+        key: "weight_threshold",
+        default: "8.0",
+        about: "Confirm weights this many lbs from the recent average; 0 disables",
+    },
+    ConfigOption {
+        table: "validation",
+        key: "max_feet_per_mile",
+        default: "1500",
+        about: "Confirm elevation steeper than this; 0 disables",
+    },
+    ConfigOption {
+        table: "display",
+        key: "glyphs",
+        default: "\"auto\"",
+        about: "\"unicode\", \"ascii\", or \"auto\" to follow the locale",
+    },
+    ConfigOption {
+        table: "display",
+        key: "units",
+        default: "\"imperial\"",
+        about: "\"imperial\" or \"metric\" for entry and display",
+    },
+    ConfigOption {
+        table: "display",
+        key: "distance_decimals",
+        default: "unset",
+        about: "Round distances and distance totals to this many decimal places",
+    },
+    ConfigOption {
+        table: "display",
+        key: "weight_decimals",
+        default: "unset",
+        about: "Round weights and weight averages to this many decimal places",
+    },
+    ConfigOption {
+        table: "display",
+        key: "locale",
+        default: "\"en-US\"",
+        about: "\"en-US\", \"en-GB\", \"de-DE\" or \"fr-FR\" number separators and date order",
+    },
+    ConfigOption {
+        table: "display",
+        key: "dual_units",
+        default: "false",
+        about: "Show distances, vert and weights in both unit systems, e.g. 6.2 mi / 10.0 km",
+    },
+    ConfigOption {
+        table: "display",
+        key: "theme",
+        default: "\"dark\"",
+        about: "\"dark\", \"light\", \"solarized\" or \"high-contrast\" palette",
+    },
+    ConfigOption {
+        table: "display",
+        key: "screen_reader",
+        default: "false",
+        about: "ASCII only, with focus and selection spelled out",
+    },
+    ConfigOption {
+        table: "keys",
+        key: "preset",
+        default: "\"qwerty\"",
+        about: "\"qwerty\", \"dvorak\" or \"colemak\" movement keys",
+    },
+    ConfigOption {
+        table: "stats",
+        key: "vert_threshold",
+        default: "1000",
+        about: "Feet of gain for a vert day and the streak",
+    },
+    ConfigOption {
+        table: "stats",
+        key: "week_start",
+        default: "\"monday\"",
+        about: "First day of weekly totals and the week view",
+    },
+    ConfigOption {
+        table: "stats",
+        key: "count_indoor",
+        default: "true",
+        about: "Count indoor days in vert per mile and the distance and vert streaks",
+    },
+    ConfigOption {
+        table: "stats",
+        key: "long_run_miles",
+        default: "10.0",
+        about: "Miles a run needs for the week view's long-run streak",
+    },
+    ConfigOption {
+        table: "goals",
+        key: "weekly_miles",
+        default: "unset",
+        about: "Miles to run each week, shown as a gauge on the week view",
+    },
+    ConfigOption {
+        table: "goals",
+        key: "yearly_vert",
+        default: "unset",
+        about: "Feet of vert to climb each year, with its pace on the startup screen",
+    },
+    ConfigOption {
+        table: "stats",
+        key: "ramp_warning",
+        default: "1.5",
+        about: "7-day over 28-day weekly distance that warns on the daily view; 0 for off",
+    },
+    ConfigOption {
+        table: "storage",
+        key: "data_dir",
+        default: "~/.mountains",
+        about: "Directory for the database and markdown logs",
+    },
+    ConfigOption {
+        table: "lock",
+        key: "passphrase_hash",
+        default: "unset",
+        about: "Ask for a passphrase at startup; set with mountains passphrase",
+    },
+    ConfigOption {
+        table: "lock",
+        key: "idle_minutes",
+        default: "0",
+        about: "Lock again after this many idle minutes; 0 only at startup",
+    },
+    ConfigOption {
+        table: "session",
+        key: "idle_minutes",
+        default: "0",
+        about: "Minutes without input before idle_action runs; 0 disables",
+    },
+    ConfigOption {
+        table: "session",
+        key: "idle_action",
+        default: "\"sync\"",
+        about: "\"sync\", or sync then \"exit\" or \"lock\" (F9 screen without a passphrase)",
+    },
+    ConfigOption {
+        table: "health",
+        key: "stale_after_days",
+        default: "7",
+        about: "Startup banner when sync or backups are this stale; 0 disables",
+    },
+    ConfigOption {
+        table: "computed",
+        key: "fields",
+        default: "[]",
+        about: "Formulas for the Computed section, as [[computed.fields]]: name, formula, decimals",
+    },
+    ConfigOption {
+        table: "home",
+        key: "views",
+        default: "[]",
+        about: "Named filters for the Views menu (V), as [[home.views]]: name, query",
+    },
+    ConfigOption {
+        table: "food",
+        key: "calorie_target",
+        default: "unset",
+        about: "Daily calories to aim for, shown as a gauge over the food list",
+    },
+    ConfigOption {
+        table: "food",
+        key: "ocr_command",
+        default: "unset",
+        about: "OCR program (like tesseract) that P runs on a photo to find foods in it",
+    },
+    ConfigOption {
+        table: "notes",
+        key: "word_goal",
+        default: "unset",
+        about: "Words to write in the day's notes, counted on the Notes section and tracked as a streak",
+    },
+    ConfigOption {
+        table: "heart_rate",
+        key: "zones",
+        default: "[]",
+        about: "Heart rates where zone 2 and each zone after it start, for time in zone",
+    },
+    ConfigOption {
+        table: "hydration",
+        key: "glass",
+        default: "8 oz / 0.25 L",
+        about: "Water added by + on the daily view, in display units",
+    },
+    ConfigOption {
+        table: "gear",
+        key: "retire_after",
+        default: "400",
+        about: "Distance on a piece of gear before it's flagged to retire (0 never warns)",
+    },
+    ConfigOption {
+        table: "weather",
+        key: "latitude",
+        default: "unset",
+        about: "Where to look up the weather for days with a run, with longitude",
+    },
+    ConfigOption {
+        table: "weather",
+        key: "longitude",
+        default: "unset",
+        about: "Set both to fetch each run's high, low and conditions from Open-Meteo",
+    },
+    ConfigOption {
+        table: "hooks",
+        key: "post_save",
+        default: "unset",
+        about: "Command run after a day is saved, with the day as JSON on stdin",
+    },
+    ConfigOption {
+        table: "hooks",
+        key: "post_sync",
+        default: "unset",
+        about: "Command run after a successful cloud sync, with the open day as JSON",
+    },
+    ConfigOption {
+        table: "hooks",
+        key: "pre_quit",
+        default: "unset",
+        about: "Command run before quitting (waited on for up to 10 seconds)",
+    },
+    ConfigOption {
+        table: "streaks",
+        key: "rules",
+        default: "[]",
+        about: "Streaks to track, as [[streaks.rules]]: metric = \"miles\", \"vert\" or \"logged\", threshold, skip_days, name",
+    },
+    ConfigOption {
+        table: "colors",
+        key: "measurements",
+        default: "theme",
+        about: "Measurements accent (color name or \"#rrggbb\")",
+    },
+    ConfigOption {
+        table: "colors",
+        key: "running",
+        default: "theme",
+        about: "Running accent",
+    },
+    ConfigOption {
+        table: "colors",
+        key: "food",
+        default: "theme",
+        about: "Food accent",
+    },
+    ConfigOption {
+        table: "colors",
+        key: "sokay",
+        default: "theme",
+        about: "Sokay accent",
+    },
+    ConfigOption {
+        table: "colors",
+        key: "strength",
+        default: "theme",
+        about: "Strength & mobility accent",
+    },
+    ConfigOption {
+        table: "colors",
+        key: "notes",
+        default: "theme",
+        about: "Notes accent",
+    },
+];
+
+impl Binding {
+    /// The keys as typed on `keymap`, e.g. "j/k" or "Shift+H/Shift+T".
+    pub fn typed(&self, keymap: &Keymap) -> String {
+        let keys: Vec<String> = self.keys.iter().map(|key| typed_key(key, keymap)).collect();
+        keys.join("/")
+    }
+}
+
+fn typed_key(key: &str, keymap: &Keymap) -> String {
+    let (shift, letter) = match key.strip_prefix("Shift+") {
+        Some(letter) => ("Shift+", letter),
+        None => ("", key),
+    };
+    let mut chars = letter.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) if c.is_ascii_alphabetic() => format!("{shift}{}", keymap.key_for(c)),
+        _ => key.to_string(),
+    }
+}
+
+fn section_lines(section: &Section, keymap: &Keymap, lines: &mut Vec<String>) {
+    for binding in section.bindings {
+        lines.push(format!("  {:<18}{}", binding.typed(keymap), binding.action));
+    }
+}
+
+/// The shortcuts for the named sections, headed by their titles.
+pub fn shortcuts(titles: &[&str], keymap: &Keymap) -> Vec<String> {
+    let mut lines = Vec::new();
+    for section in SECTIONS.iter().filter(|s| titles.contains(&s.title)) {
+        if !lines.is_empty() {
+            lines.push(String::new());
+        }
+        lines.push(format!("{}:", section.title));
+        section_lines(section, keymap, &mut lines);
+    }
+    lines
+}
+
+/// The whole manual as lines for the F1 screen.
+pub fn lines(keymap: &Keymap) -> Vec<String> {
+    let mut lines = Vec::new();
+    for section in SECTIONS {
+        lines.push(section.title.to_uppercase());
+        lines.push(format!("  {}", section.about));
+        section_lines(section, keymap, &mut lines);
+        lines.push(String::new());
+    }
+    if let Some(keymap) = keymap.describe() {
+        lines.push(keymap);
+        lines.push(String::new());
+    }
+
+    lines.push("CONFIGURATION".to_string());
+    lines.push("  Set in config.toml in the data directory (~/.mountains).".to_string());
+    let mut table = "";
+    for option in CONFIG {
+        if option.table != table {
+            table = option.table;
+            lines.push(format!("  [{table}]"));
+        }
+        lines.push(format!("    {} = {}", option.key, option.default));
+        lines.push(format!("        {}", option.about));
+    }
+    lines
+}
+
+/// Escapes text for roff: backslashes, hyphens, and a leading control dot.
+fn roff(text: &str) -> String {
+    let escaped = text.replace('\\', "\\\\").replace('-', "\\-");
+    if escaped.starts_with(['.', '\'']) {
+        format!("\\&{escaped}")
+    } else {
+        escaped
+    }
+}
+
+/// The man page, in roff, for `mountains man`.
+pub fn man_page() -> String {
+    let mut page = format!(
+        ".TH MOUNTAINS 1 \"\" \"mountains {}\" \"User Commands\"\n",
+        env!("CARGO_PKG_VERSION")
+    );
+    page.push_str(
+        ".SH NAME\nmountains \\- trail running and nutrition training log for the terminal\n",
+    );
+
+    page.push_str(".SH SYNOPSIS\n.B mountains\n[\\fIOPTIONS\\fR]\n");
+    for command in COMMANDS {
+        page.push_str(&format!(".br\n.B mountains {}\n", command.name));
+        if !command.usage.is_empty() {
+            page.push_str(&format!("{}\n", roff(command.usage)));
+        }
+    }
+
+    page.push_str(".SH DESCRIPTION\n");
+    page.push_str(
+        "Run with no arguments to open the interactive training log. \
+         Days are stored in a local database, optionally synced to Turso, \
+         and backed up as markdown files.\n",
+    );
+
+    page.push_str(".SH OPTIONS\n");
+    for (short, long, value, about) in FLAGS {
+        let value = value.map(|v| format!(" \" \" \\fI{v}\\fR")).unwrap_or_default();
+        page.push_str(&format!(
+            ".TP\n.BR \\-{short} \", \" \\-\\-{long}{value}\n{}\n",
+            roff(about)
+        ));
+    }
+
+    page.push_str(".SH COMMANDS\n");
+    for command in COMMANDS {
+        page.push_str(&format!(
+            ".TP\n.B {}\n{}\n",
+            command.name,
+            roff(command.about)
+        ));
+    }
+
+    page.push_str(".SH KEYS\n");
+    let qwerty = Keymap::default();
+    for section in SECTIONS {
+        page.push_str(&format!(".SS {}\n{}\n", section.title, roff(section.about)));
+        for binding in section.bindings {
+            page.push_str(&format!(
+                ".TP\n.B {}\n{}\n",
+                roff(&binding.typed(&qwerty)),
+                roff(binding.action)
+            ));
+        }
+    }
+
+    page.push_str(".SH CONFIGURATION\n");
+    for option in CONFIG {
+        page.push_str(&format!(
+            ".TP\n.B [{}] {} = {}\n{}\n",
+            option.table,
+            option.key,
+            roff(option.default),
+            roff(option.about)
+        ));
+    }
+
+    page.push_str(".SH ENVIRONMENT\n.TP\n.B MOUNTAINS_DATA_DIR\n");
+    page.push_str("Directory for config.toml, the database and markdown logs.\n");
+    page.push_str(".SH FILES\n.TP\n.I ~/.mountains/config.toml\nSettings.\n");
+    page
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{AppConfig, ColorConfig, HooksConfig};
+    use crate::events::keys::KeymapPreset;
+
+    #[test]
+    fn every_config_option_is_documented() {
+        let mut config = AppConfig::default();
+        config.template.strength_mobility = Some(String::new());
+        config.template.notes = Some(String::new());
+        config.storage.data_dir = Some(String::new());
+        config.lock.passphrase_hash = Some(String::new());
+        config.hooks = HooksConfig {
+            post_save: Some(String::new()),
+            post_sync: Some(String::new()),
+            pre_quit: Some(String::new()),
+        };
+        config.food.calorie_target = Some(2000);
+        config.goals.weekly_miles = Some(40.0);
+        config.goals.yearly_vert = Some(400_000);
+        config.food.ocr_command = Some("tesseract".to_string());
+        config.display.distance_decimals = Some(1);
+        config.display.weight_decimals = Some(1);
+        config.hydration.glass = Some(12.0);
+        config.notes.word_goal = Some(200);
+        config.weather.latitude = Some(40.0);
+        config.weather.longitude = Some(-105.0);
+        config.colors = ColorConfig {
+            measurements: Some(String::new()),
+            running: Some(String::new()),
+            food: Some(String::new()),
+            sokay: Some(String::new()),
+            strength: Some(String::new()),
+            notes: Some(String::new()),
+        };
+        let toml: toml::Table = toml::from_str(&toml::to_string(&config).unwrap()).unwrap();
+
+        for (table, keys) in &toml {
+            for key in keys.as_table().unwrap().keys() {
+                assert!(
+                    CONFIG.iter().any(|o| o.table == table && o.key == key),
+                    "[{table}] {key} is missing from the manual"
+                );
+            }
+        }
+        assert_eq!(
+            CONFIG.len(),
+            toml.values()
+                .map(|t| t.as_table().unwrap().len())
+                .sum::<usize>()
+        );
+    }
+
+    #[test]
+    fn keys_follow_the_keymap() {
+        let dvorak = Keymap::new(KeymapPreset::Dvorak);
+        let shortcuts = shortcuts(&[DAILY_VIEW], &dvorak);
+        assert_eq!(shortcuts[0], "Daily View:");
+        assert!(shortcuts.contains(&format!(
+            "  {:<18}Focus the next / previous section",
+            "Shift+H/Shift+T"
+        )));
+        assert!(shortcuts.contains(&format!("  {:<18}Edit miles / elevation gain", "m/n")));
+
+        let manual = lines(&dvorak);
+        assert!(manual.contains(&"Keymap dvorak: d=h h=j t=k n=l j=d k=t l=n".to_string()));
+        assert!(manual.contains(&"    vert_threshold = 1000".to_string()));
+    }
+
+    #[test]
+    fn man_page_covers_commands_and_escapes_dashes() {
+        let page = man_page();
+        assert!(page.starts_with(".TH MOUNTAINS 1"));
+        for command in COMMANDS {
+            assert!(page.contains(&format!(".B mountains {}\n", command.name)));
+        }
+        assert!(page.contains(".BR \\-V \", \" \\-\\-version\n"));
+        assert!(page.contains(".B Ctrl+O/Ctrl+I\n"));
+        assert!(!page.lines().any(|line| line.starts_with("--")));
+    }
+}
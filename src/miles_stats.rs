@@ -1,5 +1,5 @@
 use crate::models::DailyLog;
-use chrono::{Datelike, NaiveDate};
+use chrono::{Datelike, NaiveDate, Weekday};
 
 /// Rounds to one decimal place, normalizing negative zero to positive zero.
 /// An empty `f32` sum yields `-0.0` (std's additive identity), which would
@@ -9,11 +9,15 @@ fn round_tenths(total: f32) -> f32 {
     if rounded == 0.0 { 0.0 } else { rounded }
 }
 
-pub fn calculate_weekly_miles(logs: &[DailyLog], reference_date: NaiveDate) -> f32 {
-    let current_week = reference_date.iso_week();
+pub fn calculate_weekly_miles(
+    logs: &[DailyLog],
+    reference_date: NaiveDate,
+    week_start: Weekday,
+) -> f32 {
+    let current_week = reference_date.week(week_start).first_day();
     let total: f32 = logs
         .iter()
-        .filter(|log| log.date.iso_week() == current_week)
+        .filter(|log| log.date.week(week_start).first_day() == current_week)
         .filter_map(|log| log.miles_covered)
         .sum();
 
@@ -42,6 +46,33 @@ pub fn calculate_yearly_miles(logs: &[DailyLog], reference_date: NaiveDate) -> f
     round_tenths(total)
 }
 
+/// Totals for each week (keyed by its first day) from the week containing
+/// `from` through the week containing `to`, oldest first. Weeks without miles
+/// are 0.
+pub fn weekly_miles_series(
+    logs: &[DailyLog],
+    from: NaiveDate,
+    to: NaiveDate,
+    week_start: Weekday,
+) -> Vec<(NaiveDate, f32)> {
+    let first = from.week(week_start).first_day();
+    let mut series: Vec<(NaiveDate, f32)> = first
+        .iter_weeks()
+        .take_while(|week| *week <= to)
+        .map(|week| (week, 0.0))
+        .collect();
+    for log in logs.iter().filter(|log| log.date >= first) {
+        let week = (log.date - first).num_days() as usize / 7;
+        if let (Some(miles), Some(entry)) = (log.miles_covered, series.get_mut(week)) {
+            entry.1 += miles;
+        }
+    }
+    for entry in &mut series {
+        entry.1 = round_tenths(entry.1);
+    }
+    series
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -65,7 +96,7 @@ mod tests {
             log(NaiveDate::from_ymd_opt(2026, 7, 27).unwrap(), Some(30.0)),
         ];
 
-        assert_eq!(calculate_weekly_miles(&logs, reference), 8.7);
+        assert_eq!(calculate_weekly_miles(&logs, reference, Weekday::Mon), 8.7);
     }
 
     #[test]
@@ -78,7 +109,7 @@ mod tests {
             log(NaiveDate::from_ymd_opt(2026, 1, 5).unwrap(), Some(30.0)),
         ];
 
-        assert_eq!(calculate_weekly_miles(&logs, reference), 12.0);
+        assert_eq!(calculate_weekly_miles(&logs, reference, Weekday::Mon), 12.0);
     }
 
     #[test]
@@ -115,7 +146,7 @@ mod tests {
             log(NaiveDate::from_ymd_opt(2026, 7, 22).unwrap(), Some(30.476)),
         ];
 
-        assert_eq!(calculate_weekly_miles(&logs, reference), 38.1);
+        assert_eq!(calculate_weekly_miles(&logs, reference, Weekday::Mon), 38.1);
         assert_eq!(calculate_monthly_miles(&logs, reference), 38.1);
         assert_eq!(calculate_yearly_miles(&logs, reference), 38.1);
     }
@@ -124,7 +155,7 @@ mod tests {
     fn empty_mileage_totals_are_positive_zero() {
         let reference = NaiveDate::from_ymd_opt(2026, 7, 22).unwrap();
         for result in [
-            calculate_weekly_miles(&[], reference),
+            calculate_weekly_miles(&[], reference, Weekday::Mon),
             calculate_monthly_miles(&[], reference),
             calculate_yearly_miles(&[], reference),
         ] {
@@ -133,4 +164,26 @@ mod tests {
             assert_eq!(format!("{result:.1}"), "0.0");
         }
     }
+
+    #[test]
+    fn weekly_miles_series_fills_empty_weeks() {
+        let day = |m, d| NaiveDate::from_ymd_opt(2026, m, d).unwrap();
+        let logs = vec![
+            log(day(7, 1), Some(4.0)),
+            log(day(7, 3), Some(2.5)),
+            log(day(7, 16), Some(6.0)),
+            log(day(7, 30), Some(9.0)),
+        ];
+
+        let series = weekly_miles_series(&logs, day(7, 2), day(7, 22), Weekday::Mon);
+        assert_eq!(
+            series,
+            vec![
+                (day(6, 29), 6.5),
+                (day(7, 6), 0.0),
+                (day(7, 13), 6.0),
+                (day(7, 20), 0.0),
+            ]
+        );
+    }
 }
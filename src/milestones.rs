@@ -0,0 +1,136 @@
+//! Lifetime milestone projections for the startup screen: the next round
+//! number of lifetime distance and vert, and when the recent pace reaches it.
+
+use chrono::{Days, NaiveDate};
+
+use crate::stats_cache::LifetimeTotals;
+use crate::format::Units;
+use crate::units::Quantity;
+
+/// Days of logs the pace is averaged over.
+pub const PACE_WINDOW_DAYS: u64 = 90;
+
+/// Projections further out than this are more guess than goal, so they
+/// aren't shown.
+const MAX_DAYS_OUT: i64 = 3650;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Projection {
+    pub quantity: Quantity,
+    /// The milestone, in display units.
+    pub target: f64,
+    pub date: NaiveDate,
+    pub days_left: i64,
+}
+
+impl Projection {
+    /// "10,000 lifetime miles in 138 days (Mar 03, 2027)"
+    pub fn describe(&self, units: Units) -> String {
+        format!(
+            "{} lifetime {} in {} {} ({})",
+            units.localize(&format!("{:.0}", self.target)),
+            units.name(self.quantity),
+            self.days_left,
+            if self.days_left == 1 { "day" } else { "days" },
+            units.short_date(self.date)
+        )
+    }
+}
+
+/// The next milestone above `total`: 1, 2.5 and 5 times a power of ten,
+/// starting at 100.
+pub fn next_milestone(total: f64) -> f64 {
+    let mut scale = 100.0;
+    loop {
+        for step in [1.0, 2.5, 5.0] {
+            if step * scale > total {
+                return step * scale;
+            }
+        }
+        scale *= 10.0;
+    }
+}
+
+/// Projects the next distance and vert milestones from `lifetime` and the
+/// totals logged over the last `PACE_WINDOW_DAYS` (`recent_miles`,
+/// `recent_elevation`). Quantities with no recent pace are left out.
+pub fn project(
+    lifetime: LifetimeTotals,
+    recent_miles: f32,
+    recent_elevation: i32,
+    units: Units,
+    today: NaiveDate,
+) -> Vec<Projection> {
+    [
+        (
+            Quantity::Distance,
+            lifetime.miles as f64,
+            recent_miles as f64,
+        ),
+        (
+            Quantity::Elevation,
+            lifetime.elevation as f64,
+            recent_elevation as f64,
+        ),
+    ]
+    .into_iter()
+    .filter_map(|(quantity, total, recent)| {
+        let total = units.convert(quantity, total);
+        let per_day = units.convert(quantity, recent) / PACE_WINDOW_DAYS as f64;
+        if per_day <= 0.0 {
+            return None;
+        }
+        let target = next_milestone(total);
+        let days_left = ((target - total) / per_day).ceil() as i64;
+        if days_left > MAX_DAYS_OUT {
+            return None;
+        }
+        Some(Projection {
+            quantity,
+            target,
+            date: today.checked_add_days(Days::new(days_left as u64))?,
+            days_left,
+        })
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::UnitSystem;
+
+    #[test]
+    fn milestones_step_through_round_numbers() {
+        assert_eq!(next_milestone(0.0), 100.0);
+        assert_eq!(next_milestone(100.0), 250.0);
+        assert_eq!(next_milestone(4_999.0), 5_000.0);
+        assert_eq!(next_milestone(6_200.0), 10_000.0);
+        assert_eq!(next_milestone(87_500.0), 100_000.0);
+    }
+
+    #[test]
+    fn projects_from_the_recent_pace() {
+        let today = NaiveDate::from_ymd_opt(2026, 10, 1).unwrap();
+        let lifetime = LifetimeTotals {
+            miles: 9_820.0,
+            elevation: 91_000,
+            days_logged: 900,
+            first_log: NaiveDate::from_ymd_opt(2020, 1, 1),
+            vert_per_mile: None,
+        };
+        // 2 miles and 100 ft a day
+        let projections = project(lifetime, 180.0, 9_000, UnitSystem::Imperial.into(), today);
+        assert_eq!(
+            projections[0].describe(UnitSystem::Imperial.into()),
+            "10,000 lifetime miles in 90 days (Dec 30, 2026)"
+        );
+        assert_eq!(projections[1].target, 100_000.0);
+        assert_eq!(projections[1].days_left, 90);
+
+        let stalled = project(lifetime, 180.0, 0, UnitSystem::Imperial.into(), today);
+        assert_eq!(stalled.len(), 1);
+        let crawling = project(lifetime, 0.1, 9_000, UnitSystem::Imperial.into(), today);
+        assert_eq!(crawling[0].quantity, Quantity::Elevation);
+    }
+}
@@ -1,7 +1,14 @@
-use chrono::NaiveDate;
+use chrono::{NaiveDate, NaiveTime};
+use std::collections::{BTreeMap, HashSet};
 use serde::{Deserialize, Serialize};
 
+pub mod activity;
+pub mod backfill;
 pub mod field_accessor;
+pub mod food_form;
+pub mod food_index;
+pub mod home_filter;
+pub mod jump_list;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DailyLog {
@@ -13,7 +20,70 @@ pub struct DailyLog {
     pub elevation_gain: Option<i32>,
     pub sokay_entries: Vec<String>,
     pub strength_mobility: Option<String>,
+    /// Sets picked out of the strength & mobility text, which keeps the
+    /// rest of what was typed.
+    #[serde(default)]
+    pub strength_sets: Vec<crate::strength::StrengthSet>,
     pub notes: Option<String>,
+    /// Water drunk, in fluid ounces.
+    #[serde(default)]
+    pub water: Option<f32>,
+    /// Hours slept the night before.
+    #[serde(default)]
+    pub sleep_hours: Option<f32>,
+    /// Morning resting heart rate, in beats per minute.
+    #[serde(default)]
+    pub resting_hr: Option<u32>,
+    /// Morning heart rate variability, in milliseconds.
+    #[serde(default)]
+    pub hrv: Option<u32>,
+    /// How the day felt, from 1 (drained) to 5 (full of beans).
+    #[serde(default)]
+    pub energy: Option<u8>,
+    /// Perceived effort of the day's training (RPE), from 1 to 10.
+    #[serde(default)]
+    pub rpe: Option<u8>,
+    /// How long the day's run took, in seconds.
+    #[serde(default)]
+    pub duration_secs: Option<u32>,
+    /// When the day's run started, for comparing morning and evening runs.
+    #[serde(default)]
+    pub start_time: Option<NaiveTime>,
+    /// Average and highest heart rate over the day's run, in bpm.
+    #[serde(default)]
+    pub avg_hr: Option<u32>,
+    #[serde(default)]
+    pub max_hr: Option<u32>,
+    /// Run, hike, bike, ski or strength, for splitting the totals by sport.
+    #[serde(default)]
+    pub activity: Option<activity::ActivityType>,
+    /// Name of the shoes (or other gear) the day's run was in.
+    #[serde(default)]
+    pub gear: Option<String>,
+    /// The trail or route the day's run was on, free text.
+    #[serde(default)]
+    pub route: Option<String>,
+    /// Fetched from Open-Meteo once a run is logged, if `[weather]` is set.
+    #[serde(default)]
+    pub weather: Option<crate::weather::Weather>,
+    /// Run on a treadmill or otherwise inside, which `[stats] count_indoor`
+    /// can leave out of vert per mile and the streaks.
+    #[serde(default)]
+    pub indoor: bool,
+    /// Deliberately not run, as opposed to not logged. Passed over by the
+    /// distance and vert streaks and left out of the week's days run.
+    #[serde(default)]
+    pub rest_day: bool,
+    /// A line set in the morning for what the day is for.
+    #[serde(default)]
+    pub intention: Option<String>,
+    /// A line written in the evening about something to be grateful for.
+    #[serde(default)]
+    pub gratitude: Option<String>,
+    /// Hidden from the lists and stats without being deleted, e.g. a junk
+    /// test entry. Changed through `AppState::set_archived`.
+    #[serde(default)]
+    pub archived: bool,
 }
 
 impl DailyLog {
@@ -27,10 +97,76 @@ impl DailyLog {
             elevation_gain: None,
             sokay_entries: Vec::new(),
             strength_mobility: None,
+            strength_sets: Vec::new(),
             notes: None,
+            water: None,
+            sleep_hours: None,
+            resting_hr: None,
+            hrv: None,
+            energy: None,
+            rpe: None,
+            avg_hr: None,
+            max_hr: None,
+            duration_secs: None,
+            start_time: None,
+            activity: None,
+            gear: None,
+            route: None,
+            weather: None,
+            indoor: false,
+            rest_day: false,
+            intention: None,
+            gratitude: None,
+            archived: false,
         }
     }
 
+    /// Adds `ounces` of water, or takes it away when negative. Returns
+    /// false when there was nothing to take away. Dropping to zero clears
+    /// the field.
+    pub fn add_water(&mut self, ounces: f32) -> bool {
+        let current = self.water.unwrap_or(0.0);
+        if ounces < 0.0 && current <= 0.0 {
+            return false;
+        }
+        let total = current + ounces;
+        // Metric glasses don't come out even in ounces
+        self.water = (total > 0.01).then_some(total);
+        true
+    }
+
+    /// Rates the day's energy 1-5. Giving the rating it already has clears
+    /// it, so a slip of the finger is one more keypress to undo.
+    pub fn rate_energy(&mut self, rating: u8) {
+        self.energy = (self.energy != Some(rating)).then_some(rating);
+    }
+
+    /// Calories across the day's foods, or None when no food has any.
+    pub fn calories(&self) -> Option<u32> {
+        self.food_entries
+            .iter()
+            .filter_map(|entry| entry.calories)
+            .reduce(|total, calories| total + calories)
+    }
+
+    /// The strength & mobility section as it's shown and edited: the sets
+    /// a line each, then the rest of the text. None when there's neither.
+    pub fn strength_text(&self, units: crate::format::Units) -> Option<String> {
+        let text = crate::strength::join_text(
+            &self.strength_sets,
+            self.strength_mobility.as_deref(),
+            units,
+        );
+        (!text.is_empty()).then_some(text)
+    }
+
+    /// Words written in the day's notes.
+    pub fn note_words(&self) -> usize {
+        self.notes
+            .as_deref()
+            .map_or(0, |notes| notes.split_whitespace().count())
+    }
+
     pub fn add_food_entry(&mut self, entry: FoodEntry) {
         self.food_entries.push(entry);
     }
@@ -52,27 +188,95 @@ impl DailyLog {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FoodEntry {
     pub name: String,
+    /// Free text like "1 cup" or "2 slices".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub calories: Option<u32>,
+    /// Filled in from a barcode lookup; there's no field to type them.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub macros: Option<Macros>,
 }
 
 impl FoodEntry {
     pub fn new(name: String) -> Self {
-        Self { name }
+        Self {
+            name,
+            quantity: None,
+            calories: None,
+            macros: None,
+        }
+    }
+
+    /// The name with whatever quantity, calories and macros were given:
+    /// "Oatmeal (1 cup, 350 cal, 12g protein, 60g carbs, 6g fat)".
+    pub fn label(&self) -> String {
+        let mut details = Vec::new();
+        if let Some(quantity) = &self.quantity {
+            details.push(quantity.clone());
+        }
+        if let Some(calories) = self.calories {
+            details.push(format!("{} cal", calories));
+        }
+        if let Some(macros) = self.macros {
+            details.push(macros.describe());
+        }
+        if details.is_empty() {
+            self.name.clone()
+        } else {
+            format!("{} ({})", self.name, details.join(", "))
+        }
     }
 }
 
+/// Grams of protein, carbs and fat in a food as logged.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Macros {
+    pub protein: f32,
+    pub carbs: f32,
+    pub fat: f32,
+}
+
+impl Macros {
+    /// "12g protein, 60g carbs, 6g fat"
+    pub fn describe(&self) -> String {
+        format!(
+            "{:.0}g protein, {:.0}g carbs, {:.0}g fat",
+            self.protein, self.carbs, self.fat
+        )
+    }
+}
+
+/// One food's past from the food lookup: every day it was logged, newest
+/// first.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FoodHistory {
+    /// Spelling from the most recent entry.
+    pub name: String,
+    pub dates: Vec<NaiveDate>,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum MeasurementField {
     Weight,
     Waist,
+    Sleep,
+    RestingHr,
+    Hrv,
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum RunningField {
     Miles,
     Elevation,
+    Duration,
+    StartTime,
+    Rpe,
+    AvgHr,
+    MaxHr,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -86,14 +290,48 @@ pub enum FocusedSection {
 }
 
 /// Target for delete confirmation dialogs
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum DeleteTarget {
     Day,
     Food(usize),
     Sokay(usize),
 }
 
-#[derive(Debug, Clone)]
+/// The two one-line reflections a day holds beside its notes, each with
+/// its own quick-entry box.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Reflection {
+    Intention,
+    Gratitude,
+}
+
+impl Reflection {
+    pub fn label(self) -> &'static str {
+        match self {
+            Reflection::Intention => "Intention",
+            Reflection::Gratitude => "Grateful For",
+        }
+    }
+
+    pub fn get(self, log: &DailyLog) -> Option<&str> {
+        match self {
+            Reflection::Intention => log.intention.as_deref(),
+            Reflection::Gratitude => log.gratitude.as_deref(),
+        }
+    }
+
+    /// Sets it on `log`; blank clears it.
+    pub fn set(self, log: &mut DailyLog, text: &str) {
+        let text = text.trim();
+        let value = (!text.is_empty()).then(|| text.to_string());
+        match self {
+            Reflection::Intention => log.intention = value,
+            Reflection::Gratitude => log.gratitude = value,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub enum AppScreen {
     Startup,
     Statistics,
@@ -105,10 +343,95 @@ pub enum AppScreen {
     EditSokay(usize),
     InputField(field_accessor::FieldType),
     ConfirmDelete(DeleteTarget),
+    /// A value that passed parsing but looks implausible, awaiting y/n.
+    ConfirmValue(field_accessor::FieldType),
     ShortcutsHelp,
     DateInput,
+    /// The `g` prompt on the daily view, taking fuzzy dates.
+    JumpToDate,
+    /// The `/` filter prompt over the logged days list.
+    HomeFilter,
+    /// The `V` menu of saved filters over the logged days list.
+    HomeViews,
+    /// The `/` search of past food entries over the daily view.
+    FoodLookup,
+    /// Month-grid date picker over the logged days list.
+    Calendar,
     Syncing,
     ConfigSync,
+    Heatmap,
+    /// Twelve mini month calendars colored by vert.
+    Year,
+    Charts,
+    Week,
+    Month,
+    /// The F1 manual of screens, keys and config options.
+    Manual,
+    /// Display settings, previewed live over the startup screen.
+    Settings,
+    /// Days whose save failed for good, to retry or export.
+    SaveErrors,
+    /// Every piece of gear with its distance; also where gear is added and
+    /// retired.
+    Gear,
+    /// Choosing the gear for the selected day's run.
+    GearPicker,
+    /// Typing the selected day's route, with routes run before offered.
+    EditRoute,
+    /// Every route run, with how often and how much vert.
+    Routes,
+    /// Every exercise logged in sets, with a chart of how it's come along.
+    Strength,
+    /// Typing the selected day's morning intention or evening gratitude.
+    EditReflection(Reflection),
+    /// Typing the path of a meal or receipt photo to read food from.
+    OcrImage,
+    /// Picking which lines read off the photo to log as food.
+    OcrReview,
+    /// The longest days and the days with the most vert.
+    BestEfforts,
+    /// Goals with their progress, added and edited in place.
+    Goals,
+    /// Training phases with their totals, marked and changed in place.
+    Phases,
+    /// Injuries and niggles, logged, resolved and changed in place.
+    Injuries,
+    /// Choosing a range of days, then filling each in turn.
+    Backfill,
+}
+
+impl AppScreen {
+    /// Screens driven by single-key commands, as opposed to text entry and
+    /// y/n prompts. Only these see keymap preset swaps. Gear, goals, phases
+    /// and injuries also type names and drafts in place; the app leaves
+    /// keys alone while they do.
+    pub fn takes_commands(&self) -> bool {
+        matches!(
+            self,
+            AppScreen::Startup
+                | AppScreen::Statistics
+                | AppScreen::Home
+                | AppScreen::DailyView
+                | AppScreen::ShortcutsHelp
+                | AppScreen::Heatmap
+                | AppScreen::Year
+                | AppScreen::Charts
+                | AppScreen::Week
+                | AppScreen::Month
+                | AppScreen::Manual
+                | AppScreen::Settings
+                | AppScreen::Calendar
+                | AppScreen::SaveErrors
+                | AppScreen::Routes
+                | AppScreen::Strength
+                | AppScreen::BestEfforts
+                | AppScreen::Gear
+                | AppScreen::GearPicker
+                | AppScreen::Goals
+                | AppScreen::Phases
+                | AppScreen::Injuries
+        )
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -118,11 +441,37 @@ pub enum ConfigSyncField {
     EnableToggle,
 }
 
+/// Short-lived message drawn over whatever screen is showing.
+#[derive(Debug, Clone)]
+pub struct Toast {
+    pub message: String,
+    pub expires_at: std::time::Instant,
+}
+
+impl Toast {
+    const DURATION: std::time::Duration = std::time::Duration::from_secs(4);
+
+    pub fn new(message: String) -> Self {
+        Self {
+            message,
+            expires_at: std::time::Instant::now() + Self::DURATION,
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        std::time::Instant::now() >= self.expires_at
+    }
+}
+
 #[derive(Debug)]
 pub struct AppState {
     pub current_screen: AppScreen,
     pub selected_date: NaiveDate,
-    pub daily_logs: Vec<DailyLog>,
+    /// Newest first. Private so every mutation goes through the methods below,
+    /// which keep `log_index` and `stats` in step.
+    daily_logs: Vec<DailyLog>,
+    /// Date -> position in `daily_logs`, for per-frame lookups.
+    log_index: BTreeMap<NaiveDate, usize>,
     pub focused_section: FocusedSection,
     pub food_list_focused: bool,
     pub sokay_list_focused: bool,
@@ -131,6 +480,57 @@ pub struct AppState {
     pub date_input_error: Option<String>,
     pub config_sync_focused_field: ConfigSyncField,
     pub config_sync_status: Option<String>,
+    /// Why the value being confirmed on `ConfirmValue` looks wrong.
+    pub value_warning: Option<String>,
+    pub toast: Option<Toast>,
+    /// Startup warning about stale syncs or backups, until dismissed with x.
+    pub health_banner: Option<String>,
+    /// `/` query and sort order for the logged days list.
+    pub home_filter: home_filter::HomeFilter,
+    /// Months folded to just their header on the logged days list.
+    pub collapsed_months: HashSet<NaiveDate>,
+    /// Matches for the food lookup's query, most recently eaten first.
+    pub food_history: Vec<FoodHistory>,
+    /// Period totals for the render path; cleared whenever `daily_logs` changes.
+    pub stats: crate::stats_cache::StatsCache,
+    /// Foods logged before, for Add Food's suggestions; cleared along with
+    /// `stats`.
+    pub food_index: food_index::FoodIndex,
+    /// Units values are shown and entered in.
+    pub units: crate::format::Units,
+    /// Colors every screen draws with.
+    pub theme: crate::ui::theme::Theme,
+    /// Spell out focus and selection in text for screen readers.
+    pub screen_reader: bool,
+    /// `[[computed.fields]]` formulas for the daily view's Computed section,
+    /// compiled when the config loads.
+    pub computed: crate::computed::Formulas,
+    /// Cloud sync is set up; without it the startup screen says logs stay
+    /// on this computer.
+    pub sync_configured: bool,
+    /// `[food] calorie_target`, for the daily view's calorie gauge.
+    pub calorie_target: Option<u32>,
+    /// `[goals]`, for the week view's gauge.
+    pub goals: crate::config::GoalsConfig,
+    /// `[heart_rate] zones`, sorted, for the week view's time in zone.
+    pub hr_zones: Vec<u32>,
+    /// `[notes] word_goal`, for the Notes section's word count.
+    pub notes_word_goal: Option<u32>,
+    /// Saves that failed for good, oldest first, one per day.
+    pub failed_saves: Vec<crate::save_errors::FailedSave>,
+    /// Every piece of gear, retired or not, as stored in the `gear` table.
+    pub gear: Vec<crate::gear::Gear>,
+    /// `[gear] retire_after`, in stored miles; 0 turns the warning off.
+    pub gear_retire_after: f32,
+    /// Goals from the goals screen, paused or not, as stored in the `goals`
+    /// table.
+    pub goal_list: Vec<crate::goals::Goal>,
+    /// Training phases, earliest first, as stored in the `phases` table.
+    pub phases: Vec<crate::phases::Phase>,
+    /// Injuries, latest first, as stored in the `injuries` table.
+    pub injuries: Vec<crate::injuries::Injury>,
+    /// The imported training plan, by day.
+    pub plan: BTreeMap<NaiveDate, crate::plan::PlannedWorkout>,
     /// Last rendered frame size, used to bound multi-line section scrolling.
     pub frame_width: u16,
     pub frame_height: u16,
@@ -142,6 +542,7 @@ impl AppState {
             current_screen: AppScreen::Startup,
             selected_date: chrono::Local::now().date_naive(),
             daily_logs: Vec::new(),
+            log_index: BTreeMap::new(),
             focused_section: FocusedSection::Measurements {
                 focused_field: MeasurementField::Weight,
             },
@@ -152,25 +553,299 @@ impl AppState {
             date_input_error: None,
             config_sync_focused_field: ConfigSyncField::DbUrl,
             config_sync_status: None,
+            value_warning: None,
+            toast: None,
+            health_banner: None,
+            home_filter: home_filter::HomeFilter::default(),
+            collapsed_months: HashSet::new(),
+            food_history: Vec::new(),
+            stats: crate::stats_cache::StatsCache::default(),
+            food_index: food_index::FoodIndex::default(),
+            units: crate::format::Units::default(),
+            theme: crate::ui::theme::Theme::default(),
+            screen_reader: false,
+            computed: crate::computed::Formulas::default(),
+            sync_configured: false,
+            calorie_target: None,
+            goals: Default::default(),
+            notes_word_goal: None,
+            hr_zones: Vec::new(),
+            failed_saves: Vec::new(),
+            gear: Vec::new(),
+            goal_list: Vec::new(),
+            phases: Vec::new(),
+            injuries: Vec::new(),
+            plan: BTreeMap::new(),
+            gear_retire_after: 0.0,
             frame_width: 0,
             frame_height: 0,
         }
     }
 
     pub fn get_or_create_daily_log(&mut self, date: NaiveDate) -> &mut DailyLog {
-        if let Some(pos) = self.daily_logs.iter().position(|log| log.date == date) {
-            &mut self.daily_logs[pos]
+        if !self.log_index.contains_key(&date) {
+            self.insert_daily_log(DailyLog::new(date));
+        }
+        self.daily_log_mut(date)
+            .expect("log was just inserted for this date")
+    }
+
+    pub fn show_toast(&mut self, message: String) {
+        self.toast = Some(Toast::new(message));
+    }
+
+    /// Miles a week to aim for: `[goals] weekly_miles`, or else the first
+    /// active weekly goal from the goals screen.
+    pub fn weekly_miles_goal(&self) -> Option<f32> {
+        self.goals
+            .weekly_miles
+            .or_else(|| {
+                self.goal_list
+                    .iter()
+                    .find(|goal| goal.active && goal.kind == crate::goals::GoalKind::WeeklyMiles)
+                    .map(|goal| goal.target as f32)
+            })
+            .filter(|&miles| miles > 0.0)
+    }
+
+    /// Drops the toast once its display time has passed.
+    pub fn clear_expired_toast(&mut self) {
+        if self.toast.as_ref().is_some_and(Toast::is_expired) {
+            self.toast = None;
+        }
+    }
+
+    /// Every day that counts, newest first. Archived days are kept after
+    /// these, so they stay out of the lists and stats.
+    pub fn daily_logs(&self) -> &[DailyLog] {
+        &self.daily_logs[..self.daily_logs.partition_point(|log| !log.archived)]
+    }
+
+    /// Every log, archived ones last; what `HomeRow::Day` positions index.
+    pub fn all_daily_logs(&self) -> &[DailyLog] {
+        &self.daily_logs
+    }
+
+    /// Positions in `all_daily_logs` of the rows on the logged days list,
+    /// after its filter and sort.
+    pub fn home_rows(&self) -> Vec<usize> {
+        self.home_filter.rows(
+            &self.daily_logs,
+            self.units.system,
+            self.stats.config().vert_threshold,
+        )
+    }
+
+    /// The logged days list as shown: month headers and the days of
+    /// expanded months, or just the days when sorted by distance.
+    pub fn home_list(&self) -> Vec<home_filter::HomeRow> {
+        let rows = self.home_rows();
+        if self.home_filter.sort.groups_by_month() {
+            home_filter::group_by_month(&rows, &self.daily_logs, &self.collapsed_months)
         } else {
-            self.daily_logs.push(DailyLog::new(date));
-            self.daily_logs.sort_by(|a, b| b.date.cmp(&a.date));
-            self.daily_logs
-                .iter_mut()
-                .find(|log| log.date == date)
-                .unwrap()
+            rows.into_iter().map(home_filter::HomeRow::Day).collect()
+        }
+    }
+
+    /// The log shown on row `row` of the logged days list, if it's a day.
+    pub fn home_log(&self, row: usize) -> Option<&DailyLog> {
+        match self.home_list().get(row) {
+            Some(&home_filter::HomeRow::Day(pos)) => self.daily_logs.get(pos),
+            _ => None,
+        }
+    }
+
+    /// Collapses `month` on the logged days list, or expands it again.
+    pub fn toggle_month(&mut self, month: NaiveDate) {
+        if !self.collapsed_months.remove(&month) {
+            self.collapsed_months.insert(month);
+        }
+    }
+
+    /// Adds a log in newest-first position, replacing any log for the same date.
+    pub fn insert_daily_log(&mut self, log: DailyLog) {
+        self.stats.invalidate();
+        self.food_index.invalidate();
+        if let Some(&pos) = self.log_index.get(&log.date) {
+            if self.daily_logs[pos].archived == log.archived {
+                self.daily_logs[pos] = log;
+                return;
+            }
+            // Moving between the counted and archived runs of the list
+            self.remove_daily_log(log.date);
+        }
+        let key = log_order(&log);
+        let pos = self
+            .daily_logs
+            .partition_point(|existing| log_order(existing) < key);
+        self.daily_logs.insert(pos, log);
+        self.reindex_from(pos);
+    }
+
+    /// Archives the day at `date`, or brings it back, returning it to save.
+    pub fn set_archived(&mut self, date: NaiveDate, archived: bool) -> Option<DailyLog> {
+        let mut log = self.get_daily_log(date)?.clone();
+        log.archived = archived;
+        self.insert_daily_log(log.clone());
+        Some(log)
+    }
+
+    /// Replaces every log, e.g. after loading or re-reading the database.
+    pub fn set_daily_logs(&mut self, mut logs: Vec<DailyLog>) {
+        self.stats.invalidate();
+        self.food_index.invalidate();
+        logs.sort_by_key(|log| std::cmp::Reverse(log.date));
+        logs.dedup_by_key(|log| log.date);
+        logs.sort_by_key(|log| log.archived);
+        self.daily_logs = logs;
+        self.log_index.clear();
+        self.reindex_from(0);
+    }
+
+    pub fn remove_daily_log(&mut self, date: NaiveDate) {
+        if let Some(pos) = self.log_index.remove(&date) {
+            self.stats.invalidate();
+            self.food_index.invalidate();
+            self.daily_logs.remove(pos);
+            self.reindex_from(pos);
         }
     }
 
+    /// Mutable access to an existing log; callers are assumed to modify it.
+    pub fn daily_log_mut(&mut self, date: NaiveDate) -> Option<&mut DailyLog> {
+        let pos = *self.log_index.get(&date)?;
+        self.stats.invalidate();
+        self.food_index.invalidate();
+        self.daily_logs.get_mut(pos)
+    }
+
     pub fn get_daily_log(&self, date: NaiveDate) -> Option<&DailyLog> {
-        self.daily_logs.iter().find(|log| log.date == date)
+        self.log_index
+            .get(&date)
+            .and_then(|&pos| self.daily_logs.get(pos))
+    }
+
+    /// The log for `date` unless it's archived, for the calendars and
+    /// summaries that archived days stay out of.
+    pub fn counted_daily_log(&self, date: NaiveDate) -> Option<&DailyLog> {
+        self.get_daily_log(date).filter(|log| !log.archived)
+    }
+
+    /// Refreshes index entries for every log at or after `start`, whose
+    /// positions shift on insert/remove.
+    fn reindex_from(&mut self, start: usize) {
+        for (pos, log) in self.daily_logs.iter().enumerate().skip(start) {
+            self.log_index.insert(log.date, pos);
+        }
+    }
+}
+
+/// Where a log sits in `AppState`: counted days first, each run newest first.
+fn log_order(log: &DailyLog) -> (bool, std::cmp::Reverse<NaiveDate>) {
+    (log.archived, std::cmp::Reverse(log.date))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn food_labels_and_calorie_totals_skip_what_is_missing() {
+        let mut log = DailyLog::new(NaiveDate::from_ymd_opt(2026, 7, 1).unwrap());
+        log.add_food_entry(FoodEntry::new("Toast".to_string()));
+        assert_eq!(log.calories(), None);
+
+        log.add_food_entry(FoodEntry {
+            name: "Banana".to_string(),
+            quantity: None,
+            calories: Some(105),
+            macros: None,
+        });
+        log.add_food_entry(FoodEntry {
+            name: "Rice".to_string(),
+            quantity: Some("2 cups".to_string()),
+            calories: Some(400),
+            macros: Some(Macros {
+                protein: 8.4,
+                carbs: 88.0,
+                fat: 0.6,
+            }),
+        });
+        assert_eq!(log.food_entries[0].label(), "Toast");
+        assert_eq!(log.food_entries[1].label(), "Banana (105 cal)");
+        assert_eq!(
+            log.food_entries[2].label(),
+            "Rice (2 cups, 400 cal, 8g protein, 88g carbs, 1g fat)"
+        );
+        assert_eq!(log.calories(), Some(505));
+    }
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 7, d).unwrap()
+    }
+
+    fn dates(state: &AppState) -> Vec<u32> {
+        state
+            .daily_logs()
+            .iter()
+            .map(|log| chrono::Datelike::day(&log.date))
+            .collect()
+    }
+
+    #[test]
+    fn index_tracks_inserts_and_removals() {
+        let mut state = AppState::new();
+        state.set_daily_logs(vec![DailyLog::new(day(3)), DailyLog::new(day(9))]);
+        state.insert_daily_log(DailyLog::new(day(5)));
+        state.get_or_create_daily_log(day(12)).weight = Some(170.0);
+        state.get_or_create_daily_log(day(1));
+        assert_eq!(dates(&state), [12, 9, 5, 3, 1]);
+
+        state.remove_daily_log(day(9));
+        assert_eq!(dates(&state), [12, 5, 3, 1]);
+        for d in [12, 5, 3, 1] {
+            assert_eq!(state.get_daily_log(day(d)).map(|log| log.date), Some(day(d)));
+        }
+        assert!(state.get_daily_log(day(9)).is_none());
+        assert_eq!(state.get_daily_log(day(12)).unwrap().weight, Some(170.0));
+    }
+
+    #[test]
+    fn inserting_an_existing_date_replaces_it() {
+        let mut state = AppState::new();
+        state.insert_daily_log(DailyLog::new(day(4)));
+        state.insert_daily_log(DailyLog {
+            notes: Some("second".into()),
+            ..DailyLog::new(day(4))
+        });
+        assert_eq!(dates(&state), [4]);
+        assert_eq!(state.get_daily_log(day(4)).unwrap().notes.as_deref(), Some("second"));
+    }
+
+    #[test]
+    fn archived_days_drop_out_of_daily_logs_but_stay_reachable() {
+        let mut state = AppState::new();
+        state.set_daily_logs(vec![
+            DailyLog::new(day(3)),
+            DailyLog {
+                archived: true,
+                ..DailyLog::new(day(9))
+            },
+            DailyLog::new(day(5)),
+        ]);
+        assert_eq!(dates(&state), [5, 3]);
+        assert_eq!(state.all_daily_logs().len(), 3);
+
+        state.set_archived(day(5), true);
+        state.get_or_create_daily_log(day(7));
+        assert_eq!(dates(&state), [7, 3]);
+        assert!(state.get_daily_log(day(5)).unwrap().archived);
+
+        let log = state.set_archived(day(9), false).unwrap();
+        assert!(!log.archived);
+        assert_eq!(dates(&state), [9, 7, 3]);
+        for d in [9, 7, 5, 3] {
+            assert_eq!(state.get_daily_log(day(d)).map(|log| log.date), Some(day(d)));
+        }
     }
 }
@@ -0,0 +1,82 @@
+//! What kind of training a day was, so mileage can be split by sport.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ActivityType {
+    Run,
+    Hike,
+    Bike,
+    Ski,
+    /// A gym or mobility day with no distance worth counting.
+    Strength,
+}
+
+impl ActivityType {
+    pub const ALL: [ActivityType; 5] = [
+        ActivityType::Run,
+        ActivityType::Hike,
+        ActivityType::Bike,
+        ActivityType::Ski,
+        ActivityType::Strength,
+    ];
+
+    /// Stored in the database and typed in `type:` filters.
+    pub fn name(self) -> &'static str {
+        match self {
+            ActivityType::Run => "run",
+            ActivityType::Hike => "hike",
+            ActivityType::Bike => "bike",
+            ActivityType::Ski => "ski",
+            ActivityType::Strength => "strength",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ActivityType::Run => "Run",
+            ActivityType::Hike => "Hike",
+            ActivityType::Bike => "Bike",
+            ActivityType::Ski => "Ski",
+            ActivityType::Strength => "Strength",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|kind| kind.name().eq_ignore_ascii_case(name.trim()))
+    }
+
+    /// The type after `current` for the daily view's `A`, running through
+    /// every type and then back to untagged.
+    pub fn cycle(current: Option<Self>) -> Option<Self> {
+        match current {
+            None => Some(Self::ALL[0]),
+            Some(kind) => {
+                let index = Self::ALL.iter().position(|k| *k == kind).unwrap_or(0);
+                Self::ALL.get(index + 1).copied()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cycles_through_every_type_and_back_to_untagged() {
+        let mut kind = None;
+        let mut seen = Vec::new();
+        for _ in 0..ActivityType::ALL.len() {
+            kind = ActivityType::cycle(kind);
+            seen.push(kind.unwrap());
+        }
+        assert_eq!(seen, ActivityType::ALL);
+        assert_eq!(ActivityType::cycle(kind), None);
+        assert_eq!(ActivityType::from_name(" HIKE"), Some(ActivityType::Hike));
+        assert_eq!(ActivityType::from_name("swim"), None);
+    }
+}
@@ -0,0 +1,286 @@
+use chrono::{Days, NaiveDate};
+
+use crate::date_parse::parse_fuzzy_date;
+use crate::format::Units;
+use crate::models::DailyLog;
+use crate::units::Quantity;
+
+/// Longest range the backfill steps through, so a typo'd year doesn't
+/// queue up a decade of days.
+pub const MAX_BACKFILL_DAYS: i64 = 92;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BackfillField {
+    #[default]
+    From,
+    To,
+    Miles,
+    Vert,
+    Weight,
+    Note,
+}
+
+impl BackfillField {
+    pub const RANGE: [BackfillField; 2] = [BackfillField::From, BackfillField::To];
+    pub const DAY: [BackfillField; 4] = [
+        BackfillField::Miles,
+        BackfillField::Vert,
+        BackfillField::Weight,
+        BackfillField::Note,
+    ];
+
+    /// The fields on the same step as this one.
+    fn step(self) -> &'static [BackfillField] {
+        match self {
+            BackfillField::From | BackfillField::To => &Self::RANGE,
+            _ => &Self::DAY,
+        }
+    }
+
+    pub fn next(self) -> Self {
+        let fields = self.step();
+        let index = fields.iter().position(|&field| field == self).unwrap_or(0);
+        fields[(index + 1) % fields.len()]
+    }
+
+    pub fn previous(self) -> Self {
+        let fields = self.step();
+        let index = fields.iter().position(|&field| field == self).unwrap_or(0);
+        fields[(index + fields.len() - 1) % fields.len()]
+    }
+}
+
+/// The backfill wizard: a range of days, then a condensed form for each day
+/// in turn. Like the food form, the focused field's text lives in the input
+/// buffer while it's being edited.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Backfill {
+    pub field: BackfillField,
+    pub from: String,
+    pub to: String,
+    /// The first and last days, once chosen.
+    pub range: Option<(NaiveDate, NaiveDate)>,
+    /// The day being filled in.
+    pub day: Option<NaiveDate>,
+    pub miles: String,
+    pub vert: String,
+    pub weight: String,
+    pub note: String,
+    /// Set when the day's notes run to more than one line, so they aren't
+    /// put in the one-line field and a typed note is added under them.
+    pub long_notes: bool,
+    /// Days saved so far.
+    pub saved: usize,
+    pub error: Option<String>,
+    /// Miles, vert and weight as loaded. A field still showing its loaded
+    /// text keeps the stored value, which in metric the text rounds.
+    loaded: [String; 3],
+}
+
+impl Backfill {
+    pub fn value(&self, field: BackfillField) -> &str {
+        match field {
+            BackfillField::From => &self.from,
+            BackfillField::To => &self.to,
+            BackfillField::Miles => &self.miles,
+            BackfillField::Vert => &self.vert,
+            BackfillField::Weight => &self.weight,
+            BackfillField::Note => &self.note,
+        }
+    }
+
+    pub fn set_value(&mut self, field: BackfillField, value: String) {
+        match field {
+            BackfillField::From => self.from = value,
+            BackfillField::To => self.to = value,
+            BackfillField::Miles => self.miles = value,
+            BackfillField::Vert => self.vert = value,
+            BackfillField::Weight => self.weight = value,
+            BackfillField::Note => self.note = value,
+        }
+    }
+
+    /// Reads the range, anything `parse_fuzzy_date` takes. A blank end is
+    /// today. The first day is returned to fill in.
+    pub fn choose_range(&mut self, today: NaiveDate) -> Result<NaiveDate, String> {
+        let from = parse_fuzzy_date(&self.from, today)
+            .ok_or_else(|| format!("Unrecognized date '{}'", self.from.trim()))?;
+        let to = if self.to.trim().is_empty() {
+            today
+        } else {
+            parse_fuzzy_date(&self.to, today)
+                .ok_or_else(|| format!("Unrecognized date '{}'", self.to.trim()))?
+        };
+        if to > today {
+            return Err("Future dates not allowed".to_string());
+        }
+        if from > to {
+            return Err("The range ends before it starts".to_string());
+        }
+        if (to - from).num_days() >= MAX_BACKFILL_DAYS {
+            return Err(format!("At most {} days at a time", MAX_BACKFILL_DAYS));
+        }
+        self.range = Some((from, to));
+        self.day = Some(from);
+        Ok(from)
+    }
+
+    /// Fills the day's fields from what's logged for it, if anything.
+    pub fn load(&mut self, log: Option<&DailyLog>, units: Units) {
+        let amount = |quantity, value: Option<f32>| {
+            value.map(|value| units.amount(quantity, value)).unwrap_or_default()
+        };
+        self.miles = amount(Quantity::Distance, log.and_then(|log| log.miles_covered));
+        self.vert = amount(
+            Quantity::Elevation,
+            log.and_then(|log| log.elevation_gain).map(|feet| feet as f32),
+        );
+        self.weight = amount(Quantity::Weight, log.and_then(|log| log.weight));
+        self.loaded = [self.miles.clone(), self.vert.clone(), self.weight.clone()];
+        let notes = log.and_then(|log| log.notes.as_deref()).unwrap_or_default();
+        self.long_notes = notes.contains('\n');
+        self.note = if self.long_notes { String::new() } else { notes.to_string() };
+    }
+
+    /// Puts the typed values on `log`, in display units; a blank field
+    /// clears its value and an untouched one leaves it as stored. Errors
+    /// name the field that didn't read.
+    pub fn apply(&self, log: &mut DailyLog, units: Units) -> Result<(), String> {
+        let read = |quantity, text: &str, name: &str| -> Result<Option<f64>, String> {
+            if text.trim().is_empty() {
+                return Ok(None);
+            }
+            units
+                .parse(quantity, text)
+                .filter(|&value| value >= 0.0)
+                .map(Some)
+                .ok_or_else(|| format!("'{}' isn't a {}", text.trim(), name))
+        };
+        let miles = read(Quantity::Distance, &self.miles, "distance")?;
+        let vert = read(Quantity::Elevation, &self.vert, "vert")?;
+        let weight = read(Quantity::Weight, &self.weight, "weight")?;
+        let [loaded_miles, loaded_vert, loaded_weight] = &self.loaded;
+        if self.miles != *loaded_miles {
+            log.miles_covered = miles.map(|miles| miles as f32);
+        }
+        if self.vert != *loaded_vert {
+            log.elevation_gain = vert.map(|feet| feet.round() as i32);
+        }
+        if self.weight != *loaded_weight {
+            log.weight = weight.map(|weight| weight as f32);
+        }
+
+        let note = self.note.trim();
+        if self.long_notes {
+            if !note.is_empty()
+                && let Some(notes) = &mut log.notes
+            {
+                notes.push('\n');
+                notes.push_str(note);
+            }
+        } else {
+            log.notes = (!note.is_empty()).then(|| note.to_string());
+        }
+        Ok(())
+    }
+
+    /// `day`'s log with the typed values, or None when they match what's
+    /// logged (or leave an unlogged day blank).
+    pub fn changes(
+        &self,
+        day: NaiveDate,
+        existing: Option<&DailyLog>,
+        units: Units,
+    ) -> Result<Option<DailyLog>, String> {
+        let mut log = existing.cloned().unwrap_or_else(|| DailyLog::new(day));
+        self.apply(&mut log, units)?;
+        let fields = |log: &DailyLog| {
+            (log.miles_covered, log.elevation_gain, log.weight, log.notes.clone())
+        };
+        let before = existing.map(fields).unwrap_or_default();
+        Ok((fields(&log) != before).then_some(log))
+    }
+
+    /// Moves on to the next day, or None after the last one.
+    pub fn advance(&mut self) -> Option<NaiveDate> {
+        let (_, to) = self.range?;
+        let next = self.day?.checked_add_days(Days::new(1)).filter(|&next| next <= to)?;
+        self.day = Some(next);
+        Some(next)
+    }
+
+    /// Which day of how many is being filled in: (3, 14).
+    pub fn position(&self) -> Option<(i64, i64)> {
+        let (from, to) = self.range?;
+        Some(((self.day? - from).num_days() + 1, (to - from).num_days() + 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::UnitSystem;
+
+    #[test]
+    fn steps_through_the_range_filling_each_day() {
+        let today = NaiveDate::from_ymd_opt(2026, 7, 20).unwrap();
+        let units = Units::from(UnitSystem::Imperial);
+        let mut backfill = Backfill {
+            from: "2026-07-18".to_string(),
+            ..Backfill::default()
+        };
+        assert_eq!(backfill.choose_range(today), Ok(today - Days::new(2)));
+        assert_eq!(backfill.position(), Some((1, 3)));
+
+        let mut log = DailyLog {
+            notes: Some("Sore calves\nIced after".to_string()),
+            weight: Some(172.0),
+            ..DailyLog::new(today - Days::new(2))
+        };
+        backfill.load(Some(&log), units);
+        assert_eq!((backfill.weight.as_str(), backfill.note.as_str()), ("172", ""));
+        backfill.set_value(BackfillField::Miles, "8.5".to_string());
+        backfill.set_value(BackfillField::Vert, "2100".to_string());
+        backfill.set_value(BackfillField::Note, "Hut to hut".to_string());
+        backfill.apply(&mut log, units).unwrap();
+        assert_eq!((log.miles_covered, log.elevation_gain), (Some(8.5), Some(2100)));
+        assert_eq!(log.notes.as_deref(), Some("Sore calves\nIced after\nHut to hut"));
+
+        // Loaded back as saved, nothing has changed
+        backfill.load(Some(&log), units);
+        assert!(matches!(backfill.changes(log.date, Some(&log), units), Ok(None)));
+        backfill.set_value(BackfillField::Weight, "heavy".to_string());
+        assert_eq!(backfill.apply(&mut log, units), Err("'heavy' isn't a weight".to_string()));
+
+        // In metric the fields show rounded numbers; left alone, the day
+        // isn't rewritten with them
+        let metric = Units::from(UnitSystem::Metric);
+        let log = DailyLog {
+            miles_covered: Some(10.0),
+            elevation_gain: Some(2100),
+            weight: Some(172.0),
+            ..log
+        };
+        backfill.load(Some(&log), metric);
+        assert_eq!((backfill.miles.as_str(), backfill.weight.as_str()), ("16.1", "78"));
+        assert!(matches!(backfill.changes(log.date, Some(&log), metric), Ok(None)));
+        backfill.set_value(BackfillField::Miles, "20".to_string());
+        let changed = backfill.changes(log.date, Some(&log), metric).unwrap().unwrap();
+        assert_eq!(changed.weight, Some(172.0));
+        assert!((changed.miles_covered.unwrap() - 12.43).abs() < 0.01);
+
+        assert_eq!(backfill.advance(), Some(today - Days::new(1)));
+        assert_eq!(backfill.advance(), Some(today));
+        assert_eq!(backfill.position(), Some((3, 3)));
+        assert_eq!(backfill.advance(), None);
+
+        let mut backwards = Backfill {
+            from: "2026-07-19".to_string(),
+            to: "2026-07-10".to_string(),
+            ..Backfill::default()
+        };
+        assert!(backwards.choose_range(today).is_err());
+        assert_eq!(BackfillField::Note.next(), BackfillField::Miles);
+        assert_eq!(BackfillField::From.previous(), BackfillField::To);
+    }
+}
@@ -1,4 +1,16 @@
+use crate::events::handlers::InputHandler;
 use crate::models::{AppState, DailyLog};
+use crate::units::Quantity;
+use chrono::NaiveDate;
+
+/// Previous values offered below the weight/waist input.
+pub const HISTORY_LEN: usize = 5;
+
+/// Most sleep a night can hold; anything over is a typo.
+pub const MAX_SLEEP_HOURS: f32 = 24.0;
+
+/// Top of the perceived effort scale.
+pub const MAX_RPE: u8 = 10;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum FieldType {
@@ -6,21 +18,129 @@ pub enum FieldType {
     Waist,
     Miles,
     Elevation,
+    /// Hours slept, typed into its own modal rather than in place.
+    Sleep,
+    /// Morning resting heart rate and HRV, each in their own modal too.
+    RestingHr,
+    Hrv,
+    /// Run time as h:mm:ss, in a modal as well.
+    Duration,
+    /// When the run started, as 24-hour h:mm, in a modal too.
+    StartTime,
+    /// Perceived effort of the day's training, 1-10, also in a modal.
+    Rpe,
+    /// The run's average and max heart rate, in modals like resting HR.
+    AvgHr,
+    MaxHr,
     StrengthMobility,
     Notes,
 }
 
 impl FieldType {
-    /// Gets the current value of this field as a String
+    /// Single-value numeric fields, edited in place in their section row.
+    pub fn is_numeric(&self) -> bool {
+        matches!(
+            self,
+            FieldType::Weight | FieldType::Waist | FieldType::Miles | FieldType::Elevation
+        )
+    }
+
+    /// Up/Down scrub step and display decimals for numeric fields; `large` is
+    /// the Shift step.
+    pub fn scrub_step(&self, large: bool) -> Option<(f64, usize)> {
+        let (step, decimals) = match self {
+            FieldType::Weight | FieldType::Waist | FieldType::Miles => (0.1, 1),
+            FieldType::Elevation => (10.0, 0),
+            FieldType::Sleep => (0.25, 2),
+            FieldType::RestingHr
+            | FieldType::Hrv
+            | FieldType::Rpe
+            | FieldType::AvgHr
+            | FieldType::MaxHr => (1.0, 0),
+            FieldType::Duration
+            | FieldType::StartTime
+            | FieldType::StrengthMobility
+            | FieldType::Notes => return None,
+        };
+        Some((if large { step * 10.0 } else { step }, decimals))
+    }
+
+    /// Fields that offer their recent values while being edited.
+    pub fn shows_history(&self) -> bool {
+        matches!(self, FieldType::Weight | FieldType::Waist)
+    }
+
+    /// The field's value in `log` as a number, for the numeric fields.
+    pub fn numeric_value(&self, log: &DailyLog) -> Option<f32> {
+        match self {
+            FieldType::Weight => log.weight,
+            FieldType::Waist => log.waist,
+            FieldType::Miles => log.miles_covered,
+            FieldType::Elevation => log.elevation_gain.map(|e| e as f32),
+            FieldType::Sleep => log.sleep_hours,
+            FieldType::RestingHr => log.resting_hr.map(|bpm| bpm as f32),
+            FieldType::Hrv => log.hrv.map(|ms| ms as f32),
+            FieldType::Rpe => log.rpe.map(f32::from),
+            FieldType::AvgHr => log.avg_hr.map(|bpm| bpm as f32),
+            FieldType::MaxHr => log.max_hr.map(|bpm| bpm as f32),
+            FieldType::Duration
+            | FieldType::StartTime
+            | FieldType::StrengthMobility
+            | FieldType::Notes => None,
+        }
+    }
+
+    /// What a numeric field measures, for unit labels and conversion.
+    pub fn quantity(&self) -> Option<Quantity> {
+        match self {
+            FieldType::Weight => Some(Quantity::Weight),
+            FieldType::Waist => Some(Quantity::Waist),
+            FieldType::Miles => Some(Quantity::Distance),
+            FieldType::Elevation => Some(Quantity::Elevation),
+            FieldType::Sleep
+            | FieldType::RestingHr
+            | FieldType::Hrv
+            | FieldType::Rpe
+            | FieldType::AvgHr
+            | FieldType::MaxHr
+            | FieldType::Duration
+            | FieldType::StartTime
+            | FieldType::StrengthMobility
+            | FieldType::Notes => None,
+        }
+    }
+
+    /// Up to `HISTORY_LEN` recorded values before `before`, newest first.
+    pub fn history(&self, logs: &[DailyLog], before: NaiveDate) -> Vec<(NaiveDate, f32)> {
+        let mut values: Vec<(NaiveDate, f32)> = logs
+            .iter()
+            .filter(|log| log.date < before)
+            .filter_map(|log| self.numeric_value(log).map(|value| (log.date, value)))
+            .collect();
+        values.sort_by_key(|(date, _)| std::cmp::Reverse(*date));
+        values.truncate(HISTORY_LEN);
+        values
+    }
+
+    /// Gets the current value of this field as a String, in the display units
     pub fn get_value(&self, state: &AppState) -> String {
         if let Some(log) = state.get_daily_log(state.selected_date) {
             match self {
-                FieldType::Weight => log.weight.map(|w| w.to_string()).unwrap_or_default(),
-                FieldType::Waist => log.waist.map(|w| w.to_string()).unwrap_or_default(),
-                FieldType::Miles => log.miles_covered.map(|m| m.to_string()).unwrap_or_default(),
-                FieldType::Elevation => log.elevation_gain.map(|e| e.to_string()).unwrap_or_default(),
-                FieldType::StrengthMobility => log.strength_mobility.clone().unwrap_or_default(),
+                FieldType::StrengthMobility => log.strength_text(state.units).unwrap_or_default(),
                 FieldType::Notes => log.notes.clone().unwrap_or_default(),
+                FieldType::Sleep => log.sleep_hours.map(format_hours).unwrap_or_default(),
+                FieldType::RestingHr => log.resting_hr.map(|bpm| bpm.to_string()).unwrap_or_default(),
+                FieldType::Hrv => log.hrv.map(|ms| ms.to_string()).unwrap_or_default(),
+                FieldType::Rpe => log.rpe.map(|rpe| rpe.to_string()).unwrap_or_default(),
+                FieldType::AvgHr => log.avg_hr.map(|bpm| bpm.to_string()).unwrap_or_default(),
+                FieldType::MaxHr => log.max_hr.map(|bpm| bpm.to_string()).unwrap_or_default(),
+                FieldType::Duration => log.duration_secs.map(format_duration).unwrap_or_default(),
+                FieldType::StartTime => log.start_time.map(format_time_of_day).unwrap_or_default(),
+                _ => self
+                    .numeric_value(log)
+                    .zip(self.quantity())
+                    .map(|(value, quantity)| state.units.amount(quantity, value))
+                    .unwrap_or_default(),
             }
         } else {
             String::new()
@@ -29,43 +149,47 @@ impl FieldType {
 
     /// Updates this field with the provided input and returns the modified log
     pub fn update_value(&self, state: &mut AppState, input: String) -> DailyLog {
+        // Re-saving the value as shown must not nudge the stored number through
+        // a rounding round trip when entering in metric.
+        let unchanged = self.is_numeric() && input == self.get_value(state);
+        let units = state.units;
         let log = state.get_or_create_daily_log(state.selected_date);
+        let stored = self
+            .quantity()
+            .filter(|_| !input.is_empty())
+            .and_then(|quantity| units.parse(quantity, &input));
 
         match self {
-            FieldType::Weight => {
-                log.weight = if input.is_empty() {
-                    None
-                } else {
-                    input.parse().ok()
-                };
+            _ if unchanged => {}
+            FieldType::Weight => log.weight = stored.map(|value| value as f32),
+            FieldType::Waist => log.waist = stored.map(|value| value as f32),
+            FieldType::Miles => log.miles_covered = stored.map(|value| value as f32),
+            FieldType::Elevation => log.elevation_gain = stored.map(|value| value.round() as i32),
+            FieldType::Sleep => {
+                log.sleep_hours = input
+                    .trim()
+                    .parse::<f32>()
+                    .ok()
+                    .filter(|hours| (0.0..=MAX_SLEEP_HOURS).contains(hours));
             }
-            FieldType::Waist => {
-                log.waist = if input.is_empty() {
-                    None
-                } else {
-                    input.parse().ok()
-                };
-            }
-            FieldType::Miles => {
-                log.miles_covered = if input.is_empty() {
-                    None
-                } else {
-                    input.parse().ok()
-                };
-            }
-            FieldType::Elevation => {
-                log.elevation_gain = if input.is_empty() {
-                    None
-                } else {
-                    input.parse().ok()
-                };
+            // Zero is a blank reading, not a heart rate
+            FieldType::RestingHr => log.resting_hr = input.trim().parse().ok().filter(|&bpm| bpm > 0),
+            FieldType::Hrv => log.hrv = input.trim().parse().ok().filter(|&ms| ms > 0),
+            FieldType::AvgHr => log.avg_hr = input.trim().parse().ok().filter(|&bpm| bpm > 0),
+            FieldType::MaxHr => log.max_hr = input.trim().parse().ok().filter(|&bpm| bpm > 0),
+            FieldType::Duration => log.duration_secs = InputHandler::parse_duration(&input),
+            FieldType::StartTime => log.start_time = InputHandler::parse_time_of_day(&input),
+            FieldType::Rpe => {
+                log.rpe = input
+                    .trim()
+                    .parse()
+                    .ok()
+                    .filter(|rpe| (1..=MAX_RPE).contains(rpe));
             }
+            // Lines that name sets are kept as sets, the rest as text
             FieldType::StrengthMobility => {
-                log.strength_mobility = if input.trim().is_empty() {
-                    None
-                } else {
-                    Some(input)
-                };
+                (log.strength_sets, log.strength_mobility) =
+                    crate::strength::split_text(&input, units);
             }
             FieldType::Notes => {
                 log.notes = if input.trim().is_empty() {
@@ -80,6 +204,27 @@ impl FieldType {
     }
 }
 
+/// A time of day as 24-hour "6:05" or "18:30".
+pub fn format_time_of_day(time: chrono::NaiveTime) -> String {
+    time.format("%-H:%M").to_string()
+}
+
+/// A run time as "1:02:03", or "45:30" under an hour.
+pub fn format_duration(seconds: u32) -> String {
+    let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, minutes, seconds)
+    } else {
+        format!("{}:{:02}", minutes, seconds)
+    }
+}
+
+/// Hours without trailing zeros: "7.5", "8", "6.25".
+pub fn format_hours(hours: f32) -> String {
+    let text = format!("{:.2}", hours);
+    text.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -137,9 +282,15 @@ mod tests {
 
         assert_eq!(FieldType::StrengthMobility.get_value(&state), "");
 
-        let exercises = "Pull-ups: 3x8\nPush-ups: 3x15".to_string();
-        FieldType::StrengthMobility.update_value(&mut state, exercises.clone());
-        assert_eq!(FieldType::StrengthMobility.get_value(&state), exercises);
+        // Sets are kept apart from the text and shown first when edited again
+        let exercises = "Hip flow\nPull-ups: 3x8\nPush-ups: 3x15".to_string();
+        let log = FieldType::StrengthMobility.update_value(&mut state, exercises);
+        assert_eq!(log.strength_sets.len(), 2);
+        assert_eq!(log.strength_mobility.as_deref(), Some("Hip flow"));
+        assert_eq!(
+            FieldType::StrengthMobility.get_value(&state),
+            "Pull-ups 3x8\nPush-ups 3x15\nHip flow"
+        );
 
         // Empty/whitespace clears it
         FieldType::StrengthMobility.update_value(&mut state, "   ".to_string());
@@ -157,6 +308,114 @@ mod tests {
         assert_eq!(FieldType::Notes.get_value(&state), note);
     }
 
+    #[test]
+    fn test_sleep_field_accessor() {
+        let mut state = AppState::new();
+
+        assert_eq!(FieldType::Sleep.get_value(&state), "");
+
+        let log = FieldType::Sleep.update_value(&mut state, "7.50".to_string());
+        assert_eq!(log.sleep_hours, Some(7.5));
+        assert_eq!(FieldType::Sleep.get_value(&state), "7.5");
+
+        // More than a day's worth of sleep is a typo and clears the field
+        FieldType::Sleep.update_value(&mut state, "75".to_string());
+        assert_eq!(FieldType::Sleep.get_value(&state), "");
+        assert_eq!(format_hours(8.0), "8");
+        assert_eq!(format_hours(6.25), "6.25");
+    }
+
+    #[test]
+    fn test_heart_rate_field_accessors() {
+        let mut state = AppState::new();
+
+        let log = FieldType::RestingHr.update_value(&mut state, "48".to_string());
+        assert_eq!(log.resting_hr, Some(48));
+        let log = FieldType::Hrv.update_value(&mut state, " 72 ".to_string());
+        assert_eq!(log.hrv, Some(72));
+        assert_eq!(FieldType::Hrv.get_value(&state), "72");
+
+        FieldType::RestingHr.update_value(&mut state, "0".to_string());
+        assert_eq!(FieldType::RestingHr.get_value(&state), "");
+    }
+
+    #[test]
+    fn test_duration_round_trips_as_a_clock_time() {
+        let mut state = AppState::new();
+
+        let log = FieldType::Duration.update_value(&mut state, "1:02:03".to_string());
+        assert_eq!(log.duration_secs, Some(3723));
+        assert_eq!(FieldType::Duration.get_value(&state), "1:02:03");
+        FieldType::Duration.update_value(&mut state, "45:30".to_string());
+        assert_eq!(FieldType::Duration.get_value(&state), "45:30");
+        assert_eq!(format_duration(65), "1:05");
+    }
+
+    #[test]
+    fn test_rpe_stays_on_the_scale() {
+        let mut state = AppState::new();
+
+        let log = FieldType::Rpe.update_value(&mut state, "7".to_string());
+        assert_eq!(log.rpe, Some(7));
+        assert_eq!(FieldType::Rpe.get_value(&state), "7");
+
+        for off_scale in ["0", "11", "7.5"] {
+            let log = FieldType::Rpe.update_value(&mut state, off_scale.to_string());
+            assert_eq!(log.rpe, None);
+        }
+    }
+
+    #[test]
+    fn test_run_heart_rate_ignores_blank_readings() {
+        let mut state = AppState::new();
+        FieldType::AvgHr.update_value(&mut state, "146".to_string());
+        let log = FieldType::MaxHr.update_value(&mut state, "171".to_string());
+        assert_eq!((log.avg_hr, log.max_hr), (Some(146), Some(171)));
+        assert_eq!(FieldType::MaxHr.get_value(&state), "171");
+
+        let log = FieldType::AvgHr.update_value(&mut state, "0".to_string());
+        assert_eq!(log.avg_hr, None);
+    }
+
+    #[test]
+    fn test_history_is_newest_first_before_date() {
+        let day = |d| NaiveDate::from_ymd_opt(2026, 7, d).unwrap();
+        let mut logs: Vec<DailyLog> = (1..=8)
+            .map(|d| DailyLog {
+                weight: Some(170.0 + d as f32),
+                ..DailyLog::new(day(d))
+            })
+            .collect();
+        logs[6].weight = None;
+
+        let history = FieldType::Weight.history(&logs, day(8));
+        let dates: Vec<u32> = history.iter().map(|(date, _)| chrono::Datelike::day(date)).collect();
+        assert_eq!(dates, [6, 5, 4, 3, 2]);
+        assert_eq!(history[0].1, 176.0);
+        assert!(FieldType::Notes.history(&logs, day(8)).is_empty());
+    }
+
+    #[test]
+    fn test_metric_entry_is_stored_imperial() {
+        let mut state = AppState::new();
+        state.units = crate::units::UnitSystem::Metric.into();
+
+        let log = FieldType::Miles.update_value(&mut state, "10".to_string());
+        assert!((log.miles_covered.unwrap() - 6.2137).abs() < 0.001);
+        assert_eq!(FieldType::Miles.get_value(&state), "10");
+
+        let log = FieldType::Elevation.update_value(&mut state, "305".to_string());
+        assert_eq!(log.elevation_gain, Some(1001));
+
+        // Saving the shown value again keeps the stored number as it was
+        state.insert_daily_log(DailyLog {
+            elevation_gain: Some(1000),
+            ..log
+        });
+        let log = FieldType::Elevation.update_value(&mut state, "305".to_string());
+        assert_eq!(log.elevation_gain, Some(1000));
+    }
+
     #[test]
     fn test_invalid_numeric_input() {
         let mut state = AppState::new();
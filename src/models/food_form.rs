@@ -0,0 +1,113 @@
+//! The Add / Edit Food form: a name, and optionally a quantity and calories.
+
+use super::{FoodEntry, Macros};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum FoodField {
+    #[default]
+    Name,
+    Quantity,
+    Calories,
+}
+
+impl FoodField {
+    pub fn next(self) -> Self {
+        match self {
+            FoodField::Name => FoodField::Quantity,
+            FoodField::Quantity => FoodField::Calories,
+            FoodField::Calories => FoodField::Name,
+        }
+    }
+
+    pub fn previous(self) -> Self {
+        match self {
+            FoodField::Name => FoodField::Calories,
+            FoodField::Quantity => FoodField::Name,
+            FoodField::Calories => FoodField::Quantity,
+        }
+    }
+}
+
+/// What's been typed into each field. The focused field's text lives in the
+/// input buffer while it's being edited and is stored back here when focus
+/// moves on.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FoodForm {
+    pub field: FoodField,
+    pub name: String,
+    pub quantity: String,
+    pub calories: String,
+    /// Carried over from the entry or a barcode lookup, not typed.
+    pub macros: Option<Macros>,
+}
+
+impl FoodForm {
+    pub fn from_entry(entry: &FoodEntry) -> Self {
+        Self {
+            field: FoodField::Name,
+            name: entry.name.clone(),
+            quantity: entry.quantity.clone().unwrap_or_default(),
+            calories: entry.calories.map(|c| c.to_string()).unwrap_or_default(),
+            macros: entry.macros,
+        }
+    }
+
+    pub fn value(&self, field: FoodField) -> &str {
+        match field {
+            FoodField::Name => &self.name,
+            FoodField::Quantity => &self.quantity,
+            FoodField::Calories => &self.calories,
+        }
+    }
+
+    pub fn set_value(&mut self, field: FoodField, value: String) {
+        match field {
+            FoodField::Name => self.name = value,
+            FoodField::Quantity => self.quantity = value,
+            FoodField::Calories => self.calories = value,
+        }
+    }
+
+    /// The entry to save, or None without a name. Blank quantity and
+    /// calories are left off, as are calories that aren't a whole number.
+    pub fn entry(&self) -> Option<FoodEntry> {
+        let name = self.name.trim();
+        if name.is_empty() {
+            return None;
+        }
+        let quantity = self.quantity.trim();
+        Some(FoodEntry {
+            name: name.to_string(),
+            quantity: (!quantity.is_empty()).then(|| quantity.to_string()),
+            calories: self.calories.trim().parse().ok(),
+            macros: self.macros,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn form_round_trips_an_entry_and_skips_blanks() {
+        let entry = FoodEntry {
+            name: "Oatmeal".to_string(),
+            quantity: Some("1 cup".to_string()),
+            calories: Some(350),
+            macros: None,
+        };
+        let mut form = FoodForm::from_entry(&entry);
+        assert_eq!(form.value(FoodField::Calories), "350");
+        assert_eq!(form.entry(), Some(entry));
+
+        form.set_value(FoodField::Quantity, "  ".to_string());
+        form.set_value(FoodField::Calories, "lots".to_string());
+        assert_eq!(form.entry(), Some(FoodEntry::new("Oatmeal".to_string())));
+
+        form.set_value(FoodField::Name, " ".to_string());
+        assert_eq!(form.entry(), None);
+        assert_eq!(FoodField::Name.previous(), FoodField::Calories);
+        assert_eq!(FoodField::Calories.next(), FoodField::Name);
+    }
+}
@@ -0,0 +1,97 @@
+//! Foods logged before, for Add Food to suggest while the name is typed.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use super::DailyLog;
+
+/// Every logged food with how many times it was logged, most frequent
+/// first. Entries that differ only in case or surrounding spaces count as
+/// one food, spelled as it was most recently.
+fn food_frequency(logs: &[DailyLog]) -> Vec<(String, usize)> {
+    let mut by_date: Vec<&DailyLog> = logs.iter().collect();
+    by_date.sort_by_key(|log| log.date);
+    let mut foods: HashMap<String, (String, usize)> = HashMap::new();
+    for entry in by_date.iter().flat_map(|log| &log.food_entries) {
+        let name = entry.name.trim();
+        if name.is_empty() {
+            continue;
+        }
+        let food = foods.entry(name.to_lowercase()).or_default();
+        food.0 = name.to_string();
+        food.1 += 1;
+    }
+    let mut foods: Vec<(String, usize)> = foods.into_values().collect();
+    foods.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    foods
+}
+
+/// The logged foods, counted on the first keystroke and reused until the
+/// logs change and `invalidate` drops them.
+#[derive(Debug, Default)]
+pub struct FoodIndex {
+    foods: RefCell<Option<Vec<(String, usize)>>>,
+}
+
+impl FoodIndex {
+    pub fn invalidate(&self) {
+        self.foods.borrow_mut().take();
+    }
+
+    /// Up to `limit` logged foods starting with `prefix` (ignoring case),
+    /// most often eaten first. An empty prefix, or one that already spells
+    /// out a food exactly, suggests nothing.
+    pub fn suggestions(&self, logs: &[DailyLog], prefix: &str, limit: usize) -> Vec<String> {
+        let prefix = prefix.trim_start().to_lowercase();
+        if prefix.is_empty() {
+            return Vec::new();
+        }
+        let mut foods = self.foods.borrow_mut();
+        foods
+            .get_or_insert_with(|| food_frequency(logs))
+            .iter()
+            .filter(|(name, _)| {
+                let name = name.to_lowercase();
+                name.starts_with(&prefix) && name != prefix
+            })
+            .take(limit)
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn suggests_frequent_foods_by_prefix_until_invalidated() {
+        let day = |d| NaiveDate::from_ymd_opt(2026, 7, d).unwrap();
+        let with_foods = |d, foods: &[&str]| DailyLog {
+            food_entries: foods
+                .iter()
+                .map(|name| super::super::FoodEntry::new(name.to_string()))
+                .collect(),
+            ..DailyLog::new(day(d))
+        };
+        let mut logs = vec![
+            with_foods(1, &["oatmeal", "Orange"]),
+            with_foods(2, &["Oatmeal ", "Oat milk latte"]),
+            with_foods(3, &["Oatmeal", "orange", "Rice"]),
+        ];
+        let index = FoodIndex::default();
+        assert_eq!(
+            index.suggestions(&logs, "o", 5),
+            vec!["Oatmeal", "orange", "Oat milk latte"]
+        );
+        assert_eq!(index.suggestions(&logs, "OAT", 1), vec!["Oatmeal"]);
+        assert!(index.suggestions(&logs, "oatmeal", 5).is_empty());
+        assert!(index.suggestions(&logs, "", 5).is_empty());
+
+        logs.push(with_foods(4, &["Ramen"]));
+        assert_eq!(index.suggestions(&logs, "r", 5), vec!["Rice"]);
+        index.invalidate();
+        assert_eq!(index.suggestions(&logs, "r", 5), vec!["Ramen", "Rice"]);
+    }
+}
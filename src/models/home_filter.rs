@@ -0,0 +1,411 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+use crate::models::DailyLog;
+use crate::models::activity::ActivityType;
+use crate::month_stats::month_start;
+use crate::units::{Quantity, UnitSystem};
+
+/// Order of the logged days list.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum HomeSort {
+    #[default]
+    NewestFirst,
+    OldestFirst,
+    MostMiles,
+}
+
+impl HomeSort {
+    pub fn next(self) -> Self {
+        match self {
+            HomeSort::NewestFirst => HomeSort::OldestFirst,
+            HomeSort::OldestFirst => HomeSort::MostMiles,
+            HomeSort::MostMiles => HomeSort::NewestFirst,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            HomeSort::NewestFirst => "newest first",
+            HomeSort::OldestFirst => "oldest first",
+            HomeSort::MostMiles => "most distance first",
+        }
+    }
+
+    /// Date orders keep each month's days together, so they get month headers.
+    pub fn groups_by_month(self) -> bool {
+        self != HomeSort::MostMiles
+    }
+}
+
+/// A row of the logged days list.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HomeRow {
+    /// Header for the month starting on `month`; a collapsed month's days
+    /// aren't listed.
+    Month { month: NaiveDate, collapsed: bool },
+    /// Position of the day's log in `AppState`'s logs.
+    Day(usize),
+}
+
+/// Puts a header before each month's days in `rows`, leaving out the days
+/// of `collapsed` months. `rows` must already be in date order.
+pub fn group_by_month(
+    rows: &[usize],
+    logs: &[DailyLog],
+    collapsed: &HashSet<NaiveDate>,
+) -> Vec<HomeRow> {
+    let mut grouped = Vec::new();
+    let mut current = None;
+    for &pos in rows {
+        let month = month_start(logs[pos].date);
+        let collapsed = collapsed.contains(&month);
+        if current != Some(month) {
+            grouped.push(HomeRow::Month { month, collapsed });
+            current = Some(month);
+        }
+        if !collapsed {
+            grouped.push(HomeRow::Day(pos));
+        }
+    }
+    grouped
+}
+
+/// One word of a filter query; a day is shown when it matches all of them.
+#[derive(Debug, Clone, PartialEq)]
+enum Predicate {
+    /// `vert`: at least the configured vert-day threshold.
+    VertDay,
+    /// `vert>=1500`, `miles<5`, `weight<180` and so on, in the display
+    /// units.
+    Compare(Quantity, Comparison, f64),
+    HasSokay,
+    HasNotes,
+    HasFood,
+    HasStrength,
+    HasWeight,
+    /// `type:hike`: tagged with that activity.
+    Activity(ActivityType),
+    /// Anything else: found in the notes, strength text, food or sokay.
+    Text(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Comparison {
+    AtLeast,
+    Above,
+    AtMost,
+    Below,
+}
+
+impl Comparison {
+    fn holds(self, value: f64, bound: f64) -> bool {
+        match self {
+            Comparison::AtLeast => value >= bound,
+            Comparison::Above => value > bound,
+            Comparison::AtMost => value <= bound,
+            Comparison::Below => value < bound,
+        }
+    }
+}
+
+/// The typed `/` query and sort order for the logged days list.
+#[derive(Debug, Clone, Default)]
+pub struct HomeFilter {
+    pub query: String,
+    pub sort: HomeSort,
+    /// List archived days too, dimmed, so they can be brought back.
+    pub show_archived: bool,
+}
+
+/// A filter query kept under a name, as `[[home.views]]` entries, to pick
+/// from the Views menu instead of typing it again.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SavedView {
+    pub name: String,
+    pub query: String,
+}
+
+/// Saves `query` as `name`, replacing a view of the same name (ignoring
+/// case) in place, or adding it at the end. Returns its position.
+pub fn save_view(views: &mut Vec<SavedView>, name: &str, query: &str) -> usize {
+    let view = SavedView {
+        name: name.trim().to_string(),
+        query: query.trim().to_string(),
+    };
+    match views
+        .iter()
+        .position(|existing| existing.name.eq_ignore_ascii_case(&view.name))
+    {
+        Some(index) => {
+            views[index] = view;
+            index
+        }
+        None => {
+            views.push(view);
+            views.len() - 1
+        }
+    }
+}
+
+impl HomeFilter {
+    pub fn is_active(&self) -> bool {
+        !self.query.trim().is_empty() || self.sort != HomeSort::default()
+    }
+
+    /// Positions in `logs` (as `AppState` keeps them: newest first, with
+    /// archived days after the rest) of the days to list, in display order.
+    pub fn rows(&self, logs: &[DailyLog], units: UnitSystem, vert_threshold: i32) -> Vec<usize> {
+        let predicates: Vec<Predicate> = self
+            .query
+            .split_whitespace()
+            .map(|word| parse_predicate(word, units))
+            .collect();
+        let mut rows: Vec<usize> = (0..logs.len())
+            .filter(|&i| self.show_archived || !logs[i].archived)
+            .filter(|&i| {
+                predicates
+                    .iter()
+                    .all(|predicate| matches(&logs[i], predicate, vert_threshold))
+            })
+            .collect();
+        // Archived days go back in among the rest by date
+        if self.show_archived {
+            rows.sort_by_key(|&i| std::cmp::Reverse(logs[i].date));
+        }
+        match self.sort {
+            HomeSort::NewestFirst => {}
+            HomeSort::OldestFirst => rows.reverse(),
+            HomeSort::MostMiles => rows.sort_by(|&a, &b| {
+                let miles = |i: usize| logs[i].miles_covered.unwrap_or(0.0);
+                miles(b).total_cmp(&miles(a))
+            }),
+        }
+        rows
+    }
+}
+
+fn parse_predicate(word: &str, units: UnitSystem) -> Predicate {
+    let lower = word.to_lowercase();
+    match lower.as_str() {
+        "vert" => return Predicate::VertDay,
+        "sokay" => return Predicate::HasSokay,
+        "notes" => return Predicate::HasNotes,
+        "food" => return Predicate::HasFood,
+        "strength" => return Predicate::HasStrength,
+        "weight" => return Predicate::HasWeight,
+        _ => {}
+    }
+    if let Some(kind) = lower
+        .strip_prefix("type:")
+        .and_then(ActivityType::from_name)
+    {
+        return Predicate::Activity(kind);
+    }
+    for (name, quantity) in [
+        ("vert", Quantity::Elevation),
+        ("miles", Quantity::Distance),
+        ("km", Quantity::Distance),
+        ("distance", Quantity::Distance),
+        ("weight", Quantity::Weight),
+        ("waist", Quantity::Waist),
+        ("water", Quantity::Water),
+    ] {
+        let Some(rest) = lower.strip_prefix(name) else {
+            continue;
+        };
+        let (comparison, number) = if let Some(n) = rest.strip_prefix(">=") {
+            (Comparison::AtLeast, n)
+        } else if let Some(n) = rest.strip_prefix("<=") {
+            (Comparison::AtMost, n)
+        } else if let Some(n) = rest.strip_prefix('>') {
+            (Comparison::Above, n)
+        } else if let Some(n) = rest.strip_prefix('<') {
+            (Comparison::Below, n)
+        } else {
+            continue;
+        };
+        if let Ok(shown) = number.parse::<f64>() {
+            return Predicate::Compare(quantity, comparison, units.store(quantity, shown));
+        }
+    }
+    Predicate::Text(lower)
+}
+
+fn matches(log: &DailyLog, predicate: &Predicate, vert_threshold: i32) -> bool {
+    let has_text = |text: &Option<String>| text.as_deref().is_some_and(|t| !t.trim().is_empty());
+    match predicate {
+        Predicate::VertDay => log.elevation_gain.is_some_and(|feet| feet >= vert_threshold),
+        Predicate::Compare(quantity, comparison, bound) => {
+            let value = match quantity {
+                Quantity::Elevation => log.elevation_gain.map(f64::from),
+                Quantity::Distance => log.miles_covered.map(f64::from),
+                Quantity::Weight => log.weight.map(f64::from),
+                Quantity::Waist => log.waist.map(f64::from),
+                Quantity::Water => log.water.map(f64::from),
+            };
+            value.is_some_and(|value| comparison.holds(value, *bound))
+        }
+        Predicate::HasSokay => !log.sokay_entries.is_empty(),
+        Predicate::HasNotes => has_text(&log.notes),
+        Predicate::HasFood => !log.food_entries.is_empty(),
+        Predicate::HasStrength => !log.strength_sets.is_empty() || has_text(&log.strength_mobility),
+        Predicate::HasWeight => log.weight.is_some(),
+        Predicate::Activity(kind) => log.activity == Some(*kind),
+        Predicate::Text(needle) => {
+            let found = |text: &str| text.to_lowercase().contains(needle.as_str());
+            log.notes.as_deref().is_some_and(found)
+                || log.strength_mobility.as_deref().is_some_and(found)
+                || log.strength_sets.iter().any(|set| found(&set.exercise))
+                || log.food_entries.iter().any(|food| found(&food.name))
+                || log.sokay_entries.iter().any(|entry| found(entry))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::FoodEntry;
+    use chrono::NaiveDate;
+
+    fn logs() -> Vec<DailyLog> {
+        let day = |d| NaiveDate::from_ymd_opt(2024, 11, d).unwrap();
+        let mut big = DailyLog::new(day(3));
+        big.miles_covered = Some(14.0);
+        big.elevation_gain = Some(3200);
+        big.notes = Some("Ridge loop in the fog".to_string());
+        big.activity = Some(ActivityType::Hike);
+        let mut easy = DailyLog::new(day(2));
+        easy.miles_covered = Some(5.0);
+        easy.elevation_gain = Some(400);
+        easy.add_sokay_entry("Chips".to_string());
+        let mut rest = DailyLog::new(day(1));
+        rest.add_food_entry(FoodEntry::new("Oatmeal".to_string()));
+        vec![big, easy, rest]
+    }
+
+    fn rows(query: &str, sort: HomeSort) -> Vec<usize> {
+        let filter = HomeFilter {
+            query: query.to_string(),
+            sort,
+            show_archived: false,
+        };
+        filter.rows(&logs(), UnitSystem::Imperial, 1000)
+    }
+
+    #[test]
+    fn filters_by_every_predicate_kind() {
+        let newest = HomeSort::NewestFirst;
+        assert_eq!(rows("", newest), vec![0, 1, 2]);
+        assert_eq!(rows("vert", newest), vec![0]);
+        assert_eq!(rows("vert>=400", newest), vec![0, 1]);
+        assert_eq!(rows("miles<10", newest), vec![1]);
+        assert_eq!(rows("sokay", newest), vec![1]);
+        assert_eq!(rows("notes", newest), vec![0]);
+        assert_eq!(rows("FOG", newest), vec![0]);
+        assert_eq!(rows("oat", newest), vec![2]);
+        assert_eq!(rows("food sokay", newest), Vec::<usize>::new());
+        assert_eq!(rows("type:hike", newest), vec![0]);
+        assert_eq!(rows("type:run", newest), Vec::<usize>::new());
+
+        let metric = HomeFilter {
+            query: "km>=20".to_string(),
+            sort: newest,
+            show_archived: false,
+        };
+        assert_eq!(metric.rows(&logs(), UnitSystem::Metric, 1000), vec![0]);
+    }
+
+    #[test]
+    fn archived_days_are_listed_only_when_asked_for() {
+        // As AppState keeps them, the archived day after the rest
+        let mut logs = logs();
+        let mut junk = logs.remove(1);
+        junk.archived = true;
+        logs.push(junk);
+        let mut filter = HomeFilter::default();
+        assert_eq!(filter.rows(&logs, UnitSystem::Imperial, 1000), vec![0, 1]);
+        filter.show_archived = true;
+        assert_eq!(filter.rows(&logs, UnitSystem::Imperial, 1000), vec![0, 2, 1]);
+        filter.sort = HomeSort::OldestFirst;
+        assert_eq!(filter.rows(&logs, UnitSystem::Imperial, 1000), vec![1, 2, 0]);
+    }
+
+    #[test]
+    fn groups_days_under_month_headers_and_hides_collapsed_months() {
+        let day = |m, d| NaiveDate::from_ymd_opt(2024, m, d).unwrap();
+        let logs = vec![
+            DailyLog::new(day(11, 2)),
+            DailyLog::new(day(11, 1)),
+            DailyLog::new(day(10, 30)),
+            DailyLog::new(day(9, 4)),
+        ];
+        let november = HomeRow::Month {
+            month: day(11, 1),
+            collapsed: false,
+        };
+        let mut collapsed = HashSet::new();
+        assert_eq!(
+            group_by_month(&[0, 1, 2, 3], &logs, &collapsed),
+            vec![
+                november,
+                HomeRow::Day(0),
+                HomeRow::Day(1),
+                HomeRow::Month {
+                    month: day(10, 1),
+                    collapsed: false
+                },
+                HomeRow::Day(2),
+                HomeRow::Month {
+                    month: day(9, 1),
+                    collapsed: false
+                },
+                HomeRow::Day(3),
+            ]
+        );
+
+        collapsed.insert(day(10, 1));
+        assert_eq!(
+            group_by_month(&[1, 2], &logs, &collapsed),
+            vec![
+                november,
+                HomeRow::Day(1),
+                HomeRow::Month {
+                    month: day(10, 1),
+                    collapsed: true
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn saving_a_view_under_an_existing_name_replaces_it() {
+        let mut views = Vec::new();
+        assert_eq!(save_view(&mut views, "Big vert days", "vert>=3000"), 0);
+        assert_eq!(save_view(&mut views, " Race days ", " race "), 1);
+        assert_eq!(save_view(&mut views, "big vert DAYS", "vert>=2500"), 0);
+        assert_eq!(
+            views,
+            vec![
+                SavedView {
+                    name: "big vert DAYS".to_string(),
+                    query: "vert>=2500".to_string(),
+                },
+                SavedView {
+                    name: "Race days".to_string(),
+                    query: "race".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn sorts_by_date_or_distance() {
+        assert_eq!(rows("", HomeSort::OldestFirst), vec![2, 1, 0]);
+        assert_eq!(rows("", HomeSort::MostMiles), vec![0, 1, 2]);
+        assert_eq!(rows("sokay", HomeSort::MostMiles), vec![1]);
+    }
+}
@@ -0,0 +1,113 @@
+use chrono::NaiveDate;
+
+/// Oldest entries are dropped past this many locations.
+const MAX_JUMPS: usize = 100;
+
+/// A screen the jump list can return to.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum JumpLocation {
+    Startup,
+    Home,
+    Statistics,
+    Day(NaiveDate),
+}
+
+/// Session-scoped history of visited locations, walked with Ctrl+O/Ctrl+I
+/// like vim's jump list.
+#[derive(Debug, Default)]
+pub struct JumpList {
+    entries: Vec<JumpLocation>,
+    position: usize,
+}
+
+impl JumpList {
+    /// Records a visit. Revisiting the current entry is a no-op; anything else
+    /// drops the forward history, as a new branch does in vim.
+    pub fn record(&mut self, location: JumpLocation) {
+        if self.entries.get(self.position) == Some(&location) {
+            return;
+        }
+        if !self.entries.is_empty() {
+            self.entries.truncate(self.position + 1);
+        }
+        self.entries.push(location);
+        if self.entries.len() > MAX_JUMPS {
+            self.entries.remove(0);
+        }
+        self.position = self.entries.len() - 1;
+    }
+
+    pub fn back(&mut self) -> Option<JumpLocation> {
+        if self.position == 0 {
+            return None;
+        }
+        self.position -= 1;
+        self.entries.get(self.position).copied()
+    }
+
+    pub fn forward(&mut self) -> Option<JumpLocation> {
+        if self.position + 1 >= self.entries.len() {
+            return None;
+        }
+        self.position += 1;
+        self.entries.get(self.position).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(d: u32) -> JumpLocation {
+        JumpLocation::Day(NaiveDate::from_ymd_opt(2026, 7, d).unwrap())
+    }
+
+    #[test]
+    fn back_and_forward_walk_the_history() {
+        let mut jumps = JumpList::default();
+        jumps.record(JumpLocation::Startup);
+        jumps.record(day(22));
+        jumps.record(day(22));
+        jumps.record(day(3));
+
+        assert_eq!(jumps.back(), Some(day(22)));
+        assert_eq!(jumps.back(), Some(JumpLocation::Startup));
+        assert_eq!(jumps.back(), None);
+        assert_eq!(jumps.forward(), Some(day(22)));
+        assert_eq!(jumps.forward(), Some(day(3)));
+        assert_eq!(jumps.forward(), None);
+    }
+
+    #[test]
+    fn recording_after_going_back_drops_forward_entries() {
+        let mut jumps = JumpList::default();
+        jumps.record(day(1));
+        jumps.record(day(2));
+        jumps.record(day(3));
+        jumps.back();
+        jumps.back();
+
+        // Landing on the entry we jumped to is not a new visit
+        jumps.record(day(1));
+        assert_eq!(jumps.forward(), Some(day(2)));
+        jumps.back();
+
+        jumps.record(JumpLocation::Home);
+        assert_eq!(jumps.forward(), None);
+        assert_eq!(jumps.back(), Some(day(1)));
+    }
+
+    #[test]
+    fn history_is_capped() {
+        let mut jumps = JumpList::default();
+        for d in 0..(MAX_JUMPS as i64 + 10) {
+            let date = NaiveDate::from_ymd_opt(2020, 1, 1).unwrap() + chrono::Duration::days(d);
+            jumps.record(JumpLocation::Day(date));
+        }
+        let mut steps = 0;
+        while jumps.back().is_some() {
+            steps += 1;
+        }
+        assert_eq!(steps, MAX_JUMPS - 1);
+    }
+}
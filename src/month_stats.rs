@@ -0,0 +1,109 @@
+use crate::elevation_stats::{calculate_monthly_elevation, count_monthly_vert_days};
+use crate::miles_stats::calculate_monthly_miles;
+use crate::models::DailyLog;
+use chrono::{Datelike, Months, NaiveDate};
+
+/// Everything the month report shows for one calendar month.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonthSummary {
+    pub month: NaiveDate,
+    pub days_logged: usize,
+    pub miles: f32,
+    pub elevation: i32,
+    /// Days at or over the vert threshold.
+    pub vert_days: usize,
+    pub average_weight: Option<f32>,
+    pub sokay_count: usize,
+    /// Date and distance of the month's longest run.
+    pub longest_run: Option<(NaiveDate, f32)>,
+}
+
+/// First day of the month containing `date`.
+pub fn month_start(date: NaiveDate) -> NaiveDate {
+    date.with_day(1).unwrap_or(date)
+}
+
+/// First day of the month `months` away from `date`'s month (negative for earlier).
+pub fn shift_month(date: NaiveDate, months: i32) -> Option<NaiveDate> {
+    let start = month_start(date);
+    if months >= 0 {
+        start.checked_add_months(Months::new(months as u32))
+    } else {
+        start.checked_sub_months(Months::new(months.unsigned_abs()))
+    }
+}
+
+/// Summarizes the month containing `date`, counting days with at least
+/// `vert_threshold` feet of gain as vert days.
+pub fn summarize_month(logs: &[DailyLog], date: NaiveDate, vert_threshold: i32) -> MonthSummary {
+    let month_logs: Vec<&DailyLog> = logs
+        .iter()
+        .filter(|log| log.date.year() == date.year() && log.date.month() == date.month())
+        .collect();
+
+    let weights: Vec<f32> = month_logs.iter().filter_map(|log| log.weight).collect();
+    let average_weight =
+        (!weights.is_empty()).then(|| weights.iter().sum::<f32>() / weights.len() as f32);
+    let longest_run = month_logs
+        .iter()
+        .filter_map(|log| log.miles_covered.map(|miles| (log.date, miles)))
+        .filter(|(_, miles)| *miles > 0.0)
+        .max_by(|a, b| a.1.total_cmp(&b.1).then(b.0.cmp(&a.0)));
+
+    MonthSummary {
+        month: month_start(date),
+        days_logged: month_logs.len(),
+        miles: calculate_monthly_miles(logs, date),
+        elevation: calculate_monthly_elevation(logs, date),
+        vert_days: count_monthly_vert_days(logs, date, vert_threshold),
+        average_weight,
+        sokay_count: month_logs.iter().map(|log| log.sokay_entries.len()).sum(),
+        longest_run,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summarizes_the_calendar_month() {
+        let day = |m, d| NaiveDate::from_ymd_opt(2026, m, d).unwrap();
+        let logs = vec![
+            DailyLog {
+                miles_covered: Some(12.4),
+                elevation_gain: Some(3100),
+                weight: Some(171.0),
+                ..DailyLog::new(day(7, 18))
+            },
+            DailyLog {
+                miles_covered: Some(5.0),
+                elevation_gain: Some(600),
+                weight: Some(173.0),
+                sokay_entries: vec!["ice cream".to_string()],
+                ..DailyLog::new(day(7, 2))
+            },
+            DailyLog {
+                miles_covered: Some(20.0),
+                ..DailyLog::new(day(8, 1))
+            },
+        ];
+
+        let summary = summarize_month(&logs, day(7, 30), 1000);
+        assert_eq!(summary.month, day(7, 1));
+        assert_eq!(summary.days_logged, 2);
+        assert_eq!(summary.miles, 17.4);
+        assert_eq!(summary.elevation, 3700);
+        assert_eq!(summary.vert_days, 1);
+        assert_eq!(summary.average_weight, Some(172.0));
+        assert_eq!(summary.sokay_count, 1);
+        assert_eq!(summary.longest_run, Some((day(7, 18), 12.4)));
+    }
+
+    #[test]
+    fn shifting_months_crosses_years() {
+        let date = NaiveDate::from_ymd_opt(2026, 1, 31).unwrap();
+        assert_eq!(shift_month(date, -1), NaiveDate::from_ymd_opt(2025, 12, 1));
+        assert_eq!(shift_month(date, 1), NaiveDate::from_ymd_opt(2026, 2, 1));
+    }
+}
@@ -0,0 +1,211 @@
+//! Reading food off a photo of a meal or a receipt. The image goes through
+//! the OCR command set as `[food] ocr_command` (tesseract, say), and the
+//! lines it finds are offered as food entries to pick from. Experimental:
+//! receipts vary, so every line is reviewed before anything is logged.
+
+use anyhow::{Context, Result, bail};
+use std::io::Read;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+/// How long the OCR command may take over one image before it's killed.
+pub const OCR_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// File extensions read as images.
+const IMAGE_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "tif", "tiff", "bmp", "gif", "webp"];
+
+/// Receipt lines that are never food.
+const RECEIPT_WORDS: &[&str] = &[
+    "total", "subtotal", "tax", "tip", "gratuity", "change", "cash", "visa", "mastercard",
+    "amex", "debit", "credit", "card", "balance", "thank", "receipt", "server", "table",
+    "order", "guests",
+];
+
+/// Whether `path` names an image, going by its extension.
+pub fn is_image(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| IMAGE_EXTENSIONS.contains(&ext.to_lowercase().as_str()))
+}
+
+/// Runs `command` on `image` the way tesseract takes it (`<command> <image>
+/// stdout`) and returns the text it printed. It's killed after `timeout`,
+/// or as soon as `cancel` is set. Blocking; run it with `spawn_blocking`.
+pub fn extract_text(
+    command: &str,
+    image: &Path,
+    timeout: Duration,
+    cancel: &AtomicBool,
+) -> Result<String> {
+    if !image.is_file() {
+        bail!("No image at {}", image.display());
+    }
+    if !is_image(image) {
+        bail!("{} isn't an image", image.display());
+    }
+    let mut child = Command::new(command)
+        .arg(image)
+        .arg("stdout")
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .with_context(|| format!("Failed to run {}", command))?;
+    // Drained as it runs, so a chatty command can't fill a pipe and stall
+    let stdout = child.stdout.take().map(drain);
+    let stderr = child.stderr.take().map(drain);
+
+    let started = Instant::now();
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        let cancelled = cancel.load(Ordering::Relaxed);
+        if cancelled || started.elapsed() >= timeout {
+            let _ = child.kill();
+            let _ = child.wait();
+            if cancelled {
+                bail!("Stopped reading the photo");
+            }
+            bail!("{} was stopped after {}s", command, timeout.as_secs());
+        }
+        std::thread::sleep(Duration::from_millis(20));
+    };
+    let collect = |pipe: Option<std::thread::JoinHandle<Vec<u8>>>| {
+        pipe.and_then(|pipe| pipe.join().ok()).unwrap_or_default()
+    };
+    let (stdout, stderr) = (collect(stdout), collect(stderr));
+    if !status.success() {
+        let stderr = String::from_utf8_lossy(&stderr);
+        bail!(
+            "{} couldn't read the image: {}",
+            command,
+            stderr.lines().next().unwrap_or("no details")
+        );
+    }
+    Ok(String::from_utf8_lossy(&stdout).into_owned())
+}
+
+/// Reads a child's pipe to the end on its own thread.
+fn drain(mut pipe: impl Read + Send + 'static) -> std::thread::JoinHandle<Vec<u8>> {
+    std::thread::spawn(move || {
+        let mut bytes = Vec::new();
+        let _ = pipe.read_to_end(&mut bytes);
+        bytes
+    })
+}
+
+/// The lines of OCR'd text that could be foods: prices and quantities
+/// trimmed off, receipt totals and lines without words dropped, each kept
+/// once in the order found.
+pub fn candidate_foods(text: &str) -> Vec<String> {
+    let mut foods: Vec<String> = Vec::new();
+    for line in text.lines() {
+        let food = strip_price(line.trim());
+        let food = strip_count(food);
+        let letters = food.chars().filter(|c| c.is_alphabetic()).count();
+        if letters < 3 {
+            continue;
+        }
+        let lower = food.to_lowercase();
+        if lower
+            .split(|c: char| !c.is_alphabetic())
+            .any(|word| RECEIPT_WORDS.contains(&word))
+        {
+            continue;
+        }
+        if !foods.iter().any(|seen| seen.to_lowercase() == lower) {
+            foods.push(food.to_string());
+        }
+    }
+    foods
+}
+
+/// "Fish tacos  $12.50" -> "Fish tacos"
+fn strip_price(line: &str) -> &str {
+    let trimmed = line.trim_end();
+    let Some((rest, last)) = trimmed.rsplit_once(char::is_whitespace) else {
+        return trimmed;
+    };
+    let price = last.trim_start_matches(['$', '€', '£']);
+    let is_price = !price.is_empty()
+        && price.chars().any(|c| c.is_ascii_digit())
+        && price.chars().all(|c| c.is_ascii_digit() || c == '.' || c == ',');
+    if is_price { rest.trim_end() } else { trimmed }
+}
+
+/// "2 x Fish tacos" or "2 Fish tacos" -> "Fish tacos"
+fn strip_count(line: &str) -> &str {
+    let Some((first, rest)) = line.split_once(char::is_whitespace) else {
+        return line;
+    };
+    if !first.chars().all(|c| c.is_ascii_digit()) {
+        return line;
+    }
+    let rest = rest.trim_start();
+    rest.strip_prefix("x ")
+        .or_else(|| rest.strip_prefix("X "))
+        .unwrap_or(rest)
+        .trim_start()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn receipt_lines_become_food_names() {
+        let text = "TRAILHEAD CAFE\n\
+                    2 x Fish tacos   $24.00\n\
+                    Green chile stew 9.50\n\
+                    Iced tea 3.00\n\
+                    \n\
+                    Subtotal 36.50\n\
+                    Tax 2.90\n\
+                    TOTAL $39.40\n\
+                    VISA ****1234\n\
+                    fish tacos 12.00\n\
+                    Thank you!\n";
+        assert_eq!(
+            candidate_foods(text),
+            vec!["TRAILHEAD CAFE", "Fish tacos", "Green chile stew", "Iced tea"]
+        );
+    }
+
+    #[test]
+    fn only_image_files_are_read() {
+        assert!(is_image(Path::new("/tmp/lunch.JPG")));
+        assert!(is_image(Path::new("receipt.png")));
+        assert!(!is_image(Path::new("notes.txt")));
+        assert!(!is_image(Path::new("receipt")));
+        let cancel = AtomicBool::new(false);
+        let missing = Path::new("/no/such/lunch.png");
+        assert!(extract_text("tesseract", missing, OCR_TIMEOUT, &cancel).is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn a_slow_command_is_stopped() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let image = dir.path().join("lunch.png");
+        std::fs::write(&image, b"").unwrap();
+        let script = dir.path().join("slow-ocr");
+        std::fs::write(&script, "#!/bin/sh\nsleep 5\n").unwrap();
+        std::fs::set_permissions(&script, std::os::unix::fs::PermissionsExt::from_mode(0o755))
+            .unwrap();
+        let command = script.to_str().unwrap();
+
+        let cancel = AtomicBool::new(false);
+        let started = Instant::now();
+        let error =
+            extract_text(command, &image, Duration::from_millis(100), &cancel).unwrap_err();
+        assert!(error.to_string().ends_with("was stopped after 0s"));
+        assert!(started.elapsed() < Duration::from_secs(2));
+
+        cancel.store(true, Ordering::Relaxed);
+        let error = extract_text(command, &image, OCR_TIMEOUT, &cancel).unwrap_err();
+        assert_eq!(error.to_string(), "Stopped reading the photo");
+    }
+}
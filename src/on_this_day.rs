@@ -0,0 +1,118 @@
+//! "On this day": what was logged on the same month and day in earlier
+//! years, for the flashback panel on the daily view.
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::models::DailyLog;
+use crate::format::Units;
+use crate::units::Quantity;
+
+/// How much of the day's notes the flashback shows.
+const NOTES_SNIPPET_CHARS: usize = 40;
+
+/// Logs from the same month and day as `date` in earlier years, newest
+/// first. Feb 29 only matches leap years.
+pub fn on_this_day(logs: &[DailyLog], date: NaiveDate) -> Vec<&DailyLog> {
+    let mut matches: Vec<&DailyLog> = logs
+        .iter()
+        .filter(|log| {
+            log.date.month() == date.month()
+                && log.date.day() == date.day()
+                && log.date.year() < date.year()
+        })
+        .collect();
+    matches.sort_by_key(|log| std::cmp::Reverse(log.date));
+    matches
+}
+
+/// "2025 (1 year ago): 7.5 mi, 1200 ft - Windy on the ridge, turned…"
+pub fn describe(log: &DailyLog, date: NaiveDate, units: Units) -> String {
+    let years = date.year() - log.date.year();
+    let mut parts = Vec::new();
+    if let Some(miles) = log.miles_covered {
+        parts.push(units.format(Quantity::Distance, miles));
+    }
+    if let Some(feet) = log.elevation_gain {
+        parts.push(units.format(Quantity::Elevation, feet as f32));
+    }
+    let mut summary = if parts.is_empty() {
+        "logged".to_string()
+    } else {
+        parts.join(", ")
+    };
+    if let Some(snippet) = log.notes.as_deref().and_then(notes_snippet) {
+        summary.push_str(" - ");
+        summary.push_str(&snippet);
+    }
+    format!(
+        "{} ({} {} ago): {}",
+        log.date.year(),
+        years,
+        if years == 1 { "year" } else { "years" },
+        summary
+    )
+}
+
+/// The first line of `notes`, cut to `NOTES_SNIPPET_CHARS`.
+fn notes_snippet(notes: &str) -> Option<String> {
+    let line = notes.lines().map(str::trim).find(|line| !line.is_empty())?;
+    if line.chars().count() > NOTES_SNIPPET_CHARS {
+        let cut: String = line.chars().take(NOTES_SNIPPET_CHARS).collect();
+        Some(format!("{}…", cut.trim_end()))
+    } else {
+        Some(line.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::UnitSystem;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn finds_the_same_day_in_earlier_years() {
+        let logs = vec![
+            DailyLog::new(date(2023, 10, 16)),
+            DailyLog::new(date(2025, 10, 16)),
+            DailyLog::new(date(2025, 10, 15)),
+            DailyLog::new(date(2026, 10, 16)),
+            DailyLog::new(date(2024, 2, 29)),
+        ];
+        let years: Vec<i32> = on_this_day(&logs, date(2026, 10, 16))
+            .iter()
+            .map(|log| log.date.year())
+            .collect();
+        assert_eq!(years, vec![2025, 2023]);
+        assert_eq!(on_this_day(&logs, date(2028, 2, 29)).len(), 1);
+    }
+
+    #[test]
+    fn describes_totals_and_a_notes_snippet() {
+        let today = date(2026, 10, 16);
+        let log = DailyLog {
+            miles_covered: Some(7.5),
+            elevation_gain: Some(1200),
+            notes: Some(
+                "\nWindy on the ridge, turned around at the saddle and jogged down\nSecond line"
+                    .to_string(),
+            ),
+            ..DailyLog::new(date(2025, 10, 16))
+        };
+        assert_eq!(
+            describe(&log, today, UnitSystem::Imperial.into()),
+            "2025 (1 year ago): 7.5 mi, 1,200 ft - Windy on the ridge, turned around at the…"
+        );
+        assert_eq!(
+            describe(
+                &DailyLog::new(date(2022, 10, 16)),
+                today,
+                UnitSystem::Imperial.into()
+            ),
+            "2022 (4 years ago): logged"
+        );
+    }
+}
@@ -0,0 +1,246 @@
+//! Training phases (base, build, peak, taper and recovery) marked over
+//! ranges of days on the phases screen and kept in the `phases` table. The
+//! daily view and the week view say which phase a day falls in, and the
+//! phases screen totals each one.
+
+use chrono::NaiveDate;
+
+use crate::date_parse::parse_fuzzy_date;
+use crate::format::Units;
+use crate::models::DailyLog;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PhaseKind {
+    Base,
+    Build,
+    Peak,
+    Taper,
+    Recovery,
+}
+
+impl PhaseKind {
+    pub const ALL: [PhaseKind; 5] = [
+        PhaseKind::Base,
+        PhaseKind::Build,
+        PhaseKind::Peak,
+        PhaseKind::Taper,
+        PhaseKind::Recovery,
+    ];
+
+    /// How the kind is stored in the `phases` table.
+    pub fn key(self) -> &'static str {
+        match self {
+            PhaseKind::Base => "base",
+            PhaseKind::Build => "build",
+            PhaseKind::Peak => "peak",
+            PhaseKind::Taper => "taper",
+            PhaseKind::Recovery => "recovery",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.key() == key)
+    }
+
+    pub fn title(self) -> &'static str {
+        match self {
+            PhaseKind::Base => "Base",
+            PhaseKind::Build => "Build",
+            PhaseKind::Peak => "Peak",
+            PhaseKind::Taper => "Taper",
+            PhaseKind::Recovery => "Recovery",
+        }
+    }
+
+    /// The next kind, for cycling through them while marking a phase.
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|&kind| kind == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+/// The phase being added or changed on the phases screen; its range is
+/// typed into the input buffer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PhaseDraft {
+    /// The phase being changed, or none for a new one.
+    pub editing: Option<i64>,
+    pub kind: PhaseKind,
+}
+
+/// A phase over `start` through `end`, both days included.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Phase {
+    pub id: i64,
+    pub kind: PhaseKind,
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+}
+
+impl Phase {
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        (self.start..=self.end).contains(&date)
+    }
+
+    pub fn days(&self) -> i64 {
+        (self.end - self.start).num_days() + 1
+    }
+
+    /// "Build, Aug 1, 2026 to Sep 15, 2026"
+    pub fn label(&self, units: Units) -> String {
+        format!(
+            "{}, {} to {}",
+            self.kind.title(),
+            units.short_date(self.start),
+            units.short_date(self.end)
+        )
+    }
+
+    /// The range as it's typed to change it: "2026-08-01 to 2026-09-15".
+    pub fn range_text(&self) -> String {
+        format!("{} to {}", self.start.format("%Y-%m-%d"), self.end.format("%Y-%m-%d"))
+    }
+
+    /// Days run, distance and vert from the start through `today` or the
+    /// end, whichever comes first.
+    pub fn totals<'a>(
+        &self,
+        today: NaiveDate,
+        actual: impl Fn(NaiveDate) -> Option<&'a DailyLog>,
+    ) -> PhaseTotals {
+        let mut totals = PhaseTotals::default();
+        for date in self.start.iter_days().take_while(|&date| date <= self.end.min(today)) {
+            totals.elapsed_days += 1;
+            let Some(log) = actual(date) else { continue };
+            if log.miles_covered.is_some_and(|miles| miles > 0.0) {
+                totals.days_run += 1;
+            }
+            totals.miles += log.miles_covered.unwrap_or(0.0) as f64;
+            totals.vert += log.elevation_gain.unwrap_or(0) as i64;
+        }
+        totals
+    }
+}
+
+/// A phase's running so far.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PhaseTotals {
+    /// Days of the phase through today.
+    pub elapsed_days: u32,
+    pub days_run: u32,
+    pub miles: f64,
+    pub vert: i64,
+}
+
+impl PhaseTotals {
+    /// Miles a week over the days so far; none before the phase starts.
+    pub fn weekly_miles(&self) -> Option<f64> {
+        (self.elapsed_days > 0).then(|| self.miles / self.elapsed_days as f64 * 7.0)
+    }
+}
+
+/// The phase `date` falls in. Phases never overlap, so there's at most one.
+pub fn phase_on(phases: &[Phase], date: NaiveDate) -> Option<&Phase> {
+    phases.iter().find(|phase| phase.contains(date))
+}
+
+/// The phase covering the most of `dates`, for a week that straddles two.
+pub fn phase_during<'a>(phases: &'a [Phase], dates: &[NaiveDate]) -> Option<&'a Phase> {
+    phases
+        .iter()
+        .map(|phase| (phase, dates.iter().filter(|&&date| phase.contains(date)).count()))
+        .filter(|&(_, days)| days > 0)
+        .max_by_key(|&(phase, days)| (days, phase.start))
+        .map(|(phase, _)| phase)
+}
+
+/// Reads "FROM to TO" or "FROM..TO", each anything `parse_fuzzy_date`
+/// takes. Days still to come need a year, like 2026-11-15.
+pub fn parse_range(text: &str, today: NaiveDate) -> Result<(NaiveDate, NaiveDate), String> {
+    let (from, to) = text
+        .split_once("..")
+        .or_else(|| text.split_once(" to "))
+        .ok_or_else(|| "Type a range like 2026-08-01 to 2026-09-15".to_string())?;
+    let read = |text: &str| {
+        parse_fuzzy_date(text, today).ok_or_else(|| format!("Unrecognized date '{}'", text.trim()))
+    };
+    let (start, end) = (read(from)?, read(to)?);
+    if start > end {
+        return Err("The range ends before it starts".to_string());
+    }
+    Ok((start, end))
+}
+
+/// A phase other than `except` that shares a day with `start` through
+/// `end`.
+pub fn overlapping(
+    phases: &[Phase],
+    start: NaiveDate,
+    end: NaiveDate,
+    except: Option<i64>,
+) -> Option<&Phase> {
+    phases
+        .iter()
+        .filter(|phase| Some(phase.id) != except)
+        .find(|phase| phase.start <= end && start <= phase.end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(month: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, month, d).unwrap()
+    }
+
+    fn phase(id: i64, kind: PhaseKind, start: NaiveDate, end: NaiveDate) -> Phase {
+        Phase { id, kind, start, end }
+    }
+
+    #[test]
+    fn finds_the_phase_for_a_day_and_a_week() {
+        let phases = [
+            phase(1, PhaseKind::Build, day(8, 1), day(9, 15)),
+            phase(2, PhaseKind::Taper, day(9, 16), day(9, 30)),
+        ];
+        assert_eq!(phase_on(&phases, day(9, 15)).map(|p| p.kind), Some(PhaseKind::Build));
+        assert_eq!(phase_on(&phases, day(10, 1)), None);
+
+        // Mon Sep 14 through Sun Sep 20: two days of build, five of taper
+        let week: Vec<NaiveDate> = (14..=20).map(|d| day(9, d)).collect();
+        assert_eq!(phase_during(&phases, &week).map(|p| p.id), Some(2));
+        assert_eq!(phase_during(&phases, &[day(7, 1)]), None);
+
+        assert_eq!(overlapping(&phases, day(9, 10), day(9, 20), None).map(|p| p.id), Some(1));
+        assert_eq!(overlapping(&phases, day(9, 10), day(9, 15), Some(1)), None);
+        assert_eq!(PhaseKind::Recovery.next(), PhaseKind::Base);
+        assert_eq!(PhaseKind::from_key("peak"), Some(PhaseKind::Peak));
+    }
+
+    #[test]
+    fn reads_a_range_and_totals_the_days_so_far() {
+        let today = day(8, 10);
+        assert_eq!(parse_range("2026-08-01 to 2026-09-15", today), Ok((day(8, 1), day(9, 15))));
+        assert_eq!(parse_range("aug 3..yesterday", today), Ok((day(8, 3), day(8, 9))));
+        assert!(parse_range("2026-08-01", today).is_err());
+        assert!(parse_range("2026-09-15 to 2026-08-01", today).is_err());
+
+        let logs = [
+            DailyLog {
+                miles_covered: Some(10.0),
+                elevation_gain: Some(2000),
+                ..DailyLog::new(day(8, 2))
+            },
+            DailyLog {
+                miles_covered: Some(4.0),
+                ..DailyLog::new(day(8, 12))
+            },
+        ];
+        let build = phase(1, PhaseKind::Build, day(8, 1), day(9, 15));
+        let totals = build.totals(today, |date| logs.iter().find(|log| log.date == date));
+        assert_eq!((totals.elapsed_days, totals.days_run), (10, 1));
+        assert_eq!((totals.miles, totals.vert), (10.0, 2000));
+        assert_eq!(totals.weekly_miles(), Some(7.0));
+        assert_eq!(build.days(), 46);
+    }
+}
@@ -0,0 +1,240 @@
+//! A training plan imported from a CSV of days and planned distance and
+//! vert (`mountains plan`), shown against what was actually run on the
+//! daily and week views.
+
+use anyhow::{Context, Result, bail};
+use chrono::NaiveDate;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::db_manager::DbManager;
+use crate::format::Units;
+use crate::models::DailyLog;
+use crate::units::Quantity;
+
+/// One planned day, in stored units. A day with neither distance nor vert
+/// is a planned rest day.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlannedWorkout {
+    pub date: NaiveDate,
+    pub miles: Option<f32>,
+    pub vert: Option<i32>,
+    pub note: Option<String>,
+}
+
+impl PlannedWorkout {
+    /// "8 mi, 1,500 ft", or None for a rest day.
+    pub fn amounts(&self, units: Units) -> Option<String> {
+        let mut parts = Vec::new();
+        if let Some(miles) = self.miles {
+            parts.push(units.format(Quantity::Distance, miles));
+        }
+        if let Some(feet) = self.vert {
+            parts.push(units.format(Quantity::Elevation, feet as f32));
+        }
+        (!parts.is_empty()).then(|| parts.join(", "))
+    }
+
+    /// "8 mi, 1,500 ft - Tempo", or "Rest".
+    pub fn describe(&self, units: Units) -> String {
+        let mut text = self.amounts(units).unwrap_or_else(|| "Rest".to_string());
+        if let Some(note) = &self.note {
+            text.push_str(&format!(" - {}", note));
+        }
+        text
+    }
+}
+
+/// Reads a plan: `date,miles,vert,note` per line, dates as YYYY-MM-DD and
+/// distance and vert in display units. A header line, blank cells and the
+/// note are optional; the note is the rest of the line, commas and all.
+/// Errors name the line that didn't read.
+pub fn parse_csv(text: &str, units: Units) -> Result<Vec<PlannedWorkout>> {
+    let mut plan: Vec<PlannedWorkout> = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        let number = index + 1;
+        let line = line.trim();
+        if line.is_empty() || (index == 0 && line.to_lowercase().starts_with("date")) {
+            continue;
+        }
+        let mut cells = line.splitn(4, ',').map(|cell| cell.trim().trim_matches('"').trim());
+        let day = cells.next().unwrap_or_default();
+        let date = NaiveDate::parse_from_str(day, "%Y-%m-%d")
+            .with_context(|| format!("line {}: '{}' isn't a YYYY-MM-DD date", number, day))?;
+        if plan.iter().any(|planned| planned.date == date) {
+            bail!("line {}: {} is planned twice", number, date);
+        }
+        let mut read = |quantity, name: &str| -> Result<Option<f64>> {
+            match cells.next().filter(|cell| !cell.is_empty()) {
+                None => Ok(None),
+                Some(cell) => units
+                    .parse(quantity, cell)
+                    .filter(|&value| value >= 0.0)
+                    .map(Some)
+                    .with_context(|| format!("line {}: '{}' isn't a {}", number, cell, name)),
+            }
+        };
+        let miles = read(Quantity::Distance, "distance")?;
+        let vert = read(Quantity::Elevation, "vert")?;
+        let note = cells.next().filter(|note| !note.is_empty());
+        plan.push(PlannedWorkout {
+            date,
+            miles: miles.map(|miles| miles as f32),
+            vert: vert.map(|feet| feet.round() as i32),
+            note: note.map(str::to_string),
+        });
+    }
+    plan.sort_by_key(|planned| planned.date);
+    Ok(plan)
+}
+
+/// A week's plan against what was run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeekPlan {
+    pub miles: f32,
+    pub vert: i32,
+    /// Share of the planned distance run so far, each day's run counted up
+    /// to its plan so a long day can't make up for a skipped one. None
+    /// until a planned day with distance has come.
+    pub adherence: Option<f64>,
+}
+
+/// The plan for `dates` against `actual`, or None when none of them is
+/// planned. Days after `today` count toward the totals but not adherence.
+pub fn week_plan<'a>(
+    plan: &BTreeMap<NaiveDate, PlannedWorkout>,
+    dates: &[NaiveDate],
+    today: NaiveDate,
+    actual: impl Fn(NaiveDate) -> Option<&'a DailyLog>,
+) -> Option<WeekPlan> {
+    let planned: Vec<&PlannedWorkout> = dates.iter().filter_map(|date| plan.get(date)).collect();
+    if planned.is_empty() {
+        return None;
+    }
+    let (mut due, mut done) = (0.0, 0.0);
+    for day in planned.iter().filter(|day| day.date <= today) {
+        let miles = day.miles.unwrap_or(0.0);
+        if miles > 0.0 {
+            let ran = actual(day.date).and_then(|log| log.miles_covered).unwrap_or(0.0);
+            due += miles as f64;
+            done += ran.min(miles) as f64;
+        }
+    }
+    Some(WeekPlan {
+        miles: planned.iter().filter_map(|day| day.miles).sum(),
+        vert: planned.iter().filter_map(|day| day.vert).sum(),
+        adherence: (due > 0.0).then(|| done / due),
+    })
+}
+
+/// `mountains plan`: reads a plan CSV and stores it, replacing what was
+/// planned for the same days. Days the file leaves out keep their plan.
+pub async fn run_cli(path: &Path) -> Result<()> {
+    let config = crate::config::AppConfig::load()?;
+    let text = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    let plan = parse_csv(&text, Units::from_config(&config.display))?;
+    let (Some(first), Some(last)) = (plan.first(), plan.last()) else {
+        bail!("{} has no planned days", path.display());
+    };
+
+    let data_dir = config.storage_dir()?;
+    std::fs::create_dir_all(&data_dir).context("Failed to create .mountains directory")?;
+    let mut db = DbManager::new_local_first(&data_dir).await?;
+    if config.sync.is_configured()
+        && let Some(db_path_str) = data_dir.join("mountains.db").to_str()
+    {
+        // Offline is fine: rows land locally and sync on the next connect
+        let _ = db
+            .upgrade_to_remote_replica(
+                db_path_str,
+                config.sync.db_url.clone(),
+                config.sync.auth_token.clone(),
+            )
+            .await;
+    }
+    db.save_planned_workouts(&plan).await?;
+    println!(
+        "Planned {} day(s) from {} to {}",
+        plan.len(),
+        first.date.format("%Y-%m-%d"),
+        last.date.format("%Y-%m-%d")
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::UnitSystem;
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 8, d).unwrap()
+    }
+
+    #[test]
+    fn reads_a_plan_in_display_units() {
+        let csv = "date,miles,vert,note\n\
+                   2026-08-04,8,1500,\"Tempo, 3x10 min\"\n\
+                   \n\
+                   2026-08-03,,,\n\
+                   2026-08-05,5.5\n";
+        let plan = parse_csv(csv, Units::from(UnitSystem::Imperial)).unwrap();
+        assert_eq!(plan.len(), 3);
+        assert_eq!(plan[0].describe(Units::from(UnitSystem::Imperial)), "Rest");
+        assert_eq!(
+            plan[1],
+            PlannedWorkout {
+                date: day(4),
+                miles: Some(8.0),
+                vert: Some(1500),
+                note: Some("Tempo, 3x10 min".to_string()),
+            }
+        );
+        assert_eq!((plan[2].miles, plan[2].vert), (Some(5.5), None));
+
+        let metric = parse_csv("2026-08-04,10,500", Units::from(UnitSystem::Metric)).unwrap();
+        assert!((metric[0].miles.unwrap() - 6.2137).abs() < 0.001);
+        assert_eq!(metric[0].vert, Some(1640));
+
+        let error = |csv| parse_csv(csv, Units::from(UnitSystem::Imperial)).unwrap_err().to_string();
+        assert_eq!(error("date,miles\n8/4/2026,8"), "line 2: '8/4/2026' isn't a YYYY-MM-DD date");
+        assert_eq!(error("2026-08-04,far"), "line 1: 'far' isn't a distance");
+        assert_eq!(error("2026-08-04,8\n2026-08-04,9"), "line 2: 2026-08-04 is planned twice");
+    }
+
+    #[test]
+    fn adherence_caps_each_day_at_its_plan_and_skips_days_to_come() {
+        let plan: BTreeMap<NaiveDate, PlannedWorkout> = [(3, 0.0), (4, 8.0), (5, 4.0), (6, 10.0)]
+            .into_iter()
+            .map(|(d, miles)| {
+                let planned = PlannedWorkout {
+                    date: day(d),
+                    miles: Some(miles),
+                    vert: Some(1000),
+                    note: None,
+                };
+                (day(d), planned)
+            })
+            .collect();
+        let logs = [
+            DailyLog {
+                miles_covered: Some(3.0),
+                ..DailyLog::new(day(3))
+            },
+            DailyLog {
+                miles_covered: Some(12.0),
+                ..DailyLog::new(day(4))
+            },
+        ];
+        let actual = |date| logs.iter().find(|log| log.date == date);
+        let week: Vec<NaiveDate> = (3..=9).map(day).collect();
+
+        // The 12 mile day counts as the 8 planned; the 4 mile day was skipped
+        let standing = week_plan(&plan, &week, day(5), actual).unwrap();
+        assert_eq!((standing.miles, standing.vert), (22.0, 4000));
+        assert_eq!(standing.adherence, Some(8.0 / 12.0));
+        assert_eq!(week_plan(&plan, &week, day(3), actual).unwrap().adherence, None);
+        assert_eq!(week_plan(&plan, &[day(20)], day(20), actual), None);
+    }
+}
@@ -0,0 +1,148 @@
+//! The few places where Windows and Unix differ: locating the data directory,
+//! keeping credential files private, and the system clipboard. Everything
+//! else takes its inputs as arguments so both behaviours can be tested on any
+//! OS.
+
+use anyhow::{Context, Result, bail};
+use std::path::{Path, PathBuf};
+
+/// Resolves the data directory from a `MOUNTAINS_DATA_DIR` override, falling
+/// back to `.mountains` under `home`. The override is read as
+/// `expand_user_path` reads a path.
+pub fn resolve_data_dir(overridden: Option<&str>, home: Option<&Path>) -> Result<PathBuf> {
+    match overridden.filter(|dir| !unquote(dir).is_empty()) {
+        Some(dir) => expand_user_path(dir, home),
+        None => {
+            let home = home.context("Could not find home directory")?;
+            Ok(home.join(".mountains"))
+        }
+    }
+}
+
+/// A path as the user typed or pasted it: possibly quoted (as from
+/// Explorer's "Copy as path") and possibly starting with `~/` or `~\`.
+pub fn expand_user_path(typed: &str, home: Option<&Path>) -> Result<PathBuf> {
+    let path = unquote(typed);
+    if path.is_empty() {
+        bail!("No path given");
+    }
+    let rest = match path.strip_prefix('~') {
+        Some("") => "",
+        Some(rest) if rest.starts_with(['/', '\\']) => &rest[1..],
+        _ => return Ok(PathBuf::from(path)),
+    };
+    let home = home.context("Could not find home directory to expand ~")?;
+    Ok(rest
+        .split(['/', '\\'])
+        .filter(|part| !part.is_empty())
+        .fold(home.to_path_buf(), |path, part| path.join(part)))
+}
+
+fn unquote(typed: &str) -> &str {
+    typed.trim().trim_matches('"').trim()
+}
+
+/// Makes a file holding credentials readable by its owner only. Files under
+/// the Windows user profile already inherit an owner-only ACL, so there is
+/// nothing to change there.
+pub fn restrict_to_owner(path: &Path) -> Result<()> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600))
+            .with_context(|| format!("Failed to restrict permissions on {}", path.display()))?;
+    }
+    #[cfg(not(unix))]
+    let _ = path;
+    Ok(())
+}
+
+/// Somewhere copied text goes: the system clipboard, or a stand-in in tests.
+pub trait Clipboard {
+    fn copy(&mut self, text: &str) -> Result<()>;
+}
+
+/// The system clipboard, opened on first use and then kept open: on X11 and
+/// Wayland the copied text is served by this process, so it must outlive the
+/// copy for the paste to work.
+#[derive(Default)]
+pub struct SystemClipboard {
+    inner: Option<arboard::Clipboard>,
+}
+
+impl Clipboard for SystemClipboard {
+    fn copy(&mut self, text: &str) -> Result<()> {
+        let clipboard = match &mut self.inner {
+            Some(clipboard) => clipboard,
+            None => self
+                .inner
+                .insert(arboard::Clipboard::new().context("No clipboard available")?),
+        };
+        clipboard
+            .set_text(text)
+            .context("Failed to copy to the clipboard")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn data_dir_defaults_under_home_and_honours_overrides() {
+        let home = Path::new("home");
+        assert_eq!(
+            resolve_data_dir(None, Some(home)).unwrap(),
+            home.join(".mountains")
+        );
+        assert_eq!(
+            resolve_data_dir(Some("  "), Some(home)).unwrap(),
+            home.join(".mountains")
+        );
+        assert_eq!(
+            resolve_data_dir(Some("\"D:\\Training Log\""), Some(home)).unwrap(),
+            PathBuf::from("D:\\Training Log")
+        );
+        assert_eq!(
+            resolve_data_dir(Some("~\\logs\\mountains"), Some(home)).unwrap(),
+            home.join("logs").join("mountains")
+        );
+        assert_eq!(
+            resolve_data_dir(Some("~/logs"), Some(home)).unwrap(),
+            home.join("logs")
+        );
+        assert_eq!(
+            resolve_data_dir(Some("~user/logs"), Some(home)).unwrap(),
+            PathBuf::from("~user/logs")
+        );
+        assert!(resolve_data_dir(None, None).is_err());
+    }
+
+    #[test]
+    fn typed_paths_expand_the_home_directory() {
+        let home = Path::new("home");
+        assert_eq!(
+            expand_user_path(" \"~/Photos/lunch.jpg\" ", Some(home)).unwrap(),
+            home.join("Photos").join("lunch.jpg")
+        );
+        assert_eq!(
+            expand_user_path("/tmp/lunch.jpg", None).unwrap(),
+            PathBuf::from("/tmp/lunch.jpg")
+        );
+        assert!(expand_user_path("~/lunch.jpg", None).is_err());
+        assert!(expand_user_path("\"\"", Some(home)).is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn credential_files_are_owner_only() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        std::fs::write(&path, "token").unwrap();
+        restrict_to_owner(&path).unwrap();
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+}
@@ -0,0 +1,196 @@
+//! Render profiling for the F10 overlay: how long frames take, what each
+//! screen costs to draw, and, in debug builds, how many allocations it
+//! takes. Meant for checking a redesign or a new section didn't make the
+//! redraw, which runs about ten times a second, any heavier.
+//!
+//! Allocations are counted for the whole process, so a save, hook or sync
+//! running on another thread during a draw is counted against that frame.
+
+#[cfg(debug_assertions)]
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::collections::HashMap;
+use std::mem::Discriminant;
+#[cfg(debug_assertions)]
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use crate::models::AppScreen;
+
+#[cfg(debug_assertions)]
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+
+/// The system allocator, counting every allocation so a frame's can be
+/// told apart from the last one's. Installed in debug builds only.
+#[cfg(debug_assertions)]
+pub struct CountingAllocator;
+
+#[cfg(debug_assertions)]
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc_zeroed(layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+/// Allocations made so far, across every thread.
+#[cfg(debug_assertions)]
+pub fn allocations() -> u64 {
+    ALLOCATIONS.load(Ordering::Relaxed)
+}
+
+/// Release builds don't count allocations; the overlay leaves them out.
+#[cfg(not(debug_assertions))]
+pub fn allocations() -> u64 {
+    0
+}
+
+/// Running totals for frames or for one screen's draws.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Cost {
+    pub count: u32,
+    pub total: Duration,
+    pub worst: Duration,
+    pub last: Duration,
+    pub allocations: u64,
+}
+
+impl Cost {
+    fn record(&mut self, took: Duration, allocations: u64) {
+        self.count += 1;
+        self.total += took;
+        self.worst = self.worst.max(took);
+        self.last = took;
+        self.allocations += allocations;
+    }
+
+    pub fn average(&self) -> Duration {
+        self.total.checked_div(self.count).unwrap_or_default()
+    }
+
+    pub fn average_allocations(&self) -> u64 {
+        self.allocations.checked_div(self.count as u64).unwrap_or(0)
+    }
+}
+
+/// What the overlay shows. Nothing is recorded while it's off.
+#[derive(Debug, Default)]
+pub struct Profiler {
+    pub enabled: bool,
+    pub frames: Cost,
+    /// Allocations in the last frame.
+    pub last_allocations: u64,
+    screens: HashMap<Discriminant<AppScreen>, (String, Cost)>,
+}
+
+impl Profiler {
+    /// Shows or hides the overlay. Showing it starts the numbers fresh.
+    pub fn toggle(&mut self) {
+        *self = Self {
+            enabled: !self.enabled,
+            ..Self::default()
+        };
+    }
+
+    /// A whole frame: drawing the screen plus writing it to the terminal.
+    pub fn record_frame(&mut self, took: Duration, allocations: u64) {
+        if self.enabled {
+            self.frames.record(took, allocations);
+            self.last_allocations = allocations;
+        }
+    }
+
+    /// Drawing one screen, keyed by its kind so every food edit counts as
+    /// one screen. Named once, so recording doesn't allocate after that.
+    pub fn record_screen(&mut self, screen: &AppScreen, took: Duration, allocations: u64) {
+        if !self.enabled {
+            return;
+        }
+        self.screens
+            .entry(std::mem::discriminant(screen))
+            .or_insert_with(|| (screen_name(screen), Cost::default()))
+            .1
+            .record(took, allocations);
+    }
+
+    /// Each screen drawn since the overlay opened, costliest on average
+    /// first.
+    pub fn screens(&self) -> Vec<(&str, &Cost)> {
+        let mut screens: Vec<(&str, &Cost)> = self
+            .screens
+            .values()
+            .map(|(name, cost)| (name.as_str(), cost))
+            .collect();
+        screens.sort_by(|a, b| b.1.average().cmp(&a.1.average()).then_with(|| a.0.cmp(b.0)));
+        screens
+    }
+}
+
+/// "EditFood" for `EditFood(2)`.
+fn screen_name(screen: &AppScreen) -> String {
+    let name = format!("{:?}", screen);
+    match name.split_once('(') {
+        Some((kind, _)) => kind.to_string(),
+        None => name,
+    }
+}
+
+/// A duration in milliseconds to a tenth: "2.4 ms".
+pub fn millis(duration: Duration) -> String {
+    format!("{:.1} ms", duration.as_secs_f64() * 1000.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn records_frames_and_screens_only_while_on() {
+        let mut profiler = Profiler::default();
+        profiler.record_frame(Duration::from_millis(5), 100);
+        assert_eq!(profiler.frames.count, 0);
+
+        profiler.toggle();
+        profiler.record_frame(Duration::from_millis(2), 40);
+        profiler.record_frame(Duration::from_millis(6), 60);
+        profiler.record_screen(&AppScreen::EditFood(0), Duration::from_millis(1), 10);
+        profiler.record_screen(&AppScreen::EditFood(3), Duration::from_millis(3), 30);
+        profiler.record_screen(&AppScreen::Home, Duration::from_millis(1), 4);
+
+        assert_eq!(profiler.frames.average(), Duration::from_millis(4));
+        assert_eq!(profiler.frames.worst, Duration::from_millis(6));
+        assert_eq!(profiler.last_allocations, 60);
+        let screens = profiler.screens();
+        assert_eq!(screens[0].0, "EditFood");
+        assert_eq!((screens[0].1.count, screens[0].1.average_allocations()), (2, 20));
+        assert_eq!(screens[1].0, "Home");
+        assert_eq!(millis(screens[0].1.average()), "2.0 ms");
+
+        // Closing and reopening starts over
+        profiler.toggle();
+        profiler.toggle();
+        assert!(profiler.enabled && profiler.screens().is_empty());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn counts_allocations() {
+        let before = allocations();
+        let boxed = std::hint::black_box(Box::new([0u8; 64]));
+        assert!(allocations() > before);
+        drop(boxed);
+    }
+}
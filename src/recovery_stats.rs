@@ -0,0 +1,160 @@
+//! Morning resting heart rate and HRV trends for the startup screen: the
+//! latest reading against the week before it, with a warning when it has
+//! moved far enough to suggest an easy day.
+
+use chrono::{Days, NaiveDate};
+
+use crate::models::DailyLog;
+
+/// How many days before a reading make up its baseline.
+pub const BASELINE_DAYS: u64 = 7;
+/// Resting HR this many beats over the baseline earns a warning.
+pub const RESTING_HR_RISE: f32 = 5.0;
+/// HRV this fraction under the baseline earns a warning.
+pub const HRV_DROP: f32 = 0.15;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RecoveryMetric {
+    RestingHr,
+    Hrv,
+}
+
+impl RecoveryMetric {
+    pub fn label(self) -> &'static str {
+        match self {
+            RecoveryMetric::RestingHr => "Resting HR",
+            RecoveryMetric::Hrv => "HRV",
+        }
+    }
+
+    pub fn unit(self) -> &'static str {
+        match self {
+            RecoveryMetric::RestingHr => "bpm",
+            RecoveryMetric::Hrv => "ms",
+        }
+    }
+
+    fn read(self, log: &DailyLog) -> Option<u32> {
+        match self {
+            RecoveryMetric::RestingHr => log.resting_hr,
+            RecoveryMetric::Hrv => log.hrv,
+        }
+    }
+}
+
+/// The most recent reading and the average of the readings in the
+/// `BASELINE_DAYS` before it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trend {
+    pub metric: RecoveryMetric,
+    pub date: NaiveDate,
+    pub latest: u32,
+    /// None without any readings in the days before.
+    pub baseline: Option<f32>,
+}
+
+impl Trend {
+    /// "Resting HR 52 bpm (7-day avg 47)"
+    pub fn describe(&self) -> String {
+        let mut text = format!("{} {} {}", self.metric.label(), self.latest, self.metric.unit());
+        if let Some(baseline) = self.baseline {
+            text.push_str(&format!(" ({}-day avg {:.0})", BASELINE_DAYS, baseline));
+        }
+        text
+    }
+
+    /// A warning when the reading has moved the wrong way from its
+    /// baseline: resting HR up, or HRV down.
+    pub fn warning(&self) -> Option<String> {
+        let baseline = self.baseline?;
+        let latest = self.latest as f32;
+        match self.metric {
+            RecoveryMetric::RestingHr if latest - baseline >= RESTING_HR_RISE => Some(format!(
+                "Resting HR is {:.0} bpm above your {}-day average - maybe take it easy",
+                latest - baseline,
+                BASELINE_DAYS
+            )),
+            RecoveryMetric::Hrv if latest <= baseline * (1.0 - HRV_DROP) => Some(format!(
+                "HRV is {:.0}% below your {}-day average - maybe take it easy",
+                (1.0 - latest / baseline) * 100.0,
+                BASELINE_DAYS
+            )),
+            _ => None,
+        }
+    }
+}
+
+/// The trend for `metric` as of `today`, or None when nothing was read in
+/// the last `BASELINE_DAYS`: an old reading says little about this morning.
+pub fn trend(logs: &[DailyLog], metric: RecoveryMetric, today: NaiveDate) -> Option<Trend> {
+    let recent = today.checked_sub_days(Days::new(BASELINE_DAYS - 1))?;
+    let (date, latest) = logs
+        .iter()
+        .filter(|log| log.date >= recent && log.date <= today)
+        .filter_map(|log| metric.read(log).map(|value| (log.date, value)))
+        .max_by_key(|(date, _)| *date)?;
+
+    let since = date.checked_sub_days(Days::new(BASELINE_DAYS))?;
+    let before: Vec<u32> = logs
+        .iter()
+        .filter(|log| log.date >= since && log.date < date)
+        .filter_map(|log| metric.read(log))
+        .collect();
+    let baseline =
+        (!before.is_empty()).then(|| before.iter().sum::<u32>() as f32 / before.len() as f32);
+    Some(Trend {
+        metric,
+        date,
+        latest,
+        baseline,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 7, d).unwrap()
+    }
+
+    fn logs(readings: &[(u32, u32, u32)]) -> Vec<DailyLog> {
+        readings
+            .iter()
+            .map(|&(d, resting_hr, hrv)| DailyLog {
+                resting_hr: Some(resting_hr),
+                hrv: Some(hrv),
+                ..DailyLog::new(day(d))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn compares_the_latest_reading_with_the_week_before() {
+        let logs = logs(&[(1, 47, 72), (3, 46, 70), (5, 48, 74), (8, 53, 58)]);
+
+        let resting_hr = trend(&logs, RecoveryMetric::RestingHr, day(9)).unwrap();
+        assert_eq!(resting_hr.date, day(8));
+        assert_eq!(resting_hr.describe(), "Resting HR 53 bpm (7-day avg 47)");
+        assert_eq!(
+            resting_hr.warning().unwrap(),
+            "Resting HR is 6 bpm above your 7-day average - maybe take it easy"
+        );
+
+        let hrv = trend(&logs, RecoveryMetric::Hrv, day(9)).unwrap();
+        assert_eq!(
+            hrv.warning().unwrap(),
+            "HRV is 19% below your 7-day average - maybe take it easy"
+        );
+
+        // A steady reading, or one with nothing to compare to, is quiet
+        let steady = trend(&logs, RecoveryMetric::RestingHr, day(5)).unwrap();
+        assert_eq!(steady.warning(), None);
+        let first = trend(&logs, RecoveryMetric::Hrv, day(1)).unwrap();
+        assert_eq!((first.baseline, first.warning()), (None, None));
+        assert_eq!(first.describe(), "HRV 72 ms");
+
+        // Nothing read in the last week
+        assert_eq!(trend(&logs, RecoveryMetric::Hrv, day(20)), None);
+    }
+}
@@ -0,0 +1,105 @@
+//! Named trails and routes: which one a day's run was on, and what's been
+//! run on each over time.
+
+use chrono::NaiveDate;
+use std::collections::HashMap;
+
+use crate::models::DailyLog;
+
+/// Everything logged on one route.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RouteStats {
+    /// As it was last typed, since the same route may be typed differently.
+    pub name: String,
+    pub runs: usize,
+    pub miles: f32,
+    pub elevation: i32,
+    pub last_run: NaiveDate,
+}
+
+/// Each route with its runs and totals, most run first. Names that differ
+/// only in case or surrounding spaces are the same route.
+pub fn route_stats(logs: &[DailyLog]) -> Vec<RouteStats> {
+    let mut by_date: Vec<&DailyLog> = logs.iter().collect();
+    by_date.sort_by_key(|log| log.date);
+    let mut routes: HashMap<String, RouteStats> = HashMap::new();
+    for log in by_date {
+        let Some(name) = log.route.as_deref().map(str::trim).filter(|n| !n.is_empty()) else {
+            continue;
+        };
+        let route = routes
+            .entry(name.to_lowercase())
+            .or_insert_with(|| RouteStats {
+                name: String::new(),
+                runs: 0,
+                miles: 0.0,
+                elevation: 0,
+                last_run: log.date,
+            });
+        route.name = name.to_string();
+        route.runs += 1;
+        route.miles += log.miles_covered.unwrap_or(0.0);
+        route.elevation += log.elevation_gain.unwrap_or(0);
+        route.last_run = log.date;
+    }
+    let mut routes: Vec<RouteStats> = routes.into_values().collect();
+    routes.sort_by(|a, b| b.runs.cmp(&a.runs).then_with(|| a.name.cmp(&b.name)));
+    routes
+}
+
+/// Up to `limit` route names starting with `prefix` (ignoring case), most
+/// run first. Like food suggestions, an empty prefix or one that already
+/// names a route suggests nothing.
+pub fn route_suggestions(logs: &[DailyLog], prefix: &str, limit: usize) -> Vec<String> {
+    let prefix = prefix.trim_start().to_lowercase();
+    if prefix.is_empty() {
+        return Vec::new();
+    }
+    route_stats(logs)
+        .into_iter()
+        .map(|route| route.name)
+        .filter(|name| {
+            let name = name.to_lowercase();
+            name.starts_with(&prefix) && name != prefix
+        })
+        .take(limit)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(d: u32, route: &str, miles: f32, elevation: i32) -> DailyLog {
+        DailyLog {
+            route: Some(route.to_string()),
+            miles_covered: Some(miles),
+            elevation_gain: Some(elevation),
+            ..DailyLog::new(NaiveDate::from_ymd_opt(2026, 7, d).unwrap())
+        }
+    }
+
+    #[test]
+    fn totals_each_route_and_suggests_the_most_run() {
+        let logs = vec![
+            run(5, "Bear Peak loop", 7.5, 2800),
+            run(1, "bear peak loop ", 7.0, 2700),
+            run(3, "Mesa Trail", 6.0, 900),
+            run(4, "Bear Canyon", 5.0, 1500),
+            DailyLog::new(NaiveDate::from_ymd_opt(2026, 7, 6).unwrap()),
+        ];
+        let stats = route_stats(&logs);
+        assert_eq!(stats.len(), 3);
+        assert_eq!(stats[0].name, "Bear Peak loop");
+        assert_eq!((stats[0].runs, stats[0].miles, stats[0].elevation), (2, 14.5, 5500));
+        assert_eq!(stats[0].last_run, NaiveDate::from_ymd_opt(2026, 7, 5).unwrap());
+        assert_eq!(stats[1].name, "Bear Canyon");
+
+        assert_eq!(
+            route_suggestions(&logs, "bea", 5),
+            vec!["Bear Peak loop".to_string(), "Bear Canyon".to_string()]
+        );
+        assert!(route_suggestions(&logs, "mesa trail", 5).is_empty());
+        assert!(route_suggestions(&logs, "", 5).is_empty());
+    }
+}
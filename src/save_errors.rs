@@ -0,0 +1,194 @@
+//! Saves that didn't make it to disk. A locked or busy database is worth a
+//! few more tries; anything else (a full disk, a read-only directory) won't
+//! fix itself, so the day is kept for the failed saves screen, where it can
+//! be retried once the cause is sorted or exported as JSON.
+
+use anyhow::{Context, Result};
+use chrono::NaiveDate;
+use std::collections::HashMap;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::models::DailyLog;
+
+/// Waits between attempts at a transient failure. Once these run out the
+/// save is reported like a permanent one.
+pub const RETRY_DELAYS: [Duration; 3] = [
+    Duration::from_millis(250),
+    Duration::from_secs(1),
+    Duration::from_secs(4),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FailureKind {
+    /// Likely to clear up on its own: the database is locked or busy.
+    Transient,
+    /// Needs a person: no space, no permission, a broken file.
+    Permanent,
+}
+
+/// SQLITE_BUSY and SQLITE_LOCKED: another connection holds a lock.
+const SQLITE_BUSY: i32 = 5;
+const SQLITE_LOCKED: i32 = 6;
+
+/// SQLite's own messages for those two codes, for errors that reach us
+/// only as text.
+const TRANSIENT_MESSAGES: &[&str] = &["database is locked", "database table is locked"];
+
+/// Looks through `error` and its causes for something that says waiting
+/// will help. Anything unrecognized is treated as permanent, since retrying
+/// it would only delay the report.
+pub fn classify(error: &anyhow::Error) -> FailureKind {
+    for cause in error.chain() {
+        if let Some(io) = cause.downcast_ref::<std::io::Error>() {
+            return match io.kind() {
+                ErrorKind::WouldBlock
+                | ErrorKind::Interrupted
+                | ErrorKind::TimedOut
+                | ErrorKind::ResourceBusy => FailureKind::Transient,
+                _ => FailureKind::Permanent,
+            };
+        }
+        if let Some(sqlite) = cause.downcast_ref::<libsql::Error>() {
+            let code = match sqlite {
+                libsql::Error::SqliteFailure(code, _) => Some(*code),
+                libsql::Error::RemoteSqliteFailure(code, _, _) => Some(*code),
+                _ => None,
+            };
+            // Extended codes keep the primary code in the low byte
+            if let Some(code) = code {
+                return match code & 0xff {
+                    SQLITE_BUSY | SQLITE_LOCKED => FailureKind::Transient,
+                    _ => FailureKind::Permanent,
+                };
+            }
+        }
+        let message = cause.to_string().to_lowercase();
+        if TRANSIENT_MESSAGES.iter().any(|m| message.contains(m)) {
+            return FailureKind::Transient;
+        }
+    }
+    FailureKind::Permanent
+}
+
+/// Keeps the saves of each day in order. Each save takes a number when it's
+/// queued and holds its day's lock while it writes and retries, so an older
+/// save can't land after a newer one, and one that's been overtaken while
+/// waiting is dropped rather than written over the newer day.
+#[derive(Debug, Clone, Default)]
+pub struct SaveQueue {
+    days: Arc<Mutex<HashMap<NaiveDate, DaySaves>>>,
+}
+
+#[derive(Debug, Default)]
+struct DaySaves {
+    latest: u64,
+    lock: Arc<tokio::sync::Mutex<()>>,
+}
+
+impl SaveQueue {
+    /// Queues a save of `date`, returning its number and the day's lock.
+    pub fn enqueue(&self, date: NaiveDate) -> (u64, Arc<tokio::sync::Mutex<()>>) {
+        let mut days = self.days.lock().unwrap_or_else(|e| e.into_inner());
+        let day = days.entry(date).or_default();
+        day.latest += 1;
+        (day.latest, Arc::clone(&day.lock))
+    }
+
+    /// Whether a save of `date` was queued after the one numbered `save`.
+    pub fn superseded(&self, date: NaiveDate, save: u64) -> bool {
+        let days = self.days.lock().unwrap_or_else(|e| e.into_inner());
+        days.get(&date).is_some_and(|day| day.latest > save)
+    }
+}
+
+/// A day whose latest save failed for good.
+#[derive(Debug, Clone)]
+pub struct FailedSave {
+    /// The day as it was sent to be saved.
+    pub log: DailyLog,
+    /// The last error, with its causes.
+    pub error: String,
+    pub kind: FailureKind,
+    pub attempts: usize,
+}
+
+/// Writes `log` as pretty JSON into `dir`, returning the file's path, so a
+/// day that can't be saved isn't lost with the app.
+pub fn export_json(dir: &Path, log: &DailyLog) -> Result<PathBuf> {
+    std::fs::create_dir_all(dir).context("Failed to create the exports directory")?;
+    let path = dir.join(format!("unsaved-{}.json", log.date.format("%Y-%m-%d")));
+    let json = serde_json::to_string_pretty(log).context("Failed to serialize the day")?;
+    std::fs::write(&path, json).with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn locks_are_retried_and_everything_else_is_reported() {
+        let locked = anyhow::anyhow!("SQLite failure: `database is locked`")
+            .context("Failed to save daily log");
+        assert_eq!(classify(&locked), FailureKind::Transient);
+
+        let full = anyhow::Error::from(std::io::Error::from(ErrorKind::StorageFull))
+            .context("Failed to write to file");
+        assert_eq!(classify(&full), FailureKind::Permanent);
+        let timed_out = anyhow::Error::from(std::io::Error::from(ErrorKind::TimedOut));
+        assert_eq!(classify(&timed_out), FailureKind::Transient);
+        assert_eq!(
+            classify(&anyhow::anyhow!("no such table: daily_logs")),
+            FailureKind::Permanent
+        );
+
+        // Busy and locked go by their codes, extended ones included
+        let busy = anyhow::Error::from(libsql::Error::SqliteFailure(5, "whatever".into()))
+            .context("Failed to save daily log");
+        assert_eq!(classify(&busy), FailureKind::Transient);
+        let snapshot = libsql::Error::SqliteFailure(6 | (1 << 8), "locked".into());
+        assert_eq!(classify(&anyhow::Error::from(snapshot)), FailureKind::Transient);
+        let read_only = libsql::Error::SqliteFailure(8, "database is locked".into());
+        assert_eq!(classify(&anyhow::Error::from(read_only)), FailureKind::Permanent);
+        // Anything else that merely mentions being busy isn't a lock
+        assert_eq!(
+            classify(&anyhow::anyhow!("sync server busy, try later")),
+            FailureKind::Permanent
+        );
+    }
+
+    #[tokio::test]
+    async fn a_newer_save_of_the_day_supersedes_an_older_one() {
+        let queue = SaveQueue::default();
+        let day = NaiveDate::from_ymd_opt(2026, 7, 22).unwrap();
+        let other = NaiveDate::from_ymd_opt(2026, 7, 23).unwrap();
+        let (first, lock) = queue.enqueue(day);
+        let guard = lock.lock().await;
+        let (second, same_lock) = queue.enqueue(day);
+        queue.enqueue(other);
+        assert!(Arc::ptr_eq(&lock, &same_lock));
+        assert!(same_lock.try_lock().is_err());
+        assert!(queue.superseded(day, first));
+        assert!(!queue.superseded(day, second));
+        drop(guard);
+        assert!(same_lock.try_lock().is_ok());
+    }
+
+    #[test]
+    fn export_writes_the_day_as_json() {
+        let dir = TempDir::new().unwrap();
+        let log = DailyLog {
+            miles_covered: Some(4.2),
+            ..DailyLog::new(NaiveDate::from_ymd_opt(2026, 7, 22).unwrap())
+        };
+        let path = export_json(&dir.path().join("exports"), &log).unwrap();
+        assert!(path.ends_with("unsaved-2026-07-22.json"));
+        let json: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+        assert_eq!(json["miles_covered"], 4.2);
+    }
+}
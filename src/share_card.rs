@@ -0,0 +1,186 @@
+//! The PNG "share card" for a day: date, distance, vert and the first line
+//! of the notes, sized for social media previews. Drawn with
+//! embedded-graphics' built-in bitmap fonts so no font files have to ship
+//! with the app, then scaled up and encoded with `png`.
+
+use anyhow::{Context, Result};
+use embedded_graphics::{
+    mono_font::{
+        MonoFont, MonoTextStyle,
+        iso_8859_1::{FONT_6X10, FONT_7X13, FONT_10X20},
+    },
+    pixelcolor::Rgb888,
+    prelude::*,
+    primitives::{PrimitiveStyle, Rectangle},
+    text::{Baseline, Text},
+};
+use std::path::{Path, PathBuf};
+
+use crate::models::DailyLog;
+use crate::format::Units;
+use crate::units::Quantity;
+
+/// Drawing size; the saved image is `SCALE` times larger, 1200x630, the
+/// size most sites use for link and post previews.
+const WIDTH: u32 = 400;
+const HEIGHT: u32 = 210;
+const SCALE: u32 = 3;
+const MARGIN: i32 = 20;
+
+const BACKGROUND: Rgb888 = Rgb888::new(24, 28, 36);
+const ACCENT: Rgb888 = Rgb888::new(57, 211, 83);
+const TEXT: Rgb888 = Rgb888::new(235, 235, 235);
+const LABEL: Rgb888 = Rgb888::new(140, 146, 158);
+
+/// An RGB framebuffer embedded-graphics draws into.
+struct Canvas {
+    pixels: Vec<u8>,
+}
+
+impl Canvas {
+    fn new() -> Self {
+        Self {
+            pixels: vec![0; (WIDTH * HEIGHT * 3) as usize],
+        }
+    }
+
+    /// The canvas scaled up by `SCALE`, encoded as PNG.
+    fn to_png(&self) -> Result<Vec<u8>> {
+        let (width, height) = (WIDTH * SCALE, HEIGHT * SCALE);
+        let mut scaled = Vec::with_capacity((width * height * 3) as usize);
+        for y in 0..height {
+            let row = ((y / SCALE) * WIDTH * 3) as usize;
+            for x in 0..width {
+                let i = row + ((x / SCALE) * 3) as usize;
+                scaled.extend_from_slice(&self.pixels[i..i + 3]);
+            }
+        }
+
+        let mut png = Vec::new();
+        let mut encoder = png::Encoder::new(&mut png, width, height);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().context("Failed to start the PNG")?;
+        writer
+            .write_image_data(&scaled)
+            .context("Failed to encode the PNG")?;
+        writer.finish().context("Failed to finish the PNG")?;
+        Ok(png)
+    }
+}
+
+impl OriginDimensions for Canvas {
+    fn size(&self) -> Size {
+        Size::new(WIDTH, HEIGHT)
+    }
+}
+
+impl DrawTarget for Canvas {
+    type Color = Rgb888;
+    type Error = std::convert::Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(point, color) in pixels {
+            if let (Ok(x), Ok(y)) = (u32::try_from(point.x), u32::try_from(point.y))
+                && x < WIDTH
+                && y < HEIGHT
+            {
+                let i = ((y * WIDTH + x) * 3) as usize;
+                self.pixels[i..i + 3].copy_from_slice(&[color.r(), color.g(), color.b()]);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The first non-blank line of the notes, cut to `max` characters.
+fn note_line(notes: Option<&str>, max: usize) -> Option<String> {
+    let line = notes?
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())?;
+    if line.chars().count() <= max {
+        return Some(line.to_string());
+    }
+    let cut: String = line.chars().take(max.saturating_sub(3)).collect();
+    Some(format!("{}...", cut.trim_end()))
+}
+
+fn text(canvas: &mut Canvas, content: &str, x: i32, y: i32, font: &MonoFont, color: Rgb888) {
+    let style = MonoTextStyle::new(font, color);
+    let _ = Text::with_baseline(content, Point::new(x, y), style, Baseline::Top).draw(canvas);
+}
+
+/// Draws the card for `log` and returns it as PNG bytes.
+pub fn render(log: &DailyLog, units: Units) -> Result<Vec<u8>> {
+    let mut canvas = Canvas::new();
+    let _ = canvas.clear(BACKGROUND);
+    let _ = Rectangle::new(Point::zero(), Size::new(WIDTH, 4))
+        .into_styled(PrimitiveStyle::with_fill(ACCENT))
+        .draw(&mut canvas);
+
+    text(&mut canvas, "MOUNTAINS", MARGIN, 18, &FONT_6X10, ACCENT);
+    let date = units.weekday_date(log.date);
+    text(&mut canvas, &date, MARGIN, 34, &FONT_10X20, TEXT);
+
+    let distance = log.miles_covered.map_or("-".to_string(), |miles| {
+        units.format(Quantity::Distance, miles)
+    });
+    let vert = log.elevation_gain.map_or("-".to_string(), |feet| {
+        units.total(Quantity::Elevation, feet as f64)
+    });
+    let column = (WIDTH as i32) / 2;
+    text(&mut canvas, &distance, MARGIN, 84, &FONT_10X20, TEXT);
+    text(&mut canvas, "DISTANCE", MARGIN, 108, &FONT_6X10, LABEL);
+    text(&mut canvas, &vert, column, 84, &FONT_10X20, TEXT);
+    text(&mut canvas, "VERT", column, 108, &FONT_6X10, LABEL);
+
+    let width = FONT_7X13.character_size.width + FONT_7X13.character_spacing;
+    let max = (WIDTH - 2 * MARGIN as u32) / width;
+    if let Some(note) = note_line(log.notes.as_deref(), max as usize) {
+        text(&mut canvas, &note, MARGIN, 156, &FONT_7X13, TEXT);
+    }
+
+    canvas.to_png()
+}
+
+/// Renders the card for `log` into `dir`, creating it if needed, and returns
+/// the file written.
+pub fn save(log: &DailyLog, units: Units, dir: &Path) -> Result<PathBuf> {
+    std::fs::create_dir_all(dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+    let path = dir.join(format!("share-{}.png", log.date.format("%Y-%m-%d")));
+    std::fs::write(&path, render(log, units)?)
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::UnitSystem;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn card_is_a_preview_sized_png() {
+        let mut log = DailyLog::new(NaiveDate::from_ymd_opt(2024, 6, 1).unwrap());
+        log.miles_covered = Some(12.4);
+        log.elevation_gain = Some(3200);
+        log.notes = Some("\n  Ridge loop in the fog\nsecond line".to_string());
+
+        let png = render(&log, UnitSystem::Imperial.into()).unwrap();
+        let decoder = png::Decoder::new(std::io::Cursor::new(png));
+        let reader = decoder.read_info().unwrap();
+        assert_eq!(reader.info().width, 1200);
+        assert_eq!(reader.info().height, 630);
+
+        assert_eq!(
+            note_line(log.notes.as_deref(), 40).unwrap(),
+            "Ridge loop in the fog"
+        );
+        assert_eq!(note_line(Some("abcdefghij"), 8).unwrap(), "abcde...");
+        assert_eq!(note_line(Some("  \n "), 8), None);
+    }
+}
@@ -0,0 +1,58 @@
+use crate::models::DailyLog;
+use chrono::{NaiveDate, Weekday};
+
+/// Average hours slept over the nights in `logs` that have sleep logged, to
+/// two decimals, or None when none do.
+pub fn average_sleep<'a>(logs: impl IntoIterator<Item = &'a DailyLog>) -> Option<f32> {
+    let nights: Vec<f32> = logs.into_iter().filter_map(|log| log.sleep_hours).collect();
+    if nights.is_empty() {
+        return None;
+    }
+    let average = nights.iter().sum::<f32>() / nights.len() as f32;
+    Some((average * 100.0).round() / 100.0)
+}
+
+/// Average sleep for each week (keyed by its first day) from the week
+/// containing `from` through the week containing `to`, oldest first. Weeks
+/// without any sleep logged are None.
+pub fn weekly_sleep_series(
+    logs: &[DailyLog],
+    from: NaiveDate,
+    to: NaiveDate,
+    week_start: Weekday,
+) -> Vec<(NaiveDate, Option<f32>)> {
+    let first = from.week(week_start).first_day();
+    first
+        .iter_weeks()
+        .take_while(|week| *week <= to)
+        .map(|week| {
+            let nights = logs
+                .iter()
+                .filter(|log| log.date.week(week_start).first_day() == week);
+            (week, average_sleep(nights))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn weekly_averages_skip_nights_without_sleep() {
+        let day = |d| NaiveDate::from_ymd_opt(2026, 7, d).unwrap();
+        let logs: Vec<DailyLog> = [(6, Some(7.0)), (7, None), (8, Some(8.5)), (20, Some(6.0))]
+            .into_iter()
+            .map(|(d, sleep_hours)| DailyLog {
+                sleep_hours,
+                ..DailyLog::new(day(d))
+            })
+            .collect();
+
+        assert_eq!(
+            weekly_sleep_series(&logs, day(7), day(21), Weekday::Mon),
+            vec![(day(6), Some(7.75)), (day(13), None), (day(20), Some(6.0))]
+        );
+        assert_eq!(average_sleep(&logs[1..2]), None);
+    }
+}
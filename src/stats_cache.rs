@@ -0,0 +1,508 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use chrono::{Datelike, NaiveDate, Weekday};
+
+use crate::config::StatsConfig;
+use crate::elevation_stats::{
+    calculate_current_streak, calculate_monthly_elevation, calculate_weekly_elevation,
+    calculate_yearly_elevation, count_monthly_vert_days,
+};
+use crate::milestones::PACE_WINDOW_DAYS;
+use crate::miles_stats::{calculate_monthly_miles, calculate_weekly_miles, calculate_yearly_miles};
+use crate::models::DailyLog;
+use crate::streaks::{Streak, StreakRule, StreakSpan, current_streak, streak_history};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Metric {
+    Miles,
+    Elevation,
+    VertDays,
+    DaysLogged,
+}
+
+/// Lengths of the rolling totals, in days, shown on the startup screen and
+/// under the day's run.
+pub const ROLLING_DAYS: [u32; 2] = [7, 28];
+
+/// Period a stat covers. Calendar periods are normalized to their first day
+/// so every date in the same week/month/year shares one cache entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Period {
+    Week(NaiveDate),
+    Month(NaiveDate),
+    Year(NaiveDate),
+    /// The `days` days ending on `end`, inclusive.
+    Trailing { end: NaiveDate, days: u32 },
+}
+
+impl Period {
+    pub fn week_of(date: NaiveDate, week_start: Weekday) -> Self {
+        Period::Week(date.week(week_start).first_day())
+    }
+
+    pub fn month_of(date: NaiveDate) -> Self {
+        Period::Month(date.with_day(1).unwrap_or(date))
+    }
+
+    pub fn year_of(date: NaiveDate) -> Self {
+        Period::Year(date.with_ordinal(1).unwrap_or(date))
+    }
+
+    pub fn trailing(end: NaiveDate, days: u32) -> Self {
+        Period::Trailing { end, days }
+    }
+
+    /// Whether `date` falls in the period.
+    pub fn contains(self, date: NaiveDate, week_start: Weekday) -> bool {
+        match self {
+            Period::Week(_) => Period::week_of(date, week_start) == self,
+            Period::Month(_) => Period::month_of(date) == self,
+            Period::Year(_) => Period::year_of(date) == self,
+            Period::Trailing { end, days } => {
+                date <= end && (end - date).num_days() < days as i64
+            }
+        }
+    }
+}
+
+/// All-time totals across every log.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct LifetimeTotals {
+    pub miles: f32,
+    pub elevation: i32,
+    pub days_logged: usize,
+    pub first_log: Option<NaiveDate>,
+    /// Feet of vert per mile over the days with distance logged, leaving
+    /// out indoor days unless `[stats] count_indoor`.
+    pub vert_per_mile: Option<f32>,
+}
+
+impl LifetimeTotals {
+    fn compute(logs: &[DailyLog], count_indoor: bool) -> Self {
+        let (graded_miles, graded_elevation) = logs
+            .iter()
+            .filter(|log| count_indoor || !log.indoor)
+            .filter_map(|log| {
+                let miles = log.miles_covered.filter(|&miles| miles > 0.0)?;
+                Some((miles, log.elevation_gain.unwrap_or(0)))
+            })
+            .fold((0.0, 0), |(miles, elevation), (m, e)| (miles + m, elevation + e));
+        LifetimeTotals {
+            miles: logs.iter().filter_map(|log| log.miles_covered).sum(),
+            elevation: logs.iter().filter_map(|log| log.elevation_gain).sum(),
+            days_logged: logs.len(),
+            first_log: logs.iter().map(|log| log.date).min(),
+            vert_per_mile: (graded_miles > 0.0).then(|| graded_elevation as f32 / graded_miles),
+        }
+    }
+}
+
+/// How long it's been since `first`, in the largest two units that apply:
+/// "2 years, 3 months", "5 months", "12 days".
+pub fn training_age(first: NaiveDate, today: NaiveDate) -> String {
+    let plural = |count: u32, unit: &str| {
+        format!("{} {}{}", count, unit, if count == 1 { "" } else { "s" })
+    };
+    let mut months = (today.year() - first.year()) * 12 + today.month() as i32
+        - first.month() as i32;
+    if today.day() < first.day() {
+        months -= 1;
+    }
+    if months <= 0 {
+        return plural((today - first).num_days().max(0) as u32, "day");
+    }
+    let (years, months) = (months as u32 / 12, months as u32 % 12);
+    match (years, months) {
+        (0, months) => plural(months, "month"),
+        (years, 0) => plural(years, "year"),
+        (years, months) => format!("{}, {}", plural(years, "year"), plural(months, "month")),
+    }
+}
+
+/// Memoized period stats for the render path. The screens redraw ~10 times a
+/// second, so totals are computed once per (metric, period) and reused until
+/// the logs change and `invalidate` drops everything.
+#[derive(Debug, Default)]
+pub struct StatsCache {
+    config: StatsConfig,
+    values: RefCell<HashMap<(Metric, Period), f64>>,
+    streak: RefCell<Option<Option<usize>>>,
+    lifetime: RefCell<Option<LifetimeTotals>>,
+    recent: RefCell<Option<(NaiveDate, f32, i32)>>,
+    streak_rules: Vec<StreakRule>,
+    streaks: RefCell<Option<Vec<Option<Streak>>>>,
+    run_streak: RefCell<Option<Option<Streak>>>,
+    run_streak_history: RefCell<Option<Vec<StreakSpan>>>,
+}
+
+impl StatsCache {
+    /// Applies the `[stats]` settings, dropping anything computed under the
+    /// old ones.
+    pub fn configure(&mut self, config: StatsConfig) {
+        self.config = config;
+        self.invalidate();
+    }
+
+    pub fn config(&self) -> StatsConfig {
+        self.config
+    }
+
+    pub fn invalidate(&self) {
+        self.values.borrow_mut().clear();
+        self.streak.borrow_mut().take();
+        self.lifetime.borrow_mut().take();
+        self.recent.borrow_mut().take();
+        self.streaks.borrow_mut().take();
+        self.run_streak.borrow_mut().take();
+        self.run_streak_history.borrow_mut().take();
+    }
+
+    /// Sets the `[[streaks.rules]]` to track, or none for just the vert
+    /// streak.
+    pub fn set_streak_rules(&mut self, rules: Vec<StreakRule>) {
+        self.streak_rules = rules;
+        self.invalidate();
+    }
+
+    /// Each tracked rule with its current streak, if one is going.
+    pub fn streaks(&self, logs: &[DailyLog]) -> Vec<(StreakRule, Option<Streak>)> {
+        let rules = if self.streak_rules.is_empty() {
+            vec![StreakRule::vert(self.config.vert_threshold)]
+        } else {
+            self.streak_rules.clone()
+        };
+        let streaks = self
+            .streaks
+            .borrow_mut()
+            .get_or_insert_with(|| {
+                rules
+                    .iter()
+                    .map(|rule| current_streak(logs, rule, self.config.count_indoor))
+                    .collect()
+            })
+            .clone();
+        rules.into_iter().zip(streaks).collect()
+    }
+
+    /// The running streak going now: consecutive days with any miles.
+    pub fn run_streak(&self, logs: &[DailyLog]) -> Option<Streak> {
+        *self.run_streak.borrow_mut().get_or_insert_with(|| {
+            current_streak(logs, &StreakRule::run(), self.config.count_indoor)
+        })
+    }
+
+    /// Up to `limit` of the longest running streaks on record, longest
+    /// first.
+    pub fn longest_run_streaks(&self, logs: &[DailyLog], limit: usize) -> Vec<StreakSpan> {
+        let mut history = self.run_streak_history.borrow_mut();
+        let history = history.get_or_insert_with(|| {
+            streak_history(logs, &StreakRule::run(), self.config.count_indoor)
+        });
+        history.iter().take(limit).copied().collect()
+    }
+
+    pub fn miles(&self, logs: &[DailyLog], period: Period) -> f32 {
+        self.get(logs, Metric::Miles, period) as f32
+    }
+
+    pub fn elevation(&self, logs: &[DailyLog], period: Period) -> i32 {
+        self.get(logs, Metric::Elevation, period) as i32
+    }
+
+    /// Days with a log in `period`.
+    pub fn days_logged(&self, logs: &[DailyLog], period: Period) -> usize {
+        self.get(logs, Metric::DaysLogged, period) as usize
+    }
+
+    /// Days at or over the configured vert threshold in the month containing
+    /// `date`.
+    pub fn vert_days(&self, logs: &[DailyLog], date: NaiveDate) -> usize {
+        self.get(logs, Metric::VertDays, Period::month_of(date)) as usize
+    }
+
+    pub fn current_streak(&self, logs: &[DailyLog]) -> Option<usize> {
+        *self
+            .streak
+            .borrow_mut()
+            .get_or_insert_with(|| {
+                calculate_current_streak(logs, self.config.vert_threshold, self.config.count_indoor)
+            })
+    }
+
+    pub fn lifetime(&self, logs: &[DailyLog]) -> LifetimeTotals {
+        *self
+            .lifetime
+            .borrow_mut()
+            .get_or_insert_with(|| LifetimeTotals::compute(logs, self.config.count_indoor))
+    }
+
+    /// Distance and vert over the `PACE_WINDOW_DAYS` days up to `today`, the
+    /// pace milestone projections go by.
+    pub fn recent(&self, logs: &[DailyLog], today: NaiveDate) -> (f32, i32) {
+        let mut recent = self.recent.borrow_mut();
+        if let Some((date, miles, elevation)) = *recent
+            && date == today
+        {
+            return (miles, elevation);
+        }
+        let since = today - chrono::Days::new(PACE_WINDOW_DAYS);
+        let window = logs.iter().filter(|log| log.date > since && log.date <= today);
+        let (miles, elevation) = window.fold((0.0, 0), |(miles, elevation), log| {
+            (
+                miles + log.miles_covered.unwrap_or(0.0),
+                elevation + log.elevation_gain.unwrap_or(0),
+            )
+        });
+        *recent = Some((today, miles, elevation));
+        (miles, elevation)
+    }
+
+    fn get(&self, logs: &[DailyLog], metric: Metric, period: Period) -> f64 {
+        *self
+            .values
+            .borrow_mut()
+            .entry((metric, period))
+            .or_insert_with(|| compute(logs, metric, period, self.config))
+    }
+}
+
+fn compute(logs: &[DailyLog], metric: Metric, period: Period, config: StatsConfig) -> f64 {
+    let week_start = config.week_start;
+    match (metric, period) {
+        (Metric::Miles, Period::Week(date)) => {
+            calculate_weekly_miles(logs, date, week_start) as f64
+        }
+        (Metric::Miles, Period::Month(date)) => calculate_monthly_miles(logs, date) as f64,
+        (Metric::Miles, Period::Year(date)) => calculate_yearly_miles(logs, date) as f64,
+        (Metric::Elevation, Period::Week(date)) => {
+            calculate_weekly_elevation(logs, date, week_start) as f64
+        }
+        (Metric::Elevation, Period::Month(date)) => calculate_monthly_elevation(logs, date) as f64,
+        (Metric::Elevation, Period::Year(date)) => calculate_yearly_elevation(logs, date) as f64,
+        (Metric::VertDays, Period::Week(date) | Period::Month(date) | Period::Year(date)) => {
+            count_monthly_vert_days(logs, date, config.vert_threshold) as f64
+        }
+        (metric, Period::Trailing { .. }) => {
+            let window = logs.iter().filter(|log| period.contains(log.date, week_start));
+            match metric {
+                // Folded from 0.0, since an empty float sum is -0.0
+                Metric::Miles => window
+                    .filter_map(|log| log.miles_covered)
+                    .fold(0.0, |total, miles| total + miles) as f64,
+                Metric::Elevation => window.filter_map(|log| log.elevation_gain).sum::<i32>() as f64,
+                Metric::VertDays => window
+                    .filter(|log| log.elevation_gain.unwrap_or(0) >= config.vert_threshold)
+                    .count() as f64,
+                Metric::DaysLogged => window.count() as f64,
+            }
+        }
+        (Metric::DaysLogged, period) => {
+            logs.iter().filter(|log| period.contains(log.date, week_start)).count() as f64
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn log(date: NaiveDate, miles: f32, elevation: i32) -> DailyLog {
+        DailyLog {
+            miles_covered: Some(miles),
+            elevation_gain: Some(elevation),
+            ..DailyLog::new(date)
+        }
+    }
+
+    #[test]
+    fn periods_normalize_to_their_first_day() {
+        let date = NaiveDate::from_ymd_opt(2026, 7, 23).unwrap();
+        assert_eq!(
+            Period::week_of(date, Weekday::Mon),
+            Period::Week(NaiveDate::from_ymd_opt(2026, 7, 20).unwrap())
+        );
+        assert_eq!(
+            Period::week_of(date, Weekday::Sun),
+            Period::Week(NaiveDate::from_ymd_opt(2026, 7, 19).unwrap())
+        );
+        assert_eq!(
+            Period::month_of(date),
+            Period::Month(NaiveDate::from_ymd_opt(2026, 7, 1).unwrap())
+        );
+        assert_eq!(
+            Period::year_of(date),
+            Period::Year(NaiveDate::from_ymd_opt(2026, 1, 1).unwrap())
+        );
+    }
+
+    #[test]
+    fn cached_values_hold_until_invalidated() {
+        let date = NaiveDate::from_ymd_opt(2026, 7, 22).unwrap();
+        let mut logs = vec![log(date, 5.5, 1200)];
+        let cache = StatsCache::default();
+
+        assert_eq!(cache.miles(&logs, Period::month_of(date)), 5.5);
+        assert_eq!(cache.elevation(&logs, Period::year_of(date)), 1200);
+        assert_eq!(cache.vert_days(&logs, date), 1);
+
+        logs.push(log(date.pred_opt().unwrap(), 3.0, 1500));
+        assert_eq!(cache.miles(&logs, Period::month_of(date)), 5.5);
+        assert_eq!(cache.current_streak(&logs), Some(2));
+
+        cache.invalidate();
+        assert_eq!(cache.miles(&logs, Period::month_of(date)), 8.5);
+        assert_eq!(
+            cache.elevation(&logs, Period::week_of(date, Weekday::Mon)),
+            2700
+        );
+        assert_eq!(cache.vert_days(&logs, date), 2);
+        assert_eq!(cache.days_logged(&logs, Period::month_of(date)), 2);
+        assert_eq!(cache.days_logged(&logs, Period::year_of(date.with_year(2025).unwrap())), 0);
+    }
+
+    #[test]
+    fn trailing_periods_end_on_the_day_and_reach_back() {
+        let date = NaiveDate::from_ymd_opt(2026, 7, 22).unwrap();
+        let logs = vec![
+            log(date, 5.0, 1200),
+            log(date - chrono::Days::new(6), 3.0, 800),
+            log(date - chrono::Days::new(7), 10.0, 3000),
+            log(date + chrono::Days::new(1), 4.0, 400),
+        ];
+        let cache = StatsCache::default();
+        assert_eq!(cache.miles(&logs, Period::trailing(date, 7)), 8.0);
+        assert_eq!(cache.elevation(&logs, Period::trailing(date, 7)), 2000);
+        assert_eq!(cache.miles(&logs, Period::trailing(date, 28)), 18.0);
+        assert_eq!(cache.days_logged(&logs, Period::trailing(date, 28)), 3);
+    }
+
+    #[test]
+    fn lifetime_totals_cover_every_log_until_invalidated() {
+        let date = NaiveDate::from_ymd_opt(2026, 7, 22).unwrap();
+        let mut logs = vec![log(date, 5.5, 1200), log(date - chrono::Days::new(400), 3.0, 800)];
+        let cache = StatsCache::default();
+        let lifetime = cache.lifetime(&logs);
+        assert_eq!(lifetime.miles, 8.5);
+        assert_eq!(lifetime.elevation, 2000);
+        assert_eq!(lifetime.days_logged, 2);
+        assert_eq!(lifetime.first_log, NaiveDate::from_ymd_opt(2025, 6, 17));
+
+        assert_eq!(cache.recent(&logs, date), (5.5, 1200));
+
+        logs.push(log(date, 1.0, 100));
+        assert_eq!(cache.lifetime(&logs).days_logged, 2);
+        cache.invalidate();
+        assert_eq!(cache.lifetime(&logs).days_logged, 3);
+    }
+
+    #[test]
+    fn vert_per_mile_can_leave_out_indoor_days() {
+        let date = NaiveDate::from_ymd_opt(2026, 7, 22).unwrap();
+        let treadmill = DailyLog {
+            indoor: true,
+            ..log(date.pred_opt().unwrap(), 6.0, 0)
+        };
+        let logs = vec![log(date, 4.0, 1200), treadmill];
+        let mut cache = StatsCache::default();
+        assert_eq!(cache.lifetime(&logs).vert_per_mile, Some(120.0));
+
+        cache.configure(StatsConfig {
+            count_indoor: false,
+            ..StatsConfig::default()
+        });
+        let lifetime = cache.lifetime(&logs);
+        assert_eq!(lifetime.vert_per_mile, Some(300.0));
+        // The totals still hold every mile
+        assert_eq!(lifetime.miles, 10.0);
+    }
+
+    #[test]
+    fn training_age_reads_in_the_largest_units() {
+        let first = NaiveDate::from_ymd_opt(2024, 3, 15).unwrap();
+        let age = |y, m, d| training_age(first, NaiveDate::from_ymd_opt(y, m, d).unwrap());
+        assert_eq!(age(2024, 3, 15), "0 days");
+        assert_eq!(age(2024, 3, 16), "1 day");
+        assert_eq!(age(2024, 4, 14), "30 days");
+        assert_eq!(age(2024, 4, 15), "1 month");
+        assert_eq!(age(2025, 3, 15), "1 year");
+        assert_eq!(age(2026, 6, 20), "2 years, 3 months");
+    }
+
+    #[test]
+    fn tracks_each_streak_rule_or_just_vert() {
+        let date = NaiveDate::from_ymd_opt(2026, 7, 22).unwrap();
+        let logs = vec![log(date, 6.0, 300), log(date.pred_opt().unwrap(), 7.0, 1200)];
+        let mut cache = StatsCache::default();
+        let streaks = cache.streaks(&logs);
+        assert_eq!(streaks.len(), 1);
+        assert_eq!(streaks[0].1, None);
+
+        cache.set_streak_rules(vec![StreakRule {
+            metric: crate::streaks::StreakMetric::Miles,
+            threshold: 5.0,
+            ..StreakRule::default()
+        }]);
+        assert_eq!(cache.streaks(&logs)[0].1.unwrap().days, 2);
+    }
+
+    #[test]
+    fn configuring_a_threshold_recounts_vert_days() {
+        let date = NaiveDate::from_ymd_opt(2026, 7, 22).unwrap();
+        let logs = vec![log(date, 5.5, 1200)];
+        let mut cache = StatsCache::default();
+        assert_eq!(cache.vert_days(&logs, date), 1);
+
+        cache.configure(StatsConfig {
+            vert_threshold: 1500,
+            ..StatsConfig::default()
+        });
+        assert_eq!(cache.vert_days(&logs, date), 0);
+        assert_eq!(cache.current_streak(&logs), None);
+    }
+
+    /// Compares per-frame stat cost with and without the cache over five years
+    /// of daily logs. Run with:
+    /// `cargo test --release stats_cache -- --ignored --nocapture`
+    #[test]
+    #[ignore]
+    fn benchmark_five_years_of_logs() {
+        let start = NaiveDate::from_ymd_opt(2021, 1, 1).unwrap();
+        let mut logs: Vec<DailyLog> = (0..5 * 365)
+            .map(|d| log(start + chrono::Duration::days(d), 4.2, 1000 + (d as i32 % 400)))
+            .collect();
+        logs.sort_by_key(|log| std::cmp::Reverse(log.date));
+        let today = logs[0].date;
+        const FRAMES: u32 = 200;
+
+        let frame = |cache: &StatsCache| {
+            cache.vert_days(&logs, today);
+            cache.elevation(&logs, Period::year_of(today));
+            cache.miles(&logs, Period::month_of(today));
+            cache.miles(&logs, Period::year_of(today));
+            cache.current_streak(&logs);
+        };
+
+        let uncached = std::time::Instant::now();
+        for _ in 0..FRAMES {
+            frame(&StatsCache::default());
+        }
+        let uncached = uncached.elapsed();
+
+        let cache = StatsCache::default();
+        let cached = std::time::Instant::now();
+        for _ in 0..FRAMES {
+            frame(&cache);
+        }
+        let cached = cached.elapsed();
+
+        println!(
+            "{} logs, {} frames: uncached {:?}/frame, cached {:?}/frame",
+            logs.len(),
+            FRAMES,
+            uncached / FRAMES,
+            cached / FRAMES
+        );
+        assert!(cached < uncached);
+    }
+}
@@ -0,0 +1,459 @@
+//! Streaks: runs of days that meet a rule, like 1000+ ft of vert or 5+
+//! miles, optionally forgiving a few missed days in between. The built-in
+//! vert and running streaks on the startup screen are two of these;
+//! `[[streaks.rules]]` adds more.
+
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use crate::models::DailyLog;
+use crate::format::Units;
+use crate::units::Quantity;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StreakMetric {
+    Miles,
+    #[default]
+    Vert,
+    /// Any log at all, whatever's in it.
+    Logged,
+    /// Words written in the day's notes.
+    Words,
+}
+
+/// One streak to track.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StreakRule {
+    /// Shown on the streaks panel; defaults to the rule, e.g. "5+ mi".
+    pub name: Option<String>,
+    pub metric: StreakMetric,
+    /// Least a day needs, in miles, feet or words. Unused for `logged`.
+    /// Zero miles counts any day with a run.
+    pub threshold: f64,
+    /// Days in a row that can miss the rule without ending the streak.
+    pub skip_days: u32,
+}
+
+impl Default for StreakRule {
+    fn default() -> Self {
+        Self {
+            name: None,
+            metric: StreakMetric::Vert,
+            threshold: 1000.0,
+            skip_days: 0,
+        }
+    }
+}
+
+impl StreakRule {
+    /// The built-in streak: every day at least `vert_threshold` feet.
+    pub fn vert(vert_threshold: i32) -> Self {
+        Self {
+            threshold: vert_threshold as f64,
+            ..Self::default()
+        }
+    }
+
+    /// The built-in running streak: every day with any miles at all.
+    pub fn run() -> Self {
+        Self {
+            name: None,
+            metric: StreakMetric::Miles,
+            threshold: 0.0,
+            skip_days: 0,
+        }
+    }
+
+    /// The journaling streak for `[notes] word_goal`: every day at least
+    /// that many words of notes.
+    pub fn journal(word_goal: u32) -> Self {
+        Self {
+            name: Some("Journal".to_string()),
+            metric: StreakMetric::Words,
+            threshold: word_goal as f64,
+            skip_days: 0,
+        }
+    }
+
+    pub fn label(&self, units: Units) -> String {
+        if let Some(name) = &self.name {
+            return name.clone();
+        }
+        let rule = match self.metric {
+            StreakMetric::Miles if self.threshold <= 0.0 => "Running".to_string(),
+            StreakMetric::Miles => format!(
+                "{}+ {}",
+                units.decimal(units.convert(Quantity::Distance, self.threshold), 0),
+                units.label(Quantity::Distance)
+            ),
+            StreakMetric::Vert => format!(
+                "{} {}",
+                units.vert_threshold(self.threshold as i32),
+                units.label(Quantity::Elevation)
+            ),
+            StreakMetric::Logged => "Logged".to_string(),
+            StreakMetric::Words => format!("{}+ words", units.decimal(self.threshold, 0)),
+        };
+        match self.skip_days {
+            0 => rule,
+            days => format!("{} (skip {})", rule, days),
+        }
+    }
+
+    /// Whether the day is left out of this streak altogether: a rest day,
+    /// or an indoor day when those don't count, says nothing about distance
+    /// or vert.
+    fn sets_aside(&self, log: &DailyLog, count_indoor: bool) -> bool {
+        (log.rest_day || (!count_indoor && log.indoor))
+            && matches!(self.metric, StreakMetric::Miles | StreakMetric::Vert)
+    }
+
+    fn counts(&self, log: &DailyLog) -> bool {
+        match self.metric {
+            StreakMetric::Miles => {
+                let miles = log.miles_covered.unwrap_or(0.0) as f64;
+                miles > 0.0 && miles >= self.threshold
+            }
+            StreakMetric::Vert => log.elevation_gain.unwrap_or(0) as f64 >= self.threshold,
+            StreakMetric::Logged => true,
+            StreakMetric::Words => log.note_words() as f64 >= self.threshold,
+        }
+    }
+}
+
+/// A streak still going as of the latest log.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Streak {
+    /// Days that met the rule; skipped days don't count.
+    pub days: usize,
+    /// First day of the streak.
+    pub since: NaiveDate,
+}
+
+/// The streak for `rule` that runs up to the most recent log, if any.
+/// Counting starts from the latest log rather than today, so a streak
+/// doesn't break before today has been logged; the latest log itself may
+/// miss the rule as long as it's within the allowed skip days. Rest days,
+/// and indoor days without `count_indoor`, are passed over, neither adding
+/// to the streak nor breaking it.
+pub fn current_streak(logs: &[DailyLog], rule: &StreakRule, count_indoor: bool) -> Option<Streak> {
+    let by_date: BTreeMap<NaiveDate, &DailyLog> = logs.iter().map(|log| (log.date, log)).collect();
+    let (&first, _) = by_date.first_key_value()?;
+    let (&latest, _) = by_date.last_key_value()?;
+
+    let mut streak: Option<Streak> = None;
+    let mut missed = 0;
+    let mut date = latest;
+    loop {
+        match by_date.get(&date) {
+            Some(log) if rule.sets_aside(log, count_indoor) => {}
+            Some(log) if rule.counts(log) => {
+                streak = Some(Streak {
+                    days: streak.map_or(0, |s| s.days) + 1,
+                    since: date,
+                });
+                missed = 0;
+            }
+            _ => {
+                missed += 1;
+                if missed > rule.skip_days {
+                    break;
+                }
+            }
+        }
+        match date.pred_opt() {
+            Some(previous) if previous >= first => date = previous,
+            _ => break,
+        }
+    }
+    streak
+}
+
+/// A streak from start to finish, for the longest streaks on record.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StreakSpan {
+    pub days: usize,
+    pub since: NaiveDate,
+    /// Last day that met the rule.
+    pub until: NaiveDate,
+}
+
+impl StreakSpan {
+    /// "21 days, Mar 02 - Mar 22, 2026"
+    pub fn describe(&self, units: Units) -> String {
+        let since = if self.since.year() == self.until.year() {
+            units.month_day(self.since)
+        } else {
+            units.short_date(self.since)
+        };
+        format!(
+            "{} {}, {} - {}",
+            self.days,
+            if self.days == 1 { "day" } else { "days" },
+            since,
+            units.short_date(self.until)
+        )
+    }
+}
+
+/// Every streak of two or more days for `rule` over all the logs, longest
+/// first and the more recent first on a tie. Skip days, rest days and
+/// indoor days work as in `current_streak`.
+pub fn streak_history(logs: &[DailyLog], rule: &StreakRule, count_indoor: bool) -> Vec<StreakSpan> {
+    let by_date: BTreeMap<NaiveDate, &DailyLog> = logs.iter().map(|log| (log.date, log)).collect();
+    let (Some((&first, _)), Some((&latest, _))) = (by_date.first_key_value(), by_date.last_key_value())
+    else {
+        return Vec::new();
+    };
+
+    let mut spans = Vec::new();
+    let mut current: Option<StreakSpan> = None;
+    let mut missed = 0;
+    for date in first.iter_days().take_while(|date| *date <= latest) {
+        match by_date.get(&date) {
+            Some(log) if rule.sets_aside(log, count_indoor) => {}
+            Some(log) if rule.counts(log) => {
+                let span = current.get_or_insert(StreakSpan {
+                    days: 0,
+                    since: date,
+                    until: date,
+                });
+                span.days += 1;
+                span.until = date;
+                missed = 0;
+            }
+            _ => {
+                missed += 1;
+                if missed > rule.skip_days {
+                    spans.extend(current.take());
+                }
+            }
+        }
+    }
+    spans.extend(current);
+    spans.retain(|span| span.days >= 2);
+    spans.sort_by(|a, b| b.days.cmp(&a.days).then_with(|| b.until.cmp(&a.until)));
+    spans
+}
+
+/// The startup screen's line about the running streak, counted once it
+/// reaches two days like the vert streak.
+pub fn run_streak_message(days: Option<usize>) -> String {
+    match days.filter(|&days| days >= 2) {
+        Some(days) => format!("You've run {} days in a row!", days),
+        None => "Any run, any distance, starts a running streak".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::UnitSystem;
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 7, d).unwrap()
+    }
+
+    fn log(d: u32, miles: f32, feet: i32) -> DailyLog {
+        DailyLog {
+            miles_covered: Some(miles),
+            elevation_gain: Some(feet),
+            ..DailyLog::new(day(d))
+        }
+    }
+
+    #[test]
+    fn streaks_follow_each_rule_and_its_skip_days() {
+        let logs = vec![
+            log(10, 6.0, 1200),
+            log(11, 5.5, 300),
+            log(12, 3.0, 1500),
+            // 13th not logged
+            log(14, 8.0, 2000),
+            log(15, 5.0, 1100),
+        ];
+
+        let vert = StreakRule::vert(1000);
+        assert_eq!(
+            current_streak(&logs, &vert, true),
+            Some(Streak {
+                days: 2,
+                since: day(14)
+            })
+        );
+
+        let miles = StreakRule {
+            metric: StreakMetric::Miles,
+            threshold: 5.0,
+            skip_days: 2,
+            ..StreakRule::default()
+        };
+        assert_eq!(
+            current_streak(&logs, &miles, true),
+            Some(Streak {
+                days: 4,
+                since: day(10)
+            })
+        );
+
+        let forgiving_vert = StreakRule {
+            skip_days: 1,
+            ..vert.clone()
+        };
+        assert_eq!(current_streak(&logs, &forgiving_vert, true).unwrap().days, 4);
+
+        let logged = StreakRule {
+            metric: StreakMetric::Logged,
+            ..StreakRule::default()
+        };
+        assert_eq!(current_streak(&logs, &logged, true).unwrap().since, day(14));
+
+        assert_eq!(current_streak(&[log(16, 0.0, 0)], &vert, true), None);
+        assert_eq!(current_streak(&[], &vert, true), None);
+    }
+
+    #[test]
+    fn indoor_days_hold_distance_streaks_when_left_out() {
+        let treadmill = |d| DailyLog {
+            indoor: true,
+            ..log(d, 4.0, 0)
+        };
+        let logs = vec![log(10, 6.0, 1200), treadmill(11), log(12, 7.0, 1500)];
+        let vert = StreakRule::vert(1000);
+        assert_eq!(current_streak(&logs, &vert, true).unwrap().days, 1);
+        assert_eq!(
+            current_streak(&logs, &vert, false),
+            Some(Streak {
+                days: 2,
+                since: day(10)
+            })
+        );
+        // Logging the day still counts either way
+        let logged = StreakRule {
+            metric: StreakMetric::Logged,
+            ..StreakRule::default()
+        };
+        assert_eq!(current_streak(&logs, &logged, false).unwrap().days, 3);
+    }
+
+    #[test]
+    fn rest_days_hold_distance_and_vert_streaks() {
+        let rest = |d| DailyLog {
+            rest_day: true,
+            ..DailyLog::new(day(d))
+        };
+        let logs = vec![log(10, 6.0, 1200), rest(11), log(12, 7.0, 1500), rest(13)];
+        let vert = StreakRule::vert(1000);
+        assert_eq!(
+            current_streak(&logs, &vert, true),
+            Some(Streak {
+                days: 2,
+                since: day(10)
+            })
+        );
+        assert_eq!(current_streak(&logs, &StreakRule::run(), true).unwrap().days, 2);
+        let history = streak_history(&logs, &vert, true);
+        assert_eq!((history[0].since, history[0].until), (day(10), day(12)));
+        // An unmarked empty day still breaks it
+        let logs = vec![log(10, 6.0, 1200), DailyLog::new(day(11)), log(12, 7.0, 1500)];
+        assert_eq!(current_streak(&logs, &vert, true).unwrap().days, 1);
+    }
+
+    #[test]
+    fn labels_describe_the_rule_unless_named() {
+        let miles = StreakRule {
+            metric: StreakMetric::Miles,
+            threshold: 5.0,
+            skip_days: 1,
+            ..StreakRule::default()
+        };
+        assert_eq!(miles.label(UnitSystem::Imperial.into()), "5+ mi (skip 1)");
+        assert_eq!(StreakRule::vert(1000).label(UnitSystem::Metric.into()), "305+ m");
+        let ultra = StreakRule {
+            threshold: 1000.0,
+            ..miles.clone()
+        };
+        assert_eq!(ultra.label(UnitSystem::Imperial.into()), "1,000+ mi (skip 1)");
+        let named = StreakRule {
+            name: Some("Daily vert".to_string()),
+            ..StreakRule::default()
+        };
+        assert_eq!(named.label(UnitSystem::Imperial.into()), "Daily vert");
+    }
+
+    #[test]
+    fn journal_streak_counts_days_with_enough_words() {
+        let journal = |d, notes: &str| DailyLog {
+            notes: Some(notes.to_string()),
+            ..DailyLog::new(day(d))
+        };
+        let logs = vec![
+            journal(10, "Legs heavy, but the ridge was worth it"),
+            journal(11, "Short one"),
+            journal(12, "Easy miles along the creek in the rain"),
+            journal(13, "Felt strong on  the climb,\nfinally"),
+        ];
+        let rule = StreakRule::journal(6);
+        assert_eq!(rule.label(UnitSystem::Imperial.into()), "Journal");
+        assert_eq!(
+            current_streak(&logs, &rule, true),
+            Some(Streak {
+                days: 2,
+                since: day(12)
+            })
+        );
+    }
+
+    #[test]
+    fn running_streak_counts_any_miles_and_keeps_a_history() {
+        let logs = vec![
+            log(1, 3.0, 0),
+            log(2, 0.5, 0),
+            log(3, 0.0, 0),
+            log(4, 6.0, 0),
+            log(5, 2.0, 0),
+            log(6, 4.0, 0),
+            // 7th not logged
+            log(8, 1.0, 0),
+            log(9, 5.0, 0),
+        ];
+        let run = StreakRule::run();
+        assert_eq!(run.label(UnitSystem::Imperial.into()), "Running");
+        assert_eq!(current_streak(&logs, &run, true).unwrap().days, 2);
+        assert_eq!(run_streak_message(Some(2)), "You've run 2 days in a row!");
+        assert_eq!(
+            run_streak_message(Some(1)),
+            "Any run, any distance, starts a running streak"
+        );
+
+        let history = streak_history(&logs, &run, true);
+        assert_eq!(
+            history,
+            vec![
+                StreakSpan {
+                    days: 3,
+                    since: day(4),
+                    until: day(6)
+                },
+                StreakSpan {
+                    days: 2,
+                    since: day(8),
+                    until: day(9)
+                },
+                StreakSpan {
+                    days: 2,
+                    since: day(1),
+                    until: day(2)
+                },
+            ]
+        );
+        assert_eq!(
+            history[0].describe(UnitSystem::Imperial.into()),
+            "3 days, Jul 04 - Jul 06, 2026"
+        );
+        assert!(streak_history(&[], &run, true).is_empty());
+    }
+}
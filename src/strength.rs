@@ -0,0 +1,312 @@
+//! Strength sets in the strength & mobility text. Lines like "Squat 3x8 @
+//! 135 lbs" are kept as an exercise, sets, reps and weight in the
+//! `strength_sets` table, and everything else as the text it was typed as.
+//! Exercise names complete from what's been logged, and the strength screen
+//! charts each exercise over time.
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::format::Units;
+use crate::models::DailyLog;
+use crate::units::{Quantity, UnitSystem};
+
+/// One exercise done for `sets` sets of `reps`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StrengthSet {
+    pub exercise: String,
+    pub sets: u32,
+    pub reps: u32,
+    /// In pounds, as stored; none for bodyweight work.
+    pub weight: Option<f32>,
+}
+
+impl StrengthSet {
+    /// Reads "Squat 3x8 @ 135", "Squat: 3 x 8 135 lbs" or "- Pull-ups 3x10".
+    /// A weight without a unit is in display units; "lbs" or "kg" after it
+    /// says otherwise. Anything else is None, and stays free text.
+    pub fn parse(line: &str, units: Units) -> Option<Self> {
+        let line = line.trim();
+        let line = line
+            .strip_prefix("- ")
+            .or_else(|| line.strip_prefix("* "))
+            .unwrap_or(line);
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let (at, (sets, reps), rest) = (1..words.len()).find_map(|at| {
+            if let Some(counts) = sets_by_reps(words[at]) {
+                return Some((at, counts, &words[at + 1..]));
+            }
+            // "3 x 8", spaced out
+            match words.get(at..at + 3) {
+                Some(&[sets, "x" | "X" | "×", reps]) => {
+                    Some((at, (sets.parse().ok()?, reps.parse().ok()?), &words[at + 3..]))
+                }
+                _ => None,
+            }
+        })?;
+        let exercise = words[..at].join(" ");
+        let exercise = exercise.trim_end_matches(':').trim();
+        if sets == 0 || reps == 0 || !exercise.chars().any(char::is_alphabetic) {
+            return None;
+        }
+        Some(Self {
+            exercise: exercise.to_string(),
+            sets,
+            reps,
+            weight: parse_weight(rest, units)?,
+        })
+    }
+
+    /// "Squat 3x8 @ 135 lbs", as it's put back in the editor.
+    pub fn describe(&self, units: Units) -> String {
+        let mut text = format!("{} {}x{}", self.exercise, self.sets, self.reps);
+        if let Some(weight) = self.weight {
+            text.push_str(&format!(
+                " @ {} {}",
+                units.amount(Quantity::Weight, weight),
+                units.label(Quantity::Weight)
+            ));
+        }
+        text
+    }
+}
+
+/// "3x8" as (3, 8).
+fn sets_by_reps(word: &str) -> Option<(u32, u32)> {
+    let (sets, reps) = word.split_once(['x', 'X', '×'])?;
+    Some((sets.parse().ok()?, reps.parse().ok()?))
+}
+
+/// The weight after the sets and reps: nothing, or "@ 135", "135 lbs",
+/// "60kg" and so on. The outer None is words that aren't a weight.
+fn parse_weight(words: &[&str], units: Units) -> Option<Option<f32>> {
+    let words = match words {
+        ["@", rest @ ..] => rest,
+        _ => words,
+    };
+    let (number, unit) = match words {
+        [] => return Some(None),
+        [number] => {
+            let split = number
+                .find(|c: char| !c.is_ascii_digit() && c != '.')
+                .unwrap_or(number.len());
+            number.split_at(split)
+        }
+        [number, unit] => (*number, *unit),
+        _ => return None,
+    };
+    let system = match unit.to_lowercase().as_str() {
+        "" => units.system,
+        "lb" | "lbs" => UnitSystem::Imperial,
+        "kg" | "kgs" => UnitSystem::Metric,
+        _ => return None,
+    };
+    let pounds = system.parse(Quantity::Weight, number).filter(|&w| w > 0.0)?;
+    Some(Some(pounds as f32))
+}
+
+/// Splits strength & mobility text into the sets it names and the rest of
+/// it, which is None when there's nothing else.
+pub fn split_text(text: &str, units: Units) -> (Vec<StrengthSet>, Option<String>) {
+    let mut sets = Vec::new();
+    let mut rest = Vec::new();
+    for line in text.lines() {
+        match StrengthSet::parse(line, units) {
+            Some(set) => sets.push(set),
+            None => rest.push(line),
+        }
+    }
+    let rest = rest.join("\n").trim().to_string();
+    (sets, (!rest.is_empty()).then_some(rest))
+}
+
+/// The sets a line each, then the rest of the text: what `split_text`
+/// reads back.
+pub fn join_text(sets: &[StrengthSet], rest: Option<&str>, units: Units) -> String {
+    let mut lines: Vec<String> = sets.iter().map(|set| set.describe(units)).collect();
+    if let Some(rest) = rest.map(str::trim).filter(|rest| !rest.is_empty()) {
+        lines.push(rest.to_string());
+    }
+    lines.join("\n")
+}
+
+/// One day of an exercise.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Session {
+    pub date: NaiveDate,
+    pub sets: u32,
+    /// Reps over every set.
+    pub reps: u32,
+    /// The heaviest set, in stored pounds.
+    pub top_weight: Option<f32>,
+}
+
+/// Every day an exercise was logged, oldest first.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExerciseProgress {
+    /// As it was last typed, since the same exercise may be typed
+    /// differently.
+    pub name: String,
+    pub sessions: Vec<Session>,
+}
+
+impl ExerciseProgress {
+    /// Whether any set was done with weight, so the chart follows the top
+    /// weight rather than the reps.
+    pub fn weighted(&self) -> bool {
+        self.sessions.iter().any(|session| session.top_weight.is_some())
+    }
+
+    pub fn best_weight(&self) -> Option<f32> {
+        self.sessions
+            .iter()
+            .filter_map(|session| session.top_weight)
+            .reduce(f32::max)
+    }
+
+    pub fn most_reps(&self) -> u32 {
+        self.sessions.iter().map(|session| session.reps).max().unwrap_or(0)
+    }
+}
+
+/// Each exercise with its sessions, most done first. Like routes, names
+/// that differ only in case are the same exercise.
+pub fn exercise_progress(logs: &[DailyLog]) -> Vec<ExerciseProgress> {
+    let mut by_date: Vec<&DailyLog> = logs.iter().collect();
+    by_date.sort_by_key(|log| log.date);
+    let mut exercises: HashMap<String, ExerciseProgress> = HashMap::new();
+    for log in by_date {
+        for set in &log.strength_sets {
+            let exercise = exercises
+                .entry(set.exercise.to_lowercase())
+                .or_insert_with(|| ExerciseProgress {
+                    name: String::new(),
+                    sessions: Vec::new(),
+                });
+            exercise.name = set.exercise.clone();
+            if exercise.sessions.last().is_none_or(|session| session.date != log.date) {
+                exercise.sessions.push(Session {
+                    date: log.date,
+                    sets: 0,
+                    reps: 0,
+                    top_weight: None,
+                });
+            }
+            if let Some(session) = exercise.sessions.last_mut() {
+                session.sets += set.sets;
+                session.reps += set.sets * set.reps;
+                session.top_weight = match (session.top_weight, set.weight) {
+                    (Some(top), Some(weight)) => Some(top.max(weight)),
+                    (top, weight) => top.or(weight),
+                };
+            }
+        }
+    }
+    let mut exercises: Vec<ExerciseProgress> = exercises.into_values().collect();
+    exercises.sort_by(|a, b| {
+        b.sessions
+            .len()
+            .cmp(&a.sessions.len())
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    exercises
+}
+
+/// Up to `limit` exercise names starting with `prefix` (ignoring case),
+/// most done first. Like route suggestions, an empty prefix or one that
+/// already names an exercise suggests nothing.
+pub fn exercise_suggestions(logs: &[DailyLog], prefix: &str, limit: usize) -> Vec<String> {
+    let prefix = prefix.trim_start().to_lowercase();
+    if prefix.is_empty() {
+        return Vec::new();
+    }
+    exercise_progress(logs)
+        .into_iter()
+        .map(|exercise| exercise.name)
+        .filter(|name| {
+            let name = name.to_lowercase();
+            name.starts_with(&prefix) && name != prefix
+        })
+        .take(limit)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn imperial() -> Units {
+        Units::from(UnitSystem::Imperial)
+    }
+
+    fn set(exercise: &str, sets: u32, reps: u32, weight: Option<f32>) -> StrengthSet {
+        StrengthSet {
+            exercise: exercise.to_string(),
+            sets,
+            reps,
+            weight,
+        }
+    }
+
+    #[test]
+    fn reads_sets_and_leaves_other_lines_as_text() {
+        let units = imperial();
+        assert_eq!(
+            StrengthSet::parse("Back squat 3x8 @ 135", units),
+            Some(set("Back squat", 3, 8, Some(135.0)))
+        );
+        assert_eq!(
+            StrengthSet::parse("- Pull-ups: 3 x 10", units),
+            Some(set("Pull-ups", 3, 10, None))
+        );
+        let deadlift = StrengthSet::parse("Deadlift 5x5 100kg", units).unwrap();
+        assert!((deadlift.weight.unwrap() - 220.462).abs() < 0.01);
+        assert_eq!(StrengthSet::parse("20 min of stretching", units), None);
+        assert_eq!(StrengthSet::parse("Squat 3x8 felt heavy", units), None);
+        assert_eq!(StrengthSet::parse("3x8", units), None);
+
+        let text = "Squat 3x8 @ 135 lbs\nHip mobility flow\nPlank 3x1\n";
+        let (sets, rest) = split_text(text, units);
+        assert_eq!(sets, vec![set("Squat", 3, 8, Some(135.0)), set("Plank", 3, 1, None)]);
+        assert_eq!(rest.as_deref(), Some("Hip mobility flow"));
+        assert_eq!(
+            join_text(&sets, rest.as_deref(), units),
+            "Squat 3x8 @ 135 lbs\nPlank 3x1\nHip mobility flow"
+        );
+
+        // Metric shows and reads back the same kilograms
+        let metric = Units::from(UnitSystem::Metric);
+        let (sets, _) = split_text("Squat 3x8 @ 60", metric);
+        assert_eq!(join_text(&sets, None, metric), "Squat 3x8 @ 60 kg");
+        let (sets, rest) = split_text("Just stretching", units);
+        assert!(sets.is_empty());
+        assert_eq!(rest.as_deref(), Some("Just stretching"));
+    }
+
+    #[test]
+    fn follows_each_exercise_and_suggests_the_most_done() {
+        let day = |d, sets: Vec<StrengthSet>| DailyLog {
+            strength_sets: sets,
+            ..DailyLog::new(NaiveDate::from_ymd_opt(2026, 9, d).unwrap())
+        };
+        let logs = vec![
+            day(8, vec![set("squat", 3, 8, Some(145.0)), set("Squat", 1, 5, Some(155.0))]),
+            day(1, vec![set("Squat", 3, 8, Some(135.0)), set("Push-ups", 3, 15, None)]),
+            day(4, vec![set("Side plank", 2, 1, None)]),
+        ];
+        let progress = exercise_progress(&logs);
+        assert_eq!(progress.len(), 3);
+        assert_eq!(progress[0].name, "Squat");
+        assert_eq!(progress[0].sessions.len(), 2);
+        assert_eq!(progress[0].sessions[1].sets, 4);
+        assert_eq!(progress[0].sessions[1].reps, 29);
+        assert_eq!(progress[0].best_weight(), Some(155.0));
+        assert!(!progress[1].weighted());
+        assert_eq!(progress[1].most_reps(), 45);
+
+        assert_eq!(exercise_suggestions(&logs, "s", 5), vec!["Squat", "Side plank"]);
+        assert!(exercise_suggestions(&logs, "squat", 5).is_empty());
+        assert!(exercise_suggestions(&logs, "", 5).is_empty());
+    }
+}
@@ -0,0 +1,178 @@
+//! Morning runs against evening ones: pace and energy by when the day's run
+//! started, for the statistics screen.
+
+use chrono::{Days, NaiveDate, NaiveTime, Timelike};
+
+use crate::models::DailyLog;
+use crate::format::Units;
+
+/// How far back the statistics screen looks when comparing start times.
+pub const TIME_OF_DAY_WINDOW_DAYS: u64 = 90;
+
+/// Runs starting before this hour are morning runs.
+const MORNING_BEFORE_HOUR: u32 = 12;
+/// Runs starting at or after this hour are evening runs; those between are
+/// left out of both.
+const EVENING_FROM_HOUR: u32 = 17;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RunTime {
+    Morning,
+    Evening,
+}
+
+impl RunTime {
+    /// Which side of the day a run starting at `time` falls on, if either.
+    pub fn of(time: NaiveTime) -> Option<Self> {
+        if time.hour() < MORNING_BEFORE_HOUR {
+            Some(RunTime::Morning)
+        } else if time.hour() >= EVENING_FROM_HOUR {
+            Some(RunTime::Evening)
+        } else {
+            None
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            RunTime::Morning => "Morning",
+            RunTime::Evening => "Evening",
+        }
+    }
+}
+
+/// The runs on one side of the day.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct RunTimeGroup {
+    pub runs: usize,
+    /// Time and distance over the runs that have both, for the pace.
+    pub seconds: u32,
+    pub miles: f32,
+    /// Average 1-5 energy rating over the runs that have one.
+    pub energy: Option<f32>,
+}
+
+impl RunTimeGroup {
+    /// "Morning: 9:45 /mi, energy 3.8/5 (12 runs)", leaving out what
+    /// wasn't logged.
+    pub fn describe(&self, run_time: RunTime, units: Units) -> String {
+        if self.runs == 0 {
+            return format!("{}: no runs yet", run_time.label());
+        }
+        let mut parts = Vec::new();
+        if let Some(pace) = units.pace(self.seconds, self.miles) {
+            parts.push(pace);
+        }
+        if let Some(energy) = self.energy {
+            parts.push(format!("energy {}/5", units.decimal(energy as f64, 1)));
+        }
+        let runs = format!("{} {}", self.runs, if self.runs == 1 { "run" } else { "runs" });
+        if parts.is_empty() {
+            format!("{}: {}", run_time.label(), runs)
+        } else {
+            format!("{}: {} ({})", run_time.label(), parts.join(", "), runs)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimeOfDayComparison {
+    pub morning: RunTimeGroup,
+    pub evening: RunTimeGroup,
+}
+
+/// Morning and evening runs over the `TIME_OF_DAY_WINDOW_DAYS` ending on
+/// `today`, or None when no run in that window has a start time.
+pub fn compare_time_of_day(logs: &[DailyLog], today: NaiveDate) -> Option<TimeOfDayComparison> {
+    let from = today
+        .checked_sub_days(Days::new(TIME_OF_DAY_WINDOW_DAYS - 1))
+        .unwrap_or(today);
+    let started: Vec<(NaiveTime, &DailyLog)> = logs
+        .iter()
+        .filter(|log| log.date >= from && log.date <= today)
+        .filter_map(|log| Some((log.start_time?, log)))
+        .collect();
+    if started.is_empty() {
+        return None;
+    }
+    let timed: Vec<(RunTime, &DailyLog)> = started
+        .into_iter()
+        .filter_map(|(time, log)| Some((RunTime::of(time)?, log)))
+        .collect();
+
+    let group = |run_time: RunTime| {
+        let runs: Vec<&DailyLog> = timed
+            .iter()
+            .filter(|(time, _)| *time == run_time)
+            .map(|(_, log)| *log)
+            .collect();
+        let mut group = RunTimeGroup {
+            runs: runs.len(),
+            ..RunTimeGroup::default()
+        };
+        for (seconds, miles) in runs
+            .iter()
+            .filter_map(|log| log.duration_secs.zip(log.miles_covered))
+            .filter(|&(_, miles)| miles > 0.0)
+        {
+            group.seconds += seconds;
+            group.miles += miles;
+        }
+        let ratings: Vec<f32> = runs.iter().filter_map(|log| log.energy).map(f32::from).collect();
+        group.energy =
+            (!ratings.is_empty()).then(|| ratings.iter().sum::<f32>() / ratings.len() as f32);
+        group
+    };
+    Some(TimeOfDayComparison {
+        morning: group(RunTime::Morning),
+        evening: group(RunTime::Evening),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::UnitSystem;
+
+    fn run(d: u32, start: (u32, u32), minutes: u32, miles: f32, energy: Option<u8>) -> DailyLog {
+        DailyLog {
+            start_time: NaiveTime::from_hms_opt(start.0, start.1, 0),
+            duration_secs: Some(minutes * 60),
+            miles_covered: Some(miles),
+            energy,
+            ..DailyLog::new(NaiveDate::from_ymd_opt(2026, 7, d).unwrap())
+        }
+    }
+
+    #[test]
+    fn compares_pace_and_energy_by_start_time() {
+        let today = NaiveDate::from_ymd_opt(2026, 7, 31).unwrap();
+        let logs = vec![
+            run(1, (6, 0), 50, 5.0, Some(4)),
+            run(3, (7, 30), 40, 4.0, Some(5)),
+            run(5, (18, 15), 66, 6.0, Some(3)),
+            // Midday runs count for neither
+            run(7, (13, 0), 30, 2.0, Some(1)),
+        ];
+        let comparison = compare_time_of_day(&logs, today).unwrap();
+        assert_eq!(comparison.morning.runs, 2);
+        assert_eq!(
+            comparison.morning.describe(RunTime::Morning, UnitSystem::Imperial.into()),
+            "Morning: 10:00 /mi, energy 4.5/5 (2 runs)"
+        );
+        assert_eq!(
+            comparison.evening.describe(RunTime::Evening, UnitSystem::Imperial.into()),
+            "Evening: 11:00 /mi, energy 3.0/5 (1 run)"
+        );
+
+        let mornings_only = vec![run(1, (6, 0), 50, 5.0, None)];
+        let comparison = compare_time_of_day(&mornings_only, today).unwrap();
+        assert_eq!(
+            comparison.evening.describe(RunTime::Evening, UnitSystem::Imperial.into()),
+            "Evening: no runs yet"
+        );
+
+        let untimed = vec![DailyLog::new(today)];
+        assert_eq!(compare_time_of_day(&untimed, today), None);
+    }
+}
@@ -0,0 +1,190 @@
+//! Training load for the startup screen: each day's session load, its
+//! acute (ATL) and chronic (CTL) averages, and form (TSB), the gap between
+//! them.
+
+use chrono::{Days, NaiveDate};
+use std::collections::HashMap;
+
+use crate::models::DailyLog;
+use crate::stats_cache::{Period, StatsCache};
+
+/// Days the acute load averages over, roughly the last week.
+pub const ACUTE_DAYS: f32 = 7.0;
+/// Days the chronic load averages over, roughly the last six weeks.
+pub const CHRONIC_DAYS: f32 = 42.0;
+/// Load per mile for a day without both a run time and an RPE: about ten
+/// minutes at a moderate effort of 6.
+pub const LOAD_PER_MILE: f32 = 60.0;
+/// Days in the acute and chronic windows of the workload ratio.
+pub const RATIO_ACUTE_DAYS: u32 = 7;
+pub const RATIO_CHRONIC_DAYS: u32 = 28;
+/// Form this far under the chronic load (as a share of it) is building;
+/// further under is overreaching.
+const BUILDING_SHARE: f32 = 0.05;
+const OVERREACHING_SHARE: f32 = 0.3;
+
+/// A day's session load: minutes times RPE, or distance as a stand-in when
+/// either is missing.
+pub fn session_load(log: &DailyLog) -> f32 {
+    match (log.duration_secs, log.rpe) {
+        (Some(secs), Some(rpe)) => secs as f32 / 60.0 * rpe as f32,
+        _ => log.miles_covered.unwrap_or(0.0) * LOAD_PER_MILE,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LoadTrend {
+    Building,
+    Maintaining,
+    Overreaching,
+}
+
+impl LoadTrend {
+    pub fn label(self) -> &'static str {
+        match self {
+            LoadTrend::Building => "building",
+            LoadTrend::Maintaining => "maintaining",
+            LoadTrend::Overreaching => "overreaching",
+        }
+    }
+}
+
+/// Acute and chronic load as of a day.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrainingLoad {
+    pub acute: f32,
+    pub chronic: f32,
+}
+
+impl TrainingLoad {
+    /// Chronic minus acute: negative while recent training outpaces the
+    /// usual.
+    pub fn form(&self) -> f32 {
+        self.chronic - self.acute
+    }
+
+    pub fn trend(&self) -> LoadTrend {
+        let form = self.form();
+        if form < -self.chronic * OVERREACHING_SHARE {
+            LoadTrend::Overreaching
+        } else if form < -self.chronic * BUILDING_SHARE {
+            LoadTrend::Building
+        } else {
+            LoadTrend::Maintaining
+        }
+    }
+
+    /// "Fitness 42 | Fatigue 55 | Form -13 (building)"
+    pub fn describe(&self) -> String {
+        format!(
+            "Fitness {:.0} | Fatigue {:.0} | Form {:.0} ({})",
+            self.chronic,
+            self.acute,
+            self.form(),
+            self.trend().label()
+        )
+    }
+}
+
+/// Training load as of `today`, each average stepping a day at a time from
+/// the first log. None when nothing was logged in the chronic window: an
+/// old block of training says little about now.
+pub fn training_load(logs: &[DailyLog], today: NaiveDate) -> Option<TrainingLoad> {
+    let mut loads: HashMap<NaiveDate, f32> = HashMap::new();
+    for log in logs.iter().filter(|log| log.date <= today) {
+        *loads.entry(log.date).or_default() += session_load(log);
+    }
+    let window = today.checked_sub_days(Days::new(CHRONIC_DAYS as u64))?;
+    if !loads.iter().any(|(date, load)| *date > window && *load > 0.0) {
+        return None;
+    }
+
+    let mut load = TrainingLoad {
+        acute: 0.0,
+        chronic: 0.0,
+    };
+    let mut date = *loads.keys().min()?;
+    while date <= today {
+        let day = loads.get(&date).copied().unwrap_or(0.0);
+        load.acute += (day - load.acute) / ACUTE_DAYS;
+        load.chronic += (day - load.chronic) / CHRONIC_DAYS;
+        date = date.succ_opt()?;
+    }
+    Some(load)
+}
+
+/// Acute:chronic workload ratio as of `date`: the last 7 days' distance
+/// against the weekly average of the last 28. None until the logs go back
+/// the full 28 days, since a first week would always look like a jump.
+pub fn acute_chronic_ratio(stats: &StatsCache, logs: &[DailyLog], date: NaiveDate) -> Option<f32> {
+    let first = stats.lifetime(logs).first_log?;
+    if (date - first).num_days() < RATIO_CHRONIC_DAYS as i64 - 1 {
+        return None;
+    }
+    let acute = stats.miles(logs, Period::trailing(date, RATIO_ACUTE_DAYS));
+    let chronic = stats.miles(logs, Period::trailing(date, RATIO_CHRONIC_DAYS));
+    let weekly = chronic / (RATIO_CHRONIC_DAYS / RATIO_ACUTE_DAYS) as f32;
+    (weekly > 0.0).then(|| acute / weekly)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(offset: u64) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 6, 1).unwrap() + Days::new(offset)
+    }
+
+    #[test]
+    fn session_load_uses_effort_or_falls_back_to_distance() {
+        let timed = DailyLog {
+            duration_secs: Some(3600),
+            rpe: Some(7),
+            miles_covered: Some(6.0),
+            ..DailyLog::new(day(0))
+        };
+        assert_eq!(session_load(&timed), 420.0);
+        let untimed = DailyLog {
+            miles_covered: Some(5.0),
+            ..DailyLog::new(day(0))
+        };
+        assert_eq!(session_load(&untimed), 300.0);
+    }
+
+    #[test]
+    fn a_sudden_jump_in_load_reads_as_overreaching() {
+        let run = |offset, miles| DailyLog {
+            miles_covered: Some(miles),
+            ..DailyLog::new(day(offset))
+        };
+        // Five steady months, then a week at three times the volume
+        let mut logs: Vec<DailyLog> = (0..150).map(|offset| run(offset, 5.0)).collect();
+        let steady = training_load(&logs, day(149)).unwrap();
+        assert_eq!(steady.trend(), LoadTrend::Maintaining);
+
+        logs.extend((150..157).map(|offset| run(offset, 15.0)));
+        let load = training_load(&logs, day(156)).unwrap();
+        assert!(load.acute > load.chronic);
+        assert_eq!(load.trend(), LoadTrend::Overreaching);
+        assert!(load.describe().ends_with("(overreaching)"));
+
+        // Nothing in the last six weeks
+        assert_eq!(training_load(&logs, day(250)), None);
+    }
+
+    #[test]
+    fn workload_ratio_waits_for_four_weeks_of_history() {
+        let run = |offset, miles| DailyLog {
+            miles_covered: Some(miles),
+            ..DailyLog::new(day(offset))
+        };
+        // 10 miles a week for three weeks, then 20
+        let mut logs: Vec<DailyLog> = (0..3).map(|week| run(week * 7, 10.0)).collect();
+        logs.push(run(21, 20.0));
+        let stats = StatsCache::default();
+        assert_eq!(acute_chronic_ratio(&stats, &logs, day(26)), None);
+        assert_eq!(acute_chronic_ratio(&stats, &logs, day(27)), Some(1.6));
+        // A quiet week after
+        assert_eq!(acute_chronic_ratio(&stats, &logs, day(34)), Some(0.0));
+    }
+}
@@ -1,31 +1,55 @@
 use ratatui::{
     Frame,
     layout::{Constraint, Direction, Flex, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, BorderType, Borders, Padding, Paragraph},
+    widgets::{Block, BorderType, Borders, Clear, Padding, Paragraph},
 };
 
+use crate::models::{AppState, DailyLog};
+use crate::profiling::{Profiler, millis};
+use crate::stats_cache::{Period, ROLLING_DAYS};
+use crate::ui::theme::Theme;
+use crate::format::Units;
+use crate::units::Quantity;
+use chrono::{Datelike, NaiveDate, Weekday};
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct HelpRegion {
     pub key: String,
     pub area: Rect,
 }
 
-pub fn create_title_style() -> Style {
+pub fn create_title_style(theme: &Theme) -> Style {
     Style::default()
-        .fg(Color::Green)
+        .fg(theme.good)
         .add_modifier(Modifier::BOLD)
 }
 
-pub fn create_input_style() -> Style {
-    Style::default().fg(Color::Yellow)
+pub fn create_input_style(theme: &Theme) -> Style {
+    Style::default().fg(theme.highlight)
 }
 
 pub fn create_highlight_style() -> Style {
     Style::default().add_modifier(Modifier::REVERSED)
 }
 
+/// A block title that says it has focus when a screen reader is in use,
+/// since the border color alone can't be heard.
+pub fn focus_title(title: &str, focused: bool, screen_reader: bool) -> String {
+    if focused && screen_reader {
+        format!("{} (focused)", title)
+    } else {
+        title.to_string()
+    }
+}
+
+/// Highlight symbol for the selected list item: spelled out for screen
+/// readers, which can't see the reversed colors.
+pub fn selection_symbol(spelled_out: bool) -> &'static str {
+    if spelled_out { "(selected) " } else { "" }
+}
+
 pub fn create_standard_layout(area: Rect) -> std::rc::Rc<[Rect]> {
     Layout::default()
         .direction(Direction::Vertical)
@@ -38,12 +62,12 @@ pub fn create_standard_layout(area: Rect) -> std::rc::Rc<[Rect]> {
         .split(area)
 }
 
-pub fn render_title(f: &mut Frame, area: Rect, title: &str) {
-    let title_widget = Paragraph::new(title).style(create_title_style()).block(
+pub fn render_title(f: &mut Frame, area: Rect, title: &str, theme: &Theme) {
+    let title_widget = Paragraph::new(title).style(create_title_style(theme)).block(
         Block::default()
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
-            .border_style(Style::default().fg(Color::Rgb(255, 165, 0)))
+            .border_style(Style::default().fg(theme.warning))
             .padding(Padding::uniform(1)),
     );
     f.render_widget(title_widget, area);
@@ -61,6 +85,7 @@ pub fn render_help(
     tiers: &[&str],
     show_border: bool,
     centered: bool,
+    theme: &Theme,
 ) -> Vec<HelpRegion> {
     let available = if show_border {
         area.width.saturating_sub(2)
@@ -68,7 +93,7 @@ pub fn render_help(
         area.width
     } as usize;
 
-    let spans = build_help_spans(choose_help_tier(tiers, available));
+    let spans = build_help_spans(choose_help_tier(tiers, available), theme);
 
     let block = if show_border {
         Block::default().borders(Borders::ALL)
@@ -108,20 +133,23 @@ fn choose_help_tier<'a>(tiers: &[&'a str], available: usize) -> &'a str {
         .unwrap_or("")
 }
 
-/// Rendered display width of a help string once styled into spans.
+/// Rendered display width of a help string once styled into spans. Colors
+/// don't change the width, so any theme will do.
 fn help_line_width(help_text: &str) -> usize {
-    build_help_spans(help_text).iter().map(|s| s.width()).sum()
+    build_help_spans(help_text, &Theme::default())
+        .iter()
+        .map(|s| s.width())
+        .sum()
 }
 
 /// Parses a `key: desc | key: desc` help string into styled spans.
-fn build_help_spans(help_text: &str) -> Vec<Span<'static>> {
+fn build_help_spans(help_text: &str, theme: &Theme) -> Vec<Span<'static>> {
     let mut spans = Vec::new();
 
     // Split by pipe separator to get individual commands
     for (i, segment) in help_text.split('|').enumerate() {
         if i > 0 {
-            // Add the pipe separator in white
-            spans.push(Span::styled(" | ", Style::default().fg(Color::White)));
+            spans.push(Span::styled(" | ", Style::default().fg(theme.text)));
         }
 
         let trimmed = segment.trim();
@@ -131,22 +159,19 @@ fn build_help_spans(help_text: &str) -> Vec<Span<'static>> {
             let key_part = trimmed[..colon_pos].trim();
             let desc_part = trimmed[colon_pos + 1..].trim();
 
-            // Key in yellow
             spans.push(Span::styled(
                 key_part.to_string(),
-                Style::default().fg(Color::Yellow),
+                Style::default().fg(theme.highlight),
             ));
 
-            // Colon and description in white
             spans.push(Span::styled(
                 format!(": {}", desc_part),
-                Style::default().fg(Color::White),
+                Style::default().fg(theme.text),
             ));
         } else {
-            // If no colon, just display in white
             spans.push(Span::styled(
                 trimmed.to_string(),
-                Style::default().fg(Color::White),
+                Style::default().fg(theme.text),
             ));
         }
     }
@@ -192,6 +217,257 @@ pub fn format_input_with_cursor(input: &str) -> String {
     }
 }
 
+/// Draws a one-line notice just above the footer, on top of the current screen.
+pub fn render_toast(f: &mut Frame, message: &str, theme: &Theme) {
+    let area = f.area();
+    let width = (message.chars().count() as u16 + 4).min(area.width);
+    let height = 3.min(area.height);
+    let toast_area = Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + area.height.saturating_sub(height + 3),
+        width,
+        height,
+    );
+
+    f.render_widget(Clear, toast_area);
+    let toast = Paragraph::new(message)
+        .style(Style::default().fg(theme.text))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.highlight)),
+        );
+    f.render_widget(toast, toast_area);
+}
+
+/// A day's key numbers, one per line, for hover tooltips. Only what was
+/// logged is listed.
+pub fn day_summary_lines(log: &DailyLog, units: Units) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut running = Vec::new();
+    if let Some(miles) = log.miles_covered {
+        running.push(units.format(Quantity::Distance, miles));
+    }
+    if let Some(feet) = log.elevation_gain {
+        running.push(format!("{} vert", units.format(Quantity::Elevation, feet as f32)));
+    }
+    if !running.is_empty() {
+        lines.push(running.join(", "));
+    }
+    if let Some(weight) = log.weight {
+        lines.push(format!("Weight {}", units.format(Quantity::Weight, weight)));
+    }
+    if let Some(water) = log.water {
+        lines.push(format!("Water {}", units.format(Quantity::Water, water)));
+    }
+    let foods = log.food_entries.len();
+    if foods > 0 {
+        let mut food = format!("{} food{}", foods, if foods == 1 { "" } else { "s" });
+        if let Some(calories) = log.calories() {
+            food.push_str(&format!(", {} cal", units.count(calories as i64)));
+        }
+        lines.push(food);
+    }
+    if !log.sokay_entries.is_empty() {
+        lines.push(format!("{} sokay", log.sokay_entries.len()));
+    }
+    if !log.strength_sets.is_empty()
+        || log.strength_mobility.as_deref().is_some_and(|s| !s.trim().is_empty())
+    {
+        lines.push("Strength & mobility".to_string());
+    }
+    if log.notes.as_deref().is_some_and(|n| !n.trim().is_empty()) {
+        lines.push("Notes".to_string());
+    }
+    if lines.is_empty() {
+        lines.push("Nothing logged".to_string());
+    }
+    lines
+}
+
+/// "Last 7 days: 32.1 mi, 8400 ft | Last 28 days: 120.4 mi, 30100 ft",
+/// each window ending on `end`.
+pub fn rolling_totals_text(state: &AppState, end: NaiveDate) -> String {
+    ROLLING_DAYS
+        .iter()
+        .map(|&days| {
+            let period = Period::trailing(end, days);
+            format!(
+                "Last {} days: {}, {}",
+                days,
+                state.units.total(
+                    Quantity::Distance,
+                    state.stats.miles(state.daily_logs(), period) as f64
+                ),
+                state.units.total(
+                    Quantity::Elevation,
+                    state.stats.elevation(state.daily_logs(), period) as f64
+                )
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(" | ")
+}
+
+/// Draws a small box of `lines` just below and right of the pointer at
+/// (`column`, `row`), moved back inside the screen when it would spill off
+/// an edge.
+pub fn render_tooltip(
+    f: &mut Frame,
+    (column, row): (u16, u16),
+    title: &str,
+    lines: &[String],
+    theme: &Theme,
+) {
+    let area = f.area();
+    let content_width = lines
+        .iter()
+        .map(|line| line.chars().count())
+        .chain(std::iter::once(title.chars().count()))
+        .max()
+        .unwrap_or(0) as u16;
+    let width = (content_width + 4).min(area.width);
+    let height = (lines.len() as u16 + 2).min(area.height);
+    let x = column.saturating_add(2).min(area.right().saturating_sub(width));
+    let y = if row.saturating_add(1 + height) <= area.bottom() {
+        row + 1
+    } else {
+        row.saturating_sub(height)
+    };
+    let tooltip_area = Rect::new(x, y, width, height);
+
+    f.render_widget(Clear, tooltip_area);
+    let text: Vec<Line> = lines
+        .iter()
+        .map(|line| Line::from(Span::styled(line.clone(), Style::default().fg(theme.text))))
+        .collect();
+    let tooltip = Paragraph::new(text).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.highlight))
+            .title(title.to_string())
+            .padding(Padding::horizontal(1)),
+    );
+    f.render_widget(tooltip, tooltip_area);
+}
+
+/// The F10 profiling overlay, in the top right corner: the last frame's
+/// time and allocations, then each screen's average and worst draw. Only
+/// debug builds count allocations, so release builds show times alone.
+/// The counts take in every thread, which the overlay says, since a save or
+/// sync finishing mid-draw shows up in them.
+pub fn render_profiler(f: &mut Frame, profiler: &Profiler, theme: &Theme) {
+    let frames = &profiler.frames;
+    let counted = cfg!(debug_assertions);
+    let mut lines = vec![format!(
+        "Frame  {}  avg {}  worst {}",
+        millis(frames.last),
+        millis(frames.average()),
+        millis(frames.worst)
+    )];
+    if counted {
+        lines.push(format!(
+            "       {} allocs, avg {} (all threads)",
+            profiler.last_allocations,
+            frames.average_allocations()
+        ));
+    }
+    for (name, cost) in profiler.screens() {
+        let mut line = format!(
+            "{:<14} {} avg  {} worst",
+            name,
+            millis(cost.average()),
+            millis(cost.worst)
+        );
+        if counted {
+            line.push_str(&format!("  {} allocs", cost.average_allocations()));
+        }
+        lines.push(line);
+    }
+
+    let area = f.area();
+    let width = (lines.iter().map(|line| line.chars().count()).max().unwrap_or(0) as u16 + 4)
+        .min(area.width);
+    let height = (lines.len() as u16 + 2).min(area.height);
+    let overlay_area = Rect::new(area.right().saturating_sub(width), area.y, width, height);
+    f.render_widget(Clear, overlay_area);
+    let text: Vec<Line> = lines
+        .into_iter()
+        .map(|line| Line::from(Span::styled(line, Style::default().fg(theme.text))))
+        .collect();
+    let overlay = Paragraph::new(text).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.warning))
+            .title(" Render Profile (F10) ")
+            .padding(Padding::horizontal(1)),
+    );
+    f.render_widget(overlay, overlay_area);
+}
+
+/// Width of a `calendar_lines` grid: seven four-column day cells.
+pub const CALENDAR_WIDTH: u16 = 28;
+
+/// A month grid around `cursor`: the month and year, weekday initials
+/// starting from `week_start`, then a row per week. Days with a log are bold
+/// in the `good` color, the cursor is bracketed and days after `today` are
+/// dimmed.
+pub fn calendar_lines(
+    cursor: NaiveDate,
+    today: NaiveDate,
+    week_start: Weekday,
+    has_log: impl Fn(NaiveDate) -> bool,
+    theme: &Theme,
+) -> Vec<Line<'static>> {
+    let first = cursor.with_day(1).unwrap_or(cursor);
+    let mut lines = vec![
+        Line::from(Span::styled(
+            first.format("%B %Y").to_string(),
+            Style::default().fg(theme.highlight).add_modifier(Modifier::BOLD),
+        ))
+        .centered(),
+        Line::from(Span::styled(
+            (0..7)
+                .map(|offset| {
+                    let weekday = (0..offset).fold(week_start, |day, _| day.succ());
+                    format!(" {:<3}", &weekday.to_string()[..2])
+                })
+                .collect::<String>(),
+            Style::default().fg(theme.label),
+        )),
+    ];
+
+    let mut day = first.week(week_start).first_day();
+    while day.month() == first.month() || day < first {
+        let mut spans = Vec::with_capacity(7);
+        for _ in 0..7 {
+            let text = if day.month() != first.month() {
+                "    ".to_string()
+            } else if day == cursor {
+                format!("[{:>2}]", day.day())
+            } else {
+                format!(" {:>2} ", day.day())
+            };
+            let style = if day > today {
+                Style::default().fg(theme.dim)
+            } else if has_log(day) {
+                Style::default().fg(theme.good).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.text)
+            };
+            let style = if day == today {
+                style.add_modifier(Modifier::UNDERLINED)
+            } else {
+                style
+            };
+            spans.push(Span::styled(text, style));
+            day = day.succ_opt().unwrap_or(day);
+        }
+        lines.push(Line::from(spans));
+    }
+    lines
+}
+
 pub fn centered_rect(area: Rect, percent_x: u16, percent_y: u16) -> Rect {
     let vertical = Layout::vertical([Constraint::Percentage(percent_y)])
         .flex(Flex::Center)
@@ -252,6 +528,26 @@ mod tests {
         assert_eq!(help_line_width("a: A | b: B"), 11);
     }
 
+    #[test]
+    fn calendar_lays_out_the_month_from_the_week_start() {
+        let date = |d| NaiveDate::from_ymd_opt(2024, 11, d).unwrap();
+        let theme = Theme::default();
+        let lines = calendar_lines(date(13), date(20), Weekday::Mon, |day| day == date(1), &theme);
+        let text: Vec<String> = lines.iter().map(|line| line.to_string()).collect();
+
+        assert_eq!(text[0], "November 2024");
+        assert_eq!(text[1], " Mo  Tu  We  Th  Fr  Sa  Su ");
+        assert_eq!(text[2], "                  1   2   3 ");
+        assert_eq!(text[4], " 11  12 [13] 14  15  16  17 ");
+        assert_eq!(text.len(), 7);
+        assert!(text.iter().all(|line| line.chars().count() <= CALENDAR_WIDTH as usize));
+        assert_eq!(lines[2].spans[4].style.fg, Some(theme.good));
+        assert_eq!(lines[5].spans[6].style.fg, Some(theme.dim));
+
+        let sunday_first = calendar_lines(date(13), date(20), Weekday::Sun, |_| false, &theme);
+        assert_eq!(sunday_first[1].to_string(), " Su  Mo  Tu  We  Th  Fr  Sa ");
+    }
+
     #[test]
     fn help_regions_follow_centered_rendered_segments() {
         let regions = build_help_regions("a: Add | q: Quit", Rect::new(10, 5, 30, 1), true);
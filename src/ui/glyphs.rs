@@ -0,0 +1,89 @@
+use ratatui::buffer::Buffer;
+
+/// ASCII stand-in for a decorative glyph, or `None` for symbols that should be
+/// left alone (plain ASCII, and letters like the é in a food name).
+pub fn ascii_fallback(symbol: &str) -> Option<&'static str> {
+    let c = symbol.chars().next()?;
+    if c.is_ascii() {
+        return None;
+    }
+    let replacement = match c {
+        // Box drawing: lines and every corner/junction style
+        '─' | '━' | '═' | '┄' | '┅' | '┈' | '┉' | '╌' | '╍' | '╴' | '╶' | '╸' | '╺' => "-",
+        '│' | '┃' | '║' | '┆' | '┇' | '┊' | '┋' | '╎' | '╏' | '╵' | '╷' | '╹' | '╻' => "|",
+        '\u{2500}'..='\u{257F}' => "+",
+        // Block elements, as drawn by sparklines, bars and scrollbars
+        '▁' | '▂' | '▃' => "_",
+        '▄' | '▅' | '▆' => "=",
+        '\u{2580}'..='\u{259F}' | '■' => "#",
+        // Braille dots from chart line markers; the empty pattern stays blank
+        '\u{2800}' => " ",
+        '\u{2801}'..='\u{28FF}' => ".",
+        '←' => "<",
+        '→' | '►' | '▶' | '▸' => ">",
+        '↑' | '▲' => "^",
+        '↓' | '▼' | '▾' => "v",
+        '✓' | '✔' | '•' | '●' => "*",
+        '⚪' | '○' => "o",
+        '⚠' => "!",
+        '—' | '–' => "-",
+        '×' => "x",
+        '±' => "+",
+        '…' | '·' => ".",
+        _ => return None,
+    };
+    Some(replacement)
+}
+
+/// Rewrites every decorative glyph in a rendered frame to ASCII. Runs once per
+/// frame after all screens have drawn, so widgets don't need to know the mode.
+pub fn asciify(buffer: &mut Buffer) {
+    for cell in buffer.content.iter_mut() {
+        if let Some(replacement) = ascii_fallback(cell.symbol()) {
+            cell.set_symbol(replacement);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{
+        Terminal,
+        backend::TestBackend,
+        widgets::{Block, BorderType, Borders, Paragraph},
+    };
+
+    #[test]
+    fn decorative_glyphs_map_to_ascii_and_text_is_kept() {
+        assert_eq!(ascii_fallback("╭"), Some("+"));
+        assert_eq!(ascii_fallback("►"), Some(">"));
+        assert_eq!(ascii_fallback("⚠️"), Some("!"));
+        assert_eq!(ascii_fallback("⣿"), Some("."));
+        assert_eq!(ascii_fallback("a"), None);
+        assert_eq!(ascii_fallback("é"), None);
+    }
+
+    #[test]
+    fn asciify_rewrites_a_rendered_frame() {
+        let mut terminal = Terminal::new(TestBackend::new(12, 3)).unwrap();
+        terminal
+            .draw(|f| {
+                let block = Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded);
+                f.render_widget(Paragraph::new("✓ café").block(block), f.area());
+                asciify(f.buffer_mut());
+            })
+            .unwrap();
+
+        let rows: Vec<String> = terminal
+            .backend()
+            .buffer()
+            .content
+            .chunks(12)
+            .map(|row| row.iter().map(|cell| cell.symbol()).collect())
+            .collect();
+        assert_eq!(rows, ["+----------+", "|* café    |", "+----------+"]);
+    }
+}
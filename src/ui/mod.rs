@@ -1,6 +1,8 @@
 pub mod components;
+pub mod glyphs;
 pub mod modals;
 pub mod screens;
+pub mod theme;
 
 use crate::models::ConfigSyncField;
 use crate::models::field_accessor::FieldType;
@@ -13,6 +15,8 @@ pub enum ClickAction {
     StartupLogs,
     StartupAddDate,
     OpenStatistics,
+    OpenHeatmap,
+    OpenCharts,
     OpenCloudSync,
     Quit,
     BackToStartup,
@@ -26,6 +30,8 @@ pub enum ClickAction {
     Notes,
     FocusConfigField(ConfigSyncField),
     ToggleConfigSync,
+    /// A day on the calendar picker.
+    CalendarDay(chrono::NaiveDate),
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -57,6 +63,16 @@ pub fn left_click_position(mouse: MouseEvent) -> Option<(u16, u16)> {
     }
 }
 
+/// Where the pointer moved to, for hover tooltips. Terminals only report
+/// moves while mouse capture is on.
+pub fn hover_position(mouse: MouseEvent) -> Option<(u16, u16)> {
+    if mouse.kind == MouseEventKind::Moved {
+        Some((mouse.column, mouse.row))
+    } else {
+        None
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -104,5 +120,7 @@ mod tests {
         );
         assert_eq!(left_click_position(mouse(MouseEventKind::ScrollDown)), None);
         assert_eq!(left_click_position(mouse(MouseEventKind::Moved)), None);
+        assert_eq!(hover_position(mouse(MouseEventKind::Moved)), Some((7, 9)));
+        assert_eq!(hover_position(mouse(MouseEventKind::ScrollDown)), None);
     }
 }
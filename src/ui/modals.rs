@@ -1,11 +1,15 @@
 use ratatui::{
     Frame,
+    layout::Rect,
     style::{Color, Style},
-    widgets::{Block, Borders, Clear, Paragraph},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
 };
 
-use crate::ui::components::{centered_rect, create_input_style, format_input_with_cursor};
+use crate::ui::components::{
+    centered_rect, create_highlight_style, create_input_style, format_input_with_cursor,
+};
 use crate::ui::screens::{calculate_cursor_in_wrapped_text, wrap_at_width};
+use crate::ui::theme::Theme;
 
 /// Types of input modals
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -73,13 +77,15 @@ impl InputModalConfig {
     }
 }
 
-/// Renders a generic input modal over the current screen
+/// Renders a generic input modal over the current screen, returning where
+/// it was drawn so suggestions can hang off it
 pub fn render_input_modal(
     f: &mut Frame,
     config: InputModalConfig,
     input_buffer: &str,
     cursor_position: usize,
-) {
+    theme: &Theme,
+) -> Rect {
     let (default_width, height_percent) = config.modal_type.dimensions();
     let width_percent = config.width_percent.unwrap_or(default_width);
     let popup_area = centered_rect(f.area(), width_percent, height_percent);
@@ -103,7 +109,7 @@ pub fn render_input_modal(
         InputModalType::Text => {
             // Single-line input rendering
             let input_text = format_input_with_cursor(input_buffer);
-            let input = Paragraph::new(input_text).style(create_input_style());
+            let input = Paragraph::new(input_text).style(create_input_style(theme));
             f.render_widget(input, inner_area);
 
             // Set cursor position (inner area already accounts for borders and padding)
@@ -118,7 +124,7 @@ pub fn render_input_modal(
                 wrap_at_width(input_buffer, width)
             };
 
-            let input = Paragraph::new(wrapped_text).style(create_input_style());
+            let input = Paragraph::new(wrapped_text).style(create_input_style(theme));
             f.render_widget(input, inner_area);
 
             // Calculate cursor position on the wrapped text
@@ -127,4 +133,44 @@ pub fn render_input_modal(
             f.set_cursor_position((cursor_x, cursor_y));
         }
     }
+    popup_area
+}
+
+/// A dropdown of completions hanging off the bottom edge of `popup_area`,
+/// with `selected` highlighted.
+pub fn render_suggestions(
+    f: &mut Frame,
+    suggestions: &[String],
+    selected: Option<usize>,
+    popup_area: Rect,
+    theme: &Theme,
+) {
+    if suggestions.is_empty() {
+        return;
+    }
+    let y = popup_area.bottom();
+    let height = (suggestions.len() as u16 + 2).min(f.area().bottom().saturating_sub(y));
+    if height < 3 {
+        return;
+    }
+    let area = Rect::new(popup_area.x, y, popup_area.width, height);
+
+    let items: Vec<ListItem> = suggestions
+        .iter()
+        .map(|suggestion| ListItem::new(suggestion.as_str()))
+        .collect();
+    let mut list_state = ListState::default();
+    list_state.select(selected);
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.dim))
+                .title("Frequent (↑/↓, Tab)"),
+        )
+        .style(Style::default().fg(theme.text))
+        .highlight_style(create_highlight_style());
+
+    f.render_widget(Clear, area);
+    f.render_stateful_widget(list, area, &mut list_state);
 }
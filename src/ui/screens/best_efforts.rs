@@ -0,0 +1,144 @@
+use chrono::NaiveDate;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Padding, Paragraph},
+};
+
+use crate::best_efforts::{self, BEST_EFFORTS, BestEffort};
+use crate::models::AppState;
+use crate::format::Units;
+use crate::units::Quantity;
+
+/// Renders the ten longest days and the ten with the most vert side by
+/// side, `selected` highlighted in the `focused` list.
+pub fn render_best_efforts_screen(
+    f: &mut Frame,
+    state: &AppState,
+    focused: BestEffort,
+    selected: usize,
+) {
+    let theme = &state.theme;
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme.sections.running))
+        .title(" Best Efforts ")
+        .title_style(Style::default().fg(theme.text).add_modifier(Modifier::BOLD))
+        .padding(Padding::new(2, 2, 1, 1));
+    let inner_area = block.inner(f.area());
+    f.render_widget(block, f.area());
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(inner_area);
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(chunks[0]);
+
+    for (effort, area) in [(BestEffort::Miles, columns[0]), (BestEffort::Elevation, columns[1])] {
+        let highlighted = (effort == focused).then_some(selected);
+        render_column(f, state, effort, highlighted, area);
+    }
+
+    let help_spans = vec![
+        Span::styled("j/k", Style::default().fg(theme.highlight)),
+        Span::styled(": Select | ", Style::default().fg(theme.text)),
+        Span::styled("Tab", Style::default().fg(theme.highlight)),
+        Span::styled(": Distance/Vert | ", Style::default().fg(theme.text)),
+        Span::styled("Enter", Style::default().fg(theme.highlight)),
+        Span::styled(": Open Day | ", Style::default().fg(theme.text)),
+        Span::styled("Esc", Style::default().fg(theme.highlight)),
+        Span::styled(": Back", Style::default().fg(theme.text)),
+    ];
+    f.render_widget(
+        Paragraph::new(Line::from(help_spans)).alignment(ratatui::layout::Alignment::Center),
+        chunks[1],
+    );
+}
+
+fn render_column(
+    f: &mut Frame,
+    state: &AppState,
+    effort: BestEffort,
+    selected: Option<usize>,
+    area: Rect,
+) {
+    let theme = &state.theme;
+    let title_style = if selected.is_some() {
+        Style::default()
+            .fg(theme.highlight)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(theme.text).add_modifier(Modifier::BOLD)
+    };
+    let mut lines = vec![Line::from(Span::styled(effort.title(), title_style)), Line::from("")];
+
+    let days = best_efforts::top_days(state.daily_logs(), effort, BEST_EFFORTS);
+    if days.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "Nothing logged yet",
+            Style::default().fg(theme.dim),
+        )));
+    }
+    let selected = selected.map(|index| index.min(days.len().saturating_sub(1)));
+    for (index, &(date, value)) in days.iter().enumerate() {
+        let style = if Some(index) == selected {
+            Style::default()
+                .fg(theme.highlight)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme.text)
+        };
+        lines.push(Line::from(Span::styled(
+            row(index, date, effort, value, state.units, Some(index) == selected),
+            style,
+        )));
+    }
+    f.render_widget(Paragraph::new(lines), area);
+}
+
+/// "►  1. 20.5 mi   Sat May 02, 2026"
+fn row(
+    index: usize,
+    date: NaiveDate,
+    effort: BestEffort,
+    value: f32,
+    units: Units,
+    selected: bool,
+) -> String {
+    let quantity = match effort {
+        BestEffort::Miles => Quantity::Distance,
+        BestEffort::Elevation => Quantity::Elevation,
+    };
+    format!(
+        "{}{:>2}. {:<10} {}",
+        if selected { "► " } else { "  " },
+        index + 1,
+        units.format(quantity, value),
+        units.short_weekday_date(date)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::UnitSystem;
+
+    #[test]
+    fn rows_rank_the_day_with_its_value() {
+        let date = NaiveDate::from_ymd_opt(2026, 5, 2).unwrap();
+        assert_eq!(
+            row(0, date, BestEffort::Miles, 20.5, UnitSystem::Imperial.into(), true),
+            "►  1. 20.5 mi    Sat May 02, 2026"
+        );
+        assert_eq!(
+            row(9, date, BestEffort::Elevation, 4100.0, UnitSystem::Imperial.into(), false),
+            "  10. 4,100 ft   Sat May 02, 2026"
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use chrono::{Datelike, NaiveDate};
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, ListState, Padding, Paragraph},
+};
+
+use super::home::render_home_screen;
+use crate::models::AppState;
+use crate::ui::components::{CALENDAR_WIDTH, calendar_lines};
+use crate::ui::{ClickAction, ClickTarget};
+
+/// Renders the calendar picker as a popup over the logged days list, with a
+/// line under the grid saying whether the cursor's day has a log.
+pub fn render_calendar_screen(
+    f: &mut Frame,
+    state: &AppState,
+    list_state: &mut ListState,
+    sync_status: &str,
+    cursor: NaiveDate,
+    click_targets: Option<&mut Vec<ClickTarget>>,
+) {
+    render_home_screen(f, state, list_state, sync_status, None);
+    let theme = &state.theme;
+
+    let today = chrono::Local::now().date_naive();
+    let week_start = state.stats.config().week_start;
+    let mut lines = calendar_lines(
+        cursor,
+        today,
+        week_start,
+        |date| state.counted_daily_log(date).is_some(),
+        theme,
+    );
+    let status = if state.counted_daily_log(cursor).is_some() {
+        "has a log"
+    } else {
+        "no log yet"
+    };
+    lines.push(Line::from(""));
+    lines.push(
+        Line::from(Span::styled(
+            format!("{}: {}", state.units.weekday_month_day(cursor), status),
+            Style::default().fg(theme.label),
+        ))
+        .centered(),
+    );
+
+    let area = f.area();
+    let width = (CALENDAR_WIDTH + 6).min(area.width);
+    let height = (lines.len() as u16 + 5).min(area.height);
+    let popup_area = Rect {
+        x: area.x + area.width.saturating_sub(width) / 2,
+        y: area.y + area.height.saturating_sub(height) / 2,
+        width,
+        height,
+    };
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme.border))
+        .title(" Calendar ")
+        .title_style(Style::default().fg(theme.text).add_modifier(Modifier::BOLD))
+        .padding(Padding::new(2, 2, 1, 0));
+    let inner_area = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(inner_area);
+    f.render_widget(Paragraph::new(lines), chunks[0]);
+    if let Some(click_targets) = click_targets {
+        push_day_targets(click_targets, chunks[0], cursor, week_start);
+    }
+
+    let help_spans = vec![
+        Span::styled("hjkl", Style::default().fg(theme.highlight)),
+        Span::styled(" [ ] ", Style::default().fg(theme.highlight)),
+        Span::styled("Enter", Style::default().fg(theme.highlight)),
+        Span::styled(" Esc", Style::default().fg(theme.highlight)),
+    ];
+    f.render_widget(
+        Paragraph::new(Line::from(help_spans)).alignment(Alignment::Center),
+        chunks[1],
+    );
+}
+
+/// A target over each day cell of the `calendar_lines` grid drawn at `area`:
+/// below the month and weekday rows, four columns a day.
+fn push_day_targets(
+    click_targets: &mut Vec<ClickTarget>,
+    area: Rect,
+    cursor: NaiveDate,
+    week_start: chrono::Weekday,
+) {
+    let first = cursor.with_day(1).unwrap_or(cursor);
+    let grid_start = first.week(week_start).first_day();
+    let mut day = first;
+    while day.month() == first.month() {
+        let offset = (day - grid_start).num_days() as u16;
+        let cell = Rect::new(area.x + offset % 7 * 4, area.y + 2 + offset / 7, 4, 1);
+        if area.contains(cell.as_position()) {
+            click_targets.push(ClickTarget::new(cell, ClickAction::CalendarDay(day)));
+        }
+        match day.succ_opt() {
+            Some(next) => day = next,
+            None => break,
+        }
+    }
+}
@@ -0,0 +1,584 @@
+use chrono::{Days, NaiveDate};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    symbols,
+    text::{Line, Span},
+    widgets::{Axis, Block, Borders, Chart, Dataset, GraphType, Paragraph, Sparkline},
+};
+
+use crate::elevation_stats::weekly_elevation_series;
+use crate::injuries;
+use crate::miles_stats::weekly_miles_series;
+use crate::models::AppState;
+use crate::models::field_accessor::{FieldType, format_hours};
+use crate::sleep_stats::{average_sleep, weekly_sleep_series};
+use crate::ui::components::{create_standard_layout, render_help, render_title};
+use crate::ui::theme::Theme;
+use crate::format::Units;
+use crate::units::{Quantity, UnitSystem};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ChartMetric {
+    Weight,
+    Waist,
+    WeeklyMiles,
+    WeeklyVert,
+    WeeklySleep,
+}
+
+impl ChartMetric {
+    const ALL: [ChartMetric; 5] = [
+        ChartMetric::Weight,
+        ChartMetric::Waist,
+        ChartMetric::WeeklyMiles,
+        ChartMetric::WeeklyVert,
+        ChartMetric::WeeklySleep,
+    ];
+
+    pub fn label(&self, units: Units) -> &'static str {
+        match self {
+            ChartMetric::Weight => "Weight",
+            ChartMetric::Waist => "Waist",
+            ChartMetric::WeeklyMiles => match units.system {
+                UnitSystem::Imperial => "Weekly Miles",
+                UnitSystem::Metric => "Weekly Kilometers",
+            },
+            ChartMetric::WeeklyVert => "Weekly Vert",
+            ChartMetric::WeeklySleep => "Weekly Sleep",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|m| *m == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    pub fn prev(self) -> Self {
+        let index = Self::ALL.iter().position(|m| *m == self).unwrap_or(0);
+        Self::ALL[(index + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ChartRange {
+    Days30,
+    Days90,
+    Year,
+}
+
+impl ChartRange {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ChartRange::Days30 => "30 days",
+            ChartRange::Days90 => "90 days",
+            ChartRange::Year => "year",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            ChartRange::Days30 => ChartRange::Days90,
+            ChartRange::Days90 => ChartRange::Year,
+            ChartRange::Year => ChartRange::Days30,
+        }
+    }
+
+    /// First day of the range ending on `today`, inclusive.
+    pub fn start(&self, today: NaiveDate) -> NaiveDate {
+        let days = match self {
+            ChartRange::Days30 => 29,
+            ChartRange::Days90 => 89,
+            ChartRange::Year => 364,
+        };
+        today.checked_sub_days(Days::new(days)).unwrap_or(today)
+    }
+}
+
+/// Renders the trends screen: one metric over the selected range, drawn as a
+/// line chart for body measurements and as weekly bars for training volume.
+pub fn render_charts_screen(
+    f: &mut Frame,
+    state: &AppState,
+    metric: ChartMetric,
+    range: ChartRange,
+    today: NaiveDate,
+) {
+    let theme = &state.theme;
+    let chunks = create_standard_layout(f.area());
+    render_title(f, chunks[0], "Trends", theme);
+
+    if state.daily_logs().is_empty() {
+        render_no_logs(f, chunks[1], state);
+        render_help(f, chunks[2], &[" Esc: Back"], true, true, theme);
+        return;
+    }
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(
+            "{} - last {}",
+            metric.label(state.units),
+            range.label()
+        ))
+        .border_style(Style::default().fg(theme.border))
+        .padding(ratatui::widgets::Padding::horizontal(1));
+    let inner = block.inner(chunks[1]);
+    f.render_widget(block, chunks[1]);
+
+    let sections = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2),
+            Constraint::Min(3),
+            Constraint::Length(2),
+        ])
+        .split(inner);
+    f.render_widget(
+        Paragraph::new(metric_tabs(metric, state.units, theme)),
+        sections[0],
+    );
+
+    let from = range.start(today);
+    let summary = match metric {
+        ChartMetric::Weight => {
+            render_measurement_chart(f, sections[1], state, FieldType::Weight, from, today)
+        }
+        ChartMetric::Waist => {
+            render_measurement_chart(f, sections[1], state, FieldType::Waist, from, today)
+        }
+        ChartMetric::WeeklyMiles => {
+            let units = state.units;
+            let series: Vec<(NaiveDate, f32)> = weekly_miles_series(
+                state.daily_logs(),
+                from,
+                today,
+                state.stats.config().week_start,
+            )
+            .into_iter()
+            .map(|(week, miles)| (week, units.convert(Quantity::Distance, miles as f64) as f32))
+            .collect();
+            let unit = units.label(Quantity::Distance);
+            let total: f32 = series.iter().map(|(_, miles)| miles).sum();
+            let best = series.iter().map(|(_, miles)| *miles).fold(0.0, f32::max);
+            let bars: Vec<u64> = series
+                .iter()
+                .map(|(_, miles)| (miles * 10.0) as u64)
+                .collect();
+            let weeks = series_dates(&series);
+            let hurt = injuries::weeks_hurt(&state.injuries, &weeks);
+            render_weekly_bars(f, sections[1], &weeks, &bars, &hurt, state.units, theme);
+            let decimals = units.decimals(Quantity::Distance);
+            let mut summary = format!(
+                "Total {:.*} {unit} | Avg {:.*} {unit}/week | Best week {:.*} {unit}",
+                decimals,
+                total,
+                decimals,
+                total / series.len().max(1) as f32,
+                decimals,
+                best
+            );
+            if hurt.contains(&true) {
+                summary.push_str(" | ━ injured");
+            }
+            summary
+        }
+        ChartMetric::WeeklyVert => {
+            let units = state.units;
+            let series: Vec<(NaiveDate, i32)> = weekly_elevation_series(
+                state.daily_logs(),
+                from,
+                today,
+                state.stats.config().week_start,
+            )
+            .into_iter()
+            .map(|(week, feet)| {
+                let shown = units.convert(Quantity::Elevation, feet as f64);
+                (week, shown.round() as i32)
+            })
+            .collect();
+            let unit = units.label(Quantity::Elevation);
+            let total: i32 = series.iter().map(|(_, feet)| feet).sum();
+            let best = series.iter().map(|(_, feet)| *feet).max().unwrap_or(0);
+            let bars: Vec<u64> = series
+                .iter()
+                .map(|(_, feet)| (*feet).max(0) as u64)
+                .collect();
+            let weeks = series_dates(&series);
+            render_weekly_bars(f, sections[1], &weeks, &bars, &[], state.units, theme);
+            format!(
+                "Total {} {unit} | Avg {} {unit}/week | Best week {} {unit}",
+                total,
+                total / series.len().max(1) as i32,
+                best
+            )
+        }
+        ChartMetric::WeeklySleep => {
+            let series = weekly_sleep_series(
+                state.daily_logs(),
+                from,
+                today,
+                state.stats.config().week_start,
+            );
+            // Weeks without sleep logged show as gaps, not short nights
+            let bars: Vec<u64> = series
+                .iter()
+                .map(|(_, hours)| (hours.unwrap_or(0.0) * 10.0) as u64)
+                .collect();
+            let weeks = series_dates(&series);
+            render_weekly_bars(f, sections[1], &weeks, &bars, &[], state.units, theme);
+            let logged: Vec<f32> = series.iter().filter_map(|(_, hours)| *hours).collect();
+            let nights = state
+                .daily_logs()
+                .iter()
+                .filter(|log| log.date >= from && log.date <= today);
+            match average_sleep(nights) {
+                Some(average) => format!(
+                    "Avg {} h/night | Best week {} h | Worst week {} h",
+                    format_hours(average),
+                    format_hours(logged.iter().copied().fold(0.0, f32::max)),
+                    format_hours(logged.iter().copied().fold(f32::MAX, f32::min))
+                ),
+                None => "No sleep logged in this range yet.".to_string(),
+            }
+        }
+    };
+    f.render_widget(
+        Paragraph::new(Line::from(Span::styled(
+            summary,
+            Style::default().fg(theme.highlight),
+        ))),
+        sections[2],
+    );
+
+    render_help(
+        f,
+        chunks[2],
+        &[
+            " ←/→ h/l: Metric | r: Range (30d/90d/year) | Esc: Back",
+            " hl: Metric | r: Range | Esc: Back",
+        ],
+        true,
+        true,
+        theme,
+    );
+}
+
+/// The trends screen before anything is logged: what each chart will show
+/// and what to log to fill it in.
+fn render_no_logs(f: &mut Frame, area: Rect, state: &AppState) {
+    let theme = &state.theme;
+    let units = state.units;
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title("Nothing to chart yet")
+        .border_style(Style::default().fg(theme.border))
+        .padding(ratatui::widgets::Padding::uniform(1));
+    let chart = |metric: ChartMetric, about: String| {
+        Line::from(vec![
+            Span::styled(
+                format!("{:<18}", metric.label(units)),
+                Style::default().fg(theme.highlight).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(about, Style::default().fg(theme.text)),
+        ])
+    };
+    let lines = vec![
+        Line::from(Span::styled(
+            "Charts fill in as you log days. Once you do, you'll find:",
+            Style::default().fg(theme.text),
+        )),
+        Line::default(),
+        chart(
+            ChartMetric::Weight,
+            "a line through each weigh-in (press w in a day's log)".to_string(),
+        ),
+        chart(
+            ChartMetric::Waist,
+            "a line through each waist measurement (s)".to_string(),
+        ),
+        chart(
+            ChartMetric::WeeklyMiles,
+            format!("a bar per week of {} run (m)", units.name(Quantity::Distance)),
+        ),
+        chart(
+            ChartMetric::WeeklyVert,
+            format!("a bar per week of {} climbed (l)", units.name(Quantity::Elevation)),
+        ),
+        chart(
+            ChartMetric::WeeklySleep,
+            "a bar per week of average hours slept (z)".to_string(),
+        ),
+        Line::default(),
+        Line::from(Span::styled(
+            "Each covers the last 30 days, 90 days or year. Press n on the startup screen to start today's log.",
+            Style::default().fg(theme.dim),
+        )),
+    ];
+    f.render_widget(
+        Paragraph::new(lines)
+            .wrap(ratatui::widgets::Wrap { trim: false })
+            .block(block),
+        area,
+    );
+}
+
+fn metric_tabs(selected: ChartMetric, units: Units, theme: &Theme) -> Line<'static> {
+    let mut spans = Vec::new();
+    for metric in ChartMetric::ALL {
+        let style = if metric == selected {
+            Style::default()
+                .fg(theme.on_border)
+                .bg(theme.border)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme.label)
+        };
+        spans.push(Span::styled(format!(" {} ", metric.label(units)), style));
+        spans.push(Span::raw(" "));
+    }
+    Line::from(spans)
+}
+
+fn series_dates<T>(series: &[(NaiveDate, T)]) -> Vec<NaiveDate> {
+    series.iter().map(|(date, _)| *date).collect()
+}
+
+/// Line chart of a body measurement, one point per logged day. Returns the
+/// summary line shown below the chart.
+fn render_measurement_chart(
+    f: &mut Frame,
+    area: Rect,
+    state: &AppState,
+    field: FieldType,
+    from: NaiveDate,
+    today: NaiveDate,
+) -> String {
+    let theme = &state.theme;
+    let mut values: Vec<(NaiveDate, f32)> = state
+        .daily_logs()
+        .iter()
+        .filter(|log| log.date >= from && log.date <= today)
+        .filter_map(|log| field.numeric_value(log).map(|value| (log.date, value)))
+        .map(|(date, value)| {
+            let shown = field.quantity().map_or(value as f64, |quantity| {
+                state.units.convert(quantity, value as f64)
+            });
+            (date, shown as f32)
+        })
+        .collect();
+    values.reverse();
+
+    let (Some(first), Some(last)) = (values.first(), values.last()) else {
+        f.render_widget(
+            Paragraph::new("Nothing logged in this range yet.")
+                .style(Style::default().fg(theme.dim)),
+            area,
+        );
+        return String::new();
+    };
+
+    let low = values.iter().map(|(_, v)| *v).fold(f32::MAX, f32::min);
+    let high = values.iter().map(|(_, v)| *v).fold(f32::MIN, f32::max);
+    let points: Vec<(f64, f64)> = values
+        .iter()
+        .map(|(date, value)| ((*date - from).num_days() as f64, *value as f64))
+        .collect();
+    // Keep a flat line off the chart's edges
+    let pad = ((high - low) as f64 * 0.1).max(0.5);
+    let span_days = (today - from).num_days() as f64;
+    let decimals = field
+        .quantity()
+        .map_or(1, |quantity| state.units.decimals(quantity));
+
+    let dataset = Dataset::default()
+        .marker(symbols::Marker::Braille)
+        .graph_type(GraphType::Line)
+        .style(Style::default().fg(theme.good))
+        .data(&points);
+    let chart = Chart::new(vec![dataset])
+        .x_axis(
+            Axis::default()
+                .style(Style::default().fg(theme.label))
+                .bounds([0.0, span_days])
+                .labels([
+                    state.units.month_day(from),
+                    state.units.month_day(today),
+                ]),
+        )
+        .y_axis(
+            Axis::default()
+                .style(Style::default().fg(theme.label))
+                .bounds([low as f64 - pad, high as f64 + pad])
+                .labels([
+                    format!("{:.*}", decimals, low),
+                    format!("{:.*}", decimals, high),
+                ]),
+        );
+    f.render_widget(chart, area);
+
+    format!(
+        "Latest {:.*} | Low {:.*} | High {:.*} | Change {:+.*}",
+        decimals,
+        last.1,
+        decimals,
+        low,
+        decimals,
+        high,
+        decimals,
+        last.1 - first.1
+    )
+}
+
+/// Sparkline of weekly totals, each week stretched to an equal share of the
+/// width, with the first and last week's dates underneath.
+/// `hurt` marks the weeks an injury covered with a line under their bars;
+/// empty, or all false, leaves the row out.
+fn render_weekly_bars(
+    f: &mut Frame,
+    area: Rect,
+    weeks: &[NaiveDate],
+    totals: &[u64],
+    hurt: &[bool],
+    units: Units,
+    theme: &Theme,
+) {
+    let marked = hurt.contains(&true);
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(1),
+            Constraint::Length(if marked { 1 } else { 0 }),
+            Constraint::Length(1),
+        ])
+        .split(area);
+
+    let bar_width = (rows[0].width as usize / totals.len().max(1)).max(1);
+    let data: Vec<u64> = totals
+        .iter()
+        .flat_map(|total| std::iter::repeat_n(*total, bar_width))
+        .collect();
+    f.render_widget(
+        Sparkline::default()
+            .data(&data)
+            .style(Style::default().fg(theme.good)),
+        rows[0],
+    );
+
+    if marked {
+        let line: String = hurt
+            .iter()
+            .map(|&hurt| if hurt { "━" } else { " " }.repeat(bar_width))
+            .collect();
+        f.render_widget(
+            Paragraph::new(line).style(Style::default().fg(theme.error)),
+            rows[1],
+        );
+    }
+
+    if let (Some(first), Some(last)) = (weeks.first(), weeks.last()) {
+        let left = units.month_day(*first);
+        let right = units.month_day(*last);
+        let gap = (bar_width * totals.len()).saturating_sub(left.len() + right.len());
+        f.render_widget(
+            Paragraph::new(format!("{}{}{}", left, " ".repeat(gap), right))
+                .style(Style::default().fg(theme.label)),
+            rows[2],
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::DailyLog;
+    use ratatui::{Terminal, backend::TestBackend};
+
+    fn render(
+        state: &AppState,
+        metric: ChartMetric,
+        range: ChartRange,
+        today: NaiveDate,
+    ) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+        terminal
+            .draw(|frame| render_charts_screen(frame, state, metric, range, today))
+            .unwrap();
+        terminal
+            .backend()
+            .buffer()
+            .content
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect()
+    }
+
+    #[test]
+    fn metric_and_range_cycle() {
+        assert_eq!(ChartMetric::WeeklySleep.next(), ChartMetric::Weight);
+        assert_eq!(ChartMetric::Weight.prev(), ChartMetric::WeeklySleep);
+        assert_eq!(ChartRange::Year.next(), ChartRange::Days30);
+        let today = NaiveDate::from_ymd_opt(2026, 7, 30).unwrap();
+        assert_eq!(
+            ChartRange::Days30.start(today),
+            NaiveDate::from_ymd_opt(2026, 7, 1).unwrap()
+        );
+    }
+
+    #[test]
+    fn renders_summaries_for_each_metric() {
+        let today = NaiveDate::from_ymd_opt(2026, 7, 30).unwrap();
+        let mut state = AppState::new();
+        state.set_daily_logs(
+            [
+                (30, 171.0, 6.0, 1500),
+                (20, 173.5, 4.0, 800),
+                (1, 175.0, 10.0, 2000),
+            ]
+            .into_iter()
+            .map(|(day, weight, miles, feet)| DailyLog {
+                weight: Some(weight),
+                miles_covered: Some(miles),
+                elevation_gain: Some(feet),
+                ..DailyLog::new(NaiveDate::from_ymd_opt(2026, 7, day).unwrap())
+            })
+            .collect(),
+        );
+
+        let weight = render(&state, ChartMetric::Weight, ChartRange::Days30, today);
+        assert!(weight.contains("Weight - last 30 days"));
+        assert!(weight.contains("Latest 171.0 | Low 171.0 | High 175.0 | Change -4.0"));
+
+        let miles = render(&state, ChartMetric::WeeklyMiles, ChartRange::Days90, today);
+        assert!(miles.contains("Total 20.0 mi"));
+        assert!(miles.contains("Best week 10.0 mi"));
+        assert!(!miles.contains("injured"));
+
+        state.injuries = vec![crate::injuries::Injury {
+            id: 1,
+            body_part: "Left knee".to_string(),
+            severity: crate::injuries::Severity::Mild,
+            start: NaiveDate::from_ymd_opt(2026, 7, 20).unwrap(),
+            resolved: Some(NaiveDate::from_ymd_opt(2026, 7, 22).unwrap()),
+        }];
+        let miles = render(&state, ChartMetric::WeeklyMiles, ChartRange::Days90, today);
+        assert!(miles.contains("━"));
+        assert!(miles.contains("| ━ injured"));
+
+        let waist = render(&state, ChartMetric::Waist, ChartRange::Year, today);
+        assert!(waist.contains("Nothing logged in this range yet."));
+
+        let sleep = render(&state, ChartMetric::WeeklySleep, ChartRange::Days30, today);
+        assert!(sleep.contains("No sleep logged in this range yet."));
+    }
+
+    #[test]
+    fn explains_the_charts_before_anything_is_logged() {
+        let today = NaiveDate::from_ymd_opt(2026, 7, 30).unwrap();
+        let text = render(&AppState::new(), ChartMetric::Weight, ChartRange::Days30, today);
+        assert!(text.contains("Nothing to chart yet"));
+        assert!(text.contains("a bar per week of miles run (m)"));
+        assert!(!text.contains("Weight - last 30 days"));
+    }
+}
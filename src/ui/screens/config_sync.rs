@@ -1,7 +1,7 @@
 use ratatui::{
     Frame,
     layout::{Constraint, Direction, Layout},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, BorderType, Borders, Clear, Padding, Paragraph},
 };
@@ -22,19 +22,19 @@ pub fn render_config_sync_screen(
 ) {
     // Render startup screen behind as backdrop
     render_startup_screen(f, state, None);
+    let theme = &state.theme;
 
     let popup_area = centered_rect(f.area(), 60, 50);
     f.render_widget(Clear, popup_area);
 
-    let border_color = Color::Cyan;
     let block = Block::default()
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
-        .border_style(Style::default().fg(border_color))
+        .border_style(Style::default().fg(theme.border))
         .title(" Configure Cloud Sync ")
         .title_style(
             Style::default()
-                .fg(Color::White)
+                .fg(theme.text)
                 .add_modifier(Modifier::BOLD),
         )
         .padding(Padding::new(2, 2, 1, 1));
@@ -65,10 +65,10 @@ pub fn render_config_sync_screen(
     // DB URL label
     let url_label_style = if *focused == ConfigSyncField::DbUrl {
         Style::default()
-            .fg(Color::Yellow)
+            .fg(theme.highlight)
             .add_modifier(Modifier::BOLD)
     } else {
-        Style::default().fg(Color::DarkGray)
+        Style::default().fg(theme.dim)
     };
     f.render_widget(
         Paragraph::new("Database URL:").style(url_label_style),
@@ -82,14 +82,14 @@ pub fn render_config_sync_screen(
         url_buffer
     };
     let url_style = if *focused == ConfigSyncField::DbUrl {
-        Style::default().fg(Color::White)
+        Style::default().fg(theme.text)
     } else {
-        Style::default().fg(Color::DarkGray)
+        Style::default().fg(theme.dim)
     };
     let url_border_color = if *focused == ConfigSyncField::DbUrl {
-        Color::Yellow
+        theme.highlight
     } else {
-        Color::DarkGray
+        theme.dim
     };
     let url_block = Block::default()
         .borders(Borders::ALL)
@@ -104,10 +104,10 @@ pub fn render_config_sync_screen(
     // Auth Token label
     let token_label_style = if *focused == ConfigSyncField::AuthToken {
         Style::default()
-            .fg(Color::Yellow)
+            .fg(theme.highlight)
             .add_modifier(Modifier::BOLD)
     } else {
-        Style::default().fg(Color::DarkGray)
+        Style::default().fg(theme.dim)
     };
     f.render_widget(
         Paragraph::new("Auth Token:").style(token_label_style),
@@ -122,14 +122,14 @@ pub fn render_config_sync_screen(
         &"*".repeat(token_buffer.len())
     };
     let token_style = if *focused == ConfigSyncField::AuthToken {
-        Style::default().fg(Color::White)
+        Style::default().fg(theme.text)
     } else {
-        Style::default().fg(Color::DarkGray)
+        Style::default().fg(theme.dim)
     };
     let token_border_color = if *focused == ConfigSyncField::AuthToken {
-        Color::Yellow
+        theme.highlight
     } else {
-        Color::DarkGray
+        theme.dim
     };
     let token_block = Block::default()
         .borders(Borders::ALL)
@@ -155,7 +155,7 @@ pub fn render_config_sync_screen(
     if has_saved_token && token_buffer.is_empty() {
         f.render_widget(
             Paragraph::new(" (leave empty to keep existing)")
-                .style(Style::default().fg(Color::DarkGray)),
+                .style(Style::default().fg(theme.dim)),
             chunks[5],
         );
     }
@@ -163,17 +163,17 @@ pub fn render_config_sync_screen(
     // Enable toggle
     let toggle_style = if *focused == ConfigSyncField::EnableToggle {
         Style::default()
-            .fg(Color::Yellow)
+            .fg(theme.highlight)
             .add_modifier(Modifier::BOLD)
     } else {
-        Style::default().fg(Color::DarkGray)
+        Style::default().fg(theme.dim)
     };
 
     let enabled_span = if sync_enabled {
         Span::styled(
             "[Enabled]",
             Style::default()
-                .fg(Color::Green)
+                .fg(theme.good)
                 .add_modifier(Modifier::BOLD),
         )
     } else {
@@ -183,7 +183,7 @@ pub fn render_config_sync_screen(
     let disabled_span = if !sync_enabled {
         Span::styled(
             "[Disabled]",
-            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            Style::default().fg(theme.error).add_modifier(Modifier::BOLD),
         )
     } else {
         Span::styled("Disabled", toggle_style)
@@ -200,11 +200,11 @@ pub fn render_config_sync_screen(
     // Status message
     if let Some(status) = &state.config_sync_status {
         let color = if status.contains("Saved") {
-            Color::Green
+            theme.good
         } else if status.contains("Error") {
-            Color::Red
+            theme.error
         } else {
-            Color::Yellow
+            theme.highlight
         };
         f.render_widget(
             Paragraph::new(status.as_str()).style(Style::default().fg(color)),
@@ -214,14 +214,14 @@ pub fn render_config_sync_screen(
 
     // Help line
     let help_spans = vec![
-        Span::styled("Tab", Style::default().fg(Color::Yellow)),
-        Span::styled(": Next Field | ", Style::default().fg(Color::White)),
-        Span::styled("Space", Style::default().fg(Color::Yellow)),
-        Span::styled(": Toggle | ", Style::default().fg(Color::White)),
-        Span::styled("Enter", Style::default().fg(Color::Yellow)),
-        Span::styled(": Save | ", Style::default().fg(Color::White)),
-        Span::styled("Esc", Style::default().fg(Color::Yellow)),
-        Span::styled(": Cancel", Style::default().fg(Color::White)),
+        Span::styled("Tab", Style::default().fg(theme.highlight)),
+        Span::styled(": Next Field | ", Style::default().fg(theme.text)),
+        Span::styled("Space", Style::default().fg(theme.highlight)),
+        Span::styled(": Toggle | ", Style::default().fg(theme.text)),
+        Span::styled("Enter", Style::default().fg(theme.highlight)),
+        Span::styled(": Save | ", Style::default().fg(theme.text)),
+        Span::styled("Esc", Style::default().fg(theme.highlight)),
+        Span::styled(": Cancel", Style::default().fg(theme.text)),
     ];
     f.render_widget(
         Paragraph::new(Line::from(help_spans)).alignment(ratatui::layout::Alignment::Center),
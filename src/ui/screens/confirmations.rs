@@ -1,20 +1,27 @@
 use chrono::NaiveDate;
 use ratatui::{
     Frame,
-    style::{Color, Style},
+    style::Style,
     widgets::{Block, Borders, Clear, ListState, Paragraph},
 };
 
+use crate::format::Units;
 use crate::models::AppState;
 use crate::ui::components::{centered_rect, create_standard_layout, render_help, render_title};
-use super::daily_view::render_daily_view_screen;
+use crate::ui::theme::Theme;
+use super::daily_view::{render_daily_view_screen, InPlaceEdit};
 
 /// Renders the delete day confirmation screen
-pub fn render_confirm_delete_day_screen(f: &mut Frame, selected_date: NaiveDate) {
+pub fn render_confirm_delete_day_screen(
+    f: &mut Frame,
+    selected_date: NaiveDate,
+    units: Units,
+    theme: &Theme,
+) {
     let chunks = create_standard_layout(f.area());
 
     let title = "Delete Day - Confirmation Required";
-    render_title(f, chunks[0], title);
+    render_title(f, chunks[0], title, theme);
 
     let warning_text = format!(
         "Are you sure you want to delete the entire log for {}?\n\n\
@@ -26,22 +33,29 @@ pub fn render_confirm_delete_day_screen(f: &mut Frame, selected_date: NaiveDate)
         - Daily notes\n\n\
         This action cannot be undone.\n\n\
         Type 'y' to confirm deletion or 'n' to cancel.",
-        selected_date.format("%B %d, %Y")
+        units.date(selected_date)
     );
 
     let warning_widget = Paragraph::new(warning_text)
-        .style(Style::default().fg(Color::White))
+        .style(Style::default().fg(theme.text))
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Red))
+                .border_style(Style::default().fg(theme.error))
                 .title("Warning: Permanent Deletion")
                 .padding(ratatui::widgets::Padding::new(1, 0, 1, 0)),
         )
         .wrap(ratatui::widgets::Wrap { trim: false });
     f.render_widget(warning_widget, chunks[1]);
 
-    render_help(f, chunks[2], &["y: Delete Day | n/Esc: Cancel"], true, false);
+    render_help(
+        f,
+        chunks[2],
+        &["y: Delete Day | n/Esc: Cancel"],
+        true,
+        false,
+        theme,
+    );
 }
 
 /// Renders the delete food item confirmation dialog as a centered modal
@@ -65,6 +79,7 @@ pub fn render_confirm_delete_food_screen(
         "Unknown".to_string()
     };
 
+    let theme = &state.theme;
     let popup_area = centered_rect(f.area(), 60, 20);
 
     f.render_widget(Clear, popup_area);
@@ -78,7 +93,7 @@ pub fn render_confirm_delete_food_screen(
 
     let block = Block::default()
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Red))
+        .border_style(Style::default().fg(theme.error))
         .title("Confirm Deletion")
         .padding(ratatui::widgets::Padding::uniform(1));
 
@@ -86,7 +101,7 @@ pub fn render_confirm_delete_food_screen(
     f.render_widget(block, popup_area);
 
     let text = Paragraph::new(message)
-        .style(Style::default().fg(Color::White))
+        .style(Style::default().fg(theme.text))
         .wrap(ratatui::widgets::Wrap { trim: false });
     f.render_widget(text, inner_area);
 }
@@ -112,6 +127,7 @@ pub fn render_confirm_delete_sokay_screen(
         "Unknown".to_string()
     };
 
+    let theme = &state.theme;
     let popup_area = centered_rect(f.area(), 60, 20);
 
     f.render_widget(Clear, popup_area);
@@ -125,7 +141,7 @@ pub fn render_confirm_delete_sokay_screen(
 
     let block = Block::default()
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Red))
+        .border_style(Style::default().fg(theme.error))
         .title("Confirm Deletion")
         .padding(ratatui::widgets::Padding::uniform(1));
 
@@ -133,7 +149,100 @@ pub fn render_confirm_delete_sokay_screen(
     f.render_widget(block, popup_area);
 
     let text = Paragraph::new(message)
-        .style(Style::default().fg(Color::White))
+        .style(Style::default().fg(theme.text))
+        .wrap(ratatui::widgets::Wrap { trim: false });
+    f.render_widget(text, inner_area);
+}
+
+/// Renders the implausible-value prompt over the daily view, keeping the
+/// pending value visible in its row.
+pub fn render_confirm_value_screen(
+    f: &mut Frame,
+    state: &AppState,
+    food_list_state: &mut ListState,
+    sokay_list_state: &mut ListState,
+    sync_status: &str,
+    edit: InPlaceEdit,
+) {
+    render_daily_view_screen(
+        f,
+        state,
+        food_list_state,
+        sokay_list_state,
+        sync_status,
+        Some(edit),
+        None,
+    );
+
+    let theme = &state.theme;
+    let popup_area = centered_rect(f.area(), 60, 20);
+
+    f.render_widget(Clear, popup_area);
+
+    let message = format!(
+        "{}\n\n\
+        Press 'y' to save anyway or 'n' to keep editing.",
+        state.value_warning.as_deref().unwrap_or("This value looks unusual.")
+    );
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.highlight))
+        .title("Confirm Value")
+        .padding(ratatui::widgets::Padding::uniform(1));
+
+    let inner_area = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    let text = Paragraph::new(message)
+        .style(Style::default().fg(theme.text))
+        .wrap(ratatui::widgets::Wrap { trim: false });
+    f.render_widget(text, inner_area);
+}
+
+/// The y/n prompt over whatever screen 'q' was pressed on, when quitting now
+/// would lose a half-typed edit or cut a save short.
+/// Asks before Esc throws away a change to something already saved.
+pub fn render_discard_confirmation(f: &mut Frame, theme: &Theme) {
+    let popup_area = centered_rect(f.area(), 40, 20);
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.warning))
+        .title("Discard changes?")
+        .padding(ratatui::widgets::Padding::uniform(1));
+    let inner_area = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    let message = "This differs from what's saved.\n\nPress 'y' to discard it or 'n' to keep editing.";
+    let text = Paragraph::new(message)
+        .style(Style::default().fg(theme.text))
+        .wrap(ratatui::widgets::Wrap { trim: false });
+    f.render_widget(text, inner_area);
+}
+
+pub fn render_quit_confirmation(f: &mut Frame, concerns: &[String], theme: &Theme) {
+    let popup_area = centered_rect(f.area(), 60, 30);
+
+    f.render_widget(Clear, popup_area);
+
+    let message = format!(
+        "{}\n\nPress 'y' to quit anyway or 'n' to stay.",
+        concerns.join("\n")
+    );
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.highlight))
+        .title("Quit?")
+        .padding(ratatui::widgets::Padding::uniform(1));
+
+    let inner_area = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    let text = Paragraph::new(message)
+        .style(Style::default().fg(theme.text))
         .wrap(ratatui::widgets::Wrap { trim: false });
     f.render_widget(text, inner_area);
 }
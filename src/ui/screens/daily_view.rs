@@ -1,17 +1,32 @@
 use chrono::{Datelike, NaiveDate};
 use ratatui::{
     Frame,
-    layout::{Constraint, Direction, Layout},
-    style::{Color, Style},
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    widgets::{Block, Borders, Clear, Gauge, List, ListItem, ListState, Paragraph},
 };
 
-use crate::miles_stats::{calculate_monthly_miles, calculate_yearly_miles};
-use crate::models::field_accessor::FieldType;
-use crate::models::{AppState, DailyLog, FocusedSection, MeasurementField, RunningField};
-use crate::ui::components::{create_highlight_style, render_help, render_title};
+use crate::models::field_accessor::{
+    FieldType, format_duration, format_hours, format_time_of_day,
+};
+use crate::injuries;
+use crate::on_this_day::{describe, on_this_day};
+use crate::phases::phase_on;
+use crate::stats_cache::Period;
+use crate::training_load::acute_chronic_ratio;
+use crate::models::{
+    AppState, DailyLog, FocusedSection, MeasurementField, Reflection, RunningField,
+};
+use crate::ui::components::{
+    create_highlight_style, focus_title, render_help, render_title, rolling_totals_text,
+    selection_symbol,
+};
+use crate::ui::theme::Theme;
 use crate::ui::{ClickAction, ClickTarget};
+use crate::gear::{GearMileage, GearStatus};
+use crate::format::Units;
+use crate::units::Quantity;
 
 /// Active in-place edit of a numeric field, rendered directly inside its section
 /// row (Measurements / Running) instead of in a popup modal.
@@ -19,8 +34,13 @@ pub struct InPlaceEdit<'a> {
     pub field: FieldType,
     pub buffer: &'a str,
     pub cursor: usize,
+    /// Highlighted entry of the recent-values dropdown, if one is picked.
+    pub history_selected: Option<usize>,
 }
 
+/// Most earlier years the on-this-day panel lists.
+const FLASHBACK_YEARS: usize = 3;
+
 /// Renders the daily view screen for a specific date
 pub fn render_daily_view_screen(
     f: &mut Frame,
@@ -32,13 +52,21 @@ pub fn render_daily_view_screen(
     click_targets: Option<&mut Vec<ClickTarget>>,
 ) {
     let mut click_targets = click_targets;
+    let flashback = on_this_day(state.daily_logs(), state.selected_date);
+    let flashback_height = match flashback.len() {
+        0 => 0,
+        years => years.min(FLASHBACK_YEARS) as u16 + 2,
+    };
+    let computed_height = if state.computed.is_empty() { 0 } else { 3 };
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .margin(1)
         .constraints([
             Constraint::Length(5), // Title (increased for vertical padding)
-            Constraint::Length(3), // Measurements (Weight, Waist)
-            Constraint::Length(3), // Running (Miles, Elevation)
+            Constraint::Length(4), // Measurements (Weight, Waist; Resting HR, HRV, Energy)
+            Constraint::Length(4), // Running (Miles, Elevation; rolling totals)
+            Constraint::Length(flashback_height), // On this day, when there is one
+            Constraint::Length(computed_height), // Computed fields, when configured
             Constraint::Min(4),    // Food list (scrollable)
             Constraint::Min(4),    // Sokay list (scrollable, same size as food)
             Constraint::Length(4), // Strength & Mobility section
@@ -47,81 +75,144 @@ pub fn render_daily_view_screen(
         ])
         .split(f.area());
 
+    let phase = phase_on(&state.phases, state.selected_date)
+        .map(|phase| format!(" - {} Phase", phase.kind.title()))
+        .unwrap_or_default();
     let title = format!(
-        "Mountains Training Log - {} {}",
-        state.selected_date.format("%B %d, %Y"),
+        "Mountains Training Log - {}{} {}",
+        state.units.date(state.selected_date),
+        phase,
         sync_status
     );
-    render_title(f, chunks[0], &title);
+    render_title(f, chunks[0], &title, &state.theme);
+    render_ramp_warning(f, chunks[0], state);
+    render_niggles(f, chunks[0], state);
+
+    let log = state.get_daily_log(state.selected_date);
 
     render_measurements_section(
         f,
         chunks[1],
         state.selected_date,
-        &state.daily_logs,
+        state.daily_logs(),
+        log,
         &state.focused_section,
         edit.as_ref(),
+        state.units,
+        &state.theme,
+        state.screen_reader,
         click_targets.as_deref_mut(),
     );
 
     let today = chrono::Local::now().date_naive();
-    let yearly_miles = calculate_yearly_miles(&state.daily_logs, today);
-    let monthly_miles = calculate_monthly_miles(&state.daily_logs, today);
+    let yearly_miles = state.stats.miles(state.daily_logs(), Period::year_of(today));
+    let monthly_miles = state.stats.miles(state.daily_logs(), Period::month_of(today));
+    let gear = log.and_then(|l| l.gear.as_deref()).map(|name| {
+        let worn = GearMileage {
+            name: name.to_string(),
+            retired: state.gear.iter().any(|g| g.name == name && g.retired),
+            miles: crate::gear::miles_on(name, state.daily_logs()),
+            days: 0,
+            last_used: None,
+        };
+        let status = worn.status(state.gear_retire_after);
+        (worn, status)
+    });
+    // The day's plan, if one was imported, leads the rolling totals line
+    let mut rolling = rolling_totals_text(state, state.selected_date);
+    if let Some(planned) = state.plan.get(&state.selected_date) {
+        rolling = format!("Planned: {} | {}", planned.describe(state.units), rolling);
+    }
     render_running_section(
         f,
         chunks[2],
-        state.selected_date,
-        &state.daily_logs,
+        log,
         &state.focused_section,
         yearly_miles,
         monthly_miles,
+        &rolling,
+        gear.as_ref(),
         edit.as_ref(),
+        state.units,
+        &state.theme,
+        state.screen_reader,
         click_targets.as_deref_mut(),
     );
 
+    if !flashback.is_empty() {
+        render_flashback_section(f, chunks[3], &flashback, state);
+    }
+    if !state.computed.is_empty() {
+        render_computed_section(f, chunks[4], state);
+    }
+
+    // The calorie gauge takes the top of the food section once there's a
+    // target and the day's food has calories
+    let food_area = match (state.calorie_target, log.and_then(DailyLog::calories)) {
+        (Some(target), Some(calories)) if target > 0 => {
+            let areas = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Length(3), Constraint::Min(4)])
+                .split(chunks[5]);
+            render_calorie_gauge(f, areas[0], calories, target, state.units, &state.theme);
+            areas[1]
+        }
+        _ => chunks[5],
+    };
+
     render_food_list_section(
         f,
-        chunks[3],
-        state.selected_date,
-        &state.daily_logs,
+        food_area,
+        log,
         food_list_state,
         &state.focused_section,
         state.food_list_focused,
+        state.units,
+        &state.theme,
+        state.screen_reader,
         click_targets.as_deref_mut(),
     );
 
     render_sokay_section(
         f,
-        chunks[4],
+        chunks[6],
         state.selected_date,
-        &state.daily_logs,
+        state.daily_logs(),
+        log,
         sokay_list_state,
         &state.focused_section,
         state.sokay_list_focused,
+        &state.theme,
+        state.screen_reader,
         click_targets.as_deref_mut(),
     );
 
+    let strength_text = log.and_then(|log| log.strength_text(state.units));
     render_strength_mobility_section(
         f,
-        chunks[5],
-        state.selected_date,
-        &state.daily_logs,
+        chunks[7],
+        strength_text.as_deref(),
         &state.focused_section,
+        &state.theme,
+        state.screen_reader,
         click_targets.as_deref_mut(),
     );
 
     render_notes_section(
         f,
-        chunks[6],
-        state.selected_date,
-        &state.daily_logs,
+        chunks[8],
+        log,
         &state.focused_section,
+        state.notes_word_goal,
+        &state.theme,
+        state.screen_reader,
         click_targets.as_deref_mut(),
     );
 
     let help_tiers: &[&str] = if edit.is_some() {
         &[
-            " Editing — type value | Enter: Save | Esc: Cancel",
+            " Editing — type value | Up/Down: ±step (Shift: ×10) | Alt+Up/Down: Recent | Enter: Save | Esc: Cancel",
+            " Up/Down: Adjust | Enter: Save | Esc: Cancel",
             " Enter: Save | Esc: Cancel",
         ]
     } else {
@@ -132,45 +223,134 @@ pub fn render_daily_view_screen(
             " Space: Shortcuts | Esc: Back",
         ]
     };
-    render_help(f, chunks[7], help_tiers, true, false);
+    render_help(f, chunks[9], help_tiers, true, false, &state.theme);
 
     // Render expanded overlay for multi-line sections when focused
     match &state.focused_section {
         FocusedSection::StrengthMobility => {
             render_strength_mobility_expanded(
                 f,
-                chunks[5],
-                state.selected_date,
-                &state.daily_logs,
+                chunks[7],
+                strength_text.as_deref(),
                 state.strength_mobility_scroll,
+                &state.theme,
+                state.screen_reader,
                 click_targets.as_deref_mut(),
             );
         }
         FocusedSection::Notes => {
             render_notes_expanded(
                 f,
-                chunks[6],
-                state.selected_date,
-                &state.daily_logs,
+                chunks[8],
+                log,
                 state.notes_scroll,
-                click_targets.as_deref_mut(),
+                state.notes_word_goal,
+                &state.theme,
+                state.screen_reader,
+                click_targets,
             );
         }
         _ => {}
     }
 }
 
+/// Renders the on-this-day panel: a line for each earlier year that has a
+/// log on this date, newest first.
+fn render_flashback_section(
+    f: &mut Frame,
+    area: ratatui::layout::Rect,
+    flashback: &[&DailyLog],
+    state: &AppState,
+) {
+    let theme = &state.theme;
+    let lines: Vec<Line> = flashback
+        .iter()
+        .take(FLASHBACK_YEARS)
+        .map(|log| {
+            Line::from(Span::styled(
+                describe(log, state.selected_date, state.units),
+                Style::default().fg(theme.text),
+            ))
+        })
+        .collect();
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.dim))
+        .title("On This Day")
+        .padding(ratatui::widgets::Padding::horizontal(1));
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Renders the Computed section: each `[[computed.fields]]` formula worked
+/// out for the selected day.
+fn render_computed_section(f: &mut Frame, area: ratatui::layout::Rect, state: &AppState) {
+    let theme = &state.theme;
+    let mut spans = Vec::new();
+    let values = state.computed.values(state.daily_logs(), state.selected_date);
+    for (index, (name, value)) in values.into_iter().enumerate() {
+        if index > 0 {
+            spans.push(Span::styled(" | ", Style::default().fg(theme.dim)));
+        }
+        spans.push(Span::styled(format!("{}: ", name), Style::default().fg(theme.label)));
+        spans.push(Span::styled(value, Style::default().fg(theme.text)));
+    }
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.dim))
+        .title("Computed")
+        .padding(ratatui::widgets::Padding::horizontal(1));
+    f.render_widget(Paragraph::new(Line::from(spans)).block(block), area);
+}
+
+/// Renders calories eaten against `[food] calorie_target`, turning red once
+/// the target is passed.
+fn render_calorie_gauge(
+    f: &mut Frame,
+    area: ratatui::layout::Rect,
+    calories: u32,
+    target: u32,
+    units: Units,
+    theme: &Theme,
+) {
+    let color = if calories > target {
+        theme.error
+    } else {
+        theme.sections.food
+    };
+    let gauge = Gauge::default()
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.dim))
+                .title("Calories"),
+        )
+        .gauge_style(Style::default().fg(color))
+        .ratio((calories as f64 / target as f64).min(1.0))
+        .label(format!(
+            "{} / {} cal",
+            units.count(calories as i64),
+            units.count(target as i64)
+        ))
+        .use_unicode(true);
+    f.render_widget(gauge, area);
+}
+
 /// Renders the measurements display section
+#[allow(clippy::too_many_arguments)]
 fn render_measurements_section(
     f: &mut Frame,
     area: ratatui::layout::Rect,
     selected_date: NaiveDate,
     daily_logs: &[DailyLog],
+    log: Option<&DailyLog>,
     focused_section: &FocusedSection,
     edit: Option<&InPlaceEdit>,
+    units: Units,
+    theme: &Theme,
+    screen_reader: bool,
     click_targets: Option<&mut Vec<ClickTarget>>,
 ) {
-    let log = daily_logs.iter().find(|log| log.date == selected_date);
+    let accent = theme.sections.measurements;
 
     // A field in this section being actively edited in place (Weight or Waist).
     let editing_field = match edit.map(|e| e.field) {
@@ -190,10 +370,17 @@ fn render_measurements_section(
             _ => None,
         });
 
-    let weight_value = log.and_then(|l| l.weight).map(|w| format!("{} lbs", w));
-    let waist_value = log.and_then(|l| l.waist).map(|w| format!("{} in", w));
-
-    let base = Style::default().fg(Color::Yellow);
+    let weight_value = log
+        .and_then(|l| l.weight)
+        .map(|w| units.format(Quantity::Weight, w));
+    let waist_value = log
+        .and_then(|l| l.waist)
+        .map(|w| units.format(Quantity::Waist, w));
+    let weight_unit = format!(" {}", units.label(Quantity::Weight));
+    let waist_unit = format!(" {}", units.label(Quantity::Waist));
+
+    let base = Style::default().fg(accent);
+    let placeholder = Style::default().fg(theme.dim);
     let mut spans: Vec<Span> = Vec::new();
     let mut width: u16 = 0;
     let mut caret_col: Option<u16> = None;
@@ -203,6 +390,7 @@ fn render_measurements_section(
         &mut caret_col,
         &mut width,
         base,
+        placeholder,
         marked_field.as_ref() == Some(&MeasurementField::Weight),
         "Weight: ",
         if editing_field == Some(MeasurementField::Weight) {
@@ -211,7 +399,7 @@ fn render_measurements_section(
             None
         },
         weight_value.as_deref(),
-        " lbs",
+        &weight_unit,
         "Press 'w' to add",
     );
     push_span(&mut spans, &mut width, " | ".to_string(), base);
@@ -220,6 +408,7 @@ fn render_measurements_section(
         &mut caret_col,
         &mut width,
         base,
+        placeholder,
         marked_field.as_ref() == Some(&MeasurementField::Waist),
         "Waist Size: ",
         if editing_field == Some(MeasurementField::Waist) {
@@ -228,40 +417,185 @@ fn render_measurements_section(
             None
         },
         waist_value.as_deref(),
-        " in",
+        &waist_unit,
         "Press 's' to add",
     );
+    // Sleep is typed into its own modal, so it's only ever marked here
+    push_span(&mut spans, &mut width, " | ".to_string(), base);
+    let sleep_value = log.and_then(|l| l.sleep_hours).map(format_hours);
+    let sleep_region = push_field(
+        &mut spans,
+        &mut caret_col,
+        &mut width,
+        base,
+        placeholder,
+        marked_field.as_ref() == Some(&MeasurementField::Sleep),
+        "Sleep: ",
+        None,
+        sleep_value.as_deref(),
+        " h",
+        "Press 'z' to add",
+    );
+    // Water isn't typed in, so it's never focused or edited in place
+    push_span(&mut spans, &mut width, " | Water: ".to_string(), base);
+    match log.and_then(|l| l.water) {
+        Some(water) => push_span(
+            &mut spans,
+            &mut width,
+            units.format(Quantity::Water, water),
+            base,
+        ),
+        None => push_span(
+            &mut spans,
+            &mut width,
+            "Press '+' to add".to_string(),
+            placeholder,
+        ),
+    }
+
+    // The morning check-in numbers get a line of their own
+    let mut heart_spans: Vec<Span> = Vec::new();
+    let mut heart_width: u16 = 0;
+    let resting_hr = log.and_then(|l| l.resting_hr).map(|bpm| bpm.to_string());
+    let resting_hr_region = push_field(
+        &mut heart_spans,
+        &mut caret_col,
+        &mut heart_width,
+        base,
+        placeholder,
+        marked_field.as_ref() == Some(&MeasurementField::RestingHr),
+        "Resting HR: ",
+        None,
+        resting_hr.as_deref(),
+        " bpm",
+        "Press 'r' to add",
+    );
+    push_span(&mut heart_spans, &mut heart_width, " | ".to_string(), base);
+    let hrv = log.and_then(|l| l.hrv).map(|ms| ms.to_string());
+    let hrv_region = push_field(
+        &mut heart_spans,
+        &mut caret_col,
+        &mut heart_width,
+        base,
+        placeholder,
+        marked_field.as_ref() == Some(&MeasurementField::Hrv),
+        "HRV: ",
+        None,
+        hrv.as_deref(),
+        " ms",
+        "Press 'v' to add",
+    );
+    // Energy is rated with a single keypress, so like water it's never edited
+    push_span(&mut heart_spans, &mut heart_width, " | Energy: ".to_string(), base);
+    match log.and_then(|l| l.energy) {
+        Some(energy) => push_span(
+            &mut heart_spans,
+            &mut heart_width,
+            format!("{energy}/5"),
+            base,
+        ),
+        None => push_span(
+            &mut heart_spans,
+            &mut heart_width,
+            "Press 1-5".to_string(),
+            placeholder,
+        ),
+    }
 
     let border_style = if has_focus {
-        Style::default().fg(Color::Yellow)
+        Style::default().fg(accent)
     } else {
-        Style::default().fg(Color::DarkGray)
+        Style::default().fg(theme.dim)
     };
 
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(border_style)
-        .title("Measurements")
+        .title(focus_title("Measurements", has_focus, screen_reader))
         .padding(ratatui::widgets::Padding::horizontal(1));
     let inner = block.inner(area);
 
-    let measurements_widget = Paragraph::new(Line::from(spans)).block(block);
+    let measurements_widget =
+        Paragraph::new(vec![Line::from(spans), Line::from(heart_spans)]).block(block);
     f.render_widget(measurements_widget, area);
 
     if let Some(click_targets) = click_targets {
-        push_field_target(click_targets, inner, weight_region, FieldType::Weight);
-        push_field_target(click_targets, inner, waist_region, FieldType::Waist);
+        push_field_target(click_targets, inner, 0, weight_region, FieldType::Weight);
+        push_field_target(click_targets, inner, 0, waist_region, FieldType::Waist);
+        push_field_target(click_targets, inner, 0, sleep_region, FieldType::Sleep);
+        push_field_target(click_targets, inner, 1, resting_hr_region, FieldType::RestingHr);
+        push_field_target(click_targets, inner, 1, hrv_region, FieldType::Hrv);
     }
 
     if let Some(col) = caret_col {
         f.set_cursor_position((inner.x + col, inner.y));
     }
+
+    if let Some(edit) = edit.filter(|edit| edit.field.shows_history()) {
+        let (start, _) = if edit.field == FieldType::Weight {
+            weight_region
+        } else {
+            waist_region
+        };
+        let history = edit.field.history(daily_logs, selected_date);
+        let quantity = edit.field.quantity().unwrap_or(Quantity::Weight);
+        let history: Vec<(NaiveDate, String)> = history
+            .into_iter()
+            .map(|(date, value)| (date, units.amount(quantity, value)))
+            .collect();
+        render_history_dropdown(
+            f,
+            area,
+            inner.x + start,
+            &history,
+            edit.history_selected,
+            units,
+            theme,
+        );
+    }
 }
 
-/// Dimmed style for inline "Press 'x' to add" placeholders shown when a numeric
-/// field is unset, matching the dimmed placeholders used by the list sections.
-fn placeholder_style() -> Style {
-    Style::default().fg(Color::DarkGray)
+/// Recent values listed just below the section row being edited, so a new
+/// entry can be eyeballed against (or copied from) the last few days.
+fn render_history_dropdown(
+    f: &mut Frame,
+    section: ratatui::layout::Rect,
+    x: u16,
+    history: &[(NaiveDate, String)],
+    selected: Option<usize>,
+    units: Units,
+    theme: &Theme,
+) {
+    if history.is_empty() {
+        return;
+    }
+
+    let screen = f.area();
+    let y = section.y + section.height;
+    let width = 24.min(screen.right().saturating_sub(x));
+    let height = (history.len() as u16 + 2).min(screen.bottom().saturating_sub(y));
+    if width < 4 || height < 3 {
+        return;
+    }
+    let area = ratatui::layout::Rect::new(x, y, width, height);
+
+    let items: Vec<ListItem> = history
+        .iter()
+        .map(|(date, value)| ListItem::new(format!("{}  {}", units.month_day(*date), value)))
+        .collect();
+    let mut list_state = ListState::default();
+    list_state.select(selected);
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.dim))
+                .title("Recent (Alt+↑/↓)"),
+        )
+        .highlight_style(create_highlight_style());
+
+    f.render_widget(Clear, area);
+    f.render_stateful_widget(list, area, &mut list_state);
 }
 
 /// Pushes a styled span and advances the running display width (in cells) used
@@ -274,13 +608,16 @@ fn push_span(spans: &mut Vec<Span<'static>>, width: &mut u16, text: String, styl
 /// Appends one labelled field to a section row, recording the caret column when
 /// the field is being edited in place. `marked` adds the ► focus marker; `edit`
 /// (when `Some`) substitutes the input buffer for the value and sets the caret.
-/// When `value` is `None` and the field isn't being edited, the dimmed `help`
-/// placeholder is shown in place of the value.
+/// When `value` is `None` and the field isn't being edited, the `help`
+/// placeholder is shown dimmed in place of the value, matching the list
+/// sections' placeholders.
+#[allow(clippy::too_many_arguments)]
 fn push_field(
     spans: &mut Vec<Span<'static>>,
     caret_col: &mut Option<u16>,
     width: &mut u16,
     base_style: Style,
+    placeholder_style: Style,
     marked: bool,
     label: &str,
     edit: Option<&InPlaceEdit>,
@@ -303,38 +640,118 @@ fn push_field(
     } else if let Some(value) = value {
         push_span(spans, width, value.to_string(), base_style);
     } else {
-        push_span(spans, width, help.to_string(), placeholder_style());
+        push_span(spans, width, help.to_string(), placeholder_style);
     }
     (start, width.saturating_sub(start))
 }
 
+/// Makes a field's span on line `row` of the section clickable.
 fn push_field_target(
     click_targets: &mut Vec<ClickTarget>,
     inner: ratatui::layout::Rect,
+    row: u16,
     (start, width): (u16, u16),
     field: FieldType,
 ) {
-    let area =
-        ratatui::layout::Rect::new(inner.x.saturating_add(start), inner.y, width, inner.height)
-            .intersection(inner);
+    let area = ratatui::layout::Rect::new(
+        inner.x.saturating_add(start),
+        inner.y.saturating_add(row),
+        width,
+        1,
+    )
+    .intersection(inner);
     if area.width > 0 && area.height > 0 {
         click_targets.push(ClickTarget::new(area, ClickAction::EditField(field)));
     }
 }
 
+/// Warns along the bottom of the title box when the last 7 days' distance
+/// is well over the 28-day weekly average, as of the selected day.
+/// Names the injuries hurting on the selected day along the title box's
+/// bottom border, like " Niggles: Left knee (moderate, day 12) ".
+fn render_niggles(f: &mut Frame, title_area: Rect, state: &AppState) {
+    let active = injuries::active_on(&state.injuries, state.selected_date);
+    if active.is_empty() || title_area.height < 5 {
+        return;
+    }
+    let names: Vec<String> = active
+        .iter()
+        .map(|injury| {
+            format!(
+                "{} ({}, day {})",
+                injury.body_part,
+                injury.severity.key(),
+                injury.days(state.selected_date)
+            )
+        })
+        .collect();
+    let area = Rect {
+        x: title_area.x + 2,
+        y: title_area.y + 4,
+        width: title_area.width.saturating_sub(4),
+        height: 1,
+    };
+    let text = format!(" Niggles: {} ", names.join(", "));
+    let width = (text.chars().count() as u16).min(area.width);
+    f.render_widget(
+        Paragraph::new(Line::from(Span::styled(
+            text,
+            Style::default().fg(state.theme.error),
+        ))),
+        Rect { width, ..area },
+    );
+}
+
+fn render_ramp_warning(f: &mut Frame, title_area: Rect, state: &AppState) {
+    let threshold = state.stats.config().ramp_warning;
+    if threshold <= 0.0 || title_area.height < 5 {
+        return;
+    }
+    let Some(ratio) = acute_chronic_ratio(&state.stats, state.daily_logs(), state.selected_date)
+    else {
+        return;
+    };
+    if ratio < threshold {
+        return;
+    }
+    let area = Rect {
+        x: title_area.x + 2,
+        y: title_area.y + 3,
+        width: title_area.width.saturating_sub(4),
+        height: 1,
+    };
+    f.render_widget(
+        Paragraph::new(Line::from(Span::styled(
+            format!(
+                "⚠ Ramping up fast: the last 7 days are {}x your 4-week average",
+                state.units.decimal(ratio as f64, 1)
+            ),
+            Style::default()
+                .fg(state.theme.warning)
+                .add_modifier(Modifier::BOLD),
+        ))),
+        area,
+    );
+}
+
 /// Renders the running activity display section
+#[allow(clippy::too_many_arguments)]
 fn render_running_section(
     f: &mut Frame,
     area: ratatui::layout::Rect,
-    selected_date: NaiveDate,
-    daily_logs: &[DailyLog],
+    log: Option<&DailyLog>,
     focused_section: &FocusedSection,
     yearly_miles: f32,
     monthly_miles: f32,
+    rolling: &str,
+    gear: Option<&(GearMileage, GearStatus)>,
     edit: Option<&InPlaceEdit>,
+    units: Units,
+    theme: &Theme,
+    screen_reader: bool,
     click_targets: Option<&mut Vec<ClickTarget>>,
 ) {
-    let log = daily_logs.iter().find(|log| log.date == selected_date);
+    let accent = theme.sections.running;
 
     let editing_field = match edit.map(|e| e.field) {
         Some(FieldType::Miles) => Some(RunningField::Miles),
@@ -371,27 +788,36 @@ fn render_running_section(
         _ => "Unknown",
     };
 
+    let distance = units.name(Quantity::Distance);
     let yearly_text = format!(
-        "You have {:.1} miles covered for {}",
-        yearly_miles, current_year
+        "You have {} {} covered for {}",
+        units.number(Quantity::Distance, yearly_miles as f64),
+        distance,
+        current_year
     );
     let monthly_text = if monthly_miles == 0.0 {
-        format!("No miles covered yet for the month of {}", month_name)
+        format!("No {} covered yet for the month of {}", distance, month_name)
     } else {
         format!(
-            "{:.1} miles covered for the month of {}",
-            monthly_miles, month_name
+            "{} {} covered for the month of {}",
+            units.number(Quantity::Distance, monthly_miles as f64),
+            distance,
+            month_name
         )
     };
 
     let miles_value = log
         .and_then(|l| l.miles_covered)
-        .map(|m| format!("{} mi", m));
+        .map(|m| units.format(Quantity::Distance, m));
     let elevation_value = log
         .and_then(|l| l.elevation_gain)
-        .map(|e| format!("{} ft", e));
+        .map(|e| units.format(Quantity::Elevation, e as f32));
+    let miles_label = format!("{}: ", units.distance_title());
+    let miles_unit = format!(" {}", units.label(Quantity::Distance));
+    let elevation_unit = format!(" {}", units.label(Quantity::Elevation));
 
-    let base = Style::default().fg(Color::LightRed);
+    let base = Style::default().fg(accent);
+    let placeholder = Style::default().fg(theme.dim);
     let mut spans: Vec<Span> = Vec::new();
     let mut width: u16 = 0;
     let mut caret_col: Option<u16> = None;
@@ -401,23 +827,33 @@ fn render_running_section(
         &mut caret_col,
         &mut width,
         base,
+        placeholder,
         marked_field.as_ref() == Some(&RunningField::Miles),
-        "Miles: ",
+        &miles_label,
         if editing_field == Some(RunningField::Miles) {
             edit
         } else {
             None
         },
         miles_value.as_deref(),
-        " mi",
+        &miles_unit,
         "Press 'm' to add",
     );
+    // Pace follows from the miles and the run time, so it's shown, not typed
+    if editing_field != Some(RunningField::Miles)
+        && let Some(pace) = log
+            .and_then(|l| l.duration_secs.zip(l.miles_covered))
+            .and_then(|(seconds, miles)| units.pace(seconds, miles))
+    {
+        push_span(&mut spans, &mut width, format!(" ({})", pace), base);
+    }
     push_span(&mut spans, &mut width, " | ".to_string(), base);
     let elevation_region = push_field(
         &mut spans,
         &mut caret_col,
         &mut width,
         base,
+        placeholder,
         marked_field.as_ref() == Some(&RunningField::Elevation),
         "Elevation: ",
         if editing_field == Some(RunningField::Elevation) {
@@ -426,9 +862,86 @@ fn render_running_section(
             None
         },
         elevation_value.as_deref(),
-        " ft",
+        &elevation_unit,
         "Press 'l' to add",
     );
+    // Run time, RPE and heart rate are typed into their own modals, so like sleep
+    // they're only ever marked
+    push_span(&mut spans, &mut width, " | ".to_string(), base);
+    let duration_value = log.and_then(|l| l.duration_secs).map(format_duration);
+    let duration_region = push_field(
+        &mut spans,
+        &mut caret_col,
+        &mut width,
+        base,
+        placeholder,
+        marked_field.as_ref() == Some(&RunningField::Duration),
+        "Time: ",
+        None,
+        duration_value.as_deref(),
+        "",
+        "Press 'T' to add",
+    );
+    push_span(&mut spans, &mut width, " | ".to_string(), base);
+    let start_value = log.and_then(|l| l.start_time).map(format_time_of_day);
+    let start_region = push_field(
+        &mut spans,
+        &mut caret_col,
+        &mut width,
+        base,
+        placeholder,
+        marked_field.as_ref() == Some(&RunningField::StartTime),
+        "Start: ",
+        None,
+        start_value.as_deref(),
+        "",
+        "Press 'B' to add",
+    );
+    push_span(&mut spans, &mut width, " | ".to_string(), base);
+    let rpe_value = log.and_then(|l| l.rpe).map(|rpe| rpe.to_string());
+    let rpe_region = push_field(
+        &mut spans,
+        &mut caret_col,
+        &mut width,
+        base,
+        placeholder,
+        marked_field.as_ref() == Some(&RunningField::Rpe),
+        "RPE: ",
+        None,
+        rpe_value.as_deref(),
+        "/10",
+        "Press 'E' to add",
+    );
+    push_span(&mut spans, &mut width, " | ".to_string(), base);
+    let avg_hr_value = log.and_then(|l| l.avg_hr).map(|bpm| bpm.to_string());
+    let avg_hr_region = push_field(
+        &mut spans,
+        &mut caret_col,
+        &mut width,
+        base,
+        placeholder,
+        marked_field.as_ref() == Some(&RunningField::AvgHr),
+        "Avg HR: ",
+        None,
+        avg_hr_value.as_deref(),
+        " bpm",
+        "Press 'H' to add",
+    );
+    push_span(&mut spans, &mut width, " | ".to_string(), base);
+    let max_hr_value = log.and_then(|l| l.max_hr).map(|bpm| bpm.to_string());
+    let max_hr_region = push_field(
+        &mut spans,
+        &mut caret_col,
+        &mut width,
+        base,
+        placeholder,
+        marked_field.as_ref() == Some(&RunningField::MaxHr),
+        "Max HR: ",
+        None,
+        max_hr_value.as_deref(),
+        " bpm",
+        "Press 'X' to add",
+    );
     push_span(
         &mut spans,
         &mut width,
@@ -437,24 +950,93 @@ fn render_running_section(
     );
 
     let border_style = if has_focus {
-        Style::default().fg(Color::LightRed)
+        Style::default().fg(accent)
     } else {
-        Style::default().fg(Color::DarkGray)
+        Style::default().fg(theme.dim)
     };
 
-    let block = Block::default()
+    let mut block = Block::default()
         .borders(Borders::ALL)
         .border_style(border_style)
-        .title("Running")
+        .title(focus_title("Running", has_focus, screen_reader))
         .padding(ratatui::widgets::Padding::horizontal(1));
+    if log.is_some_and(|l| l.indoor) {
+        block = block.title_top(Line::from(Span::styled(
+            " Indoor ",
+            Style::default().fg(theme.dim),
+        )));
+    }
+    if log.is_some_and(|l| l.rest_day) {
+        block = block.title_top(Line::from(Span::styled(
+            " Rest Day ",
+            Style::default().fg(theme.good).add_modifier(Modifier::ITALIC),
+        )));
+    }
+    // The activity type rides on the top border, in its own color
+    if let Some(kind) = log.and_then(|l| l.activity) {
+        block = block.title_top(
+            Line::from(Span::styled(
+                format!(" {} ", kind.label()),
+                Style::default()
+                    .fg(theme.on_border)
+                    .bg(theme.activity(kind)),
+            ))
+            .right_aligned(),
+        );
+    }
+    // The day's weather, once it's been fetched, sits in the middle
+    if let Some(weather) = log.and_then(|l| l.weather) {
+        block = block.title_top(
+            Line::from(Span::styled(
+                format!(" {} ", weather.describe(units)),
+                Style::default().fg(theme.dim),
+            ))
+            .centered(),
+        );
+    }
+    // The route sits bottom left, opposite the gear
+    if let Some(route) = log.and_then(|l| l.route.as_deref()) {
+        block = block.title_bottom(Line::from(Span::styled(
+            format!(" {} ", route),
+            Style::default().fg(accent),
+        )));
+    }
+    // And the gear it was run in, warning once that gear is nearly done
+    if let Some((worn, status)) = gear {
+        let color = match status {
+            GearStatus::Fine => accent,
+            GearStatus::RetireSoon => theme.warning,
+            GearStatus::Worn => theme.error,
+        };
+        block = block.title_bottom(
+            Line::from(Span::styled(
+                format!(
+                    " {} · {} ",
+                    worn.name,
+                    units.total(Quantity::Distance, worn.miles as f64)
+                ),
+                Style::default().fg(color),
+            ))
+            .right_aligned(),
+        );
+    }
     let inner = block.inner(area);
 
-    let running_widget = Paragraph::new(Line::from(spans)).block(block);
+    let running_widget = Paragraph::new(vec![
+        Line::from(spans),
+        Line::from(Span::styled(rolling.to_string(), Style::default().fg(theme.dim))),
+    ])
+    .block(block);
     f.render_widget(running_widget, area);
 
     if let Some(click_targets) = click_targets {
-        push_field_target(click_targets, inner, miles_region, FieldType::Miles);
-        push_field_target(click_targets, inner, elevation_region, FieldType::Elevation);
+        push_field_target(click_targets, inner, 0, miles_region, FieldType::Miles);
+        push_field_target(click_targets, inner, 0, elevation_region, FieldType::Elevation);
+        push_field_target(click_targets, inner, 0, duration_region, FieldType::Duration);
+        push_field_target(click_targets, inner, 0, start_region, FieldType::StartTime);
+        push_field_target(click_targets, inner, 0, rpe_region, FieldType::Rpe);
+        push_field_target(click_targets, inner, 0, avg_hr_region, FieldType::AvgHr);
+        push_field_target(click_targets, inner, 0, max_hr_region, FieldType::MaxHr);
     }
 
     if let Some(col) = caret_col {
@@ -463,17 +1045,20 @@ fn render_running_section(
 }
 
 /// Renders the food items list section
+#[allow(clippy::too_many_arguments)]
 fn render_food_list_section(
     f: &mut Frame,
     area: ratatui::layout::Rect,
-    selected_date: NaiveDate,
-    daily_logs: &[DailyLog],
+    log: Option<&DailyLog>,
     food_list_state: &mut ListState,
     focused_section: &FocusedSection,
     food_list_focused: bool,
+    units: Units,
+    theme: &Theme,
+    screen_reader: bool,
     click_targets: Option<&mut Vec<ClickTarget>>,
 ) {
-    let log = daily_logs.iter().find(|log| log.date == selected_date);
+    let accent = theme.sections.food;
     let entry_count = log.map_or(0, |log| log.food_entries.len());
 
     let items: Vec<ListItem> = if let Some(log) = log {
@@ -483,7 +1068,7 @@ fn render_food_list_section(
             log.food_entries
                 .iter()
                 .map(|entry| {
-                    let display = format!("- {}", entry.name);
+                    let display = format!("- {}", entry.label());
                     ListItem::new(display)
                 })
                 .collect()
@@ -492,28 +1077,30 @@ fn render_food_list_section(
         vec![ListItem::new("No food entries yet. Press 'f' to add one.")]
     };
 
-    let border_style = if matches!(focused_section, FocusedSection::FoodItems) {
-        Style::default().fg(Color::Yellow)
+    let focused = matches!(focused_section, FocusedSection::FoodItems);
+    let border_style = if focused {
+        Style::default().fg(accent)
     } else {
-        Style::default().fg(Color::DarkGray)
+        Style::default().fg(theme.dim)
     };
 
-    let highlight_style =
-        if matches!(focused_section, FocusedSection::FoodItems) && food_list_focused {
-            create_highlight_style()
-        } else {
-            Style::default()
-        };
+    let selecting = focused && food_list_focused;
+    let highlight_style = if selecting {
+        create_highlight_style()
+    } else {
+        Style::default()
+    };
 
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(border_style)
-        .title("Food Items")
+        .title(focus_title(&food_title(log, units), focused, screen_reader))
         .padding(ratatui::widgets::Padding::uniform(1));
     let inner = block.inner(area);
     let list = List::new(items)
         .block(block)
-        .highlight_style(highlight_style);
+        .highlight_style(highlight_style)
+        .highlight_symbol(selection_symbol(selecting && screen_reader));
     f.render_stateful_widget(list, area, food_list_state);
 
     if let Some(click_targets) = click_targets {
@@ -531,37 +1118,35 @@ fn render_food_list_section(
     }
 }
 
+/// "Food Items", with the day's calorie total once any food has calories.
+fn food_title(log: Option<&DailyLog>, units: Units) -> String {
+    match log.and_then(DailyLog::calories) {
+        Some(calories) => format!("Food Items - {} cal", units.count(calories as i64)),
+        None => "Food Items".to_string(),
+    }
+}
+
 /// Renders the sokay display section
+#[allow(clippy::too_many_arguments)]
 fn render_sokay_section(
     f: &mut Frame,
     area: ratatui::layout::Rect,
     selected_date: NaiveDate,
     daily_logs: &[DailyLog],
+    log: Option<&DailyLog>,
     sokay_list_state: &mut ListState,
     focused_section: &FocusedSection,
     sokay_list_focused: bool,
+    theme: &Theme,
+    screen_reader: bool,
     click_targets: Option<&mut Vec<ClickTarget>>,
 ) {
-    let log = daily_logs.iter().find(|log| log.date == selected_date);
+    let accent = theme.sections.sokay;
     let entry_count = log.map_or(0, |log| log.sokay_entries.len());
 
     // Calculate cumulative sokay count up to selected date
     let cumulative_sokay = crate::events::handlers::ActionHandler::calculate_cumulative_sokay(
-        &crate::models::AppState {
-            current_screen: crate::models::AppScreen::DailyView,
-            selected_date,
-            daily_logs: daily_logs.to_vec(),
-            focused_section: FocusedSection::FoodItems,
-            food_list_focused: false,
-            sokay_list_focused: false,
-            strength_mobility_scroll: 0,
-            notes_scroll: 0,
-            date_input_error: None,
-            config_sync_focused_field: crate::models::ConfigSyncField::DbUrl,
-            config_sync_status: None,
-            frame_width: 0,
-            frame_height: 0,
-        },
+        daily_logs,
         selected_date,
     );
 
@@ -583,14 +1168,15 @@ fn render_sokay_section(
         vec![ListItem::new("No sokay entries yet. Press 'c' to add one.")]
     };
 
-    let border_style = if matches!(focused_section, FocusedSection::Sokay) {
-        Style::default().fg(Color::Magenta)
+    let focused = matches!(focused_section, FocusedSection::Sokay);
+    let border_style = if focused {
+        Style::default().fg(accent)
     } else {
-        Style::default().fg(Color::DarkGray)
+        Style::default().fg(theme.dim)
     };
 
-    let highlight_style = if matches!(focused_section, FocusedSection::Sokay) && sokay_list_focused
-    {
+    let selecting = focused && sokay_list_focused;
+    let highlight_style = if selecting {
         create_highlight_style()
     } else {
         Style::default()
@@ -599,12 +1185,13 @@ fn render_sokay_section(
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(border_style)
-        .title(title)
+        .title(focus_title(&title, focused, screen_reader))
         .padding(ratatui::widgets::Padding::uniform(1));
     let inner = block.inner(area);
     let list = List::new(items)
         .block(block)
-        .highlight_style(highlight_style);
+        .highlight_style(highlight_style)
+        .highlight_symbol(selection_symbol(selecting && screen_reader));
     f.render_stateful_widget(list, area, sokay_list_state);
 
     if let Some(click_targets) = click_targets {
@@ -641,47 +1228,39 @@ fn push_visible_list_targets(
     }
 }
 
-/// Renders the strength & mobility display section
+/// Renders the strength & mobility display section: the day's sets, then
+/// the rest of the text
 fn render_strength_mobility_section(
     f: &mut Frame,
     area: ratatui::layout::Rect,
-    selected_date: NaiveDate,
-    daily_logs: &[DailyLog],
+    text: Option<&str>,
     focused_section: &FocusedSection,
+    theme: &Theme,
+    screen_reader: bool,
     click_targets: Option<&mut Vec<ClickTarget>>,
 ) {
-    let log = daily_logs.iter().find(|log| log.date == selected_date);
+    let accent = theme.sections.strength;
 
     let has_focus = matches!(focused_section, FocusedSection::StrengthMobility);
 
-    let sm_text = if let Some(log) = log {
-        if let Some(sm) = &log.strength_mobility {
-            if sm.trim().is_empty() {
-                "No exercises recorded yet. Press 't' to add training info.".to_string()
-            } else {
-                sm.clone()
-            }
-        } else {
-            "No exercises recorded yet. Press 't' to add training info.".to_string()
-        }
-    } else {
-        "No exercises recorded yet. Press 't' to add training info.".to_string()
-    };
+    let sm_text = text
+        .unwrap_or("No exercises recorded yet. Press 't' to add training info.")
+        .to_string();
 
     let border_style = if has_focus {
-        Style::default().fg(Color::Cyan)
+        Style::default().fg(accent)
     } else {
-        Style::default().fg(Color::DarkGray)
+        Style::default().fg(theme.dim)
     };
 
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(border_style)
-        .title("Strength & Mobility")
+        .title(focus_title("Strength & Mobility", has_focus, screen_reader))
         .padding(ratatui::widgets::Padding::horizontal(1));
     let inner = block.inner(area);
     let sm_widget = Paragraph::new(sm_text)
-        .style(Style::default().fg(Color::Cyan))
+        .style(Style::default().fg(accent))
         .block(block)
         .wrap(ratatui::widgets::Wrap { trim: false });
     f.render_widget(sm_widget, area);
@@ -691,15 +1270,18 @@ fn render_strength_mobility_section(
 }
 
 /// Renders the notes display section
+#[allow(clippy::too_many_arguments)]
 fn render_notes_section(
     f: &mut Frame,
     area: ratatui::layout::Rect,
-    selected_date: NaiveDate,
-    daily_logs: &[DailyLog],
+    log: Option<&DailyLog>,
     focused_section: &FocusedSection,
+    word_goal: Option<u32>,
+    theme: &Theme,
+    screen_reader: bool,
     click_targets: Option<&mut Vec<ClickTarget>>,
 ) {
-    let log = daily_logs.iter().find(|log| log.date == selected_date);
+    let accent = theme.sections.notes;
 
     let has_focus = matches!(focused_section, FocusedSection::Notes);
 
@@ -718,19 +1300,23 @@ fn render_notes_section(
     };
 
     let border_style = if has_focus {
-        Style::default().fg(Color::Green)
+        Style::default().fg(accent)
     } else {
-        Style::default().fg(Color::DarkGray)
+        Style::default().fg(theme.dim)
     };
 
-    let block = Block::default()
+    let mut block = Block::default()
         .borders(Borders::ALL)
         .border_style(border_style)
-        .title("Notes")
+        .title(focus_title("Notes", has_focus, screen_reader))
         .padding(ratatui::widgets::Padding::horizontal(1));
+    if let Some(progress) = word_goal_title(log, word_goal, theme) {
+        block = block.title_top(progress);
+    }
+    block = reflection_titles(block, log, theme);
     let inner = block.inner(area);
     let notes_widget = Paragraph::new(notes_text)
-        .style(Style::default().fg(Color::Green))
+        .style(Style::default().fg(accent))
         .block(block)
         .wrap(ratatui::widgets::Wrap { trim: false });
     f.render_widget(notes_widget, area);
@@ -739,6 +1325,53 @@ fn render_notes_section(
     }
 }
 
+/// " 142/200 words " on the Notes border, dim until the day's notes reach
+/// `[notes] word_goal`.
+fn word_goal_title(
+    log: Option<&DailyLog>,
+    word_goal: Option<u32>,
+    theme: &Theme,
+) -> Option<Line<'static>> {
+    let goal = word_goal?;
+    let words = log.map_or(0, DailyLog::note_words);
+    let color = if words >= goal as usize { theme.good } else { theme.dim };
+    Some(
+        Line::from(Span::styled(
+            format!(" {}/{} words ", words, goal),
+            Style::default().fg(color),
+        ))
+        .right_aligned(),
+    )
+}
+
+/// The day's intention along the bottom of the Notes border on the left,
+/// and what it was grateful for on the right.
+fn reflection_titles<'a>(
+    mut block: Block<'a>,
+    log: Option<&DailyLog>,
+    theme: &Theme,
+) -> Block<'a> {
+    let Some(log) = log else {
+        return block;
+    };
+    for reflection in [Reflection::Intention, Reflection::Gratitude] {
+        if let Some(text) = reflection.get(log) {
+            let line = Line::from(vec![
+                Span::styled(
+                    format!(" {}: ", reflection.label()),
+                    Style::default().fg(theme.label),
+                ),
+                Span::styled(format!("{} ", text), Style::default().fg(theme.text)),
+            ]);
+            block = block.title_bottom(match reflection {
+                Reflection::Intention => line,
+                Reflection::Gratitude => line.right_aligned(),
+            });
+        }
+    }
+    block
+}
+
 /// Calculates the number of display lines needed for text at given width
 fn calculate_text_height(text: &str, width: usize) -> usize {
     if text.is_empty() || width == 0 {
@@ -789,26 +1422,17 @@ pub fn max_scroll_offset(text: &str, frame_width: u16, frame_height: u16) -> u16
 fn render_strength_mobility_expanded(
     f: &mut Frame,
     original_area: ratatui::layout::Rect,
-    selected_date: NaiveDate,
-    daily_logs: &[DailyLog],
+    text: Option<&str>,
     scroll_offset: u16,
+    theme: &Theme,
+    screen_reader: bool,
     click_targets: Option<&mut Vec<ClickTarget>>,
 ) {
-    let log = daily_logs.iter().find(|log| log.date == selected_date);
+    let accent = theme.sections.strength;
 
-    let text = if let Some(log) = log {
-        if let Some(sm) = &log.strength_mobility {
-            if sm.trim().is_empty() {
-                "No exercises recorded yet. Press 't' to add training info.".to_string()
-            } else {
-                sm.clone()
-            }
-        } else {
-            "No exercises recorded yet. Press 't' to add training info.".to_string()
-        }
-    } else {
-        "No exercises recorded yet. Press 't' to add training info.".to_string()
-    };
+    let text = text
+        .unwrap_or("No exercises recorded yet. Press 't' to add training info.")
+        .to_string();
 
     let default_height = 4;
     let width = original_area.width.saturating_sub(4) as usize;
@@ -833,12 +1457,12 @@ fn render_strength_mobility_expanded(
 
     let block = Block::default()
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Cyan))
-        .title("Strength & Mobility")
+        .border_style(Style::default().fg(accent))
+        .title(focus_title("Strength & Mobility", true, screen_reader))
         .padding(ratatui::widgets::Padding::horizontal(1));
 
     let paragraph = Paragraph::new(text)
-        .style(Style::default().fg(Color::Cyan))
+        .style(Style::default().fg(accent))
         .block(block)
         .wrap(ratatui::widgets::Wrap { trim: false })
         .scroll((scroll_offset, 0));
@@ -853,15 +1477,18 @@ fn render_strength_mobility_expanded(
 }
 
 /// Renders expanded Notes section when focused
+#[allow(clippy::too_many_arguments)]
 fn render_notes_expanded(
     f: &mut Frame,
     original_area: ratatui::layout::Rect,
-    selected_date: NaiveDate,
-    daily_logs: &[DailyLog],
+    log: Option<&DailyLog>,
     scroll_offset: u16,
+    word_goal: Option<u32>,
+    theme: &Theme,
+    screen_reader: bool,
     click_targets: Option<&mut Vec<ClickTarget>>,
 ) {
-    let log = daily_logs.iter().find(|log| log.date == selected_date);
+    let accent = theme.sections.notes;
 
     let text = if let Some(log) = log {
         if let Some(notes) = &log.notes {
@@ -898,14 +1525,18 @@ fn render_notes_expanded(
 
     f.render_widget(Clear, expanded_area);
 
-    let block = Block::default()
+    let mut block = Block::default()
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Green))
-        .title("Notes")
+        .border_style(Style::default().fg(accent))
+        .title(focus_title("Notes", true, screen_reader))
         .padding(ratatui::widgets::Padding::horizontal(1));
+    if let Some(progress) = word_goal_title(log, word_goal, theme) {
+        block = block.title_top(progress);
+    }
+    block = reflection_titles(block, log, theme);
 
     let paragraph = Paragraph::new(text)
-        .style(Style::default().fg(Color::Green))
+        .style(Style::default().fg(accent))
         .block(block)
         .wrap(ratatui::widgets::Wrap { trim: false })
         .scroll((scroll_offset, 0));
@@ -919,6 +1550,7 @@ fn render_notes_expanded(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::FoodEntry;
     use ratatui::{Terminal, backend::TestBackend};
 
     #[test]
@@ -949,6 +1581,8 @@ mod tests {
             FieldType::Waist,
             FieldType::Miles,
             FieldType::Elevation,
+            FieldType::Duration,
+            FieldType::Rpe,
         ] {
             assert!(
                 targets
@@ -958,6 +1592,329 @@ mod tests {
         }
     }
 
+    #[test]
+    fn running_section_derives_pace_from_the_run_time() {
+        let mut terminal = Terminal::new(TestBackend::new(160, 40)).unwrap();
+        let mut state = AppState::new();
+        state.set_daily_logs(vec![DailyLog {
+            miles_covered: Some(6.2),
+            duration_secs: Some(3600),
+            ..DailyLog::new(state.selected_date)
+        }]);
+        terminal
+            .draw(|frame| {
+                render_daily_view_screen(
+                    frame,
+                    &state,
+                    &mut ListState::default(),
+                    &mut ListState::default(),
+                    "",
+                    None,
+                    None,
+                );
+            })
+            .unwrap();
+        let text: String = terminal
+            .backend()
+            .buffer()
+            .content
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(text.contains("Miles: 6.2 mi (9:41 /mi) | Elevation: Press 'l' to add | Time: 1:00:00"));
+    }
+
+    #[test]
+    fn flashback_lists_earlier_years_on_this_date() {
+        let mut terminal = Terminal::new(TestBackend::new(120, 40)).unwrap();
+        let mut state = AppState::new();
+        state.selected_date = NaiveDate::from_ymd_opt(2026, 10, 16).unwrap();
+        let render = |terminal: &mut Terminal<TestBackend>, state: &AppState| {
+            terminal
+                .draw(|frame| {
+                    render_daily_view_screen(
+                        frame,
+                        state,
+                        &mut ListState::default(),
+                        &mut ListState::default(),
+                        "",
+                        None,
+                        None,
+                    );
+                })
+                .unwrap();
+            terminal
+                .backend()
+                .buffer()
+                .content
+                .iter()
+                .map(|cell| cell.symbol())
+                .collect::<String>()
+        };
+        assert!(!render(&mut terminal, &state).contains("On This Day"));
+
+        state.set_daily_logs(vec![DailyLog {
+            miles_covered: Some(9.0),
+            notes: Some("First snow up top".to_string()),
+            ..DailyLog::new(NaiveDate::from_ymd_opt(2024, 10, 16).unwrap())
+        }]);
+        let text = render(&mut terminal, &state);
+        assert!(text.contains("On This Day"));
+        assert!(text.contains("2024 (2 years ago): 9 mi - First snow up top"));
+        assert!(!text.contains("Computed"));
+
+        state.computed = crate::computed::Formulas::compile(&[crate::computed::ComputedField {
+            name: "Week vert".to_string(),
+            formula: r#"sum("vert", 7)"#.to_string(),
+            decimals: 0,
+        }]);
+        let text = render(&mut terminal, &state);
+        assert!(text.contains("Computed"));
+        assert!(text.contains("Week vert: 0"));
+    }
+
+    #[test]
+    fn calorie_gauge_shows_once_food_has_calories_and_reddens_past_the_target() {
+        let mut terminal = Terminal::new(TestBackend::new(100, 40)).unwrap();
+        let mut state = AppState::new();
+        state.calorie_target = Some(2000);
+        let date = state.selected_date;
+        let mut render = |state: &AppState| {
+            terminal
+                .draw(|frame| {
+                    render_daily_view_screen(
+                        frame,
+                        state,
+                        &mut ListState::default(),
+                        &mut ListState::default(),
+                        "",
+                        None,
+                        None,
+                    );
+                })
+                .unwrap();
+            let buffer = terminal.backend().buffer().clone();
+            let text: String = buffer.content.iter().map(|cell| cell.symbol()).collect();
+            let red = buffer
+                .content
+                .iter()
+                .any(|cell| cell.fg == state.theme.error && cell.symbol() == "█");
+            (text, red)
+        };
+        let food = |calories| FoodEntry {
+            calories,
+            ..FoodEntry::new("Oatmeal".to_string())
+        };
+
+        state.set_daily_logs(vec![DailyLog {
+            food_entries: vec![food(None)],
+            ..DailyLog::new(date)
+        }]);
+        assert!(!render(&state).0.contains("Calories"));
+
+        state.set_daily_logs(vec![DailyLog {
+            food_entries: vec![food(Some(1500))],
+            ..DailyLog::new(date)
+        }]);
+        let (text, red) = render(&state);
+        assert!(text.contains("1,500 / 2,000 cal"));
+        assert!(!red);
+
+        state.set_daily_logs(vec![DailyLog {
+            food_entries: vec![food(Some(1500)), food(Some(800))],
+            ..DailyLog::new(date)
+        }]);
+        let (text, red) = render(&state);
+        assert!(text.contains("2,300 / 2,000 cal"));
+        assert!(red);
+    }
+
+    #[test]
+    fn notes_count_words_toward_the_goal() {
+        let mut terminal = Terminal::new(TestBackend::new(100, 40)).unwrap();
+        let mut state = AppState::new();
+        let date = state.selected_date;
+        let mut render = |state: &AppState| {
+            terminal
+                .draw(|frame| {
+                    render_daily_view_screen(
+                        frame,
+                        state,
+                        &mut ListState::default(),
+                        &mut ListState::default(),
+                        "",
+                        None,
+                        None,
+                    );
+                })
+                .unwrap();
+            let buffer = terminal.backend().buffer().clone();
+            let text: String = buffer.content.iter().map(|cell| cell.symbol()).collect();
+            let met = buffer
+                .content
+                .iter()
+                .any(|cell| cell.fg == state.theme.good && cell.symbol() == "w");
+            (text, met)
+        };
+        state.set_daily_logs(vec![DailyLog {
+            notes: Some("Windy on the ridge today".to_string()),
+            ..DailyLog::new(date)
+        }]);
+        assert!(!render(&state).0.contains("words"));
+
+        state.notes_word_goal = Some(6);
+        let (text, met) = render(&state);
+        assert!(text.contains("5/6 words"));
+        assert!(!met);
+
+        state.set_daily_logs(vec![DailyLog {
+            notes: Some("Windy on the ridge today, legs fine".to_string()),
+            ..DailyLog::new(date)
+        }]);
+        let (text, met) = render(&state);
+        assert!(text.contains("7/6 words"));
+        assert!(met);
+    }
+
+    #[test]
+    fn warns_when_the_last_week_ramps_past_the_threshold() {
+        let mut terminal = Terminal::new(TestBackend::new(120, 40)).unwrap();
+        let mut state = AppState::new();
+        let date = NaiveDate::from_ymd_opt(2026, 7, 28).unwrap();
+        state.set_daily_logs(
+            [(27, 10.0), (20, 10.0), (13, 10.0), (0, 20.0)]
+                .into_iter()
+                .map(|(days_ago, miles)| DailyLog {
+                    miles_covered: Some(miles),
+                    ..DailyLog::new(date - chrono::Days::new(days_ago))
+                })
+                .collect(),
+        );
+        state.selected_date = date;
+        let mut render = |state: &AppState| {
+            terminal
+                .draw(|frame| {
+                    render_daily_view_screen(
+                        frame,
+                        state,
+                        &mut ListState::default(),
+                        &mut ListState::default(),
+                        "",
+                        None,
+                        None,
+                    );
+                })
+                .unwrap();
+            let text: String = terminal
+                .backend()
+                .buffer()
+                .content
+                .iter()
+                .map(|cell| cell.symbol())
+                .collect();
+            text
+        };
+        assert!(render(&state).contains("Ramping up fast: the last 7 days are 1.6x your 4-week average"));
+
+        state.stats.configure(crate::config::StatsConfig {
+            ramp_warning: 2.0,
+            ..crate::config::StatsConfig::default()
+        });
+        assert!(!render(&state).contains("Ramping up fast"));
+    }
+
+    #[test]
+    fn running_shows_rolling_totals_up_to_the_selected_day() {
+        let mut terminal = Terminal::new(TestBackend::new(120, 40)).unwrap();
+        let mut state = AppState::new();
+        let date = NaiveDate::from_ymd_opt(2026, 7, 22).unwrap();
+        state.set_daily_logs(vec![
+            DailyLog {
+                miles_covered: Some(6.0),
+                elevation_gain: Some(1500),
+                ..DailyLog::new(date)
+            },
+            DailyLog {
+                miles_covered: Some(10.0),
+                elevation_gain: Some(2500),
+                ..DailyLog::new(date - chrono::Days::new(10))
+            },
+        ]);
+        let mut render = |state: &AppState| {
+            terminal
+                .draw(|frame| {
+                    render_daily_view_screen(
+                        frame,
+                        state,
+                        &mut ListState::default(),
+                        &mut ListState::default(),
+                        "",
+                        None,
+                        None,
+                    );
+                })
+                .unwrap();
+            let text: String = terminal
+                .backend()
+                .buffer()
+                .content
+                .iter()
+                .map(|cell| cell.symbol())
+                .collect();
+            text
+        };
+        state.selected_date = date;
+        assert!(render(&state).contains("Last 7 days: 6.0 mi, 1,500 ft | Last 28 days: 16.0 mi, 4,000 ft"));
+        state.selected_date = date - chrono::Days::new(1);
+        assert!(render(&state).contains("Last 7 days: 0.0 mi, 0 ft | Last 28 days: 10.0 mi, 2,500 ft"));
+    }
+
+    #[test]
+    fn screen_reader_mode_spells_out_focus_and_selection() {
+        let mut terminal = Terminal::new(TestBackend::new(120, 40)).unwrap();
+        let mut state = AppState::new();
+        let mut log = DailyLog::new(state.selected_date);
+        log.food_entries
+            .push(crate::models::FoodEntry::new("oatmeal".to_string()));
+        state.insert_daily_log(log);
+        state.focused_section = FocusedSection::FoodItems;
+        state.food_list_focused = true;
+        let mut food_state = ListState::default();
+        food_state.select(Some(0));
+        let mut sokay_state = ListState::default();
+
+        let mut render = |state: &AppState| {
+            terminal
+                .draw(|frame| {
+                    render_daily_view_screen(
+                        frame,
+                        state,
+                        &mut food_state,
+                        &mut sokay_state,
+                        "",
+                        None,
+                        None,
+                    );
+                })
+                .unwrap();
+            terminal
+                .backend()
+                .buffer()
+                .content
+                .iter()
+                .map(|cell| cell.symbol())
+                .collect::<String>()
+        };
+
+        let plain = render(&state);
+        assert!(!plain.contains("(focused)") && !plain.contains("(selected)"));
+        state.screen_reader = true;
+        let spoken = render(&state);
+        assert!(spoken.contains("Food Items (focused)"));
+        assert!(spoken.contains("(selected) - oatmeal"));
+        assert!(!spoken.contains("Notes (focused)"));
+    }
+
     #[test]
     fn expanded_notes_target_is_registered_after_underlying_sections() {
         let backend = TestBackend::new(100, 40);
@@ -965,7 +1922,7 @@ mod tests {
         let mut state = AppState::new();
         state.focused_section = FocusedSection::Notes;
         let date = state.selected_date;
-        state.daily_logs.push(DailyLog {
+        state.insert_daily_log(DailyLog {
             date,
             notes: Some("long notes ".repeat(200)),
             ..DailyLog::new(date)
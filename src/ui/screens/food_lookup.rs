@@ -0,0 +1,178 @@
+use chrono::NaiveDate;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use super::daily_view::render_daily_view_screen;
+use crate::format::Units;
+use crate::models::{AppState, FoodHistory};
+use crate::ui::components::{centered_rect, format_input_with_cursor};
+use crate::ui::theme::Theme;
+
+/// How many of a food's dates are listed under it.
+const RECENT_DATES: usize = 4;
+
+/// Renders the food lookup over the daily view: the query, then each
+/// matching food with how many days it was eaten and when, the selected one
+/// highlighted.
+#[allow(clippy::too_many_arguments)]
+pub fn render_food_lookup_screen(
+    f: &mut Frame,
+    state: &AppState,
+    food_list_state: &mut ratatui::widgets::ListState,
+    sokay_list_state: &mut ratatui::widgets::ListState,
+    sync_status: &str,
+    input_buffer: &str,
+    selected: usize,
+) {
+    render_daily_view_screen(f, state, food_list_state, sokay_list_state, sync_status, None, None);
+    let theme = &state.theme;
+
+    let area = centered_rect(f.area(), 70, 70);
+    f.render_widget(Clear, area);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(" Food History ")
+        .border_style(Style::default().fg(theme.sections.food))
+        .padding(ratatui::widgets::Padding::horizontal(1));
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2),
+            Constraint::Min(0),
+            Constraint::Length(1),
+        ])
+        .split(inner);
+
+    f.render_widget(
+        Paragraph::new(Line::from(vec![
+            Span::styled("Search: ", Style::default().fg(theme.label)),
+            Span::styled(
+                format_input_with_cursor(input_buffer),
+                Style::default().fg(theme.text),
+            ),
+        ])),
+        chunks[0],
+    );
+
+    let today = chrono::Local::now().date_naive();
+    let lines = if input_buffer.trim().is_empty() {
+        vec![Line::from(Span::styled(
+            "Type part of a food's name to see when you last ate it.",
+            Style::default().fg(theme.dim),
+        ))]
+    } else if state.food_history.is_empty() {
+        vec![Line::from(Span::styled(
+            format!("Nothing logged matching \"{}\".", input_buffer.trim()),
+            Style::default().fg(theme.dim),
+        ))]
+    } else {
+        // Keep the selection on screen by dropping the matches above it
+        let per_match = 2;
+        let fits = (chunks[1].height as usize / per_match).max(1);
+        let skip = (selected + 1).saturating_sub(fits);
+        state
+            .food_history
+            .iter()
+            .enumerate()
+            .skip(skip)
+            .flat_map(|(index, food)| match_lines(food, index == selected, today, state.units, theme))
+            .collect()
+    };
+    f.render_widget(Paragraph::new(lines), chunks[1]);
+
+    f.render_widget(
+        Paragraph::new(Line::from(Span::styled(
+            "↑/↓: Select | Enter: Open Last Day | Esc: Back",
+            Style::default().fg(theme.dim),
+        )))
+        .alignment(ratatui::layout::Alignment::Center),
+        chunks[2],
+    );
+}
+
+/// "Oatmeal - 12 days, last Oct 03, 2026 (13 days ago)" and a dim line of the
+/// latest dates.
+fn match_lines(
+    food: &FoodHistory,
+    selected: bool,
+    today: NaiveDate,
+    units: Units,
+    theme: &Theme,
+) -> Vec<Line<'static>> {
+    let count = food.dates.len();
+    let summary = match food.dates.first() {
+        Some(&last) => format!(
+            " - {} {}, last {} ({})",
+            count,
+            if count == 1 { "day" } else { "days" },
+            units.short_date(last),
+            days_ago(last, today)
+        ),
+        None => String::new(),
+    };
+    let name_style = if selected {
+        Style::default()
+            .fg(theme.highlight)
+            .add_modifier(Modifier::BOLD | Modifier::REVERSED)
+    } else {
+        Style::default()
+            .fg(theme.highlight)
+            .add_modifier(Modifier::BOLD)
+    };
+    let mut recent: Vec<String> = food
+        .dates
+        .iter()
+        .take(RECENT_DATES)
+        .map(|&date| units.month_day(date))
+        .collect();
+    if count > RECENT_DATES {
+        recent.push(format!("+{} more", count - RECENT_DATES));
+    }
+    vec![
+        Line::from(vec![
+            Span::styled(food.name.clone(), name_style),
+            Span::styled(summary, Style::default().fg(theme.text)),
+        ]),
+        Line::from(Span::styled(
+            format!("  {}", recent.join(", ")),
+            Style::default().fg(theme.dim),
+        )),
+    ]
+}
+
+fn days_ago(date: NaiveDate, today: NaiveDate) -> String {
+    match (today - date).num_days() {
+        0 => "today".to_string(),
+        1 => "yesterday".to_string(),
+        days => format!("{} days ago", days),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn match_lines_summarize_count_and_last_date() {
+        let day = |d| NaiveDate::from_ymd_opt(2026, 7, d).unwrap();
+        let food = FoodHistory {
+            name: "Oatmeal".to_string(),
+            dates: (1..=6).rev().map(day).collect(),
+        };
+        let lines = match_lines(&food, true, day(20), Units::default(), &Theme::default());
+        assert_eq!(
+            lines[0].to_string(),
+            "Oatmeal - 6 days, last Jul 06, 2026 (14 days ago)"
+        );
+        assert_eq!(lines[1].to_string(), "  Jul 06, Jul 05, Jul 04, Jul 03, +2 more");
+        assert_eq!(days_ago(day(20), day(20)), "today");
+    }
+}
@@ -0,0 +1,200 @@
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Padding, Paragraph},
+};
+
+use super::daily_view::render_daily_view_screen;
+use crate::gear::{self, GearMileage, GearStatus};
+use crate::models::AppState;
+use crate::ui::components::{centered_rect, format_input_with_cursor};
+use crate::format::Units;
+use crate::units::Quantity;
+
+/// Renders every piece of gear with its distance, days and last use, active
+/// gear first. `naming` is the name being typed for new gear, if any.
+pub fn render_gear_screen(f: &mut Frame, state: &AppState, selected: usize, naming: Option<&str>) {
+    let theme = &state.theme;
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme.sections.running))
+        .title(" Gear ")
+        .title_style(Style::default().fg(theme.text).add_modifier(Modifier::BOLD))
+        .padding(Padding::new(2, 2, 1, 1));
+    let inner_area = block.inner(f.area());
+    f.render_widget(block, f.area());
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(0),
+            Constraint::Length(if naming.is_some() { 2 } else { 0 }),
+            Constraint::Length(1),
+        ])
+        .split(inner_area);
+
+    let rows = gear::mileage(&state.gear, state.daily_logs());
+    let mut lines = Vec::new();
+    if rows.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "No gear yet. Press 'a' to add a pair of shoes.",
+            Style::default().fg(theme.dim),
+        )));
+    }
+    for (index, row) in rows.iter().enumerate() {
+        let is_selected = index == selected.min(rows.len() - 1);
+        let name_style = if is_selected {
+            Style::default()
+                .fg(theme.highlight)
+                .add_modifier(Modifier::BOLD)
+        } else if row.retired {
+            Style::default().fg(theme.dim)
+        } else {
+            Style::default().fg(theme.text)
+        };
+        let mut spans = vec![
+            Span::styled(
+                format!("{}{}", if is_selected { "► " } else { "  " }, row.name),
+                name_style,
+            ),
+            Span::styled(
+                format!(" - {}", summary(row, state.units)),
+                Style::default().fg(theme.dim),
+            ),
+        ];
+        match row.status(state.gear_retire_after) {
+            GearStatus::Fine => {}
+            GearStatus::RetireSoon => spans.push(Span::styled(
+                " (retire soon)",
+                Style::default().fg(theme.warning),
+            )),
+            GearStatus::Worn => spans.push(Span::styled(
+                " (time to retire)",
+                Style::default().fg(theme.error),
+            )),
+        }
+        if row.retired {
+            spans.push(Span::styled(" (retired)", Style::default().fg(theme.dim)));
+        }
+        lines.push(Line::from(spans));
+    }
+    f.render_widget(Paragraph::new(lines), chunks[0]);
+
+    if let Some(name) = naming {
+        f.render_widget(
+            Paragraph::new(Line::from(vec![
+                Span::styled("Name: ", Style::default().fg(theme.label)),
+                Span::styled(format_input_with_cursor(name), Style::default().fg(theme.text)),
+            ])),
+            chunks[1],
+        );
+    }
+
+    let help_spans = if naming.is_some() {
+        vec![
+            Span::styled("Enter", Style::default().fg(theme.highlight)),
+            Span::styled(": Add | ", Style::default().fg(theme.text)),
+            Span::styled("Esc", Style::default().fg(theme.highlight)),
+            Span::styled(": Cancel", Style::default().fg(theme.text)),
+        ]
+    } else {
+        vec![
+            Span::styled("j/k", Style::default().fg(theme.highlight)),
+            Span::styled(": Select | ", Style::default().fg(theme.text)),
+            Span::styled("a", Style::default().fg(theme.highlight)),
+            Span::styled(": Add | ", Style::default().fg(theme.text)),
+            Span::styled("x", Style::default().fg(theme.highlight)),
+            Span::styled(": Retire/Restore | ", Style::default().fg(theme.text)),
+            Span::styled("Esc", Style::default().fg(theme.highlight)),
+            Span::styled(": Back", Style::default().fg(theme.text)),
+        ]
+    };
+    f.render_widget(
+        Paragraph::new(Line::from(help_spans)).alignment(ratatui::layout::Alignment::Center),
+        chunks[2],
+    );
+}
+
+/// Renders the gear picker over the daily view: the active gear, then
+/// "None", the selected choice highlighted.
+pub fn render_gear_picker_screen(
+    f: &mut Frame,
+    state: &AppState,
+    food_list_state: &mut ratatui::widgets::ListState,
+    sokay_list_state: &mut ratatui::widgets::ListState,
+    sync_status: &str,
+    selected: usize,
+) {
+    render_daily_view_screen(f, state, food_list_state, sokay_list_state, sync_status, None, None);
+    let theme = &state.theme;
+
+    let area = centered_rect(f.area(), 50, 50);
+    f.render_widget(Clear, area);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(" Run In ")
+        .border_style(Style::default().fg(theme.sections.running))
+        .padding(Padding::horizontal(1));
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(inner);
+
+    let choices: Vec<String> = state
+        .gear
+        .iter()
+        .filter(|g| !g.retired)
+        .map(|g| g.name.clone())
+        .chain(["None".to_string()])
+        .collect();
+    let lines: Vec<Line> = choices
+        .into_iter()
+        .enumerate()
+        .map(|(index, name)| {
+            if index == selected {
+                Line::from(Span::styled(
+                    format!("► {}", name),
+                    Style::default()
+                        .fg(theme.highlight)
+                        .add_modifier(Modifier::BOLD),
+                ))
+            } else {
+                Line::from(Span::styled(
+                    format!("  {}", name),
+                    Style::default().fg(theme.text),
+                ))
+            }
+        })
+        .collect();
+    f.render_widget(Paragraph::new(lines), chunks[0]);
+
+    f.render_widget(
+        Paragraph::new(Line::from(Span::styled(
+            "↑/↓: Select | Enter: Choose | Esc: Back",
+            Style::default().fg(theme.dim),
+        )))
+        .alignment(ratatui::layout::Alignment::Center),
+        chunks[1],
+    );
+}
+
+/// "312.4 mi over 41 days, last Jul 03, 2026"
+fn summary(row: &GearMileage, units: Units) -> String {
+    let distance = units.total(Quantity::Distance, row.miles as f64);
+    match row.last_used {
+        Some(last) => format!(
+            "{} over {} {}, last {}",
+            distance,
+            row.days,
+            if row.days == 1 { "day" } else { "days" },
+            units.short_date(last)
+        ),
+        None => format!("{}, not used yet", distance),
+    }
+}
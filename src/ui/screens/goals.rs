@@ -0,0 +1,225 @@
+use chrono::Local;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, LineGauge, Padding, Paragraph},
+};
+
+use crate::goals::{Goal, GoalDraft};
+use crate::models::AppState;
+use crate::ui::components::format_input_with_cursor;
+
+/// Rows each goal takes: its label, its bar and a gap.
+const GOAL_HEIGHT: u16 = 3;
+
+/// Renders every goal with a progress bar, paused ones dimmed. `draft` is
+/// the goal being added or edited and the target typed so far, if any.
+pub fn render_goals_screen(
+    f: &mut Frame,
+    state: &AppState,
+    selected: usize,
+    draft: Option<(GoalDraft, &str)>,
+) {
+    let theme = &state.theme;
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme.sections.running))
+        .title(" Goals ")
+        .title_style(Style::default().fg(theme.text).add_modifier(Modifier::BOLD))
+        .padding(Padding::new(2, 2, 1, 1));
+    let inner_area = block.inner(f.area());
+    f.render_widget(block, f.area());
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(0),
+            Constraint::Length(if draft.is_some() { 2 } else { 0 }),
+            Constraint::Length(1),
+        ])
+        .split(inner_area);
+
+    let goals = &state.goal_list;
+    if goals.is_empty() {
+        f.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                "No goals yet. Press 'a' to add one.",
+                Style::default().fg(theme.dim),
+            ))),
+            chunks[0],
+        );
+    }
+    // Keep the selected goal on screen when they don't all fit
+    let selected = selected.min(goals.len().saturating_sub(1));
+    let fits = (chunks[0].height / GOAL_HEIGHT).max(1) as usize;
+    let first = (selected + 1).saturating_sub(fits);
+    for (offset, goal) in goals.iter().skip(first).take(fits).enumerate() {
+        let area = Rect {
+            y: chunks[0].y + offset as u16 * GOAL_HEIGHT,
+            height: GOAL_HEIGHT.min(chunks[0].height),
+            ..chunks[0]
+        };
+        render_goal(f, state, goal, first + offset == selected, area);
+    }
+
+    if let Some((draft, typed)) = draft {
+        let prompt = match draft.kind.quantity() {
+            Some(quantity) => format!("{} ({}): ", draft.kind.title(), state.units.label(quantity)),
+            None => format!("{} (a week): ", draft.kind.title()),
+        };
+        f.render_widget(
+            Paragraph::new(Line::from(vec![
+                Span::styled(prompt, Style::default().fg(theme.label)),
+                Span::styled(format_input_with_cursor(typed), Style::default().fg(theme.text)),
+            ])),
+            chunks[1],
+        );
+    }
+
+    let help_spans = match draft {
+        Some((draft, _)) => {
+            let mut spans = vec![
+                Span::styled("Enter", Style::default().fg(theme.highlight)),
+                Span::styled(": Save | ", Style::default().fg(theme.text)),
+            ];
+            if draft.editing.is_none() {
+                spans.extend([
+                    Span::styled("Tab", Style::default().fg(theme.highlight)),
+                    Span::styled(": Kind | ", Style::default().fg(theme.text)),
+                ]);
+            }
+            spans.extend([
+                Span::styled("Esc", Style::default().fg(theme.highlight)),
+                Span::styled(": Cancel", Style::default().fg(theme.text)),
+            ]);
+            spans
+        }
+        None => vec![
+            Span::styled("j/k", Style::default().fg(theme.highlight)),
+            Span::styled(": Select | ", Style::default().fg(theme.text)),
+            Span::styled("a", Style::default().fg(theme.highlight)),
+            Span::styled(": Add | ", Style::default().fg(theme.text)),
+            Span::styled("e", Style::default().fg(theme.highlight)),
+            Span::styled(": Edit | ", Style::default().fg(theme.text)),
+            Span::styled("x", Style::default().fg(theme.highlight)),
+            Span::styled(": Pause/Resume | ", Style::default().fg(theme.text)),
+            Span::styled("d", Style::default().fg(theme.highlight)),
+            Span::styled(": Delete | ", Style::default().fg(theme.text)),
+            Span::styled("Esc", Style::default().fg(theme.highlight)),
+            Span::styled(": Back", Style::default().fg(theme.text)),
+        ],
+    };
+    f.render_widget(
+        Paragraph::new(Line::from(help_spans)).alignment(ratatui::layout::Alignment::Center),
+        chunks[2],
+    );
+}
+
+/// One goal: "► Weekly distance: 40.0 mi a week - 18.0 / 40.0 mi - On pace
+/// for 42.0 mi" over a bar, green on track and amber behind.
+fn render_goal(f: &mut Frame, state: &AppState, goal: &Goal, selected: bool, area: Rect) {
+    let theme = &state.theme;
+    let units = state.units;
+    let standing = goal.standing(
+        state.daily_logs(),
+        Local::now().date_naive(),
+        state.stats.config().week_start,
+        units,
+    );
+    let name_style = if selected {
+        Style::default()
+            .fg(theme.highlight)
+            .add_modifier(Modifier::BOLD)
+    } else if goal.active {
+        Style::default().fg(theme.text)
+    } else {
+        Style::default().fg(theme.dim)
+    };
+    let mut spans = vec![
+        Span::styled(
+            format!(
+                "{}{}: {}",
+                if selected { "► " } else { "  " },
+                goal.kind.title(),
+                goal.label(units)
+            ),
+            name_style,
+        ),
+        Span::styled(format!(" - {}", standing.detail), Style::default().fg(theme.dim)),
+    ];
+    if !goal.active {
+        spans.push(Span::styled(" (paused)", Style::default().fg(theme.dim)));
+    }
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Length(1), Constraint::Min(0)])
+        .split(area);
+    f.render_widget(Paragraph::new(Line::from(spans)), rows[0]);
+
+    let color = if !goal.active {
+        theme.dim
+    } else if standing.on_track {
+        theme.good
+    } else {
+        theme.warning
+    };
+    let gauge = LineGauge::default()
+        .filled_style(Style::default().fg(color))
+        .unfilled_style(Style::default().fg(theme.dim))
+        .label(format!("  {:>3.0}%", standing.ratio * 100.0))
+        .ratio(standing.ratio);
+    f.render_widget(gauge, rows[1]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::goals::GoalKind;
+    use ratatui::{Terminal, backend::TestBackend};
+
+    #[test]
+    fn goals_render_with_their_standing_and_paused_ones_marked() {
+        let today = Local::now().date_naive();
+        let mut state = AppState::new();
+        state.set_daily_logs(vec![crate::models::DailyLog {
+            sokay_entries: vec![Default::default(); 4],
+            ..crate::models::DailyLog::new(today)
+        }]);
+        state.goal_list = vec![
+            Goal {
+                id: 1,
+                kind: GoalKind::SokayLimit,
+                target: 3.0,
+                since: today,
+                active: true,
+            },
+            Goal {
+                id: 2,
+                kind: GoalKind::Weight,
+                target: 170.0,
+                since: today,
+                active: false,
+            },
+        ];
+        let mut terminal = Terminal::new(TestBackend::new(120, 20)).unwrap();
+        terminal
+            .draw(|frame| render_goals_screen(frame, &state, 0, None))
+            .unwrap();
+        let text: String = terminal
+            .backend()
+            .buffer()
+            .content
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+
+        assert!(text.contains(
+            "► Sokay limit: At most 3 sokay a week - 4 of 3 this week - over the limit"
+        ));
+        assert!(text.contains("100%"));
+        assert!(text.contains("Target weight: 170 lbs - No weight logged yet (paused)"));
+    }
+}
@@ -0,0 +1,200 @@
+use chrono::{Datelike, Days, NaiveDate};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+};
+
+use crate::models::AppState;
+use crate::ui::components::{create_standard_layout, render_help, render_title};
+use crate::ui::theme::Theme;
+use crate::units::Quantity;
+
+const DAY_LABELS: [&str; 7] = ["Mon", "   ", "Wed", "   ", "Fri", "   ", "Sun"];
+/// Left gutter holding the weekday labels.
+const LABEL_WIDTH: u16 = 4;
+
+/// Elevation buckets from no entry (0) to 2000+ ft (4), GitHub-style.
+pub fn heatmap_level(elevation: Option<i32>) -> usize {
+    match elevation.unwrap_or(0) {
+        feet if feet <= 0 => 0,
+        1..=499 => 1,
+        500..=999 => 2,
+        1000..=1999 => 3,
+        _ => 4,
+    }
+}
+
+/// Monday of the week containing January 1st; column 0 of the grid.
+fn grid_start(year: i32) -> NaiveDate {
+    let jan_1 = NaiveDate::from_ymd_opt(year, 1, 1).unwrap_or_default();
+    jan_1 - Days::new(jan_1.weekday().num_days_from_monday() as u64)
+}
+
+/// (week column, weekday row) of `date` in its year's grid.
+pub fn cell_position(date: NaiveDate) -> (usize, usize) {
+    let days = (date - grid_start(date.year())).num_days() as usize;
+    (days / 7, date.weekday().num_days_from_monday() as usize)
+}
+
+/// Renders a calendar heatmap of daily elevation gain for the cursor's year,
+/// one column per week and one row per weekday.
+pub fn render_heatmap_screen(f: &mut Frame, state: &AppState, cursor: NaiveDate) {
+    let theme = &state.theme;
+    let chunks = create_standard_layout(f.area());
+    let year = cursor.year();
+    render_title(f, chunks[0], &format!("Elevation Heatmap - {}", year), theme);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.border))
+        .padding(ratatui::widgets::Padding::horizontal(1));
+    let inner = block.inner(chunks[1]);
+    f.render_widget(block, chunks[1]);
+
+    let dec_31 = NaiveDate::from_ymd_opt(year, 12, 31).unwrap_or(cursor);
+    let weeks = cell_position(dec_31).0 + 1;
+    // Two cells per week when there's room, otherwise squeeze to one.
+    let cell_width: usize = if inner.width >= LABEL_WIDTH + weeks as u16 * 2 {
+        2
+    } else {
+        1
+    };
+
+    let mut lines = vec![month_header(year, weeks, cell_width, theme), Line::from("")];
+    let start = grid_start(year);
+    for (row, label) in DAY_LABELS.iter().enumerate() {
+        let mut spans = vec![Span::styled(
+            format!("{:<width$}", label, width = LABEL_WIDTH as usize),
+            Style::default().fg(theme.label),
+        )];
+        for week in 0..weeks {
+            let date = start + Days::new((week * 7 + row) as u64);
+            if date.year() != year {
+                spans.push(Span::raw(" ".repeat(cell_width)));
+                continue;
+            }
+            let level = heatmap_level(state.counted_daily_log(date).and_then(|log| log.elevation_gain));
+            let mut style = Style::default().fg(theme.heat[level]);
+            if date == cursor {
+                style = style.bg(theme.text).add_modifier(Modifier::BOLD);
+            }
+            let symbol = if cell_width == 2 { "■ " } else { "■" };
+            spans.push(Span::styled(symbol, style));
+        }
+        lines.push(Line::from(spans));
+    }
+
+    let sections = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(lines.len() as u16 + 1), Constraint::Min(0)])
+        .split(inner);
+    f.render_widget(Paragraph::new(lines), sections[0]);
+
+    let cursor_elevation = state
+        .counted_daily_log(cursor)
+        .and_then(|log| log.elevation_gain)
+        .map_or("no elevation logged".to_string(), |feet| {
+            state.units.format(Quantity::Elevation, feet as f32)
+        });
+    let mut legend = vec![Span::styled("Less ", Style::default().fg(theme.label))];
+    for level in 0..=4 {
+        legend.push(Span::styled("■ ", Style::default().fg(theme.heat[level])));
+    }
+    legend.push(Span::styled("More", Style::default().fg(theme.label)));
+    let details = Paragraph::new(vec![
+        Line::from(Span::styled(
+            format!("{}: {}", state.units.weekday_date(cursor), cursor_elevation),
+            Style::default().fg(theme.highlight),
+        )),
+        Line::from(""),
+        Line::from(legend),
+    ]);
+    f.render_widget(details, sections[1]);
+
+    render_help(
+        f,
+        chunks[2],
+        &[
+            " ←/→ h/l: Week | ↑/↓ k/j: Day | Enter: Open Day | Esc: Back",
+            " hl: Week | jk: Day | Enter: Open | Esc: Back",
+        ],
+        true,
+        true,
+        theme,
+    );
+}
+
+/// Month abbreviations placed above the first week column of each month.
+fn month_header(year: i32, weeks: usize, cell_width: usize, theme: &Theme) -> Line<'static> {
+    let mut header = vec![' '; LABEL_WIDTH as usize + weeks * cell_width];
+    for month in 1..=12 {
+        let Some(first) = NaiveDate::from_ymd_opt(year, month, 1) else {
+            continue;
+        };
+        let column = LABEL_WIDTH as usize + cell_position(first).0 * cell_width;
+        for (offset, c) in first.format("%b").to_string().chars().enumerate() {
+            if let Some(slot) = header.get_mut(column + offset) {
+                *slot = c;
+            }
+        }
+    }
+    Line::from(Span::styled(
+        header.into_iter().collect::<String>(),
+        Style::default().fg(theme.label),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::DailyLog;
+    use ratatui::{Terminal, backend::TestBackend};
+
+    #[test]
+    fn levels_bucket_elevation() {
+        assert_eq!(heatmap_level(None), 0);
+        assert_eq!(heatmap_level(Some(0)), 0);
+        assert_eq!(heatmap_level(Some(300)), 1);
+        assert_eq!(heatmap_level(Some(999)), 2);
+        assert_eq!(heatmap_level(Some(1000)), 3);
+        assert_eq!(heatmap_level(Some(4500)), 4);
+    }
+
+    #[test]
+    fn cell_positions_start_on_the_week_of_january_first() {
+        // Jan 1 2026 is a Thursday
+        assert_eq!(cell_position(NaiveDate::from_ymd_opt(2026, 1, 1).unwrap()), (0, 3));
+        assert_eq!(cell_position(NaiveDate::from_ymd_opt(2026, 1, 5).unwrap()), (1, 0));
+        assert_eq!(cell_position(NaiveDate::from_ymd_opt(2026, 12, 31).unwrap()), (52, 3));
+    }
+
+    #[test]
+    fn renders_months_and_cursor_details() {
+        let date = NaiveDate::from_ymd_opt(2026, 7, 22).unwrap();
+        let mut state = AppState::new();
+        state.set_daily_logs(vec![DailyLog {
+            elevation_gain: Some(1200),
+            ..DailyLog::new(date)
+        }]);
+
+        let mut terminal = Terminal::new(TestBackend::new(130, 24)).unwrap();
+        terminal
+            .draw(|frame| render_heatmap_screen(frame, &state, date))
+            .unwrap();
+        let text: String = terminal
+            .backend()
+            .buffer()
+            .content
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+
+        assert!(text.contains("Elevation Heatmap - 2026"));
+        assert!(text.contains("Jan"));
+        assert!(text.contains("Dec"));
+        assert!(text.contains("Wednesday, July 22, 2026: 1,200 ft"));
+    }
+}
@@ -1,12 +1,16 @@
 use ratatui::{
     Frame,
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style},
-    widgets::{Block, Borders, Clear, Gauge, ListState, Paragraph},
+    style::{Modifier, Style},
+    widgets::{Block, Borders, Clear, ListState, Paragraph},
 };
 
+use crate::events::keys::Keymap;
+use crate::manual;
 use crate::models::AppState;
 use crate::ui::components::centered_rect;
+use crate::ui::theme::Theme;
+use std::time::Duration;
 use super::daily_view::render_daily_view_screen;
 
 /// Renders the shortcuts help overlay on the daily view screen
@@ -16,33 +20,19 @@ pub fn render_shortcuts_help_screen(
     food_list_state: &mut ListState,
     sokay_list_state: &mut ListState,
     sync_status: &str,
+    keymap: &Keymap,
 ) {
     render_daily_view_screen(f, state, food_list_state, sokay_list_state, sync_status, None, None);
 
-    let shortcuts_text = "\
-Measurements:
-  w - Edit weight
-  s - Edit waist size
-
-Activity:
-  m - Edit miles covered
-  l - Edit elevation gain
-
-Nutrition:
-  f - Add food item
-  c - Add sokay entry
-
-Training:
-  t - Edit strength & mobility
-  n - Edit daily notes
-  Alt+Enter - Insert newline (in multiline fields)
-
-Press Enter to save entry, or Esc to exit field
-
-With any focused section, press Enter to place cursor
-
-
-Press Space or Esc to close this modal";
+    let mut lines = manual::shortcuts(&[manual::DAILY_VIEW, manual::TEXT_FIELDS], keymap);
+    if let Some(swaps) = keymap.describe() {
+        lines.push(String::new());
+        lines.push(swaps);
+    }
+    lines.push(String::new());
+    lines.push("F1: Full manual | Space or Esc: Close".to_string());
+    let shortcuts_text = lines.join("\n");
+    let shortcuts_text = shortcuts_text.as_str();
 
     // Size the popup to the content (plus border + top/bottom padding) so the
     // last line is never clipped, then center it within the screen.
@@ -57,9 +47,10 @@ Press Space or Esc to close this modal";
 
     f.render_widget(Clear, popup_area);
 
+    let theme = &state.theme;
     let block = Block::default()
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Green))
+        .border_style(Style::default().fg(theme.good))
         .title("Shortcuts")
         .padding(ratatui::widgets::Padding::uniform(1));
 
@@ -67,13 +58,33 @@ Press Space or Esc to close this modal";
     f.render_widget(block, popup_area);
 
     let text = Paragraph::new(shortcuts_text)
-        .style(Style::default().fg(Color::White))
+        .style(Style::default().fg(theme.text))
         .wrap(ratatui::widgets::Wrap { trim: false });
     f.render_widget(text, inner_area);
 }
 
 /// Renders the syncing screen with a centered modal and progress gauge
-pub fn render_syncing_screen(f: &mut Frame, sync_status: &str) {
+/// Spinner frames for the syncing screen, and their ASCII stand-ins.
+const SPINNER: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+const ASCII_SPINNER: [&str; 4] = ["|", "/", "-", "\\"];
+/// How long each spinner frame shows.
+const SPINNER_FRAME: Duration = Duration::from_millis(100);
+
+/// The spinner frame for `elapsed` time since it started turning.
+pub fn spinner_frame(elapsed: Duration, ascii: bool) -> &'static str {
+    let frames: &[&'static str] = if ascii { &ASCII_SPINNER } else { &SPINNER };
+    let tick = (elapsed.as_millis() / SPINNER_FRAME.as_millis()) as usize;
+    frames[tick % frames.len()]
+}
+
+/// Renders the sync on the way out: the status, and `spinner` (a frame and
+/// the time taken) while it's still running.
+pub fn render_syncing_screen(
+    f: &mut Frame,
+    sync_status: &str,
+    spinner: Option<&str>,
+    theme: &Theme,
+) {
     let popup_area = centered_rect(f.area(), 60, 25);
 
     f.render_widget(Clear, popup_area);
@@ -82,11 +93,11 @@ pub fn render_syncing_screen(f: &mut Frame, sync_status: &str) {
     let is_complete = sync_status.contains("complete");
 
     let border_color = if is_offline {
-        Color::Rgb(255, 165, 0) // Orange for offline
+        theme.warning
     } else if is_complete {
-        Color::Green
+        theme.good
     } else {
-        Color::Cyan
+        theme.border
     };
 
     let block = Block::default()
@@ -94,7 +105,7 @@ pub fn render_syncing_screen(f: &mut Frame, sync_status: &str) {
         .border_type(ratatui::widgets::BorderType::Rounded)
         .border_style(Style::default().fg(border_color))
         .title(if is_offline { "Offline" } else { "Syncing" })
-        .title_style(Style::default().fg(Color::White).add_modifier(Modifier::BOLD))
+        .title_style(Style::default().fg(theme.text).add_modifier(Modifier::BOLD))
         .padding(ratatui::widgets::Padding::uniform(1));
 
     let inner_area = block.inner(popup_area);
@@ -104,29 +115,24 @@ pub fn render_syncing_screen(f: &mut Frame, sync_status: &str) {
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(3), // Message
-            Constraint::Length(1), // Gauge
+            Constraint::Length(1), // Spinner
             Constraint::Min(0),    // Spacing
         ])
         .split(inner_area);
 
     let message = Paragraph::new(sync_status)
-        .style(Style::default().fg(Color::White))
+        .style(Style::default().fg(theme.text))
         .alignment(ratatui::layout::Alignment::Center);
     f.render_widget(message, chunks[0]);
 
-    if !is_offline {
-        let gauge_percent = if is_complete { 100 } else { 50 };
-        let gauge_color = if is_complete { Color::Green } else { Color::Cyan };
-
-        let gauge = Gauge::default()
-            .gauge_style(Style::default().fg(gauge_color))
-            .ratio(gauge_percent as f64 / 100.0)
-            .use_unicode(true);
-
-        f.render_widget(gauge, chunks[1]);
-    } else {
+    if let Some(spinner) = spinner {
+        let spinner = Paragraph::new(spinner)
+            .style(Style::default().fg(theme.border))
+            .alignment(ratatui::layout::Alignment::Center);
+        f.render_widget(spinner, chunks[1]);
+    } else if is_offline {
         let offline_note = Paragraph::new("Changes will sync on next startup")
-            .style(Style::default().fg(Color::Rgb(255, 165, 0)))
+            .style(Style::default().fg(theme.warning))
             .alignment(ratatui::layout::Alignment::Center);
         f.render_widget(offline_note, chunks[1]);
     }
@@ -1,14 +1,114 @@
+use chrono::NaiveDate;
 use ratatui::{
     Frame,
-    widgets::{Block, Borders, List, ListItem, ListState},
+    layout::{Constraint, Direction, Layout},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
 };
+use std::collections::HashMap;
 
-use crate::models::AppState;
+use crate::models::home_filter::{HomeRow, SavedView};
+use crate::models::{AppScreen, AppState, DailyLog};
+use crate::month_stats::month_start;
+use crate::stats_cache::Period;
+use crate::format::Units;
+use crate::units::Quantity;
 use crate::ui::components::{
-    create_highlight_style, create_standard_layout, render_help, render_title,
+    centered_rect, create_highlight_style, create_standard_layout, format_input_with_cursor,
+    render_help, render_title, selection_symbol,
 };
 use crate::ui::{ClickAction, ClickTarget};
 
+/// What the Views menu shows.
+pub struct ViewsMenu<'a> {
+    pub views: &'a [SavedView],
+    pub selected: usize,
+    /// The name being typed for the current filter, while saving it.
+    pub naming: Option<&'a str>,
+}
+
+/// Renders the Views menu over the logged days list: each saved filter with
+/// its query, the highlighted one reversed.
+pub fn render_home_views_screen(
+    f: &mut Frame,
+    state: &AppState,
+    list_state: &mut ListState,
+    sync_status: &str,
+    menu: ViewsMenu,
+) {
+    render_home_screen(f, state, list_state, sync_status, None);
+    let theme = &state.theme;
+
+    let area = centered_rect(f.area(), 60, 50);
+    f.render_widget(Clear, area);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(" Views ")
+        .border_style(Style::default().fg(theme.border))
+        .padding(ratatui::widgets::Padding::horizontal(1));
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(inner);
+
+    let mut lines: Vec<Line> = if menu.views.is_empty() {
+        vec![Line::from(Span::styled(
+            "No saved views yet. Filter with / and press s here to save it.",
+            Style::default().fg(theme.dim),
+        ))]
+    } else {
+        // Keep the selection on screen by dropping the views above it
+        let fits = (chunks[0].height as usize).saturating_sub(2).max(1);
+        let skip = (menu.selected + 1).saturating_sub(fits);
+        menu.views
+            .iter()
+            .enumerate()
+            .skip(skip)
+            .map(|(index, view)| {
+                let name_style = if index == menu.selected {
+                    Style::default()
+                        .fg(theme.highlight)
+                        .add_modifier(Modifier::BOLD | Modifier::REVERSED)
+                } else {
+                    Style::default()
+                        .fg(theme.highlight)
+                        .add_modifier(Modifier::BOLD)
+                };
+                Line::from(vec![
+                    Span::styled(view.name.clone(), name_style),
+                    Span::styled(format!("  {}", view.query), Style::default().fg(theme.dim)),
+                ])
+            })
+            .collect()
+    };
+    if let Some(name) = menu.naming {
+        lines.push(Line::default());
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("Save \"{}\" as: ", state.home_filter.query.trim()),
+                Style::default().fg(theme.label),
+            ),
+            Span::styled(format_input_with_cursor(name), Style::default().fg(theme.text)),
+        ]));
+    }
+    f.render_widget(Paragraph::new(lines), chunks[0]);
+
+    let help = if menu.naming.is_some() {
+        "Enter: Save | Esc: Cancel"
+    } else {
+        "↑/↓: Select | Enter: Apply | s: Save Current Filter | d: Delete | Esc: Back"
+    };
+    f.render_widget(
+        Paragraph::new(Line::from(Span::styled(help, Style::default().fg(theme.dim))))
+            .alignment(ratatui::layout::Alignment::Center),
+        chunks[1],
+    );
+}
+
 /// Renders the home screen showing all available daily logs
 pub fn render_home_screen(
     f: &mut Frame,
@@ -21,41 +121,169 @@ pub fn render_home_screen(
 
     // Render title with sync status
     let title = format!("Mountains - A Trail Running Training Log {}", sync_status);
-    render_title(f, chunks[0], &title);
+    render_title(f, chunks[0], &title, &state.theme);
+
+    if state.all_daily_logs().is_empty() {
+        render_first_run(f, chunks[1], state);
+        render_help(
+            f,
+            chunks[2],
+            &[
+                " Enter: Create Today's Log | a: Add Past Date | v: Calendar | S: Startup Screen | q: Quit",
+                " Enter: Today | a: Add | S: Startup | q: Quit",
+            ],
+            true,
+            false,
+            &state.theme,
+        );
+        return;
+    }
 
-    // Create the list of daily logs
-    let items: Vec<ListItem> = if state.daily_logs.is_empty() {
-        vec![ListItem::new(
-            "No training logs yet. Press Enter to create one for today.",
-        )]
+    // Create the list of daily logs, under month headers when sorted by date
+    let rows = state.home_list();
+    let matching: Vec<&DailyLog> = state
+        .home_rows()
+        .into_iter()
+        .map(|pos| &state.all_daily_logs()[pos])
+        .collect();
+    let shown: Vec<&DailyLog> = rows
+        .iter()
+        .filter_map(|row| match row {
+            HomeRow::Day(pos) => state.all_daily_logs().get(*pos),
+            HomeRow::Month { .. } => None,
+        })
+        .collect();
+    let items: Vec<ListItem> = if rows.is_empty() {
+        vec![ListItem::new("No days match the filter. Press / to change it.")]
     } else {
-        state
-            .daily_logs
-            .iter()
-            .map(|log| {
-                let date_str = log.date.format("%B %d, %Y").to_string();
-                ListItem::new(date_str)
+        let theme = &state.theme;
+        let indent = if state.home_filter.sort.groups_by_month() {
+            "  "
+        } else {
+            ""
+        };
+        // Archived days are listed but don't count toward the month
+        let counted: Vec<&DailyLog> = matching.iter().copied().filter(|log| !log.archived).collect();
+        let totals = month_totals(&counted);
+        let mut summaries = summary_rows(&shown, state.units).into_iter();
+        let mut shown_logs = shown.iter();
+        // Only make room for activity tags once a day has one
+        let tagged = shown.iter().any(|log| log.activity.is_some());
+        rows.iter()
+            .map(|row| match *row {
+                HomeRow::Month { month, collapsed } => ListItem::new(Line::from(Span::styled(
+                    month_header(
+                        month,
+                        collapsed,
+                        totals.get(&month).copied().unwrap_or_default(),
+                        state.units,
+                    ),
+                    Style::default()
+                        .fg(theme.highlight)
+                        .add_modifier(Modifier::BOLD),
+                ))),
+                HomeRow::Day(_) => {
+                    let (date, badges) = summaries.next().unwrap_or_default();
+                    let log = shown_logs.next();
+                    let activity = log.and_then(|log| log.activity);
+                    let archived = log.is_some_and(|log| log.archived);
+                    let rest_day = log.is_some_and(|log| log.rest_day);
+                    // Rest days are set apart in italics, so they don't read
+                    // as days that just weren't logged
+                    let date_style = match (archived, rest_day) {
+                        (true, _) => Style::default().fg(theme.dim),
+                        (false, true) => Style::default()
+                            .fg(theme.good)
+                            .add_modifier(Modifier::ITALIC),
+                        (false, false) => Style::default().fg(theme.text),
+                    };
+                    let mut spans = vec![Span::styled(format!("{}{}", indent, date), date_style)];
+                    if tagged {
+                        spans.push(Span::raw(" "));
+                        spans.push(match activity {
+                            Some(kind) => Span::styled(
+                                format!("{:<8}", kind.label()),
+                                Style::default().fg(theme.activity(kind)),
+                            ),
+                            None => Span::raw(" ".repeat(8)),
+                        });
+                    }
+                    if archived {
+                        spans.push(Span::styled(badges, Style::default().fg(theme.dim)));
+                        spans.push(Span::styled(" (archived)", Style::default().fg(theme.dim)));
+                    } else {
+                        spans.push(Span::styled(badges, Style::default().fg(theme.label)));
+                    }
+                    if rest_day {
+                        spans.push(Span::styled(
+                            " rest day",
+                            Style::default().fg(theme.good).add_modifier(Modifier::ITALIC),
+                        ));
+                    }
+                    ListItem::new(Line::from(spans))
+                }
             })
             .collect()
     };
 
     // Create the List widget with styling
+    let filter = &state.home_filter;
+    let title = if state.current_screen == AppScreen::HomeFilter {
+        format!("Daily Training Logs - filter: {}_", filter.query)
+    } else if filter.is_active() {
+        let query = filter.query.trim();
+        let query = if query.is_empty() { "none" } else { query };
+        format!(
+            "Daily Training Logs - filter: {} - {} of {}, {}",
+            query,
+            matching.len(),
+            state.daily_logs().len(),
+            filter.sort.label()
+        )
+    } else {
+        "Daily Training Logs".to_string()
+    };
+    let title = if filter.show_archived {
+        format!("{} - showing archived", title)
+    } else {
+        title
+    };
+    // The month of the highlighted day gets a summary strip under the list
+    let selected_day = list_state
+        .selected()
+        .and_then(|index| match rows.get(index) {
+            Some(HomeRow::Day(pos)) => state.all_daily_logs().get(*pos),
+            _ => None,
+        });
+    let list_area = match selected_day {
+        Some(log) => {
+            let split = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(0), Constraint::Length(1)])
+                .split(chunks[1]);
+            f.render_widget(month_footer(log.date, state), split[1]);
+            split[0]
+        }
+        None => chunks[1],
+    };
+
     let block = Block::default()
         .borders(Borders::ALL)
-        .title("Daily Training Logs")
+        .title(title)
         .padding(ratatui::widgets::Padding::uniform(1));
-    let list_inner = block.inner(chunks[1]);
+    let list_inner = block.inner(list_area);
     let list = List::new(items)
         .block(block)
-        .highlight_style(create_highlight_style());
+        .highlight_style(create_highlight_style())
+        .highlight_symbol(selection_symbol(state.screen_reader));
 
-    f.render_stateful_widget(list, chunks[1], list_state);
+    f.render_stateful_widget(list, list_area, list_state);
 
     if let Some(click_targets) = click_targets {
         let first_visible = list_state.offset();
         for row in 0..list_inner.height as usize {
             let index = first_visible + row;
-            if index >= state.daily_logs.len() {
+            if index >= rows.len() {
                 break;
             }
             click_targets.push(ClickTarget::new(
@@ -71,33 +299,233 @@ pub fn render_home_screen(
     }
 
     // Render help text
+    let help_tiers: &[&str] = if state.current_screen == AppScreen::HomeFilter {
+        &[
+            " Filter by vert, vert>=1500, miles<10, sokay, notes, food or any word | Enter: Keep | Esc: Clear",
+            " vert, miles<10, sokay, notes, words | Enter: Keep | Esc: Clear",
+            " Enter: Keep | Esc: Clear",
+        ]
+    } else {
+        &[
+            " ↑/k: Up | ↓/j: Down | Enter: Select/Today | a: Add Date | v: Calendar | /: Filter | V: Views | o: Sort | z/Z: Fold Month/All | Esc: Unfocus | d: Delete Day | x: Archive | A: Show Archived | H: Heatmap | Y: Year | C: Charts | W: Week | M: Month | S: Startup Screen | q: Quit",
+            " ↑/k: Up | ↓/j: Down | Enter: Select | a: Add | v: Calendar | /: Filter | V: Views | o: Sort | z: Fold | Esc: Unfocus | d: Delete | H: Heatmap | S: Startup | q: Quit",
+            " ↑↓/jk: Move | Enter: Select | a: Add | v: Calendar | /: Filter | d: Delete | S: Startup | q: Quit",
+            " jk: Move | Enter: Select | a: Add | q: Quit",
+        ]
+    };
     render_help(
         f,
         chunks[2],
-        &[
-            " ↑/k: Up | ↓/j: Down | Enter: Select/Today | a: Add Date | Esc: Unfocus | d: Delete Day | S: Startup Screen | q: Quit",
-            " ↑/k: Up | ↓/j: Down | Enter: Select | a: Add | Esc: Unfocus | d: Delete | S: Startup | q: Quit",
-            " ↑↓/jk: Move | Enter: Select | a: Add | d: Delete | S: Startup | q: Quit",
-            " jk: Move | Enter: Select | a: Add | q: Quit",
-        ],
+        help_tiers,
         true,
         false,
+        &state.theme,
     );
 }
 
+/// The logged days list before anything is logged: a box in the middle of
+/// `area` inviting Enter to start today's log.
+fn render_first_run(f: &mut Frame, area: ratatui::layout::Rect, state: &AppState) {
+    let theme = &state.theme;
+    let outer = Block::default()
+        .borders(Borders::ALL)
+        .title("Daily Training Logs");
+    let inner = outer.inner(area);
+    f.render_widget(outer, area);
+
+    let lines = vec![
+        Line::from(Span::styled(
+            "No training logs yet",
+            Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
+        )),
+        Line::default(),
+        Line::from(vec![
+            Span::styled("Press ", Style::default().fg(theme.text)),
+            Span::styled(
+                " Enter ",
+                Style::default()
+                    .fg(theme.highlight)
+                    .add_modifier(Modifier::BOLD | Modifier::REVERSED),
+            ),
+            Span::styled(" to create today's log", Style::default().fg(theme.text)),
+        ]),
+        Line::default(),
+        Line::from(Span::styled(
+            "Weight, miles, vert, food and notes all live in a day's log.",
+            Style::default().fg(theme.dim),
+        )),
+        Line::from(Span::styled(
+            "Catching up? a adds a past date, v picks one from a calendar.",
+            Style::default().fg(theme.dim),
+        )),
+    ];
+    let width = (lines.iter().map(Line::width).max().unwrap_or(0) as u16 + 6).min(inner.width);
+    let height = (lines.len() as u16 + 4).min(inner.height);
+    let card = ratatui::layout::Rect::new(
+        inner.x + (inner.width - width) / 2,
+        inner.y + (inner.height - height) / 2,
+        width,
+        height,
+    );
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(ratatui::widgets::BorderType::Rounded)
+        .border_style(Style::default().fg(theme.highlight))
+        .padding(ratatui::widgets::Padding::uniform(1));
+    f.render_widget(
+        Paragraph::new(lines)
+            .alignment(ratatui::layout::Alignment::Center)
+            .block(block),
+        card,
+    );
+}
+
+/// Distance, vert and day count of each month's days in `logs`.
+fn month_totals(logs: &[&DailyLog]) -> HashMap<NaiveDate, (f32, i32, usize)> {
+    let mut totals: HashMap<NaiveDate, (f32, i32, usize)> = HashMap::new();
+    for log in logs {
+        let entry = totals.entry(month_start(log.date)).or_default();
+        entry.0 += log.miles_covered.unwrap_or(0.0);
+        entry.1 += log.elevation_gain.unwrap_or(0);
+        entry.2 += 1;
+    }
+    totals
+}
+
+/// "▾ October 2024 — 142 mi / 21300 ft", with the day count when collapsed.
+fn month_header(
+    month: NaiveDate,
+    collapsed: bool,
+    (miles, vert, days): (f32, i32, usize),
+    units: Units,
+) -> String {
+    let mut header = format!(
+        "{} {} — {} / {}",
+        if collapsed { "▸" } else { "▾" },
+        month.format("%B %Y"),
+        units.fixed(Quantity::Distance, miles as f64, 0),
+        units.total(Quantity::Elevation, vert as f64)
+    );
+    if collapsed {
+        header.push_str(&format!(
+            " ({} {})",
+            days,
+            if days == 1 { "day" } else { "days" }
+        ));
+    }
+    header
+}
+
+/// "March 2024 — 120.4 mi · 15000 ft · 22 days logged" for the month
+/// containing `date`, from the cached totals. Unlike the month headers it
+/// ignores the filter: it's the whole month.
+fn month_footer(date: NaiveDate, state: &AppState) -> Paragraph<'static> {
+    let theme = &state.theme;
+    let logs = state.daily_logs();
+    let month = Period::month_of(date);
+    let days = state.stats.days_logged(logs, month);
+    let vert_days = state.stats.vert_days(logs, date);
+    let units = state.units;
+    Paragraph::new(Line::from(vec![
+        Span::styled(
+            format!(" {} — ", date.format("%B %Y")),
+            Style::default()
+                .fg(theme.highlight)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!(
+                "{} · {} · {} {} logged · {} vert {}",
+                units.format(Quantity::Distance, state.stats.miles(logs, month)),
+                units.total(Quantity::Elevation, state.stats.elevation(logs, month) as f64),
+                days,
+                if days == 1 { "day" } else { "days" },
+                vert_days,
+                if vert_days == 1 { "day" } else { "days" }
+            ),
+            Style::default().fg(theme.text),
+        ),
+    ]))
+}
+
+/// Each log's date and its badges ("6.2 mi · 1450 ft · 3 foods · 1 sokay"),
+/// padded so every badge lines up in a column. A column only appears when
+/// some day has a value for it, and a day without one gets blank space.
+fn summary_rows(logs: &[&DailyLog], units: Units) -> Vec<(String, String)> {
+    let plural = |count: usize, one: &str, many: &str| match count {
+        0 => String::new(),
+        1 => format!("1 {}", one),
+        n => format!("{} {}", n, many),
+    };
+    let columns: Vec<[String; 4]> = logs
+        .iter()
+        .map(|log| {
+            [
+                log.miles_covered
+                    .map(|miles| units.format(Quantity::Distance, miles))
+                    .unwrap_or_default(),
+                log.elevation_gain
+                    .map(|feet| units.total(Quantity::Elevation, feet as f64))
+                    .unwrap_or_default(),
+                plural(log.food_entries.len(), "food", "foods"),
+                plural(log.sokay_entries.len(), "sokay", "sokay"),
+            ]
+        })
+        .collect();
+    let mut widths = [0; 4];
+    for row in &columns {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let date_width = logs
+        .iter()
+        .map(|log| units.date(log.date).chars().count())
+        .max()
+        .unwrap_or(0);
+
+    logs.iter()
+        .zip(columns)
+        .map(|(log, row)| {
+            let date = format!("{:<date_width$}", units.date(log.date));
+            let mut badges = String::new();
+            let mut previous_blank = true;
+            for (column, (cell, width)) in row.iter().zip(widths).enumerate() {
+                if width == 0 {
+                    continue;
+                }
+                // Dots only between two badges, so blanks stay blank
+                let joined = !previous_blank && !cell.is_empty();
+                badges.push_str(if joined { " · " } else { "   " });
+                // Distance and vert line up on the right, counts on the left
+                if column < 2 {
+                    badges.push_str(&format!("{:>width$}", cell));
+                } else {
+                    badges.push_str(&format!("{:<width$}", cell));
+                }
+                previous_blank = cell.is_empty();
+            }
+            (date, badges.trim_end().to_string())
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::units::UnitSystem;
     use chrono::NaiveDate;
     use ratatui::{Terminal, backend::TestBackend};
 
     #[test]
     fn click_targets_follow_the_stateful_lists_scroll_offset() {
         let mut state = AppState::new();
-        state.daily_logs = (1..=10)
-            .rev()
-            .map(|day| crate::models::DailyLog::new(NaiveDate::from_ymd_opt(2026, 7, day).unwrap()))
-            .collect();
+        state.set_daily_logs(
+            (1..=10)
+                .rev()
+                .map(|day| crate::models::DailyLog::new(NaiveDate::from_ymd_opt(2026, 7, day).unwrap()))
+                .collect(),
+        );
         let mut list_state = ListState::default();
         list_state.select(Some(7));
         let backend = TestBackend::new(80, 16);
@@ -125,6 +553,112 @@ mod tests {
         );
     }
 
+    #[test]
+    fn a_highlighted_day_shows_its_whole_month_in_the_footer() {
+        let day = |m, d, miles| crate::models::DailyLog {
+            miles_covered: Some(miles),
+            elevation_gain: Some(1500),
+            ..crate::models::DailyLog::new(NaiveDate::from_ymd_opt(2026, m, d).unwrap())
+        };
+        let mut state = AppState::new();
+        state.set_daily_logs(vec![day(8, 2, 3.0), day(7, 20, 6.5), day(7, 4, 4.0)]);
+        let render = |list_state: &mut ListState| {
+            let mut terminal = Terminal::new(TestBackend::new(100, 20)).unwrap();
+            terminal
+                .draw(|frame| render_home_screen(frame, &state, list_state, "", None))
+                .unwrap();
+            let text: String = terminal
+                .backend()
+                .buffer()
+                .content
+                .iter()
+                .map(|cell| cell.symbol())
+                .collect();
+            text
+        };
+
+        // Rows: August header, Aug 2, July header, Jul 20, Jul 4
+        let mut list_state = ListState::default();
+        assert!(!render(&mut list_state).contains("days logged"));
+        list_state.select(Some(3));
+        assert!(render(&mut list_state).contains("July 2026 — 10.5 mi · 3,000 ft · 2 days logged · 2 vert days"));
+        // The header of a month isn't a day
+        list_state.select(Some(2));
+        assert!(!render(&mut list_state).contains("days logged"));
+    }
+
+    #[test]
+    fn first_run_invites_enter_instead_of_an_empty_list() {
+        let mut terminal = Terminal::new(TestBackend::new(100, 24)).unwrap();
+        let mut targets = Vec::new();
+        terminal
+            .draw(|frame| {
+                render_home_screen(
+                    frame,
+                    &AppState::new(),
+                    &mut ListState::default(),
+                    "",
+                    Some(&mut targets),
+                );
+            })
+            .unwrap();
+        let text: String = terminal
+            .backend()
+            .buffer()
+            .content
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(text.contains("No training logs yet"));
+        assert!(text.contains(" Enter  to create today's log"));
+        assert!(text.contains("Enter: Create Today's Log"));
+        assert!(targets.is_empty());
+    }
+
+    #[test]
+    fn summary_badges_line_up_in_columns() {
+        let day = |d| NaiveDate::from_ymd_opt(2024, 11, d).unwrap();
+        let mut long = DailyLog::new(day(3));
+        long.miles_covered = Some(16.25);
+        long.elevation_gain = Some(4200);
+        long.add_food_entry(crate::models::FoodEntry::new("Oats".to_string()));
+        long.add_sokay_entry("Chips".to_string());
+        let mut short = DailyLog::new(day(2));
+        short.miles_covered = Some(6.2);
+        short.elevation_gain = Some(450);
+        for food in ["Oats", "Rice", "Beans"] {
+            short.add_food_entry(crate::models::FoodEntry::new(food.to_string()));
+        }
+        let rest = DailyLog::new(day(1));
+
+        let rows = summary_rows(&[&long, &short, &rest], UnitSystem::Imperial.into());
+        assert_eq!(rows[0].0, "November 03, 2024");
+        assert_eq!(rows[0].1, "   16.25 mi · 4,200 ft · 1 food  · 1 sokay");
+        assert_eq!(rows[1].1, "     6.2 mi ·   450 ft · 3 foods");
+        assert_eq!(rows[2].1, "");
+    }
+
+    #[test]
+    fn month_headers_total_the_months_days() {
+        let day = |d| NaiveDate::from_ymd_opt(2024, 10, d).unwrap();
+        let mut long = DailyLog::new(day(12));
+        long.miles_covered = Some(20.4);
+        long.elevation_gain = Some(5100);
+        let mut short = DailyLog::new(day(3));
+        short.miles_covered = Some(6.0);
+        short.elevation_gain = Some(800);
+
+        let totals = month_totals(&[&long, &short])[&day(1)];
+        assert_eq!(
+            month_header(day(1), false, totals, UnitSystem::Imperial.into()),
+            "▾ October 2024 — 26 mi / 5,900 ft"
+        );
+        assert_eq!(
+            month_header(day(1), true, totals, UnitSystem::Imperial.into()),
+            "▸ October 2024 — 26 mi / 5,900 ft (2 days)"
+        );
+    }
+
     #[test]
     fn empty_list_placeholder_is_not_clickable() {
         let backend = TestBackend::new(80, 20);
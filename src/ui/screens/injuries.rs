@@ -0,0 +1,188 @@
+use chrono::Local;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Padding, Paragraph},
+};
+
+use crate::injuries::InjuryDraft;
+use crate::models::AppState;
+use crate::ui::components::format_input_with_cursor;
+use crate::units::Quantity;
+
+/// Rows each injury takes: what hurt and when, how long and a gap.
+const INJURY_HEIGHT: usize = 3;
+
+/// Renders every injury, latest first, with how long it lasted and the
+/// running done on it. `draft` is the injury being logged or changed and
+/// what's typed so far, if any.
+pub fn render_injuries_screen(
+    f: &mut Frame,
+    state: &AppState,
+    selected: usize,
+    draft: Option<(InjuryDraft, &str)>,
+) {
+    let theme = &state.theme;
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme.border))
+        .title(" Injuries ")
+        .title_style(Style::default().fg(theme.text).add_modifier(Modifier::BOLD))
+        .padding(Padding::new(2, 2, 1, 1));
+    let inner_area = block.inner(f.area());
+    f.render_widget(block, f.area());
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(0),
+            Constraint::Length(if draft.is_some() { 2 } else { 0 }),
+            Constraint::Length(1),
+        ])
+        .split(inner_area);
+
+    let today = Local::now().date_naive();
+    let injuries = &state.injuries;
+    let mut lines = Vec::new();
+    if injuries.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "No injuries logged. Press 'a' when something starts to hurt.",
+            Style::default().fg(theme.dim),
+        )));
+    }
+    // Keep the selected injury on screen when they don't all fit
+    let selected = selected.min(injuries.len().saturating_sub(1));
+    let fits = (chunks[0].height as usize / INJURY_HEIGHT).max(1);
+    let first = (selected + 1).saturating_sub(fits);
+    for (index, injury) in injuries.iter().enumerate().skip(first).take(fits) {
+        let is_selected = index == selected;
+        let name_style = if is_selected {
+            Style::default()
+                .fg(theme.highlight)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme.text)
+        };
+        let mut spans = vec![Span::styled(
+            format!(
+                "{}{}",
+                if is_selected { "► " } else { "  " },
+                injury.label(state.units)
+            ),
+            name_style,
+        )];
+        if injury.resolved.is_none() {
+            spans.push(Span::styled(" (active)", Style::default().fg(theme.error)));
+        }
+        lines.push(Line::from(spans));
+        let days = injury.days(today);
+        let miles = injury.miles_during(today, |date| state.counted_daily_log(date));
+        lines.push(Line::from(Span::styled(
+            format!(
+                "    {} {}, {} run",
+                days,
+                if days == 1 { "day" } else { "days" },
+                state.units.total(Quantity::Distance, miles)
+            ),
+            Style::default().fg(theme.dim),
+        )));
+        lines.push(Line::default());
+    }
+    f.render_widget(Paragraph::new(lines), chunks[0]);
+
+    if let Some((draft, typed)) = draft {
+        f.render_widget(
+            Paragraph::new(Line::from(vec![
+                Span::styled(
+                    format!("{}: body part, from ... to ...: ", draft.severity.title()),
+                    Style::default().fg(theme.label),
+                ),
+                Span::styled(format_input_with_cursor(typed), Style::default().fg(theme.text)),
+            ])),
+            chunks[1],
+        );
+    }
+
+    let help_spans = match draft {
+        Some(_) => vec![
+            Span::styled("Enter", Style::default().fg(theme.highlight)),
+            Span::styled(": Save | ", Style::default().fg(theme.text)),
+            Span::styled("Tab", Style::default().fg(theme.highlight)),
+            Span::styled(": Severity | ", Style::default().fg(theme.text)),
+            Span::styled("Esc", Style::default().fg(theme.highlight)),
+            Span::styled(": Cancel", Style::default().fg(theme.text)),
+        ],
+        None => vec![
+            Span::styled("j/k", Style::default().fg(theme.highlight)),
+            Span::styled(": Select | ", Style::default().fg(theme.text)),
+            Span::styled("a", Style::default().fg(theme.highlight)),
+            Span::styled(": Add | ", Style::default().fg(theme.text)),
+            Span::styled("e", Style::default().fg(theme.highlight)),
+            Span::styled(": Edit | ", Style::default().fg(theme.text)),
+            Span::styled("r", Style::default().fg(theme.highlight)),
+            Span::styled(": Resolve | ", Style::default().fg(theme.text)),
+            Span::styled("d", Style::default().fg(theme.highlight)),
+            Span::styled(": Delete | ", Style::default().fg(theme.text)),
+            Span::styled("Esc", Style::default().fg(theme.highlight)),
+            Span::styled(": Back", Style::default().fg(theme.text)),
+        ],
+    };
+    f.render_widget(
+        Paragraph::new(Line::from(help_spans)).alignment(ratatui::layout::Alignment::Center),
+        chunks[2],
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::injuries::{Injury, Severity};
+    use crate::models::DailyLog;
+    use chrono::Days;
+    use ratatui::{Terminal, backend::TestBackend};
+
+    #[test]
+    fn injuries_render_with_their_days_and_running() {
+        let today = Local::now().date_naive();
+        let mut state = AppState::new();
+        state.set_daily_logs(vec![DailyLog {
+            miles_covered: Some(3.5),
+            ..DailyLog::new(today)
+        }]);
+        state.injuries = vec![
+            Injury {
+                id: 2,
+                body_part: "Left knee".to_string(),
+                severity: Severity::Moderate,
+                start: today - Days::new(4),
+                resolved: None,
+            },
+            Injury {
+                id: 1,
+                body_part: "Right calf".to_string(),
+                severity: Severity::Mild,
+                start: today - Days::new(40),
+                resolved: Some(today - Days::new(31)),
+            },
+        ];
+        let mut terminal = Terminal::new(TestBackend::new(120, 20)).unwrap();
+        terminal
+            .draw(|frame| render_injuries_screen(frame, &state, 0, None))
+            .unwrap();
+        let text: String = terminal
+            .backend()
+            .buffer()
+            .content
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+
+        assert!(text.contains("► Left knee (moderate), since"));
+        assert!(text.contains("(active)"));
+        assert!(text.contains("5 days, 3.5 mi run"));
+        assert!(text.contains("10 days, 0.0 mi run"));
+    }
+}
@@ -1,10 +1,36 @@
-use ratatui::{Frame, style::Color, widgets::ListState};
-
-use crate::models::AppState;
-use crate::ui::modals::{render_input_modal, InputModalConfig};
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, ListState, Paragraph},
+};
+
+use crate::models::{AppState, Reflection};
+use crate::models::backfill::{Backfill, BackfillField};
+use crate::models::food_form::{FoodField, FoodForm};
+use crate::models::field_accessor::FieldType;
+use crate::ui::components::{centered_rect, create_input_style};
+use crate::ui::modals::{render_input_modal, render_suggestions, InputModalConfig};
+use crate::ui::theme::Theme;
+use crate::units::Quantity;
 use super::daily_view::render_daily_view_screen;
 use super::home::render_home_screen;
 
+/// What the food form shows: the stored fields, plus the focused one as
+/// it's being typed and any frequent foods matching it.
+pub struct FoodFormView<'a> {
+    pub form: &'a FoodForm,
+    pub input_buffer: &'a str,
+    pub cursor_position: usize,
+    pub suggestions: &'a [String],
+    pub suggestion_selected: Option<usize>,
+    /// Editing a saved entry and the form no longer matches it.
+    pub changed: bool,
+    /// A barcode typed as the name is being looked up.
+    pub looking_up: bool,
+}
+
 /// Renders the add food entry screen as a centered modal dialog
 pub fn render_add_food_screen(
     f: &mut Frame,
@@ -12,14 +38,15 @@ pub fn render_add_food_screen(
     food_list_state: &mut ListState,
     sokay_list_state: &mut ListState,
     sync_status: &str,
-    input_buffer: &str,
-    cursor_position: usize,
+    view: FoodFormView,
 ) {
     render_daily_view_screen(f, state, food_list_state, sokay_list_state, sync_status, None, None);
 
-    let title = format!("Add Food - {}", state.selected_date.format("%B %d, %Y"));
-    let config = InputModalConfig::text(title, Color::Yellow);
-    render_input_modal(f, config, input_buffer, cursor_position);
+    let mut title = format!("Add Food - {}", state.units.date(state.selected_date));
+    if view.looking_up {
+        title.push_str(" - Looking up...");
+    }
+    render_food_form(f, title, view, &state.theme);
 }
 
 /// Renders the edit food entry screen as a centered modal dialog
@@ -29,17 +56,105 @@ pub fn render_edit_food_screen(
     food_list_state: &mut ListState,
     sokay_list_state: &mut ListState,
     sync_status: &str,
-    input_buffer: &str,
-    cursor_position: usize,
+    view: FoodFormView,
 ) {
     render_daily_view_screen(f, state, food_list_state, sokay_list_state, sync_status, None, None);
 
-    let title = format!("Edit Food - {}", state.selected_date.format("%B %d, %Y"));
-    let config = InputModalConfig::text(title, Color::Yellow);
-    render_input_modal(f, config, input_buffer, cursor_position);
+    let title = format!("Edit Food - {}", state.units.date(state.selected_date));
+    let title = changed_title(title, view.changed);
+    render_food_form(f, title, view, &state.theme);
+}
+
+/// Width of the field labels, so the values line up.
+const FOOD_LABEL_WIDTH: u16 = 10;
+
+/// The food form: name, quantity and calories on their own rows, the
+/// focused one taking the cursor, and frequent foods under the form while
+/// the name is being typed. Macros from a barcode show under calories.
+fn render_food_form(f: &mut Frame, title: String, view: FoodFormView, theme: &Theme) {
+    let screen = f.area();
+    let width = centered_rect(screen, 50, 100).width;
+    let height = (8 + u16::from(view.form.macros.is_some())).min(screen.height);
+    let popup_area = Rect::new(
+        screen.x + (screen.width - width) / 2,
+        screen.y + (screen.height - height) / 2,
+        width,
+        height,
+    );
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .style(Style::default().fg(theme.sections.food))
+        .padding(ratatui::widgets::Padding {
+            left: 1,
+            right: 1,
+            top: 1,
+            bottom: 0,
+        });
+    let inner = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    let focused = view.form.field;
+    let rows = [
+        (FoodField::Name, "Food:", "name, or a barcode and Enter"),
+        (FoodField::Quantity, "Quantity:", "optional, e.g. 1 cup"),
+        (FoodField::Calories, "Calories:", "optional"),
+    ];
+    let mut lines: Vec<Line> = rows
+        .iter()
+        .map(|&(field, label, placeholder)| {
+            let label = Span::styled(
+                format!("{:<width$}", label, width = FOOD_LABEL_WIDTH as usize),
+                if field == focused {
+                    Style::default()
+                        .fg(theme.highlight)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(theme.label)
+                },
+            );
+            let value = if field == focused {
+                Span::styled(view.input_buffer.to_string(), create_input_style(theme))
+            } else if view.form.value(field).is_empty() {
+                Span::styled(placeholder, Style::default().fg(theme.dim))
+            } else {
+                Span::styled(view.form.value(field).to_string(), Style::default().fg(theme.text))
+            };
+            Line::from(vec![label, value])
+        })
+        .collect();
+    if let Some(macros) = view.form.macros {
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{:<width$}", "Macros:", width = FOOD_LABEL_WIDTH as usize),
+                Style::default().fg(theme.label),
+            ),
+            Span::styled(macros.describe(), Style::default().fg(theme.dim)),
+        ]));
+    }
+    lines.push(Line::default());
+    lines.push(Line::from(Span::styled(
+        "Tab: Next Field | Enter: Save | Esc: Cancel",
+        Style::default().fg(theme.dim),
+    )));
+    f.render_widget(Paragraph::new(lines), inner);
+
+    let row = rows.iter().position(|&(field, _, _)| field == focused).unwrap_or(0) as u16;
+    f.set_cursor_position((
+        inner.x + FOOD_LABEL_WIDTH + view.cursor_position as u16,
+        inner.y + row,
+    ));
+
+    if focused == FoodField::Name {
+        render_suggestions(f, view.suggestions, view.suggestion_selected, popup_area, theme);
+    }
 }
 
-/// Renders the edit strength & mobility screen as a centered modal dialog
+/// Renders the edit strength & mobility screen as a centered modal dialog,
+/// with exercises done before that match the line being typed under it
+#[allow(clippy::too_many_arguments)]
 pub fn render_edit_strength_mobility_screen(
     f: &mut Frame,
     state: &AppState,
@@ -48,15 +163,21 @@ pub fn render_edit_strength_mobility_screen(
     sync_status: &str,
     input_buffer: &str,
     cursor_position: usize,
+    changed: bool,
+    suggestions: &[String],
+    suggestion_selected: Option<usize>,
 ) {
     render_daily_view_screen(f, state, food_list_state, sokay_list_state, sync_status, None, None);
 
-    let title = format!("Edit Strength & Mobility - {}", state.selected_date.format("%B %d, %Y"));
-    let config = InputModalConfig::multiline(title, Color::Cyan);
-    render_input_modal(f, config, input_buffer, cursor_position);
+    let title = format!("Edit Strength & Mobility - {}", state.units.date(state.selected_date));
+    let title = changed_title(title, changed);
+    let config = InputModalConfig::multiline(title, state.theme.sections.strength);
+    let popup_area = render_input_modal(f, config, input_buffer, cursor_position, &state.theme);
+    render_suggestions(f, suggestions, suggestion_selected, popup_area, &state.theme);
 }
 
 /// Renders the edit notes screen as a centered modal dialog
+#[allow(clippy::too_many_arguments)]
 pub fn render_edit_notes_screen(
     f: &mut Frame,
     state: &AppState,
@@ -65,12 +186,102 @@ pub fn render_edit_notes_screen(
     sync_status: &str,
     input_buffer: &str,
     cursor_position: usize,
+    changed: bool,
+) {
+    render_daily_view_screen(f, state, food_list_state, sokay_list_state, sync_status, None, None);
+
+    let title = format!("Edit Notes - {}", state.units.date(state.selected_date));
+    let title = changed_title(title, changed);
+    let config = InputModalConfig::multiline(title, state.theme.sections.notes);
+    render_input_modal(f, config, input_buffer, cursor_position, &state.theme);
+}
+
+/// Renders the input for a value that isn't edited in place (sleep, resting
+/// HR, HRV, run time, RPE, run heart rate) as a modal over the daily view
+#[allow(clippy::too_many_arguments)]
+pub fn render_edit_measurement_screen(
+    f: &mut Frame,
+    state: &AppState,
+    food_list_state: &mut ListState,
+    sokay_list_state: &mut ListState,
+    sync_status: &str,
+    field: FieldType,
+    input_buffer: &str,
+    cursor_position: usize,
+) {
+    render_daily_view_screen(f, state, food_list_state, sokay_list_state, sync_status, None, None);
+
+    let name = match field {
+        FieldType::RestingHr => "Resting Heart Rate (bpm)",
+        FieldType::Hrv => "HRV (ms)",
+        FieldType::Rpe => "Effort (RPE 1-10)",
+        FieldType::AvgHr => "Average Heart Rate (bpm)",
+        FieldType::MaxHr => "Max Heart Rate (bpm)",
+        FieldType::Duration => "Run Time (h:mm:ss)",
+        FieldType::StartTime => "Start Time (24-hour h:mm)",
+        _ => "Hours Slept",
+    };
+    let accent = if matches!(
+        field,
+        FieldType::Duration
+            | FieldType::StartTime
+            | FieldType::Rpe
+            | FieldType::AvgHr
+            | FieldType::MaxHr
+    ) {
+        state.theme.sections.running
+    } else {
+        state.theme.sections.measurements
+    };
+    let title = format!("{} - {}", name, state.units.date(state.selected_date));
+    let config = InputModalConfig::text(title, accent);
+    render_input_modal(f, config, input_buffer, cursor_position, &state.theme);
+}
+
+/// Renders the route input as a modal over the daily view, with routes run
+/// before that match what's typed hanging under it
+#[allow(clippy::too_many_arguments)]
+pub fn render_edit_route_screen(
+    f: &mut Frame,
+    state: &AppState,
+    food_list_state: &mut ListState,
+    sokay_list_state: &mut ListState,
+    sync_status: &str,
+    input_buffer: &str,
+    cursor_position: usize,
+    suggestions: &[String],
+    suggestion_selected: Option<usize>,
+) {
+    render_daily_view_screen(f, state, food_list_state, sokay_list_state, sync_status, None, None);
+
+    let title = format!("Route - {}", state.units.date(state.selected_date));
+    let config = InputModalConfig::text(title, state.theme.sections.running);
+    let popup_area = render_input_modal(f, config, input_buffer, cursor_position, &state.theme);
+    render_suggestions(f, suggestions, suggestion_selected, popup_area, &state.theme);
+}
+
+/// Renders the morning intention or evening gratitude box over the daily
+/// view.
+#[allow(clippy::too_many_arguments)]
+pub fn render_edit_reflection_screen(
+    f: &mut Frame,
+    state: &AppState,
+    food_list_state: &mut ListState,
+    sokay_list_state: &mut ListState,
+    sync_status: &str,
+    reflection: Reflection,
+    input_buffer: &str,
+    cursor_position: usize,
 ) {
     render_daily_view_screen(f, state, food_list_state, sokay_list_state, sync_status, None, None);
 
-    let title = format!("Edit Notes - {}", state.selected_date.format("%B %d, %Y"));
-    let config = InputModalConfig::multiline(title, Color::Green);
-    render_input_modal(f, config, input_buffer, cursor_position);
+    let title = format!(
+        "{} - {}",
+        reflection.label(),
+        state.units.date(state.selected_date)
+    );
+    let config = InputModalConfig::text(title, state.theme.sections.notes);
+    render_input_modal(f, config, input_buffer, cursor_position, &state.theme);
 }
 
 /// Renders the add sokay screen as a centered modal dialog
@@ -85,9 +296,9 @@ pub fn render_add_sokay_screen(
 ) {
     render_daily_view_screen(f, state, food_list_state, sokay_list_state, sync_status, None, None);
 
-    let title = format!("Add Sokay Entry - {}", state.selected_date.format("%B %d, %Y"));
-    let config = InputModalConfig::text(title, Color::Magenta);
-    render_input_modal(f, config, input_buffer, cursor_position);
+    let title = format!("Add Sokay Entry - {}", state.units.date(state.selected_date));
+    let config = InputModalConfig::text(title, state.theme.sections.sokay);
+    render_input_modal(f, config, input_buffer, cursor_position, &state.theme);
 }
 
 /// Renders the date input screen as a modal over the home screen
@@ -102,14 +313,154 @@ pub fn render_date_input_screen(
     render_home_screen(f, state, list_state, sync_status, None);
 
     let (title, color) = match &state.date_input_error {
-        Some(err) => (format!("Add Entry (MM.DD.YYYY) - {}", err), Color::Red),
-        None => ("Add Entry (MM.DD.YYYY)".to_string(), Color::Cyan),
+        Some(err) => (format!("Add Entry (MM.DD.YYYY) - {}", err), state.theme.error),
+        None => ("Add Entry (MM.DD.YYYY)".to_string(), state.theme.border),
     };
     let config = InputModalConfig::text(title, color).with_width_percent(25);
-    render_input_modal(f, config, input_buffer, cursor_position);
+    render_input_modal(f, config, input_buffer, cursor_position, &state.theme);
+}
+
+/// Width of the backfill form's labels, so the values line up.
+const BACKFILL_LABEL_WIDTH: u16 = 14;
+
+/// Renders the backfill wizard over the logged days list: the range to
+/// fill first, then the condensed form for each day in turn.
+pub fn render_backfill_screen(
+    f: &mut Frame,
+    state: &AppState,
+    list_state: &mut ListState,
+    sync_status: &str,
+    backfill: &Backfill,
+    input_buffer: &str,
+    cursor_position: usize,
+) {
+    render_home_screen(f, state, list_state, sync_status, None);
+    let theme = &state.theme;
+    let units = state.units;
+
+    let (title, rows, help) = match (backfill.day, backfill.position()) {
+        (Some(day), Some((index, count))) => (
+            format!("Backfill - {} ({} of {})", units.weekday_date(day), index, count),
+            vec![
+                (BackfillField::Miles, format!("{}:", units.distance_title()), "blank for none"),
+                (
+                    BackfillField::Vert,
+                    format!("Vert ({}):", units.label(Quantity::Elevation)),
+                    "blank for none",
+                ),
+                (
+                    BackfillField::Weight,
+                    format!("Weight ({}):", units.label(Quantity::Weight)),
+                    "blank for none",
+                ),
+                (
+                    BackfillField::Note,
+                    "Note:".to_string(),
+                    if backfill.long_notes {
+                        "added under the day's notes"
+                    } else {
+                        "one line"
+                    },
+                ),
+            ],
+            "Tab: Next Field | Enter: Save & Next Day | Esc: Done",
+        ),
+        _ => (
+            "Backfill".to_string(),
+            vec![
+                (BackfillField::From, "From:".to_string(), "e.g. Jul 1, 2026-07-01 or -14"),
+                (BackfillField::To, "To:".to_string(), "blank for today"),
+            ],
+            "Tab: Next Field | Enter: Start | Esc: Cancel",
+        ),
+    };
+
+    let screen = f.area();
+    let width = centered_rect(screen, 50, 100).width;
+    let height = (rows.len() as u16 + 6).min(screen.height);
+    let popup_area = Rect::new(
+        screen.x + (screen.width - width) / 2,
+        screen.y + (screen.height - height) / 2,
+        width,
+        height,
+    );
+    f.render_widget(Clear, popup_area);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .style(Style::default().fg(theme.border))
+        .padding(ratatui::widgets::Padding {
+            left: 1,
+            right: 1,
+            top: 1,
+            bottom: 0,
+        });
+    let inner = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    let focused = backfill.field;
+    let mut lines: Vec<Line> = rows
+        .iter()
+        .map(|(field, label, placeholder)| {
+            let label = Span::styled(
+                format!("{:<width$}", label, width = BACKFILL_LABEL_WIDTH as usize),
+                if *field == focused {
+                    Style::default()
+                        .fg(theme.highlight)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(theme.label)
+                },
+            );
+            let value = if *field == focused {
+                Span::styled(input_buffer.to_string(), create_input_style(theme))
+            } else if backfill.value(*field).is_empty() {
+                Span::styled(*placeholder, Style::default().fg(theme.dim))
+            } else {
+                Span::styled(backfill.value(*field).to_string(), Style::default().fg(theme.text))
+            };
+            Line::from(vec![label, value])
+        })
+        .collect();
+    lines.push(match &backfill.error {
+        Some(error) => Line::from(Span::styled(error.clone(), Style::default().fg(theme.error))),
+        None => Line::default(),
+    });
+    lines.push(Line::from(Span::styled(help, Style::default().fg(theme.dim))));
+    f.render_widget(Paragraph::new(lines), inner);
+
+    let row = rows.iter().position(|(field, _, _)| *field == focused).unwrap_or(0) as u16;
+    f.set_cursor_position((
+        inner.x + BACKFILL_LABEL_WIDTH + cursor_position as u16,
+        inner.y + row,
+    ));
+}
+
+/// Renders the `g` jump-to-date prompt as a modal over the daily view
+pub fn render_jump_to_date_screen(
+    f: &mut Frame,
+    state: &AppState,
+    food_list_state: &mut ListState,
+    sokay_list_state: &mut ListState,
+    sync_status: &str,
+    input_buffer: &str,
+    cursor_position: usize,
+) {
+    render_daily_view_screen(f, state, food_list_state, sokay_list_state, sync_status, None, None);
+
+    let (title, color) = match &state.date_input_error {
+        Some(err) => (format!("Go to Date - {}", err), state.theme.error),
+        None => (
+            "Go to Date (2024-11-03, Nov 3, yesterday, -3)".to_string(),
+            state.theme.border,
+        ),
+    };
+    let config = InputModalConfig::text(title, color).with_width_percent(40);
+    render_input_modal(f, config, input_buffer, cursor_position, &state.theme);
 }
 
 /// Renders the edit sokay screen as a centered modal dialog
+#[allow(clippy::too_many_arguments)]
 pub fn render_edit_sokay_screen(
     f: &mut Frame,
     state: &AppState,
@@ -118,12 +469,24 @@ pub fn render_edit_sokay_screen(
     sync_status: &str,
     input_buffer: &str,
     cursor_position: usize,
+    changed: bool,
 ) {
     render_daily_view_screen(f, state, food_list_state, sokay_list_state, sync_status, None, None);
 
-    let title = format!("Edit Sokay Entry - {}", state.selected_date.format("%B %d, %Y"));
-    let config = InputModalConfig::text(title, Color::Magenta);
-    render_input_modal(f, config, input_buffer, cursor_position);
+    let title = format!("Edit Sokay Entry - {}", state.units.date(state.selected_date));
+    let title = changed_title(title, changed);
+    let config = InputModalConfig::text(title, state.theme.sections.sokay);
+    render_input_modal(f, config, input_buffer, cursor_position, &state.theme);
+}
+
+/// Marks an edit modal's title once what's typed differs from what's
+/// saved, so Esc asking first doesn't come as a surprise.
+fn changed_title(title: String, changed: bool) -> String {
+    if changed {
+        format!("{} (changed)", title)
+    } else {
+        title
+    }
 }
 
 /// Wraps text at word boundaries to fit within a given width
@@ -516,4 +879,70 @@ mod tests {
             (2, 0)
         );
     }
+
+    // -- properties ----------------------------------------------------------
+
+    mod properties {
+        use super::*;
+        use proptest::prelude::*;
+
+        /// Short words, long unbroken runs, multibyte chars, tabs and newlines,
+        /// so both the word-wrap and hard-break paths get exercised.
+        fn text() -> impl Strategy<Value = String> {
+            "[a-zé \\t\\n]{0,80}"
+        }
+
+        /// Byte offsets of every char boundary in `text`, end included.
+        fn char_boundaries(text: &str) -> Vec<usize> {
+            text.char_indices().map(|(i, _)| i).chain([text.len()]).collect()
+        }
+
+        proptest! {
+            #[test]
+            fn no_wrapped_line_exceeds_width(text in text(), width in 1usize..24) {
+                for line in wrap_at_width(&text, width).split('\n') {
+                    prop_assert!(line.chars().count() <= width, "{:?} exceeds {}", line, width);
+                }
+            }
+
+            #[test]
+            fn wrapping_only_inserts_newlines(text in text(), width in 1usize..24) {
+                let wrapped = wrap_at_width(&text, width);
+                let strip = |s: &str| s.chars().filter(|&c| c != '\n').collect::<String>();
+                prop_assert_eq!(strip(&wrapped), strip(&text));
+                prop_assert!(wrapped.matches('\n').count() >= text.matches('\n').count());
+            }
+
+            #[test]
+            fn cursor_lands_within_rendered_text(
+                text in text(),
+                width in 1usize..24,
+                pick in any::<prop::sample::Index>(),
+            ) {
+                let lines: Vec<String> = wrap_at_width(&text, width)
+                    .split('\n')
+                    .map(str::to_string)
+                    .collect();
+                let boundaries = char_boundaries(&text);
+                let cursor = boundaries[pick.index(boundaries.len())];
+                let area = Rect { x: 3, y: 5, width: width as u16, height: 40 };
+
+                let (x, y) = calculate_cursor_in_wrapped_text(area, &text, cursor, width);
+                let (col, line) = ((x - area.x) as usize, (y - area.y) as usize);
+                prop_assert!(line < lines.len(), "line {} of {:?}", line, lines);
+                prop_assert!(col <= lines[line].chars().count(), "col {} on {:?}", col, lines[line]);
+            }
+
+            #[test]
+            fn cursor_at_end_matches_last_wrapped_line(text in text(), width in 1usize..24) {
+                let wrapped = wrap_at_width(&text, width);
+                let last = wrapped.rsplit('\n').next().unwrap_or_default();
+                let expected = (last.chars().count() as u16, wrapped.matches('\n').count() as u16);
+                prop_assert_eq!(
+                    calculate_cursor_in_wrapped_text(origin(24, 40), &text, text.len(), width),
+                    expected
+                );
+            }
+        }
+    }
 }
@@ -0,0 +1,58 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Padding, Paragraph},
+};
+
+use crate::lock::AppLock;
+use crate::ui::components::centered_rect;
+use crate::ui::theme::Theme;
+
+/// Renders the passphrase prompt. Nothing from the log is drawn behind it,
+/// and the passphrase shows as one `*` per character.
+pub fn render_lock_screen(f: &mut Frame, lock: &AppLock, theme: &Theme) {
+    let area = centered_rect(f.area(), 50, 30);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme.border))
+        .title(" Mountains is locked ")
+        .title_style(Style::default().fg(theme.text).add_modifier(Modifier::BOLD))
+        .padding(Padding::new(2, 2, 1, 1));
+    let inner_area = block.inner(area);
+    f.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(inner_area);
+
+    let mut lines = vec![
+        Line::from(Span::styled("Passphrase", Style::default().fg(theme.label))),
+        Line::from(Span::styled(
+            format!("{}_", "*".repeat(lock.input.chars().count())),
+            Style::default().fg(theme.text),
+        )),
+    ];
+    if lock.failed {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "Wrong passphrase",
+            Style::default().fg(theme.error),
+        )));
+    }
+    f.render_widget(Paragraph::new(lines), chunks[0]);
+
+    let help_spans = vec![
+        Span::styled("Enter", Style::default().fg(theme.highlight)),
+        Span::styled(": Unlock | ", Style::default().fg(theme.text)),
+        Span::styled("Esc", Style::default().fg(theme.highlight)),
+        Span::styled(": Quit", Style::default().fg(theme.text)),
+    ];
+    f.render_widget(
+        Paragraph::new(Line::from(help_spans)).alignment(Alignment::Center),
+        chunks[1],
+    );
+}
@@ -0,0 +1,41 @@
+use ratatui::{
+    Frame,
+    style::Style,
+    widgets::{Block, Borders, Paragraph},
+};
+
+use crate::ui::components::{create_standard_layout, render_help, render_title};
+use crate::ui::theme::Theme;
+
+/// Rows of the manual visible in a frame `frame_height` tall: the standard
+/// layout's margin, title and help rows, then the body's border.
+pub fn manual_page_height(frame_height: u16) -> u16 {
+    frame_height.saturating_sub(12).max(1)
+}
+
+/// Renders the F1 manual, scrolled down `scroll` lines.
+pub fn render_manual_screen(f: &mut Frame, lines: &[String], scroll: u16, theme: &Theme) {
+    let chunks = create_standard_layout(f.area());
+    render_title(f, chunks[0], "Manual", theme);
+
+    let text: Vec<ratatui::text::Line> = lines.iter().map(|line| line.as_str().into()).collect();
+    let manual = Paragraph::new(text).scroll((scroll, 0)).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.border))
+            .padding(ratatui::widgets::Padding::horizontal(1)),
+    );
+    f.render_widget(manual, chunks[1]);
+
+    render_help(
+        f,
+        chunks[2],
+        &[
+            " j/k: Scroll | PgUp/PgDn: Page | Esc/F1: Close",
+            " j/k: Scroll | Esc: Close",
+        ],
+        true,
+        true,
+        theme,
+    );
+}
@@ -6,20 +6,46 @@ pub mod inputs;
 pub mod confirmations;
 pub mod help;
 pub mod config_sync;
+pub mod heatmap;
+pub mod charts;
+pub mod week;
+pub mod month;
+pub mod manual;
+pub mod settings;
+pub mod privacy;
+pub mod lock;
+pub mod calendar;
+pub mod year;
+pub mod food_lookup;
+pub mod save_errors;
+pub mod gear;
+pub mod routes;
+pub mod photo_food;
+pub mod best_efforts;
+pub mod goals;
+pub mod phases;
+pub mod injuries;
+pub mod strength;
 
 // Re-export all public functions for backward compatibility
 pub use startup::render_startup_screen;
 pub use statistics::render_statistics_screen;
-pub use home::render_home_screen;
+pub use home::{ViewsMenu, render_home_screen, render_home_views_screen};
 pub use daily_view::{max_scroll_offset, render_daily_view_screen, InPlaceEdit};
 pub use inputs::{
+    FoodFormView,
     render_add_food_screen,
     render_edit_food_screen,
     render_edit_strength_mobility_screen,
     render_edit_notes_screen,
+    render_edit_measurement_screen,
     render_add_sokay_screen,
+    render_edit_route_screen,
+    render_edit_reflection_screen,
     render_edit_sokay_screen,
     render_date_input_screen,
+    render_backfill_screen,
+    render_jump_to_date_screen,
     wrap_at_width,
     calculate_cursor_in_wrapped_text,
 };
@@ -27,9 +53,33 @@ pub use confirmations::{
     render_confirm_delete_day_screen,
     render_confirm_delete_food_screen,
     render_confirm_delete_sokay_screen,
+    render_confirm_value_screen,
+    render_discard_confirmation,
+    render_quit_confirmation,
 };
 pub use help::{
     render_shortcuts_help_screen,
     render_syncing_screen,
+    spinner_frame,
 };
 pub use config_sync::render_config_sync_screen;
+pub use heatmap::render_heatmap_screen;
+pub use charts::render_charts_screen;
+pub use week::render_week_screen;
+pub use month::render_month_screen;
+pub use manual::{manual_page_height, render_manual_screen};
+pub use settings::render_settings_screen;
+pub use privacy::render_privacy_screen;
+pub use lock::render_lock_screen;
+pub use calendar::render_calendar_screen;
+pub use year::render_year_screen;
+pub use food_lookup::render_food_lookup_screen;
+pub use save_errors::render_save_errors_screen;
+pub use gear::{render_gear_picker_screen, render_gear_screen};
+pub use routes::render_routes_screen;
+pub use best_efforts::render_best_efforts_screen;
+pub use goals::render_goals_screen;
+pub use phases::render_phases_screen;
+pub use injuries::render_injuries_screen;
+pub use strength::render_strength_screen;
+pub use photo_food::{render_ocr_image_screen, render_ocr_review_screen};
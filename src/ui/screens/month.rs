@@ -0,0 +1,133 @@
+use chrono::NaiveDate;
+use ratatui::{
+    Frame,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+};
+
+use crate::models::AppState;
+use crate::month_stats::summarize_month;
+use crate::ui::components::{create_standard_layout, render_help, render_title};
+use crate::units::Quantity;
+
+/// Renders the month report for the month containing `month`: training and
+/// body totals gathered in one place.
+pub fn render_month_screen(f: &mut Frame, state: &AppState, month: NaiveDate) {
+    let theme = &state.theme;
+    let chunks = create_standard_layout(f.area());
+    let vert_threshold = state.stats.config().vert_threshold;
+    let summary = summarize_month(state.daily_logs(), month, vert_threshold);
+    render_title(
+        f,
+        chunks[0],
+        &format!("Monthly Summary - {}", summary.month.format("%B %Y")),
+        theme,
+    );
+
+    let label = Style::default().fg(theme.label);
+    let value = Style::default()
+        .fg(theme.highlight)
+        .add_modifier(Modifier::BOLD);
+    let row = |name: &str, text: String| {
+        Line::from(vec![
+            Span::styled(format!("{:<20}", name), label),
+            Span::styled(text, value),
+        ])
+    };
+
+    let units = state.units;
+    let lines = vec![
+        row("Days Logged", summary.days_logged.to_string()),
+        Line::from(""),
+        row(
+            &format!("Total {}", units.distance_title()),
+            units.number(Quantity::Distance, summary.miles as f64),
+        ),
+        row(
+            "Total Vert",
+            units.total(Quantity::Elevation, summary.elevation as f64),
+        ),
+        row(
+            &format!(
+                "{} {} Days",
+                units.vert_threshold(vert_threshold),
+                units.label(Quantity::Elevation)
+            ),
+            summary.vert_days.to_string(),
+        ),
+        row(
+            "Longest Run",
+            summary
+                .longest_run
+                .map_or("-".to_string(), |(date, miles)| {
+                    format!(
+                        "{} on {}",
+                        units.total(Quantity::Distance, miles as f64),
+                        units.month_day(date)
+                    )
+                }),
+        ),
+        Line::from(""),
+        row(
+            "Average Weight",
+            summary.average_weight.map_or("-".to_string(), |weight| {
+                units.total(Quantity::Weight, weight as f64)
+            }),
+        ),
+        row("Sokay Entries", summary.sokay_count.to_string()),
+    ];
+    let report = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.border))
+            .padding(ratatui::widgets::Padding::uniform(1)),
+    );
+    f.render_widget(report, chunks[1]);
+
+    render_help(
+        f,
+        chunks[2],
+        &[
+            " [/]: Previous/Next Month | Esc: Back",
+            " [/]: Month | Esc: Back",
+        ],
+        true,
+        true,
+        theme,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::DailyLog;
+    use ratatui::{Terminal, backend::TestBackend};
+
+    #[test]
+    fn renders_the_months_report() {
+        let date = NaiveDate::from_ymd_opt(2026, 7, 18).unwrap();
+        let mut state = AppState::new();
+        state.set_daily_logs(vec![DailyLog {
+            miles_covered: Some(12.4),
+            elevation_gain: Some(3100),
+            ..DailyLog::new(date)
+        }]);
+
+        let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+        terminal
+            .draw(|frame| render_month_screen(frame, &state, date))
+            .unwrap();
+        let text: String = terminal
+            .backend()
+            .buffer()
+            .content
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+
+        assert!(text.contains("Monthly Summary - July 2026"));
+        assert!(text.contains("12.4 mi on Jul 18"));
+        assert!(text.contains("3,100 ft"));
+    }
+}
@@ -0,0 +1,200 @@
+use chrono::Local;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Padding, Paragraph},
+};
+
+use crate::format::Units;
+use crate::models::AppState;
+use crate::phases::{Phase, PhaseDraft, PhaseTotals};
+use crate::ui::components::format_input_with_cursor;
+use crate::units::Quantity;
+
+/// Rows each phase takes: its range, its totals and a gap.
+const PHASE_HEIGHT: usize = 3;
+
+/// Renders every phase, earliest first, with the running done in it so far.
+/// `draft` is the phase being marked or changed and the range typed so far,
+/// if any.
+pub fn render_phases_screen(
+    f: &mut Frame,
+    state: &AppState,
+    selected: usize,
+    draft: Option<(PhaseDraft, &str)>,
+) {
+    let theme = &state.theme;
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme.sections.running))
+        .title(" Phases ")
+        .title_style(Style::default().fg(theme.text).add_modifier(Modifier::BOLD))
+        .padding(Padding::new(2, 2, 1, 1));
+    let inner_area = block.inner(f.area());
+    f.render_widget(block, f.area());
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(0),
+            Constraint::Length(if draft.is_some() { 2 } else { 0 }),
+            Constraint::Length(1),
+        ])
+        .split(inner_area);
+
+    let today = Local::now().date_naive();
+    let phases = &state.phases;
+    let mut lines = Vec::new();
+    if phases.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "No phases yet. Press 'a' to mark one.",
+            Style::default().fg(theme.dim),
+        )));
+    }
+    // Keep the selected phase on screen when they don't all fit
+    let selected = selected.min(phases.len().saturating_sub(1));
+    let fits = (chunks[0].height as usize / PHASE_HEIGHT).max(1);
+    let first = (selected + 1).saturating_sub(fits);
+    for (index, phase) in phases.iter().enumerate().skip(first).take(fits) {
+        let is_selected = index == selected;
+        let name_style = if is_selected {
+            Style::default()
+                .fg(theme.highlight)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme.text)
+        };
+        let mut spans = vec![
+            Span::styled(
+                format!(
+                    "{}{}",
+                    if is_selected { "► " } else { "  " },
+                    phase.label(state.units)
+                ),
+                name_style,
+            ),
+            Span::styled(format!(" ({} days)", phase.days()), Style::default().fg(theme.dim)),
+        ];
+        if phase.contains(today) {
+            spans.push(Span::styled(" (now)", Style::default().fg(theme.good)));
+        }
+        lines.push(Line::from(spans));
+        let totals = phase.totals(today, |date| state.counted_daily_log(date));
+        lines.push(Line::from(Span::styled(
+            format!("    {}", describe_totals(phase, &totals, state.units)),
+            Style::default().fg(theme.dim),
+        )));
+        lines.push(Line::default());
+    }
+    f.render_widget(Paragraph::new(lines), chunks[0]);
+
+    if let Some((draft, typed)) = draft {
+        f.render_widget(
+            Paragraph::new(Line::from(vec![
+                Span::styled(
+                    format!("{} from ... to ...: ", draft.kind.title()),
+                    Style::default().fg(theme.label),
+                ),
+                Span::styled(format_input_with_cursor(typed), Style::default().fg(theme.text)),
+            ])),
+            chunks[1],
+        );
+    }
+
+    let help_spans = match draft {
+        Some(_) => vec![
+            Span::styled("Enter", Style::default().fg(theme.highlight)),
+            Span::styled(": Save | ", Style::default().fg(theme.text)),
+            Span::styled("Tab", Style::default().fg(theme.highlight)),
+            Span::styled(": Kind | ", Style::default().fg(theme.text)),
+            Span::styled("Esc", Style::default().fg(theme.highlight)),
+            Span::styled(": Cancel", Style::default().fg(theme.text)),
+        ],
+        None => vec![
+            Span::styled("j/k", Style::default().fg(theme.highlight)),
+            Span::styled(": Select | ", Style::default().fg(theme.text)),
+            Span::styled("a", Style::default().fg(theme.highlight)),
+            Span::styled(": Add | ", Style::default().fg(theme.text)),
+            Span::styled("e", Style::default().fg(theme.highlight)),
+            Span::styled(": Edit | ", Style::default().fg(theme.text)),
+            Span::styled("d", Style::default().fg(theme.highlight)),
+            Span::styled(": Delete | ", Style::default().fg(theme.text)),
+            Span::styled("Esc", Style::default().fg(theme.highlight)),
+            Span::styled(": Back", Style::default().fg(theme.text)),
+        ],
+    };
+    f.render_widget(
+        Paragraph::new(Line::from(help_spans)).alignment(ratatui::layout::Alignment::Center),
+        chunks[2],
+    );
+}
+
+/// "12 of 46 days: 142.3 mi, 18,400 ft, 9 days run, 23.7 mi a week", or
+/// that it hasn't started.
+fn describe_totals(phase: &Phase, totals: &PhaseTotals, units: Units) -> String {
+    let Some(weekly) = totals.weekly_miles() else {
+        return "Not started yet".to_string();
+    };
+    format!(
+        "{} of {} days: {}, {}, {} {} run, {} a week",
+        totals.elapsed_days,
+        phase.days(),
+        units.total(Quantity::Distance, totals.miles),
+        units.total(Quantity::Elevation, totals.vert as f64),
+        totals.days_run,
+        if totals.days_run == 1 { "day" } else { "days" },
+        units.total(Quantity::Distance, weekly)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::DailyLog;
+    use crate::phases::PhaseKind;
+    use chrono::Days;
+    use ratatui::{Terminal, backend::TestBackend};
+
+    #[test]
+    fn phases_render_with_their_totals_so_far() {
+        let today = Local::now().date_naive();
+        let mut state = AppState::new();
+        state.set_daily_logs(vec![DailyLog {
+            miles_covered: Some(7.0),
+            elevation_gain: Some(1200),
+            ..DailyLog::new(today)
+        }]);
+        state.phases = vec![
+            Phase {
+                id: 1,
+                kind: PhaseKind::Build,
+                start: today - Days::new(6),
+                end: today + Days::new(7),
+            },
+            Phase {
+                id: 2,
+                kind: PhaseKind::Taper,
+                start: today + Days::new(8),
+                end: today + Days::new(21),
+            },
+        ];
+        let mut terminal = Terminal::new(TestBackend::new(120, 20)).unwrap();
+        terminal
+            .draw(|frame| render_phases_screen(frame, &state, 0, None))
+            .unwrap();
+        let text: String = terminal
+            .backend()
+            .buffer()
+            .content
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+
+        assert!(text.contains("(14 days) (now)"));
+        assert!(text.contains("7 of 14 days: 7.0 mi, 1,200 ft, 1 day run, 7.0 mi a week"));
+        assert!(text.contains("Not started yet"));
+    }
+}
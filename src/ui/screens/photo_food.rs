@@ -0,0 +1,101 @@
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, ListState, Padding, Paragraph},
+};
+
+use super::daily_view::render_daily_view_screen;
+use crate::models::AppState;
+use crate::ui::components::centered_rect;
+use crate::ui::modals::{InputModalConfig, render_input_modal};
+
+/// Renders the photo path input as a modal over the daily view, saying so
+/// while the OCR command is `reading` the photo
+#[allow(clippy::too_many_arguments)]
+pub fn render_ocr_image_screen(
+    f: &mut Frame,
+    state: &AppState,
+    food_list_state: &mut ListState,
+    sokay_list_state: &mut ListState,
+    sync_status: &str,
+    input_buffer: &str,
+    cursor_position: usize,
+    reading: bool,
+) {
+    render_daily_view_screen(f, state, food_list_state, sokay_list_state, sync_status, None, None);
+
+    let title = if reading {
+        "Reading the photo... (Esc: cancel)".to_string()
+    } else {
+        format!("Food From a Photo (image path) - {}", state.units.date(state.selected_date))
+    };
+    let config = InputModalConfig::text(title, state.theme.sections.food);
+    render_input_modal(f, config, input_buffer, cursor_position, &state.theme);
+}
+
+/// Renders the lines read off a photo as a checklist over the daily view,
+/// the picked ones to be logged as food.
+pub fn render_ocr_review_screen(
+    f: &mut Frame,
+    state: &AppState,
+    food_list_state: &mut ListState,
+    sokay_list_state: &mut ListState,
+    sync_status: &str,
+    candidates: &[(String, bool)],
+    selected: usize,
+) {
+    render_daily_view_screen(f, state, food_list_state, sokay_list_state, sync_status, None, None);
+    let theme = &state.theme;
+
+    let area = centered_rect(f.area(), 60, 60);
+    f.render_widget(Clear, area);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(" Food From the Photo ")
+        .border_style(Style::default().fg(theme.sections.food))
+        .padding(Padding::horizontal(1));
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(inner);
+
+    // Keep the selection on screen by dropping the lines above it
+    let fits = (chunks[0].height as usize).max(1);
+    let skip = (selected + 1).saturating_sub(fits);
+    let lines: Vec<Line> = candidates
+        .iter()
+        .enumerate()
+        .skip(skip)
+        .map(|(index, (food, picked))| {
+            let text = format!(
+                "{}[{}] {}",
+                if index == selected { "► " } else { "  " },
+                if *picked { "x" } else { " " },
+                food
+            );
+            let style = if index == selected {
+                Style::default()
+                    .fg(theme.highlight)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.text)
+            };
+            Line::from(Span::styled(text, style))
+        })
+        .collect();
+    f.render_widget(Paragraph::new(lines), chunks[0]);
+
+    f.render_widget(
+        Paragraph::new(Line::from(Span::styled(
+            "↑/↓: Select | Space: Pick | a: All | Enter: Log Picked | Esc: Cancel",
+            Style::default().fg(theme.dim),
+        )))
+        .alignment(ratatui::layout::Alignment::Center),
+        chunks[1],
+    );
+}
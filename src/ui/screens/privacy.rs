@@ -0,0 +1,12 @@
+use ratatui::{Frame, style::Style, widgets::Paragraph};
+
+use crate::ui::theme::Theme;
+
+/// What F9 shows in place of the app: an idle shell prompt, with nothing on
+/// screen to say what's behind it.
+pub fn render_privacy_screen(f: &mut Frame, theme: &Theme) {
+    f.render_widget(
+        Paragraph::new("$ ").style(Style::default().fg(theme.label)),
+        f.area(),
+    );
+}
@@ -0,0 +1,117 @@
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Padding, Paragraph},
+};
+
+use crate::models::AppState;
+use crate::routes::{self, RouteStats};
+use crate::format::Units;
+use crate::units::Quantity;
+
+/// Renders every route run, most run first, with its runs, distance, vert
+/// and last run.
+pub fn render_routes_screen(f: &mut Frame, state: &AppState, selected: usize) {
+    let theme = &state.theme;
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme.sections.running))
+        .title(" Routes ")
+        .title_style(Style::default().fg(theme.text).add_modifier(Modifier::BOLD))
+        .padding(Padding::new(2, 2, 1, 1));
+    let inner_area = block.inner(f.area());
+    f.render_widget(block, f.area());
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(inner_area);
+
+    let routes = routes::route_stats(state.daily_logs());
+    let lines: Vec<Line> = if routes.is_empty() {
+        vec![Line::from(Span::styled(
+            "No routes yet. Press 'R' on a day to name the route you ran.",
+            Style::default().fg(theme.dim),
+        ))]
+    } else {
+        let selected = selected.min(routes.len() - 1);
+        // Keep the selection on screen by dropping the routes above it
+        let fits = (chunks[0].height as usize).max(1);
+        let skip = (selected + 1).saturating_sub(fits);
+        routes
+            .iter()
+            .enumerate()
+            .skip(skip)
+            .map(|(index, route)| {
+                let name_style = if index == selected {
+                    Style::default()
+                        .fg(theme.highlight)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(theme.text)
+                };
+                Line::from(vec![
+                    Span::styled(
+                        format!("{}{}", if index == selected { "► " } else { "  " }, route.name),
+                        name_style,
+                    ),
+                    Span::styled(
+                        format!(" - {}", summary(route, state.units)),
+                        Style::default().fg(theme.dim),
+                    ),
+                ])
+            })
+            .collect()
+    };
+    f.render_widget(Paragraph::new(lines), chunks[0]);
+
+    let help_spans = vec![
+        Span::styled("j/k", Style::default().fg(theme.highlight)),
+        Span::styled(": Select | ", Style::default().fg(theme.text)),
+        Span::styled("Enter", Style::default().fg(theme.highlight)),
+        Span::styled(": Open Last Run | ", Style::default().fg(theme.text)),
+        Span::styled("Esc", Style::default().fg(theme.highlight)),
+        Span::styled(": Back", Style::default().fg(theme.text)),
+    ];
+    f.render_widget(
+        Paragraph::new(Line::from(help_spans)).alignment(ratatui::layout::Alignment::Center),
+        chunks[1],
+    );
+}
+
+/// "12 runs, 88.0 mi, 33600 ft, last Jul 05, 2026"
+fn summary(route: &RouteStats, units: Units) -> String {
+    format!(
+        "{} {}, {}, {}, last {}",
+        route.runs,
+        if route.runs == 1 { "run" } else { "runs" },
+        units.total(Quantity::Distance, route.miles as f64),
+        units.format(Quantity::Elevation, route.elevation as f32),
+        units.short_date(route.last_run)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::UnitSystem;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn summary_counts_runs_and_totals() {
+        let route = RouteStats {
+            name: "Bear Peak loop".to_string(),
+            runs: 12,
+            miles: 88.0,
+            elevation: 33600,
+            last_run: NaiveDate::from_ymd_opt(2026, 7, 5).unwrap(),
+        };
+        assert_eq!(
+            summary(&route, UnitSystem::Imperial.into()),
+            "12 runs, 88.0 mi, 33,600 ft, last Jul 05, 2026"
+        );
+    }
+}
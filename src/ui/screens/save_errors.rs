@@ -0,0 +1,80 @@
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Padding, Paragraph, Wrap},
+};
+
+use crate::models::AppState;
+use crate::save_errors::FailureKind;
+
+/// Renders the days whose save failed, the selected one highlighted, each
+/// with the error that stopped it.
+pub fn render_save_errors_screen(f: &mut Frame, state: &AppState, selected: usize) {
+    let theme = &state.theme;
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme.error))
+        .title(" Failed Saves ")
+        .title_style(Style::default().fg(theme.text).add_modifier(Modifier::BOLD))
+        .padding(Padding::new(2, 2, 1, 1));
+    let inner_area = block.inner(f.area());
+    f.render_widget(block, f.area());
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(inner_area);
+
+    let mut lines = Vec::new();
+    if state.failed_saves.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "Every change has been saved.",
+            Style::default().fg(theme.good),
+        )));
+    }
+    for (index, failed) in state.failed_saves.iter().enumerate() {
+        let is_selected = index == selected.min(state.failed_saves.len() - 1);
+        let date = state.units.weekday_date(failed.log.date);
+        let tries = match (failed.kind, failed.attempts) {
+            (FailureKind::Transient, attempts) => format!("still locked after {} tries", attempts),
+            (FailureKind::Permanent, _) => "won't fix itself".to_string(),
+        };
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{}{}", if is_selected { "► " } else { "  " }, date),
+                if is_selected {
+                    Style::default()
+                        .fg(theme.highlight)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(theme.text)
+                },
+            ),
+            Span::styled(format!(" ({})", tries), Style::default().fg(theme.dim)),
+        ]));
+        lines.push(Line::from(Span::styled(
+            format!("    {}", failed.error),
+            Style::default().fg(theme.error),
+        )));
+        lines.push(Line::from(""));
+    }
+    f.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), chunks[0]);
+
+    let help_spans = vec![
+        Span::styled("j/k", Style::default().fg(theme.highlight)),
+        Span::styled(": Select | ", Style::default().fg(theme.text)),
+        Span::styled("r", Style::default().fg(theme.highlight)),
+        Span::styled(": Retry | ", Style::default().fg(theme.text)),
+        Span::styled("e", Style::default().fg(theme.highlight)),
+        Span::styled(": Export JSON | ", Style::default().fg(theme.text)),
+        Span::styled("Esc", Style::default().fg(theme.highlight)),
+        Span::styled(": Back", Style::default().fg(theme.text)),
+    ];
+    f.render_widget(
+        Paragraph::new(Line::from(help_spans)).alignment(ratatui::layout::Alignment::Center),
+        chunks[1],
+    );
+}
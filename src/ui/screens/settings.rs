@@ -0,0 +1,74 @@
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Padding, Paragraph},
+};
+
+use super::startup::render_startup_screen;
+use crate::models::AppState;
+use crate::ui::components::centered_rect;
+use crate::ui::theme::ThemeName;
+
+/// Renders the settings popup over the startup screen. `state.theme` is
+/// already the selected theme, so the backdrop previews it.
+pub fn render_settings_screen(f: &mut Frame, state: &AppState, selected: ThemeName) {
+    render_startup_screen(f, state, None);
+    let theme = &state.theme;
+
+    let popup_area = centered_rect(f.area(), 50, 40);
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme.border))
+        .title(" Settings ")
+        .title_style(Style::default().fg(theme.text).add_modifier(Modifier::BOLD))
+        .padding(Padding::new(2, 2, 1, 1));
+    let inner_area = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(inner_area);
+
+    let mut lines = vec![
+        Line::from(Span::styled("Theme", Style::default().fg(theme.label))),
+        Line::from(""),
+    ];
+    for name in ThemeName::ALL {
+        let line = if name == selected {
+            Span::styled(
+                format!("► {}", name.label()),
+                Style::default()
+                    .fg(theme.highlight)
+                    .add_modifier(Modifier::BOLD),
+            )
+        } else {
+            Span::styled(
+                format!("  {}", name.label()),
+                Style::default().fg(theme.text),
+            )
+        };
+        lines.push(Line::from(line));
+    }
+    f.render_widget(Paragraph::new(lines), chunks[0]);
+
+    let help_spans = vec![
+        Span::styled("j/k", Style::default().fg(theme.highlight)),
+        Span::styled(": Theme | ", Style::default().fg(theme.text)),
+        Span::styled("Enter", Style::default().fg(theme.highlight)),
+        Span::styled(": Save | ", Style::default().fg(theme.text)),
+        Span::styled("g", Style::default().fg(theme.highlight)),
+        Span::styled(": Gear | ", Style::default().fg(theme.text)),
+        Span::styled("Esc", Style::default().fg(theme.highlight)),
+        Span::styled(": Cancel", Style::default().fg(theme.text)),
+    ];
+    f.render_widget(
+        Paragraph::new(Line::from(help_spans)).alignment(ratatui::layout::Alignment::Center),
+        chunks[1],
+    );
+}
@@ -1,17 +1,24 @@
+use chrono::Datelike;
 use ratatui::{
     Frame,
-    style::{Color, Modifier, Style},
+    layout::Rect,
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
 };
 
 use crate::assets::APP_TITLE;
-use crate::elevation_stats::{
-    calculate_yearly_elevation, count_monthly_1000_days, get_streak_message,
-};
+use crate::elevation_stats::get_streak_message;
+use crate::goals::{self, Progress};
+use crate::streaks::run_streak_message;
+use crate::milestones;
 use crate::models::AppState;
-use crate::ui::components::{create_standard_layout, render_help};
+use crate::recovery_stats::{RecoveryMetric, trend};
+use crate::stats_cache::Period;
+use crate::training_load::{LoadTrend, training_load};
+use crate::ui::components::{create_standard_layout, render_help, rolling_totals_text};
 use crate::ui::{ClickAction, ClickTarget};
+use crate::units::Quantity;
 
 /// Renders the startup screen with ASCII art and elevation statistics
 pub fn render_startup_screen(
@@ -19,13 +26,18 @@ pub fn render_startup_screen(
     state: &AppState,
     click_targets: Option<&mut Vec<ClickTarget>>,
 ) {
+    let theme = &state.theme;
     let chunks = create_standard_layout(f.area());
 
     // Calculate statistics
     let now = chrono::Local::now().date_naive();
-    let monthly_count = count_monthly_1000_days(&state.daily_logs, now);
-    let yearly_total = calculate_yearly_elevation(&state.daily_logs, now);
-    let streak_message = get_streak_message(&state.daily_logs);
+    let vert_threshold = state.stats.config().vert_threshold;
+    let monthly_count = state.stats.vert_days(state.daily_logs(), now);
+    let yearly_total = state.stats.elevation(state.daily_logs(), Period::year_of(now));
+    let streak_message = get_streak_message(state.stats.current_streak(state.daily_logs()), vert_threshold, state.units);
+    let run_message = run_streak_message(
+        state.stats.run_streak(state.daily_logs()).map(|streak| streak.days),
+    );
 
     // Get current month name and year
     let month_name = now.format("%B").to_string();
@@ -46,7 +58,7 @@ pub fn render_startup_screen(
         content_lines.push(Line::from(Span::styled(
             line,
             Style::default()
-                .fg(Color::Cyan)
+                .fg(theme.border)
                 .add_modifier(Modifier::BOLD),
         )));
     }
@@ -56,7 +68,7 @@ pub fn render_startup_screen(
     content_lines.push(Line::from(Span::styled(
         "For mindfulness and motivation on the trails",
         Style::default()
-            .fg(Color::Yellow)
+            .fg(theme.highlight)
             .add_modifier(Modifier::ITALIC),
     )));
 
@@ -65,30 +77,147 @@ pub fn render_startup_screen(
     content_lines.push(Line::from(""));
 
     // Add monthly statistic
+    let units = state.units;
     let monthly_text = format!(
-        "You have {} days of 1000+ feet of vert in the month of {}",
-        monthly_count, month_name
+        "You have {} days of {} {} of vert in the month of {}",
+        monthly_count,
+        units.vert_threshold(vert_threshold),
+        units.name(Quantity::Elevation),
+        month_name
     );
     content_lines.push(Line::from(Span::styled(
         monthly_text,
-        Style::default().fg(Color::White),
+        Style::default().fg(theme.text),
     )));
 
-    // Add yearly statistic
+    // Add yearly statistic, against the goal when there is one
     content_lines.push(Line::from(""));
-    let yearly_text = format!("You have {} feet for {}", yearly_total, year);
+    match state.goals.yearly_vert.filter(|&feet| feet > 0) {
+        Some(goal) => {
+            let year_start = now.with_ordinal(1).unwrap_or(now);
+            let days_in_year = if now.leap_year() { 366 } else { 365 };
+            let progress = Progress::new(
+                yearly_total as f64,
+                goal as f64,
+                year_start,
+                days_in_year,
+                now,
+            );
+            content_lines.push(Line::from(Span::styled(
+                format!(
+                    "You have {} {} for {}, {:.0}% of your {} goal",
+                    units.number(Quantity::Elevation, yearly_total as f64),
+                    units.name(Quantity::Elevation),
+                    year,
+                    progress.done / progress.target * 100.0,
+                    units.total(Quantity::Elevation, progress.target)
+                ),
+                Style::default().fg(theme.text),
+            )));
+            content_lines.push(Line::from(Span::styled(
+                progress.projection(units, Quantity::Elevation),
+                Style::default().fg(if progress.on_pace() {
+                    theme.good
+                } else {
+                    theme.warning
+                }),
+            )));
+        }
+        None => content_lines.push(Line::from(Span::styled(
+            format!(
+                "You have {} {} for {}",
+                units.number(Quantity::Elevation, yearly_total as f64),
+                units.name(Quantity::Elevation),
+                year
+            ),
+            Style::default().fg(theme.text),
+        ))),
+    }
+
+    // Add how the goals from the goals screen stand
+    let week_start = state.stats.config().week_start;
+    if let Some((summary, all_on_track)) =
+        goals::summary(&state.goal_list, state.daily_logs(), now, week_start, units)
+    {
+        content_lines.push(Line::from(Span::styled(
+            summary,
+            Style::default().fg(if all_on_track { theme.good } else { theme.warning }),
+        )));
+    }
+
+    // Add rolling weekly and monthly volume
     content_lines.push(Line::from(Span::styled(
-        yearly_text,
-        Style::default().fg(Color::White),
+        rolling_totals_text(state, now),
+        Style::default().fg(theme.text),
     )));
 
     // Add streak message
     content_lines.push(Line::from(""));
     content_lines.push(Line::from(Span::styled(
         streak_message,
-        Style::default().fg(Color::Green),
+        Style::default().fg(theme.good),
+    )));
+    content_lines.push(Line::from(Span::styled(
+        run_message,
+        Style::default().fg(theme.good),
     )));
 
+    // Add lifetime milestone countdowns
+    let (recent_miles, recent_elevation) = state.stats.recent(state.daily_logs(), now);
+    let projections = milestones::project(
+        state.stats.lifetime(state.daily_logs()),
+        recent_miles,
+        recent_elevation,
+        units,
+        now,
+    );
+    if !projections.is_empty() {
+        content_lines.push(Line::from(""));
+    }
+    for projection in projections {
+        content_lines.push(Line::from(Span::styled(
+            format!("Next up: {}", projection.describe(units)),
+            Style::default().fg(theme.label),
+        )));
+    }
+
+    // Add this morning's heart numbers, with a warning when they're off
+    let trends: Vec<_> = [RecoveryMetric::RestingHr, RecoveryMetric::Hrv]
+        .into_iter()
+        .filter_map(|metric| trend(state.daily_logs(), metric, now))
+        .collect();
+    if !trends.is_empty() {
+        content_lines.push(Line::from(""));
+        content_lines.push(Line::from(Span::styled(
+            trends
+                .iter()
+                .map(|trend| trend.describe())
+                .collect::<Vec<_>>()
+                .join("   "),
+            Style::default().fg(theme.label),
+        )));
+    }
+    for warning in trends.iter().filter_map(|trend| trend.warning()) {
+        content_lines.push(Line::from(Span::styled(
+            format!("⚠ {}", warning),
+            Style::default().fg(theme.warning),
+        )));
+    }
+
+    // Add training load, flagged when it's climbing too fast
+    if let Some(load) = training_load(state.daily_logs(), now) {
+        let color = if load.trend() == LoadTrend::Overreaching {
+            theme.warning
+        } else {
+            theme.label
+        };
+        content_lines.push(Line::from(""));
+        content_lines.push(Line::from(Span::styled(
+            load.describe(),
+            Style::default().fg(color),
+        )));
+    }
+
     // Render the content in the main area (centered)
     let content = Paragraph::new(content_lines)
         .block(Block::default().borders(Borders::NONE))
@@ -96,17 +225,42 @@ pub fn render_startup_screen(
 
     f.render_widget(content, chunks[1]);
 
+    if let Some(banner) = &state.health_banner {
+        let banner_area = ratatui::layout::Rect {
+            height: 1.min(chunks[1].height),
+            ..chunks[1]
+        };
+        let line = Line::from(vec![
+            Span::styled(
+                format!("⚠ {}", banner),
+                Style::default()
+                    .fg(theme.warning)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled("  x: Dismiss", Style::default().fg(theme.dim)),
+        ]);
+        f.render_widget(
+            Paragraph::new(line).alignment(ratatui::layout::Alignment::Center),
+            banner_area,
+        );
+    }
+
+    if !state.sync_configured {
+        render_offline_hint(f, chunks[1], state);
+    }
+
     // Render help text without border for clean appearance, centered horizontally
     let help_regions = render_help(
         f,
         chunks[2],
         &[
-            " n: Today's Log | l: Log List | a: Add Past Entry | s: Statistics | c: Cloud Sync | q: Quit ",
-            " n: Today | l: List | s: Stats | a: Add | q: Quit ",
+            " n: Today's Log | l: Log List | a: Add Past Entry | s: Statistics | H: Heatmap | Y: Year | C: Charts | c: Cloud Sync | ,: Settings | q: Quit ",
+            " n: Today | l: List | s: Stats | H: Heatmap | a: Add | q: Quit ",
             " n: Today | s: Stats | q: Quit ",
         ],
         false,
         true,
+        theme,
     );
 
     if let Some(click_targets) = click_targets {
@@ -116,6 +270,8 @@ pub fn render_startup_screen(
                 "l" => Some(ClickAction::StartupLogs),
                 "a" => Some(ClickAction::StartupAddDate),
                 "s" => Some(ClickAction::OpenStatistics),
+                "H" => Some(ClickAction::OpenHeatmap),
+                "C" => Some(ClickAction::OpenCharts),
                 "c" => Some(ClickAction::OpenCloudSync),
                 "q" => Some(ClickAction::Quit),
                 _ => None,
@@ -127,6 +283,36 @@ pub fn render_startup_screen(
     }
 }
 
+/// One line along the bottom of `area` for when cloud sync isn't set up:
+/// everything still works, but only on this computer.
+fn render_offline_hint(f: &mut Frame, area: Rect, state: &AppState) {
+    let theme = &state.theme;
+    if area.height == 0 {
+        return;
+    }
+    let hint_area = Rect {
+        y: area.bottom() - 1,
+        height: 1,
+        ..area
+    };
+    let line = Line::from(vec![
+        Span::styled("Offline only", Style::default().fg(theme.warning)),
+        Span::styled(
+            " - logs are saved on this computer. Press ",
+            Style::default().fg(theme.dim),
+        ),
+        Span::styled("c", Style::default().fg(theme.highlight)),
+        Span::styled(
+            " to sync them through Turso.",
+            Style::default().fg(theme.dim),
+        ),
+    ]);
+    f.render_widget(
+        Paragraph::new(line).alignment(ratatui::layout::Alignment::Center),
+        hint_area,
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -144,6 +330,116 @@ mod tests {
         targets
     }
 
+    #[test]
+    fn shows_milestone_countdowns_at_the_recent_pace() {
+        let today = chrono::Local::now().date_naive();
+        let mut state = AppState::new();
+        state.set_daily_logs(vec![crate::models::DailyLog {
+            miles_covered: Some(180.0),
+            elevation_gain: Some(9_000),
+            ..crate::models::DailyLog::new(today)
+        }]);
+        let mut terminal = Terminal::new(TestBackend::new(120, 40)).unwrap();
+        terminal
+            .draw(|frame| render_startup_screen(frame, &state, None))
+            .unwrap();
+        let text: String = terminal
+            .backend()
+            .buffer()
+            .content
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+
+        assert!(text.contains("Next up: 250 lifetime miles in 35 days"));
+        assert!(text.contains("Next up: 10,000 lifetime feet in 10 days"));
+        assert!(text.contains("Last 7 days: 180.0 mi, 9,000 ft | Last 28 days: 180.0 mi, 9,000 ft"));
+        assert!(text.contains("Fitness 257 | Fatigue 1543 | Form -1286 (overreaching)"));
+    }
+
+    #[test]
+    fn a_yearly_vert_goal_replaces_the_bare_total() {
+        let today = chrono::Local::now().date_naive();
+        let mut state = AppState::new();
+        state.set_daily_logs(vec![crate::models::DailyLog {
+            elevation_gain: Some(4_500),
+            ..crate::models::DailyLog::new(today)
+        }]);
+        let render = |state: &AppState| {
+            let mut terminal = Terminal::new(TestBackend::new(120, 40)).unwrap();
+            terminal
+                .draw(|frame| render_startup_screen(frame, state, None))
+                .unwrap();
+            terminal
+                .backend()
+                .buffer()
+                .content
+                .iter()
+                .map(|cell| cell.symbol())
+                .collect::<String>()
+        };
+        let year = today.format("%Y");
+        assert!(render(&state).contains(&format!("You have 4,500 feet for {}  ", year)));
+
+        state.goals.yearly_vert = Some(9_000);
+        let text = render(&state);
+        assert!(text.contains(&format!(
+            "You have 4,500 feet for {}, 50% of your 9,000 ft goal",
+            year
+        )));
+        state.goals.yearly_vert = Some(4_000);
+        assert!(render(&state).contains("Goal met"));
+    }
+
+    #[test]
+    fn a_raised_resting_heart_rate_is_flagged() {
+        let today = chrono::Local::now().date_naive();
+        let mut state = AppState::new();
+        state.set_daily_logs(
+            [(0, 55), (1, 47), (2, 48)]
+                .into_iter()
+                .map(|(days_ago, bpm)| crate::models::DailyLog {
+                    resting_hr: Some(bpm),
+                    ..crate::models::DailyLog::new(today - chrono::Days::new(days_ago))
+                })
+                .collect(),
+        );
+        let mut terminal = Terminal::new(TestBackend::new(120, 40)).unwrap();
+        terminal
+            .draw(|frame| render_startup_screen(frame, &state, None))
+            .unwrap();
+        let text: String = terminal
+            .backend()
+            .buffer()
+            .content
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(text.contains("Resting HR 55 bpm (7-day avg 48)"));
+        assert!(text.contains("Resting HR is 8 bpm above your 7-day average"));
+    }
+
+    #[test]
+    fn offline_hint_shows_until_sync_is_configured() {
+        let render = |state: &AppState| {
+            let mut terminal = Terminal::new(TestBackend::new(120, 40)).unwrap();
+            terminal
+                .draw(|frame| render_startup_screen(frame, state, None))
+                .unwrap();
+            terminal
+                .backend()
+                .buffer()
+                .content
+                .iter()
+                .map(|cell| cell.symbol())
+                .collect::<String>()
+        };
+        let mut state = AppState::new();
+        assert!(render(&state).contains("Offline only - logs are saved on this computer."));
+        state.sync_configured = true;
+        assert!(!render(&state).contains("Offline only"));
+    }
+
     #[test]
     fn statistics_is_clickable_in_wide_and_narrow_footer_tiers() {
         for width in [120, 40] {
@@ -1,19 +1,31 @@
 use chrono::{Datelike, Days, NaiveDate};
 use ratatui::{
     Frame,
-    style::{Color, Modifier, Style},
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph, Wrap},
 };
 
-use crate::elevation_stats::{
-    calculate_monthly_elevation, calculate_weekly_elevation, calculate_yearly_elevation,
-    count_monthly_1000_days, get_streak_message,
+use crate::activity_stats::{ActivityTotals, totals_by_activity};
+use crate::elevation_stats::get_streak_message;
+use crate::energy_stats::{
+    ENERGY_WINDOW_DAYS, EnergyByMileage, describe_average, describe_correlation,
+    energy_by_mileage,
 };
-use crate::miles_stats::{calculate_monthly_miles, calculate_weekly_miles, calculate_yearly_miles};
+use crate::injuries::{self, YearInjuries};
 use crate::models::AppState;
+use crate::stats_cache::{LifetimeTotals, Period, training_age};
+use crate::streaks::{Streak, StreakRule, StreakSpan};
+use crate::time_of_day::{
+    RunTime, TIME_OF_DAY_WINDOW_DAYS, TimeOfDayComparison, compare_time_of_day,
+};
 use crate::ui::components::{create_standard_layout, render_help, render_title};
+use crate::ui::theme::Theme;
 use crate::ui::{ClickAction, ClickTarget};
+use crate::format::Units;
+use crate::units::{Quantity, UnitSystem};
+use crate::week_stats::{week_number, week_start};
 
 pub fn render_statistics_screen(
     f: &mut Frame,
@@ -21,38 +33,54 @@ pub fn render_statistics_screen(
     reference_date: NaiveDate,
     click_targets: &mut Vec<ClickTarget>,
 ) {
+    let theme = &state.theme;
     let chunks = create_standard_layout(f.area());
     let title = format!(
         "Mountains Statistics - {}",
-        reference_date.format("%B %d, %Y")
+        state.units.date(reference_date)
     );
-    render_title(f, chunks[0], &title);
-
-    let weekly_miles = calculate_weekly_miles(&state.daily_logs, reference_date);
-    let monthly_miles = calculate_monthly_miles(&state.daily_logs, reference_date);
-    let yearly_miles = calculate_yearly_miles(&state.daily_logs, reference_date);
-    let weekly_elevation = calculate_weekly_elevation(&state.daily_logs, reference_date);
-    let monthly_elevation = calculate_monthly_elevation(&state.daily_logs, reference_date);
-    let yearly_elevation = calculate_yearly_elevation(&state.daily_logs, reference_date);
-    let monthly_1000_days = count_monthly_1000_days(&state.daily_logs, reference_date);
-
-    let week = reference_date.iso_week();
-    let monday = reference_date
-        .checked_sub_days(Days::new(
-            reference_date.weekday().num_days_from_monday() as u64
-        ))
-        .unwrap_or(reference_date);
-    let sunday = monday.checked_add_days(Days::new(6)).unwrap_or(monday);
+    render_title(f, chunks[0], &title, theme);
+
+    let logs = state.daily_logs();
+    let config = state.stats.config();
+    let (this_week, this_month, this_year) = (
+        Period::week_of(reference_date, config.week_start),
+        Period::month_of(reference_date),
+        Period::year_of(reference_date),
+    );
+    let weekly_miles = state.stats.miles(logs, this_week);
+    let monthly_miles = state.stats.miles(logs, this_month);
+    let yearly_miles = state.stats.miles(logs, this_year);
+    let weekly_elevation = state.stats.elevation(logs, this_week);
+    let monthly_elevation = state.stats.elevation(logs, this_month);
+    let yearly_elevation = state.stats.elevation(logs, this_year);
+    let vert_days_line = format!(
+        "{} {} days this month: {}",
+        state.units.vert_threshold(config.vert_threshold),
+        state.units.label(Quantity::Elevation),
+        state.stats.vert_days(logs, reference_date)
+    );
+    let streak_message = get_streak_message(
+        state.stats.current_streak(logs),
+        config.vert_threshold,
+        state.units,
+    );
+
+    let first_day = week_start(reference_date, config.week_start);
+    let last_day = first_day
+        .checked_add_days(Days::new(6))
+        .unwrap_or(first_day);
     let week_label = format!(
         "Week {} ({}–{})",
-        week.week(),
-        monday.format("%b %d"),
-        sunday.format("%b %d")
+        week_number(first_day),
+        state.units.month_day(first_day),
+        state.units.month_day(last_day)
     );
     let month_label = reference_date.format("%B %Y").to_string();
     let year_label = reference_date.year().to_string();
 
-    let lines = if chunks[1].height < 12 {
+    let compact = chunks[1].height < 12;
+    let mut lines = if compact {
         compact_lines(
             &week_label,
             &month_label,
@@ -63,8 +91,10 @@ pub fn render_statistics_screen(
             weekly_elevation,
             monthly_elevation,
             yearly_elevation,
-            monthly_1000_days,
-            &get_streak_message(&state.daily_logs),
+            &vert_days_line,
+            &streak_message,
+            state.units,
+            theme,
         )
     } else {
         detailed_lines(
@@ -77,21 +107,103 @@ pub fn render_statistics_screen(
             weekly_elevation,
             monthly_elevation,
             yearly_elevation,
-            monthly_1000_days,
-            &get_streak_message(&state.daily_logs),
+            &vert_days_line,
+            &streak_message,
+            state.units,
+            theme,
         )
     };
 
+    let by_activity = totals_by_activity(logs, reference_date.year());
+    if !by_activity.is_empty() {
+        lines.extend(activity_lines(&by_activity, &year_label, compact, state.units, theme));
+    }
+    if let Some(energy) = energy_by_mileage(logs, reference_date) {
+        lines.extend(energy_lines(energy, compact, theme));
+    }
+    if let Some(comparison) = compare_time_of_day(logs, reference_date) {
+        lines.extend(time_of_day_lines(comparison, compact, state.units, theme));
+    }
+    let year_injuries = injuries::year_injuries(
+        &state.injuries,
+        reference_date.year(),
+        reference_date,
+        |date| state.counted_daily_log(date),
+    );
+    if let Some(year) = year_injuries {
+        let active: Vec<&str> = injuries::active_on(&state.injuries, reference_date)
+            .into_iter()
+            .map(|injury| injury.body_part.as_str())
+            .collect();
+        lines.extend(injury_lines(year, &active, &year_label, compact, state.units, theme));
+    }
+
+    // Lifetime totals and streaks sit in a column beside the period totals,
+    // or side by side under them on a narrow terminal, and are left out when
+    // there's no room for either
+    let area = chunks[1];
+    let (totals_area, side_areas) = if area.width >= 90 {
+        let split = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Min(0), Constraint::Length(LIFETIME_WIDTH)])
+            .split(area);
+        let column = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(LIFETIME_HEIGHT), Constraint::Min(0)])
+            .split(split[1]);
+        (split[0], Some((column[0], column[1])))
+    } else if area.height >= 20 {
+        let split = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(LIFETIME_HEIGHT)])
+            .split(area);
+        let row = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Length(LIFETIME_WIDTH), Constraint::Min(0)])
+            .split(split[1]);
+        (split[0], Some((row[0], row[1])))
+    } else {
+        (area, None)
+    };
+
     let statistics = Paragraph::new(lines)
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Cyan))
+                .border_style(Style::default().fg(theme.border))
                 .title("Activity Totals")
                 .padding(ratatui::widgets::Padding::horizontal(1)),
         )
         .wrap(Wrap { trim: false });
-    f.render_widget(statistics, chunks[1]);
+    f.render_widget(statistics, totals_area);
+    if let Some((lifetime_area, streaks_area)) = side_areas {
+        render_lifetime(
+            f,
+            lifetime_area,
+            state.stats.lifetime(logs),
+            reference_date,
+            state.units,
+            theme,
+        );
+        let mut streaks = state.stats.streaks(logs);
+        streaks.push((StreakRule::run(), state.stats.run_streak(logs)));
+        let mut lines = streak_lines(&streaks, state.units, theme);
+        lines.extend(longest_streak_lines(
+            &state.stats.longest_run_streaks(logs, LONGEST_STREAKS),
+            state.units,
+            theme,
+        ));
+        let panel = Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(theme.border))
+                    .title("Streaks")
+                    .padding(ratatui::widgets::Padding::horizontal(1)),
+            )
+            .wrap(Wrap { trim: false });
+        f.render_widget(panel, streaks_area);
+    }
 
     let help_regions = render_help(
         f,
@@ -99,6 +211,7 @@ pub fn render_statistics_screen(
         &[" Esc: Startup | q: Quit", " Esc: Back | q: Quit"],
         true,
         true,
+        theme,
     );
     for region in help_regions {
         let action = match region.key.as_str() {
@@ -112,6 +225,130 @@ pub fn render_statistics_screen(
     }
 }
 
+const LIFETIME_WIDTH: u16 = 36;
+const LIFETIME_HEIGHT: u16 = 8;
+
+/// The all-time panel: when logging started, how long ago that was, and
+/// every mile and foot since.
+fn render_lifetime(
+    f: &mut Frame,
+    area: Rect,
+    lifetime: LifetimeTotals,
+    today: NaiveDate,
+    units: Units,
+    theme: &Theme,
+) {
+    let label = Style::default().fg(theme.label);
+    let value = Style::default()
+        .fg(theme.highlight)
+        .add_modifier(Modifier::BOLD);
+    let row = |name: &str, text: String| {
+        Line::from(vec![
+            Span::styled(format!("{:<14}", name), label),
+            Span::styled(text, value),
+        ])
+    };
+    let lines = match lifetime.first_log {
+        None => vec![Line::from(Span::styled("Nothing logged yet", label))],
+        Some(first) => {
+            let mut lines = vec![
+                row("Since", units.short_date(first)),
+                row("Training Age", training_age(first, today)),
+                row("Days Logged", lifetime.days_logged.to_string()),
+                row(
+                    units.distance_title(),
+                    units.total(Quantity::Distance, lifetime.miles as f64),
+                ),
+                row(
+                    "Vert",
+                    units.total(Quantity::Elevation, lifetime.elevation as f64),
+                ),
+            ];
+            if let Some(feet_per_mile) = lifetime.vert_per_mile {
+                let (name, per) = match units.system {
+                    UnitSystem::Imperial => ("Vert/Mile", "mi"),
+                    UnitSystem::Metric => ("Vert/Km", "km"),
+                };
+                let grade = units.convert(Quantity::Elevation, feet_per_mile as f64)
+                    / units.convert(Quantity::Distance, 1.0);
+                lines.push(row(
+                    name,
+                    format!("{} {}/{}", units.decimal(grade, 0), units.label(Quantity::Elevation), per),
+                ));
+            }
+            lines
+        }
+    };
+    let panel = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.border))
+            .title("Lifetime")
+            .padding(ratatui::widgets::Padding::horizontal(1)),
+    );
+    f.render_widget(panel, area);
+}
+
+/// A line per streak rule: "5+ mi: 12 days since Jul 10", or "none going".
+fn streak_lines(
+    streaks: &[(StreakRule, Option<Streak>)],
+    units: Units,
+    theme: &Theme,
+) -> Vec<Line<'static>> {
+    streaks
+        .iter()
+        .map(|(rule, streak)| {
+            let status = match streak {
+                Some(streak) => Span::styled(
+                    format!(
+                        "{} {} since {}",
+                        streak.days,
+                        if streak.days == 1 { "day" } else { "days" },
+                        units.month_day(streak.since)
+                    ),
+                    Style::default()
+                        .fg(theme.highlight)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                None => Span::styled("none going", Style::default().fg(theme.dim)),
+            };
+            Line::from(vec![
+                Span::styled(format!("{}: ", rule.label(units)), Style::default().fg(theme.label)),
+                status,
+            ])
+        })
+        .collect()
+}
+
+/// How many of the longest running streaks the Streaks panel lists.
+const LONGEST_STREAKS: usize = 3;
+
+/// The longest running streaks under their own heading, or nothing before
+/// the first one.
+fn longest_streak_lines(
+    spans: &[StreakSpan],
+    units: Units,
+    theme: &Theme,
+) -> Vec<Line<'static>> {
+    if spans.is_empty() {
+        return Vec::new();
+    }
+    let mut lines = vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            "Longest running streaks",
+            Style::default().fg(theme.label),
+        )),
+    ];
+    lines.extend(spans.iter().enumerate().map(|(index, span)| {
+        Line::from(Span::styled(
+            format!("{}. {}", index + 1, span.describe(units)),
+            Style::default().fg(theme.text),
+        ))
+    }));
+    lines
+}
+
 #[allow(clippy::too_many_arguments)]
 fn detailed_lines(
     week_label: &str,
@@ -123,30 +360,32 @@ fn detailed_lines(
     weekly_elevation: i32,
     monthly_elevation: i32,
     yearly_elevation: i32,
-    monthly_1000_days: usize,
+    vert_days_line: &str,
     streak_message: &str,
+    units: Units,
+    theme: &Theme,
 ) -> Vec<Line<'static>> {
     let heading = Style::default()
-        .fg(Color::Yellow)
+        .fg(theme.highlight)
         .add_modifier(Modifier::BOLD);
-    let value = Style::default().fg(Color::White);
+    let value = Style::default().fg(theme.text);
 
     vec![
         Line::from(Span::styled(format!("This Week — {week_label}"), heading)),
-        totals_line(weekly_miles, weekly_elevation, value),
+        totals_line(weekly_miles, weekly_elevation, units, value),
         Line::default(),
         Line::from(Span::styled(format!("This Month — {month_label}"), heading)),
-        totals_line(monthly_miles, monthly_elevation, value),
+        totals_line(monthly_miles, monthly_elevation, units, value),
         Line::default(),
         Line::from(Span::styled(format!("This Year — {year_label}"), heading)),
-        totals_line(yearly_miles, yearly_elevation, value),
+        totals_line(yearly_miles, yearly_elevation, units, value),
         Line::from(Span::styled(
-            format!("1000+ ft days this month: {monthly_1000_days}"),
-            Style::default().fg(Color::LightRed),
+            vert_days_line.to_string(),
+            Style::default().fg(theme.sections.running),
         )),
         Line::from(Span::styled(
             streak_message.to_string(),
-            Style::default().fg(Color::Green),
+            Style::default().fg(theme.good),
         )),
     ]
 }
@@ -162,36 +401,222 @@ fn compact_lines(
     weekly_elevation: i32,
     monthly_elevation: i32,
     yearly_elevation: i32,
-    monthly_1000_days: usize,
+    vert_days_line: &str,
     streak_message: &str,
+    units: Units,
+    theme: &Theme,
 ) -> Vec<Line<'static>> {
-    let value = Style::default().fg(Color::White);
+    let value = Style::default().fg(theme.text);
     vec![
-        compact_totals_line(week_label, weekly_miles, weekly_elevation, value),
-        compact_totals_line(month_label, monthly_miles, monthly_elevation, value),
-        compact_totals_line(year_label, yearly_miles, yearly_elevation, value),
+        compact_totals_line(week_label, weekly_miles, weekly_elevation, units, value),
+        compact_totals_line(month_label, monthly_miles, monthly_elevation, units, value),
+        compact_totals_line(year_label, yearly_miles, yearly_elevation, units, value),
         Line::default(),
         Line::from(Span::styled(
-            format!("1000+ ft days this month: {monthly_1000_days}"),
-            Style::default().fg(Color::LightRed),
+            vert_days_line.to_string(),
+            Style::default().fg(theme.sections.running),
         )),
         Line::from(Span::styled(
             streak_message.to_string(),
-            Style::default().fg(Color::Green),
+            Style::default().fg(theme.good),
+        )),
+    ]
+}
+
+/// The year's distance and vert for each activity type: a line each, or
+/// all on one line when space is short.
+fn activity_lines(
+    totals: &[ActivityTotals],
+    year_label: &str,
+    compact: bool,
+    units: Units,
+    theme: &Theme,
+) -> Vec<Line<'static>> {
+    let label = |total: &ActivityTotals| {
+        total
+            .activity
+            .map(|kind| kind.label())
+            .unwrap_or("Untagged")
+    };
+    let color = |total: &ActivityTotals| {
+        total
+            .activity
+            .map(|kind| theme.activity(kind))
+            .unwrap_or(theme.dim)
+    };
+    if compact {
+        let mut spans = Vec::new();
+        for (i, total) in totals.iter().enumerate() {
+            if i > 0 {
+                spans.push(Span::styled(" · ", Style::default().fg(theme.dim)));
+            }
+            spans.push(Span::styled(
+                format!(
+                    "{} {}",
+                    label(total),
+                    units.total(Quantity::Distance, total.miles as f64)
+                ),
+                Style::default().fg(color(total)),
+            ));
+        }
+        return vec![Line::from(spans)];
+    }
+    let mut lines = vec![
+        Line::default(),
+        Line::from(Span::styled(
+            format!("By Activity — {year_label}"),
+            Style::default()
+                .fg(theme.highlight)
+                .add_modifier(Modifier::BOLD),
+        )),
+    ];
+    for total in totals {
+        lines.push(Line::from(vec![
+            Span::styled(format!("{:<10}", label(total)), Style::default().fg(color(total))),
+            Span::styled(
+                format!(
+                    "{} | {} | {} {}",
+                    units.total(Quantity::Distance, total.miles as f64),
+                    units.total(Quantity::Elevation, total.elevation as f64),
+                    total.days,
+                    if total.days == 1 { "day" } else { "days" }
+                ),
+                Style::default().fg(theme.text),
+            ),
+        ]));
+    }
+    lines
+}
+
+/// Energy on run days against rest days, and how it tracks the miles when
+/// there's room for it.
+fn energy_lines(energy: EnergyByMileage, compact: bool, theme: &Theme) -> Vec<Line<'static>> {
+    let value = Style::default().fg(theme.text);
+    let averages = format!(
+        "{} | {}",
+        describe_average("Run days", energy.run_days),
+        describe_average("Rest days", energy.rest_days)
+    );
+    if compact {
+        return vec![Line::from(Span::styled(format!("Energy - {averages}"), value))];
+    }
+    let mut lines = vec![
+        Line::default(),
+        Line::from(Span::styled(
+            format!("Energy — last {ENERGY_WINDOW_DAYS} days"),
+            Style::default()
+                .fg(theme.highlight)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::styled(averages, value)),
+    ];
+    if let Some(r) = energy.correlation {
+        lines.push(Line::from(Span::styled(
+            format!("Energy vs miles: {}", describe_correlation(r)),
+            Style::default().fg(theme.dim),
+        )));
+    }
+    lines
+}
+
+/// Pace and energy on morning runs against evening ones.
+fn time_of_day_lines(
+    comparison: TimeOfDayComparison,
+    compact: bool,
+    units: Units,
+    theme: &Theme,
+) -> Vec<Line<'static>> {
+    let value = Style::default().fg(theme.text);
+    let groups = format!(
+        "{} | {}",
+        comparison.morning.describe(RunTime::Morning, units),
+        comparison.evening.describe(RunTime::Evening, units)
+    );
+    if compact {
+        return vec![Line::from(Span::styled(groups, value))];
+    }
+    vec![
+        Line::default(),
+        Line::from(Span::styled(
+            format!("Time of Day — last {TIME_OF_DAY_WINDOW_DAYS} days"),
+            Style::default()
+                .fg(theme.highlight)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::styled(groups, value)),
+        Line::from(Span::styled(
+            "Morning is before noon, evening from 5pm",
+            Style::default().fg(theme.dim),
         )),
     ]
 }
 
-fn totals_line(miles: f32, elevation: i32, style: Style) -> Line<'static> {
+/// The year's injuries, the days they covered and the running done through
+/// them, then whatever still hurts.
+fn injury_lines(
+    year: YearInjuries,
+    active: &[&str],
+    year_label: &str,
+    compact: bool,
+    units: Units,
+    theme: &Theme,
+) -> Vec<Line<'static>> {
+    let value = Style::default().fg(theme.text);
+    let summary = format!(
+        "{} {}, {} {} hurt, {} run while hurt",
+        year.count,
+        if year.count == 1 { "injury" } else { "injuries" },
+        year.days,
+        if year.days == 1 { "day" } else { "days" },
+        units.total(Quantity::Distance, year.miles)
+    );
+    if compact {
+        return vec![Line::from(Span::styled(format!("Injuries - {summary}"), value))];
+    }
+    let mut lines = vec![
+        Line::default(),
+        Line::from(Span::styled(
+            format!("Injuries — {year_label}"),
+            Style::default()
+                .fg(theme.highlight)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::styled(summary, value)),
+    ];
+    if !active.is_empty() {
+        lines.push(Line::from(Span::styled(
+            format!("Still hurting: {}", active.join(", ")),
+            Style::default().fg(theme.error),
+        )));
+    }
+    lines
+}
+
+fn totals_line(miles: f32, elevation: i32, units: Units, style: Style) -> Line<'static> {
     Line::from(Span::styled(
-        format!("Miles: {miles:.1} mi | Elevation: {elevation} ft"),
+        format!(
+            "{}: {} | Elevation: {}",
+            units.distance_title(),
+            units.total(Quantity::Distance, miles as f64),
+            units.total(Quantity::Elevation, elevation as f64)
+        ),
         style,
     ))
 }
 
-fn compact_totals_line(label: &str, miles: f32, elevation: i32, style: Style) -> Line<'static> {
+fn compact_totals_line(
+    label: &str,
+    miles: f32,
+    elevation: i32,
+    units: Units,
+    style: Style,
+) -> Line<'static> {
     Line::from(Span::styled(
-        format!("{label}: {miles:.1} mi | {elevation} ft"),
+        format!(
+            "{label}: {} | {}",
+            units.total(Quantity::Distance, miles as f64),
+            units.total(Quantity::Elevation, elevation as f64)
+        ),
         style,
     ))
 }
@@ -222,20 +647,200 @@ mod tests {
     fn renders_week_month_year_totals_and_existing_stats() {
         let date = NaiveDate::from_ymd_opt(2026, 7, 22).unwrap();
         let mut state = AppState::new();
-        state.daily_logs = vec![DailyLog {
+        state.set_daily_logs(vec![DailyLog {
             date,
             miles_covered: Some(7.5),
             elevation_gain: Some(1200),
             ..DailyLog::new(date)
-        }];
+        }]);
 
         let text = rendered_text(&state, date, 100, 26);
         assert!(text.contains("This Week"));
         assert!(text.contains("Week 30 (Jul 20–Jul 26)"));
         assert!(text.contains("This Month — July 2026"));
         assert!(text.contains("This Year — 2026"));
-        assert!(text.contains("Miles: 7.5 mi | Elevation: 1200 ft"));
-        assert!(text.contains("1000+ ft days this month: 1"));
+        assert!(text.contains("Miles: 7.5 mi | Elevation: 1,200 ft"));
+        assert!(text.contains("1,000+ ft days this month: 1"));
+        assert!(text.contains("Since         Jul 22, 2026"));
+        assert!(text.contains("Days Logged   1"));
+    }
+
+    #[test]
+    fn the_year_is_split_by_activity_once_days_are_tagged() {
+        use crate::models::activity::ActivityType;
+        let date = NaiveDate::from_ymd_opt(2026, 7, 22).unwrap();
+        let mut state = AppState::new();
+        let day = |d, activity, miles| DailyLog {
+            activity,
+            miles_covered: Some(miles),
+            ..DailyLog::new(NaiveDate::from_ymd_opt(2026, 7, d).unwrap())
+        };
+        state.set_daily_logs(vec![day(22, None, 4.0)]);
+        assert!(!rendered_text(&state, date, 100, 30).contains("By Activity"));
+
+        state.set_daily_logs(vec![
+            day(22, Some(ActivityType::Run), 6.0),
+            day(21, Some(ActivityType::Hike), 9.5),
+            day(20, Some(ActivityType::Run), 4.0),
+        ]);
+        let text = rendered_text(&state, date, 100, 30);
+        assert!(text.contains("By Activity — 2026"));
+        assert!(text.contains("Run       10.0 mi | 0 ft | 2 days"));
+        assert!(text.contains("Hike      9.5 mi | 0 ft | 1 day"));
+    }
+
+    #[test]
+    fn energy_is_compared_on_run_and_rest_days_once_rated() {
+        let date = NaiveDate::from_ymd_opt(2026, 7, 22).unwrap();
+        let mut state = AppState::new();
+        let day = |d| NaiveDate::from_ymd_opt(2026, 7, d).unwrap();
+        state.set_daily_logs(vec![DailyLog {
+            miles_covered: Some(7.5),
+            ..DailyLog::new(date)
+        }]);
+        assert!(!rendered_text(&state, date, 100, 30).contains("Energy"));
+
+        state.set_daily_logs(
+            [
+                (18, 2, None),
+                (19, 4, Some(5.0)),
+                (20, 5, Some(9.0)),
+                (21, 1, None),
+                (22, 3, Some(2.0)),
+            ]
+            .into_iter()
+            .map(|(d, energy, miles_covered)| DailyLog {
+                energy: Some(energy),
+                miles_covered,
+                ..DailyLog::new(day(d))
+            })
+            .collect(),
+        );
+        let text = rendered_text(&state, date, 100, 30);
+        assert!(text.contains("Energy — last 90 days"));
+        assert!(text.contains("Run days: 4.0/5 (3 days) | Rest days: 1.5/5 (2 days)"));
+        assert!(text.contains("Energy vs miles: r = 0.9"));
+        assert!(rendered_text(&state, date, 100, 20).contains("Energy - Run days: 4.0/5"));
+    }
+
+    #[test]
+    fn morning_and_evening_runs_are_compared_once_start_times_are_logged() {
+        let date = NaiveDate::from_ymd_opt(2026, 7, 22).unwrap();
+        let mut state = AppState::new();
+        let run = |d, hour, minutes: u32| DailyLog {
+            start_time: chrono::NaiveTime::from_hms_opt(hour, 0, 0),
+            duration_secs: Some(minutes * 60),
+            miles_covered: Some(5.0),
+            ..DailyLog::new(NaiveDate::from_ymd_opt(2026, 7, d).unwrap())
+        };
+        state.set_daily_logs(vec![DailyLog {
+            miles_covered: Some(5.0),
+            ..DailyLog::new(date)
+        }]);
+        assert!(!rendered_text(&state, date, 120, 34).contains("Time of Day"));
+
+        state.set_daily_logs(vec![run(20, 6, 45), run(21, 19, 50)]);
+        let text = rendered_text(&state, date, 120, 34);
+        assert!(text.contains("Time of Day — last 90 days"));
+        assert!(text.contains("Morning: 9:00 /mi (1 run) | Evening: 10:00 /mi (1 run)"));
+    }
+
+    #[test]
+    fn injuries_are_counted_for_the_year_once_logged() {
+        use crate::injuries::{Injury, Severity};
+        let date = NaiveDate::from_ymd_opt(2026, 7, 22).unwrap();
+        let mut state = AppState::new();
+        state.set_daily_logs(vec![DailyLog {
+            miles_covered: Some(3.0),
+            ..DailyLog::new(NaiveDate::from_ymd_opt(2026, 7, 20).unwrap())
+        }]);
+        assert!(!rendered_text(&state, date, 120, 34).contains("Injuries"));
+
+        state.injuries = vec![
+            Injury {
+                id: 2,
+                body_part: "Left knee".to_string(),
+                severity: Severity::Moderate,
+                start: NaiveDate::from_ymd_opt(2026, 7, 18).unwrap(),
+                resolved: None,
+            },
+            Injury {
+                id: 1,
+                body_part: "Right calf".to_string(),
+                severity: Severity::Mild,
+                start: NaiveDate::from_ymd_opt(2026, 3, 1).unwrap(),
+                resolved: Some(NaiveDate::from_ymd_opt(2026, 3, 7).unwrap()),
+            },
+        ];
+        let text = rendered_text(&state, date, 120, 34);
+        assert!(text.contains("Injuries — 2026"));
+        assert!(text.contains("2 injuries, 12 days hurt, 3.0 mi run while hurt"));
+        assert!(text.contains("Still hurting: Left knee"));
+    }
+
+    #[test]
+    fn lifetime_panel_moves_under_the_totals_on_narrow_screens() {
+        let date = NaiveDate::from_ymd_opt(2026, 7, 22).unwrap();
+        let mut state = AppState::new();
+        state.set_daily_logs(vec![
+            DailyLog {
+                miles_covered: Some(7.5),
+                elevation_gain: Some(1200),
+                ..DailyLog::new(date)
+            },
+            DailyLog {
+                miles_covered: Some(10.0),
+                elevation_gain: Some(3000),
+                ..DailyLog::new(NaiveDate::from_ymd_opt(2024, 5, 1).unwrap())
+            },
+        ]);
+
+        let text = rendered_text(&state, date, 60, 30);
+        assert!(text.contains("Lifetime"));
+        assert!(text.contains("Training Age  2 years, 2 months"));
+        assert!(text.contains("Miles         17.5 mi"));
+        assert!(text.contains("Vert          4,200 ft"));
+
+        assert!(!rendered_text(&state, date, 60, 20).contains("Lifetime"));
+    }
+
+    #[test]
+    fn streaks_panel_lists_each_rule() {
+        let date = NaiveDate::from_ymd_opt(2026, 7, 22).unwrap();
+        let mut state = AppState::new();
+        state.set_daily_logs(vec![
+            DailyLog {
+                miles_covered: Some(7.5),
+                elevation_gain: Some(1200),
+                ..DailyLog::new(date)
+            },
+            DailyLog {
+                miles_covered: Some(6.0),
+                elevation_gain: Some(400),
+                ..DailyLog::new(date.pred_opt().unwrap())
+            },
+        ]);
+
+        let text = rendered_text(&state, date, 100, 30);
+        assert!(text.contains("Streaks"));
+        assert!(text.contains("1,000+ ft: 1 day since Jul 22"));
+
+        state.stats.set_streak_rules(vec![
+            StreakRule {
+                metric: crate::streaks::StreakMetric::Miles,
+                threshold: 5.0,
+                ..StreakRule::default()
+            },
+            StreakRule {
+                name: Some("Big days".to_string()),
+                threshold: 3000.0,
+                ..StreakRule::default()
+            },
+        ]);
+        let text = rendered_text(&state, date, 100, 30);
+        assert!(text.contains("5+ mi: 2 days since Jul 21"));
+        assert!(text.contains("Big days: none going"));
+        assert!(!text.contains("1,000+ ft:"));
     }
 
     #[test]
@@ -246,10 +851,26 @@ mod tests {
         assert!(text.contains("Week 30"));
         assert!(text.contains("July 2026"));
         assert!(text.contains("2026: 0.0 mi | 0 ft"));
-        assert!(text.contains("1000+ ft days this month: 0"));
+        assert!(text.contains("1,000+ ft days this month: 0"));
         assert!(text.contains("Esc: Startup"));
     }
 
+    #[test]
+    fn metric_units_convert_totals() {
+        let date = NaiveDate::from_ymd_opt(2026, 7, 22).unwrap();
+        let mut state = AppState::new();
+        state.units = UnitSystem::Metric.into();
+        state.set_daily_logs(vec![DailyLog {
+            miles_covered: Some(10.0),
+            elevation_gain: Some(1200),
+            ..DailyLog::new(date)
+        }]);
+
+        let text = rendered_text(&state, date, 100, 26);
+        assert!(text.contains("Kilometers: 16.1 km | Elevation: 366 m"));
+        assert!(text.contains("305+ m days this month: 1"));
+    }
+
     #[test]
     fn footer_registers_back_and_quit_targets() {
         let backend = TestBackend::new(80, 20);
@@ -0,0 +1,270 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    symbols,
+    text::{Line, Span},
+    widgets::{Axis, Block, BorderType, Borders, Chart, Dataset, GraphType, Padding, Paragraph},
+};
+
+use crate::format::Units;
+use crate::models::AppState;
+use crate::strength::{self, ExerciseProgress};
+use crate::units::Quantity;
+
+/// Width of the exercise list beside the chart.
+const LIST_WIDTH: u16 = 32;
+
+/// Renders every exercise logged in sets, most done first, with a chart of
+/// the selected one: its top weight each session, or its reps when it's
+/// done without weight.
+pub fn render_strength_screen(f: &mut Frame, state: &AppState, selected: usize) {
+    let theme = &state.theme;
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme.sections.strength))
+        .title(" Strength ")
+        .title_style(Style::default().fg(theme.text).add_modifier(Modifier::BOLD))
+        .padding(Padding::new(2, 2, 1, 1));
+    let inner_area = block.inner(f.area());
+    f.render_widget(block, f.area());
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(inner_area);
+
+    let help_spans = vec![
+        Span::styled("j/k", Style::default().fg(theme.highlight)),
+        Span::styled(": Select | ", Style::default().fg(theme.text)),
+        Span::styled("Enter", Style::default().fg(theme.highlight)),
+        Span::styled(": Open Last Session | ", Style::default().fg(theme.text)),
+        Span::styled("Esc", Style::default().fg(theme.highlight)),
+        Span::styled(": Back", Style::default().fg(theme.text)),
+    ];
+    f.render_widget(
+        Paragraph::new(Line::from(help_spans)).alignment(Alignment::Center),
+        chunks[1],
+    );
+
+    let exercises = strength::exercise_progress(state.daily_logs());
+    if exercises.is_empty() {
+        f.render_widget(
+            Paragraph::new(
+                "No sets yet. Press 't' on a day and add a line like \"Squat 3x8 @ 135\".",
+            )
+            .style(Style::default().fg(theme.dim)),
+            chunks[0],
+        );
+        return;
+    }
+    let selected = selected.min(exercises.len() - 1);
+
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Length(LIST_WIDTH), Constraint::Min(0)])
+        .split(chunks[0]);
+
+    // Keep the selection on screen by dropping the exercises above it
+    let fits = (columns[0].height as usize).max(1);
+    let skip = (selected + 1).saturating_sub(fits);
+    let lines: Vec<Line> = exercises
+        .iter()
+        .enumerate()
+        .skip(skip)
+        .map(|(index, exercise)| {
+            let name_style = if index == selected {
+                Style::default()
+                    .fg(theme.highlight)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.text)
+            };
+            Line::from(vec![
+                Span::styled(
+                    format!("{}{}", if index == selected { "► " } else { "  " }, exercise.name),
+                    name_style,
+                ),
+                Span::styled(
+                    format!(" ({})", exercise.sessions.len()),
+                    Style::default().fg(theme.dim),
+                ),
+            ])
+        })
+        .collect();
+    f.render_widget(Paragraph::new(lines), columns[0]);
+
+    let chart_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(columns[1]);
+    let exercise = &exercises[selected];
+    render_progress_chart(f, chart_chunks[0], state, exercise);
+    f.render_widget(
+        Paragraph::new(summary(exercise, state.units)).style(Style::default().fg(theme.label)),
+        chart_chunks[1],
+    );
+}
+
+/// Line chart of one exercise, a point a session.
+fn render_progress_chart(f: &mut Frame, area: Rect, state: &AppState, exercise: &ExerciseProgress) {
+    let theme = &state.theme;
+    let (Some(first), Some(last)) = (exercise.sessions.first(), exercise.sessions.last()) else {
+        return;
+    };
+    let weighted = exercise.weighted();
+    let points: Vec<(f64, f64)> = exercise
+        .sessions
+        .iter()
+        .filter_map(|session| {
+            let value = if weighted {
+                state
+                    .units
+                    .convert(Quantity::Weight, session.top_weight? as f64)
+            } else {
+                session.reps as f64
+            };
+            Some(((session.date - first.date).num_days() as f64, value))
+        })
+        .collect();
+    let low = points.iter().map(|(_, v)| *v).fold(f64::MAX, f64::min);
+    let high = points.iter().map(|(_, v)| *v).fold(f64::MIN, f64::max);
+    // Keep a flat line off the chart's edges
+    let pad = ((high - low) * 0.1).max(1.0);
+    let span_days = ((last.date - first.date).num_days() as f64).max(1.0);
+    let label = |value: f64| {
+        if weighted {
+            format!("{} {}", state.units.decimal(value, 0), state.units.label(Quantity::Weight))
+        } else {
+            format!("{} reps", state.units.decimal(value, 0))
+        }
+    };
+
+    let dataset = Dataset::default()
+        .marker(symbols::Marker::Braille)
+        .graph_type(GraphType::Line)
+        .style(Style::default().fg(theme.sections.strength))
+        .data(&points);
+    let chart = Chart::new(vec![dataset])
+        .block(Block::default().padding(Padding::left(1)))
+        .x_axis(
+            Axis::default()
+                .style(Style::default().fg(theme.label))
+                .bounds([0.0, span_days])
+                .labels([
+                    state.units.month_day(first.date),
+                    state.units.month_day(last.date),
+                ]),
+        )
+        .y_axis(
+            Axis::default()
+                .style(Style::default().fg(theme.label))
+                .bounds([low - pad, high + pad])
+                .labels([label(low), label(high)]),
+        );
+    f.render_widget(chart, area);
+}
+
+/// "12 sessions | Last Oct 14: 4 sets, 29 reps, top 155 lbs | Best 155 lbs",
+/// with the most reps in place of the best weight for bodyweight work.
+fn summary(exercise: &ExerciseProgress, units: Units) -> String {
+    let count = exercise.sessions.len();
+    let mut text = format!("{} {}", count, if count == 1 { "session" } else { "sessions" });
+    if let Some(last) = exercise.sessions.last() {
+        text.push_str(&format!(
+            " | Last {}: {} sets, {} reps",
+            units.month_day(last.date),
+            last.sets,
+            last.reps
+        ));
+        if let Some(top) = last.top_weight {
+            text.push_str(&format!(", top {}", units.format(Quantity::Weight, top)));
+        }
+    }
+    match exercise.best_weight() {
+        Some(best) => text.push_str(&format!(" | Best {}", units.format(Quantity::Weight, best))),
+        None => text.push_str(&format!(" | Most reps {}", exercise.most_reps())),
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::DailyLog;
+    use crate::strength::{Session, StrengthSet};
+    use crate::units::UnitSystem;
+    use chrono::NaiveDate;
+    use ratatui::{Terminal, backend::TestBackend};
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 10, d).unwrap()
+    }
+
+    #[test]
+    fn summary_gives_the_last_session_and_the_best() {
+        let session = |d, reps, top_weight| Session {
+            date: day(d),
+            sets: 4,
+            reps,
+            top_weight,
+        };
+        let squat = ExerciseProgress {
+            name: "Squat".to_string(),
+            sessions: vec![session(7, 24, Some(145.0)), session(14, 29, Some(155.0))],
+        };
+        let units = Units::from(UnitSystem::Imperial);
+        assert_eq!(
+            summary(&squat, units),
+            "2 sessions | Last Oct 14: 4 sets, 29 reps, top 155 lbs | Best 155 lbs"
+        );
+        let pull_ups = ExerciseProgress {
+            name: "Pull-ups".to_string(),
+            sessions: vec![session(14, 32, None)],
+        };
+        assert_eq!(
+            summary(&pull_ups, units),
+            "1 session | Last Oct 14: 4 sets, 32 reps | Most reps 32"
+        );
+    }
+
+    #[test]
+    fn strength_screen_lists_exercises_and_charts_the_selected() {
+        let mut state = AppState::new();
+        let log = |d, weight| DailyLog {
+            strength_sets: vec![
+                StrengthSet {
+                    exercise: "Squat".to_string(),
+                    sets: 3,
+                    reps: 8,
+                    weight: Some(weight),
+                },
+                StrengthSet {
+                    exercise: "Plank".to_string(),
+                    sets: 3,
+                    reps: 1,
+                    weight: None,
+                },
+            ],
+            ..DailyLog::new(day(d))
+        };
+        state.set_daily_logs(vec![log(14, 145.0), log(7, 135.0)]);
+        let mut terminal = Terminal::new(TestBackend::new(120, 24)).unwrap();
+        terminal
+            .draw(|frame| render_strength_screen(frame, &state, 1))
+            .unwrap();
+        let text: String = terminal
+            .backend()
+            .buffer()
+            .content
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+
+        assert!(text.contains("  Plank (2)"));
+        assert!(text.contains("► Squat (2)"));
+        assert!(text.contains("145 lbs"));
+        assert!(text.contains("Best 145 lbs"));
+    }
+}
@@ -0,0 +1,482 @@
+use chrono::{Days, NaiveDate};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Bar, BarChart, BarGroup, Block, Borders, Gauge, Paragraph},
+};
+
+use crate::elevation_stats::weekly_elevation_series;
+use crate::goals::Progress;
+use crate::heart_rate::time_in_zones;
+use crate::models::field_accessor::{format_duration, format_hours};
+use crate::models::{AppState, DailyLog};
+use crate::phases::phase_during;
+use crate::plan::{PlannedWorkout, week_plan};
+use crate::sleep_stats::average_sleep;
+use crate::ui::components::{create_standard_layout, render_help, render_title};
+use crate::format::Units;
+use crate::units::Quantity;
+use crate::week_stats::{long_run_streak, summarize_week, week_dates, week_number};
+
+/// Weeks of vert in the bar chart, ending with the one shown.
+const VERT_WEEKS: u64 = 12;
+
+/// Renders the week containing `cursor`: totals up top, then one row per day
+/// with the cursor's day highlighted.
+pub fn render_week_screen(f: &mut Frame, state: &AppState, cursor: NaiveDate) {
+    let theme = &state.theme;
+    let chunks = create_standard_layout(f.area());
+    let stats = state.stats.config();
+    let week_start = stats.week_start;
+    let summary = summarize_week(state.daily_logs(), cursor, week_start);
+    let dates = week_dates(cursor, week_start);
+    let phase = phase_during(&state.phases, &dates)
+        .map(|phase| format!(" - {} Phase", phase.kind.title()))
+        .unwrap_or_default();
+    render_title(
+        f,
+        chunks[0],
+        &format!(
+            "Week of {} (W{:02}){}",
+            state.units.date(summary.start),
+            week_number(summary.start),
+            phase
+        ),
+        theme,
+    );
+
+    let goal = state.weekly_miles_goal();
+    let units = state.units;
+    let today = chrono::Local::now().date_naive();
+    let plan = week_plan(&state.plan, &dates, today, |date| state.counted_daily_log(date));
+    let in_zones = time_in_zones(
+        dates.iter().filter_map(|date| state.counted_daily_log(*date)),
+        &state.hr_zones,
+    );
+    let has_zones = in_zones.iter().any(|&seconds| seconds > 0);
+    let totals_height = 4 + plan.is_some() as u16 + has_zones as u16;
+    let sections = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(if goal.is_some() { 3 } else { 0 }),
+            Constraint::Length(totals_height),
+            Constraint::Length(11),
+            Constraint::Min(0),
+        ])
+        .split(chunks[1]);
+    if let Some(goal) = goal {
+        let progress = Progress::new(
+            summary.miles as f64,
+            goal as f64,
+            summary.start,
+            7,
+            today,
+        );
+        render_goal_gauge(f, sections[0], &progress, state);
+    }
+
+    let label = Style::default().fg(theme.label);
+    let value = Style::default()
+        .fg(theme.highlight)
+        .add_modifier(Modifier::BOLD);
+    let sleep = average_sleep(dates.iter().filter_map(|date| state.counted_daily_log(*date)))
+        .map_or("-".to_string(), |hours| format!("{} h", format_hours(hours)));
+    let mut totals = vec![Line::from(vec![
+        Span::styled(format!("{}: ", units.distance_title()), label),
+        Span::styled(
+            units.number(Quantity::Distance, summary.miles as f64),
+            value,
+        ),
+        Span::styled("   Vert: ", label),
+        Span::styled(
+            units.total(Quantity::Elevation, summary.elevation as f64),
+            value,
+        ),
+        // Rest days were meant to be off, so they don't count against it
+        Span::styled("   Days Run: ", label),
+        Span::styled(format!("{}/{}", summary.days_run, 7 - summary.rest_days), value),
+        Span::styled("   Sokay: ", label),
+        Span::styled(summary.sokay_count.to_string(), value),
+        Span::styled("   Avg Sleep: ", label),
+        Span::styled(sleep, value),
+        Span::styled("   Avg RPE: ", label),
+        Span::styled(
+            summary
+                .average_rpe
+                .map_or("-".to_string(), |rpe| units.decimal(rpe as f64, 1)),
+            value,
+        ),
+    ])];
+    let long_run = summary.long_run.map_or("-".to_string(), |(date, miles)| {
+        format!(
+            "{} on {}",
+            units.format(Quantity::Distance, miles),
+            date.format("%a")
+        )
+    });
+    let streak = long_run_streak(
+        state.daily_logs(),
+        cursor,
+        week_start,
+        stats.long_run_miles,
+        today,
+    );
+    totals.push(Line::from(vec![
+        Span::styled("Long Run: ", label),
+        Span::styled(long_run, value),
+        Span::styled("   Long-Run Streak: ", label),
+        Span::styled(
+            format!("{} {}", streak, if streak == 1 { "week" } else { "weeks" }),
+            value,
+        ),
+        Span::styled(
+            format!(" of {}+", units.format(Quantity::Distance, stats.long_run_miles)),
+            label,
+        ),
+    ]));
+    // Planned against run, with how much of the plan due so far was done
+    if let Some(plan) = plan {
+        totals.push(Line::from(vec![
+            Span::styled("Planned: ", label),
+            Span::styled(
+                format!(
+                    "{}, {}",
+                    units.total(Quantity::Distance, plan.miles as f64),
+                    units.total(Quantity::Elevation, plan.vert as f64)
+                ),
+                value,
+            ),
+            Span::styled("   Adherence: ", label),
+            Span::styled(
+                plan.adherence
+                    .map_or("-".to_string(), |ratio| format!("{:.0}%", ratio * 100.0)),
+                value,
+            ),
+        ]));
+    }
+    if has_zones {
+        let mut spans = vec![Span::styled("Time in Zone:", label)];
+        for (zone, seconds) in in_zones.into_iter().enumerate() {
+            spans.push(Span::styled(format!("   Z{} ", zone + 1), label));
+            spans.push(Span::styled(format_duration(seconds), value));
+        }
+        totals.push(Line::from(spans));
+    }
+    let totals = Paragraph::new(totals).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Totals")
+            .padding(ratatui::widgets::Padding::horizontal(1)),
+    );
+    f.render_widget(totals, sections[1]);
+
+    let rows: Vec<Line> = dates
+        .into_iter()
+        .map(|date| {
+            let style = if date == cursor {
+                Style::default().add_modifier(Modifier::REVERSED)
+            } else {
+                Style::default()
+            };
+            let mut row = day_row(date, state.counted_daily_log(date), units);
+            if let Some(planned) = state.plan.get(&date) {
+                row.push_str(&planned_cell(planned, units));
+            }
+            Line::styled(row, style)
+        })
+        .collect();
+    let days = Paragraph::new(rows).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Days")
+            .padding(ratatui::widgets::Padding::uniform(1)),
+    );
+    f.render_widget(days, sections[2]);
+    render_vert_bars(f, sections[3], state, summary.start);
+
+    render_help(
+        f,
+        chunks[2],
+        &[
+            " [/]: Previous/Next Week | ↑/k ↓/j: Day | Enter: Open Day | Esc: Back",
+            " [/]: Week | jk: Day | Enter: Open | Esc: Back",
+        ],
+        true,
+        false,
+        theme,
+    );
+}
+
+/// The week's miles against `[goals] weekly_miles`, titled with how the
+/// week stands. Green while on pace, amber when behind.
+fn render_goal_gauge(f: &mut Frame, area: Rect, progress: &Progress, state: &AppState) {
+    let theme = &state.theme;
+    let units = state.units;
+    let color = if progress.on_pace() {
+        theme.good
+    } else {
+        theme.warning
+    };
+    let gauge = Gauge::default()
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(
+                    "Weekly Goal - {}",
+                    progress.projection(units, Quantity::Distance)
+                )),
+        )
+        .gauge_style(Style::default().fg(color))
+        .ratio(progress.ratio())
+        .label(format!(
+            "{} / {}",
+            units.number(Quantity::Distance, progress.done),
+            units.total(Quantity::Distance, progress.target)
+        ))
+        .use_unicode(true);
+    f.render_widget(gauge, area);
+}
+
+/// Bars of weekly vert for the `VERT_WEEKS` weeks ending with the one that
+/// starts on `start`, that week's bar highlighted.
+fn render_vert_bars(f: &mut Frame, area: Rect, state: &AppState, start: NaiveDate) {
+    let theme = &state.theme;
+    let units = state.units;
+    let from = start - Days::new((VERT_WEEKS - 1) * 7);
+    let series = weekly_elevation_series(
+        state.daily_logs(),
+        from,
+        start,
+        state.stats.config().week_start,
+    );
+    let bars: Vec<Bar> = series
+        .iter()
+        .map(|&(week, feet)| {
+            let shown = units.convert(Quantity::Elevation, feet.max(0) as f64).round() as u64;
+            let color = if week == start {
+                theme.highlight
+            } else {
+                theme.good
+            };
+            Bar::default()
+                .value(shown)
+                .text_value(shown.to_string())
+                .label(Line::from(format!("W{:02}", week_number(week))))
+                .style(Style::default().fg(color))
+                .value_style(Style::default().fg(theme.text).bg(color))
+        })
+        .collect();
+    let inner_width = area.width.saturating_sub(4);
+    let bar_width = (inner_width / VERT_WEEKS as u16).saturating_sub(1).max(3);
+    let chart = BarChart::default()
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("Weekly Vert ({})", units.label(Quantity::Elevation)))
+                .padding(ratatui::widgets::Padding::horizontal(1)),
+        )
+        .data(BarGroup::default().bars(&bars))
+        .bar_width(bar_width)
+        .bar_gap(1)
+        .label_style(Style::default().fg(theme.label));
+    f.render_widget(chart, area);
+}
+
+fn day_row(date: NaiveDate, log: Option<&DailyLog>, units: Units) -> String {
+    let day = units.weekday_month_day(date);
+    let Some(log) = log else {
+        return format!("{}   -", day);
+    };
+    let miles = log.miles_covered.map_or("-".to_string(), |miles| {
+        units.total(Quantity::Distance, miles as f64)
+    });
+    let elevation = log.elevation_gain.map_or("-".to_string(), |feet| {
+        units.total(Quantity::Elevation, feet as f64)
+    });
+    let mut row = format!(
+        "{}   {:>8}   {:>8}   {} sokay   {} food",
+        day,
+        miles,
+        elevation,
+        log.sokay_entries.len(),
+        log.food_entries.len()
+    );
+    if let Some(rpe) = log.rpe {
+        row.push_str(&format!("   RPE {}", rpe));
+    }
+    if log.rest_day {
+        row.push_str("   Rest day");
+    }
+    row
+}
+
+/// "   (plan 8 mi, 1,500 ft)" after a day's row, or "   (plan rest)".
+fn planned_cell(planned: &PlannedWorkout, units: Units) -> String {
+    let amounts = planned.amounts(units).unwrap_or_else(|| "rest".to_string());
+    format!("   (plan {})", amounts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{Terminal, backend::TestBackend};
+
+    #[test]
+    fn renders_totals_and_a_row_per_day() {
+        let date = NaiveDate::from_ymd_opt(2026, 7, 22).unwrap();
+        let mut state = AppState::new();
+        state.set_daily_logs(vec![DailyLog {
+            miles_covered: Some(6.5),
+            elevation_gain: Some(1400),
+            rpe: Some(7),
+            ..DailyLog::new(date)
+        }]);
+
+        let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+        terminal
+            .draw(|frame| render_week_screen(frame, &state, date))
+            .unwrap();
+        let text: String = terminal
+            .backend()
+            .buffer()
+            .content
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+
+        assert!(text.contains("Week of July 20, 2026 (W30)"));
+        assert!(text.contains("Days Run: 1/7"));
+        assert!(text.contains("Avg RPE: 7.0"));
+        assert!(text.contains("Wed Jul 22     6.5 mi   1,400 ft   0 sokay   0 food   RPE 7"));
+        assert!(text.contains("Sun Jul 26   -"));
+        assert!(text.contains("Long Run: 6.5 mi on Wed   Long-Run Streak: 0 weeks of 10 mi+"));
+        assert!(!text.contains("Time in Zone"));
+    }
+
+    #[test]
+    fn weekly_goal_gauge_shows_the_weeks_miles_against_it() {
+        let date = NaiveDate::from_ymd_opt(2020, 7, 22).unwrap();
+        let mut state = AppState::new();
+        state.set_daily_logs(vec![DailyLog {
+            miles_covered: Some(36.5),
+            ..DailyLog::new(date)
+        }]);
+        let render = |state: &AppState| {
+            let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+            terminal
+                .draw(|frame| render_week_screen(frame, state, date))
+                .unwrap();
+            terminal
+                .backend()
+                .buffer()
+                .content
+                .iter()
+                .map(|cell| cell.symbol())
+                .collect::<String>()
+        };
+        assert!(!render(&state).contains("Weekly Goal"));
+
+        state.goals.weekly_miles = Some(40.0);
+        let text = render(&state);
+        assert!(text.contains("Weekly Goal - Missed by 3.5 mi"));
+        assert!(text.contains("36.5 / 40.0 mi"));
+    }
+
+    #[test]
+    fn an_imported_plan_shows_against_the_days_run() {
+        let date = NaiveDate::from_ymd_opt(2020, 7, 22).unwrap();
+        let mut state = AppState::new();
+        state.set_daily_logs(vec![DailyLog {
+            miles_covered: Some(5.0),
+            ..DailyLog::new(date)
+        }]);
+        for (day, miles) in [(21, None), (22, Some(8.0)), (25, Some(12.0))] {
+            let date = NaiveDate::from_ymd_opt(2020, 7, day).unwrap();
+            let planned = PlannedWorkout {
+                date,
+                miles,
+                vert: miles.map(|_| 1000),
+                note: None,
+            };
+            state.plan.insert(date, planned);
+        }
+
+        let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+        terminal
+            .draw(|frame| render_week_screen(frame, &state, date))
+            .unwrap();
+        let text: String = terminal
+            .backend()
+            .buffer()
+            .content
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+
+        assert!(text.contains("Planned: 20.0 mi, 2,000 ft   Adherence: 25%"));
+        assert!(text.contains("Tue Jul 21   -   (plan rest)"));
+        assert!(text.contains("0 food   (plan 8 mi, 1,000 ft)"));
+    }
+
+    #[test]
+    fn charts_twelve_weeks_of_vert_ending_with_this_one() {
+        let date = NaiveDate::from_ymd_opt(2026, 7, 22).unwrap();
+        let mut state = AppState::new();
+        state.set_daily_logs(vec![
+            DailyLog {
+                elevation_gain: Some(2400),
+                ..DailyLog::new(date)
+            },
+            DailyLog {
+                elevation_gain: Some(3100),
+                ..DailyLog::new(NaiveDate::from_ymd_opt(2026, 5, 6).unwrap())
+            },
+        ]);
+
+        let mut terminal = Terminal::new(TestBackend::new(100, 40)).unwrap();
+        terminal
+            .draw(|frame| render_week_screen(frame, &state, date))
+            .unwrap();
+        let text: String = terminal
+            .backend()
+            .buffer()
+            .content
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+
+        assert!(text.contains("Weekly Vert (ft)"));
+        assert!(text.contains("W19"));
+        assert!(text.contains("W30"));
+        assert!(!text.contains("W18"));
+        assert!(text.contains("3100"));
+        assert!(text.contains("2400"));
+    }
+
+    #[test]
+    fn shows_time_in_zone_once_zones_are_set() {
+        let date = NaiveDate::from_ymd_opt(2026, 7, 22).unwrap();
+        let mut state = AppState::new();
+        state.hr_zones = vec![125, 145, 160, 175];
+        state.set_daily_logs(vec![DailyLog {
+            duration_secs: Some(3900),
+            avg_hr: Some(150),
+            ..DailyLog::new(date)
+        }]);
+
+        let mut terminal = Terminal::new(TestBackend::new(120, 30)).unwrap();
+        terminal
+            .draw(|frame| render_week_screen(frame, &state, date))
+            .unwrap();
+        let text: String = terminal
+            .backend()
+            .buffer()
+            .content
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(text.contains("Time in Zone:   Z1 0:00   Z2 0:00   Z3 1:05:00   Z4 0:00   Z5 0:00"));
+    }
+}
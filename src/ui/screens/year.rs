@@ -0,0 +1,262 @@
+use chrono::{Datelike, NaiveDate, Weekday};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+};
+
+use super::heatmap::heatmap_level;
+use crate::models::AppState;
+use crate::ui::components::{create_standard_layout, render_help, render_title};
+use crate::ui::theme::Theme;
+use crate::units::Quantity;
+
+/// Seven three-character day cells.
+const MONTH_WIDTH: u16 = 21;
+/// Gap between month columns.
+const GUTTER: u16 = 2;
+/// The month name, then up to six weeks, then a blank line.
+const MONTH_HEIGHT: u16 = 8;
+
+/// One month as a small grid of day numbers colored by that day's vert:
+/// the month name and vert total, then a row per week. Days without a log
+/// are dim, the cursor is reversed and today is underlined.
+pub fn mini_month_lines(
+    first: NaiveDate,
+    cursor: NaiveDate,
+    today: NaiveDate,
+    week_start: Weekday,
+    state: &AppState,
+) -> Vec<Line<'static>> {
+    let theme = &state.theme;
+    let total: i32 = state
+        .daily_logs()
+        .iter()
+        .filter(|log| log.date.year() == first.year() && log.date.month() == first.month())
+        .filter_map(|log| log.elevation_gain)
+        .sum();
+    let total = if total > 0 {
+        state.units.total(Quantity::Elevation, total as f64)
+    } else {
+        String::new()
+    };
+    let is_cursor_month = cursor.year() == first.year() && cursor.month() == first.month();
+    let name_style = if is_cursor_month {
+        Style::default()
+            .fg(theme.highlight)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(theme.label)
+    };
+    let mut lines = vec![Line::from(vec![
+        Span::styled(format!("{:<9}", first.format("%B")), name_style),
+        Span::styled(
+            format!("{:>width$}", total, width = MONTH_WIDTH as usize - 9),
+            Style::default().fg(theme.dim),
+        ),
+    ])];
+
+    let mut day = first.week(week_start).first_day();
+    while day.month() == first.month() || day < first {
+        let mut spans = Vec::with_capacity(7);
+        for _ in 0..7 {
+            if day.month() != first.month() {
+                spans.push(Span::raw("   "));
+            } else {
+                spans.push(Span::styled(
+                    format!("{:>2}", day.day()),
+                    day_style(day, cursor, today, state, theme),
+                ));
+                spans.push(Span::raw(" "));
+            }
+            day = day.succ_opt().unwrap_or(day);
+        }
+        lines.push(Line::from(spans));
+    }
+    lines
+}
+
+fn day_style(
+    day: NaiveDate,
+    cursor: NaiveDate,
+    today: NaiveDate,
+    state: &AppState,
+    theme: &Theme,
+) -> Style {
+    let mut style = match state.counted_daily_log(day) {
+        _ if day > today => Style::default().fg(theme.dim),
+        None => Style::default().fg(theme.dim),
+        Some(log) => match heatmap_level(log.elevation_gain) {
+            0 => Style::default().fg(theme.text),
+            level => Style::default()
+                .fg(theme.heat[level])
+                .add_modifier(Modifier::BOLD),
+        },
+    };
+    if day == today {
+        style = style.add_modifier(Modifier::UNDERLINED);
+    }
+    if day == cursor {
+        style = style.add_modifier(Modifier::REVERSED);
+    }
+    style
+}
+
+/// How many month grids fit side by side: six, four or three, whichever
+/// divides the year evenly and fits.
+fn months_per_row(width: u16) -> u16 {
+    [6, 4, 3]
+        .into_iter()
+        .find(|&columns| columns * MONTH_WIDTH + (columns - 1) * GUTTER <= width)
+        .unwrap_or(3)
+}
+
+/// Renders the cursor's year as twelve mini month calendars, with the
+/// cursor's day and the year's totals underneath.
+pub fn render_year_screen(f: &mut Frame, state: &AppState, cursor: NaiveDate) {
+    let theme = &state.theme;
+    let chunks = create_standard_layout(f.area());
+    let year = cursor.year();
+    render_title(f, chunks[0], &format!("Year at a Glance - {}", year), theme);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.border))
+        .padding(ratatui::widgets::Padding::horizontal(1));
+    let inner = block.inner(chunks[1]);
+    f.render_widget(block, chunks[1]);
+
+    let columns = months_per_row(inner.width);
+    let rows = 12 / columns;
+    let sections = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(rows * MONTH_HEIGHT), Constraint::Min(0)])
+        .split(inner);
+
+    let today = chrono::Local::now().date_naive();
+    let week_start = state.stats.config().week_start;
+    for month in 1..=12u32 {
+        let Some(first) = NaiveDate::from_ymd_opt(year, month, 1) else {
+            continue;
+        };
+        let index = month as u16 - 1;
+        let area = Rect {
+            x: sections[0].x + (index % columns) * (MONTH_WIDTH + GUTTER),
+            y: sections[0].y + (index / columns) * MONTH_HEIGHT,
+            width: MONTH_WIDTH,
+            height: MONTH_HEIGHT,
+        }
+        .intersection(sections[0]);
+        let lines = mini_month_lines(first, cursor, today, week_start, state);
+        f.render_widget(Paragraph::new(lines), area);
+    }
+
+    let year_logs: Vec<_> = state
+        .daily_logs()
+        .iter()
+        .filter(|log| log.date.year() == year)
+        .collect();
+    let year_vert: i32 = year_logs.iter().filter_map(|log| log.elevation_gain).sum();
+    let cursor_elevation = state
+        .counted_daily_log(cursor)
+        .map_or("no log".to_string(), |log| {
+            log.elevation_gain
+                .map_or("no elevation logged".to_string(), |feet| {
+                    state.units.format(Quantity::Elevation, feet as f32)
+                })
+        });
+    let mut legend = vec![Span::styled("Less ", Style::default().fg(theme.label))];
+    for level in 1..=4 {
+        legend.push(Span::styled("■ ", Style::default().fg(theme.heat[level])));
+    }
+    legend.push(Span::styled("More", Style::default().fg(theme.label)));
+    let details = Paragraph::new(vec![
+        Line::from(Span::styled(
+            format!("{}: {}", state.units.weekday_date(cursor), cursor_elevation),
+            Style::default().fg(theme.highlight),
+        )),
+        Line::from(Span::styled(
+            format!(
+                "{}: {} {} logged, {}",
+                year,
+                year_logs.len(),
+                if year_logs.len() == 1 { "day" } else { "days" },
+                state.units.total(Quantity::Elevation, year_vert as f64)
+            ),
+            Style::default().fg(theme.label),
+        )),
+        Line::from(legend),
+    ]);
+    f.render_widget(details, sections[1]);
+
+    render_help(
+        f,
+        chunks[2],
+        &[
+            " ←/→ h/l: Day | ↑/↓ k/j: Week | [/]: Month | {/}: Year | Enter: Open Day | m: Month Summary | Esc: Back",
+            " hl: Day | jk: Week | []: Month | {}: Year | Enter: Open | m: Month | Esc: Back",
+            " Enter: Open | Esc: Back",
+        ],
+        true,
+        true,
+        theme,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::DailyLog;
+    use ratatui::{Terminal, backend::TestBackend};
+
+    #[test]
+    fn lays_out_twelve_months_as_wide_as_fits() {
+        assert_eq!(months_per_row(200), 6);
+        assert_eq!(months_per_row(100), 4);
+        assert_eq!(months_per_row(70), 3);
+        assert_eq!(months_per_row(40), 3);
+
+        let date = NaiveDate::from_ymd_opt(2025, 7, 22).unwrap();
+        let mut state = AppState::new();
+        state.set_daily_logs(vec![DailyLog {
+            elevation_gain: Some(2400),
+            ..DailyLog::new(date)
+        }]);
+
+        let lines = mini_month_lines(
+            NaiveDate::from_ymd_opt(2025, 7, 1).unwrap(),
+            date,
+            date,
+            Weekday::Mon,
+            &state,
+        );
+        assert_eq!(lines[0].to_string(), "July         2,400 ft");
+        // July 2025 starts on a Tuesday
+        assert_eq!(lines[1].to_string(), "    1  2  3  4  5  6 ");
+        let cell = lines[4]
+            .spans
+            .iter()
+            .find(|span| span.content == "22")
+            .unwrap();
+        assert_eq!(cell.style.fg, Some(state.theme.heat[4]));
+        assert!(cell.style.add_modifier.contains(Modifier::REVERSED));
+
+        let mut terminal = Terminal::new(TestBackend::new(150, 34)).unwrap();
+        terminal
+            .draw(|frame| render_year_screen(frame, &state, date))
+            .unwrap();
+        let text: String = terminal
+            .backend()
+            .buffer()
+            .content
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(text.contains("Year at a Glance - 2025"));
+        assert!(text.contains("January"));
+        assert!(text.contains("December"));
+        assert!(text.contains("2025: 1 day logged, 2,400 ft"));
+    }
+}
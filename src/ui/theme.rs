@@ -0,0 +1,217 @@
+use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
+
+use crate::models::activity::ActivityType;
+
+/// Built-in color themes, picked with `[display] theme` or on the settings
+/// screen.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ThemeName {
+    /// The original palette, for dark terminals.
+    #[default]
+    Dark,
+    /// Darker, saturated colors that stay readable on white backgrounds.
+    Light,
+    /// Ethan Schoonover's Solarized accents, for Solarized dark terminals.
+    Solarized,
+    /// Blue/orange in place of green/red and bright unfocused borders, for
+    /// color vision deficiencies and low-contrast displays.
+    HighContrast,
+}
+
+impl ThemeName {
+    pub const ALL: [ThemeName; 4] = [
+        ThemeName::Dark,
+        ThemeName::Light,
+        ThemeName::Solarized,
+        ThemeName::HighContrast,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ThemeName::Dark => "Dark",
+            ThemeName::Light => "Light",
+            ThemeName::Solarized => "Solarized",
+            ThemeName::HighContrast => "High contrast",
+        }
+    }
+
+    /// The next theme in `ALL`, or the previous one when `step` is negative,
+    /// wrapping around.
+    pub fn cycle(self, step: isize) -> Self {
+        let index = Self::ALL.iter().position(|name| *name == self).unwrap_or(0);
+        let len = Self::ALL.len() as isize;
+        Self::ALL[(index as isize + step).rem_euclid(len) as usize]
+    }
+}
+
+/// Accent colors for the daily view sections, also used by the matching
+/// input modals.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SectionColors {
+    pub measurements: Color,
+    pub running: Color,
+    pub food: Color,
+    pub sokay: Color,
+    pub strength: Color,
+    pub notes: Color,
+}
+
+/// Every color the UI draws with, by role. Renderers never name a color
+/// directly, so switching themes recolors everything at once.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Theme {
+    /// Body text and values.
+    pub text: Color,
+    /// Field labels and secondary text.
+    pub label: Color,
+    /// Unfocused borders, placeholders and hints.
+    pub dim: Color,
+    /// Screen borders and informational accents.
+    pub border: Color,
+    /// Keys in help bars, headings and highlighted values.
+    pub highlight: Color,
+    /// Title text, success messages and chart lines.
+    pub good: Color,
+    /// Title borders and offline notices.
+    pub warning: Color,
+    /// Errors and destructive confirmations.
+    pub error: Color,
+    /// Text drawn on a `border`-colored background.
+    pub on_border: Color,
+    /// Heatmap cells from no vert to the most.
+    pub heat: [Color; 5],
+    pub sections: SectionColors,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme::new(ThemeName::Dark)
+    }
+}
+
+impl Theme {
+    pub fn new(name: ThemeName) -> Self {
+        match name {
+            ThemeName::Dark => Theme {
+                text: Color::White,
+                label: Color::Gray,
+                dim: Color::DarkGray,
+                border: Color::Cyan,
+                highlight: Color::Yellow,
+                good: Color::Green,
+                warning: Color::Rgb(255, 165, 0),
+                error: Color::Red,
+                on_border: Color::Black,
+                heat: [
+                    Color::DarkGray,
+                    Color::Rgb(14, 68, 41),
+                    Color::Rgb(0, 109, 50),
+                    Color::Rgb(38, 166, 65),
+                    Color::Rgb(57, 211, 83),
+                ],
+                sections: SectionColors {
+                    measurements: Color::Yellow,
+                    running: Color::LightRed,
+                    food: Color::Yellow,
+                    sokay: Color::Magenta,
+                    strength: Color::Cyan,
+                    notes: Color::Green,
+                },
+            },
+            ThemeName::Light => Theme {
+                text: Color::Black,
+                label: Color::DarkGray,
+                dim: Color::Rgb(150, 150, 150),
+                border: Color::Rgb(0, 95, 175),
+                highlight: Color::Rgb(155, 85, 0),
+                good: Color::Rgb(0, 120, 40),
+                warning: Color::Rgb(200, 90, 0),
+                error: Color::Rgb(190, 0, 0),
+                on_border: Color::White,
+                heat: [
+                    Color::Rgb(225, 228, 232),
+                    Color::Rgb(155, 233, 168),
+                    Color::Rgb(64, 196, 99),
+                    Color::Rgb(48, 161, 78),
+                    Color::Rgb(33, 110, 57),
+                ],
+                sections: SectionColors {
+                    measurements: Color::Rgb(155, 85, 0),
+                    running: Color::Rgb(190, 40, 40),
+                    food: Color::Rgb(125, 100, 0),
+                    sokay: Color::Rgb(150, 0, 150),
+                    strength: Color::Rgb(0, 110, 140),
+                    notes: Color::Rgb(0, 120, 40),
+                },
+            },
+            ThemeName::Solarized => Theme {
+                text: Color::Rgb(147, 161, 161),
+                label: Color::Rgb(131, 148, 150),
+                dim: Color::Rgb(88, 110, 117),
+                border: Color::Rgb(38, 139, 210),
+                highlight: Color::Rgb(181, 137, 0),
+                good: Color::Rgb(133, 153, 0),
+                warning: Color::Rgb(203, 75, 22),
+                error: Color::Rgb(220, 50, 47),
+                on_border: Color::Rgb(0, 43, 54),
+                heat: [
+                    Color::Rgb(7, 54, 66),
+                    Color::Rgb(56, 80, 20),
+                    Color::Rgb(95, 118, 10),
+                    Color::Rgb(133, 153, 0),
+                    Color::Rgb(172, 192, 40),
+                ],
+                sections: SectionColors {
+                    measurements: Color::Rgb(181, 137, 0),
+                    running: Color::Rgb(203, 75, 22),
+                    food: Color::Rgb(181, 137, 0),
+                    sokay: Color::Rgb(211, 54, 130),
+                    strength: Color::Rgb(42, 161, 152),
+                    notes: Color::Rgb(133, 153, 0),
+                },
+            },
+            // Okabe-Ito colors, which stay distinct under every common form
+            // of color blindness
+            ThemeName::HighContrast => Theme {
+                text: Color::White,
+                label: Color::White,
+                dim: Color::Gray,
+                border: Color::LightCyan,
+                highlight: Color::Rgb(240, 228, 66),
+                good: Color::Rgb(86, 180, 233),
+                warning: Color::Rgb(230, 159, 0),
+                error: Color::Rgb(213, 94, 0),
+                on_border: Color::Black,
+                heat: [
+                    Color::Gray,
+                    Color::Rgb(8, 48, 107),
+                    Color::Rgb(8, 81, 156),
+                    Color::Rgb(0, 114, 178),
+                    Color::Rgb(86, 180, 233),
+                ],
+                sections: SectionColors {
+                    measurements: Color::Rgb(240, 228, 66),
+                    running: Color::Rgb(230, 159, 0),
+                    food: Color::Rgb(240, 228, 66),
+                    sokay: Color::Rgb(204, 121, 167),
+                    strength: Color::Rgb(86, 180, 233),
+                    notes: Color::White,
+                },
+            },
+        }
+    }
+
+    /// Tag color for an activity type, borrowed from the roles already in
+    /// the palette so every theme covers it.
+    pub fn activity(&self, kind: ActivityType) -> Color {
+        match kind {
+            ActivityType::Run => self.sections.running,
+            ActivityType::Hike => self.good,
+            ActivityType::Bike => self.sections.sokay,
+            ActivityType::Ski => self.border,
+            ActivityType::Strength => self.highlight,
+        }
+    }
+}
@@ -0,0 +1,206 @@
+use serde::{Deserialize, Serialize};
+
+const KM_PER_MILE: f64 = 1.609344;
+const METERS_PER_FOOT: f64 = 0.3048;
+const KG_PER_LB: f64 = 0.45359237;
+const CM_PER_INCH: f64 = 2.54;
+const LITERS_PER_FL_OZ: f64 = 0.0295735296;
+
+/// The units values are shown and entered in. Logs are always stored in
+/// imperial (miles, feet, lbs, inches, fluid ounces), so switching systems never rewrites
+/// the database or the markdown files already on disk.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UnitSystem {
+    #[default]
+    Imperial,
+    Metric,
+}
+
+/// What a number measures, which decides its unit and precision.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Quantity {
+    Distance,
+    Elevation,
+    Weight,
+    Waist,
+    Water,
+}
+
+impl Quantity {
+    /// Display units per stored imperial unit in the metric system.
+    fn metric_factor(self) -> f64 {
+        match self {
+            Quantity::Distance => KM_PER_MILE,
+            Quantity::Elevation => METERS_PER_FOOT,
+            Quantity::Weight => KG_PER_LB,
+            Quantity::Waist => CM_PER_INCH,
+            Quantity::Water => LITERS_PER_FL_OZ,
+        }
+    }
+
+    /// Decimal places kept for converted values.
+    fn decimals(self) -> usize {
+        match self {
+            Quantity::Elevation => 0,
+            Quantity::Distance | Quantity::Weight | Quantity::Waist => 1,
+            Quantity::Water => 2,
+        }
+    }
+}
+
+impl UnitSystem {
+    /// The system that isn't this one.
+    pub fn other(self) -> Self {
+        match self {
+            UnitSystem::Imperial => UnitSystem::Metric,
+            UnitSystem::Metric => UnitSystem::Imperial,
+        }
+    }
+
+    pub fn label(self, quantity: Quantity) -> &'static str {
+        match (self, quantity) {
+            (UnitSystem::Imperial, Quantity::Distance) => "mi",
+            (UnitSystem::Imperial, Quantity::Elevation) => "ft",
+            (UnitSystem::Imperial, Quantity::Weight) => "lbs",
+            (UnitSystem::Imperial, Quantity::Waist) => "in",
+            (UnitSystem::Imperial, Quantity::Water) => "oz",
+            (UnitSystem::Metric, Quantity::Distance) => "km",
+            (UnitSystem::Metric, Quantity::Elevation) => "m",
+            (UnitSystem::Metric, Quantity::Weight) => "kg",
+            (UnitSystem::Metric, Quantity::Waist) => "cm",
+            (UnitSystem::Metric, Quantity::Water) => "L",
+        }
+    }
+
+    /// The unit spelled out, for sentences ("42.0 miles covered").
+    pub fn name(self, quantity: Quantity) -> &'static str {
+        match (self, quantity) {
+            (UnitSystem::Imperial, Quantity::Distance) => "miles",
+            (UnitSystem::Imperial, Quantity::Elevation) => "feet",
+            (UnitSystem::Imperial, Quantity::Weight) => "lbs",
+            (UnitSystem::Imperial, Quantity::Waist) => "inches",
+            (UnitSystem::Imperial, Quantity::Water) => "ounces",
+            (UnitSystem::Metric, Quantity::Distance) => "kilometers",
+            (UnitSystem::Metric, Quantity::Elevation) => "meters",
+            (UnitSystem::Metric, Quantity::Weight) => "kg",
+            (UnitSystem::Metric, Quantity::Waist) => "centimeters",
+            (UnitSystem::Metric, Quantity::Water) => "liters",
+        }
+    }
+
+    /// Heading for distance columns and fields: "Miles" or "Kilometers".
+    pub fn distance_title(self) -> &'static str {
+        match self {
+            UnitSystem::Imperial => "Miles",
+            UnitSystem::Metric => "Kilometers",
+        }
+    }
+
+    /// Converts a stored imperial value into this system.
+    pub fn convert(self, quantity: Quantity, stored: f64) -> f64 {
+        match self {
+            UnitSystem::Imperial => stored,
+            UnitSystem::Metric => stored * quantity.metric_factor(),
+        }
+    }
+
+    /// Converts a value entered in this system back to stored imperial.
+    pub fn store(self, quantity: Quantity, shown: f64) -> f64 {
+        match self {
+            UnitSystem::Imperial => shown,
+            UnitSystem::Metric => shown / quantity.metric_factor(),
+        }
+    }
+
+    /// The bare number for a stored value. Imperial values print exactly as
+    /// stored; converted values are rounded to the quantity's precision with
+    /// trailing zeros dropped, so they read like something typed in. Water
+    /// is added a glass at a time rather than typed, and metric glasses
+    /// don't come out even in ounces, so it's rounded in either system.
+    pub fn amount(self, quantity: Quantity, stored: f32) -> String {
+        let value = match (self, quantity) {
+            (UnitSystem::Imperial, Quantity::Water) => stored as f64,
+            (UnitSystem::Imperial, _) => return stored.to_string(),
+            (UnitSystem::Metric, _) => self.convert(quantity, stored as f64),
+        };
+        let text = format!("{:.*}", quantity.decimals(), value);
+        if text.contains('.') {
+            text.trim_end_matches('0').trim_end_matches('.').to_string()
+        } else {
+            text
+        }
+    }
+
+    /// Parses a value typed in this system into its stored imperial form.
+    /// Elevation must be a whole number in either system.
+    pub fn parse(self, quantity: Quantity, text: &str) -> Option<f64> {
+        let shown: f64 = if quantity == Quantity::Elevation {
+            text.parse::<i32>().ok()? as f64
+        } else {
+            text.parse().ok()?
+        };
+        Some(self.store(quantity, shown))
+    }
+
+    /// Time per mile or kilometer for `seconds` over a stored distance, as
+    /// "9:41 /mi". None without any distance to divide by.
+    pub fn pace(self, seconds: u32, miles: f32) -> Option<String> {
+        let distance = self.convert(Quantity::Distance, miles as f64);
+        if seconds == 0 || distance <= 0.0 {
+            return None;
+        }
+        let per_unit = (seconds as f64 / distance).round() as u64;
+        Some(format!(
+            "{}:{:02} /{}",
+            per_unit / 60,
+            per_unit % 60,
+            self.label(Quantity::Distance)
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::format::Units;
+
+    #[test]
+    fn imperial_values_print_as_stored() {
+        let units = Units::from(UnitSystem::Imperial);
+        assert_eq!(units.format(Quantity::Weight, 175.5), "175.5 lbs");
+        assert_eq!(units.format(Quantity::Elevation, 1200.0), "1,200 ft");
+        assert_eq!(units.parse(Quantity::Distance, "5.3"), Some(5.3));
+    }
+
+    #[test]
+    fn metric_values_convert_and_round_trip() {
+        let units = Units::from(UnitSystem::Metric);
+        assert_eq!(units.format(Quantity::Distance, 10.0), "16.1 km");
+        assert_eq!(units.format(Quantity::Elevation, 1000.0), "305 m");
+        assert_eq!(units.format(Quantity::Weight, 220.0), "99.8 kg");
+        assert_eq!(units.format(Quantity::Waist, 34.0), "86.4 cm");
+        assert_eq!(units.format(Quantity::Distance, 6.2137119), "10 km");
+
+        let stored = units.parse(Quantity::Weight, "80").unwrap();
+        assert_eq!(units.amount(Quantity::Weight, stored as f32), "80");
+        assert_eq!(units.parse(Quantity::Elevation, "12.5"), None);
+        assert_eq!(units.vert_threshold(1000), "305+");
+    }
+
+    #[test]
+    fn pace_is_per_mile_or_kilometer() {
+        assert_eq!(UnitSystem::Imperial.pace(3600, 6.2), Some("9:41 /mi".to_string()));
+        assert_eq!(UnitSystem::Metric.pace(3000, 6.2137119), Some("5:00 /km".to_string()));
+        assert_eq!(UnitSystem::Imperial.pace(3600, 0.0), None);
+    }
+
+    #[test]
+    fn water_rounds_in_both_systems() {
+        let glass = UnitSystem::Metric.store(Quantity::Water, 0.25) as f32;
+        let (metric, imperial) = (Units::from(UnitSystem::Metric), Units::from(UnitSystem::Imperial));
+        assert_eq!(metric.format(Quantity::Water, glass * 3.0), "0.75 L");
+        assert_eq!(imperial.format(Quantity::Water, glass), "8.45 oz");
+        assert_eq!(imperial.format(Quantity::Water, 64.0), "64 oz");
+    }
+}
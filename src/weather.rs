@@ -0,0 +1,145 @@
+//! The day's weather from Open-Meteo, fetched once a run is logged for a
+//! place set under `[weather]`. Like sync, a failed fetch (offline, a
+//! timeout) is dropped quietly and the day is saved without weather.
+
+use anyhow::{Context, Result, bail};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use crate::config::WeatherConfig;
+use crate::format::Units;
+use crate::units::UnitSystem;
+
+/// How long to wait for Open-Meteo before giving up on the day's weather.
+const FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How far back the forecast API keeps days; older ones come from the
+/// archive, which trails a few days behind.
+const FORECAST_PAST_DAYS: i64 = 90;
+
+/// The day's high and low, stored in °F like the other stored units, and
+/// its WMO weather code.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Weather {
+    pub high: f32,
+    pub low: f32,
+    pub code: u8,
+}
+
+impl Weather {
+    /// What the WMO weather code describes, in a few words.
+    pub fn conditions(&self) -> &'static str {
+        match self.code {
+            0 => "Clear",
+            1 | 2 => "Partly cloudy",
+            3 => "Overcast",
+            45 | 48 => "Fog",
+            51..=57 => "Drizzle",
+            61..=67 => "Rain",
+            71..=77 => "Snow",
+            80..=82 => "Showers",
+            85 | 86 => "Snow showers",
+            95..=99 => "Thunderstorms",
+            _ => "Unknown",
+        }
+    }
+
+    /// "61°F / 45°F, Partly cloudy", in °C for metric.
+    pub fn describe(&self, units: Units) -> String {
+        format!(
+            "{} / {}, {}",
+            temperature(self.high, units),
+            temperature(self.low, units),
+            self.conditions()
+        )
+    }
+}
+
+fn temperature(fahrenheit: f32, units: Units) -> String {
+    match units.system {
+        UnitSystem::Imperial => format!("{:.0}°F", fahrenheit),
+        UnitSystem::Metric => format!("{:.0}°C", (fahrenheit - 32.0) * 5.0 / 9.0),
+    }
+}
+
+/// The Open-Meteo request for `date`'s daily high, low and conditions at the
+/// configured place.
+fn request_url(latitude: f64, longitude: f64, date: NaiveDate, today: NaiveDate) -> String {
+    let host = if (today - date).num_days() <= FORECAST_PAST_DAYS {
+        "https://api.open-meteo.com/v1/forecast"
+    } else {
+        "https://archive-api.open-meteo.com/v1/archive"
+    };
+    let day = date.format("%Y-%m-%d");
+    format!(
+        "{}?latitude={}&longitude={}&daily=temperature_2m_max,temperature_2m_min,weather_code\
+         &temperature_unit=fahrenheit&timezone=auto&start_date={}&end_date={}",
+        host, latitude, longitude, day, day
+    )
+}
+
+/// Reads the single day out of Open-Meteo's `daily` arrays. A day the
+/// archive hasn't filled in yet comes back as nulls, which is an error.
+fn parse_daily(json: &serde_json::Value) -> Result<Weather> {
+    let daily = json.get("daily").context("No daily weather in the response")?;
+    let first = |key: &str| daily.get(key).and_then(|values| values.get(0)).and_then(|v| v.as_f64());
+    let (Some(high), Some(low), Some(code)) = (
+        first("temperature_2m_max"),
+        first("temperature_2m_min"),
+        first("weather_code"),
+    ) else {
+        bail!("No weather recorded for the day yet");
+    };
+    Ok(Weather {
+        high: high as f32,
+        low: low as f32,
+        code: code as u8,
+    })
+}
+
+/// Fetches `date`'s weather. Blocking; run it with `spawn_blocking`.
+pub fn fetch(config: &WeatherConfig, date: NaiveDate, today: NaiveDate) -> Result<Weather> {
+    let (latitude, longitude) = config.location().context("No [weather] location set")?;
+    let json: serde_json::Value = ureq::get(&request_url(latitude, longitude, date, today))
+        .timeout(FETCH_TIMEOUT)
+        .call()
+        .context("Failed to reach Open-Meteo")?
+        .into_json()
+        .context("Failed to read Open-Meteo's response")?;
+    parse_daily(&json)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recent_days_use_the_forecast_and_older_ones_the_archive() {
+        let day = |m, d| NaiveDate::from_ymd_opt(2026, m, d).unwrap();
+        let recent = request_url(40.0, -105.27, day(7, 20), day(7, 22));
+        assert!(recent.starts_with("https://api.open-meteo.com/v1/forecast?latitude=40&longitude=-105.27"));
+        assert!(recent.ends_with("start_date=2026-07-20&end_date=2026-07-20"));
+        assert!(request_url(40.0, -105.27, day(1, 2), day(7, 22)).starts_with("https://archive-api"));
+    }
+
+    #[test]
+    fn reads_the_day_and_describes_it_in_either_unit() {
+        let json = serde_json::json!({
+            "daily": {
+                "time": ["2026-07-20"],
+                "temperature_2m_max": [86.4],
+                "temperature_2m_min": [59.0],
+                "weather_code": [2]
+            }
+        });
+        let weather = parse_daily(&json).unwrap();
+        assert_eq!(weather.describe(UnitSystem::Imperial.into()), "86°F / 59°F, Partly cloudy");
+        assert_eq!(weather.describe(UnitSystem::Metric.into()), "30°C / 15°C, Partly cloudy");
+
+        let pending = serde_json::json!({
+            "daily": { "temperature_2m_max": [null], "temperature_2m_min": [null], "weather_code": [null] }
+        });
+        assert!(parse_daily(&pending).is_err());
+    }
+}
@@ -0,0 +1,191 @@
+use crate::models::DailyLog;
+use chrono::{Datelike, Days, NaiveDate, Weekday};
+use std::collections::HashSet;
+
+/// Totals for one seven-day week starting on the configured weekday.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeekSummary {
+    pub start: NaiveDate,
+    pub miles: f32,
+    pub elevation: i32,
+    /// Days with any miles logged.
+    pub days_run: usize,
+    /// Days marked as rest days, which the days run are counted out of
+    /// the week without.
+    pub rest_days: usize,
+    pub sokay_count: usize,
+    /// Average perceived effort over the days that have one, to one decimal.
+    pub average_rpe: Option<f32>,
+    /// The week's longest run and its day; the earlier day wins a tie.
+    pub long_run: Option<(NaiveDate, f32)>,
+}
+
+/// First day of the week containing `date`, for weeks starting on `first`.
+pub fn week_start(date: NaiveDate, first: Weekday) -> NaiveDate {
+    date.week(first).first_day()
+}
+
+/// ISO week number for the week starting on `start`. Weeks that don't start
+/// on Monday take the number of the ISO week holding most of their days.
+pub fn week_number(start: NaiveDate) -> u32 {
+    start
+        .checked_add_days(Days::new(3))
+        .unwrap_or(start)
+        .iso_week()
+        .week()
+}
+
+/// The seven dates of the week containing `date`, `first` first.
+pub fn week_dates(date: NaiveDate, first: Weekday) -> Vec<NaiveDate> {
+    let start = week_start(date, first);
+    (0..7)
+        .filter_map(|offset| start.checked_add_days(Days::new(offset)))
+        .collect()
+}
+
+pub fn summarize_week(logs: &[DailyLog], date: NaiveDate, first: Weekday) -> WeekSummary {
+    let start = week_start(date, first);
+    let mut summary = WeekSummary {
+        start,
+        miles: 0.0,
+        elevation: 0,
+        days_run: 0,
+        rest_days: 0,
+        sokay_count: 0,
+        average_rpe: None,
+        long_run: None,
+    };
+    let mut efforts = Vec::new();
+    for log in logs
+        .iter()
+        .filter(|log| week_start(log.date, first) == start)
+    {
+        let miles = log.miles_covered.unwrap_or(0.0);
+        summary.miles += miles;
+        summary.elevation += log.elevation_gain.unwrap_or(0);
+        summary.sokay_count += log.sokay_entries.len();
+        if log.rest_day {
+            summary.rest_days += 1;
+        }
+        if miles > 0.0 {
+            summary.days_run += 1;
+            let longer = summary.long_run.is_none_or(|(day, longest)| {
+                miles > longest || (miles == longest && log.date < day)
+            });
+            if longer {
+                summary.long_run = Some((log.date, miles));
+            }
+        }
+        efforts.extend(log.rpe.map(f32::from));
+    }
+    summary.miles = (summary.miles * 10.0).round() / 10.0;
+    if !efforts.is_empty() {
+        let average = efforts.iter().sum::<f32>() / efforts.len() as f32;
+        summary.average_rpe = Some((average * 10.0).round() / 10.0);
+    }
+    summary
+}
+
+/// Weeks in a row, back from the one containing `date`, with a run of at
+/// least `min_miles`. Like the daily streaks, the week holding `today`
+/// doesn't break it before it's over.
+pub fn long_run_streak(
+    logs: &[DailyLog],
+    date: NaiveDate,
+    first: Weekday,
+    min_miles: f32,
+    today: NaiveDate,
+) -> usize {
+    let long_weeks: HashSet<NaiveDate> = logs
+        .iter()
+        .filter(|log| log.miles_covered.is_some_and(|miles| miles > 0.0 && miles >= min_miles))
+        .map(|log| week_start(log.date, first))
+        .collect();
+    let mut week = week_start(date, first);
+    if !long_weeks.contains(&week) && week == week_start(today, first) {
+        week = week - Days::new(7);
+    }
+    let mut weeks = 0;
+    while long_weeks.contains(&week) {
+        weeks += 1;
+        week = week - Days::new(7);
+    }
+    weeks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summarizes_only_the_configured_week() {
+        let day = |m, d| NaiveDate::from_ymd_opt(2026, m, d).unwrap();
+        let logs = vec![
+            DailyLog {
+                miles_covered: Some(5.2),
+                elevation_gain: Some(1200),
+                sokay_entries: vec!["chips".to_string(), "cookie".to_string()],
+                ..DailyLog::new(day(7, 20))
+            },
+            DailyLog {
+                miles_covered: Some(3.1),
+                rpe: Some(6),
+                ..DailyLog::new(day(7, 26))
+            },
+            DailyLog {
+                sokay_entries: vec!["soda".to_string()],
+                rest_day: true,
+                ..DailyLog::new(day(7, 22))
+            },
+            DailyLog {
+                miles_covered: Some(10.0),
+                rpe: Some(9),
+                ..DailyLog::new(day(7, 27))
+            },
+        ];
+
+        let summary = summarize_week(&logs, day(7, 23), Weekday::Mon);
+        assert_eq!(summary.start, day(7, 20));
+        assert_eq!(summary.miles, 8.3);
+        assert_eq!(summary.elevation, 1200);
+        assert_eq!((summary.days_run, summary.rest_days), (2, 1));
+        assert_eq!(summary.sokay_count, 3);
+        assert_eq!(summary.average_rpe, Some(6.0));
+        assert_eq!(summary.long_run, Some((day(7, 20), 5.2)));
+        assert_eq!(
+            week_dates(day(7, 23), Weekday::Mon).last(),
+            Some(&day(7, 26))
+        );
+
+        // With Sunday starts the 26th begins the next week
+        let summary = summarize_week(&logs, day(7, 23), Weekday::Sun);
+        assert_eq!(summary.start, day(7, 19));
+        assert_eq!(summary.miles, 5.2);
+        assert_eq!(summary.average_rpe, None);
+        assert_eq!(week_number(summary.start), 30);
+    }
+
+    #[test]
+    fn long_run_streak_counts_weeks_back_and_waits_on_this_one() {
+        let day = |m, d| NaiveDate::from_ymd_opt(2026, m, d).unwrap();
+        let run = |date, miles| DailyLog {
+            miles_covered: Some(miles),
+            ..DailyLog::new(date)
+        };
+        // Long runs the weeks of Jul 6, 13 and 20; Jun 29 falls short
+        let logs = vec![
+            run(day(7, 1), 8.0),
+            run(day(7, 12), 12.0),
+            run(day(7, 18), 10.0),
+            run(day(7, 26), 14.5),
+            run(day(7, 21), 4.0),
+        ];
+        let streak = |date, today| long_run_streak(&logs, date, Weekday::Mon, 10.0, today);
+        assert_eq!(streak(day(7, 22), day(7, 29)), 3);
+        // The week of Jul 27 has no long run yet but isn't over
+        assert_eq!(streak(day(7, 28), day(7, 29)), 3);
+        assert_eq!(streak(day(8, 4), day(8, 5)), 0);
+        assert_eq!(streak(day(7, 15), day(7, 29)), 2);
+        assert_eq!(long_run_streak(&logs, day(7, 22), Weekday::Mon, 13.0, day(7, 29)), 1);
+    }
+}
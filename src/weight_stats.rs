@@ -0,0 +1,96 @@
+use crate::models::DailyLog;
+use crate::format::Units;
+use crate::units::Quantity;
+use chrono::NaiveDate;
+
+/// Number of most recent weigh-ins averaged when checking a new weight.
+const RECENT_WEIGH_INS: usize = 7;
+
+/// Average of the most recent weigh-ins strictly before `date`, or `None` when
+/// there is no earlier weight to compare against.
+pub fn recent_weight_average(logs: &[DailyLog], date: NaiveDate) -> Option<f32> {
+    let mut recent: Vec<(NaiveDate, f32)> = logs
+        .iter()
+        .filter(|log| log.date < date)
+        .filter_map(|log| log.weight.map(|weight| (log.date, weight)))
+        .collect();
+    if recent.is_empty() {
+        return None;
+    }
+
+    recent.sort_by_key(|(date, _)| std::cmp::Reverse(*date));
+    recent.truncate(RECENT_WEIGH_INS);
+    Some(recent.iter().map(|(_, weight)| weight).sum::<f32>() / recent.len() as f32)
+}
+
+/// Warning text when `weight` strays further than `threshold` lbs from the
+/// recent average (e.g. 17.2 typed for 172). A threshold of 0 disables the check.
+/// The text is written in `units`; the comparison is always in lbs.
+pub fn weight_typo_warning(
+    logs: &[DailyLog],
+    date: NaiveDate,
+    weight: f32,
+    threshold: f32,
+    units: Units,
+) -> Option<String> {
+    if threshold <= 0.0 {
+        return None;
+    }
+    let average = recent_weight_average(logs, date)?;
+    let difference = (weight - average).abs();
+    (difference > threshold).then(|| {
+        format!(
+            "{} is {} off your recent average of {}. This looks like a typo — confirm?",
+            units.format(Quantity::Weight, weight),
+            units.total(Quantity::Weight, difference as f64),
+            units.total(Quantity::Weight, average as f64)
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::UnitSystem;
+
+    fn log(day: u32, weight: Option<f32>) -> DailyLog {
+        let date = NaiveDate::from_ymd_opt(2026, 7, day).unwrap();
+        DailyLog {
+            weight,
+            ..DailyLog::new(date)
+        }
+    }
+
+    #[test]
+    fn average_uses_only_recent_earlier_weigh_ins() {
+        let mut logs: Vec<DailyLog> = (1..=9).map(|day| log(day, Some(170.0))).collect();
+        // Outside the 7 most recent before the 10th
+        logs[0].weight = Some(100.0);
+        logs.push(log(10, Some(500.0)));
+        logs.push(log(11, None));
+
+        let date = NaiveDate::from_ymd_opt(2026, 7, 10).unwrap();
+        assert_eq!(recent_weight_average(&logs, date), Some(170.0));
+        assert_eq!(
+            recent_weight_average(&logs, NaiveDate::from_ymd_opt(2026, 7, 1).unwrap()),
+            None
+        );
+    }
+
+    #[test]
+    fn warns_only_beyond_threshold() {
+        let logs = vec![log(1, Some(172.0)), log(2, Some(173.0))];
+        let date = NaiveDate::from_ymd_opt(2026, 7, 3).unwrap();
+        let imperial = Units::from(UnitSystem::Imperial);
+
+        assert!(weight_typo_warning(&logs, date, 17.2, 8.0, imperial).is_some());
+        assert!(weight_typo_warning(&logs, date, 180.0, 8.0, imperial).is_none());
+        assert!(weight_typo_warning(&logs, date, 181.0, 8.0, imperial).is_some());
+        assert!(weight_typo_warning(&logs, date, 17.2, 0.0, imperial).is_none());
+        assert!(weight_typo_warning(&[], date, 17.2, 8.0, imperial).is_none());
+
+        let metric = Units::from(UnitSystem::Metric);
+        let warning = weight_typo_warning(&logs, date, 17.2, 8.0, metric).unwrap();
+        assert!(warning.starts_with("7.8 kg is 70.4 kg off your recent average of 78.2 kg."));
+    }
+}